@@ -0,0 +1,78 @@
+# Regenerates src/kernel/co/error_catalog.rs and src/ole/co/hresult_catalog.rs,
+# which back the "error-catalog" feature.
+#
+# Constant names and values come from the const_values! blocks in error.rs and
+# hresult.rs; the English messages come from error.txt and hresult.txt, one
+# NAME|message line per constant, with the text FormatMessage returns on an
+# en-US system. When two constants share a value, the first one wins.
+#
+# Usage, from the repo root: python3 error-catalog/gen.py
+
+import os
+import sys
+
+ROOT = os.path.join(os.path.dirname(os.path.abspath(__file__)), '..')
+
+def read_consts(rs_path, typ):
+	src = open(rs_path, newline='').read().replace('\r\n', '\n')
+	body = src[src.index('const_values! { %s;' % typ):]
+	body = body[body.index('\n'):body.index('\n}\n')]
+	consts = []
+	for line in body.split('\n'):
+		line = line.strip()
+		if not line or line.startswith('//'):
+			continue
+		name, val = line.split()[:2]
+		consts.append((name, int(val.replace('_', ''), 0)))
+	return consts
+
+def read_messages(txt_path):
+	msgs = {}
+	for line in open(txt_path, newline='').read().splitlines():
+		if line:
+			name, msg = line.split('|', 1)
+			msgs[name] = msg
+	return msgs
+
+def escape(s):
+	return s.replace('\\', '\\\\').replace('"', '\\"')
+
+def generate(typ, rs_path, txt_path, out_path, module, fmt_code, header):
+	msgs = read_messages(os.path.join(ROOT, txt_path))
+	by_code = {}
+	for name, val in read_consts(os.path.join(ROOT, rs_path), typ):
+		by_code.setdefault(val, name)
+
+	unknown = sorted(set(msgs) - set(by_code.values()))
+	if unknown:
+		sys.exit('%s: names not in %s: %s' % (txt_path, rs_path, ', '.join(unknown)))
+
+	lines = header + [
+		'',
+		'/// Sorted by code: code, constant name, English message (empty if unknown).',
+		'pub(in crate::%s) const %s_CATALOG: &[(u32, &str, &str)] = &[' % (module, typ),
+	]
+	for code, name in sorted(by_code.items()):
+		lines.append('\t(%s, "%s", "%s"),' % (fmt_code(code), name, escape(msgs.get(name, ''))))
+	lines.append('];')
+
+	open(os.path.join(ROOT, out_path), 'w', newline='').write('\r\n'.join(lines) + '\r\n')
+	print('%s: %d entries' % (out_path, len(by_code)))
+
+generate('ERROR', 'src/kernel/co/error.rs', 'error-catalog/error.txt',
+	'src/kernel/co/error_catalog.rs', 'kernel', str, [
+		'#![cfg(feature = "error-catalog")]',
+		'',
+		'//! Offline catalog of [`ERROR`](crate::co::ERROR) constants. Generated by',
+		'//! `error-catalog/gen.py` from error.rs and `error-catalog/error.txt`; do not',
+		'//! edit by hand.',
+	])
+
+generate('HRESULT', 'src/ole/co/hresult.rs', 'error-catalog/hresult.txt',
+	'src/ole/co/hresult_catalog.rs', 'ole', hex, [
+		'#![cfg(feature = "error-catalog")]',
+		'',
+		'//! Offline catalog of [`HRESULT`](crate::co::HRESULT) constants. Generated by',
+		'//! `error-catalog/gen.py` from hresult.rs and `error-catalog/hresult.txt`; do',
+		'//! not edit by hand.',
+	])
@@ -2,6 +2,52 @@
 
 use crate::co::*;
 
+const_bitflag! { ACE: u8;
+	/// [`ACE_HEADER`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-ace_header)
+	/// `AceFlags` (`u8`).
+	///
+	/// Originally has `_ACE` suffix.
+	=>
+	OBJECT_INHERIT 0x01
+	CONTAINER_INHERIT 0x02
+	NO_PROPAGATE_INHERIT 0x04
+	INHERIT_ONLY 0x08
+	INHERITED 0x10
+	CRITICAL 0x20
+	SUCCESSFUL_ACCESS 0x40
+	FAILED_ACCESS 0x80
+}
+
+const_ordinary! { ACE_TYPE: u8;
+	/// [`ACE_HEADER`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-ace_header)
+	/// `AceType` (`u8`).
+	///
+	/// Originally has `_ACE_TYPE` suffix.
+	=>
+	ACCESS_ALLOWED 0x00
+	ACCESS_DENIED 0x01
+	SYSTEM_AUDIT 0x02
+	SYSTEM_ALARM 0x03
+	ACCESS_ALLOWED_COMPOUND 0x04
+	ACCESS_ALLOWED_OBJECT 0x05
+	ACCESS_DENIED_OBJECT 0x06
+	SYSTEM_AUDIT_OBJECT 0x07
+	SYSTEM_ALARM_OBJECT 0x08
+	ACCESS_ALLOWED_CALLBACK 0x09
+	ACCESS_DENIED_CALLBACK 0x0a
+	ACCESS_ALLOWED_CALLBACK_OBJECT 0x0b
+	ACCESS_DENIED_CALLBACK_OBJECT 0x0c
+	SYSTEM_AUDIT_CALLBACK 0x0d
+	SYSTEM_ALARM_CALLBACK 0x0e
+	SYSTEM_AUDIT_CALLBACK_OBJECT 0x0f
+	SYSTEM_ALARM_CALLBACK_OBJECT 0x10
+	SYSTEM_MANDATORY_LABEL 0x11
+	SYSTEM_RESOURCE_ATTRIBUTE 0x12
+	SYSTEM_SCOPED_POLICY_ID 0x13
+	SYSTEM_PROCESS_TRUST_LABEL 0x14
+	SYSTEM_ACCESS_FILTER 0x15
+}
+
 const_ordinary! { EVENTLOG: u16;
 	/// [`HEVENTLOG::ReportEvent`](crate::prelude::advapi_Heventlog::ReportEvent)
 	/// `event_type` [`u16`].
@@ -316,6 +362,22 @@ const_bitflag! { SC_MANAGER: u32;
 	GENERIC_ALL Self::ALL_ACCESS.0
 }
 
+const_bitflag! { SE_GROUP: u32;
+	/// [`SID_AND_ATTRIBUTES`](crate::SID_AND_ATTRIBUTES) `Attributes` of
+	/// [token groups](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-token_groups)
+	/// (`u32`).
+	=>
+	MANDATORY 0x0000_0001
+	ENABLED_BY_DEFAULT 0x0000_0002
+	ENABLED 0x0000_0004
+	OWNER 0x0000_0008
+	USE_FOR_DENY_ONLY 0x0000_0010
+	INTEGRITY 0x0000_0020
+	INTEGRITY_ENABLED 0x0000_0040
+	RESOURCE 0x2000_0000
+	LOGON_ID 0xc000_0000
+}
+
 const_str! { SE_PRIV;
 	/// [Privilege constants](https://learn.microsoft.com/en-us/windows/win32/secauthz/privilege-constants)
 	/// (`&'static str`).
@@ -501,6 +563,15 @@ const_ordinary! { SID_NAME_USE: u32;
 	LogonSession 11
 }
 
+const_bitflag! { SYSTEM_MANDATORY_LABEL: u32;
+	/// [`SYSTEM_MANDATORY_LABEL_ACE`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-system_mandatory_label_ace)
+	/// `Mask` (`u32`).
+	=>
+	NO_WRITE_UP 0x1
+	NO_READ_UP 0x2
+	NO_EXECUTE_UP 0x4
+}
+
 const_ordinary! { TOKEN_ELEVATION_TYPE: u32;
 	/// [`TOKEN_ELEVATION_TYPE`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ne-winnt-token_elevation_type)
 	/// enumeration (`u32`).
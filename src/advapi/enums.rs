@@ -16,7 +16,7 @@ pub enum DisabPriv<'a> {
 ///
 /// This is a high-level abstraction over the [`co::REG`](crate::co::REG)
 /// constants, plus the value they carry.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum RegistryValue {
 	/// Binary value, defined as [`REG::BINARY`](crate::co::REG::BINARY).
 	Binary(Vec<u8>),
@@ -361,6 +361,7 @@ pub enum TokenInfo<'a, 'b, 'c, 'd, 'e, 'f> {
 	LogonSid(Box<TOKEN_GROUPS<'a>>),
 	IsAppContainer(Box<u32>),
 	Capabilities(Box<TOKEN_GROUPS<'a>>),
+	AppContainerSid(Box<TOKEN_APPCONTAINER_INFORMATION<'a>>),
 	AppContainerNumber(Box<u32>),
 	DeviceClaimAttributes(Box<CLAIM_SECURITY_ATTRIBUTES_INFORMATION<'a, 'b>>),
 	DeviceGroups(Box<TOKEN_GROUPS<'a>>),
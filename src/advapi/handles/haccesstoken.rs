@@ -284,6 +284,9 @@ pub trait advapi_Haccesstoken: Handle {
 					co::TOKEN_INFORMATION_CLASS::Capabilities => TokenInfo::Capabilities(
 						Box::from_raw(Box::into_raw(buf) as *mut TOKEN_GROUPS),
 					),
+					co::TOKEN_INFORMATION_CLASS::AppContainerSid => TokenInfo::AppContainerSid(
+						Box::from_raw(Box::into_raw(buf) as *mut TOKEN_APPCONTAINER_INFORMATION),
+					),
 					co::TOKEN_INFORMATION_CLASS::AppContainerNumber => TokenInfo::AppContainerNumber(
 						Box::from_raw(Box::into_raw(buf) as *mut u32),
 					),
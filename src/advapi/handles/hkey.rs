@@ -1,6 +1,6 @@
 #![allow(non_camel_case_types, non_snake_case)]
 
-use crate::advapi::{ffi, iterators::*};
+use crate::advapi::{ffi, iterators::*, utilities::*};
 use crate::co;
 use crate::decl::*;
 use crate::guard::*;
@@ -46,6 +46,100 @@ pub trait advapi_Hkey: Handle {
 	predef_key!(PERFORMANCE_TEXT, 0x8000_0050);
 	predef_key!(PERFORMANCE_NLSTEXT, 0x8000_0060);
 
+	/// Writes the keys and values of a parsed [`RegFile`](crate::RegFile) to
+	/// the registry, like importing the file with Registry Editor.
+	///
+	/// Keys are created as needed; keys and values marked for deletion which
+	/// don't exist are ignored. Key paths must start with the name of a root
+	/// key, either long or abbreviated, like `HKEY_CURRENT_USER` or `HKCU`,
+	/// otherwise [`ERROR::INVALID_DATA`](crate::co::ERROR::INVALID_DATA) is
+	/// returned. Deleting a whole root key, like `[-HKEY_CURRENT_USER]`, is
+	/// refused with
+	/// [`ERROR::INVALID_PARAMETER`](crate::co::ERROR::INVALID_PARAMETER).
+	/// Paths are validated before any change is made.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let fin = w::FileMapped::open(
+	///     "C:\\Temp\\my_app.reg",
+	///     w::FileAccess::ExistingReadOnly,
+	/// )?;
+	/// let reg = w::RegFile::parse(fin.as_slice())?;
+	///
+	/// w::HKEY::apply_reg_file(&reg)?;
+	/// # w::SysResult::Ok(())
+	/// ```
+	fn apply_reg_file(reg_file: &RegFile) -> SysResult<()> {
+		let ignore_not_found = |res: SysResult<()>| match res {
+			Err(co::ERROR::FILE_NOT_FOUND) => Ok(()),
+			res => res,
+		};
+
+		for key in reg_file.keys.iter() { // validate before touching the registry
+			let (_, sub_key) = split_key_path(&key.path)?;
+			if key.delete && sub_key.is_empty() {
+				return Err(co::ERROR::INVALID_PARAMETER); // would wipe the whole root key
+			}
+		}
+
+		for key in reg_file.keys.iter() {
+			let (hroot, sub_key) = split_key_path(&key.path)?;
+			if key.delete {
+				ignore_not_found(hroot.RegDeleteTree(Some(sub_key)))?;
+				continue;
+			}
+
+			let (hkey, _) = hroot.RegCreateKeyEx(
+				sub_key,
+				None,
+				co::REG_OPTION::default(),
+				co::KEY::WRITE | co::KEY::QUERY_VALUE,
+				None,
+			)?;
+			for val in key.values.iter() {
+				match &val.data {
+					Some(RegFileData::Value(data)) => hkey.RegSetValueEx(val.name.as_deref(), data.clone())?,
+					Some(RegFileData::Raw(reg_type, bytes)) => set_value_raw(&*hkey, val.name.as_deref(), *reg_type, bytes)?,
+					None => ignore_not_found(hkey.RegDeleteValue(val.name.as_deref()))?,
+				}
+			}
+		}
+		Ok(())
+	}
+
+	/// Reads the values of the key, and recursively all its subkeys and their
+	/// values, returning them as a [`RegFile`](crate::RegFile), like
+	/// exporting a branch with Registry Editor.
+	///
+	/// `key_path` is the full path written to the file for this key, starting
+	/// with the name of the root key, like
+	/// `HKEY_CURRENT_USER\Software\MyApp`.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hkey = w::HKEY::CURRENT_USER.RegOpenKeyEx(
+	///     Some("Software\\MyApp"),
+	///     co::REG_OPTION::default(),
+	///     co::KEY::READ,
+	/// )?;
+	/// let reg = hkey.export_reg_file("HKEY_CURRENT_USER\\Software\\MyApp")?;
+	///
+	/// let fout = w::File::open("C:\\Temp\\my_app.reg", w::FileAccess::OpenOrCreateRW)?;
+	/// fout.erase_and_write(&reg.serialize())?;
+	/// # w::SysResult::Ok(())
+	/// ```
+	fn export_reg_file(&self, key_path: &str) -> SysResult<RegFile> {
+		let mut keys = Vec::<RegFileKey>::new();
+		export_key(self, key_path, &mut keys)?;
+		Ok(RegFile { keys })
+	}
+
 	/// [`RegConnectRegistry`](https://learn.microsoft.com/en-us/windows/win32/api/winreg/nf-winreg-regconnectregistryw)
 	/// function.
 	///
@@ -695,53 +789,8 @@ pub trait advapi_Hkey: Handle {
 		value_name: Option<&str>,
 	) -> SysResult<RegistryValue>
 	{
-		let value_name_w = WString::from_opt_str(value_name);
-		let mut buf = Vec::<u8>::default();
-
-		loop {
-			let mut data_len = u32::default(); // in bytes
-
-			match unsafe {
-				co::ERROR::from_raw(
-					ffi::RegQueryValueExW(
-						self.ptr(),
-						value_name_w.as_ptr(),
-						std::ptr::null_mut(),
-						std::ptr::null_mut(),
-						std::ptr::null_mut(),
-						&mut data_len, // first call to retrieve size only
-					) as _,
-				)
-			} {
-				co::ERROR::SUCCESS => {},
-				e => return Err(e),
-			}
-
-			buf.resize(data_len as _, 0x00);
-			let mut data_type = u32::default();
-
-			match unsafe {
-				co::ERROR::from_raw(
-					ffi::RegQueryValueExW(
-						self.ptr(),
-						value_name_w.as_ptr(),
-						std::ptr::null_mut(),
-						&mut data_type,
-						buf.as_mut_ptr() as _,
-						&mut data_len,
-					) as _,
-				)
-			} {
-				co::ERROR::SUCCESS => {
-					buf.resize(data_len as _, 0x00); // data length may have shrunk
-					return unsafe {
-						RegistryValue::from_raw(buf, co::REG::from_raw(data_type))
-					}
-				},
-				co::ERROR::MORE_DATA => continue, // value changed in a concurrent operation; retry
-				e => return Err(e),
-			}
-		}
+		let (reg_type, buf) = query_value_raw(self, value_name)?;
+		unsafe { RegistryValue::from_raw(buf, reg_type) }
 	}
 
 	/// [`RegRenameKey`](https://learn.microsoft.com/en-us/windows/win32/api/winreg/nf-winreg-regrenamekey)
@@ -946,3 +995,82 @@ impl HKEY {
 			&& (self.0 as usize) <= (Self::PERFORMANCE_NLSTEXT.0 as usize)
 	}
 }
+
+/// Reads the type and the raw bytes of a value, with
+/// [`RegQueryValueEx`](https://learn.microsoft.com/en-us/windows/win32/api/winreg/nf-winreg-regqueryvalueexw),
+/// regardless of the type being supported by
+/// [`RegistryValue`](crate::RegistryValue).
+pub(in crate::advapi) fn query_value_raw(
+	hkey: &impl advapi_Hkey,
+	value_name: Option<&str>,
+) -> SysResult<(co::REG, Vec<u8>)>
+{
+	let value_name_w = WString::from_opt_str(value_name);
+	let mut buf = Vec::<u8>::default();
+
+	loop {
+		let mut data_len = u32::default(); // in bytes
+
+		match unsafe {
+			co::ERROR::from_raw(
+				ffi::RegQueryValueExW(
+					hkey.ptr(),
+					value_name_w.as_ptr(),
+					std::ptr::null_mut(),
+					std::ptr::null_mut(),
+					std::ptr::null_mut(),
+					&mut data_len, // first call to retrieve size only
+				) as _,
+			)
+		} {
+			co::ERROR::SUCCESS => {},
+			e => return Err(e),
+		}
+
+		buf.resize(data_len as _, 0x00);
+		let mut data_type = u32::default();
+
+		match unsafe {
+			co::ERROR::from_raw(
+				ffi::RegQueryValueExW(
+					hkey.ptr(),
+					value_name_w.as_ptr(),
+					std::ptr::null_mut(),
+					&mut data_type,
+					buf.as_mut_ptr() as _,
+					&mut data_len,
+				) as _,
+			)
+		} {
+			co::ERROR::SUCCESS => {
+				buf.resize(data_len as _, 0x00); // data length may have shrunk
+				return Ok((unsafe { co::REG::from_raw(data_type) }, buf));
+			},
+			co::ERROR::MORE_DATA => continue, // value changed in a concurrent operation; retry
+			e => return Err(e),
+		}
+	}
+}
+
+/// Writes a value of any type from its raw bytes, with
+/// [`RegSetValueEx`](https://learn.microsoft.com/en-us/windows/win32/api/winreg/nf-winreg-regsetvalueexw).
+fn set_value_raw(
+	hkey: &impl advapi_Hkey,
+	value_name: Option<&str>,
+	reg_type: co::REG,
+	data: &[u8],
+) -> SysResult<()>
+{
+	error_to_sysresult(
+		unsafe {
+			ffi::RegSetValueExW(
+				hkey.ptr(),
+				WString::from_opt_str(value_name).as_ptr(),
+				0,
+				reg_type.raw(),
+				data.as_ptr() as _,
+				data.len() as _,
+			)
+		},
+	)
+}
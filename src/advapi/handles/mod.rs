@@ -8,6 +8,8 @@ mod hservicestatus;
 mod hthread;
 mod htransaction;
 
+pub(in crate::advapi) use hkey::query_value_raw;
+
 pub mod decl {
 	pub use super::haccesstoken::HACCESSTOKEN;
 	pub use super::heventlog::HEVENTLOG;
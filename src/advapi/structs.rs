@@ -3,6 +3,7 @@
 use std::marker::PhantomData;
 
 use crate::advapi::privs::*;
+use crate::advapi::utilities::*;
 use crate::co;
 use crate::decl::*;
 use crate::guard::*;
@@ -98,6 +99,47 @@ pub struct DEV_BROADCAST_VOLUME {
 	pub dbcv_flags: co::DBTF,
 }
 
+/// [`GENERIC_MAPPING`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-generic_mapping)
+/// struct.
+#[repr(C)]
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct GENERIC_MAPPING {
+	pub GenericRead: u32,
+	pub GenericWrite: u32,
+	pub GenericExecute: u32,
+	pub GenericAll: u32,
+}
+
+impl GENERIC_MAPPING {
+	/// Generic mapping of files and directories.
+	pub const FILE: Self = Self {
+		GenericRead: 0x0012_0089,
+		GenericWrite: 0x0012_0116,
+		GenericExecute: 0x0012_00a0,
+		GenericAll: 0x001f_01ff,
+	};
+
+	/// Generic mapping of registry keys.
+	pub const KEY: Self = Self {
+		GenericRead: 0x0002_0019,
+		GenericWrite: 0x0002_0006,
+		GenericExecute: 0x0002_0019,
+		GenericAll: 0x000f_003f,
+	};
+
+	/// Replaces the generic rights in the mask with the specific ones, like
+	/// [`MapGenericMask`](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-mapgenericmask).
+	#[must_use]
+	pub const fn map(&self, mask: u32) -> u32 {
+		let mut mapped = mask & 0x0fff_ffff;
+		if mask & co::GENERIC::READ.raw() != 0 { mapped |= self.GenericRead; }
+		if mask & co::GENERIC::WRITE.raw() != 0 { mapped |= self.GenericWrite; }
+		if mask & co::GENERIC::EXECUTE.raw() != 0 { mapped |= self.GenericExecute; }
+		if mask & co::GENERIC::ALL.raw() != 0 { mapped |= self.GenericAll; }
+		mapped
+	}
+}
+
 /// [`LUID_AND_ATTRIBUTES`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-luid_and_attributes)
 /// struct.
 #[repr(C)]
@@ -195,16 +237,15 @@ impl VariableSized for SID {}
 
 impl std::fmt::Display for SID {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-		match ConvertSidToStringSid(self) {
-			Ok(name) => write!(f, "{}", name),
-			Err(err) => write!(f, "{}", err),
-		}
+		fmt_sid(f, self.Revision, &self.IdentifierAuthority, self.SubAuthority())
 	}
 }
 
 impl PartialEq<SID> for SID {
 	fn eq(&self, other: &SID) -> bool {
-		EqualSid(self, other).unwrap() // assumes valid references
+		self.Revision == other.Revision
+			&& self.IdentifierAuthority == other.IdentifierAuthority
+			&& self.SubAuthority() == other.SubAuthority()
 	}
 }
 
@@ -0,0 +1,568 @@
+use crate::co;
+use crate::decl::*;
+use crate::prelude::*;
+
+/// `ACCESS_SYSTEM_SECURITY` right, granted only by the security privilege.
+const ACCESS_SYSTEM_SECURITY: u32 = 0x0100_0000;
+/// `MAXIMUM_ALLOWED` pseudo-right.
+const MAXIMUM_ALLOWED: u32 = 0x0200_0000;
+/// `READ_CONTROL` right, implicitly granted to the owner.
+const READ_CONTROL: u32 = 0x0002_0000;
+/// `WRITE_DAC` right, implicitly granted to the owner.
+const WRITE_DAC: u32 = 0x0004_0000;
+/// `WRITE_OWNER` right, granted by the take ownership privilege.
+const WRITE_OWNER: u32 = 0x0008_0000;
+/// `SYNCHRONIZE` right.
+const SYNCHRONIZE: u32 = 0x0010_0000;
+
+/// RID of the medium integrity level, the default label of objects.
+const MEDIUM_INTEGRITY: u32 = 0x2000;
+
+/// The security context evaluated by
+/// [`SecurityDescriptor::access_check`](crate::SecurityDescriptor::access_check),
+/// which can be built by hand or read from an access token.
+///
+/// Only the groups with
+/// [`SE_GROUP::ENABLED`](crate::co::SE_GROUP::ENABLED) match allow ACEs; groups
+/// with [`SE_GROUP::USE_FOR_DENY_ONLY`](crate::co::SE_GROUP::USE_FOR_DENY_ONLY)
+/// match deny ACEs only.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let user: w::SidBuf = "S-1-5-21-1-2-3-1001".parse()?;
+/// let mut subject = w::AccessSubject::new(&user);
+/// subject.groups.push((
+///     w::SidBuf::well_known(co::WELL_KNOWN_SID_TYPE::BuiltinUsers, None)?,
+///     co::SE_GROUP::ENABLED,
+/// ));
+/// # w::SysResult::Ok(())
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct AccessSubject {
+	pub user: SidBuf,
+	pub groups: Vec<(SidBuf, co::SE_GROUP)>,
+	/// Enabled privileges.
+	pub privileges: Vec<co::SE_PRIV>,
+	/// Integrity level SID, like
+	/// [`WELL_KNOWN_SID_TYPE::MediumLabel`](crate::co::WELL_KNOWN_SID_TYPE::MediumLabel).
+	/// If `None`, the mandatory integrity check is skipped.
+	pub integrity_level: Option<SidBuf>,
+	pub mandatory_policy: co::TOKEN_MANDATORY_POLICY,
+}
+
+impl AccessSubject {
+	/// Creates a new subject with the given user, no groups and no privileges,
+	/// at medium integrity level, subject to the no-write-up policy.
+	#[must_use]
+	pub fn new(user: &SID) -> Self {
+		Self {
+			user: SidBuf::from_sid(user),
+			groups: Vec::new(),
+			privileges: Vec::new(),
+			integrity_level: Some(
+				SidBuf::well_known(co::WELL_KNOWN_SID_TYPE::MediumLabel, None).unwrap()),
+			mandatory_policy: co::TOKEN_MANDATORY_POLICY::NO_WRITE_UP,
+		}
+	}
+
+	/// Reads the user, groups, enabled privileges, integrity level and
+	/// mandatory policy of an access token, which must have been opened with
+	/// [`TOKEN::QUERY`](crate::co::TOKEN::QUERY).
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let htoken = w::HPROCESS::GetCurrentProcess()
+	///     .OpenProcessToken(co::TOKEN::QUERY)?;
+	/// let subject = w::AccessSubject::from_token(&htoken)?;
+	/// # w::SysResult::Ok(())
+	/// ```
+	pub fn from_token(htoken: &HACCESSTOKEN) -> SysResult<Self> {
+		let user = match htoken.GetTokenInformation(co::TOKEN_INFORMATION_CLASS::User)? {
+			TokenInfo::User(user) => SidBuf::from_sid(
+				user.User.Sid().ok_or(co::ERROR::INVALID_SID)?),
+			_ => return Err(co::ERROR::INVALID_PARAMETER), // should never happen
+		};
+		let mut subject = Self::new(&user);
+
+		if let TokenInfo::Groups(groups) =
+			htoken.GetTokenInformation(co::TOKEN_INFORMATION_CLASS::Groups)?
+		{
+			subject.groups = groups.Groups().iter()
+				.filter_map(|g| g.Sid().map(|sid| (
+					SidBuf::from_sid(sid),
+					unsafe { co::SE_GROUP::from_raw(g.Attributes) },
+				)))
+				.collect();
+		}
+
+		if let TokenInfo::Privileges(privs) =
+			htoken.GetTokenInformation(co::TOKEN_INFORMATION_CLASS::Privileges)?
+		{
+			subject.privileges = privs.Privileges().iter()
+				.filter(|p| p.Attributes.has(co::SE_PRIV_ATTR::ENABLED))
+				.filter_map(|p| LookupPrivilegeName(None, p.Luid).ok()) // unknown privileges are irrelevant
+				.collect();
+		}
+
+		if let TokenInfo::IntegrityLevel(label) =
+			htoken.GetTokenInformation(co::TOKEN_INFORMATION_CLASS::IntegrityLevel)?
+		{
+			subject.integrity_level = label.Label.Sid().map(|sid| SidBuf::from_sid(sid));
+		}
+
+		if let TokenInfo::MandatoryPolicy(policy) =
+			htoken.GetTokenInformation(co::TOKEN_INFORMATION_CLASS::MandatoryPolicy)?
+		{
+			subject.mandatory_policy = policy.Policy;
+		}
+
+		Ok(subject)
+	}
+
+	/// Tells whether the privilege is enabled.
+	#[must_use]
+	pub fn has_privilege(&self, privilege: co::SE_PRIV) -> bool {
+		self.privileges.contains(&privilege)
+	}
+
+	/// Tells whether the SID is the user, or a group which matches allow ACEs
+	/// or, if `for_deny` is set, also deny ACEs.
+	fn matches(&self, sid: &SID, for_deny: bool) -> bool {
+		*self.user == *sid
+			|| self.groups.iter().any(|(group, attrs)| **group == *sid
+				&& (attrs.has(co::SE_GROUP::ENABLED)
+					|| (for_deny && attrs.has(co::SE_GROUP::USE_FOR_DENY_ONLY))))
+	}
+}
+
+/// Why an access right was granted or denied by
+/// [`SecurityDescriptor::access_check`](crate::SecurityDescriptor::access_check).
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum AccessReason {
+	/// An ACE of the DACL, by its index, allowed or denied the right.
+	Ace(usize),
+	/// The subject is the owner, which is implicitly allowed to read and
+	/// change the DACL.
+	Owner,
+	/// The privilege granted the right.
+	Privilege(co::SE_PRIV),
+	/// The right requires a privilege the subject doesn't have enabled.
+	MissingPrivilege(co::SE_PRIV),
+	/// The DACL is NULL or absent, so everyone has full access.
+	NullDacl,
+	/// The integrity level of the subject is below the mandatory label of the
+	/// object, whose policy denied the right.
+	MandatoryLabel,
+	/// No ACE allowed the right.
+	NotGranted,
+}
+
+/// Decision about a single access right, as returned by
+/// [`AccessCheckResult::decisions`](crate::AccessCheckResult::decisions).
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct AccessDecision {
+	/// The access right, a single bit.
+	pub right: u32,
+	pub granted: bool,
+	pub reason: AccessReason,
+}
+
+/// Result of
+/// [`SecurityDescriptor::access_check`](crate::SecurityDescriptor::access_check).
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct AccessCheckResult {
+	/// Desired access, with the generic rights mapped.
+	pub desired_access: u32,
+	/// Rights which were granted; if `MAXIMUM_ALLOWED` was requested, all
+	/// rights the subject has.
+	pub granted_access: u32,
+	/// Decision of each desired right, ordered by bit.
+	pub decisions: Vec<AccessDecision>,
+}
+
+impl AccessCheckResult {
+	/// Tells whether all the desired rights were granted, which is the
+	/// condition for the native
+	/// [`AccessCheck`](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-accesscheck)
+	/// to succeed.
+	#[must_use]
+	pub fn is_granted(&self) -> bool {
+		self.decisions.iter().all(|d| d.granted)
+	}
+
+	/// Returns the desired rights which were denied.
+	#[must_use]
+	pub fn denied_access(&self) -> u32 {
+		self.decisions.iter()
+			.filter(|d| !d.granted)
+			.fold(0, |acc, d| acc | d.right)
+	}
+
+	/// Returns the decision about the given right, which must be a single bit.
+	#[must_use]
+	pub fn decision(&self, right: u32) -> Option<&AccessDecision> {
+		self.decisions.iter().find(|d| d.right == right)
+	}
+}
+
+/// Returns the RID of an integrity level SID.
+fn integrity_rid(sid: &SID) -> u32 {
+	sid.SubAuthority().last().map_or(0, |rid| rid.raw())
+}
+
+impl SecurityDescriptor {
+	/// Evaluates the access the subject has to an object protected by this
+	/// security descriptor, reproducing the native
+	/// [`AccessCheck`](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-accesscheck)
+	/// in pure Rust, and explaining the decision of each right.
+	///
+	/// The evaluation follows the native order:
+	///
+	/// 1. the generic rights of the desired access and of the ACEs are mapped
+	///    with `generic_mapping`;
+	/// 2. the mandatory label of the SACL – medium with no-write-up if absent –
+	///    denies the rights of its policy if the subject's integrity level is
+	///    lower; `READ_CONTROL` and `SYNCHRONIZE` are never denied this way;
+	/// 3. `ACCESS_SYSTEM_SECURITY` requires the security privilege, and
+	///    `WRITE_OWNER` is granted by the take ownership privilege;
+	/// 4. the owner is granted `READ_CONTROL` and `WRITE_DAC`, unless the
+	///    DACL has an `OWNER RIGHTS` ACE;
+	/// 5. a NULL or absent DACL grants everything else;
+	/// 6. the ACEs of the DACL are evaluated in order, skipping inherit-only
+	///    ones; the first ACE to allow or deny a right decides it;
+	/// 7. the rights left undecided are denied.
+	///
+	/// Object ACEs are evaluated only if they have no object type, and
+	/// callback ACEs are skipped. Backup and restore privileges are not
+	/// considered, since they require a backup intent.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let sd: w::SecurityDescriptor = "O:BAD:(A;;KR;;;BU)(A;;KA;;;BA)".parse()?;
+	///
+	/// let user: w::SidBuf = "S-1-5-21-1-2-3-1001".parse()?;
+	/// let mut subject = w::AccessSubject::new(&user);
+	/// subject.groups.push((
+	///     w::SidBuf::well_known(co::WELL_KNOWN_SID_TYPE::BuiltinUsers, None)?,
+	///     co::SE_GROUP::ENABLED,
+	/// ));
+	///
+	/// let res = sd.access_check(&subject, co::KEY::WRITE.raw(), &w::GENERIC_MAPPING::KEY);
+	/// if !res.is_granted() {
+	///     for d in res.decisions.iter().filter(|d| !d.granted) {
+	///         println!("0x{:08x} denied: {:?}", d.right, d.reason);
+	///     }
+	/// }
+	/// # w::SysResult::Ok(())
+	/// ```
+	#[must_use]
+	pub fn access_check(&self,
+		subject: &AccessSubject,
+		desired_access: u32,
+		generic_mapping: &GENERIC_MAPPING,
+	) -> AccessCheckResult
+	{
+		let maximum_allowed = desired_access & MAXIMUM_ALLOWED != 0;
+		let desired = generic_mapping.map(desired_access & !MAXIMUM_ALLOWED);
+		let candidates = if maximum_allowed { generic_mapping.GenericAll | desired } else { desired };
+
+		let mut decided: Vec<AccessDecision> = Vec::new();
+		let mut pending = candidates;
+		let mut decide = |bits: u32, granted: bool, reason: AccessReason, pending: &mut u32| {
+			let bits = bits & *pending;
+			(0..32).map(|n| 1u32 << n)
+				.filter(|bit| bits & bit != 0)
+				.for_each(|right| decided.push(AccessDecision { right, granted, reason }));
+			*pending &= !bits;
+		};
+
+		// Mandatory integrity check.
+		if let Some(level) = &subject.integrity_level {
+			let (label_rid, policy) = self.sacl.as_ref()
+				.and_then(|sacl| sacl.aces.iter().find(|ace|
+					ace.ace_type == co::ACE_TYPE::SYSTEM_MANDATORY_LABEL
+						&& !ace.flags.has(co::ACE::INHERIT_ONLY)))
+				.map_or(
+					(MEDIUM_INTEGRITY, co::SYSTEM_MANDATORY_LABEL::NO_WRITE_UP.raw()),
+					|ace| (integrity_rid(&ace.sid), ace.mask),
+				);
+
+			if integrity_rid(level) < label_rid {
+				let mut blocked = 0;
+				if policy & co::SYSTEM_MANDATORY_LABEL::NO_WRITE_UP.raw() != 0
+					&& subject.mandatory_policy.raw() & co::TOKEN_MANDATORY_POLICY::NO_WRITE_UP.raw() != 0
+				{
+					blocked |= generic_mapping.GenericWrite;
+				}
+				if policy & co::SYSTEM_MANDATORY_LABEL::NO_READ_UP.raw() != 0 {
+					blocked |= generic_mapping.GenericRead;
+				}
+				if policy & co::SYSTEM_MANDATORY_LABEL::NO_EXECUTE_UP.raw() != 0 {
+					blocked |= generic_mapping.GenericExecute;
+				}
+				blocked &= !(READ_CONTROL | SYNCHRONIZE);
+				decide(blocked, false, AccessReason::MandatoryLabel, &mut pending);
+			}
+		}
+
+		// Privileges.
+		if subject.has_privilege(co::SE_PRIV::SECURITY_NAME) {
+			decide(ACCESS_SYSTEM_SECURITY, true,
+				AccessReason::Privilege(co::SE_PRIV::SECURITY_NAME), &mut pending);
+		} else {
+			decide(ACCESS_SYSTEM_SECURITY, false,
+				AccessReason::MissingPrivilege(co::SE_PRIV::SECURITY_NAME), &mut pending);
+		}
+		if subject.has_privilege(co::SE_PRIV::TAKE_OWNERSHIP_NAME) {
+			decide(WRITE_OWNER, true,
+				AccessReason::Privilege(co::SE_PRIV::TAKE_OWNERSHIP_NAME), &mut pending);
+		}
+
+		// Implicit owner rights.
+		let owner_rights = SidBuf::well_known(co::WELL_KNOWN_SID_TYPE::CreatorOwnerRights, None)
+			.unwrap();
+		let is_owner = self.owner.as_ref().is_some_and(|owner| subject.matches(owner, false));
+		let has_owner_rights_ace = self.dacl.as_ref()
+			.is_some_and(|dacl| dacl.aces.iter().any(|ace| ace.sid == owner_rights));
+		if is_owner && !has_owner_rights_ace {
+			decide(READ_CONTROL | WRITE_DAC, true, AccessReason::Owner, &mut pending);
+		}
+
+		// DACL.
+		match &self.dacl {
+			None => decide(pending, true, AccessReason::NullDacl, &mut pending),
+			Some(dacl) => {
+				for (idx, ace) in dacl.aces.iter().enumerate() {
+					if pending == 0 {
+						break;
+					}
+					if ace.flags.has(co::ACE::INHERIT_ONLY) || ace.object_type.is_some() {
+						continue;
+					}
+					let allow = match ace.ace_type {
+						co::ACE_TYPE::ACCESS_ALLOWED | co::ACE_TYPE::ACCESS_ALLOWED_OBJECT => true,
+						co::ACE_TYPE::ACCESS_DENIED | co::ACE_TYPE::ACCESS_DENIED_OBJECT => false,
+						_ => continue,
+					};
+					let applies = subject.matches(&ace.sid, !allow)
+						|| (is_owner && ace.sid == owner_rights);
+					if applies {
+						decide(generic_mapping.map(ace.mask), allow,
+							AccessReason::Ace(idx), &mut pending);
+					}
+				}
+			},
+		}
+		decide(pending, false, AccessReason::NotGranted, &mut pending);
+
+		let granted_access = decided.iter()
+			.filter(|d| d.granted)
+			.fold(0, |acc, d| acc | d.right);
+		let mut decisions = decided.into_iter()
+			.filter(|d| d.right & desired != 0) // with MAXIMUM_ALLOWED, only report the desired ones
+			.collect::<Vec<_>>();
+		decisions.sort_by_key(|d| d.right);
+
+		AccessCheckResult { desired_access: desired, granted_access, decisions }
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	const USER: &str = "S-1-5-21-1-2-3-1001";
+	const KEY_READ: u32 = 0x0002_0019;
+	const KEY_SET_VALUE: u32 = 0x0000_0002;
+
+	fn sd(sddl: &str) -> SecurityDescriptor {
+		sddl.parse().unwrap()
+	}
+
+	/// A medium integrity subject which is a member of Users.
+	fn user_subject() -> AccessSubject {
+		let mut subject = AccessSubject::new(&SidBuf::parse(USER).unwrap());
+		subject.groups.push((
+			SidBuf::well_known(co::WELL_KNOWN_SID_TYPE::BuiltinUsers, None).unwrap(),
+			co::SE_GROUP::ENABLED,
+		));
+		subject
+	}
+
+	fn reason(res: &AccessCheckResult, right: u32) -> (bool, AccessReason) {
+		let d = res.decision(right).unwrap();
+		(d.granted, d.reason)
+	}
+
+	#[test]
+	fn allow() {
+		let res = sd("O:BAD:(A;;KW;;;BA)(A;;KR;;;BU)")
+			.access_check(&user_subject(), KEY_READ, &GENERIC_MAPPING::KEY);
+		assert!(res.is_granted());
+		assert_eq!(res.granted_access, KEY_READ);
+		assert_eq!(reason(&res, READ_CONTROL), (true, AccessReason::Ace(1)));
+		assert_eq!(res.decisions.len(), 4);
+
+		let res = sd("O:BAD:(A;;KR;;;BU)")
+			.access_check(&user_subject(), KEY_SET_VALUE, &GENERIC_MAPPING::KEY);
+		assert!(!res.is_granted());
+		assert_eq!(res.denied_access(), KEY_SET_VALUE);
+		assert_eq!(reason(&res, KEY_SET_VALUE), (false, AccessReason::NotGranted));
+	}
+
+	#[test]
+	fn allow_generic() {
+		let res = sd("O:BAD:(A;;GR;;;BU)")
+			.access_check(&user_subject(), co::GENERIC::READ.raw(), &GENERIC_MAPPING::KEY);
+		assert!(res.is_granted());
+		assert_eq!(res.desired_access, KEY_READ);
+		assert_eq!(reason(&res, READ_CONTROL), (true, AccessReason::Ace(0)));
+	}
+
+	#[test]
+	fn deny() {
+		let res = sd("O:BAD:(D;;KW;;;BU)(A;;KA;;;BU)")
+			.access_check(&user_subject(), KEY_READ | KEY_SET_VALUE, &GENERIC_MAPPING::KEY);
+		assert!(!res.is_granted());
+		assert_eq!(reason(&res, KEY_SET_VALUE), (false, AccessReason::Ace(0)));
+		assert_eq!(reason(&res, READ_CONTROL), (false, AccessReason::Ace(0))); // also in KW
+		assert_eq!(reason(&res, 0x0000_0001), (true, AccessReason::Ace(1))); // KEY_QUERY_VALUE
+		assert_eq!(res.denied_access(), KEY_SET_VALUE | READ_CONTROL);
+	}
+
+	#[test]
+	fn deny_after_allow() {
+		let res = sd("O:BAD:(A;;KA;;;BU)(D;;KA;;;BU)") // non-canonical order: first ACE wins
+			.access_check(&user_subject(), KEY_SET_VALUE, &GENERIC_MAPPING::KEY);
+		assert_eq!(reason(&res, KEY_SET_VALUE), (true, AccessReason::Ace(0)));
+	}
+
+	#[test]
+	fn deny_only_group() {
+		let mut subject = user_subject();
+		subject.groups[0].1 = co::SE_GROUP::USE_FOR_DENY_ONLY;
+
+		let res = sd("O:BAD:(A;;KR;;;BU)")
+			.access_check(&subject, KEY_READ, &GENERIC_MAPPING::KEY);
+		assert_eq!(reason(&res, READ_CONTROL), (false, AccessReason::NotGranted));
+
+		let res = sd("O:BAD:(D;;KR;;;BU)(A;;KR;;;S-1-5-21-1-2-3-1001)")
+			.access_check(&subject, KEY_READ, &GENERIC_MAPPING::KEY);
+		assert_eq!(reason(&res, READ_CONTROL), (false, AccessReason::Ace(0)));
+	}
+
+	#[test]
+	fn inherit_only_skipped() {
+		let res = sd("O:BAD:(D;IO;KA;;;BU)(A;;KR;;;BU)")
+			.access_check(&user_subject(), KEY_READ, &GENERIC_MAPPING::KEY);
+		assert!(res.is_granted());
+		assert_eq!(reason(&res, READ_CONTROL), (true, AccessReason::Ace(1)));
+	}
+
+	#[test]
+	fn owner() {
+		let res = sd(&format!("O:{}D:", USER))
+			.access_check(&user_subject(), READ_CONTROL | WRITE_DAC | KEY_SET_VALUE,
+				&GENERIC_MAPPING::KEY);
+		assert_eq!(reason(&res, READ_CONTROL), (true, AccessReason::Owner));
+		assert_eq!(reason(&res, WRITE_DAC), (true, AccessReason::Owner));
+		assert_eq!(reason(&res, KEY_SET_VALUE), (false, AccessReason::NotGranted));
+
+		let res = sd("O:BUD:") // owner through an enabled group
+			.access_check(&user_subject(), WRITE_DAC, &GENERIC_MAPPING::KEY);
+		assert_eq!(reason(&res, WRITE_DAC), (true, AccessReason::Owner));
+
+		let res = sd("O:BAD:") // not the owner
+			.access_check(&user_subject(), WRITE_DAC, &GENERIC_MAPPING::KEY);
+		assert_eq!(reason(&res, WRITE_DAC), (false, AccessReason::NotGranted));
+	}
+
+	#[test]
+	fn owner_rights_ace() {
+		let res = sd(&format!("O:{}D:(A;;RC;;;OW)", USER))
+			.access_check(&user_subject(), READ_CONTROL | WRITE_DAC, &GENERIC_MAPPING::KEY);
+		assert_eq!(reason(&res, READ_CONTROL), (true, AccessReason::Ace(0)));
+		assert_eq!(reason(&res, WRITE_DAC), (false, AccessReason::NotGranted));
+	}
+
+	#[test]
+	fn privileges() {
+		let mut subject = user_subject();
+		let dacl = sd("O:BAD:(A;;KA;;;BU)");
+
+		let res = dacl.access_check(&subject, ACCESS_SYSTEM_SECURITY | WRITE_OWNER,
+			&GENERIC_MAPPING::KEY);
+		assert_eq!(reason(&res, ACCESS_SYSTEM_SECURITY),
+			(false, AccessReason::MissingPrivilege(co::SE_PRIV::SECURITY_NAME)));
+		assert_eq!(reason(&res, WRITE_OWNER), (true, AccessReason::Ace(0)));
+
+		subject.privileges.push(co::SE_PRIV::SECURITY_NAME);
+		subject.privileges.push(co::SE_PRIV::TAKE_OWNERSHIP_NAME);
+		let res = sd("O:BAD:(D;;KA;;;BU)").access_check(&subject,
+			ACCESS_SYSTEM_SECURITY | WRITE_OWNER, &GENERIC_MAPPING::KEY);
+		assert!(res.is_granted());
+		assert_eq!(reason(&res, ACCESS_SYSTEM_SECURITY),
+			(true, AccessReason::Privilege(co::SE_PRIV::SECURITY_NAME)));
+		assert_eq!(reason(&res, WRITE_OWNER),
+			(true, AccessReason::Privilege(co::SE_PRIV::TAKE_OWNERSHIP_NAME)));
+	}
+
+	#[test]
+	fn null_dacl() {
+		let res = sd("O:BA")
+			.access_check(&user_subject(), KEY_READ | KEY_SET_VALUE, &GENERIC_MAPPING::KEY);
+		assert!(res.is_granted());
+		assert_eq!(reason(&res, KEY_SET_VALUE), (true, AccessReason::NullDacl));
+
+		let res = sd("O:BA") // still requires the privilege
+			.access_check(&user_subject(), ACCESS_SYSTEM_SECURITY, &GENERIC_MAPPING::KEY);
+		assert!(!res.is_granted());
+	}
+
+	#[test]
+	fn empty_dacl() {
+		let res = sd("O:BAD:")
+			.access_check(&user_subject(), KEY_READ, &GENERIC_MAPPING::KEY);
+		assert!(!res.is_granted());
+		assert_eq!(res.granted_access, 0);
+	}
+
+	#[test]
+	fn mandatory_label() {
+		let mut subject = user_subject();
+		subject.integrity_level = Some(
+			SidBuf::well_known(co::WELL_KNOWN_SID_TYPE::LowLabel, None).unwrap());
+
+		let res = sd("O:BAD:(A;;KA;;;BU)") // default medium no-write-up label
+			.access_check(&subject, KEY_READ | KEY_SET_VALUE, &GENERIC_MAPPING::KEY);
+		assert_eq!(reason(&res, KEY_SET_VALUE), (false, AccessReason::MandatoryLabel));
+		assert_eq!(reason(&res, READ_CONTROL), (true, AccessReason::Ace(0)));
+
+		let res = sd("O:BAD:(A;;KA;;;BU)S:(ML;;NW;;;LW)") // labeled low
+			.access_check(&subject, KEY_SET_VALUE, &GENERIC_MAPPING::KEY);
+		assert!(res.is_granted());
+
+		subject.integrity_level = None; // check skipped
+		let res = sd("O:BAD:(A;;KA;;;BU)")
+			.access_check(&subject, KEY_SET_VALUE, &GENERIC_MAPPING::KEY);
+		assert!(res.is_granted());
+	}
+
+	#[test]
+	fn maximum_allowed() {
+		let res = sd("O:BAD:(A;;KR;;;BU)")
+			.access_check(&user_subject(), MAXIMUM_ALLOWED, &GENERIC_MAPPING::KEY);
+		assert!(res.is_granted());
+		assert!(res.decisions.is_empty());
+		assert_eq!(res.granted_access, KEY_READ);
+	}
+}
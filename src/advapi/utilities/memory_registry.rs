@@ -0,0 +1,544 @@
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+use crate::co;
+use crate::decl::*;
+use crate::prelude::*;
+
+/// A key of the in-memory tree.
+#[derive(Clone, Default)]
+struct MemKey {
+	name: String,
+	sub_keys: BTreeMap<String, MemKey>, // keyed by uppercase name, since names are case-insensitive
+	values: Vec<(String, RegistryValue)>, // in creation order
+}
+
+impl MemKey {
+	fn value_pos(&self, value_name: Option<&str>) -> Option<usize> {
+		let value_name = value_name.unwrap_or("").to_uppercase();
+		self.values.iter()
+			.position(|(name, _)| name.to_uppercase() == value_name)
+	}
+}
+
+/// The predefined root keys and their subtrees.
+#[derive(Clone)]
+struct MemTree {
+	roots: BTreeMap<usize, MemKey>, // keyed by the HKEY pointer
+}
+
+impl MemTree {
+	fn new() -> Self {
+		let roots = [
+			HKEY::CLASSES_ROOT,
+			HKEY::CURRENT_USER,
+			HKEY::LOCAL_MACHINE,
+			HKEY::USERS,
+			HKEY::CURRENT_CONFIG,
+		];
+		Self {
+			roots: roots.iter()
+				.map(|hkey| (hkey.ptr() as usize, MemKey::default()))
+				.collect(),
+		}
+	}
+
+	fn components(sub_key: &str) -> impl Iterator<Item = &str> {
+		sub_key.split('\\').filter(|name| !name.is_empty())
+	}
+
+	fn find(&self, root: &HKEY, sub_key: &str) -> SysResult<&MemKey> {
+		let mut key = self.roots.get(&(root.ptr() as usize))
+			.ok_or(co::ERROR::INVALID_HANDLE)?;
+		for name in Self::components(sub_key) {
+			key = key.sub_keys.get(&name.to_uppercase())
+				.ok_or(co::ERROR::FILE_NOT_FOUND)?;
+		}
+		Ok(key)
+	}
+
+	fn find_mut(&mut self, root: &HKEY, sub_key: &str) -> SysResult<&mut MemKey> {
+		let mut key = self.roots.get_mut(&(root.ptr() as usize))
+			.ok_or(co::ERROR::INVALID_HANDLE)?;
+		for name in Self::components(sub_key) {
+			key = key.sub_keys.get_mut(&name.to_uppercase())
+				.ok_or(co::ERROR::FILE_NOT_FOUND)?;
+		}
+		Ok(key)
+	}
+
+	fn create_key(&mut self, root: &HKEY, sub_key: &str) -> SysResult<co::REG_DISPOSITION> {
+		let mut key = self.roots.get_mut(&(root.ptr() as usize))
+			.ok_or(co::ERROR::INVALID_HANDLE)?;
+		let mut disposition = co::REG_DISPOSITION::OPENED_EXISTING_KEY;
+		for name in Self::components(sub_key) {
+			key = key.sub_keys.entry(name.to_uppercase())
+				.or_insert_with(|| {
+					disposition = co::REG_DISPOSITION::CREATED_NEW_KEY;
+					MemKey { name: name.to_owned(), ..Default::default() }
+				});
+		}
+		Ok(disposition)
+	}
+
+	fn key_exists(&self, root: &HKEY, sub_key: &str) -> SysResult<bool> {
+		match self.find(root, sub_key) {
+			Ok(_) => Ok(true),
+			Err(co::ERROR::FILE_NOT_FOUND) => Ok(false),
+			Err(e) => Err(e),
+		}
+	}
+
+	fn enum_keys(&self, root: &HKEY, sub_key: &str) -> SysResult<Vec<String>> {
+		Ok(self.find(root, sub_key)?
+			.sub_keys.values()
+			.map(|key| key.name.clone())
+			.collect())
+	}
+
+	fn enum_values(&self, root: &HKEY, sub_key: &str) -> SysResult<Vec<(String, co::REG)>> {
+		Ok(self.find(root, sub_key)?
+			.values.iter()
+			.map(|(name, data)| (name.clone(), data.reg_type()))
+			.collect())
+	}
+
+	fn query_value(&self,
+		root: &HKEY,
+		sub_key: &str,
+		value_name: Option<&str>,
+	) -> SysResult<RegistryValue>
+	{
+		let key = self.find(root, sub_key)?;
+		key.value_pos(value_name)
+			.map(|pos| key.values[pos].1.clone())
+			.ok_or(co::ERROR::FILE_NOT_FOUND)
+	}
+
+	/// Returns whether the tree changed, since the value may already have the
+	/// same data.
+	fn set_value(&mut self,
+		root: &HKEY,
+		sub_key: &str,
+		value_name: Option<&str>,
+		data: RegistryValue,
+	) -> SysResult<bool>
+	{
+		let key = self.find_mut(root, sub_key)?;
+		match key.value_pos(value_name) {
+			Some(pos) if key.values[pos].1 == data => return Ok(false),
+			Some(pos) => key.values[pos].1 = data,
+			None => key.values.push((value_name.unwrap_or("").to_owned(), data)),
+		}
+		Ok(true)
+	}
+
+	fn delete_value(&mut self,
+		root: &HKEY,
+		sub_key: &str,
+		value_name: Option<&str>,
+	) -> SysResult<bool>
+	{
+		let key = self.find_mut(root, sub_key)?;
+		let pos = key.value_pos(value_name)
+			.ok_or(co::ERROR::FILE_NOT_FOUND)?;
+		key.values.remove(pos);
+		Ok(true)
+	}
+
+	/// Returns whether the tree changed, since a root key may already be
+	/// empty.
+	fn delete_tree(&mut self, root: &HKEY, sub_key: &str) -> SysResult<bool> {
+		let names = Self::components(sub_key).collect::<Vec<_>>();
+		match names.split_last() {
+			None => {
+				let key = self.find_mut(root, "")?;
+				let changed = !key.sub_keys.is_empty() || !key.values.is_empty();
+				key.sub_keys.clear();
+				key.values.clear();
+				Ok(changed)
+			},
+			Some((name, parent)) => {
+				self.find_mut(root, &parent.join("\\"))?
+					.sub_keys.remove(&name.to_uppercase())
+					.ok_or(co::ERROR::FILE_NOT_FOUND)?;
+				Ok(true)
+			},
+		}
+	}
+}
+
+struct MemState {
+	tree: MemTree,
+	generation: u64, // incremented at each actual change, to detect transaction conflicts
+}
+
+/// In-memory [`RegistryProvider`](crate::prelude::RegistryProvider), which
+/// allows code which deals with the registry to be tested without touching
+/// the actual registry – and off Windows.
+///
+/// The tree starts with empty predefined root keys:
+/// [`HKEY::CLASSES_ROOT`](crate::prelude::advapi_Hkey::CLASSES_ROOT),
+/// [`HKEY::CURRENT_USER`](crate::prelude::advapi_Hkey::CURRENT_USER),
+/// [`HKEY::LOCAL_MACHINE`](crate::prelude::advapi_Hkey::LOCAL_MACHINE),
+/// [`HKEY::USERS`](crate::prelude::advapi_Hkey::USERS) and
+/// [`HKEY::CURRENT_CONFIG`](crate::prelude::advapi_Hkey::CURRENT_CONFIG).
+/// Any other root yields
+/// [`ERROR::INVALID_HANDLE`](crate::co::ERROR::INVALID_HANDLE). Like in the
+/// actual registry, key and value names are case-insensitive.
+///
+/// Operations can be grouped in a
+/// [`MemoryTransaction`](crate::MemoryTransaction), which mirrors
+/// [`HKEY::RegCreateKeyTransacted`](crate::prelude::advapi_Hkey::RegCreateKeyTransacted)
+/// with an [`HTRANSACTION`](crate::HTRANSACTION).
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let reg = w::MemoryRegistry::new();
+/// reg.create_key(&w::HKEY::LOCAL_MACHINE, "Software\\MyApp")?;
+///
+/// let tr = reg.begin_transaction();
+/// tr.set_value(&w::HKEY::LOCAL_MACHINE, "Software\\MyApp",
+///     Some("Installed"), w::RegistryValue::Dword(1))?;
+///
+/// // Not visible outside the transaction until committed.
+/// assert!(reg.query_value(&w::HKEY::LOCAL_MACHINE, "Software\\MyApp",
+///     Some("Installed")).is_err());
+///
+/// tr.commit()?;
+/// # w::SysResult::Ok(())
+/// ```
+pub struct MemoryRegistry {
+	state: Mutex<MemState>,
+}
+
+impl Default for MemoryRegistry {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl MemoryRegistry {
+	/// Creates a new `MemoryRegistry` with empty predefined root keys.
+	#[must_use]
+	pub fn new() -> Self {
+		Self {
+			state: Mutex::new(MemState {
+				tree: MemTree::new(),
+				generation: 0,
+			}),
+		}
+	}
+
+	/// Starts a transaction, which sees a snapshot of the current tree, and
+	/// whose changes are only visible to other users after
+	/// [`MemoryTransaction::commit`](crate::MemoryTransaction::commit).
+	#[must_use]
+	pub fn begin_transaction(&self) -> MemoryTransaction<'_> {
+		let state = self.state.lock().unwrap();
+		MemoryTransaction {
+			registry: self,
+			base_generation: state.generation,
+			tree: Mutex::new(state.tree.clone()),
+			changed: Mutex::new(false),
+		}
+	}
+
+	fn read<T>(&self, func: impl FnOnce(&MemTree) -> SysResult<T>) -> SysResult<T> {
+		func(&self.state.lock().unwrap().tree)
+	}
+
+	fn write<T>(&self, func: impl FnOnce(&mut MemTree) -> SysResult<(T, bool)>) -> SysResult<T> {
+		let mut state = self.state.lock().unwrap();
+		let (ret, changed) = func(&mut state.tree)?;
+		if changed {
+			state.generation += 1;
+		}
+		Ok(ret)
+	}
+}
+
+impl RegistryProvider for MemoryRegistry {
+	fn create_key(&self, root: &HKEY, sub_key: &str) -> SysResult<co::REG_DISPOSITION> {
+		self.write(|tree| tree.create_key(root, sub_key)
+			.map(|disp| (disp, disp == co::REG_DISPOSITION::CREATED_NEW_KEY)))
+	}
+
+	fn key_exists(&self, root: &HKEY, sub_key: &str) -> SysResult<bool> {
+		self.read(|tree| tree.key_exists(root, sub_key))
+	}
+
+	fn enum_keys(&self, root: &HKEY, sub_key: &str) -> SysResult<Vec<String>> {
+		self.read(|tree| tree.enum_keys(root, sub_key))
+	}
+
+	fn enum_values(&self, root: &HKEY, sub_key: &str) -> SysResult<Vec<(String, co::REG)>> {
+		self.read(|tree| tree.enum_values(root, sub_key))
+	}
+
+	fn query_value(&self,
+		root: &HKEY,
+		sub_key: &str,
+		value_name: Option<&str>,
+	) -> SysResult<RegistryValue>
+	{
+		self.read(|tree| tree.query_value(root, sub_key, value_name))
+	}
+
+	fn set_value(&self,
+		root: &HKEY,
+		sub_key: &str,
+		value_name: Option<&str>,
+		data: RegistryValue,
+	) -> SysResult<()>
+	{
+		self.write(|tree| tree.set_value(root, sub_key, value_name, data).map(|ch| ((), ch)))
+	}
+
+	fn delete_value(&self,
+		root: &HKEY,
+		sub_key: &str,
+		value_name: Option<&str>,
+	) -> SysResult<()>
+	{
+		self.write(|tree| tree.delete_value(root, sub_key, value_name).map(|ch| ((), ch)))
+	}
+
+	fn delete_tree(&self, root: &HKEY, sub_key: &str) -> SysResult<()> {
+		self.write(|tree| tree.delete_tree(root, sub_key).map(|ch| ((), ch)))
+	}
+}
+
+/// A transaction over a [`MemoryRegistry`](crate::MemoryRegistry), created
+/// with
+/// [`MemoryRegistry::begin_transaction`](crate::MemoryRegistry::begin_transaction).
+///
+/// Like a kernel transaction, its changes are isolated until
+/// [`commit`](crate::MemoryTransaction::commit) is called; dropping it
+/// without committing rolls back all changes. If the registry was changed by
+/// someone else since the transaction began, committing fails with
+/// [`ERROR::TRANSACTIONAL_CONFLICT`](crate::co::ERROR::TRANSACTIONAL_CONFLICT).
+/// Writes which leave the tree as it was – like setting a value to the data it
+/// already has, or creating an existing key – are not changes.
+pub struct MemoryTransaction<'a> {
+	registry: &'a MemoryRegistry,
+	base_generation: u64,
+	tree: Mutex<MemTree>,
+	changed: Mutex<bool>,
+}
+
+impl<'a> MemoryTransaction<'a> {
+	/// Applies the changes to the registry, like
+	/// [`HTRANSACTION::CommitTransaction`](crate::prelude::advapi_Htransaction::CommitTransaction).
+	pub fn commit(self) -> SysResult<()> {
+		if !*self.changed.lock().unwrap() {
+			return Ok(());
+		}
+
+		let mut state = self.registry.state.lock().unwrap();
+		if state.generation != self.base_generation {
+			return Err(co::ERROR::TRANSACTIONAL_CONFLICT);
+		}
+		state.tree = self.tree.into_inner().unwrap();
+		state.generation += 1;
+		Ok(())
+	}
+
+	/// Discards the changes, like
+	/// [`HTRANSACTION::RollbackTransaction`](crate::prelude::advapi_Htransaction::RollbackTransaction).
+	/// The same as dropping the transaction.
+	pub fn rollback(self) {}
+
+	fn read<T>(&self, func: impl FnOnce(&MemTree) -> SysResult<T>) -> SysResult<T> {
+		func(&self.tree.lock().unwrap())
+	}
+
+	fn write<T>(&self, func: impl FnOnce(&mut MemTree) -> SysResult<(T, bool)>) -> SysResult<T> {
+		let (ret, changed) = func(&mut self.tree.lock().unwrap())?;
+		if changed {
+			*self.changed.lock().unwrap() = true;
+		}
+		Ok(ret)
+	}
+}
+
+impl<'a> RegistryProvider for MemoryTransaction<'a> {
+	fn create_key(&self, root: &HKEY, sub_key: &str) -> SysResult<co::REG_DISPOSITION> {
+		self.write(|tree| tree.create_key(root, sub_key)
+			.map(|disp| (disp, disp == co::REG_DISPOSITION::CREATED_NEW_KEY)))
+	}
+
+	fn key_exists(&self, root: &HKEY, sub_key: &str) -> SysResult<bool> {
+		self.read(|tree| tree.key_exists(root, sub_key))
+	}
+
+	fn enum_keys(&self, root: &HKEY, sub_key: &str) -> SysResult<Vec<String>> {
+		self.read(|tree| tree.enum_keys(root, sub_key))
+	}
+
+	fn enum_values(&self, root: &HKEY, sub_key: &str) -> SysResult<Vec<(String, co::REG)>> {
+		self.read(|tree| tree.enum_values(root, sub_key))
+	}
+
+	fn query_value(&self,
+		root: &HKEY,
+		sub_key: &str,
+		value_name: Option<&str>,
+	) -> SysResult<RegistryValue>
+	{
+		self.read(|tree| tree.query_value(root, sub_key, value_name))
+	}
+
+	fn set_value(&self,
+		root: &HKEY,
+		sub_key: &str,
+		value_name: Option<&str>,
+		data: RegistryValue,
+	) -> SysResult<()>
+	{
+		self.write(|tree| tree.set_value(root, sub_key, value_name, data).map(|ch| ((), ch)))
+	}
+
+	fn delete_value(&self,
+		root: &HKEY,
+		sub_key: &str,
+		value_name: Option<&str>,
+	) -> SysResult<()>
+	{
+		self.write(|tree| tree.delete_value(root, sub_key, value_name).map(|ch| ((), ch)))
+	}
+
+	fn delete_tree(&self, root: &HKEY, sub_key: &str) -> SysResult<()> {
+		self.write(|tree| tree.delete_tree(root, sub_key).map(|ch| ((), ch)))
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	const KEY: &str = "Software\\MyApp";
+
+	fn dword(reg: &impl RegistryProvider, name: &str) -> SysResult<u32> {
+		match reg.query_value(&HKEY::CURRENT_USER, KEY, Some(name))? {
+			RegistryValue::Dword(n) => Ok(n),
+			_ => Err(co::ERROR::INVALID_DATA),
+		}
+	}
+
+	fn new_registry() -> MemoryRegistry {
+		let reg = MemoryRegistry::new();
+		reg.create_key(&HKEY::CURRENT_USER, KEY).unwrap();
+		reg.set_value(&HKEY::CURRENT_USER, KEY, Some("Count"), RegistryValue::Dword(1)).unwrap();
+		reg
+	}
+
+	#[test]
+	fn keys_and_values() {
+		let reg = new_registry();
+		let root = HKEY::CURRENT_USER;
+		assert_eq!(reg.create_key(&root, "software\\MYAPP"), Ok(co::REG_DISPOSITION::OPENED_EXISTING_KEY));
+		assert_eq!(reg.create_key(&root, "Software\\MyApp\\Sub\\Deep"), Ok(co::REG_DISPOSITION::CREATED_NEW_KEY));
+		assert_eq!(reg.enum_keys(&root, "Software"), Ok(vec!["MyApp".to_owned()]));
+		assert_eq!(reg.key_exists(&root, "SOFTWARE\\myapp\\sub"), Ok(true));
+		assert_eq!(reg.key_exists(&root, "Software\\Other"), Ok(false));
+
+		reg.set_value(&root, KEY, Some("COUNT"), RegistryValue::Dword(2)).unwrap();
+		reg.set_value(&root, KEY, None, RegistryValue::Sz("default".to_owned())).unwrap();
+		assert_eq!(dword(&reg, "count"), Ok(2));
+		assert_eq!(reg.enum_values(&root, KEY),
+			Ok(vec![("Count".to_owned(), co::REG::DWORD), (String::new(), co::REG::SZ)]));
+
+		reg.delete_value(&root, KEY, Some("Count")).unwrap();
+		assert_eq!(dword(&reg, "Count"), Err(co::ERROR::FILE_NOT_FOUND));
+		assert_eq!(reg.delete_value(&root, KEY, Some("Count")), Err(co::ERROR::FILE_NOT_FOUND));
+		reg.delete_tree(&root, "Software\\MyApp\\Sub").unwrap();
+		assert_eq!(reg.enum_keys(&root, KEY), Ok(Vec::new()));
+		assert_eq!(reg.delete_tree(&root, "Software\\MyApp\\Sub"), Err(co::ERROR::FILE_NOT_FOUND));
+
+		assert_eq!(reg.set_value(&root, "Software\\Other", None, RegistryValue::None),
+			Err(co::ERROR::FILE_NOT_FOUND));
+		assert_eq!(reg.create_key(&HKEY::PERFORMANCE_DATA, "x"), Err(co::ERROR::INVALID_HANDLE));
+		assert_eq!(reg.key_exists(&HKEY::PERFORMANCE_DATA, ""), Err(co::ERROR::INVALID_HANDLE));
+	}
+
+	#[test]
+	fn transaction_isolation() {
+		let reg = new_registry();
+		let root = HKEY::CURRENT_USER;
+
+		let tr = reg.begin_transaction();
+		tr.set_value(&root, KEY, Some("Count"), RegistryValue::Dword(2)).unwrap();
+		tr.create_key(&root, "Software\\MyApp\\Sub").unwrap();
+		assert_eq!(dword(&tr, "Count"), Ok(2));
+		assert_eq!(dword(&reg, "Count"), Ok(1));
+		assert_eq!(reg.key_exists(&root, "Software\\MyApp\\Sub"), Ok(false));
+		tr.commit().unwrap();
+		assert_eq!(dword(&reg, "Count"), Ok(2));
+		assert_eq!(reg.key_exists(&root, "Software\\MyApp\\Sub"), Ok(true));
+
+		let tr = reg.begin_transaction();
+		tr.delete_tree(&root, KEY).unwrap();
+		tr.rollback();
+		let tr = reg.begin_transaction();
+		tr.delete_value(&root, KEY, Some("Count")).unwrap();
+		drop(tr);
+		assert_eq!(dword(&reg, "Count"), Ok(2));
+	}
+
+	#[test]
+	fn transaction_conflicts() {
+		let root = HKEY::CURRENT_USER;
+		// (write by someone else during the transaction, conflicts)
+		let cases: &[(&str, fn(&MemoryRegistry) -> SysResult<()>, bool)] = &[
+			("nothing", |_| Ok(()), false),
+			("same value", |reg| reg.set_value(&HKEY::CURRENT_USER, KEY, Some("count"), RegistryValue::Dword(1)), false),
+			("existing key", |reg| reg.create_key(&HKEY::CURRENT_USER, KEY).map(|_| ()), false),
+			("empty root", |reg| reg.delete_tree(&HKEY::USERS, ""), false),
+			("failed write", |reg| reg.delete_value(&HKEY::CURRENT_USER, KEY, Some("x")).or(Ok(())), false),
+			("new value", |reg| reg.set_value(&HKEY::CURRENT_USER, KEY, Some("Other"), RegistryValue::Dword(1)), true),
+			("changed value", |reg| reg.set_value(&HKEY::CURRENT_USER, KEY, Some("Count"), RegistryValue::Qword(1)), true),
+			("new key", |reg| reg.create_key(&HKEY::CURRENT_USER, "Software\\Other").map(|_| ()), true),
+			("deleted value", |reg| reg.delete_value(&HKEY::CURRENT_USER, KEY, Some("Count")), true),
+			("deleted tree", |reg| reg.delete_tree(&HKEY::CURRENT_USER, "Software"), true),
+		];
+		for (desc, other_write, conflicts) in cases.iter() {
+			let reg = new_registry();
+			let tr = reg.begin_transaction();
+			tr.set_value(&root, KEY, Some("Mine"), RegistryValue::Dword(7)).unwrap();
+			other_write(&reg).unwrap();
+
+			if *conflicts {
+				assert_eq!(tr.commit(), Err(co::ERROR::TRANSACTIONAL_CONFLICT), "{}", desc);
+				assert_eq!(reg.query_value(&root, KEY, Some("Mine")), Err(co::ERROR::FILE_NOT_FOUND), "{}", desc);
+			} else {
+				assert_eq!(tr.commit(), Ok(()), "{}", desc);
+				assert_eq!(dword(&reg, "Mine"), Ok(7), "{}", desc);
+			}
+		}
+	}
+
+	#[test]
+	fn transaction_without_changes() {
+		let reg = new_registry();
+		let root = HKEY::CURRENT_USER;
+
+		let tr = reg.begin_transaction();
+		tr.set_value(&root, KEY, Some("Count"), RegistryValue::Dword(1)).unwrap(); // same data
+		tr.create_key(&root, KEY).unwrap();
+		reg.set_value(&root, KEY, Some("Count"), RegistryValue::Dword(5)).unwrap();
+		assert_eq!(tr.commit(), Ok(())); // nothing to apply
+		assert_eq!(dword(&reg, "Count"), Ok(5));
+
+		let first = reg.begin_transaction();
+		let second = reg.begin_transaction();
+		first.set_value(&root, KEY, Some("Count"), RegistryValue::Dword(6)).unwrap();
+		second.set_value(&root, KEY, Some("Count"), RegistryValue::Dword(7)).unwrap();
+		assert_eq!(first.commit(), Ok(()));
+		assert_eq!(second.commit(), Err(co::ERROR::TRANSACTIONAL_CONFLICT));
+		assert_eq!(dword(&reg, "Count"), Ok(6));
+	}
+}
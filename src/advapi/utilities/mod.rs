@@ -0,0 +1,21 @@
+mod access_check;
+mod memory_registry;
+mod reg_file;
+mod registry_mapped;
+mod registry_provider;
+mod sddl;
+mod security_descriptor;
+mod sid_buf;
+mod time_zone_db;
+mod token_snapshot;
+
+pub(in crate::advapi) use reg_file::{export_key, split_key_path};
+pub(in crate::advapi) use sid_buf::fmt_sid;
+pub use access_check::{AccessCheckResult, AccessDecision, AccessReason, AccessSubject};
+pub use memory_registry::{MemoryRegistry, MemoryTransaction};
+pub use reg_file::{RegFile, RegFileData, RegFileKey, RegFileValue};
+pub use registry_mapped::{RegistryField, RegistryMapError, RegistryMapped};
+pub use registry_provider::{NativeRegistry, RegistryProvider};
+pub use security_descriptor::{Ace, Acl, SecurityDescriptor, SecurityDescriptorBuf};
+pub use sid_buf::SidBuf;
+pub use token_snapshot::{TokenDiff, TokenPrivilege, TokenSnapshot};
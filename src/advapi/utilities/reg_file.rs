@@ -0,0 +1,616 @@
+use crate::advapi::handles::query_value_raw;
+use crate::co;
+use crate::decl::*;
+use crate::prelude::*;
+
+/// Owned, pure-Rust representation of a `.reg` file, the text format used by
+/// Registry Editor to import and export registry keys.
+///
+/// Files in both `REGEDIT5` – "Windows Registry Editor Version 5.00" – and
+/// `REGEDIT4` formats can be parsed; files are always serialized in the
+/// `REGEDIT5` format.
+///
+/// To read keys from the registry, use
+/// [`HKEY::export_reg_file`](crate::prelude::advapi_Hkey::export_reg_file); to
+/// write them, use
+/// [`HKEY::apply_reg_file`](crate::prelude::advapi_Hkey::apply_reg_file).
+///
+/// # Examples
+///
+/// Building a file and serializing it:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let reg = w::RegFile {
+///     keys: vec![
+///         w::RegFileKey {
+///             path: "HKEY_CURRENT_USER\\Software\\MyApp".to_owned(),
+///             delete: false,
+///             values: vec![
+///                 w::RegFileValue {
+///                     name: Some("Version".to_owned()),
+///                     data: Some(w::RegFileData::Value(w::RegistryValue::Dword(3))),
+///                 },
+///                 w::RegFileValue {
+///                     name: Some("Obsolete".to_owned()),
+///                     data: None, // deleted
+///                 },
+///             ],
+///         },
+///     ],
+/// };
+///
+/// let fout = w::File::open("C:\\Temp\\my_app.reg", w::FileAccess::OpenOrCreateRW)?;
+/// fout.erase_and_write(&reg.serialize())?;
+/// # w::SysResult::Ok(())
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RegFile {
+	/// The keys, in file order.
+	pub keys: Vec<RegFileKey>,
+}
+
+/// A key of a [`RegFile`](crate::RegFile).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RegFileKey {
+	/// Full path of the key, starting with the name of the root key, like
+	/// `HKEY_CURRENT_USER\Software\MyApp`.
+	pub path: String,
+	/// If `true`, the key and all its subkeys are deleted, and `values` is
+	/// ignored. Written as `[-path]`.
+	pub delete: bool,
+	/// The values of the key.
+	pub values: Vec<RegFileValue>,
+}
+
+/// A value of a [`RegFileKey`](crate::RegFileKey).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RegFileValue {
+	/// Name of the value. `None` is the default value, written as `@`.
+	pub name: Option<String>,
+	/// Data of the value. `None` deletes the value, written as `-`.
+	pub data: Option<RegFileData>,
+}
+
+/// Data of a [`RegFileValue`](crate::RegFileValue).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RegFileData {
+	/// A value of a type supported by
+	/// [`RegistryValue`](crate::RegistryValue).
+	Value(RegistryValue),
+	/// A value of any other type – like `REG_RESOURCE_LIST` – or whose data
+	/// doesn't match its type, kept as raw bytes. Written as `hex(N):`, where
+	/// `N` is the [`co::REG`](crate::co::REG) type in hexadecimal.
+	Raw(co::REG, Vec<u8>),
+}
+
+impl RegFile {
+	const HEADER_5: &'static str = "Windows Registry Editor Version 5.00";
+	const HEADER_4: &'static str = "REGEDIT4";
+	const MAX_LINE_LEN: usize = 76; // hex data is wrapped after this
+
+	/// Parses the raw contents of a `.reg` file, whose encoding is guessed
+	/// with [`Encoding::guess`](crate::Encoding::guess). Registry Editor
+	/// writes UTF-16 LE files with BOM.
+	///
+	/// Malformed files yield
+	/// [`ERROR::INVALID_DATA`](crate::co::ERROR::INVALID_DATA). Values of
+	/// types not supported by [`RegistryValue`](crate::RegistryValue) are
+	/// parsed as [`RegFileData::Raw`](crate::RegFileData::Raw).
+	pub fn parse(data: &[u8]) -> SysResult<Self> {
+		let (encoding, bom_sz) = Encoding::guess(data);
+		Self::parse_str(&encoding.decode_strict(&data[bom_sz..])?)
+	}
+
+	/// Parses the text of a `.reg` file.
+	///
+	/// Errors are the same of [`RegFile::parse`](crate::RegFile::parse).
+	pub fn parse_str(text: &str) -> SysResult<Self> {
+		let mut lines = Self::logical_lines(text).into_iter();
+		let is_v4 = match lines.next().as_deref() {
+			Some(Self::HEADER_5) => false,
+			Some(Self::HEADER_4) => true,
+			_ => return Err(co::ERROR::INVALID_DATA),
+		};
+
+		let mut keys = Vec::<RegFileKey>::new();
+		for line in lines {
+			if let Some(inner) = line.strip_prefix('[') {
+				let inner = inner.strip_suffix(']')
+					.ok_or(co::ERROR::INVALID_DATA)?;
+				let (path, delete) = match inner.strip_prefix('-') {
+					Some(path) => (path, true),
+					None => (inner, false),
+				};
+				if path.is_empty() {
+					return Err(co::ERROR::INVALID_DATA);
+				}
+				keys.push(RegFileKey {
+					path: path.to_owned(),
+					delete,
+					values: Vec::new(),
+				});
+			} else {
+				keys.last_mut()
+					.ok_or(co::ERROR::INVALID_DATA)? // value before any key
+					.values
+					.push(Self::parse_value(&line, is_v4)?);
+			}
+		}
+
+		Ok(Self { keys })
+	}
+
+	/// Splits the text in lines, joining the ones continued with a trailing
+	/// backslash, and skipping empty lines and comments.
+	#[must_use]
+	fn logical_lines(text: &str) -> Vec<String> {
+		let mut lines = Vec::<String>::new();
+		let mut continued = false;
+
+		for line in text.lines() {
+			let line = line.trim();
+			if continued {
+				let last = lines.last_mut().unwrap();
+				last.pop(); // the backslash
+				last.push_str(line);
+			} else if !line.is_empty() && !line.starts_with(';') {
+				lines.push(line.to_owned());
+			} else {
+				continue;
+			}
+			continued = lines.last().unwrap().ends_with('\\');
+		}
+
+		lines
+	}
+
+	/// Parses a quoted string, returning it and the remaining text.
+	fn parse_quoted(text: &str) -> SysResult<(String, &str)> {
+		let mut s = String::new();
+		let mut chars = text.strip_prefix('"')
+			.ok_or(co::ERROR::INVALID_DATA)?
+			.char_indices();
+
+		while let Some((_, ch)) = chars.next() {
+			match ch {
+				'\\' => s.push(chars.next().ok_or(co::ERROR::INVALID_DATA)?.1),
+				'"' => {
+					let rest = chars.next().map_or("", |(idx, _)| &text[idx + 1..]);
+					return Ok((s, rest));
+				},
+				ch => s.push(ch),
+			}
+		}
+		Err(co::ERROR::INVALID_DATA) // no closing quote
+	}
+
+	fn parse_value(line: &str, is_v4: bool) -> SysResult<RegFileValue> {
+		let (name, rest) = match line.strip_prefix('@') {
+			Some(rest) => (None, rest),
+			None => {
+				let (name, rest) = Self::parse_quoted(line)?;
+				(Some(name), rest)
+			},
+		};
+		let rest = rest.trim_start()
+			.strip_prefix('=')
+			.ok_or(co::ERROR::INVALID_DATA)?
+			.trim();
+
+		let data = if rest == "-" {
+			None
+		} else if rest.starts_with('"') {
+			let (s, after) = Self::parse_quoted(rest)?;
+			if !after.trim().is_empty() {
+				return Err(co::ERROR::INVALID_DATA);
+			}
+			Some(RegFileData::Value(RegistryValue::Sz(s)))
+		} else if let Some(num) = rest.strip_prefix("dword:") {
+			Some(RegFileData::Value(RegistryValue::Dword(
+				u32::from_str_radix(num.trim(), 16)
+					.map_err(|_| co::ERROR::INVALID_DATA)?,
+			)))
+		} else if let Some(hex) = rest.strip_prefix("hex") {
+			let (reg_type, bytes) = match hex.strip_prefix(':') {
+				Some(bytes) => (co::REG::BINARY, bytes),
+				None => {
+					let (num, bytes) = hex.strip_prefix('(')
+						.and_then(|hex| hex.split_once("):"))
+						.ok_or(co::ERROR::INVALID_DATA)?;
+					let num = u32::from_str_radix(num, 16)
+						.map_err(|_| co::ERROR::INVALID_DATA)?;
+					(unsafe { co::REG::from_raw(num) }, bytes)
+				},
+			};
+			let bytes = bytes.split(',')
+				.map(|b| b.trim())
+				.filter(|b| !b.is_empty())
+				.map(|b| u8::from_str_radix(b, 16).map_err(|_| co::ERROR::INVALID_DATA))
+				.collect::<SysResult<Vec<_>>>()?;
+			Some(Self::value_from_bytes(reg_type, bytes, is_v4))
+		} else {
+			return Err(co::ERROR::INVALID_DATA);
+		};
+
+		Ok(RegFileValue { name, data })
+	}
+
+	fn value_from_bytes(
+		reg_type: co::REG,
+		bytes: Vec<u8>,
+		is_v4: bool,
+	) -> RegFileData
+	{
+		let decode = |bytes: &[u8]| -> String {
+			if is_v4 { // ANSI strings
+				Encoding::Win1252.decode(bytes)
+			} else {
+				Encoding::Utf16le.decode(&bytes[..bytes.len() & !1])
+			}
+		};
+
+		RegFileData::Value(match reg_type {
+			co::REG::NONE if bytes.is_empty() => RegistryValue::None,
+			co::REG::SZ | co::REG::EXPAND_SZ => {
+				let mut s = decode(&bytes);
+				if let Some(idx) = s.find('\0') {
+					s.truncate(idx);
+				}
+				if reg_type == co::REG::SZ {
+					RegistryValue::Sz(s)
+				} else {
+					RegistryValue::ExpandSz(s)
+				}
+			},
+			co::REG::BINARY => RegistryValue::Binary(bytes),
+			co::REG::DWORD if bytes.len() == 4 => RegistryValue::Dword(
+				u32::from_le_bytes(bytes.try_into().unwrap()),
+			),
+			co::REG::QWORD if bytes.len() == 8 => RegistryValue::Qword(
+				u64::from_le_bytes(bytes.try_into().unwrap()),
+			),
+			co::REG::MULTI_SZ => RegistryValue::MultiSz(
+				decode(&bytes)
+					.split('\0')
+					.take_while(|s| !s.is_empty())
+					.map(|s| s.to_owned())
+					.collect(),
+			),
+			_ => return RegFileData::Raw(reg_type, bytes),
+		})
+	}
+
+	/// Serializes the keys into the raw contents of a `.reg` file, in UTF-16
+	/// LE with BOM, exactly like Registry Editor does.
+	#[must_use]
+	pub fn serialize(&self) -> Vec<u8> {
+		let mut data = Encoding::Utf16le.bom().to_vec();
+		data.extend(Encoding::Utf16le.encode(&self.serialize_str()));
+		data
+	}
+
+	/// Serializes the keys into the text of a `.reg` file, in `REGEDIT5`
+	/// format, with CRLF line breaks.
+	#[must_use]
+	pub fn serialize_str(&self) -> String {
+		let mut out = String::new();
+		out.push_str(Self::HEADER_5);
+		out.push_str("\r\n\r\n");
+
+		for key in self.keys.iter() {
+			if key.delete {
+				out.push_str(&format!("[-{}]\r\n\r\n", key.path));
+				continue;
+			}
+
+			out.push_str(&format!("[{}]\r\n", key.path));
+			for val in key.values.iter() {
+				Self::write_value(&mut out, val);
+				out.push_str("\r\n");
+			}
+			out.push_str("\r\n");
+		}
+
+		out
+	}
+
+	fn write_quoted(out: &mut String, s: &str) {
+		out.push('"');
+		for ch in s.chars() {
+			if ch == '\\' || ch == '"' {
+				out.push('\\');
+			}
+			out.push(ch);
+		}
+		out.push('"');
+	}
+
+	fn write_value(out: &mut String, val: &RegFileValue) {
+		let line_start = out.len();
+		match &val.name {
+			Some(name) => Self::write_quoted(out, name),
+			None => out.push('@'),
+		}
+		out.push('=');
+
+		let utf16z = |strs: &[&str]| -> Vec<u8> {
+			strs.iter()
+				.flat_map(|s| Encoding::Utf16le.encode(s).into_iter().chain([0, 0]))
+				.collect()
+		};
+
+		let (reg_type, bytes) = match &val.data {
+			None => {
+				out.push('-');
+				return;
+			},
+			Some(RegFileData::Raw(reg_type, bytes)) => (*reg_type, bytes.clone()),
+			Some(RegFileData::Value(data)) => match data {
+				RegistryValue::Sz(s) => {
+					if !s.contains(['\r', '\n', '\0']) {
+						Self::write_quoted(out, s);
+						return;
+					}
+					(co::REG::SZ, utf16z(&[s])) // line breaks can't be quoted
+				},
+				RegistryValue::Dword(n) => {
+					out.push_str(&format!("dword:{:08x}", n));
+					return;
+				},
+				RegistryValue::Binary(b) => (co::REG::BINARY, b.clone()),
+				RegistryValue::Qword(n) => (co::REG::QWORD, n.to_le_bytes().to_vec()),
+				RegistryValue::ExpandSz(s) => (co::REG::EXPAND_SZ, utf16z(&[s])),
+				RegistryValue::MultiSz(v) => {
+					let mut bytes = utf16z(&v.iter().map(|s| s.as_str()).collect::<Vec<_>>());
+					bytes.extend_from_slice(&[0, 0]);
+					(co::REG::MULTI_SZ, bytes)
+				},
+				RegistryValue::None => (co::REG::NONE, Vec::new()),
+			},
+		};
+
+		if reg_type == co::REG::BINARY {
+			out.push_str("hex:");
+		} else {
+			out.push_str(&format!("hex({:x}):", reg_type.raw()));
+		}
+		let mut line_len = out.len() - line_start;
+		for (idx, b) in bytes.iter().enumerate() {
+			out.push_str(&format!("{:02x}", b));
+			if idx < bytes.len() - 1 {
+				out.push(',');
+				line_len += 3;
+				if line_len > Self::MAX_LINE_LEN {
+					out.push_str("\\\r\n  ");
+					line_len = 2;
+				}
+			}
+		}
+	}
+}
+
+/// Splits a full key path into its predefined root key and the subkey path,
+/// which may be empty. Both the long and the abbreviated root names, like
+/// `HKEY_CURRENT_USER` and `HKCU`, are accepted.
+pub(in crate::advapi) fn split_key_path(path: &str) -> SysResult<(HKEY, &str)> {
+	let (root, sub_key) = path.split_once('\\').unwrap_or((path, ""));
+	let hkey = match root.to_uppercase().as_str() {
+		"HKEY_CLASSES_ROOT" | "HKCR" => HKEY::CLASSES_ROOT,
+		"HKEY_CURRENT_USER" | "HKCU" => HKEY::CURRENT_USER,
+		"HKEY_LOCAL_MACHINE" | "HKLM" => HKEY::LOCAL_MACHINE,
+		"HKEY_USERS" | "HKU" => HKEY::USERS,
+		"HKEY_CURRENT_CONFIG" | "HKCC" => HKEY::CURRENT_CONFIG,
+		_ => return Err(co::ERROR::INVALID_DATA),
+	};
+	Ok((hkey, sub_key))
+}
+
+/// Reads the values of the key, and recursively its subkeys, appending them to
+/// `keys`.
+pub(in crate::advapi) fn export_key(
+	hkey: &impl advapi_Hkey,
+	path: &str,
+	keys: &mut Vec<RegFileKey>,
+) -> SysResult<()>
+{
+	let value_names = hkey.RegEnumValue()?
+		.map(|name_type| name_type.map(|(name, _)| name))
+		.collect::<SysResult<Vec<_>>>()?;
+	let values = value_names.into_iter()
+		.map(|name| {
+			let (reg_type, bytes) = query_value_raw(hkey, Some(&name))?;
+			let data = match unsafe { RegistryValue::from_raw(bytes.clone(), reg_type) } {
+				Ok(data) => RegFileData::Value(data),
+				Err(_) => RegFileData::Raw(reg_type, bytes), // unsupported type or malformed data
+			};
+			Ok(RegFileValue {
+				name: if name.is_empty() { None } else { Some(name) },
+				data: Some(data),
+			})
+		})
+		.collect::<SysResult<Vec<_>>>()?;
+
+	keys.push(RegFileKey {
+		path: path.to_owned(),
+		delete: false,
+		values,
+	});
+
+	let sub_key_names = hkey.RegEnumKeyEx()?
+		.collect::<SysResult<Vec<_>>>()?;
+	for sub_key_name in sub_key_names.iter() {
+		let hsub = hkey.RegOpenKeyEx(
+			Some(sub_key_name),
+			co::REG_OPTION::default(),
+			co::KEY::READ,
+		)?;
+		export_key(&*hsub, &format!("{}\\{}", path, sub_key_name), keys)?;
+	}
+	Ok(())
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	fn value(name: Option<&str>, data: RegistryValue) -> RegFileValue {
+		RegFileValue {
+			name: name.map(|s| s.to_owned()),
+			data: Some(RegFileData::Value(data)),
+		}
+	}
+
+	fn key(path: &str, values: Vec<RegFileValue>) -> RegFileKey {
+		RegFileKey { path: path.to_owned(), delete: false, values }
+	}
+
+	#[test]
+	fn round_trip() {
+		let values = vec![
+			value(None, RegistryValue::Sz("default".to_owned())),
+			value(Some("Quoted \"name\" C:\\"), RegistryValue::Sz("C:\\Path \"x\"".to_owned())),
+			value(Some("Empty"), RegistryValue::Sz(String::new())),
+			value(Some("Lines"), RegistryValue::Sz("a\r\nb".to_owned())),
+			value(Some("Unicode"), RegistryValue::Sz("Ωμέγα 😀".to_owned())),
+			value(Some("Dword"), RegistryValue::Dword(0xdead_beef)),
+			value(Some("Qword"), RegistryValue::Qword(0x0123_4567_89ab_cdef)),
+			value(Some("Expand"), RegistryValue::ExpandSz("%SystemRoot%\\x".to_owned())),
+			value(Some("Multi"), RegistryValue::MultiSz(vec!["one".to_owned(), "two".to_owned()])),
+			value(Some("MultiEmpty"), RegistryValue::MultiSz(Vec::new())),
+			value(Some("Binary"), RegistryValue::Binary((0..=255).collect())),
+			value(Some("BinaryEmpty"), RegistryValue::Binary(Vec::new())),
+			value(Some("None"), RegistryValue::None),
+			RegFileValue {
+				name: Some("ResourceList".to_owned()),
+				data: Some(RegFileData::Raw(co::REG::RESOURCE_LIST, vec![1, 2, 3])),
+			},
+			RegFileValue {
+				name: Some("ShortDword".to_owned()),
+				data: Some(RegFileData::Raw(co::REG::DWORD, vec![1, 2, 3])),
+			},
+			RegFileValue { name: Some("Deleted".to_owned()), data: None },
+		];
+		let reg = RegFile {
+			keys: vec![
+				key("HKEY_CURRENT_USER\\Software\\MyApp", values),
+				key("HKEY_CURRENT_USER\\Software\\MyApp\\Empty", Vec::new()),
+				RegFileKey {
+					path: "HKEY_CURRENT_USER\\Software\\Old".to_owned(),
+					delete: true,
+					values: Vec::new(),
+				},
+			],
+		};
+
+		let text = reg.serialize_str();
+		assert!(text.lines().all(|line| line.len() <= 80), "{}", text);
+		assert_eq!(RegFile::parse_str(&text), Ok(reg.clone()));
+		assert_eq!(RegFile::parse(&reg.serialize()), Ok(reg.clone()));
+		assert_eq!(RegFile::parse(&Encoding::Utf8.encode(&text)), Ok(reg));
+	}
+
+	#[test]
+	fn serialized_text() {
+		let reg = RegFile {
+			keys: vec![
+				key("HKEY_CURRENT_USER\\Software\\MyApp", vec![
+					value(None, RegistryValue::Sz("a\"b".to_owned())),
+					value(Some("Count"), RegistryValue::Dword(42)),
+					value(Some("Path"), RegistryValue::ExpandSz("%T%".to_owned())),
+					RegFileValue { name: Some("Gone".to_owned()), data: None },
+				]),
+				RegFileKey { path: "HKEY_CURRENT_USER\\Old".to_owned(), delete: true, values: Vec::new() },
+			],
+		};
+		assert_eq!(reg.serialize_str(), "Windows Registry Editor Version 5.00\r\n\r\n\
+			[HKEY_CURRENT_USER\\Software\\MyApp]\r\n\
+			@=\"a\\\"b\"\r\n\
+			\"Count\"=dword:0000002a\r\n\
+			\"Path\"=hex(2):25,00,54,00,25,00,00,00\r\n\
+			\"Gone\"=-\r\n\
+			\r\n\
+			[-HKEY_CURRENT_USER\\Old]\r\n\r\n");
+		assert_eq!(&reg.serialize()[..4], &[0xff, 0xfe, b'W', 0x00]);
+	}
+
+	#[test]
+	fn parse_formats() {
+		let cases: &[(&str, RegFileValue)] = &[
+			("\"a\" = dword:FFFFFFFF", value(Some("a"), RegistryValue::Dword(u32::MAX))),
+			("@=\"x\"  ", value(None, RegistryValue::Sz("x".to_owned()))),
+			("\"a\"=hex:01,02,\\\n  03", value(Some("a"), RegistryValue::Binary(vec![1, 2, 3]))),
+			("\"a\"=hex(4):2a,00,00,00", value(Some("a"), RegistryValue::Dword(42))),
+			("\"a\"=hex(b):01,00,00,00,00,00,00,00", value(Some("a"), RegistryValue::Qword(1))),
+			("\"a\"=hex(1):68,00,69,00,00,00", value(Some("a"), RegistryValue::Sz("hi".to_owned()))),
+			("\"a\"=hex(7):61,00,00,00,62,00,00,00,00,00",
+				value(Some("a"), RegistryValue::MultiSz(vec!["a".to_owned(), "b".to_owned()]))),
+			("\"a\"=hex(0):", value(Some("a"), RegistryValue::None)),
+			("\"a\"=hex(0):01", RegFileValue {
+				name: Some("a".to_owned()),
+				data: Some(RegFileData::Raw(co::REG::NONE, vec![1])),
+			}),
+		];
+		for (line, expected) in cases.iter() {
+			let text = format!("Windows Registry Editor Version 5.00\r\n\r\n; comment\r\n[HKCU\\A]\r\n{}\r\n", line);
+			assert_eq!(RegFile::parse_str(&text), Ok(RegFile { keys: vec![key("HKCU\\A", vec![expected.clone()])] }),
+				"{:?}", line);
+		}
+
+		// REGEDIT4 strings are ANSI.
+		let v4 = "REGEDIT4\n\n[HKEY_LOCAL_MACHINE\\X]\n\"e\"=hex(2):25,80,25,00\n";
+		assert_eq!(RegFile::parse_str(v4), Ok(RegFile {
+			keys: vec![key("HKEY_LOCAL_MACHINE\\X", vec![
+				value(Some("e"), RegistryValue::ExpandSz("%€%".to_owned())),
+			])],
+		}));
+	}
+
+	#[test]
+	fn parse_malformed() {
+		let bodies = [
+			"[HKCU\\A",
+			"[]",
+			"[-]",
+			"\"a\"=\"b\"", // value before any key
+			"[HKCU\\A]\n\"a\"=dword:xyz",
+			"[HKCU\\A]\n\"a\"=dword:100000000",
+			"[HKCU\\A]\n\"a\"=hex:0g",
+			"[HKCU\\A]\n\"a\"=hex:100",
+			"[HKCU\\A]\n\"a\"=hex(z):00",
+			"[HKCU\\A]\n\"a\"=hex(1)00",
+			"[HKCU\\A]\n\"a\"=\"b",
+			"[HKCU\\A]\n\"a\"=\"b\" c",
+			"[HKCU\\A]\n\"a\"=b",
+			"[HKCU\\A]\n\"a\"",
+			"[HKCU\\A]\n\"a=\"b\"",
+			"[HKCU\\A]\na=\"b\"",
+		];
+		for body in bodies.iter() {
+			let text = format!("Windows Registry Editor Version 5.00\n\n{}\n", body);
+			assert_eq!(RegFile::parse_str(&text), Err(co::ERROR::INVALID_DATA), "{:?}", body);
+		}
+		assert_eq!(RegFile::parse_str(""), Err(co::ERROR::INVALID_DATA));
+		assert_eq!(RegFile::parse_str("Windows Registry Editor Version 4.00\n"), Err(co::ERROR::INVALID_DATA));
+		assert_eq!(RegFile::parse_str("REGEDIT4"), Ok(RegFile::default()));
+	}
+
+	#[test]
+	fn key_paths() {
+		let cases: &[(&str, &HKEY, &str)] = &[
+			("HKEY_CURRENT_USER\\Software\\MyApp", &HKEY::CURRENT_USER, "Software\\MyApp"),
+			("hklm\\Software", &HKEY::LOCAL_MACHINE, "Software"),
+			("HKCR", &HKEY::CLASSES_ROOT, ""),
+			("HKEY_USERS\\.DEFAULT", &HKEY::USERS, ".DEFAULT"),
+			("HKCC\\System", &HKEY::CURRENT_CONFIG, "System"),
+		];
+		for (path, root, sub_key) in cases.iter() {
+			let (hkey, rest) = split_key_path(path).unwrap();
+			assert_eq!((&hkey, rest), (*root, *sub_key), "{:?}", path);
+		}
+		for path in ["", "HKEY_FOO\\x", "Software\\MyApp"] {
+			assert_eq!(split_key_path(path), Err(co::ERROR::INVALID_DATA), "{:?}", path);
+		}
+	}
+}
@@ -0,0 +1,609 @@
+use crate::co;
+use crate::decl::*;
+
+/// Error returned by [`RegistryMapped`](crate::prelude::RegistryMapped) and
+/// [`RegistryField`](crate::prelude::RegistryField) operations.
+///
+/// All variants carry the path of the key where the error happened, relative
+/// to the root key.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RegistryMapError {
+	/// A value exists, but its type is not the one expected by the field.
+	Mismatch {
+		/// Path of the key.
+		sub_key: String,
+		/// Name of the value; empty for the default value.
+		value_name: String,
+		/// Type expected by the field.
+		expected: co::REG,
+		/// Type found in the registry.
+		found: co::REG,
+	},
+	/// A value doesn't exist, and the field has no default.
+	Missing {
+		/// Path of the key.
+		sub_key: String,
+		/// Name of the value; empty for the default value.
+		value_name: String,
+	},
+	/// An underlying registry operation failed.
+	Sys {
+		/// Path of the key.
+		sub_key: String,
+		/// The error.
+		error: co::ERROR,
+	},
+}
+
+impl std::error::Error for RegistryMapError {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		match self {
+			Self::Sys { error, .. } => Some(error),
+			_ => None,
+		}
+	}
+}
+
+impl std::fmt::Display for RegistryMapError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Self::Mismatch { sub_key, value_name, expected, found } => write!(f,
+				"Type mismatch in \"{}\" value \"{}\": expected {}, found {}.",
+				sub_key, value_name, expected, found),
+			Self::Missing { sub_key, value_name } => write!(f,
+				"Missing \"{}\" value \"{}\".", sub_key, value_name),
+			Self::Sys { sub_key, error } => write!(f,
+				"Registry error in \"{}\": {}", sub_key, error),
+		}
+	}
+}
+
+impl RegistryMapError {
+	/// Returns the equivalent system error:
+	/// [`ERROR::DATATYPE_MISMATCH`](crate::co::ERROR::DATATYPE_MISMATCH),
+	/// [`ERROR::FILE_NOT_FOUND`](crate::co::ERROR::FILE_NOT_FOUND) or the
+	/// wrapped error.
+	#[must_use]
+	pub const fn error(&self) -> co::ERROR {
+		match self {
+			Self::Mismatch { .. } => co::ERROR::DATATYPE_MISMATCH,
+			Self::Missing { .. } => co::ERROR::FILE_NOT_FOUND,
+			Self::Sys { error, .. } => *error,
+		}
+	}
+}
+
+/// Maps a struct to a registry key, so it can be read and written in a single
+/// call, through any [`RegistryProvider`](crate::prelude::RegistryProvider) –
+/// [`NativeRegistry`](crate::NativeRegistry) for the actual registry, or
+/// [`MemoryRegistry`](crate::MemoryRegistry) for an in-memory tree.
+///
+/// Each field is read and written with
+/// [`RegistryField`](crate::prelude::RegistryField), which is implemented for
+/// the following types:
+///
+/// | Field type | Registry type |
+/// | - | - |
+/// | `String` | [`REG::SZ`](crate::co::REG::SZ) (also reads [`REG::EXPAND_SZ`](crate::co::REG::EXPAND_SZ)) |
+/// | `u32` | [`REG::DWORD`](crate::co::REG::DWORD) |
+/// | `u64` | [`REG::QWORD`](crate::co::REG::QWORD) |
+/// | `bool` | [`REG::DWORD`](crate::co::REG::DWORD), zero or non-zero |
+/// | `Vec<String>` | [`REG::MULTI_SZ`](crate::co::REG::MULTI_SZ) |
+/// | `Vec<u8>` | [`REG::BINARY`](crate::co::REG::BINARY) |
+/// | `Option<T>` | Same as `T`; a missing value is `None` |
+/// | Any `RegistryMapped` | A subkey |
+///
+/// With the `derive` cargo feature, this trait can be derived. Each field is
+/// mapped to a value named after the field, and accepts the following
+/// attributes:
+///
+/// * `#[registry(rename = "Name")]` – name of the value or subkey;
+/// * `#[registry(default)]` – uses `Default::default()` if missing;
+/// * `#[registry(default = expr)]` – uses the expression if missing.
+///
+/// Missing values without a default yield
+/// [`RegistryMapError::Missing`](crate::RegistryMapError::Missing).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+///
+/// # Examples
+///
+/// Deriving the trait:
+///
+/// ```ignore
+/// use winsafe::{self as w, prelude::*};
+///
+/// #[derive(w::RegistryMapped)]
+/// struct Window {
+///     #[registry(rename = "Left")]
+///     left: u32,
+///     #[registry(rename = "Top")]
+///     top: u32,
+/// }
+///
+/// #[derive(w::RegistryMapped)]
+/// struct Config {
+///     #[registry(rename = "UserName", default)]
+///     user_name: String,
+///     #[registry(rename = "Retries", default = 3)]
+///     retries: u32,
+///     #[registry(rename = "Recent", default)]
+///     recent_files: Vec<String>,
+///     #[registry(rename = "Window")]
+///     window: Option<Window>, // subkey
+/// }
+///
+/// let reg = w::NativeRegistry::new();
+/// let config = Config::read_registry(&reg, &w::HKEY::CURRENT_USER, "Software\\MyApp")?;
+/// config.write_registry(&reg, &w::HKEY::CURRENT_USER, "Software\\MyApp")?;
+/// # Ok::<_, w::RegistryMapError>(())
+/// ```
+///
+/// Implementing the trait manually:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// struct Config {
+///     user_name: String,
+///     retries: u32,
+/// }
+///
+/// impl w::prelude::RegistryMapped for Config {
+///     fn read_registry(
+///         reg: &impl w::prelude::RegistryProvider,
+///         root: &w::HKEY,
+///         sub_key: &str,
+///     ) -> Result<Self, w::RegistryMapError>
+///     {
+///         Ok(Self {
+///             user_name: String::read_field(reg, root, sub_key, "UserName")?
+///                 .unwrap_or_default(),
+///             retries: u32::read_field(reg, root, sub_key, "Retries")?
+///                 .unwrap_or(3),
+///         })
+///     }
+///
+///     fn write_registry(&self,
+///         reg: &impl w::prelude::RegistryProvider,
+///         root: &w::HKEY,
+///         sub_key: &str,
+///     ) -> Result<(), w::RegistryMapError>
+///     {
+///         reg.create_key(root, sub_key)
+///             .map_err(|error| w::RegistryMapError::Sys {
+///                 sub_key: sub_key.to_owned(),
+///                 error,
+///             })?;
+///         self.user_name.write_field(reg, root, sub_key, "UserName")?;
+///         self.retries.write_field(reg, root, sub_key, "Retries")
+///     }
+/// }
+/// ```
+pub trait RegistryMapped: Sized {
+	/// Reads the struct from the values and subkeys of the key.
+	fn read_registry(
+		reg: &impl RegistryProvider,
+		root: &HKEY,
+		sub_key: &str,
+	) -> Result<Self, RegistryMapError>;
+
+	/// Writes the struct to the values and subkeys of the key, which is
+	/// created if it doesn't exist.
+	fn write_registry(&self,
+		reg: &impl RegistryProvider,
+		root: &HKEY,
+		sub_key: &str,
+	) -> Result<(), RegistryMapError>;
+}
+
+/// A type which can be stored as a registry value or subkey, used by
+/// [`RegistryMapped`](crate::prelude::RegistryMapped) to map each field.
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait RegistryField: Sized {
+	/// Reads the field from the key; returns `None` if it doesn't exist.
+	fn read_field(
+		reg: &impl RegistryProvider,
+		root: &HKEY,
+		sub_key: &str,
+		name: &str,
+	) -> Result<Option<Self>, RegistryMapError>;
+
+	/// Writes the field to the key, which must exist.
+	fn write_field(&self,
+		reg: &impl RegistryProvider,
+		root: &HKEY,
+		sub_key: &str,
+		name: &str,
+	) -> Result<(), RegistryMapError>;
+
+	/// Deletes the field from the key; called when writing a `None`
+	/// [`Option`](std::option::Option). A field which doesn't exist is not an
+	/// error.
+	///
+	/// The default implementation deletes the value.
+	fn delete_field(
+		reg: &impl RegistryProvider,
+		root: &HKEY,
+		sub_key: &str,
+		name: &str,
+	) -> Result<(), RegistryMapError>
+	{
+		let value_name = if name.is_empty() { None } else { Some(name) };
+		match reg.delete_value(root, sub_key, value_name) {
+			Ok(_) | Err(co::ERROR::FILE_NOT_FOUND) => Ok(()),
+			Err(error) => Err(RegistryMapError::Sys { sub_key: sub_key.to_owned(), error }),
+		}
+	}
+}
+
+/// Joins a key path and a subkey name.
+#[must_use]
+fn join_key(sub_key: &str, name: &str) -> String {
+	if sub_key.is_empty() {
+		name.to_owned()
+	} else {
+		format!("{}\\{}", sub_key, name)
+	}
+}
+
+/// Reads a value, returning `None` if it doesn't exist.
+fn read_value(
+	reg: &impl RegistryProvider,
+	root: &HKEY,
+	sub_key: &str,
+	name: &str,
+) -> Result<Option<RegistryValue>, RegistryMapError>
+{
+	let value_name = if name.is_empty() { None } else { Some(name) };
+	match reg.query_value(root, sub_key, value_name) {
+		Ok(data) => Ok(Some(data)),
+		Err(co::ERROR::FILE_NOT_FOUND) => Ok(None),
+		Err(error) => Err(RegistryMapError::Sys { sub_key: sub_key.to_owned(), error }),
+	}
+}
+
+fn write_value(
+	reg: &impl RegistryProvider,
+	root: &HKEY,
+	sub_key: &str,
+	name: &str,
+	data: RegistryValue,
+) -> Result<(), RegistryMapError>
+{
+	let value_name = if name.is_empty() { None } else { Some(name) };
+	reg.set_value(root, sub_key, value_name, data)
+		.map_err(|error| RegistryMapError::Sys { sub_key: sub_key.to_owned(), error })
+}
+
+/// Implements `RegistryField` for a type stored as a single value.
+macro_rules! impl_registry_field {
+	(
+		$ty:ty, $reg:ident,
+		|$v:ident| $from:expr,
+		|$s:ident| $to:expr
+	) => {
+		impl RegistryField for $ty {
+			fn read_field(
+				reg: &impl RegistryProvider,
+				root: &HKEY,
+				sub_key: &str,
+				name: &str,
+			) -> Result<Option<Self>, RegistryMapError>
+			{
+				match read_value(reg, root, sub_key, name)? {
+					None => Ok(None),
+					Some($v) => match $from {
+						Some(val) => Ok(Some(val)),
+						None => Err(RegistryMapError::Mismatch {
+							sub_key: sub_key.to_owned(),
+							value_name: name.to_owned(),
+							expected: co::REG::$reg,
+							found: $v.reg_type(),
+						}),
+					},
+				}
+			}
+
+			fn write_field(&self,
+				reg: &impl RegistryProvider,
+				root: &HKEY,
+				sub_key: &str,
+				name: &str,
+			) -> Result<(), RegistryMapError>
+			{
+				let $s = self;
+				write_value(reg, root, sub_key, name, $to)
+			}
+		}
+	};
+}
+
+impl_registry_field!(String, SZ,
+	|v| match &v {
+		RegistryValue::Sz(s) | RegistryValue::ExpandSz(s) => Some(s.clone()),
+		_ => None,
+	},
+	|s| RegistryValue::Sz(s.clone())
+);
+
+impl_registry_field!(u32, DWORD,
+	|v| match v {
+		RegistryValue::Dword(n) => Some(n),
+		_ => None,
+	},
+	|s| RegistryValue::Dword(*s)
+);
+
+impl_registry_field!(u64, QWORD,
+	|v| match v {
+		RegistryValue::Qword(n) => Some(n),
+		_ => None,
+	},
+	|s| RegistryValue::Qword(*s)
+);
+
+impl_registry_field!(bool, DWORD,
+	|v| match v {
+		RegistryValue::Dword(n) => Some(n != 0),
+		_ => None,
+	},
+	|s| RegistryValue::Dword(*s as _)
+);
+
+impl_registry_field!(Vec<String>, MULTI_SZ,
+	|v| match &v {
+		RegistryValue::MultiSz(strs) => Some(strs.clone()),
+		_ => None,
+	},
+	|s| RegistryValue::MultiSz(s.clone())
+);
+
+impl_registry_field!(Vec<u8>, BINARY,
+	|v| match &v {
+		RegistryValue::Binary(b) => Some(b.clone()),
+		_ => None,
+	},
+	|s| RegistryValue::Binary(s.clone())
+);
+
+impl<T: RegistryField> RegistryField for Option<T> {
+	fn read_field(
+		reg: &impl RegistryProvider,
+		root: &HKEY,
+		sub_key: &str,
+		name: &str,
+	) -> Result<Option<Self>, RegistryMapError>
+	{
+		T::read_field(reg, root, sub_key, name)
+			.map(Some) // missing is a valid None
+	}
+
+	/// If `None`, the value or subkey is deleted.
+	fn write_field(&self,
+		reg: &impl RegistryProvider,
+		root: &HKEY,
+		sub_key: &str,
+		name: &str,
+	) -> Result<(), RegistryMapError>
+	{
+		match self {
+			Some(val) => val.write_field(reg, root, sub_key, name),
+			None => T::delete_field(reg, root, sub_key, name),
+		}
+	}
+
+	fn delete_field(
+		reg: &impl RegistryProvider,
+		root: &HKEY,
+		sub_key: &str,
+		name: &str,
+	) -> Result<(), RegistryMapError>
+	{
+		T::delete_field(reg, root, sub_key, name)
+	}
+}
+
+impl<T: RegistryMapped> RegistryField for T {
+	fn read_field(
+		reg: &impl RegistryProvider,
+		root: &HKEY,
+		sub_key: &str,
+		name: &str,
+	) -> Result<Option<Self>, RegistryMapError>
+	{
+		let path = join_key(sub_key, name);
+		match reg.key_exists(root, &path) {
+			Ok(true) => T::read_registry(reg, root, &path).map(Some),
+			Ok(false) => Ok(None),
+			Err(error) => Err(RegistryMapError::Sys { sub_key: path, error }),
+		}
+	}
+
+	fn write_field(&self,
+		reg: &impl RegistryProvider,
+		root: &HKEY,
+		sub_key: &str,
+		name: &str,
+	) -> Result<(), RegistryMapError>
+	{
+		self.write_registry(reg, root, &join_key(sub_key, name))
+	}
+
+	/// Deletes the subkey and all its contents. If `name` is empty, the field
+	/// is mapped to the key itself, which is not deleted.
+	fn delete_field(
+		reg: &impl RegistryProvider,
+		root: &HKEY,
+		sub_key: &str,
+		name: &str,
+	) -> Result<(), RegistryMapError>
+	{
+		if name.is_empty() {
+			return Ok(());
+		}
+
+		let path = join_key(sub_key, name);
+		match reg.delete_tree(root, &path) {
+			Ok(_) | Err(co::ERROR::FILE_NOT_FOUND) => Ok(()),
+			Err(error) => Err(RegistryMapError::Sys { sub_key: path, error }),
+		}
+	}
+}
+
+#[cfg(all(test, feature = "derive"))]
+mod test {
+	use crate::prelude::*;
+	use super::*;
+
+	#[derive(crate::RegistryMapped, Clone, Default, PartialEq, Eq, Debug)]
+	struct Window {
+		#[registry(rename = "Left")]
+		left: u32,
+		#[registry(rename = "Top", default)]
+		top: u32,
+	}
+
+	#[derive(crate::RegistryMapped, Clone, PartialEq, Eq, Debug)]
+	struct Config {
+		/// Doc comments and other attributes are ignored.
+		#[allow(dead_code)]
+		#[registry(rename = "UserName")]
+		user_name: String,
+		#[registry(rename = "Retries", default = 1 + 2)]
+		retries: u32,
+		#[registry(default)]
+		verbose: bool,
+		size: u64,
+		#[registry(default = vec!["a.txt".to_owned()], rename = "Recent")]
+		recent_files: Vec<String>,
+		blob: Vec<u8>,
+		r#type: u32,
+		note: Option<String>,
+		#[registry(rename = "Window")]
+		window: Option<Window>,
+		main: Window,
+	}
+
+	const KEY: &str = "Software\\App";
+
+	fn config() -> Config {
+		Config {
+			user_name: "Ana".to_owned(),
+			retries: 5,
+			verbose: true,
+			size: 1 << 40,
+			recent_files: vec!["x.txt".to_owned(), "y.txt".to_owned()],
+			blob: vec![1, 2, 3],
+			r#type: 7,
+			note: Some("hello".to_owned()),
+			window: Some(Window { left: 10, top: 20 }),
+			main: Window { left: 1, top: 2 },
+		}
+	}
+
+	#[test]
+	fn round_trip() {
+		let reg = MemoryRegistry::new();
+		let root = HKEY::CURRENT_USER;
+		let mut cfg = config();
+		cfg.write_registry(&reg, &root, KEY).unwrap();
+		assert_eq!(Config::read_registry(&reg, &root, KEY), Ok(cfg.clone()));
+
+		let values: &[(&str, &str, RegistryValue)] = &[
+			(KEY, "UserName", RegistryValue::Sz("Ana".to_owned())),
+			(KEY, "Retries", RegistryValue::Dword(5)),
+			(KEY, "verbose", RegistryValue::Dword(1)),
+			(KEY, "size", RegistryValue::Qword(1 << 40)),
+			(KEY, "Recent", RegistryValue::MultiSz(vec!["x.txt".to_owned(), "y.txt".to_owned()])),
+			(KEY, "blob", RegistryValue::Binary(vec![1, 2, 3])),
+			(KEY, "type", RegistryValue::Dword(7)), // raw prefix dropped
+			(KEY, "note", RegistryValue::Sz("hello".to_owned())),
+			("Software\\App\\Window", "Left", RegistryValue::Dword(10)),
+			("Software\\App\\main", "Top", RegistryValue::Dword(2)),
+		];
+		for (sub_key, name, value) in values.iter() {
+			assert_eq!(reg.query_value(&root, sub_key, Some(name)).as_ref(), Ok(value), "{}", name);
+		}
+
+		// None deletes the value and the subkey.
+		cfg.note = None;
+		cfg.window = None;
+		cfg.write_registry(&reg, &root, KEY).unwrap();
+		assert!(!reg.key_exists(&root, "Software\\App\\Window").unwrap());
+		assert_eq!(reg.query_value(&root, KEY, Some("note")), Err(co::ERROR::FILE_NOT_FOUND));
+		assert_eq!(Config::read_registry(&reg, &root, KEY), Ok(cfg));
+	}
+
+	#[test]
+	fn defaults() {
+		let reg = MemoryRegistry::new();
+		let root = HKEY::CURRENT_USER;
+		config().write_registry(&reg, &root, KEY).unwrap();
+		for name in ["Retries", "verbose", "Recent"] {
+			reg.delete_value(&root, KEY, Some(name)).unwrap();
+		}
+		reg.delete_value(&root, "Software\\App\\main", Some("Top")).unwrap();
+
+		let cfg = Config::read_registry(&reg, &root, KEY).unwrap();
+		assert_eq!(cfg.retries, 3);
+		assert!(!cfg.verbose);
+		assert_eq!(cfg.recent_files, ["a.txt"]);
+		assert_eq!(cfg.main, Window { left: 1, top: 0 });
+	}
+
+	#[test]
+	fn errors() {
+		type Change = fn(&MemoryRegistry) -> SysResult<()>;
+		let root = HKEY::CURRENT_USER;
+		let cases: &[(Change, RegistryMapError)] = &[
+			(|reg| reg.delete_value(&HKEY::CURRENT_USER, KEY, Some("UserName")),
+				RegistryMapError::Missing {
+					sub_key: KEY.to_owned(),
+					value_name: "UserName".to_owned(),
+				}),
+			(|reg| reg.delete_value(&HKEY::CURRENT_USER, KEY, Some("type")),
+				RegistryMapError::Missing {
+					sub_key: KEY.to_owned(),
+					value_name: "type".to_owned(),
+				}),
+			(|reg| reg.delete_tree(&HKEY::CURRENT_USER, "Software\\App\\main"),
+				RegistryMapError::Missing {
+					sub_key: KEY.to_owned(),
+					value_name: "main".to_owned(),
+				}),
+			(|reg| reg.delete_value(&HKEY::CURRENT_USER, "Software\\App\\Window", Some("Left")),
+				RegistryMapError::Missing {
+					sub_key: "Software\\App\\Window".to_owned(),
+					value_name: "Left".to_owned(),
+				}),
+			(|reg| reg.set_value(&HKEY::CURRENT_USER, KEY, Some("size"), RegistryValue::Dword(1)),
+				RegistryMapError::Mismatch {
+					sub_key: KEY.to_owned(),
+					value_name: "size".to_owned(),
+					expected: co::REG::QWORD,
+					found: co::REG::DWORD,
+				}),
+		];
+		for (idx, (change, expected)) in cases.iter().enumerate() {
+			let reg = MemoryRegistry::new();
+			config().write_registry(&reg, &root, KEY).unwrap();
+			change(&reg).unwrap();
+			let res = Config::read_registry(&reg, &root, KEY);
+			assert_eq!(res.as_ref().err(), Some(expected), "case {}", idx);
+		}
+
+		let reg = MemoryRegistry::new();
+		assert_eq!(Config::read_registry(&reg, &root, KEY).map_err(|e| e.error()),
+			Err(co::ERROR::FILE_NOT_FOUND));
+	}
+}
@@ -0,0 +1,255 @@
+use crate::co;
+use crate::decl::*;
+use crate::guard::*;
+use crate::prelude::*;
+
+/// Basic registry operations, addressed by a predefined root key and a subkey
+/// path, like [`HKEY::RegGetValue`](crate::prelude::advapi_Hkey::RegGetValue)
+/// and [`HKEY::RegSetKeyValue`](crate::prelude::advapi_Hkey::RegSetKeyValue)
+/// do.
+///
+/// Code written against this trait can run on the actual registry, with
+/// [`NativeRegistry`](crate::NativeRegistry), or on an in-memory tree, with
+/// [`MemoryRegistry`](crate::MemoryRegistry), which is useful for testing.
+///
+/// An empty `sub_key` refers to the root key itself. Errors follow the native
+/// functions: a missing key or value yields
+/// [`ERROR::FILE_NOT_FOUND`](crate::co::ERROR::FILE_NOT_FOUND).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// fn load_retries(reg: &impl w::RegistryProvider) -> w::SysResult<u32> {
+///     match reg.query_value(&w::HKEY::CURRENT_USER, "Software\\MyApp", Some("Retries")) {
+///         Ok(w::RegistryValue::Dword(n)) => Ok(n),
+///         Ok(_) => Err(co::ERROR::DATATYPE_MISMATCH),
+///         Err(co::ERROR::FILE_NOT_FOUND) => Ok(3),
+///         Err(e) => Err(e),
+///     }
+/// }
+///
+/// let retries = load_retries(&w::NativeRegistry::new())?;
+///
+/// let mem = w::MemoryRegistry::new();
+/// mem.create_key(&w::HKEY::CURRENT_USER, "Software\\MyApp")?;
+/// mem.set_value(&w::HKEY::CURRENT_USER, "Software\\MyApp",
+///     Some("Retries"), w::RegistryValue::Dword(5))?;
+/// assert_eq!(load_retries(&mem)?, 5);
+/// # w::SysResult::Ok(())
+/// ```
+pub trait RegistryProvider {
+	/// Creates the key, and all the intermediate keys, if they don't exist.
+	/// Returns whether the key was created or already existed.
+	fn create_key(&self, root: &HKEY, sub_key: &str) -> SysResult<co::REG_DISPOSITION>;
+
+	/// Opens the key to check whether it exists.
+	fn key_exists(&self, root: &HKEY, sub_key: &str) -> SysResult<bool>;
+
+	/// Returns the names of the subkeys of the key.
+	fn enum_keys(&self, root: &HKEY, sub_key: &str) -> SysResult<Vec<String>>;
+
+	/// Returns the names and types of the values of the key. The default
+	/// value has an empty name.
+	fn enum_values(&self, root: &HKEY, sub_key: &str) -> SysResult<Vec<(String, co::REG)>>;
+
+	/// Retrieves a value of the key. `None` is the default value.
+	fn query_value(&self,
+		root: &HKEY,
+		sub_key: &str,
+		value_name: Option<&str>,
+	) -> SysResult<RegistryValue>;
+
+	/// Creates or replaces a value of an existing key. `None` is the default
+	/// value.
+	fn set_value(&self,
+		root: &HKEY,
+		sub_key: &str,
+		value_name: Option<&str>,
+		data: RegistryValue,
+	) -> SysResult<()>;
+
+	/// Deletes a value of the key. `None` is the default value.
+	fn delete_value(&self,
+		root: &HKEY,
+		sub_key: &str,
+		value_name: Option<&str>,
+	) -> SysResult<()>;
+
+	/// Deletes the key and all its subkeys. If `sub_key` is empty, the root key
+	/// is kept, and only its values and subkeys are deleted.
+	fn delete_tree(&self, root: &HKEY, sub_key: &str) -> SysResult<()>;
+}
+
+/// [`RegistryProvider`](crate::prelude::RegistryProvider) implemented on the
+/// actual registry, through [`HKEY`](crate::HKEY).
+///
+/// If created with
+/// [`NativeRegistry::transacted`](crate::NativeRegistry::transacted), all
+/// operations are performed within the given
+/// [`HTRANSACTION`](crate::HTRANSACTION), using
+/// [`HKEY::RegOpenKeyTransacted`](crate::prelude::advapi_Hkey::RegOpenKeyTransacted),
+/// [`HKEY::RegCreateKeyTransacted`](crate::prelude::advapi_Hkey::RegCreateKeyTransacted)
+/// and
+/// [`HKEY::RegDeleteKeyTransacted`](crate::prelude::advapi_Hkey::RegDeleteKeyTransacted).
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let htr = w::HTRANSACTION::CreateTransaction(None, None, None, "setup")?;
+///
+/// let reg = w::NativeRegistry::transacted(&htr);
+/// reg.create_key(&w::HKEY::CURRENT_USER, "Software\\MyApp")?;
+/// reg.set_value(&w::HKEY::CURRENT_USER, "Software\\MyApp",
+///     Some("Name"), w::RegistryValue::Sz("foo".to_owned()))?;
+///
+/// htr.CommitTransaction()?;
+/// # w::SysResult::Ok(())
+/// ```
+#[derive(Clone, Copy, Default)]
+pub struct NativeRegistry<'a> {
+	htransaction: Option<&'a HTRANSACTION>,
+}
+
+impl<'a> NativeRegistry<'a> {
+	/// Creates a new `NativeRegistry`, which performs the operations directly.
+	#[must_use]
+	pub const fn new() -> Self {
+		Self { htransaction: None }
+	}
+
+	/// Creates a new `NativeRegistry`, which performs the operations within
+	/// the given transaction.
+	#[must_use]
+	pub const fn transacted(htransaction: &'a HTRANSACTION) -> Self {
+		Self { htransaction: Some(htransaction) }
+	}
+
+	fn open(&self,
+		root: &HKEY,
+		sub_key: &str,
+		access_rights: co::KEY,
+	) -> SysResult<RegCloseKeyGuard>
+	{
+		match self.htransaction {
+			Some(htr) => root.RegOpenKeyTransacted(
+				sub_key, co::REG_OPTION::default(), access_rights, htr),
+			None => root.RegOpenKeyEx(
+				Some(sub_key), co::REG_OPTION::default(), access_rights),
+		}
+	}
+
+	/// Recursively deletes the subkeys; there is no transacted version of
+	/// `RegDeleteTree`.
+	fn delete_subkeys_transacted(
+		hkey: &HKEY,
+		htr: &HTRANSACTION,
+	) -> SysResult<()>
+	{
+		let sub_key_names = hkey.RegEnumKeyEx()?
+			.collect::<SysResult<Vec<_>>>()?;
+		for sub_key_name in sub_key_names.iter() {
+			let hsub = hkey.RegOpenKeyTransacted(sub_key_name,
+				co::REG_OPTION::default(), co::KEY::READ | co::KEY::SET_VALUE, htr)?;
+			Self::delete_subkeys_transacted(&hsub, htr)?;
+			hkey.RegDeleteKeyTransacted(sub_key_name, co::KEY::default(), htr)?;
+		}
+		Ok(())
+	}
+}
+
+impl<'a> RegistryProvider for NativeRegistry<'a> {
+	fn create_key(&self, root: &HKEY, sub_key: &str) -> SysResult<co::REG_DISPOSITION> {
+		let (_, disposition) = match self.htransaction {
+			Some(htr) => root.RegCreateKeyTransacted(sub_key, None,
+				co::REG_OPTION::default(), co::KEY::READ, None, htr)?,
+			None => root.RegCreateKeyEx(sub_key, None,
+				co::REG_OPTION::default(), co::KEY::READ, None)?,
+		};
+		Ok(disposition)
+	}
+
+	fn key_exists(&self, root: &HKEY, sub_key: &str) -> SysResult<bool> {
+		match self.open(root, sub_key, co::KEY::QUERY_VALUE) {
+			Ok(_) => Ok(true),
+			Err(co::ERROR::FILE_NOT_FOUND) => Ok(false),
+			Err(e) => Err(e),
+		}
+	}
+
+	fn enum_keys(&self, root: &HKEY, sub_key: &str) -> SysResult<Vec<String>> {
+		self.open(root, sub_key, co::KEY::ENUMERATE_SUB_KEYS | co::KEY::QUERY_VALUE)?
+			.RegEnumKeyEx()?
+			.collect()
+	}
+
+	fn enum_values(&self, root: &HKEY, sub_key: &str) -> SysResult<Vec<(String, co::REG)>> {
+		self.open(root, sub_key, co::KEY::QUERY_VALUE)?
+			.RegEnumValue()?
+			.collect()
+	}
+
+	fn query_value(&self,
+		root: &HKEY,
+		sub_key: &str,
+		value_name: Option<&str>,
+	) -> SysResult<RegistryValue>
+	{
+		self.open(root, sub_key, co::KEY::QUERY_VALUE)?
+			.RegQueryValueEx(value_name)
+	}
+
+	fn set_value(&self,
+		root: &HKEY,
+		sub_key: &str,
+		value_name: Option<&str>,
+		data: RegistryValue,
+	) -> SysResult<()>
+	{
+		self.open(root, sub_key, co::KEY::SET_VALUE)?
+			.RegSetValueEx(value_name, data)
+	}
+
+	fn delete_value(&self,
+		root: &HKEY,
+		sub_key: &str,
+		value_name: Option<&str>,
+	) -> SysResult<()>
+	{
+		self.open(root, sub_key, co::KEY::SET_VALUE)?
+			.RegDeleteValue(value_name)
+	}
+
+	fn delete_tree(&self, root: &HKEY, sub_key: &str) -> SysResult<()> {
+		let htr = match self.htransaction {
+			Some(htr) => htr,
+			None => return root.RegDeleteTree(
+				if sub_key.is_empty() { None } else { Some(sub_key) }),
+		};
+
+		let hkey = self.open(root, sub_key, co::KEY::READ | co::KEY::SET_VALUE)?;
+		Self::delete_subkeys_transacted(&hkey, htr)?;
+
+		if sub_key.is_empty() { // keep the key, delete the values
+			let value_names = hkey.RegEnumValue()?
+				.map(|name_type| name_type.map(|(name, _)| name))
+				.collect::<SysResult<Vec<_>>>()?;
+			for value_name in value_names.iter() {
+				hkey.RegDeleteValue(Some(value_name))?;
+			}
+			Ok(())
+		} else {
+			drop(hkey);
+			root.RegDeleteKeyTransacted(sub_key, co::KEY::default(), htr)
+		}
+	}
+}
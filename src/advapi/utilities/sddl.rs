@@ -0,0 +1,636 @@
+use std::str::FromStr;
+
+use crate::advapi::utilities::security_descriptor::is_object_ace;
+use crate::co;
+use crate::decl::*;
+use crate::prelude::*;
+
+/// SDDL account aliases. Those relative to a domain require a domain SID.
+const SID_ALIASES: &[(&str, co::WELL_KNOWN_SID_TYPE)] = &[
+	("AA", co::WELL_KNOWN_SID_TYPE::BuiltinAccessControlAssistanceOperators),
+	("AC", co::WELL_KNOWN_SID_TYPE::BuiltinAnyPackage),
+	("AN", co::WELL_KNOWN_SID_TYPE::Anonymous),
+	("AO", co::WELL_KNOWN_SID_TYPE::BuiltinAccountOperators),
+	("AP", co::WELL_KNOWN_SID_TYPE::AccountProtectedUsers),
+	("AS", co::WELL_KNOWN_SID_TYPE::AuthenticationAuthorityAsserted),
+	("AU", co::WELL_KNOWN_SID_TYPE::AuthenticatedUser),
+	("BA", co::WELL_KNOWN_SID_TYPE::BuiltinAdministrators),
+	("BG", co::WELL_KNOWN_SID_TYPE::BuiltinGuests),
+	("BO", co::WELL_KNOWN_SID_TYPE::BuiltinBackupOperators),
+	("BU", co::WELL_KNOWN_SID_TYPE::BuiltinUsers),
+	("CA", co::WELL_KNOWN_SID_TYPE::AccountCertAdmins),
+	("CD", co::WELL_KNOWN_SID_TYPE::BuiltinCertSvcDComAccessGroup),
+	("CG", co::WELL_KNOWN_SID_TYPE::CreatorGroup),
+	("CN", co::WELL_KNOWN_SID_TYPE::AccountCloneableControllers),
+	("CO", co::WELL_KNOWN_SID_TYPE::CreatorOwner),
+	("CY", co::WELL_KNOWN_SID_TYPE::BuiltinCryptoOperators),
+	("DA", co::WELL_KNOWN_SID_TYPE::AccountDomainAdmins),
+	("DC", co::WELL_KNOWN_SID_TYPE::AccountComputers),
+	("DD", co::WELL_KNOWN_SID_TYPE::AccountControllers),
+	("DG", co::WELL_KNOWN_SID_TYPE::AccountDomainGuests),
+	("DU", co::WELL_KNOWN_SID_TYPE::AccountDomainUsers),
+	("EA", co::WELL_KNOWN_SID_TYPE::AccountEnterpriseAdmins),
+	("ED", co::WELL_KNOWN_SID_TYPE::EnterpriseControllers),
+	("EK", co::WELL_KNOWN_SID_TYPE::AccountEnterpriseKeyAdmins),
+	("ER", co::WELL_KNOWN_SID_TYPE::BuiltinEventLogReadersGroup),
+	("ES", co::WELL_KNOWN_SID_TYPE::BuiltinRDSEndpointServers),
+	("HA", co::WELL_KNOWN_SID_TYPE::BuiltinHyperVAdmins),
+	("HI", co::WELL_KNOWN_SID_TYPE::HighLabel),
+	("IS", co::WELL_KNOWN_SID_TYPE::BuiltinIUsers),
+	("IU", co::WELL_KNOWN_SID_TYPE::Interactive),
+	("KA", co::WELL_KNOWN_SID_TYPE::AccountKeyAdmins),
+	("LA", co::WELL_KNOWN_SID_TYPE::AccountAdministrator),
+	("LG", co::WELL_KNOWN_SID_TYPE::AccountGuest),
+	("LS", co::WELL_KNOWN_SID_TYPE::LocalService),
+	("LU", co::WELL_KNOWN_SID_TYPE::BuiltinPerfLoggingUsers),
+	("LW", co::WELL_KNOWN_SID_TYPE::LowLabel),
+	("ME", co::WELL_KNOWN_SID_TYPE::MediumLabel),
+	("MP", co::WELL_KNOWN_SID_TYPE::MediumPlusLabel),
+	("MS", co::WELL_KNOWN_SID_TYPE::BuiltinRDSManagementServers),
+	("MU", co::WELL_KNOWN_SID_TYPE::BuiltinPerfMonitoringUsers),
+	("NO", co::WELL_KNOWN_SID_TYPE::BuiltinNetworkConfigurationOperators),
+	("NS", co::WELL_KNOWN_SID_TYPE::NetworkService),
+	("NU", co::WELL_KNOWN_SID_TYPE::Network),
+	("OW", co::WELL_KNOWN_SID_TYPE::CreatorOwnerRights),
+	("PA", co::WELL_KNOWN_SID_TYPE::AccountPolicyAdmins),
+	("PO", co::WELL_KNOWN_SID_TYPE::BuiltinPrintOperators),
+	("PS", co::WELL_KNOWN_SID_TYPE::SelfSid),
+	("PU", co::WELL_KNOWN_SID_TYPE::BuiltinPowerUsers),
+	("RA", co::WELL_KNOWN_SID_TYPE::BuiltinRDSRemoteAccessServers),
+	("RC", co::WELL_KNOWN_SID_TYPE::RestrictedCode),
+	("RD", co::WELL_KNOWN_SID_TYPE::BuiltinRemoteDesktopUsers),
+	("RE", co::WELL_KNOWN_SID_TYPE::BuiltinReplicator),
+	("RM", co::WELL_KNOWN_SID_TYPE::BuiltinRemoteManagementUsers),
+	("RO", co::WELL_KNOWN_SID_TYPE::NewEnterpriseReadonlyControllers),
+	("RS", co::WELL_KNOWN_SID_TYPE::AccountRasAndIasServers),
+	("RU", co::WELL_KNOWN_SID_TYPE::BuiltinPreWindows2000CompatibleAccess),
+	("SA", co::WELL_KNOWN_SID_TYPE::AccountSchemaAdmins),
+	("SI", co::WELL_KNOWN_SID_TYPE::SystemLabel),
+	("SO", co::WELL_KNOWN_SID_TYPE::BuiltinSystemOperators),
+	("SS", co::WELL_KNOWN_SID_TYPE::AuthenticationServiceAsserted),
+	("SU", co::WELL_KNOWN_SID_TYPE::Service),
+	("SY", co::WELL_KNOWN_SID_TYPE::LocalSystem),
+	("UD", co::WELL_KNOWN_SID_TYPE::UserModeDrivers),
+	("WD", co::WELL_KNOWN_SID_TYPE::World),
+	("WR", co::WELL_KNOWN_SID_TYPE::WriteRestrictedCode),
+];
+
+/// SDDL ACE types; the callback ones, which require conditional expressions,
+/// are not supported.
+const ACE_TYPES: &[(&str, co::ACE_TYPE)] = &[
+	("A", co::ACE_TYPE::ACCESS_ALLOWED),
+	("D", co::ACE_TYPE::ACCESS_DENIED),
+	("AU", co::ACE_TYPE::SYSTEM_AUDIT),
+	("AL", co::ACE_TYPE::SYSTEM_ALARM),
+	("OA", co::ACE_TYPE::ACCESS_ALLOWED_OBJECT),
+	("OD", co::ACE_TYPE::ACCESS_DENIED_OBJECT),
+	("OU", co::ACE_TYPE::SYSTEM_AUDIT_OBJECT),
+	("OL", co::ACE_TYPE::SYSTEM_ALARM_OBJECT),
+	("ML", co::ACE_TYPE::SYSTEM_MANDATORY_LABEL),
+	("SP", co::ACE_TYPE::SYSTEM_SCOPED_POLICY_ID),
+	("TL", co::ACE_TYPE::SYSTEM_PROCESS_TRUST_LABEL),
+];
+
+/// SDDL ACE flags.
+const ACE_FLAGS: &[(&str, co::ACE)] = &[
+	("OI", co::ACE::OBJECT_INHERIT),
+	("CI", co::ACE::CONTAINER_INHERIT),
+	("NP", co::ACE::NO_PROPAGATE_INHERIT),
+	("IO", co::ACE::INHERIT_ONLY),
+	("ID", co::ACE::INHERITED),
+	("CR", co::ACE::CRITICAL),
+	("SA", co::ACE::SUCCESSFUL_ACCESS),
+	("FA", co::ACE::FAILED_ACCESS),
+];
+
+/// SDDL access rights which stand for a combination of bits, emitted only on
+/// exact matches.
+const RIGHTS_COMBINED: &[(&str, u32)] = &[
+	("FA", 0x001f_01ff),
+	("FR", 0x0012_0089),
+	("FW", 0x0012_0116),
+	("FX", 0x0012_00a0),
+	("KA", 0x000f_003f),
+	("KR", 0x0002_0019),
+	("KW", 0x0002_0006),
+	("KX", 0x0002_0019),
+];
+
+/// SDDL access rights of single bits.
+const RIGHTS_BITS: &[(&str, u32)] = &[
+	("GA", 0x1000_0000),
+	("GR", 0x8000_0000),
+	("GW", 0x4000_0000),
+	("GX", 0x2000_0000),
+	("RC", 0x0002_0000),
+	("SD", 0x0001_0000),
+	("WD", 0x0004_0000),
+	("WO", 0x0008_0000),
+	("RP", 0x0000_0010),
+	("WP", 0x0000_0020),
+	("CC", 0x0000_0001),
+	("DC", 0x0000_0002),
+	("LC", 0x0000_0004),
+	("SW", 0x0000_0008),
+	("LO", 0x0000_0080),
+	("DT", 0x0000_0040),
+	("CR", 0x0000_0100),
+];
+
+/// SDDL policies of mandatory label ACEs.
+const RIGHTS_LABEL: &[(&str, u32)] = &[
+	("NW", 0x1),
+	("NR", 0x2),
+	("NX", 0x4),
+];
+
+/// Splits a string into 2-char tokens, which are looked up in the tables.
+fn parse_tokens<T: Copy + std::ops::BitOr<Output = T>>(
+	s: &str,
+	tables: &[&[(&str, T)]],
+	zero: T,
+) -> Option<T>
+{
+	if s.len() % 2 != 0 || !s.is_ascii() {
+		return None;
+	}
+	(0..s.len()).step_by(2)
+		.try_fold(zero, |acc, idx| {
+			let tok = &s[idx..idx + 2];
+			tables.iter()
+				.find_map(|table| table.iter().find(|(name, _)| name.eq_ignore_ascii_case(tok)))
+				.map(|(_, val)| acc | *val)
+		})
+}
+
+fn parse_sid(s: &str, domain_sid: Option<&SID>) -> SysResult<SidBuf> {
+	let s = s.trim();
+	if s.len() > 2 && s.get(..2).is_some_and(|p| p.eq_ignore_ascii_case("S-")) {
+		return SidBuf::parse(s);
+	}
+	let sid_type = SID_ALIASES.iter()
+		.find(|(alias, _)| alias.eq_ignore_ascii_case(s))
+		.map(|(_, sid_type)| *sid_type)
+		.ok_or(co::ERROR::INVALID_SID)?;
+	SidBuf::well_known(sid_type, domain_sid)
+		.map_err(|_| co::ERROR::INVALID_SID)
+}
+
+fn format_sid(sid: &SidBuf, domain_sid: Option<&SID>, out: &mut String) {
+	let alias = sid.well_known_type()
+		.and_then(|sid_type| SID_ALIASES.iter().find(|(_, ty)| *ty == sid_type))
+		.filter(|(_, sid_type)| SidBuf::well_known(*sid_type, domain_sid)
+			.is_ok_and(|wk| wk == *sid)); // domain-relative ones must match the domain
+	match alias {
+		Some((alias, _)) => out.push_str(alias),
+		None => out.push_str(&sid.to_string()),
+	}
+}
+
+fn parse_guid(s: &str) -> SysResult<Option<GUID>> {
+	let s = s.trim();
+	if s.is_empty() {
+		return Ok(None);
+	}
+	let valid = s.len() == 36
+		&& s.char_indices().all(|(idx, ch)| match idx {
+			8 | 13 | 18 | 23 => ch == '-',
+			_ => ch.is_ascii_hexdigit(),
+		});
+	if valid {
+		Ok(Some(GUID::new(s)))
+	} else {
+		Err(co::ERROR::INVALID_ACL)
+	}
+}
+
+fn parse_rights(s: &str, ace_type: co::ACE_TYPE) -> SysResult<u32> {
+	let s = s.trim();
+	if let Some(hex) = s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+		return u32::from_str_radix(hex, 16).map_err(|_| co::ERROR::INVALID_ACL);
+	}
+	if s.starts_with(|ch: char| ch.is_ascii_digit()) {
+		return s.parse().map_err(|_| co::ERROR::INVALID_ACL);
+	}
+	let tables: &[&[(&str, u32)]] = if ace_type == co::ACE_TYPE::SYSTEM_MANDATORY_LABEL {
+		&[RIGHTS_LABEL]
+	} else {
+		&[RIGHTS_COMBINED, RIGHTS_BITS]
+	};
+	parse_tokens(s, tables, 0).ok_or(co::ERROR::INVALID_ACL)
+}
+
+fn format_rights(mask: u32, ace_type: co::ACE_TYPE, out: &mut String) {
+	let bits_table = if ace_type == co::ACE_TYPE::SYSTEM_MANDATORY_LABEL {
+		RIGHTS_LABEL
+	} else {
+		if let Some((name, _)) = RIGHTS_COMBINED.iter().find(|(_, val)| *val == mask) {
+			out.push_str(name);
+			return;
+		}
+		RIGHTS_BITS
+	};
+
+	let covered = bits_table.iter().fold(0, |acc, (_, val)| acc | val);
+	if mask & !covered != 0 {
+		out.push_str(&format!("0x{:x}", mask));
+	} else {
+		bits_table.iter()
+			.filter(|(_, val)| mask & val != 0)
+			.for_each(|(name, _)| out.push_str(name));
+	}
+}
+
+fn parse_ace(s: &str, domain_sid: Option<&SID>) -> SysResult<Ace> {
+	let fields = s.split(';').collect::<Vec<_>>();
+	if fields.len() != 6 {
+		return Err(co::ERROR::INVALID_ACL);
+	}
+	let ace_type = ACE_TYPES.iter()
+		.find(|(name, _)| name.eq_ignore_ascii_case(fields[0].trim()))
+		.map(|(_, ace_type)| *ace_type)
+		.ok_or(co::ERROR::INVALID_ACL)?;
+	let flags = parse_tokens(fields[1].trim(), &[ACE_FLAGS], co::ACE::default())
+		.ok_or(co::ERROR::INVALID_ACL)?;
+
+	let sid = parse_sid(fields[5], domain_sid)?;
+	let mut ace = Ace::new(ace_type, flags, parse_rights(fields[2], ace_type)?, &sid);
+	if is_object_ace(ace_type) {
+		ace.object_type = parse_guid(fields[3])?;
+		ace.inherited_object_type = parse_guid(fields[4])?;
+	} else if !fields[3].trim().is_empty() || !fields[4].trim().is_empty() {
+		return Err(co::ERROR::INVALID_ACL);
+	}
+	Ok(ace)
+}
+
+fn format_ace(ace: &Ace, domain_sid: Option<&SID>, out: &mut String) -> SysResult<()> {
+	let name = ACE_TYPES.iter()
+		.find(|(_, ace_type)| *ace_type == ace.ace_type)
+		.map(|(name, _)| *name)
+		.ok_or(co::ERROR::INVALID_ACL)?;
+	if !ace.application_data.is_empty() {
+		return Err(co::ERROR::INVALID_ACL);
+	}
+
+	out.push('(');
+	out.push_str(name);
+	out.push(';');
+	let mut flags_left = ace.flags;
+	for (name, flag) in ACE_FLAGS.iter() {
+		if ace.flags.has(*flag) {
+			out.push_str(name);
+			flags_left &= !*flag;
+		}
+	}
+	if flags_left != co::ACE::default() {
+		return Err(co::ERROR::INVALID_ACL);
+	}
+	out.push(';');
+	format_rights(ace.mask, ace.ace_type, out);
+	out.push(';');
+	if is_object_ace(ace.ace_type) {
+		if let Some(guid) = &ace.object_type {
+			out.push_str(&guid.to_string());
+		}
+		out.push(';');
+		if let Some(guid) = &ace.inherited_object_type {
+			out.push_str(&guid.to_string());
+		}
+		out.push(';');
+	} else {
+		out.push_str(";;");
+	}
+	format_sid(&ace.sid, domain_sid, out);
+	out.push(')');
+	Ok(())
+}
+
+/// Parses the contents of a `D:` or `S:` section; returns the ACL, which is
+/// `None` if NULL, and the control flags.
+fn parse_acl(
+	s: &str,
+	is_dacl: bool,
+	domain_sid: Option<&SID>,
+) -> SysResult<(Option<Acl>, co::SE)>
+{
+	let (protected, auto_inherited, auto_inherit_req, present) = if is_dacl {
+		(co::SE::DACL_PROTECTED, co::SE::DACL_AUTO_INHERITED,
+			co::SE::DACL_AUTO_INHERIT_REQ, co::SE::DACL_PRESENT)
+	} else {
+		(co::SE::SACL_PROTECTED, co::SE::SACL_AUTO_INHERITED,
+			co::SE::SACL_AUTO_INHERIT_REQ, co::SE::SACL_PRESENT)
+	};
+
+	let (mut flags, mut aces) = match s.find('(') {
+		Some(idx) => (s[..idx].trim(), s[idx..].trim()),
+		None => (s.trim(), ""),
+	};
+	let mut control = present;
+	let mut is_null = false;
+	while !flags.is_empty() {
+		let upper = flags.to_ascii_uppercase();
+		let (flag, len) = if upper.starts_with("NO_ACCESS_CONTROL") {
+			is_null = true;
+			(co::SE::default(), 17)
+		} else if upper.starts_with("AI") {
+			(auto_inherited, 2)
+		} else if upper.starts_with("AR") {
+			(auto_inherit_req, 2)
+		} else if upper.starts_with('P') {
+			(protected, 1)
+		} else {
+			return Err(co::ERROR::INVALID_ACL);
+		};
+		control |= flag;
+		flags = flags[len..].trim_start();
+	}
+
+	let mut acl = Acl::new();
+	while !aces.is_empty() {
+		let end = match (aces.starts_with('('), aces.find(')')) {
+			(true, Some(end)) => end,
+			_ => return Err(co::ERROR::INVALID_ACL),
+		};
+		acl.aces.push(parse_ace(&aces[1..end], domain_sid)?);
+		aces = aces[end + 1..].trim_start();
+	}
+
+	if is_null && !acl.aces.is_empty() {
+		return Err(co::ERROR::INVALID_ACL);
+	}
+	Ok((if is_null { None } else { Some(acl) }, control))
+}
+
+fn format_acl(
+	acl: Option<&Acl>,
+	control: co::SE,
+	is_dacl: bool,
+	domain_sid: Option<&SID>,
+	out: &mut String,
+) -> SysResult<()>
+{
+	let (protected, auto_inherited, auto_inherit_req) = if is_dacl {
+		(co::SE::DACL_PROTECTED, co::SE::DACL_AUTO_INHERITED, co::SE::DACL_AUTO_INHERIT_REQ)
+	} else {
+		(co::SE::SACL_PROTECTED, co::SE::SACL_AUTO_INHERITED, co::SE::SACL_AUTO_INHERIT_REQ)
+	};
+
+	out.push_str(if is_dacl { "D:" } else { "S:" });
+	if control.has(protected) { out.push('P'); }
+	if control.has(auto_inherit_req) { out.push_str("AR"); }
+	if control.has(auto_inherited) { out.push_str("AI"); }
+	match acl {
+		Some(acl) => acl.aces.iter()
+			.try_for_each(|ace| format_ace(ace, domain_sid, out)),
+		None => {
+			out.push_str("NO_ACCESS_CONTROL");
+			Ok(())
+		},
+	}
+}
+
+impl FromStr for SecurityDescriptor {
+	type Err = co::ERROR;
+
+	/// Parses an SDDL string, without a domain SID; see
+	/// [`SecurityDescriptor::parse_sddl`](crate::SecurityDescriptor::parse_sddl).
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Self::parse_sddl(s, None)
+	}
+}
+
+impl SecurityDescriptor {
+	/// Parses an
+	/// [SDDL](https://learn.microsoft.com/en-us/windows/win32/secauthz/security-descriptor-definition-language)
+	/// string, like
+	/// [`ConvertStringSecurityDescriptorToSecurityDescriptor`](https://learn.microsoft.com/en-us/windows/win32/api/sddl/nf-sddl-convertstringsecuritydescriptortosecuritydescriptorw),
+	/// but without calls to the system.
+	///
+	/// Account aliases relative to a domain, like `DA` or `LA`, are resolved
+	/// against `domain_sid`, and fail with
+	/// [`ERROR::INVALID_SID`](crate::co::ERROR::INVALID_SID) if it's `None`.
+	/// Conditional ACEs and resource attributes are not supported.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let sd = w::SecurityDescriptor::parse_sddl(
+	///     "O:BAG:SYD:PAI(A;OICI;FA;;;SY)(A;OICI;FA;;;BA)(A;OICI;FR;;;BU)",
+	///     None,
+	/// )?;
+	/// assert!(sd.control.has(co::SE::DACL_PROTECTED));
+	/// assert_eq!(sd.dacl.as_ref().unwrap().aces.len(), 3);
+	/// # w::SysResult::Ok(())
+	/// ```
+	pub fn parse_sddl(sddl: &str, domain_sid: Option<&SID>) -> SysResult<Self> {
+		// Each section starts with an O, G, D or S letter followed by a colon.
+		let mut sections = Vec::<(u8, usize)>::new();
+		let mut depth = 0;
+		for (idx, ch) in sddl.char_indices() {
+			match ch {
+				'(' => depth += 1,
+				')' if depth > 0 => depth -= 1,
+				':' if depth == 0 => match sddl.as_bytes()[..idx].last() {
+					Some(tag) if b"OGDS".contains(&tag.to_ascii_uppercase()) =>
+						sections.push((tag.to_ascii_uppercase(), idx - 1)),
+					_ => return Err(co::ERROR::INVALID_SECURITY_DESCR),
+				},
+				')' => return Err(co::ERROR::INVALID_SECURITY_DESCR),
+				_ => {},
+			}
+		}
+		if sections.first().is_some_and(|(_, start)| !sddl[..*start].trim().is_empty())
+			|| (sections.is_empty() && !sddl.trim().is_empty())
+		{
+			return Err(co::ERROR::INVALID_SECURITY_DESCR);
+		}
+
+		let mut sd = Self::new();
+		for (idx, (tag, start)) in sections.iter().enumerate() {
+			let end = sections.get(idx + 1).map_or(sddl.len(), |(_, end)| *end);
+			let content = sddl.get(start + 2..end)
+				.ok_or(co::ERROR::INVALID_SECURITY_DESCR)?;
+			match tag {
+				b'O' if sd.owner.is_none() => sd.owner = Some(parse_sid(content, domain_sid)?),
+				b'G' if sd.group.is_none() => sd.group = Some(parse_sid(content, domain_sid)?),
+				b'D' if sd.dacl.is_none() && !sd.control.has(co::SE::DACL_PRESENT) => {
+					let (acl, control) = parse_acl(content, true, domain_sid)?;
+					sd.dacl = acl;
+					sd.control |= control;
+				},
+				b'S' if sd.sacl.is_none() && !sd.control.has(co::SE::SACL_PRESENT) => {
+					let (acl, control) = parse_acl(content, false, domain_sid)?;
+					sd.sacl = acl;
+					sd.control |= control;
+				},
+				_ => return Err(co::ERROR::INVALID_SECURITY_DESCR),
+			}
+		}
+		Ok(sd)
+	}
+
+	/// Formats the security descriptor as an
+	/// [SDDL](https://learn.microsoft.com/en-us/windows/win32/secauthz/security-descriptor-definition-language)
+	/// string, like
+	/// [`ConvertSecurityDescriptorToStringSecurityDescriptor`](https://learn.microsoft.com/en-us/windows/win32/api/sddl/nf-sddl-convertsecuritydescriptortostringsecuritydescriptorw),
+	/// but without calls to the system.
+	///
+	/// Account aliases relative to a domain are emitted only for SIDs within
+	/// `domain_sid`.
+	///
+	/// Fails with [`ERROR::INVALID_ACL`](crate::co::ERROR::INVALID_ACL) if an
+	/// ACE has a type, flags or application data which can't be represented
+	/// in SDDL.
+	pub fn to_sddl(&self, domain_sid: Option<&SID>) -> SysResult<String> {
+		let mut out = String::new();
+		let control = self.effective_control();
+
+		if let Some(owner) = &self.owner {
+			out.push_str("O:");
+			format_sid(owner, domain_sid, &mut out);
+		}
+		if let Some(group) = &self.group {
+			out.push_str("G:");
+			format_sid(group, domain_sid, &mut out);
+		}
+		if control.has(co::SE::DACL_PRESENT) {
+			format_acl(self.dacl.as_ref(), control, true, domain_sid, &mut out)?;
+		}
+		if control.has(co::SE::SACL_PRESENT) {
+			format_acl(self.sacl.as_ref(), control, false, domain_sid, &mut out)?;
+		}
+		Ok(out)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	const DOMAIN: &str = "S-1-5-21-1-2-3";
+
+	#[test]
+	fn sddl_round_trip() {
+		let cases: &[(&str, &str)] = &[ // input, canonical output
+			("", ""),
+			("O:BAG:SY", "O:BAG:SY"),
+			("o:ba g:sy", "O:BAG:SY"),
+			("O:S-1-5-18", "O:SY"),
+			("O:S-1-5-21-1-2-3-1001", "O:S-1-5-21-1-2-3-1001"),
+			("O:BAG:SYD:PAI(A;OICI;FA;;;SY)(A;OICI;FA;;;BA)(A;OICI;FR;;;BU)",
+				"O:BAG:SYD:PAI(A;OICI;FA;;;SY)(A;OICI;FA;;;BA)(A;OICI;FR;;;BU)"),
+			("D:(A;;GRGW;;;WD)", "D:(A;;GRGW;;;WD)"),
+			("D:(A;;0x1234;;;WD)", "D:(A;;0x1234;;;WD)"),
+			("D:(A;;0x1f01ff;;;WD)", "D:(A;;FA;;;WD)"),
+			("D:(D;CIOI;KR;;;AN)", "D:(D;OICI;KR;;;AN)"),
+			("D:", "D:"),
+			("D:NO_ACCESS_CONTROL", "D:NO_ACCESS_CONTROL"),
+			("D:ARP", "D:PAR"),
+			("S:AI(AU;SAFA;RPWP;;;WD)(ML;;NWNR;;;LW)", "S:AI(AU;SAFA;RPWP;;;WD)(ML;;NWNR;;;LW)"),
+			("D:(OA;CI;RP;bf967a86-0de6-11d0-a285-00aa003049e2;;AU)",
+				"D:(OA;CI;RP;bf967a86-0de6-11d0-a285-00aa003049e2;;AU)"),
+		];
+
+		for (input, expected) in cases.iter() {
+			let sd = SecurityDescriptor::parse_sddl(input, None)
+				.unwrap_or_else(|e| panic!("parse_sddl({:?}): {}", input, e));
+			assert_eq!(sd.to_sddl(None).unwrap(), *expected, "to_sddl({:?})", input);
+			assert_eq!(expected.parse::<SecurityDescriptor>().unwrap(), sd, "parse({:?})", expected);
+		}
+	}
+
+	#[test]
+	fn sddl_domain() {
+		let domain = SidBuf::parse(DOMAIN).unwrap();
+		let sd = SecurityDescriptor::parse_sddl("O:DAG:DUD:(A;;GA;;;LA)", Some(&domain)).unwrap();
+		assert_eq!(sd.owner.as_ref().unwrap().to_string(), "S-1-5-21-1-2-3-512");
+		assert_eq!(sd.to_sddl(Some(&domain)).unwrap(), "O:DAG:DUD:(A;;GA;;;LA)");
+		assert_eq!(sd.to_sddl(None).unwrap(),
+			"O:S-1-5-21-1-2-3-512G:S-1-5-21-1-2-3-513D:(A;;GA;;;S-1-5-21-1-2-3-500)");
+
+		let other = SidBuf::parse("S-1-5-21-4-5-6").unwrap();
+		assert_eq!(sd.to_sddl(Some(&other)).unwrap(),
+			"O:S-1-5-21-1-2-3-512G:S-1-5-21-1-2-3-513D:(A;;GA;;;S-1-5-21-1-2-3-500)");
+		assert_eq!(SecurityDescriptor::parse_sddl("O:DA", None), Err(co::ERROR::INVALID_SID));
+	}
+
+	#[test]
+	fn sddl_malformed() {
+		let cases: &[(&str, co::ERROR)] = &[
+			("O::", co::ERROR::INVALID_SECURITY_DESCR),
+			("€:", co::ERROR::INVALID_SECURITY_DESCR),
+			("O:€", co::ERROR::INVALID_SID),
+			("O:S€", co::ERROR::INVALID_SID),
+			(":", co::ERROR::INVALID_SECURITY_DESCR),
+			("X:BA", co::ERROR::INVALID_SECURITY_DESCR),
+			("BA", co::ERROR::INVALID_SECURITY_DESCR),
+			("xO:BA", co::ERROR::INVALID_SECURITY_DESCR),
+			("O:BAO:SY", co::ERROR::INVALID_SECURITY_DESCR),
+			("D:)(", co::ERROR::INVALID_SECURITY_DESCR),
+			("O:", co::ERROR::INVALID_SID),
+			("O:XX", co::ERROR::INVALID_SID),
+			("O:S-2-5-18", co::ERROR::INVALID_SID),
+			("D:(A;;FA;;WD)", co::ERROR::INVALID_ACL),
+			("D:(X;;FA;;;WD)", co::ERROR::INVALID_ACL),
+			("D:(A;XX;FA;;;WD)", co::ERROR::INVALID_ACL),
+			("D:(A;;FAX;;;WD)", co::ERROR::INVALID_ACL),
+			("D:(A;;FA;;;WD", co::ERROR::INVALID_ACL),
+			("D:(A;;FA;bf967a86-0de6-11d0-a285-00aa003049e2;;WD)", co::ERROR::INVALID_ACL),
+			("D:(OA;;FA;not-a-guid;;WD)", co::ERROR::INVALID_ACL),
+			("D:NO_ACCESS_CONTROL(A;;FA;;;WD)", co::ERROR::INVALID_ACL),
+			("D:Q", co::ERROR::INVALID_ACL),
+		];
+
+		for (input, expected) in cases.iter() {
+			assert_eq!(SecurityDescriptor::parse_sddl(input, None), Err(*expected),
+				"parse_sddl({:?})", input);
+		}
+	}
+
+	#[test]
+	fn binary_round_trip() {
+		let cases = [
+			"",
+			"O:BAG:SY",
+			"O:BAG:SYD:PAI(A;OICI;FA;;;SY)(A;OICI;FA;;;BA)(A;OICI;FR;;;BU)",
+			"D:NO_ACCESS_CONTROL",
+			"D:",
+			"O:SYS:AI(AU;SAFA;RPWP;;;WD)(ML;;NW;;;LW)",
+			"D:(OA;CI;RP;bf967a86-0de6-11d0-a285-00aa003049e2;bf967aba-0de6-11d0-a285-00aa003049e2;AU)",
+		];
+
+		for sddl in cases {
+			let sd = sddl.parse::<SecurityDescriptor>().unwrap();
+			let bytes = sd.to_bytes().unwrap();
+			assert_eq!(SecurityDescriptor::from_bytes(&bytes).unwrap(), sd, "from_bytes({:?})", sddl);
+			assert_eq!(sd.to_buf().unwrap().as_bytes(), &bytes[..], "to_buf({:?})", sddl);
+		}
+	}
+
+	#[test]
+	fn binary_malformed() {
+		let good = "O:BAD:(A;;FA;;;WD)".parse::<SecurityDescriptor>().unwrap()
+			.to_bytes().unwrap();
+
+		assert!(SecurityDescriptor::from_bytes(&[]).is_err());
+		assert!(SecurityDescriptor::from_bytes(&good[..19]).is_err());
+		for len in 20..good.len() {
+			assert!(SecurityDescriptor::from_bytes(&good[..len]).is_err(), "truncated to {}", len);
+		}
+
+		let mut bad = good.clone();
+		bad[0] = 2; // revision
+		assert_eq!(SecurityDescriptor::from_bytes(&bad), Err(co::ERROR::INVALID_SECURITY_DESCR));
+
+		let mut bad = good.clone();
+		bad[3] &= !0x80; // not self-relative
+		assert_eq!(SecurityDescriptor::from_bytes(&bad), Err(co::ERROR::INVALID_SECURITY_DESCR));
+
+		let mut bad = good.clone();
+		bad[4..8].copy_from_slice(&0xffff_fff0_u32.to_le_bytes()); // owner offset
+		assert!(SecurityDescriptor::from_bytes(&bad).is_err());
+	}
+}
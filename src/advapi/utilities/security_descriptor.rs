@@ -0,0 +1,444 @@
+use crate::co;
+use crate::decl::*;
+use crate::prelude::*;
+
+/// Revision of an [`ACL`](crate::ACL) without object ACEs.
+const ACL_REVISION: u8 = 2;
+/// Revision of an [`ACL`](crate::ACL) with object ACEs.
+const ACL_REVISION_DS: u8 = 4;
+
+/// `ACE_OBJECT_TYPE_PRESENT` flag of object ACEs.
+const OBJECT_TYPE_PRESENT: u32 = 0x1;
+/// `ACE_INHERITED_OBJECT_TYPE_PRESENT` flag of object ACEs.
+const INHERITED_OBJECT_TYPE_PRESENT: u32 = 0x2;
+
+/// Tells whether the ACE type carries object type GUIDs.
+pub(in crate::advapi) fn is_object_ace(ace_type: co::ACE_TYPE) -> bool {
+	matches!(ace_type,
+		co::ACE_TYPE::ACCESS_ALLOWED_OBJECT
+		| co::ACE_TYPE::ACCESS_DENIED_OBJECT
+		| co::ACE_TYPE::SYSTEM_AUDIT_OBJECT
+		| co::ACE_TYPE::SYSTEM_ALARM_OBJECT
+		| co::ACE_TYPE::ACCESS_ALLOWED_CALLBACK_OBJECT
+		| co::ACE_TYPE::ACCESS_DENIED_CALLBACK_OBJECT
+		| co::ACE_TYPE::SYSTEM_AUDIT_CALLBACK_OBJECT
+		| co::ACE_TYPE::SYSTEM_ALARM_CALLBACK_OBJECT)
+}
+
+fn read_u16(data: &[u8], off: usize) -> Option<u16> {
+	data.get(off..off + 2).map(|b| u16::from_le_bytes([b[0], b[1]]))
+}
+
+fn read_u32(data: &[u8], off: usize) -> Option<u32> {
+	data.get(off..off + 4).map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+}
+
+fn read_guid(data: &[u8], off: usize) -> Option<GUID> {
+	data.get(off..off + 16).map(|b| {
+		let mut raw = [0u8; 16];
+		raw.copy_from_slice(b);
+		unsafe { std::mem::transmute::<[u8; 16], GUID>(raw) }
+	})
+}
+
+/// An owned access control entry, the binary counterpart of the
+/// [`ACE_HEADER`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-ace_header)
+/// family of structs.
+///
+/// The object type GUIDs are only meaningful to object ACE types, like
+/// [`ACE_TYPE::ACCESS_ALLOWED_OBJECT`](crate::co::ACE_TYPE::ACCESS_ALLOWED_OBJECT),
+/// and they are ignored for the other types.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Ace {
+	pub ace_type: co::ACE_TYPE,
+	pub flags: co::ACE,
+	/// Access mask, or policy of a mandatory label ACE.
+	pub mask: u32,
+	pub object_type: Option<GUID>,
+	pub inherited_object_type: Option<GUID>,
+	pub sid: SidBuf,
+	/// Data stored after the SID, as in callback ACEs.
+	pub application_data: Vec<u8>,
+}
+
+impl Ace {
+	/// Creates a new ACE of any type, without object types or application
+	/// data.
+	#[must_use]
+	pub fn new(
+		ace_type: co::ACE_TYPE,
+		flags: co::ACE,
+		mask: impl Into<u32>,
+		sid: &SID,
+	) -> Self
+	{
+		Self {
+			ace_type,
+			flags,
+			mask: mask.into(),
+			object_type: None,
+			inherited_object_type: None,
+			sid: SidBuf::from_sid(sid),
+			application_data: Vec::new(),
+		}
+	}
+
+	/// Creates a new
+	/// [`ACE_TYPE::ACCESS_ALLOWED`](crate::co::ACE_TYPE::ACCESS_ALLOWED) ACE.
+	#[must_use]
+	pub fn allow(sid: &SID, mask: impl Into<u32>, flags: co::ACE) -> Self {
+		Self::new(co::ACE_TYPE::ACCESS_ALLOWED, flags, mask, sid)
+	}
+
+	/// Creates a new
+	/// [`ACE_TYPE::ACCESS_DENIED`](crate::co::ACE_TYPE::ACCESS_DENIED) ACE.
+	#[must_use]
+	pub fn deny(sid: &SID, mask: impl Into<u32>, flags: co::ACE) -> Self {
+		Self::new(co::ACE_TYPE::ACCESS_DENIED, flags, mask, sid)
+	}
+
+	/// Creates a new
+	/// [`ACE_TYPE::SYSTEM_AUDIT`](crate::co::ACE_TYPE::SYSTEM_AUDIT) ACE.
+	///
+	/// The flags should contain
+	/// [`ACE::SUCCESSFUL_ACCESS`](crate::co::ACE::SUCCESSFUL_ACCESS),
+	/// [`ACE::FAILED_ACCESS`](crate::co::ACE::FAILED_ACCESS), or both.
+	#[must_use]
+	pub fn audit(sid: &SID, mask: impl Into<u32>, flags: co::ACE) -> Self {
+		Self::new(co::ACE_TYPE::SYSTEM_AUDIT, flags, mask, sid)
+	}
+
+	/// Creates a new
+	/// [`ACE_TYPE::SYSTEM_MANDATORY_LABEL`](crate::co::ACE_TYPE::SYSTEM_MANDATORY_LABEL)
+	/// ACE, whose SID is an integrity level, like
+	/// [`WELL_KNOWN_SID_TYPE::LowLabel`](crate::co::WELL_KNOWN_SID_TYPE::LowLabel).
+	#[must_use]
+	pub fn mandatory_label(
+		label_sid: &SID,
+		policy: co::SYSTEM_MANDATORY_LABEL,
+		flags: co::ACE,
+	) -> Self
+	{
+		Self::new(co::ACE_TYPE::SYSTEM_MANDATORY_LABEL, flags, policy, label_sid)
+	}
+
+	/// Parses an ACE from its binary form, returning it and its size in bytes.
+	fn from_bytes(data: &[u8]) -> SysResult<(Self, usize)> {
+		let bad = || co::ERROR::INVALID_ACL;
+		let ace_type = unsafe { co::ACE_TYPE::from_raw(*data.first().ok_or_else(bad)?) };
+		let flags = unsafe { co::ACE::from_raw(*data.get(1).ok_or_else(bad)?) };
+		let size = read_u16(data, 2).ok_or_else(bad)? as usize;
+		if size < 8 || size > data.len() {
+			return Err(bad());
+		}
+		let data = &data[..size];
+		let mask = read_u32(data, 4).ok_or_else(bad)?;
+
+		let mut off = 8;
+		let mut object_type = None;
+		let mut inherited_object_type = None;
+		if is_object_ace(ace_type) {
+			let obj_flags = read_u32(data, off).ok_or_else(bad)?;
+			off += 4;
+			if obj_flags & OBJECT_TYPE_PRESENT != 0 {
+				object_type = Some(read_guid(data, off).ok_or_else(bad)?);
+				off += 16;
+			}
+			if obj_flags & INHERITED_OBJECT_TYPE_PRESENT != 0 {
+				inherited_object_type = Some(read_guid(data, off).ok_or_else(bad)?);
+				off += 16;
+			}
+		}
+
+		let sid = SidBuf::from_bytes(&data[off..]).map_err(|_| bad())?;
+		off += sid.as_bytes().len();
+
+		Ok((Self {
+			ace_type,
+			flags,
+			mask,
+			object_type,
+			inherited_object_type,
+			sid,
+			application_data: data[off..].to_vec(),
+		}, size))
+	}
+
+	/// Appends the binary form of the ACE.
+	fn write_bytes(&self, buf: &mut Vec<u8>) -> SysResult<()> {
+		let start = buf.len();
+		buf.push(self.ace_type.raw());
+		buf.push(self.flags.raw());
+		buf.extend_from_slice(&[0, 0]); // size, filled below
+		buf.extend_from_slice(&self.mask.to_le_bytes());
+
+		if is_object_ace(self.ace_type) {
+			let mut obj_flags = 0;
+			if self.object_type.is_some() { obj_flags |= OBJECT_TYPE_PRESENT; }
+			if self.inherited_object_type.is_some() { obj_flags |= INHERITED_OBJECT_TYPE_PRESENT; }
+			buf.extend_from_slice(&u32::to_le_bytes(obj_flags));
+			for guid in [&self.object_type, &self.inherited_object_type].into_iter().flatten() {
+				buf.extend_from_slice(&unsafe { std::mem::transmute::<GUID, [u8; 16]>(*guid) });
+			}
+		}
+
+		buf.extend_from_slice(self.sid.as_bytes());
+		buf.extend_from_slice(&self.application_data);
+		while (buf.len() - start) % 4 != 0 {
+			buf.push(0); // ACEs are DWORD-aligned
+		}
+		let size = u16::try_from(buf.len() - start).map_err(|_| co::ERROR::INVALID_ACL)?;
+		buf[start + 2..start + 4].copy_from_slice(&size.to_le_bytes());
+		Ok(())
+	}
+}
+
+/// An owned access control list, the binary counterpart of the
+/// [`ACL`](crate::ACL) struct.
+#[derive(Clone, Default, PartialEq, Eq, Debug)]
+pub struct Acl {
+	pub aces: Vec<Ace>,
+}
+
+impl From<Vec<Ace>> for Acl {
+	fn from(aces: Vec<Ace>) -> Self {
+		Self { aces }
+	}
+}
+
+impl Acl {
+	/// Creates a new, empty ACL, which denies all access when used as a DACL.
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Parses an ACL from its binary form, which starts with an
+	/// [`ACL`](crate::ACL) header.
+	pub fn from_bytes(data: &[u8]) -> SysResult<Self> {
+		let bad = || co::ERROR::INVALID_ACL;
+		let revision = *data.first().ok_or_else(bad)?;
+		if !(ACL_REVISION..=ACL_REVISION_DS).contains(&revision) {
+			return Err(bad());
+		}
+		let size = read_u16(data, 2).ok_or_else(bad)? as usize;
+		let count = read_u16(data, 4).ok_or_else(bad)?;
+		if size < 8 || size > data.len() {
+			return Err(bad());
+		}
+
+		let mut off = 8;
+		let mut aces = Vec::with_capacity(count as _);
+		for _ in 0..count {
+			let (ace, ace_size) = Ace::from_bytes(&data[off..size])?;
+			aces.push(ace);
+			off += ace_size;
+		}
+		Ok(Self { aces })
+	}
+
+	/// Returns the binary form of the ACL.
+	///
+	/// Fails with [`ERROR::INVALID_ACL`](crate::co::ERROR::INVALID_ACL) if the
+	/// ACL, or any of its ACEs, is larger than 65,535 bytes.
+	pub fn to_bytes(&self) -> SysResult<Vec<u8>> {
+		let mut buf = Vec::new();
+		self.write_bytes(&mut buf)?;
+		Ok(buf)
+	}
+
+	fn write_bytes(&self, buf: &mut Vec<u8>) -> SysResult<()> {
+		let start = buf.len();
+		let revision = if self.aces.iter().any(|ace| is_object_ace(ace.ace_type)) {
+			ACL_REVISION_DS
+		} else {
+			ACL_REVISION
+		};
+		buf.extend_from_slice(&[revision, 0, 0, 0]); // size filled below
+		let count = u16::try_from(self.aces.len()).map_err(|_| co::ERROR::INVALID_ACL)?;
+		buf.extend_from_slice(&count.to_le_bytes());
+		buf.extend_from_slice(&[0, 0]);
+		for ace in self.aces.iter() {
+			ace.write_bytes(buf)?;
+		}
+		let size = u16::try_from(buf.len() - start).map_err(|_| co::ERROR::INVALID_ACL)?;
+		buf[start + 2..start + 4].copy_from_slice(&size.to_le_bytes());
+		Ok(())
+	}
+}
+
+/// An owned security descriptor, which can be converted to and from
+/// [SDDL](https://learn.microsoft.com/en-us/windows/win32/secauthz/security-descriptor-definition-language)
+/// strings and self-relative binary descriptors, without calls to the system.
+///
+/// A DACL or SACL which is `None` is absent, unless the corresponding
+/// [`SE::DACL_PRESENT`](crate::co::SE::DACL_PRESENT) or
+/// [`SE::SACL_PRESENT`](crate::co::SE::SACL_PRESENT) flag is set in `control`,
+/// in which case it's a NULL ACL – a NULL DACL grants full access to everyone.
+///
+/// # Examples
+///
+/// Creating a named pipe accessible only to SYSTEM and administrators:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let sd: w::SecurityDescriptor = "D:P(A;;GA;;;SY)(A;;GA;;;BA)".parse()?;
+/// let mut sd_buf = sd.to_buf()?;
+///
+/// let mut sa = w::SECURITY_ATTRIBUTES::default();
+/// sa.set_lpSecurityDescriptor(Some(sd_buf.as_sd_mut()));
+/// # w::SysResult::Ok(())
+/// ```
+#[derive(Clone, Default, PartialEq, Eq, Debug)]
+pub struct SecurityDescriptor {
+	pub owner: Option<SidBuf>,
+	pub group: Option<SidBuf>,
+	pub control: co::SE,
+	pub dacl: Option<Acl>,
+	pub sacl: Option<Acl>,
+}
+
+impl SecurityDescriptor {
+	/// Creates a new, empty security descriptor.
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Returns the control flags as stored in the binary form, with the
+	/// `PRESENT` flags set according to the ACLs.
+	#[must_use]
+	pub fn effective_control(&self) -> co::SE {
+		let mut control = self.control;
+		if self.dacl.is_some() { control |= co::SE::DACL_PRESENT; }
+		if self.sacl.is_some() { control |= co::SE::SACL_PRESENT; }
+		control
+	}
+
+	/// Parses a self-relative security descriptor from its binary form, which
+	/// starts with a
+	/// [`SECURITY_DESCRIPTOR_RELATIVE`](https://learn.microsoft.com/en-us/windows-hardware/drivers/ddi/ntifs/ns-ntifs-_security_descriptor_relative)
+	/// header.
+	///
+	/// Fails with
+	/// [`ERROR::INVALID_SECURITY_DESCR`](crate::co::ERROR::INVALID_SECURITY_DESCR)
+	/// if the header is malformed, or the descriptor is not self-relative.
+	pub fn from_bytes(data: &[u8]) -> SysResult<Self> {
+		let bad = || co::ERROR::INVALID_SECURITY_DESCR;
+		if data.len() < 20 || data[0] != 1 {
+			return Err(bad());
+		}
+		let control = unsafe { co::SE::from_raw(read_u16(data, 2).unwrap()) };
+		if !control.has(co::SE::SELF_RELATIVE) {
+			return Err(bad());
+		}
+		let offset = |idx: usize| read_u32(data, 4 + idx * 4).unwrap() as usize;
+		let section = |off: usize| data.get(off..).ok_or_else(bad);
+
+		let sid_at = |off: usize| -> SysResult<Option<SidBuf>> {
+			match off {
+				0 => Ok(None),
+				off => SidBuf::from_bytes(section(off)?).map(Some),
+			}
+		};
+		let acl_at = |off: usize, present: co::SE| -> SysResult<Option<Acl>> {
+			match off {
+				0 => Ok(None),
+				_ if !control.has(present) => Ok(None),
+				off => Acl::from_bytes(section(off)?).map(Some),
+			}
+		};
+
+		Ok(Self {
+			owner: sid_at(offset(0))?,
+			group: sid_at(offset(1))?,
+			control: control & !co::SE::SELF_RELATIVE,
+			sacl: acl_at(offset(2), co::SE::SACL_PRESENT)?,
+			dacl: acl_at(offset(3), co::SE::DACL_PRESENT)?,
+		})
+	}
+
+	/// Returns the self-relative binary form of the security descriptor.
+	///
+	/// Fails with [`ERROR::INVALID_ACL`](crate::co::ERROR::INVALID_ACL) if an
+	/// ACL is too large to be represented.
+	pub fn to_bytes(&self) -> SysResult<Vec<u8>> {
+		let mut buf = vec![1, 0]; // revision
+		buf.extend_from_slice(
+			&(self.effective_control() | co::SE::SELF_RELATIVE).raw().to_le_bytes());
+		buf.resize(20, 0); // offsets filled below
+
+		let set_offset = |buf: &mut Vec<u8>, idx: usize, data: Option<Vec<u8>>| {
+			if let Some(data) = data {
+				let off = buf.len() as u32;
+				buf[4 + idx * 4..8 + idx * 4].copy_from_slice(&off.to_le_bytes());
+				buf.extend_from_slice(&data);
+			}
+		};
+		set_offset(&mut buf, 2, self.sacl.as_ref().map(|acl| acl.to_bytes()).transpose()?);
+		set_offset(&mut buf, 3, self.dacl.as_ref().map(|acl| acl.to_bytes()).transpose()?);
+		set_offset(&mut buf, 0, self.owner.as_ref().map(|sid| sid.as_bytes().to_vec()));
+		set_offset(&mut buf, 1, self.group.as_ref().map(|sid| sid.as_bytes().to_vec()));
+		Ok(buf)
+	}
+
+	/// Returns the self-relative binary form of the security descriptor in a
+	/// buffer which can be passed to native functions.
+	///
+	/// Fails like
+	/// [`SecurityDescriptor::to_bytes`](crate::SecurityDescriptor::to_bytes).
+	pub fn to_buf(&self) -> SysResult<SecurityDescriptorBuf> {
+		Ok(SecurityDescriptorBuf::from_bytes(&self.to_bytes()?))
+	}
+}
+
+/// A self-relative security descriptor stored in an aligned buffer, which can
+/// be passed to native functions expecting a
+/// [`SECURITY_DESCRIPTOR`](crate::SECURITY_DESCRIPTOR), like
+/// [`SECURITY_ATTRIBUTES::set_lpSecurityDescriptor`](crate::SECURITY_ATTRIBUTES::set_lpSecurityDescriptor).
+///
+/// Created with [`SecurityDescriptor::to_buf`](crate::SecurityDescriptor::to_buf).
+pub struct SecurityDescriptorBuf {
+	buf: Vec<usize>, // pointer-aligned
+	len: usize,
+}
+
+impl SecurityDescriptorBuf {
+	fn from_bytes(data: &[u8]) -> Self {
+		let sz_word = std::mem::size_of::<usize>();
+		let num_words = data.len().max(std::mem::size_of::<SECURITY_DESCRIPTOR>())
+			.div_ceil(sz_word);
+		let mut buf = vec![0usize; num_words];
+		unsafe {
+			std::ptr::copy_nonoverlapping(data.as_ptr(), buf.as_mut_ptr() as *mut u8, data.len());
+		}
+		Self { buf, len: data.len() }
+	}
+
+	/// Returns the self-relative binary form.
+	#[must_use]
+	pub fn as_bytes(&self) -> &[u8] {
+		unsafe { std::slice::from_raw_parts(self.buf.as_ptr() as *const u8, self.len) }
+	}
+
+	/// Returns the buffer as a
+	/// [`SECURITY_DESCRIPTOR`](crate::SECURITY_DESCRIPTOR) reference.
+	///
+	/// Since the descriptor is self-relative, its pointer fields hold offsets,
+	/// so they must not be read directly.
+	#[must_use]
+	pub fn as_sd(&self) -> &SECURITY_DESCRIPTOR {
+		unsafe { &*(self.buf.as_ptr() as *const _) }
+	}
+
+	/// Returns the buffer as a mutable
+	/// [`SECURITY_DESCRIPTOR`](crate::SECURITY_DESCRIPTOR) reference.
+	///
+	/// Since the descriptor is self-relative, its pointer fields hold offsets,
+	/// so they must not be read or written directly.
+	#[must_use]
+	pub fn as_sd_mut(&mut self) -> &mut SECURITY_DESCRIPTOR {
+		unsafe { &mut *(self.buf.as_mut_ptr() as *mut _) }
+	}
+}
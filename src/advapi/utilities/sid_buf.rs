@@ -0,0 +1,538 @@
+use std::ops::Deref;
+use std::str::FromStr;
+
+use crate::co;
+use crate::decl::*;
+use crate::kernel::privs::*;
+
+/// Maximum number of sub authorities in a SID.
+const MAX_SUB_AUTHORITIES: usize = 15;
+
+/// Well-known SIDs which don't depend on a domain, as authority and sub
+/// authorities. When more than one type shares the same SID, the first one is
+/// returned by [`SidBuf::well_known_type`](crate::SidBuf::well_known_type).
+const WELL_KNOWN: &[(co::WELL_KNOWN_SID_TYPE, u8, &[u32])] = &[
+	(co::WELL_KNOWN_SID_TYPE::Null, 0, &[0]),
+	(co::WELL_KNOWN_SID_TYPE::World, 1, &[0]),
+	(co::WELL_KNOWN_SID_TYPE::Local, 2, &[0]),
+	(co::WELL_KNOWN_SID_TYPE::LocalLogon, 2, &[1]),
+	(co::WELL_KNOWN_SID_TYPE::ConsoleLogon, 2, &[1]),
+	(co::WELL_KNOWN_SID_TYPE::CreatorOwner, 3, &[0]),
+	(co::WELL_KNOWN_SID_TYPE::CreatorGroup, 3, &[1]),
+	(co::WELL_KNOWN_SID_TYPE::CreatorOwnerServer, 3, &[2]),
+	(co::WELL_KNOWN_SID_TYPE::CreatorGroupServer, 3, &[3]),
+	(co::WELL_KNOWN_SID_TYPE::CreatorOwnerRights, 3, &[4]),
+	(co::WELL_KNOWN_SID_TYPE::NtAuthority, 5, &[]),
+	(co::WELL_KNOWN_SID_TYPE::Dialup, 5, &[1]),
+	(co::WELL_KNOWN_SID_TYPE::Network, 5, &[2]),
+	(co::WELL_KNOWN_SID_TYPE::Batch, 5, &[3]),
+	(co::WELL_KNOWN_SID_TYPE::Interactive, 5, &[4]),
+	(co::WELL_KNOWN_SID_TYPE::LogonIds, 5, &[5, 0, 0]),
+	(co::WELL_KNOWN_SID_TYPE::Service, 5, &[6]),
+	(co::WELL_KNOWN_SID_TYPE::Anonymous, 5, &[7]),
+	(co::WELL_KNOWN_SID_TYPE::Proxy, 5, &[8]),
+	(co::WELL_KNOWN_SID_TYPE::EnterpriseControllers, 5, &[9]),
+	(co::WELL_KNOWN_SID_TYPE::SelfSid, 5, &[10]),
+	(co::WELL_KNOWN_SID_TYPE::AuthenticatedUser, 5, &[11]),
+	(co::WELL_KNOWN_SID_TYPE::RestrictedCode, 5, &[12]),
+	(co::WELL_KNOWN_SID_TYPE::TerminalServer, 5, &[13]),
+	(co::WELL_KNOWN_SID_TYPE::RemoteLogonId, 5, &[14]),
+	(co::WELL_KNOWN_SID_TYPE::ThisOrganization, 5, &[15]),
+	(co::WELL_KNOWN_SID_TYPE::IUser, 5, &[17]),
+	(co::WELL_KNOWN_SID_TYPE::LocalSystem, 5, &[18]),
+	(co::WELL_KNOWN_SID_TYPE::LocalService, 5, &[19]),
+	(co::WELL_KNOWN_SID_TYPE::NetworkService, 5, &[20]),
+	(co::WELL_KNOWN_SID_TYPE::EnterpriseReadonlyControllers, 5, &[22]),
+	(co::WELL_KNOWN_SID_TYPE::BuiltinDomain, 5, &[32]),
+	(co::WELL_KNOWN_SID_TYPE::BuiltinAdministrators, 5, &[32, 544]),
+	(co::WELL_KNOWN_SID_TYPE::BuiltinUsers, 5, &[32, 545]),
+	(co::WELL_KNOWN_SID_TYPE::BuiltinGuests, 5, &[32, 546]),
+	(co::WELL_KNOWN_SID_TYPE::BuiltinPowerUsers, 5, &[32, 547]),
+	(co::WELL_KNOWN_SID_TYPE::BuiltinAccountOperators, 5, &[32, 548]),
+	(co::WELL_KNOWN_SID_TYPE::BuiltinSystemOperators, 5, &[32, 549]),
+	(co::WELL_KNOWN_SID_TYPE::BuiltinPrintOperators, 5, &[32, 550]),
+	(co::WELL_KNOWN_SID_TYPE::BuiltinBackupOperators, 5, &[32, 551]),
+	(co::WELL_KNOWN_SID_TYPE::BuiltinReplicator, 5, &[32, 552]),
+	(co::WELL_KNOWN_SID_TYPE::BuiltinPreWindows2000CompatibleAccess, 5, &[32, 554]),
+	(co::WELL_KNOWN_SID_TYPE::BuiltinRemoteDesktopUsers, 5, &[32, 555]),
+	(co::WELL_KNOWN_SID_TYPE::BuiltinNetworkConfigurationOperators, 5, &[32, 556]),
+	(co::WELL_KNOWN_SID_TYPE::BuiltinIncomingForestTrustBuilders, 5, &[32, 557]),
+	(co::WELL_KNOWN_SID_TYPE::BuiltinPerfMonitoringUsers, 5, &[32, 558]),
+	(co::WELL_KNOWN_SID_TYPE::BuiltinPerfLoggingUsers, 5, &[32, 559]),
+	(co::WELL_KNOWN_SID_TYPE::BuiltinAuthorizationAccess, 5, &[32, 560]),
+	(co::WELL_KNOWN_SID_TYPE::BuiltinTerminalServerLicenseServers, 5, &[32, 561]),
+	(co::WELL_KNOWN_SID_TYPE::BuiltinDCOMUsers, 5, &[32, 562]),
+	(co::WELL_KNOWN_SID_TYPE::BuiltinIUsers, 5, &[32, 568]),
+	(co::WELL_KNOWN_SID_TYPE::BuiltinCryptoOperators, 5, &[32, 569]),
+	(co::WELL_KNOWN_SID_TYPE::BuiltinEventLogReadersGroup, 5, &[32, 573]),
+	(co::WELL_KNOWN_SID_TYPE::BuiltinCertSvcDComAccessGroup, 5, &[32, 574]),
+	(co::WELL_KNOWN_SID_TYPE::BuiltinRDSRemoteAccessServers, 5, &[32, 575]),
+	(co::WELL_KNOWN_SID_TYPE::BuiltinRDSEndpointServers, 5, &[32, 576]),
+	(co::WELL_KNOWN_SID_TYPE::BuiltinRDSManagementServers, 5, &[32, 577]),
+	(co::WELL_KNOWN_SID_TYPE::BuiltinHyperVAdmins, 5, &[32, 578]),
+	(co::WELL_KNOWN_SID_TYPE::BuiltinAccessControlAssistanceOperators, 5, &[32, 579]),
+	(co::WELL_KNOWN_SID_TYPE::BuiltinRemoteManagementUsers, 5, &[32, 580]),
+	(co::WELL_KNOWN_SID_TYPE::BuiltinDefaultSystemManagedGroup, 5, &[32, 581]),
+	(co::WELL_KNOWN_SID_TYPE::BuiltinStorageReplicaAdmins, 5, &[32, 582]),
+	(co::WELL_KNOWN_SID_TYPE::BuiltinDeviceOwners, 5, &[32, 583]),
+	(co::WELL_KNOWN_SID_TYPE::WriteRestrictedCode, 5, &[33]),
+	(co::WELL_KNOWN_SID_TYPE::NTLMAuthentication, 5, &[64, 10]),
+	(co::WELL_KNOWN_SID_TYPE::SChannelAuthentication, 5, &[64, 14]),
+	(co::WELL_KNOWN_SID_TYPE::DigestAuthentication, 5, &[64, 21]),
+	(co::WELL_KNOWN_SID_TYPE::ThisOrganizationCertificate, 5, &[65, 1]),
+	(co::WELL_KNOWN_SID_TYPE::UserModeDrivers, 5, &[84, 0, 0, 0, 0, 0]),
+	(co::WELL_KNOWN_SID_TYPE::LocalAccount, 5, &[113]),
+	(co::WELL_KNOWN_SID_TYPE::LocalAccountAndAdministrator, 5, &[114]),
+	(co::WELL_KNOWN_SID_TYPE::OtherOrganization, 5, &[1000]),
+	(co::WELL_KNOWN_SID_TYPE::ApplicationPackageAuthority, 15, &[2]),
+	(co::WELL_KNOWN_SID_TYPE::BuiltinAnyPackage, 15, &[2, 1]),
+	(co::WELL_KNOWN_SID_TYPE::CapabilityInternetClient, 15, &[3, 1]),
+	(co::WELL_KNOWN_SID_TYPE::CapabilityInternetClientServer, 15, &[3, 2]),
+	(co::WELL_KNOWN_SID_TYPE::CapabilityPrivateNetworkClientServer, 15, &[3, 3]),
+	(co::WELL_KNOWN_SID_TYPE::CapabilityPicturesLibrary, 15, &[3, 4]),
+	(co::WELL_KNOWN_SID_TYPE::CapabilityVideosLibrary, 15, &[3, 5]),
+	(co::WELL_KNOWN_SID_TYPE::CapabilityMusicLibrary, 15, &[3, 6]),
+	(co::WELL_KNOWN_SID_TYPE::CapabilityDocumentsLibrary, 15, &[3, 7]),
+	(co::WELL_KNOWN_SID_TYPE::CapabilityEnterpriseAuthentication, 15, &[3, 8]),
+	(co::WELL_KNOWN_SID_TYPE::CapabilitySharedUserCertificates, 15, &[3, 9]),
+	(co::WELL_KNOWN_SID_TYPE::CapabilityRemovableStorage, 15, &[3, 10]),
+	(co::WELL_KNOWN_SID_TYPE::UntrustedLabel, 16, &[0]),
+	(co::WELL_KNOWN_SID_TYPE::LowLabel, 16, &[4096]),
+	(co::WELL_KNOWN_SID_TYPE::MediumLabel, 16, &[8192]),
+	(co::WELL_KNOWN_SID_TYPE::MediumPlusLabel, 16, &[8448]),
+	(co::WELL_KNOWN_SID_TYPE::HighLabel, 16, &[12288]),
+	(co::WELL_KNOWN_SID_TYPE::SystemLabel, 16, &[16384]),
+	(co::WELL_KNOWN_SID_TYPE::AuthenticationAuthorityAsserted, 18, &[1]),
+	(co::WELL_KNOWN_SID_TYPE::AuthenticationServiceAsserted, 18, &[2]),
+	(co::WELL_KNOWN_SID_TYPE::AuthenticationFreshKeyAuth, 18, &[3]),
+	(co::WELL_KNOWN_SID_TYPE::AuthenticationKeyTrust, 18, &[4]),
+	(co::WELL_KNOWN_SID_TYPE::AuthenticationKeyPropertyMFA, 18, &[5]),
+	(co::WELL_KNOWN_SID_TYPE::AuthenticationKeyPropertyAttestation, 18, &[6]),
+];
+
+/// Well-known SIDs relative to a domain, which is followed by the RID.
+const WELL_KNOWN_DOMAIN: &[(co::WELL_KNOWN_SID_TYPE, u32)] = &[
+	(co::WELL_KNOWN_SID_TYPE::NewEnterpriseReadonlyControllers, 498),
+	(co::WELL_KNOWN_SID_TYPE::AccountAdministrator, 500),
+	(co::WELL_KNOWN_SID_TYPE::AccountGuest, 501),
+	(co::WELL_KNOWN_SID_TYPE::AccountKrbtgt, 502),
+	(co::WELL_KNOWN_SID_TYPE::AccountDefaultSystemManaged, 503),
+	(co::WELL_KNOWN_SID_TYPE::AccountDomainAdmins, 512),
+	(co::WELL_KNOWN_SID_TYPE::AccountDomainUsers, 513),
+	(co::WELL_KNOWN_SID_TYPE::AccountDomainGuests, 514),
+	(co::WELL_KNOWN_SID_TYPE::AccountComputers, 515),
+	(co::WELL_KNOWN_SID_TYPE::AccountControllers, 516),
+	(co::WELL_KNOWN_SID_TYPE::AccountCertAdmins, 517),
+	(co::WELL_KNOWN_SID_TYPE::AccountSchemaAdmins, 518),
+	(co::WELL_KNOWN_SID_TYPE::AccountEnterpriseAdmins, 519),
+	(co::WELL_KNOWN_SID_TYPE::AccountPolicyAdmins, 520),
+	(co::WELL_KNOWN_SID_TYPE::AccountReadonlyControllers, 521),
+	(co::WELL_KNOWN_SID_TYPE::AccountCloneableControllers, 522),
+	(co::WELL_KNOWN_SID_TYPE::AccountProtectedUsers, 525),
+	(co::WELL_KNOWN_SID_TYPE::AccountKeyAdmins, 526),
+	(co::WELL_KNOWN_SID_TYPE::AccountEnterpriseKeyAdmins, 527),
+	(co::WELL_KNOWN_SID_TYPE::AccountRasAndIasServers, 553),
+	(co::WELL_KNOWN_SID_TYPE::CacheablePrincipalsGroup, 571),
+	(co::WELL_KNOWN_SID_TYPE::NonCacheablePrincipalsGroup, 572),
+];
+
+/// Writes a SID in the standard `S-R-I-S-S...` string format, like
+/// [`ConvertSidToStringSid`](crate::ConvertSidToStringSid).
+pub(in crate::advapi) fn fmt_sid(
+	f: &mut std::fmt::Formatter<'_>,
+	revision: u8,
+	identifier_authority: &SID_IDENTIFIER_AUTHORITY,
+	sub_authorities: &[co::RID],
+) -> std::fmt::Result
+{
+	let mut au = [0u8; 8];
+	au[2..].copy_from_slice(&identifier_authority.Value);
+	let au = u64::from_be_bytes(au);
+
+	if au > u32::MAX as u64 {
+		write!(f, "S-{}-0x{:012X}", revision, au)?;
+	} else {
+		write!(f, "S-{}-{}", revision, au)?;
+	}
+	for sub_au in sub_authorities.iter() {
+		write!(f, "-{}", sub_au.raw())?;
+	}
+	Ok(())
+}
+
+/// An owned [`SID`](crate::SID), built and parsed in pure Rust, without any
+/// calls to the system.
+///
+/// It dereferences to [`&SID`](crate::SID), so it can be passed to any
+/// function which expects a `SID`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let admins: w::SidBuf = "S-1-5-32-544".parse()?;
+/// assert_eq!(
+///     admins.well_known_type(),
+///     Some(co::WELL_KNOWN_SID_TYPE::BuiltinAdministrators),
+/// );
+///
+/// let system = w::SidBuf::well_known(co::WELL_KNOWN_SID_TYPE::LocalSystem, None)?;
+/// println!("{}", system); // S-1-5-18
+///
+/// let (name, domain, _) = w::LookupAccountSid(None, &system)?;
+/// # w::SysResult::Ok(())
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct SidBuf {
+	buf: Vec<u32>, // binary SID, aligned to be dereferenced as SID
+}
+
+impl Deref for SidBuf {
+	type Target = SID;
+
+	fn deref(&self) -> &Self::Target {
+		unsafe { &*(self.buf.as_ptr() as *const _) }
+	}
+}
+
+impl std::fmt::Display for SidBuf {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		std::fmt::Display::fmt(self.deref(), f) // delegate to the underlying SID
+	}
+}
+impl std::fmt::Debug for SidBuf {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "SidBuf({})", self)
+	}
+}
+
+impl FromStr for SidBuf {
+	type Err = co::ERROR;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Self::parse(s)
+	}
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for SidBuf {
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.collect_str(self) // serialized as the "S-1-..." string
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SidBuf {
+	fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+		Self::parse(&s).map_err(serde::de::Error::custom)
+	}
+}
+
+impl From<&SID> for SidBuf {
+	fn from(sid: &SID) -> Self {
+		Self::from_sid(sid)
+	}
+}
+
+impl SidBuf {
+	/// Creates a new SID from its identifier authority and sub authorities,
+	/// like [`AllocateAndInitializeSid`](crate::AllocateAndInitializeSid).
+	///
+	/// Fails with [`ERROR::INVALID_SID`](crate::co::ERROR::INVALID_SID) if
+	/// there are more than 15 sub authorities.
+	pub fn new(
+		identifier_authority: &SID_IDENTIFIER_AUTHORITY,
+		sub_authorities: &[co::RID],
+	) -> SysResult<Self>
+	{
+		Self::from_parts(
+			identifier_authority.Value,
+			&sub_authorities.iter().map(|rid| rid.raw()).collect::<Vec<_>>(),
+		)
+	}
+
+	fn from_parts(authority: [u8; 6], sub_authorities: &[u32]) -> SysResult<Self> {
+		if sub_authorities.len() > MAX_SUB_AUTHORITIES {
+			return Err(co::ERROR::INVALID_SID);
+		}
+
+		let mut data = Vec::with_capacity(8 + sub_authorities.len() * 4);
+		data.push(1); // revision
+		data.push(sub_authorities.len() as u8);
+		data.extend_from_slice(&authority);
+		sub_authorities.iter()
+			.for_each(|sub_au| data.extend_from_slice(&sub_au.to_le_bytes()));
+		Ok(Self { buf: u32_aligned(&data) })
+	}
+
+	/// Copies an existing [`SID`](crate::SID).
+	#[must_use]
+	pub fn from_sid(sid: &SID) -> Self {
+		Self::from_parts(
+			sid.IdentifierAuthority.Value,
+			&sid.SubAuthority().iter().map(|rid| rid.raw()).collect::<Vec<_>>(),
+		).unwrap() // a valid SID has at most 15 sub authorities
+	}
+
+	/// Parses a SID from its binary form, as returned by
+	/// [`SidBuf::as_bytes`](crate::SidBuf::as_bytes). Trailing bytes after
+	/// the SID are ignored.
+	///
+	/// Fails with [`ERROR::INVALID_SID`](crate::co::ERROR::INVALID_SID) if
+	/// the data is not a valid SID.
+	pub fn from_bytes(data: &[u8]) -> SysResult<Self> {
+		if data.len() < 8 || data[0] != 1 || data[1] as usize > MAX_SUB_AUTHORITIES {
+			return Err(co::ERROR::INVALID_SID);
+		}
+		let num_sub_au = data[1] as usize;
+		if data.len() < 8 + num_sub_au * 4 {
+			return Err(co::ERROR::INVALID_SID);
+		}
+		Ok(Self { buf: u32_aligned(&data[..8 + num_sub_au * 4]) })
+	}
+
+	/// Parses a SID in the standard `S-R-I-S-S...` string format, like
+	/// [`ConvertStringSidToSid`](crate::ConvertStringSidToSid). The identifier
+	/// authority may be written in decimal, or in hexadecimal with a `0x`
+	/// prefix.
+	///
+	/// Fails with [`ERROR::INVALID_SID`](crate::co::ERROR::INVALID_SID) if
+	/// the string is malformed.
+	pub fn parse(s: &str) -> SysResult<Self> {
+		let mut parts = s.trim().split('-');
+		if !parts.next().is_some_and(|p| p.eq_ignore_ascii_case("S"))
+			|| parts.next() != Some("1") // revision
+		{
+			return Err(co::ERROR::INVALID_SID);
+		}
+
+		let authority = match parts.next() {
+			Some(au) => match au.strip_prefix("0x").or_else(|| au.strip_prefix("0X")) {
+				Some(hex) => u64::from_str_radix(hex, 16),
+				None => au.parse::<u64>(),
+			}.map_err(|_| co::ERROR::INVALID_SID)?,
+			None => return Err(co::ERROR::INVALID_SID),
+		};
+		if authority >= 1 << 48 {
+			return Err(co::ERROR::INVALID_SID);
+		}
+
+		let sub_authorities = parts
+			.map(|p| p.parse::<u32>().map_err(|_| co::ERROR::INVALID_SID))
+			.collect::<SysResult<Vec<_>>>()?;
+
+		let mut au = [0u8; 6];
+		au.copy_from_slice(&authority.to_be_bytes()[2..]);
+		Self::from_parts(au, &sub_authorities)
+	}
+
+	/// Creates a well-known SID, like
+	/// [`CreateWellKnownSid`](crate::CreateWellKnownSid), but using an
+	/// offline table.
+	///
+	/// Account SIDs, like
+	/// [`WELL_KNOWN_SID_TYPE::AccountAdministrator`](crate::co::WELL_KNOWN_SID_TYPE::AccountAdministrator),
+	/// require `domain_sid`; otherwise it's ignored.
+	///
+	/// Fails with
+	/// [`ERROR::INVALID_PARAMETER`](crate::co::ERROR::INVALID_PARAMETER) if
+	/// the type is not in the table, or the domain is required but missing.
+	pub fn well_known(
+		sid_type: co::WELL_KNOWN_SID_TYPE,
+		domain_sid: Option<&SID>,
+	) -> SysResult<Self>
+	{
+		if let Some((_, au, sub_aus)) = WELL_KNOWN.iter()
+			.find(|(ty, _, _)| *ty == sid_type)
+		{
+			return Self::from_parts([0, 0, 0, 0, 0, *au], sub_aus);
+		}
+
+		let rid = WELL_KNOWN_DOMAIN.iter()
+			.find(|(ty, _)| *ty == sid_type)
+			.map(|(_, rid)| *rid)
+			.ok_or(co::ERROR::INVALID_PARAMETER)?;
+		let domain_sid = domain_sid.ok_or(co::ERROR::INVALID_PARAMETER)?;
+
+		let mut sub_aus = domain_sid.SubAuthority().iter()
+			.map(|rid| rid.raw())
+			.collect::<Vec<_>>();
+		sub_aus.push(rid);
+		Self::from_parts(domain_sid.IdentifierAuthority.Value, &sub_aus)
+			.map_err(|_| co::ERROR::INVALID_PARAMETER)
+	}
+
+	/// Returns the well-known type of the SID, if any, using an offline table.
+	///
+	/// Account SIDs are recognized when relative to any domain in the
+	/// `S-1-5-21-X-Y-Z` form.
+	#[must_use]
+	pub fn well_known_type(&self) -> Option<co::WELL_KNOWN_SID_TYPE> {
+		let au = &self.IdentifierAuthority.Value;
+		if au[..5] != [0; 5] {
+			return None;
+		}
+		let sub_aus = self.SubAuthority().iter()
+			.map(|rid| rid.raw())
+			.collect::<Vec<_>>();
+
+		WELL_KNOWN.iter()
+			.find(|(_, wk_au, wk_sub_aus)| *wk_au == au[5] && *wk_sub_aus == sub_aus)
+			.map(|(ty, _, _)| *ty)
+			.or_else(|| match sub_aus[..] {
+				[21, _, _, _, rid] if au[5] == 5 => WELL_KNOWN_DOMAIN.iter()
+					.find(|(_, wk_rid)| *wk_rid == rid)
+					.map(|(ty, _)| *ty),
+				_ => None,
+			})
+	}
+
+	/// Returns the binary form of the SID, which is also the memory layout of
+	/// the [`SID`](crate::SID) struct.
+	#[must_use]
+	pub fn as_bytes(&self) -> &[u8] {
+		unsafe {
+			std::slice::from_raw_parts(self.buf.as_ptr() as *const u8, self.buf.len() * 4)
+		}
+	}
+
+	/// Returns the last sub authority, which identifies the account or group
+	/// within its domain, if any.
+	#[must_use]
+	pub fn rid(&self) -> Option<co::RID> {
+		self.SubAuthority().last().copied()
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	/// Formats the binary SID directly with `fmt_sid`.
+	struct Fmt<'a>(&'a [u8]);
+
+	impl std::fmt::Display for Fmt<'_> {
+		fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+			let au = SID_IDENTIFIER_AUTHORITY { Value: self.0[2..8].try_into().unwrap() };
+			let sub_aus = self.0[8..].chunks_exact(4)
+				.map(|b| unsafe { co::RID::from_raw(u32::from_le_bytes(b.try_into().unwrap())) })
+				.collect::<Vec<_>>();
+			fmt_sid(f, self.0[0], &au, &sub_aus)
+		}
+	}
+
+	#[test]
+	fn parse_and_format() {
+		let cases: &[(&str, &str, &[u8])] = &[
+			("S-1-0-0", "S-1-0-0", &[1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]),
+			("S-1-5", "S-1-5", &[1, 0, 0, 0, 0, 0, 0, 5]),
+			("S-1-5-18", "S-1-5-18", &[1, 1, 0, 0, 0, 0, 0, 5, 18, 0, 0, 0]),
+			("s-1-5-32-544", "S-1-5-32-544",
+				&[1, 2, 0, 0, 0, 0, 0, 5, 32, 0, 0, 0, 0x20, 0x02, 0, 0]),
+			("  S-1-5-18\n", "S-1-5-18", &[1, 1, 0, 0, 0, 0, 0, 5, 18, 0, 0, 0]),
+			("S-1-0x10-4096", "S-1-16-4096", &[1, 1, 0, 0, 0, 0, 0, 16, 0, 0x10, 0, 0]),
+			("S-1-4294967295", "S-1-4294967295", &[1, 0, 0, 0, 0xff, 0xff, 0xff, 0xff]),
+			("S-1-4294967296", "S-1-0x000100000000", &[1, 0, 0, 1, 0, 0, 0, 0]),
+			("S-1-0X123456789abc-4294967295", "S-1-0x123456789ABC-4294967295",
+				&[1, 1, 0x12, 0x34, 0x56, 0x78, 0x9a, 0xbc, 0xff, 0xff, 0xff, 0xff]),
+			("S-1-5-1-2-3-4-5-6-7-8-9-10-11-12-13-14-15", "S-1-5-1-2-3-4-5-6-7-8-9-10-11-12-13-14-15", &[]),
+		];
+		for (text, formatted, bytes) in cases.iter() {
+			let sid = SidBuf::parse(text).unwrap();
+			assert_eq!(sid.to_string(), *formatted, "{:?}", text);
+			assert_eq!(Fmt(sid.as_bytes()).to_string(), *formatted, "{:?}", text);
+			if !bytes.is_empty() {
+				assert_eq!(sid.as_bytes(), *bytes, "{:?}", text);
+			}
+			assert_eq!(SidBuf::parse(formatted).as_ref(), Ok(&sid), "{:?}", text);
+			assert_eq!(SidBuf::from_bytes(sid.as_bytes()).as_ref(), Ok(&sid), "{:?}", text);
+		}
+	}
+
+	#[test]
+	fn parse_malformed() {
+		let texts = [
+			"",
+			"S",
+			"S-1",
+			"S-2-5-18", // revision
+			"X-1-5-18",
+			"S-1-5-",
+			"S-1-5--18",
+			"S-1--5",
+			"S-1-0x",
+			"S-1-0xg",
+			"S-1-281474976710656", // authority above 48 bits
+			"S-1-0x1000000000000",
+			"S-1-5-4294967296", // sub authority above 32 bits
+			"S-1-5--1",
+			"S-1-5-18 -1",
+			"S-1-5-1-2-3-4-5-6-7-8-9-10-11-12-13-14-15-16", // 16 sub authorities
+		];
+		for text in texts.iter() {
+			assert_eq!(SidBuf::parse(text), Err(co::ERROR::INVALID_SID), "{:?}", text);
+		}
+	}
+
+	#[test]
+	fn binary() {
+		let sid = SidBuf::parse("S-1-5-21-1-2-3-1001").unwrap();
+		let mut data = sid.as_bytes().to_vec();
+		data.extend_from_slice(&[0xaa, 0xbb]); // ignored
+		assert_eq!(SidBuf::from_bytes(&data), Ok(sid.clone()));
+		assert_eq!(sid.rid().map(|rid| rid.raw()), Some(1001));
+		assert_eq!(SidBuf::parse("S-1-5").unwrap().rid(), None);
+
+		let malformed: &[&[u8]] = &[
+			&[],
+			&[1, 0, 0, 0, 0, 0, 0],
+			&[2, 0, 0, 0, 0, 0, 0, 5],
+			&[1, 16, 0, 0, 0, 0, 0, 5],
+			&[1, 2, 0, 0, 0, 0, 0, 5, 32, 0, 0, 0, 0x20, 0x02, 0],
+		];
+		for data in malformed.iter() {
+			assert_eq!(SidBuf::from_bytes(data), Err(co::ERROR::INVALID_SID), "{:?}", data);
+		}
+	}
+
+	#[test]
+	fn well_known() {
+		for (ty, au, sub_aus) in WELL_KNOWN.iter() {
+			let sid = SidBuf::well_known(*ty, None).unwrap();
+			assert_eq!(sid.IdentifierAuthority.Value, [0, 0, 0, 0, 0, *au], "{:?}", ty);
+			let first = WELL_KNOWN.iter() // types sharing a SID yield the first one
+				.find(|(_, wk_au, wk_sub_aus)| wk_au == au && wk_sub_aus == sub_aus)
+				.map(|(ty, _, _)| *ty);
+			assert_eq!(sid.well_known_type(), first, "{}", sid);
+		}
+
+		let cases: &[(&str, Option<co::WELL_KNOWN_SID_TYPE>)] = &[
+			("S-1-5-18", Some(co::WELL_KNOWN_SID_TYPE::LocalSystem)),
+			("S-1-2-1", Some(co::WELL_KNOWN_SID_TYPE::LocalLogon)),
+			("S-1-16-12288", Some(co::WELL_KNOWN_SID_TYPE::HighLabel)),
+			("S-1-5-21-1-2-3-500", Some(co::WELL_KNOWN_SID_TYPE::AccountAdministrator)),
+			("S-1-5-21-1-2-3-513", Some(co::WELL_KNOWN_SID_TYPE::AccountDomainUsers)),
+			("S-1-5-21-1-2-3-1001", None),
+			("S-1-5-21-1-2-500", None),
+			("S-1-5-32-999", None),
+			("S-1-0x10000000005-18", None),
+			("S-1-5", Some(co::WELL_KNOWN_SID_TYPE::NtAuthority)),
+		];
+		for (text, ty) in cases.iter() {
+			assert_eq!(SidBuf::parse(text).unwrap().well_known_type(), *ty, "{}", text);
+		}
+
+		let domain = SidBuf::parse("S-1-5-21-1-2-3").unwrap();
+		let admin = SidBuf::well_known(co::WELL_KNOWN_SID_TYPE::AccountAdministrator, Some(&domain)).unwrap();
+		assert_eq!(admin.to_string(), "S-1-5-21-1-2-3-500");
+		assert_eq!(SidBuf::well_known(co::WELL_KNOWN_SID_TYPE::AccountAdministrator, None),
+			Err(co::ERROR::INVALID_PARAMETER));
+		assert_eq!(SidBuf::well_known(co::WELL_KNOWN_SID_TYPE::CapabilityAppointments, None),
+			Err(co::ERROR::INVALID_PARAMETER));
+		let full = SidBuf::parse("S-1-5-1-2-3-4-5-6-7-8-9-10-11-12-13-14-15").unwrap();
+		assert_eq!(SidBuf::well_known(co::WELL_KNOWN_SID_TYPE::AccountGuest, Some(&full)),
+			Err(co::ERROR::INVALID_PARAMETER));
+	}
+}
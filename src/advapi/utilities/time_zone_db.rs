@@ -0,0 +1,234 @@
+use std::collections::BTreeMap;
+
+use crate::co;
+use crate::decl::*;
+use crate::prelude::*;
+
+/// Path of the time zone database, under
+/// [`HKEY::LOCAL_MACHINE`](crate::prelude::advapi_Hkey::LOCAL_MACHINE).
+const TIME_ZONES_KEY: &str = "SOFTWARE\\Microsoft\\Windows NT\\CurrentVersion\\Time Zones";
+
+impl TimeZone {
+	/// Returns the key names of all time zones in the registry time zone
+	/// database, like "Eastern Standard Time".
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// for name in w::TimeZone::registry_names(&w::NativeRegistry::new())? {
+	///     println!("{}", name);
+	/// }
+	/// # w::SysResult::Ok(())
+	/// ```
+	pub fn registry_names(reg: &impl RegistryProvider) -> SysResult<Vec<String>> {
+		reg.enum_keys(&HKEY::LOCAL_MACHINE, TIME_ZONES_KEY)
+	}
+
+	/// Loads a time zone from the registry time zone database, including its
+	/// "Dynamic DST" per-year rules, if any.
+	///
+	/// Since any [`RegistryProvider`](crate::prelude::RegistryProvider) can be
+	/// used, a [`MemoryRegistry`](crate::MemoryRegistry) filled with
+	/// [`TimeZone::to_registry`](crate::TimeZone::to_registry) allows
+	/// deterministic conversions, independent of the time zone updates
+	/// installed on the machine.
+	///
+	/// Returns
+	/// [`ERROR::INVALID_DATA`](crate::co::ERROR::INVALID_DATA) if a value has
+	/// the wrong type or size.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let tz = w::TimeZone::from_registry(
+	///     &w::NativeRegistry::new(), "E. South America Standard Time")?;
+	///
+	/// let (start, end) = tz.transitions(2018)?.unwrap();
+	/// # w::SysResult::Ok(())
+	/// ```
+	pub fn from_registry(reg: &impl RegistryProvider, key_name: &str) -> SysResult<Self> {
+		let root = HKEY::LOCAL_MACHINE;
+		let sub_key = format!("{}\\{}", TIME_ZONES_KEY, key_name);
+
+		let string_value = |name: &str| -> SysResult<String> {
+			match reg.query_value(&root, &sub_key, Some(name)) {
+				Ok(RegistryValue::Sz(s)) => Ok(s),
+				Ok(_) => Err(co::ERROR::INVALID_DATA),
+				Err(co::ERROR::FILE_NOT_FOUND) => Ok(String::new()),
+				Err(e) => Err(e),
+			}
+		};
+		let rule_value = |sub_key: &str, name: &str| -> SysResult<TimeZoneRule> {
+			match reg.query_value(&root, sub_key, Some(name))? {
+				RegistryValue::Binary(data) => TimeZoneRule::from_reg_tzi(&data),
+				_ => Err(co::ERROR::INVALID_DATA),
+			}
+		};
+
+		let mut tz = Self {
+			display_name: string_value("Display")?,
+			standard_name: string_value("Std")?,
+			daylight_name: string_value("Dlt")?,
+			rule: rule_value(&sub_key, "TZI")?,
+			dynamic_rules: BTreeMap::new(),
+		};
+
+		let dyn_key = format!("{}\\Dynamic DST", sub_key);
+		if reg.key_exists(&root, &dyn_key)? {
+			let dword_value = |name: &str| -> SysResult<u32> {
+				match reg.query_value(&root, &dyn_key, Some(name))? {
+					RegistryValue::Dword(n) => Ok(n),
+					_ => Err(co::ERROR::INVALID_DATA),
+				}
+			};
+			let first = dword_value("FirstEntry")?;
+			let last = dword_value("LastEntry")?;
+			for year in first..=last {
+				let year = u16::try_from(year).map_err(|_| co::ERROR::INVALID_DATA)?;
+				tz.dynamic_rules.insert(year, rule_value(&dyn_key, &year.to_string())?);
+			}
+		}
+
+		Ok(tz)
+	}
+
+	/// Writes the time zone into the registry time zone database, under the
+	/// given key name, replacing any existing entry.
+	///
+	/// This is mostly useful to fill a
+	/// [`MemoryRegistry`](crate::MemoryRegistry) for tests.
+	///
+	/// Fails with
+	/// [`ERROR::INVALID_PARAMETER`](crate::co::ERROR::INVALID_PARAMETER),
+	/// before anything is written, if the key name is empty or has a
+	/// backslash, or if the dynamic rules don't have consecutive years.
+	pub fn to_registry(&self, reg: &impl RegistryProvider, key_name: &str) -> SysResult<()> {
+		if key_name.is_empty() || key_name.contains('\\') {
+			return Err(co::ERROR::INVALID_PARAMETER); // would replace other entries
+		}
+		let years = match (self.dynamic_rules.first_key_value(), self.dynamic_rules.last_key_value()) {
+			(Some((&first, _)), Some((&last, _))) => {
+				if (last - first) as usize + 1 != self.dynamic_rules.len() {
+					return Err(co::ERROR::INVALID_PARAMETER);
+				}
+				Some((first, last))
+			},
+			_ => None,
+		};
+
+		let root = HKEY::LOCAL_MACHINE;
+		let sub_key = format!("{}\\{}", TIME_ZONES_KEY, key_name);
+
+		if reg.key_exists(&root, &sub_key)? {
+			reg.delete_tree(&root, &sub_key)?;
+		}
+		reg.create_key(&root, &sub_key)?;
+		for (name, text) in [
+			("Display", &self.display_name),
+			("Std", &self.standard_name),
+			("Dlt", &self.daylight_name),
+		] {
+			reg.set_value(&root, &sub_key, Some(name), RegistryValue::Sz(text.clone()))?;
+		}
+		reg.set_value(&root, &sub_key, Some("TZI"),
+			RegistryValue::Binary(self.rule.to_reg_tzi()))?;
+
+		if let Some((first, last)) = years {
+			let dyn_key = format!("{}\\Dynamic DST", sub_key);
+			reg.create_key(&root, &dyn_key)?;
+			reg.set_value(&root, &dyn_key, Some("FirstEntry"), RegistryValue::Dword(first as _))?;
+			reg.set_value(&root, &dyn_key, Some("LastEntry"), RegistryValue::Dword(last as _))?;
+			for (year, rule) in self.dynamic_rules.iter() {
+				reg.set_value(&root, &dyn_key, Some(&year.to_string()),
+					RegistryValue::Binary(rule.to_reg_tzi()))?;
+			}
+		}
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	fn nth(month: u16, week: u16, hour: u16) -> SYSTEMTIME {
+		SYSTEMTIME { wMonth: month, wDay: week, wHour: hour, ..Default::default() }
+	}
+
+	fn us_eastern() -> TimeZone {
+		let rule = TimeZoneRule {
+			bias: 300,
+			standard_bias: 0,
+			daylight_bias: -60,
+			standard_date: nth(11, 1, 2),
+			daylight_date: nth(3, 2, 2),
+		};
+		let old = TimeZoneRule {
+			standard_date: nth(10, 5, 2),
+			daylight_date: nth(4, 1, 2),
+			..rule
+		};
+		TimeZone {
+			display_name: "(UTC-05:00) Eastern Time (US & Canada)".to_owned(),
+			standard_name: "Eastern Standard Time".to_owned(),
+			daylight_name: "Eastern Daylight Time".to_owned(),
+			rule,
+			dynamic_rules: [(2005, old), (2006, old), (2007, rule)].into_iter().collect(),
+		}
+	}
+
+	#[test]
+	fn registry_round_trip() {
+		let reg = MemoryRegistry::new();
+		let tz = us_eastern();
+		tz.to_registry(&reg, "Eastern Standard Time").unwrap();
+		let utc = TimeZone::from_rule(TimeZoneRule::default());
+		utc.to_registry(&reg, "UTC").unwrap();
+
+		let mut names = TimeZone::registry_names(&reg).unwrap();
+		names.sort();
+		assert_eq!(names, ["Eastern Standard Time", "UTC"]);
+
+		let loaded = TimeZone::from_registry(&reg, "Eastern Standard Time").unwrap();
+		assert_eq!(loaded, tz);
+		assert_eq!(loaded.transitions(2006), tz.transitions(2006));
+		assert_ne!(loaded.transitions(2006), loaded.transitions(2007));
+		assert_eq!(TimeZone::from_registry(&reg, "UTC"), Ok(utc));
+
+		// Replacing drops the old dynamic rules.
+		let fixed = TimeZone { dynamic_rules: BTreeMap::new(), ..tz };
+		fixed.to_registry(&reg, "Eastern Standard Time").unwrap();
+		assert_eq!(TimeZone::from_registry(&reg, "Eastern Standard Time"), Ok(fixed));
+	}
+
+	#[test]
+	fn registry_errors() {
+		let reg = MemoryRegistry::new();
+		let mut tz = us_eastern();
+		assert_eq!(tz.to_registry(&reg, ""), Err(co::ERROR::INVALID_PARAMETER));
+		assert_eq!(tz.to_registry(&reg, "A\\B"), Err(co::ERROR::INVALID_PARAMETER));
+		tz.dynamic_rules.remove(&2006);
+		assert_eq!(tz.to_registry(&reg, "Gap"), Err(co::ERROR::INVALID_PARAMETER));
+		assert!(!reg.key_exists(&HKEY::LOCAL_MACHINE, &format!("{}\\Gap", TIME_ZONES_KEY)).unwrap());
+
+		assert_eq!(TimeZone::from_registry(&reg, "Missing"), Err(co::ERROR::FILE_NOT_FOUND));
+
+		us_eastern().to_registry(&reg, "Bad").unwrap();
+		let key = format!("{}\\Bad", TIME_ZONES_KEY);
+		let dyn_key = format!("{}\\Dynamic DST", key);
+		let root = HKEY::LOCAL_MACHINE;
+		reg.set_value(&root, &dyn_key, Some("2006"), RegistryValue::Binary(vec![0; 40])).unwrap();
+		assert_eq!(TimeZone::from_registry(&reg, "Bad"), Err(co::ERROR::INVALID_DATA));
+		reg.set_value(&root, &dyn_key, Some("2006"),
+			RegistryValue::Binary(TimeZoneRule::default().to_reg_tzi())).unwrap();
+		reg.set_value(&root, &dyn_key, Some("LastEntry"), RegistryValue::Dword(2008)).unwrap();
+		assert_eq!(TimeZone::from_registry(&reg, "Bad"), Err(co::ERROR::FILE_NOT_FOUND));
+		reg.set_value(&root, &key, Some("Std"), RegistryValue::Dword(0)).unwrap();
+		assert_eq!(TimeZone::from_registry(&reg, "Bad"), Err(co::ERROR::INVALID_DATA));
+	}
+}
@@ -0,0 +1,513 @@
+use crate::co;
+use crate::decl::*;
+use crate::guard::*;
+use crate::prelude::*;
+
+// Only the constants stored in a snapshot can be serialized.
+const_serde! { co::SE_GROUP: u32; flags
+	MANDATORY ENABLED_BY_DEFAULT ENABLED OWNER USE_FOR_DENY_ONLY INTEGRITY
+	INTEGRITY_ENABLED RESOURCE LOGON_ID
+}
+const_serde! { co::SE_PRIV_ATTR: u32; flags
+	ENABLED_BY_DEFAULT ENABLED REMOVED USED_FOR_ACCESS
+}
+const_serde! { co::TOKEN_MANDATORY_POLICY: u32; flags
+	NO_WRITE_UP NEW_PROCESS_MIN
+}
+const_serde! { co::TOKEN_ELEVATION_TYPE: u32; values
+	Default Full Limited
+}
+const_serde! { co::TOKEN_TYPE: u32; values
+	Primary Impersonation
+}
+const_serde! { co::SECURITY_IMPERSONATION: u32; values
+	Anonymous Identification Impersonation Delegation
+}
+
+/// A privilege of a [`TokenSnapshot`](crate::TokenSnapshot).
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TokenPrivilege {
+	/// Privilege name, like `SeShutdownPrivilege`.
+	pub name: String,
+	pub luid: LUID,
+	pub attributes: co::SE_PRIV_ATTR,
+}
+
+impl TokenPrivilege {
+	/// Tells whether the privilege is enabled.
+	#[must_use]
+	pub fn is_enabled(&self) -> bool {
+		self.attributes.has(co::SE_PRIV_ATTR::ENABLED)
+	}
+}
+
+/// An owned, point-in-time copy of the information of an access token,
+/// gathered from many
+/// [`HACCESSTOKEN::GetTokenInformation`](crate::prelude::advapi_Haccesstoken::GetTokenInformation)
+/// calls.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let htoken = w::HPROCESS::GetCurrentProcess()
+///     .OpenProcessToken(co::TOKEN::QUERY)?;
+/// let before = w::TokenSnapshot::capture(&htoken)?;
+///
+/// println!("User: {}", before.user);
+/// println!("Elevated: {}", before.is_elevated());
+/// println!("Can shut down: {}", before.has_privilege(co::SE_PRIV::SHUTDOWN_NAME));
+///
+/// // ... adjust token privileges ...
+///
+/// let after = w::TokenSnapshot::capture(&htoken)?;
+/// for name in before.diff(&after).privileges_enabled.iter() {
+///     println!("Enabled: {}", name);
+/// }
+/// # w::SysResult::Ok(())
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TokenSnapshot {
+	pub user: SidBuf,
+	pub owner: SidBuf,
+	pub primary_group: SidBuf,
+	pub groups: Vec<(SidBuf, co::SE_GROUP)>,
+	pub privileges: Vec<TokenPrivilege>,
+	pub elevation_type: co::TOKEN_ELEVATION_TYPE,
+	pub elevated: bool,
+	/// Integrity level SID, like
+	/// [`WELL_KNOWN_SID_TYPE::MediumLabel`](crate::co::WELL_KNOWN_SID_TYPE::MediumLabel).
+	pub integrity_level: Option<SidBuf>,
+	pub mandatory_policy: co::TOKEN_MANDATORY_POLICY,
+	/// Snapshot of the linked token of a split UAC token: the elevated token
+	/// of a limited one, and vice-versa. The linked snapshot itself doesn't
+	/// carry its own linked token.
+	pub linked_token: Option<Box<TokenSnapshot>>,
+	pub session_id: u32,
+	/// App container SID, if the token belongs to an app container.
+	pub app_container_sid: Option<SidBuf>,
+	pub app_container_number: u32,
+	pub capabilities: Vec<(SidBuf, co::SE_GROUP)>,
+	pub token_type: co::TOKEN_TYPE,
+	/// Only meaningful for impersonation tokens.
+	pub impersonation_level: co::SECURITY_IMPERSONATION,
+	pub token_id: LUID,
+	/// Logon session of the token.
+	pub authentication_id: LUID,
+	/// Changes whenever the token is modified.
+	pub modified_id: LUID,
+	pub expiration_time: i64,
+}
+
+impl TokenSnapshot {
+	/// Captures the information of an access token, which must have been
+	/// opened with [`TOKEN::QUERY`](crate::co::TOKEN::QUERY).
+	///
+	/// The linked token is captured when available; privileges whose names
+	/// can't be retrieved are skipped.
+	pub fn capture(htoken: &HACCESSTOKEN) -> SysResult<Self> {
+		Self::capture_impl(htoken, true)
+	}
+
+	fn capture_impl(htoken: &HACCESSTOKEN, with_linked: bool) -> SysResult<Self> {
+		let info = |info_class| htoken.GetTokenInformation(info_class);
+		let groups_of = |groups: &TOKEN_GROUPS| groups.Groups().iter()
+			.filter_map(|g| g.Sid().map(|sid| (
+				SidBuf::from_sid(sid),
+				unsafe { co::SE_GROUP::from_raw(g.Attributes) },
+			)))
+			.collect::<Vec<_>>();
+		let unexpected = co::ERROR::INVALID_PARAMETER; // should never happen
+
+		let user = match info(co::TOKEN_INFORMATION_CLASS::User)? {
+			TokenInfo::User(user) => SidBuf::from_sid(user.User.Sid().ok_or(co::ERROR::INVALID_SID)?),
+			_ => return Err(unexpected),
+		};
+		let owner = match info(co::TOKEN_INFORMATION_CLASS::Owner)? {
+			TokenInfo::Owner(owner) => SidBuf::from_sid(owner.Owner().ok_or(co::ERROR::INVALID_SID)?),
+			_ => return Err(unexpected),
+		};
+		let primary_group = match info(co::TOKEN_INFORMATION_CLASS::PrimaryGroup)? {
+			TokenInfo::PrimaryGroup(group) => SidBuf::from_sid(
+				group.PrimaryGroup().ok_or(co::ERROR::INVALID_SID)?),
+			_ => return Err(unexpected),
+		};
+		let groups = match info(co::TOKEN_INFORMATION_CLASS::Groups)? {
+			TokenInfo::Groups(groups) => groups_of(&groups),
+			_ => return Err(unexpected),
+		};
+		let privileges = match info(co::TOKEN_INFORMATION_CLASS::Privileges)? {
+			TokenInfo::Privileges(privs) => privs.Privileges().iter()
+				.filter_map(|p| LookupPrivilegeName(None, p.Luid).ok().map(|name| TokenPrivilege {
+					name: name.to_string(),
+					luid: p.Luid,
+					attributes: p.Attributes,
+				}))
+				.collect(),
+			_ => return Err(unexpected),
+		};
+		let elevation_type = match info(co::TOKEN_INFORMATION_CLASS::ElevationType)? {
+			TokenInfo::ElevationType(ty) => *ty,
+			_ => return Err(unexpected),
+		};
+		let elevated = match info(co::TOKEN_INFORMATION_CLASS::Elevation)? {
+			TokenInfo::Elevation(elevation) => elevation.TokenIsElevated(),
+			_ => return Err(unexpected),
+		};
+		let integrity_level = match info(co::TOKEN_INFORMATION_CLASS::IntegrityLevel) {
+			Ok(TokenInfo::IntegrityLevel(label)) => label.Label.Sid().map(|sid| SidBuf::from_sid(sid)),
+			_ => None, // anonymous tokens have no integrity level
+		};
+		let mandatory_policy = match info(co::TOKEN_INFORMATION_CLASS::MandatoryPolicy) {
+			Ok(TokenInfo::MandatoryPolicy(policy)) => policy.Policy,
+			_ => co::TOKEN_MANDATORY_POLICY::OFF,
+		};
+
+		let linked_token = if with_linked {
+			match info(co::TOKEN_INFORMATION_CLASS::LinkedToken) {
+				Ok(TokenInfo::LinkedToken(linked)) => {
+					let hlinked = unsafe { CloseHandleGuard::new(linked.LinkedToken.raw_copy()) };
+					Some(Box::new(Self::capture_impl(&hlinked, false)?))
+				},
+				_ => None, // not a split UAC token
+			}
+		} else {
+			None
+		};
+
+		let session_id = match info(co::TOKEN_INFORMATION_CLASS::SessionId)? {
+			TokenInfo::SessionId(id) => *id,
+			_ => return Err(unexpected),
+		};
+		let app_container_sid = match info(co::TOKEN_INFORMATION_CLASS::AppContainerSid) {
+			Ok(TokenInfo::AppContainerSid(ac)) => ac.TokenAppContainer().map(|sid| SidBuf::from_sid(sid)),
+			_ => None,
+		};
+		let app_container_number = match info(co::TOKEN_INFORMATION_CLASS::AppContainerNumber) {
+			Ok(TokenInfo::AppContainerNumber(num)) => *num,
+			_ => 0,
+		};
+		let capabilities = match info(co::TOKEN_INFORMATION_CLASS::Capabilities) {
+			Ok(TokenInfo::Capabilities(caps)) => groups_of(&caps),
+			_ => Vec::new(),
+		};
+
+		let stats = match info(co::TOKEN_INFORMATION_CLASS::Statistics)? {
+			TokenInfo::Statistics(stats) => stats,
+			_ => return Err(unexpected),
+		};
+
+		Ok(Self {
+			user,
+			owner,
+			primary_group,
+			groups,
+			privileges,
+			elevation_type,
+			elevated,
+			integrity_level,
+			mandatory_policy,
+			linked_token,
+			session_id,
+			app_container_sid,
+			app_container_number,
+			capabilities,
+			token_type: stats.TokenType,
+			impersonation_level: stats.ImpersonationLevel,
+			token_id: stats.TokenId,
+			authentication_id: stats.AuthenticationId,
+			modified_id: stats.ModifiedId,
+			expiration_time: stats.ExpirationTime,
+		})
+	}
+
+	/// Tells whether the token is elevated.
+	#[must_use]
+	pub const fn is_elevated(&self) -> bool {
+		self.elevated
+	}
+
+	/// Tells whether the token belongs to an app container.
+	#[must_use]
+	pub const fn is_app_container(&self) -> bool {
+		self.app_container_sid.is_some()
+	}
+
+	/// Tells whether the token has the privilege, and it's enabled.
+	#[must_use]
+	pub fn has_privilege(&self, privilege: co::SE_PRIV) -> bool {
+		let name = privilege.to_string();
+		self.privileges.iter().any(|p| p.name == name && p.is_enabled())
+	}
+
+	/// Tells whether the SID is the user, or an enabled group of the token.
+	#[must_use]
+	pub fn is_member(&self, sid: &SID) -> bool {
+		*self.user == *sid
+			|| self.groups.iter()
+				.any(|(group, attrs)| **group == *sid && attrs.has(co::SE_GROUP::ENABLED))
+	}
+
+	/// Returns the RID of the integrity level, like `0x2000` for medium, if
+	/// any.
+	#[must_use]
+	pub fn integrity_rid(&self) -> Option<co::RID> {
+		self.integrity_level.as_ref().and_then(|sid| sid.rid())
+	}
+
+	/// Builds the subject used by
+	/// [`SecurityDescriptor::access_check`](crate::SecurityDescriptor::access_check)
+	/// out of the snapshot.
+	#[must_use]
+	pub fn access_subject(&self) -> AccessSubject {
+		AccessSubject {
+			user: self.user.clone(),
+			groups: self.groups.clone(),
+			privileges: self.privileges.iter()
+				.filter(|p| p.is_enabled())
+				.filter_map(|p| co::SE_PRIV::try_from(p.name.as_str()).ok())
+				.collect(),
+			integrity_level: self.integrity_level.clone(),
+			mandatory_policy: self.mandatory_policy,
+		}
+	}
+
+	/// Compares this snapshot to a newer one, returning the changes in
+	/// privileges, groups, integrity level and elevation.
+	#[must_use]
+	pub fn diff(&self, newer: &TokenSnapshot) -> TokenDiff {
+		let privs_by = |snap: &TokenSnapshot, pred: &dyn Fn(&TokenPrivilege) -> bool| snap.privileges.iter()
+			.filter(|p| pred(p))
+			.map(|p| p.name.clone())
+			.collect::<Vec<_>>();
+		let find_priv = |snap: &TokenSnapshot, name: &str| snap.privileges.iter()
+			.find(|p| p.name == name)
+			.cloned();
+
+		let privileges_added = privs_by(newer, &|p| find_priv(self, &p.name).is_none());
+		let privileges_removed = privs_by(self, &|p| match find_priv(newer, &p.name) {
+			Some(new) => new.attributes.has(co::SE_PRIV_ATTR::REMOVED)
+				&& !p.attributes.has(co::SE_PRIV_ATTR::REMOVED),
+			None => true,
+		});
+		let privileges_enabled = privs_by(newer, &|p| p.is_enabled()
+			&& find_priv(self, &p.name).is_some_and(|old| !old.is_enabled()));
+		let privileges_disabled = privs_by(newer, &|p| !p.is_enabled()
+			&& find_priv(self, &p.name).is_some_and(|old| old.is_enabled()));
+
+		let groups_by = |from: &TokenSnapshot, to: &TokenSnapshot,
+			pred: &dyn Fn(co::SE_GROUP, Option<co::SE_GROUP>) -> bool|
+		{
+			from.groups.iter()
+				.filter(|(sid, attrs)| pred(*attrs,
+					to.groups.iter().find(|(other, _)| other == sid).map(|(_, a)| *a)))
+				.map(|(sid, _)| sid.clone())
+				.collect::<Vec<_>>()
+		};
+		let enabled = |attrs: co::SE_GROUP| attrs.has(co::SE_GROUP::ENABLED);
+
+		TokenDiff {
+			privileges_added,
+			privileges_removed,
+			privileges_enabled,
+			privileges_disabled,
+			groups_added: groups_by(newer, self, &|_, old| old.is_none()),
+			groups_removed: groups_by(self, newer, &|_, new| new.is_none()),
+			groups_enabled: groups_by(newer, self, &|new, old|
+				enabled(new) && old.is_some_and(|old| !enabled(old))),
+			groups_disabled: groups_by(newer, self, &|new, old|
+				!enabled(new) && old.is_some_and(enabled)),
+			integrity_level_changed: (self.integrity_level != newer.integrity_level)
+				.then(|| newer.integrity_level.clone()),
+			elevation_changed: self.elevated != newer.elevated,
+		}
+	}
+}
+
+/// Changes between two [`TokenSnapshot`](crate::TokenSnapshot) objects, as
+/// returned by [`TokenSnapshot::diff`](crate::TokenSnapshot::diff).
+///
+/// Privileges are identified by name.
+#[derive(Clone, Default, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TokenDiff {
+	pub privileges_added: Vec<String>,
+	/// Privileges which are gone, or were marked as
+	/// [`SE_PRIV_ATTR::REMOVED`](crate::co::SE_PRIV_ATTR::REMOVED).
+	pub privileges_removed: Vec<String>,
+	pub privileges_enabled: Vec<String>,
+	pub privileges_disabled: Vec<String>,
+	pub groups_added: Vec<SidBuf>,
+	pub groups_removed: Vec<SidBuf>,
+	pub groups_enabled: Vec<SidBuf>,
+	pub groups_disabled: Vec<SidBuf>,
+	/// The new integrity level, if it changed.
+	pub integrity_level_changed: Option<Option<SidBuf>>,
+	pub elevation_changed: bool,
+}
+
+impl TokenDiff {
+	/// Tells whether nothing changed.
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		*self == Self::default()
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	fn sid(s: &str) -> SidBuf {
+		SidBuf::parse(s).unwrap()
+	}
+
+	fn privilege(name: &str, attributes: co::SE_PRIV_ATTR) -> TokenPrivilege {
+		TokenPrivilege { name: name.to_owned(), luid: LUID::new(0, 0), attributes }
+	}
+
+	fn snapshot() -> TokenSnapshot {
+		TokenSnapshot {
+			user: sid("S-1-5-21-1-2-3-1001"),
+			owner: sid("S-1-5-21-1-2-3-1001"),
+			primary_group: sid("S-1-5-21-1-2-3-513"),
+			groups: vec![
+				(sid("S-1-1-0"), co::SE_GROUP::MANDATORY | co::SE_GROUP::ENABLED),
+				(sid("S-1-5-32-544"), co::SE_GROUP::USE_FOR_DENY_ONLY),
+				(sid("S-1-5-32-545"), co::SE_GROUP::MANDATORY | co::SE_GROUP::ENABLED),
+			],
+			privileges: vec![
+				privilege("SeShutdownPrivilege", co::SE_PRIV_ATTR::NoValue),
+				privilege("SeChangeNotifyPrivilege", co::SE_PRIV_ATTR::ENABLED | co::SE_PRIV_ATTR::ENABLED_BY_DEFAULT),
+				privilege("SeUndockPrivilege", co::SE_PRIV_ATTR::NoValue),
+			],
+			elevation_type: co::TOKEN_ELEVATION_TYPE::Limited,
+			elevated: false,
+			integrity_level: Some(sid("S-1-16-8192")),
+			mandatory_policy: co::TOKEN_MANDATORY_POLICY::NO_WRITE_UP,
+			linked_token: None,
+			session_id: 1,
+			app_container_sid: None,
+			app_container_number: 0,
+			capabilities: Vec::new(),
+			token_type: co::TOKEN_TYPE::Primary,
+			impersonation_level: co::SECURITY_IMPERSONATION::Anonymous,
+			token_id: LUID::new(1, 0),
+			authentication_id: LUID::new(2, 0),
+			modified_id: LUID::new(3, 0),
+			expiration_time: i64::MAX,
+		}
+	}
+
+	fn names(names: &[&str]) -> Vec<String> {
+		names.iter().map(|n| n.to_string()).collect()
+	}
+
+	type Change = fn(&mut TokenSnapshot);
+
+	#[test]
+	fn diff() {
+		let cases: &[(Change, TokenDiff)] = &[
+			(|_| {}, TokenDiff::default()),
+			(|s| s.modified_id = LUID::new(4, 0), TokenDiff::default()),
+			(|s| s.privileges[0].attributes = co::SE_PRIV_ATTR::ENABLED, TokenDiff {
+				privileges_enabled: names(&["SeShutdownPrivilege"]),
+				..Default::default()
+			}),
+			(|s| s.privileges[1].attributes = co::SE_PRIV_ATTR::ENABLED_BY_DEFAULT, TokenDiff {
+				privileges_disabled: names(&["SeChangeNotifyPrivilege"]),
+				..Default::default()
+			}),
+			(|s| { s.privileges.remove(2); }, TokenDiff {
+				privileges_removed: names(&["SeUndockPrivilege"]),
+				..Default::default()
+			}),
+			(|s| s.privileges[2].attributes = co::SE_PRIV_ATTR::REMOVED, TokenDiff {
+				privileges_removed: names(&["SeUndockPrivilege"]),
+				..Default::default()
+			}),
+			(|s| s.privileges.push(privilege("SeDebugPrivilege", co::SE_PRIV_ATTR::ENABLED)), TokenDiff {
+				privileges_added: names(&["SeDebugPrivilege"]),
+				..Default::default()
+			}),
+			(|s| s.groups[1].1 = co::SE_GROUP::ENABLED | co::SE_GROUP::OWNER, TokenDiff {
+				groups_enabled: vec![sid("S-1-5-32-544")],
+				..Default::default()
+			}),
+			(|s| s.groups[2].1 = co::SE_GROUP::MANDATORY, TokenDiff {
+				groups_disabled: vec![sid("S-1-5-32-545")],
+				..Default::default()
+			}),
+			(|s| s.groups[0] = (sid("S-1-5-11"), co::SE_GROUP::ENABLED), TokenDiff {
+				groups_added: vec![sid("S-1-5-11")],
+				groups_removed: vec![sid("S-1-1-0")],
+				..Default::default()
+			}),
+			(|s| { s.elevated = true; s.integrity_level = Some(sid("S-1-16-12288")); }, TokenDiff {
+				integrity_level_changed: Some(Some(sid("S-1-16-12288"))),
+				elevation_changed: true,
+				..Default::default()
+			}),
+			(|s| s.integrity_level = None, TokenDiff {
+				integrity_level_changed: Some(None),
+				..Default::default()
+			}),
+		];
+		for (idx, (change, expected)) in cases.iter().enumerate() {
+			let old = snapshot();
+			let mut new = snapshot();
+			change(&mut new);
+			let diff = old.diff(&new);
+			assert_eq!(diff, *expected, "case {}", idx);
+			assert_eq!(diff.is_empty(), *expected == TokenDiff::default(), "case {}", idx);
+		}
+	}
+
+	#[test]
+	fn diff_reversed() {
+		let old = snapshot();
+		let mut new = snapshot();
+		new.privileges.push(privilege("SeDebugPrivilege", co::SE_PRIV_ATTR::ENABLED));
+		new.groups.push((sid("S-1-5-11"), co::SE_GROUP::ENABLED));
+
+		let forward = old.diff(&new);
+		let backward = new.diff(&old);
+		assert_eq!(forward.privileges_added, backward.privileges_removed);
+		assert_eq!(forward.groups_added, backward.groups_removed);
+		assert!(backward.privileges_added.is_empty() && backward.groups_added.is_empty());
+	}
+
+	#[cfg(feature = "serde")]
+	#[test]
+	fn serde_consts() {
+		use serde::de::IntoDeserializer;
+
+		fn accepts<T: serde::de::DeserializeOwned>(v: u32) -> bool {
+			T::deserialize(IntoDeserializer::<serde::de::value::Error>::into_deserializer(v)).is_ok()
+		}
+
+		type Accepts = fn(u32) -> bool;
+		let cases: &[(Accepts, u32, bool)] = &[
+			(accepts::<co::SE_GROUP>, 0, true),
+			(accepts::<co::SE_GROUP>, 0xc000_0007, true),
+			(accepts::<co::SE_GROUP>, 0xe000_007f, true),
+			(accepts::<co::SE_GROUP>, 0x0000_0080, false),
+			(accepts::<co::SE_PRIV_ATTR>, 0x8000_0003, true),
+			(accepts::<co::SE_PRIV_ATTR>, 0x0000_0008, false),
+			(accepts::<co::TOKEN_MANDATORY_POLICY>, 0x3, true),
+			(accepts::<co::TOKEN_MANDATORY_POLICY>, 0x4, false),
+			(accepts::<co::TOKEN_ELEVATION_TYPE>, 3, true),
+			(accepts::<co::TOKEN_ELEVATION_TYPE>, 0, false),
+			(accepts::<co::TOKEN_TYPE>, 2, true),
+			(accepts::<co::TOKEN_TYPE>, 3, false),
+			(accepts::<co::SECURITY_IMPERSONATION>, 0, true),
+			(accepts::<co::SECURITY_IMPERSONATION>, 4, false),
+		];
+		for (idx, (accepts, v, ok)) in cases.iter().enumerate() {
+			assert_eq!(accepts(*v), *ok, "case {}: {:#x}", idx, v);
+		}
+	}
+}
@@ -185,6 +185,26 @@ const_wm! { CCM;
 	DPISCALE CCM_FIRST + 0xc
 }
 
+const_ws! { CCS: u32;
+	/// Common control
+	/// [styles](https://learn.microsoft.com/en-us/windows/win32/controls/common-control-styles)
+	/// (`u32`).
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	TOP 0x0000_0001
+	NOMOVEY 0x0000_0002
+	BOTTOM 0x0000_0003
+	NORESIZE 0x0000_0004
+	NOPARENTALIGN 0x0000_0008
+	ADJUSTABLE 0x0000_0020
+	NODIVIDER 0x0000_0040
+	VERT 0x0000_0080
+	LEFT Self::VERT.0 | Self::TOP.0
+	RIGHT Self::VERT.0 | Self::BOTTOM.0
+	NOMOVEX Self::VERT.0 | Self::NOMOVEY.0
+}
+
 const_bitflag! { CDDS: u32;
 	/// [`NMCUSTOMDRAW`](crate::NMCUSTOMDRAW) `dwDrawStage` (`u32`).
 	=>
@@ -233,6 +253,44 @@ const_ordinary! { CDRF: u32;
 	NOTIFYPOSTERASE 0x0000_0040
 }
 
+const_bitflag! { CFE: u32;
+	/// [`CHARFORMAT2`](crate::CHARFORMAT2) `dwEffects` (`u32`).
+	=>
+	BOLD 0x0000_0001
+	ITALIC 0x0000_0002
+	UNDERLINE 0x0000_0004
+	STRIKEOUT 0x0000_0008
+	PROTECTED 0x0000_0010
+	LINK 0x0000_0020
+	HIDDEN 0x0000_0100
+	SUBSCRIPT 0x0001_0000
+	SUPERSCRIPT 0x0002_0000
+	AUTOBACKCOLOR 0x0400_0000
+	AUTOCOLOR 0x4000_0000
+}
+
+const_bitflag! { CFM: u32;
+	/// [`CHARFORMAT2`](crate::CHARFORMAT2) `dwMask` (`u32`).
+	=>
+	BOLD 0x0000_0001
+	ITALIC 0x0000_0002
+	UNDERLINE 0x0000_0004
+	STRIKEOUT 0x0000_0008
+	PROTECTED 0x0000_0010
+	LINK 0x0000_0020
+	HIDDEN 0x0000_0100
+	SUBSCRIPT 0x0003_0000
+	SUPERSCRIPT 0x0003_0000
+	WEIGHT 0x0040_0000
+	UNDERLINETYPE 0x0080_0000
+	BACKCOLOR 0x0400_0000
+	CHARSET 0x0800_0000
+	OFFSET 0x1000_0000
+	FACE 0x2000_0000
+	COLOR 0x4000_0000
+	SIZE 0x8000_0000
+}
+
 const_wm! { DTM;
 	/// Date and time picker control
 	/// [messages](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-date-and-time-picker-control-reference-messages)
@@ -310,6 +368,22 @@ const_values! { EM;
 	FILELINELENGTH EM_FIRST + 21
 	GETFILELINE EM_FIRST + 22
 	GETFILELINECOUNT EM_FIRST + 23
+	EXGETSEL WM::USER.raw() + 52
+	EXLIMITTEXT WM::USER.raw() + 53
+	EXSETSEL WM::USER.raw() + 55
+	GETCHARFORMAT WM::USER.raw() + 58
+	GETEVENTMASK WM::USER.raw() + 59
+	GETPARAFORMAT WM::USER.raw() + 61
+	HIDESELECTION WM::USER.raw() + 63
+	SETBKGNDCOLOR WM::USER.raw() + 67
+	SETCHARFORMAT WM::USER.raw() + 68
+	SETEVENTMASK WM::USER.raw() + 69
+	SETPARAFORMAT WM::USER.raw() + 71
+	STREAMIN WM::USER.raw() + 73
+	STREAMOUT WM::USER.raw() + 74
+	GETTEXTRANGE WM::USER.raw() + 75
+	AUTOURLDETECT WM::USER.raw() + 91
+	FINDTEXTEX WM::USER.raw() + 124
 }
 
 const_bitflag! { EMF: u32;
@@ -319,6 +393,57 @@ const_bitflag! { EMF: u32;
 	CENTERED 0x0000_0001
 }
 
+const_bitflag! { ENM: u32;
+	/// [`em::SetEventMask`](crate::msg::em::SetEventMask) `mask` (`u32`).
+	=>
+	NONE 0x0000_0000
+	CHANGE 0x0000_0001
+	UPDATE 0x0000_0002
+	SCROLL 0x0000_0004
+	SCROLLEVENTS 0x0000_0008
+	DRAGDROPDONE 0x0000_0010
+	PARAGRAPHEXPANDED 0x0000_0020
+	PAGECHANGE 0x0000_0040
+	CLIPFORMAT 0x0000_0080
+	KEYEVENTS 0x0001_0000
+	MOUSEEVENTS 0x0002_0000
+	REQUESTRESIZE 0x0004_0000
+	SELCHANGE 0x0008_0000
+	DROPFILES 0x0010_0000
+	PROTECTED 0x0020_0000
+	CORRECTTEXT 0x0040_0000
+	IMECHANGE 0x0080_0000
+	LANGCHANGE 0x0100_0000
+	OBJECTPOSITIONS 0x0200_0000
+	LINK 0x0400_0000
+	LOWFIRTF 0x0800_0000
+}
+
+const_values! { ES;
+	/// Rich edit control: disables OLE drag and drop.
+	NOOLEDRAGDROP 0x0008
+	/// Rich edit control: disables scroll bars instead of hiding them when
+	/// they're not needed.
+	DISABLENOSCROLL 0x2000
+	/// Rich edit control: preserves the selection when the control loses the
+	/// focus.
+	SAVESEL 0x8000
+	/// Rich edit control: adds space to the left margin where the user can
+	/// click to select lines.
+	SELECTIONBAR 0x0100_0000
+}
+
+const_bitflag! { FR: u32;
+	/// [`em::FindTextEx`](crate::msg::em::FindTextEx) `flags` (`u32`).
+	=>
+	DOWN 0x0000_0001
+	WHOLEWORD 0x0000_0002
+	MATCHCASE 0x0000_0004
+	MATCHDIAC 0x2000_0000
+	MATCHKASHIDA 0x4000_0000
+	MATCHALEFHAMZA 0x8000_0000
+}
+
 const_bitflag! { GDT: u32;
 	/// [`NMDATETIMECHANGE`](crate::NMDATETIMECHANGE) and
 	/// [`NMDATETIMESTRING`](crate::NMDATETIMESTRING) `dwFlags` (`u32`).
@@ -1501,6 +1626,46 @@ const_ordinary! { PBST: u32;
 	PAUSED 0x0003
 }
 
+const_ordinary! { PFA: u16;
+	/// [`PARAFORMAT2`](crate::PARAFORMAT2) `wAlignment` (`u16`).
+	=>
+	LEFT 1
+	RIGHT 2
+	CENTER 3
+	JUSTIFY 4
+	FULL_INTERWORD 4
+}
+
+const_bitflag! { PFM: u32;
+	/// [`PARAFORMAT2`](crate::PARAFORMAT2) `dwMask` (`u32`).
+	=>
+	STARTINDENT 0x0000_0001
+	RIGHTINDENT 0x0000_0002
+	OFFSET 0x0000_0004
+	ALIGNMENT 0x0000_0008
+	TABSTOPS 0x0000_0010
+	NUMBERING 0x0000_0020
+	SPACEBEFORE 0x0000_0040
+	SPACEAFTER 0x0000_0080
+	LINESPACING 0x0000_0100
+	NUMBERINGSTYLE 0x0000_2000
+	NUMBERINGTAB 0x0000_4000
+	NUMBERINGSTART 0x0000_8000
+	OFFSETINDENT 0x8000_0000
+}
+
+const_ordinary! { PFN: u16;
+	/// [`PARAFORMAT2`](crate::PARAFORMAT2) `wNumbering` (`u16`).
+	=>
+	NONE 0
+	BULLET 1
+	ARABIC 2
+	LCLETTER 3
+	UCLETTER 4
+	LCROMAN 5
+	UCROMAN 6
+}
+
 const_bitflag! { PSH: u32;
 	/// [`PROPSHEETHEADER`](crate::PROPSHEETHEADER) `dwFlags` (`u32`).
 	=>
@@ -1639,6 +1804,28 @@ const_ws! { RBS: u32;
 	DBLCLKTOGGLE 0x0000_8000
 }
 
+const_nm! { REN;
+	/// Rich edit control `WM_NOTIFY`
+	/// [notifications](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-rich-edit-control-reference-notifications)
+	/// (`i32`).
+	///
+	/// Originally with `EN` prefix.
+	=>
+	MSGFILTER 0x0700
+	REQUESTRESIZE 0x0701
+	SELCHANGE 0x0702
+	DROPFILES 0x0703
+	PROTECTED 0x0704
+	CORRECTTEXT 0x0705
+	STOPNOUNDO 0x0706
+	IMECHANGE 0x0707
+	SAVECLIPBOARD 0x0708
+	OLEOPFAILED 0x0709
+	OBJECTPOSITIONS 0x070a
+	LINK 0x070b
+	DRAGDROPDONE 0x070c
+}
+
 const_wm! { SB;
 	/// Status bar control
 	/// [messages](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-status-bars-reference-messages)
@@ -1695,6 +1882,41 @@ const_ordinary! { SBT: u16;
 	NOTABPARSING 0x0800
 }
 
+const_bitflag! { SCF: u32;
+	/// [`em::SetCharFormat`](crate::msg::em::SetCharFormat) `scope` (`u32`).
+	=>
+	DEFAULT 0x0000
+	SELECTION 0x0001
+	WORD 0x0002
+	ALL 0x0004
+}
+
+const_bitflag! { SEL: u16;
+	/// [`SELCHANGE`](crate::SELCHANGE) `seltyp` (`u16`).
+	=>
+	EMPTY 0x0000
+	TEXT 0x0001
+	OBJECT 0x0002
+	MULTICHAR 0x0004
+	MULTIOBJECT 0x0008
+}
+
+const_bitflag! { SF: u32;
+	/// [`em::StreamIn`](crate::msg::em::StreamIn) and
+	/// [`em::StreamOut`](crate::msg::em::StreamOut) `format` (`u32`).
+	///
+	/// Also includes the `SFF` prefix constants.
+	=>
+	TEXT 0x0001
+	RTF 0x0002
+	RTFNOOBJS 0x0003
+	TEXTIZED 0x0004
+	UNICODE 0x0010
+	USECODEPAGE 0x0020
+	SFF_PLAINRTF 0x4000
+	SFF_SELECTION 0x8000
+}
+
 const_wm! { STM;
 	/// Static control
 	/// [messages](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-static-control-reference-messages)
@@ -1728,6 +1950,15 @@ const_bitflag! { TBBF: u32;
 	LARGE 0x0001
 }
 
+const_ordinary! { TBDDRET: u32;
+	/// [`TBN_DROPDOWN`](https://learn.microsoft.com/en-us/windows/win32/controls/tbn-dropdown)
+	/// return value (`u32`).
+	=>
+	DEFAULT 0
+	NODEFAULT 1
+	TREATPRESSED 2
+}
+
 const_bitflag! { TBIF: u32;
 	/// [`TBBUTTONINFO`](crate::TBBUTTONINFO) `dwFlags` (`u32`).
 	=>
@@ -1893,6 +2124,14 @@ const_nm! { TBN;
 	WRAPHOTITEM TBN_FIRST - 24
 }
 
+const_bitflag! { TBNF: u32;
+	/// [`NMTBDISPINFO`](crate::NMTBDISPINFO) `dwMask` (`u32`).
+	=>
+	IMAGE 0x0000_0001
+	TEXT 0x0000_0002
+	DI_SETITEM 0x1000_0000
+}
+
 const_ws! { TBS: u32;
 	/// Trackbar control
 	/// [styles](https://learn.microsoft.com/en-us/windows/win32/controls/trackbar-control-styles)
@@ -1936,6 +2175,26 @@ const_bitflag! { TBSTATE: u8;
 	MARKED 0x80
 }
 
+const_ws! { TBSTYLE: u32;
+	/// Toolbar control
+	/// [styles](https://learn.microsoft.com/en-us/windows/win32/controls/toolbar-control-and-button-styles)
+	/// (`u32`).
+	///
+	/// The button styles are in [`BTNS`](crate::co::BTNS), and the common
+	/// control styles are in [`CCS`](crate::co::CCS).
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	TOOLTIPS 0x0100
+	WRAPABLE 0x0200
+	ALTDRAG 0x0400
+	FLAT 0x0800
+	LIST 0x1000
+	CUSTOMERASE 0x2000
+	REGISTERDROP 0x4000
+	TRANSPARENT 0x8000
+}
+
 const_wsex! { TBSTYLE_EX;
 	/// Extended toolbar control
 	/// [styles](https://learn.microsoft.com/en-us/windows/win32/controls/toolbar-extended-styles)
@@ -2201,6 +2460,30 @@ const_nm! { TRBN;
 	THUMBPOSCHANGING TRBN_FIRST - 1
 }
 
+const_ordinary! { TTDT: u32;
+	/// [`ttm::SetDelayTime`](crate::msg::ttm::SetDelayTime) and
+	/// [`ttm::GetDelayTime`](crate::msg::ttm::GetDelayTime) `which` (`u32`).
+	=>
+	AUTOMATIC 0
+	RESHOW 1
+	AUTOPOP 2
+	INITIAL 3
+}
+
+const_bitflag! { TTF: u32;
+	/// [`TTTOOLINFO`](crate::TTTOOLINFO) `uFlags` (`u32`).
+	=>
+	IDISHWND 0x0001
+	CENTERTIP 0x0002
+	RTLREADING 0x0004
+	SUBCLASS 0x0010
+	TRACK 0x0020
+	ABSOLUTE 0x0080
+	TRANSPARENT 0x0100
+	PARSELINKS 0x1000
+	DI_SETITEM 0x8000
+}
+
 const_ordinary! { TTI: i32;
 	/// [`EDITBALLOONTIP`](crate::EDITBALLOONTIP) ttiIcon (`i32`).
 	=>
@@ -2213,6 +2496,73 @@ const_ordinary! { TTI: i32;
 	ERROR_LARGE 6
 }
 
+const_wm! { TTM;
+	/// Tooltip control
+	/// [messages](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-tooltip-control-reference-messages)
+	/// (`u32`).
+	=>
+	ACTIVATE WM::USER.raw() + 1
+	SETDELAYTIME WM::USER.raw() + 3
+	ADDTOOL WM::USER.raw() + 50
+	DELTOOL WM::USER.raw() + 51
+	NEWTOOLRECT WM::USER.raw() + 52
+	RELAYEVENT WM::USER.raw() + 7
+	GETTOOLINFO WM::USER.raw() + 53
+	SETTOOLINFO WM::USER.raw() + 54
+	HITTEST WM::USER.raw() + 55
+	GETTEXT WM::USER.raw() + 56
+	UPDATETIPTEXT WM::USER.raw() + 57
+	GETTOOLCOUNT WM::USER.raw() + 13
+	ENUMTOOLS WM::USER.raw() + 58
+	GETCURRENTTOOL WM::USER.raw() + 59
+	WINDOWFROMPOINT WM::USER.raw() + 16
+	TRACKACTIVATE WM::USER.raw() + 17
+	TRACKPOSITION WM::USER.raw() + 18
+	SETTIPBKCOLOR WM::USER.raw() + 19
+	SETTIPTEXTCOLOR WM::USER.raw() + 20
+	GETDELAYTIME WM::USER.raw() + 21
+	GETTIPBKCOLOR WM::USER.raw() + 22
+	GETTIPTEXTCOLOR WM::USER.raw() + 23
+	SETMAXTIPWIDTH WM::USER.raw() + 24
+	GETMAXTIPWIDTH WM::USER.raw() + 25
+	SETMARGIN WM::USER.raw() + 26
+	GETMARGIN WM::USER.raw() + 27
+	POP WM::USER.raw() + 28
+	UPDATE WM::USER.raw() + 29
+	GETBUBBLESIZE WM::USER.raw() + 30
+	ADJUSTRECT WM::USER.raw() + 31
+	SETTITLE WM::USER.raw() + 33
+	POPUP WM::USER.raw() + 34
+	GETTITLE WM::USER.raw() + 35
+}
+
+const_nm! { TTN;
+	/// Tooltip control `WM_NOTIFY`
+	/// [notifications](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-tooltip-control-reference-notifications)
+	/// (`i32`).
+	=>
+	GETDISPINFO TTN_FIRST - 10
+	SHOW TTN_FIRST - 1
+	POP TTN_FIRST - 2
+	LINKCLICK TTN_FIRST - 3
+}
+
+const_ws! { TTS: u32;
+	/// Tooltip control
+	/// [styles](https://learn.microsoft.com/en-us/windows/win32/controls/tooltip-styles)
+	/// (`u32`).
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	ALWAYSTIP 0x01
+	NOPREFIX 0x02
+	NOANIMATE 0x10
+	NOFADE 0x20
+	BALLOON 0x40
+	CLOSE 0x80
+	USEVISUALSTYLE 0x100
+}
+
 const_ordinary! { TVC: u32;
 	/// [`NMTREEVIEW`](crate::NMTREEVIEW) `action` (`u32`).
 	=>
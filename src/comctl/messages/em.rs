@@ -4,6 +4,132 @@ use crate::msg::*;
 use crate::prelude::*;
 use crate::user::privs::*;
 
+/// [`EM_AUTOURLDETECT`](https://learn.microsoft.com/en-us/windows/win32/controls/em-autourldetect)
+/// message parameters.
+///
+/// Return type: `SysResult<()>`.
+pub struct AutoUrlDetect {
+	pub enable: bool,
+}
+
+impl MsgSend for AutoUrlDetect {
+	type RetType = SysResult<()>;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		match v {
+			0 => Ok(()),
+			_ => Err(co::ERROR::BAD_ARGUMENTS),
+		}
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::EM::AUTOURLDETECT.into(),
+			wparam: self.enable as _,
+			lparam: 0,
+		}
+	}
+}
+
+/// [`EM_EXGETSEL`](https://learn.microsoft.com/en-us/windows/win32/controls/em-exgetsel)
+/// message parameters.
+///
+/// Return type: `()`.
+pub struct ExGetSel<'a> {
+	pub range: &'a mut CHARRANGE,
+}
+
+impl<'a> MsgSend for ExGetSel<'a> {
+	type RetType = ();
+
+	unsafe fn isize_to_ret(&self, _: isize) -> Self::RetType {
+		()
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::EM::EXGETSEL.into(),
+			wparam: 0,
+			lparam: self.range as *mut _ as _,
+		}
+	}
+}
+
+/// [`EM_EXLIMITTEXT`](https://learn.microsoft.com/en-us/windows/win32/controls/em-exlimittext)
+/// message parameters.
+///
+/// If `max_chars` is zero, the default limit of 64,000 characters is used.
+///
+/// Return type: `()`.
+pub struct ExLimitText {
+	pub max_chars: u32,
+}
+
+impl MsgSend for ExLimitText {
+	type RetType = ();
+
+	unsafe fn isize_to_ret(&self, _: isize) -> Self::RetType {
+		()
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::EM::EXLIMITTEXT.into(),
+			wparam: 0,
+			lparam: self.max_chars as _,
+		}
+	}
+}
+
+/// [`EM_EXSETSEL`](https://learn.microsoft.com/en-us/windows/win32/controls/em-exsetsel)
+/// message parameters.
+///
+/// Return type: `i32`.
+pub struct ExSetSel {
+	pub range: CHARRANGE,
+}
+
+impl MsgSend for ExSetSel {
+	type RetType = i32;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		v as _
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::EM::EXSETSEL.into(),
+			wparam: 0,
+			lparam: &self.range as *const _ as _,
+		}
+	}
+}
+
+/// [`EM_FINDTEXTEX`](https://learn.microsoft.com/en-us/windows/win32/controls/em-findtextex)
+/// message parameters.
+///
+/// Return type: `Option<u32>`.
+pub struct FindTextEx<'a, 'b> {
+	pub flags: co::FR,
+	pub info: &'a mut FINDTEXTEX<'b>,
+}
+
+impl<'a, 'b> MsgSend for FindTextEx<'a, 'b> {
+	type RetType = Option<u32>;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		minus1_as_none(v).map(|v| v as _)
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::EM::FINDTEXTEX.into(),
+			wparam: self.flags.raw() as _,
+			lparam: self.info as *mut _ as _,
+		}
+	}
+}
+
 /// [`EM_GETCUEBANNER`](https://learn.microsoft.com/en-us/windows/win32/controls/em-getcuebanner)
 /// message parameters.
 ///
@@ -31,6 +157,76 @@ impl<'a> MsgSend for GetCueBanner<'a> {
 	}
 }
 
+/// [`EM_GETEVENTMASK`](https://learn.microsoft.com/en-us/windows/win32/controls/em-geteventmask)
+/// message, which has no parameters.
+///
+/// Return type: `co::ENM`.
+pub struct GetEventMask {}
+
+impl MsgSend for GetEventMask {
+	type RetType = co::ENM;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		co::ENM::from_raw(v as _)
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::EM::GETEVENTMASK.into(),
+			wparam: 0,
+			lparam: 0,
+		}
+	}
+}
+
+/// [`EM_GETPARAFORMAT`](https://learn.microsoft.com/en-us/windows/win32/controls/em-getparaformat)
+/// message parameters.
+///
+/// Return type: `()`.
+pub struct GetParaFormat<'a> {
+	pub info: &'a mut PARAFORMAT2,
+}
+
+impl<'a> MsgSend for GetParaFormat<'a> {
+	type RetType = ();
+
+	unsafe fn isize_to_ret(&self, _: isize) -> Self::RetType {
+		()
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::EM::GETPARAFORMAT.into(),
+			wparam: 0,
+			lparam: self.info as *mut _ as _,
+		}
+	}
+}
+
+/// [`EM_GETTEXTRANGE`](https://learn.microsoft.com/en-us/windows/win32/controls/em-gettextrange)
+/// message parameters.
+///
+/// Return type: `u32`.
+pub struct GetTextRange<'a, 'b> {
+	pub info: &'a mut TEXTRANGE<'b>,
+}
+
+impl<'a, 'b> MsgSend for GetTextRange<'a, 'b> {
+	type RetType = u32;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		v as _
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::EM::GETTEXTRANGE.into(),
+			wparam: 0,
+			lparam: self.info as *mut _ as _,
+		}
+	}
+}
+
 /// [`EM_HIDEBALLOONTIP`](https://learn.microsoft.com/en-us/windows/win32/controls/em-hideballoontip)
 /// message, which has no parameters.
 ///
@@ -53,6 +249,56 @@ impl MsgSend for HideBalloonTip {
 	}
 }
 
+/// [`EM_HIDESELECTION`](https://learn.microsoft.com/en-us/windows/win32/controls/em-hideselection)
+/// message parameters.
+///
+/// Return type: `()`.
+pub struct HideSelection {
+	pub hide: bool,
+}
+
+impl MsgSend for HideSelection {
+	type RetType = ();
+
+	unsafe fn isize_to_ret(&self, _: isize) -> Self::RetType {
+		()
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::EM::HIDESELECTION.into(),
+			wparam: self.hide as _,
+			lparam: 0,
+		}
+	}
+}
+
+/// [`EM_SETBKGNDCOLOR`](https://learn.microsoft.com/en-us/windows/win32/controls/em-setbkgndcolor)
+/// message parameters.
+///
+/// If `color` is `None`, the system window background color is used.
+///
+/// Return type: `COLORREF`.
+pub struct SetBkgndColor {
+	pub color: Option<COLORREF>,
+}
+
+impl MsgSend for SetBkgndColor {
+	type RetType = COLORREF;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		COLORREF::from_raw(v as _)
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::EM::SETBKGNDCOLOR.into(),
+			wparam: self.color.is_none() as _,
+			lparam: self.color.map_or(0, |c| c.raw() as _),
+		}
+	}
+}
+
 /// [`EM_SETCUEBANNER`](https://learn.microsoft.com/en-us/windows/win32/controls/em-setcuebanner)
 /// message parameters..
 ///
@@ -78,6 +324,54 @@ impl MsgSend for SetCueBanner {
 	}
 }
 
+/// [`EM_SETEVENTMASK`](https://learn.microsoft.com/en-us/windows/win32/controls/em-seteventmask)
+/// message parameters.
+///
+/// Return type: `co::ENM`.
+pub struct SetEventMask {
+	pub mask: co::ENM,
+}
+
+impl MsgSend for SetEventMask {
+	type RetType = co::ENM;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		co::ENM::from_raw(v as _)
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::EM::SETEVENTMASK.into(),
+			wparam: 0,
+			lparam: self.mask.raw() as _,
+		}
+	}
+}
+
+/// [`EM_SETPARAFORMAT`](https://learn.microsoft.com/en-us/windows/win32/controls/em-setparaformat)
+/// message parameters.
+///
+/// Return type: `SysResult<()>`.
+pub struct SetParaFormat<'a> {
+	pub info: &'a PARAFORMAT2,
+}
+
+impl<'a> MsgSend for SetParaFormat<'a> {
+	type RetType = SysResult<()>;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		zero_as_badargs(v).map(|_| ())
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::EM::SETPARAFORMAT.into(),
+			wparam: 0,
+			lparam: self.info as *const _ as _,
+		}
+	}
+}
+
 /// [`EM_SHOWBALLOONTIP`](https://learn.microsoft.com/en-us/windows/win32/controls/em-showballoontip)
 /// message parameters.
 ///
@@ -101,3 +395,53 @@ impl<'a, 'b, 'c> MsgSend for ShowBalloonTip<'a, 'b, 'c> {
 		}
 	}
 }
+
+/// [`EM_STREAMIN`](https://learn.microsoft.com/en-us/windows/win32/controls/em-streamin)
+/// message parameters.
+///
+/// Return type: `u32`.
+pub struct StreamIn<'a> {
+	pub format: co::SF,
+	pub info: &'a mut EDITSTREAM,
+}
+
+impl<'a> MsgSend for StreamIn<'a> {
+	type RetType = u32;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		v as _
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::EM::STREAMIN.into(),
+			wparam: self.format.raw() as _,
+			lparam: self.info as *mut _ as _,
+		}
+	}
+}
+
+/// [`EM_STREAMOUT`](https://learn.microsoft.com/en-us/windows/win32/controls/em-streamout)
+/// message parameters.
+///
+/// Return type: `u32`.
+pub struct StreamOut<'a> {
+	pub format: co::SF,
+	pub info: &'a mut EDITSTREAM,
+}
+
+impl<'a> MsgSend for StreamOut<'a> {
+	type RetType = u32;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		v as _
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::EM::STREAMOUT.into(),
+			wparam: self.format.raw() as _,
+			lparam: self.info as *mut _ as _,
+		}
+	}
+}
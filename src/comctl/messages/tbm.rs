@@ -368,7 +368,7 @@ impl<'a, 'b> MsgSend for GetButtonInfo<'a, 'b> {
 	type RetType = SysResult<u32>;
 
 	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
-		zero_as_badargs(v).map(|v| v as _)
+		minus1_as_badargs(v).map(|v| v as _)
 	}
 
 	fn as_generic_wm(&mut self) -> WndMsg {
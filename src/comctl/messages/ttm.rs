@@ -0,0 +1,383 @@
+use crate::co;
+use crate::decl::*;
+use crate::msg::*;
+use crate::prelude::*;
+use crate::user::privs::*;
+
+/// [`TTM_ACTIVATE`](https://learn.microsoft.com/en-us/windows/win32/controls/ttm-activate)
+/// message parameters.
+///
+/// Return type: `()`.
+pub struct Activate {
+	pub activate: bool,
+}
+
+impl MsgSend for Activate {
+	type RetType = ();
+
+	unsafe fn isize_to_ret(&self, _: isize) -> Self::RetType {
+		()
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::TTM::ACTIVATE.into(),
+			wparam: self.activate as _,
+			lparam: 0,
+		}
+	}
+}
+
+/// [`TTM_ADDTOOL`](https://learn.microsoft.com/en-us/windows/win32/controls/ttm-addtool)
+/// message parameters.
+///
+/// Return type: `SysResult<()>`.
+pub struct AddTool<'a, 'b> {
+	pub info: &'a TTTOOLINFO<'b>,
+}
+
+impl<'a, 'b> MsgSend for AddTool<'a, 'b> {
+	type RetType = SysResult<()>;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		zero_as_badargs(v).map(|_| ())
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::TTM::ADDTOOL.into(),
+			wparam: 0,
+			lparam: self.info as *const _ as _,
+		}
+	}
+}
+
+/// [`TTM_DELTOOL`](https://learn.microsoft.com/en-us/windows/win32/controls/ttm-deltool)
+/// message parameters.
+///
+/// Return type: `()`.
+pub struct DelTool<'a, 'b> {
+	pub info: &'a TTTOOLINFO<'b>,
+}
+
+impl<'a, 'b> MsgSend for DelTool<'a, 'b> {
+	type RetType = ();
+
+	unsafe fn isize_to_ret(&self, _: isize) -> Self::RetType {
+		()
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::TTM::DELTOOL.into(),
+			wparam: 0,
+			lparam: self.info as *const _ as _,
+		}
+	}
+}
+
+/// [`TTM_GETDELAYTIME`](https://learn.microsoft.com/en-us/windows/win32/controls/ttm-getdelaytime)
+/// message parameters.
+///
+/// Return type: `u32`.
+pub struct GetDelayTime {
+	pub which: co::TTDT,
+}
+
+impl MsgSend for GetDelayTime {
+	type RetType = u32;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		v as _
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::TTM::GETDELAYTIME.into(),
+			wparam: self.which.raw() as _,
+			lparam: 0,
+		}
+	}
+}
+
+/// [`TTM_GETMAXTIPWIDTH`](https://learn.microsoft.com/en-us/windows/win32/controls/ttm-getmaxtipwidth)
+/// message, which has no parameters.
+///
+/// Return type: `i32`.
+pub struct GetMaxTipWidth {}
+
+impl MsgSend for GetMaxTipWidth {
+	type RetType = i32;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		v as _
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::TTM::GETMAXTIPWIDTH.into(),
+			wparam: 0,
+			lparam: 0,
+		}
+	}
+}
+
+/// [`TTM_GETTOOLCOUNT`](https://learn.microsoft.com/en-us/windows/win32/controls/ttm-gettoolcount)
+/// message, which has no parameters.
+///
+/// Return type: `u32`.
+pub struct GetToolCount {}
+
+impl MsgSend for GetToolCount {
+	type RetType = u32;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		v as _
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::TTM::GETTOOLCOUNT.into(),
+			wparam: 0,
+			lparam: 0,
+		}
+	}
+}
+
+/// [`TTM_GETTOOLINFO`](https://learn.microsoft.com/en-us/windows/win32/controls/ttm-gettoolinfo)
+/// message parameters.
+///
+/// Return type: `SysResult<()>`.
+pub struct GetToolInfo<'a, 'b> {
+	pub info: &'a mut TTTOOLINFO<'b>,
+}
+
+impl<'a, 'b> MsgSend for GetToolInfo<'a, 'b> {
+	type RetType = SysResult<()>;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		zero_as_badargs(v).map(|_| ())
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::TTM::GETTOOLINFO.into(),
+			wparam: 0,
+			lparam: self.info as *mut _ as _,
+		}
+	}
+}
+
+/// [`TTM_NEWTOOLRECT`](https://learn.microsoft.com/en-us/windows/win32/controls/ttm-newtoolrect)
+/// message parameters.
+///
+/// Return type: `()`.
+pub struct NewToolRect<'a, 'b> {
+	pub info: &'a TTTOOLINFO<'b>,
+}
+
+impl<'a, 'b> MsgSend for NewToolRect<'a, 'b> {
+	type RetType = ();
+
+	unsafe fn isize_to_ret(&self, _: isize) -> Self::RetType {
+		()
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::TTM::NEWTOOLRECT.into(),
+			wparam: 0,
+			lparam: self.info as *const _ as _,
+		}
+	}
+}
+
+pub_struct_msg_empty! { Pop: co::TTM::POP.into();
+	/// [`TTM_POP`](https://learn.microsoft.com/en-us/windows/win32/controls/ttm-pop)
+}
+
+pub_struct_msg_empty! { Popup: co::TTM::POPUP.into();
+	/// [`TTM_POPUP`](https://learn.microsoft.com/en-us/windows/win32/controls/ttm-popup)
+}
+
+/// [`TTM_SETDELAYTIME`](https://learn.microsoft.com/en-us/windows/win32/controls/ttm-setdelaytime)
+/// message parameters.
+///
+/// If `time_ms` is `None`, the default delay is restored.
+///
+/// Return type: `()`.
+pub struct SetDelayTime {
+	pub which: co::TTDT,
+	pub time_ms: Option<u16>,
+}
+
+impl MsgSend for SetDelayTime {
+	type RetType = ();
+
+	unsafe fn isize_to_ret(&self, _: isize) -> Self::RetType {
+		()
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::TTM::SETDELAYTIME.into(),
+			wparam: self.which.raw() as _,
+			lparam: self.time_ms.map_or(-1, |ms| ms as _),
+		}
+	}
+}
+
+/// [`TTM_SETMAXTIPWIDTH`](https://learn.microsoft.com/en-us/windows/win32/controls/ttm-setmaxtipwidth)
+/// message parameters.
+///
+/// A width of `-1` allows any width. A width is needed to display multiline
+/// text.
+///
+/// Return type: `i32`.
+pub struct SetMaxTipWidth {
+	pub width: i32,
+}
+
+impl MsgSend for SetMaxTipWidth {
+	type RetType = i32;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		v as _
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::TTM::SETMAXTIPWIDTH.into(),
+			wparam: 0,
+			lparam: self.width as _,
+		}
+	}
+}
+
+/// [`TTM_SETTITLE`](https://learn.microsoft.com/en-us/windows/win32/controls/ttm-settitle)
+/// message parameters.
+///
+/// Return type: `SysResult<()>`.
+pub struct SetTitle {
+	pub icon: co::TTI,
+	pub title: WString,
+}
+
+impl MsgSend for SetTitle {
+	type RetType = SysResult<()>;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		zero_as_badargs(v).map(|_| ())
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::TTM::SETTITLE.into(),
+			wparam: self.icon.raw() as _,
+			lparam: self.title.as_ptr() as _,
+		}
+	}
+}
+
+/// [`TTM_SETTOOLINFO`](https://learn.microsoft.com/en-us/windows/win32/controls/ttm-settoolinfo)
+/// message parameters.
+///
+/// Return type: `()`.
+pub struct SetToolInfo<'a, 'b> {
+	pub info: &'a TTTOOLINFO<'b>,
+}
+
+impl<'a, 'b> MsgSend for SetToolInfo<'a, 'b> {
+	type RetType = ();
+
+	unsafe fn isize_to_ret(&self, _: isize) -> Self::RetType {
+		()
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::TTM::SETTOOLINFO.into(),
+			wparam: 0,
+			lparam: self.info as *const _ as _,
+		}
+	}
+}
+
+/// [`TTM_TRACKACTIVATE`](https://learn.microsoft.com/en-us/windows/win32/controls/ttm-trackactivate)
+/// message parameters.
+///
+/// Return type: `()`.
+pub struct TrackActivate<'a, 'b> {
+	pub activate: bool,
+	pub info: &'a TTTOOLINFO<'b>,
+}
+
+impl<'a, 'b> MsgSend for TrackActivate<'a, 'b> {
+	type RetType = ();
+
+	unsafe fn isize_to_ret(&self, _: isize) -> Self::RetType {
+		()
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::TTM::TRACKACTIVATE.into(),
+			wparam: self.activate as _,
+			lparam: self.info as *const _ as _,
+		}
+	}
+}
+
+/// [`TTM_TRACKPOSITION`](https://learn.microsoft.com/en-us/windows/win32/controls/ttm-trackposition)
+/// message parameters.
+///
+/// The coordinates are relative to the screen.
+///
+/// Return type: `()`.
+pub struct TrackPosition {
+	pub pos: POINT,
+}
+
+impl MsgSend for TrackPosition {
+	type RetType = ();
+
+	unsafe fn isize_to_ret(&self, _: isize) -> Self::RetType {
+		()
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::TTM::TRACKPOSITION.into(),
+			wparam: 0,
+			lparam: MAKEDWORD(self.pos.x as _, self.pos.y as _) as _,
+		}
+	}
+}
+
+pub_struct_msg_empty! { Update: co::TTM::UPDATE.into();
+	/// [`TTM_UPDATE`](https://learn.microsoft.com/en-us/windows/win32/controls/ttm-update)
+}
+
+/// [`TTM_UPDATETIPTEXT`](https://learn.microsoft.com/en-us/windows/win32/controls/ttm-updatetiptext)
+/// message parameters.
+///
+/// Return type: `()`.
+pub struct UpdateTipText<'a, 'b> {
+	pub info: &'a TTTOOLINFO<'b>,
+}
+
+impl<'a, 'b> MsgSend for UpdateTipText<'a, 'b> {
+	type RetType = ();
+
+	unsafe fn isize_to_ret(&self, _: isize) -> Self::RetType {
+		()
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::TTM::UPDATETIPTEXT.into(),
+			wparam: 0,
+			lparam: self.info as *const _ as _,
+		}
+	}
+}
@@ -9,8 +9,10 @@ const_values_num_privs! {
 	HINST_COMMCTRL isize = -1
 	I_IMAGECALLBACK isize = -1
 	I_IMAGENONE isize = -2
+	LPSTR_TEXTCALLBACK isize = -1
 	L_MAX_URL_LENGTH usize = 2048 + 32 + 4
 	MAX_LINKID_TEXT usize = 48
+	MAX_TAB_STOPS usize = 32
 }
 
 const_values_num_privs! {
@@ -37,6 +39,7 @@ const_values_num_privs! {
 	TCN_FIRST i32 = -550
 	TRBN_FIRST i32 = -1501
 	TVM_FIRST u32 = 0x1100
+	TTN_FIRST i32 = -520
 	TVN_FIRST i32 = -400
 	UDN_FIRST i32 = -721
 }
@@ -27,6 +27,15 @@ pub struct BUTTON_SPLITINFO {
 	pub size: SIZE,
 }
 
+/// [`CHARRANGE`](https://learn.microsoft.com/en-us/windows/win32/api/richedit/ns-richedit-charrange)
+/// struct.
+#[repr(C)]
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub struct CHARRANGE {
+	pub cpMin: i32,
+	pub cpMax: i32,
+}
+
 /// [`COLORSCHEME`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-colorscheme)
 /// struct.
 #[repr(C)]
@@ -74,6 +83,56 @@ impl<'a, 'b> EDITBALLOONTIP<'a, 'b> {
 	pub_fn_string_ptr_get_set!('b, pszText, set_pszText);
 }
 
+/// [`EDITSTREAM`](https://learn.microsoft.com/en-us/windows/win32/api/richedit/ns-richedit-editstream)
+/// struct.
+#[repr(C, packed(4))]
+pub struct EDITSTREAM {
+	pub dwCookie: usize,
+	pub dwError: u32,
+	pub pfnCallback: Option<EDITSTREAMCALLBACK>,
+}
+
+impl_default!(EDITSTREAM);
+
+/// [`ENLINK`](https://learn.microsoft.com/en-us/windows/win32/api/richedit/ns-richedit-enlink)
+/// struct.
+#[repr(C, packed(4))]
+pub struct ENLINK {
+	pub nmhdr: NMHDR,
+	pub msg: co::WM,
+	pub wParam: usize,
+	pub lParam: isize,
+	pub chrg: CHARRANGE,
+}
+
+/// [`ENPROTECTED`](https://learn.microsoft.com/en-us/windows/win32/api/richedit/ns-richedit-enprotected)
+/// struct.
+#[repr(C, packed(4))]
+pub struct ENPROTECTED {
+	pub nmhdr: NMHDR,
+	pub msg: co::WM,
+	pub wParam: usize,
+	pub lParam: isize,
+	pub chrg: CHARRANGE,
+}
+
+/// [`FINDTEXTEX`](https://learn.microsoft.com/en-us/windows/win32/api/richedit/ns-richedit-findtextexw)
+/// struct.
+#[repr(C, packed(4))]
+pub struct FINDTEXTEX<'a> {
+	pub chrg: CHARRANGE,
+	lpstrText: *mut u16,
+	pub chrgText: CHARRANGE,
+
+	_lpstrText: PhantomData<&'a mut u16>,
+}
+
+impl_default!(FINDTEXTEX, 'a);
+
+impl<'a> FINDTEXTEX<'a> {
+	pub_fn_string_ptr_get_set!('a, lpstrText, set_lpstrText);
+}
+
 /// [`HDITEM`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-hditemw)
 /// struct.
 #[repr(C)]
@@ -1064,6 +1123,78 @@ pub struct NMMOUSE {
 	pub dwHitInfo: isize,
 }
 
+/// [`NMTBDISPINFO`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-nmtbdispinfow)
+/// struct.
+#[repr(C)]
+pub struct NMTBDISPINFO<'a> {
+	pub hdr: NMHDR,
+	pub dwMask: co::TBNF,
+	pub idCommand: i32,
+	pub lParam: usize,
+	pub iImage: i32,
+	pszText: *mut u16,
+	cchText: i32,
+
+	_pszText: PhantomData<&'a mut u16>,
+}
+
+impl_default!(NMTBDISPINFO, 'a);
+
+impl<'a> NMTBDISPINFO<'a> {
+	pub_fn_string_buf_get_set!('a, pszText, set_pszText, raw_pszText, cchText);
+}
+
+/// [`NMTBGETINFOTIP`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-nmtbgetinfotipw)
+/// struct.
+#[repr(C)]
+pub struct NMTBGETINFOTIP<'a> {
+	pub hdr: NMHDR,
+	pszText: *mut u16,
+	cchTextMax: i32,
+	pub iItem: i32,
+	pub lParam: isize,
+
+	_pszText: PhantomData<&'a mut u16>,
+}
+
+impl_default!(NMTBGETINFOTIP, 'a);
+
+impl<'a> NMTBGETINFOTIP<'a> {
+	pub_fn_string_buf_get_set!('a, pszText, set_pszText, raw_pszText, cchTextMax);
+}
+
+/// [`NMTBHOTITEM`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-nmtbhotitem)
+/// struct.
+#[repr(C)]
+pub struct NMTBHOTITEM {
+	pub hdr: NMHDR,
+	pub idOld: i32,
+	pub idNew: i32,
+	pub dwFlags: co::HICF,
+}
+
+impl_default!(NMTBHOTITEM);
+
+/// [`NMTOOLBAR`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-nmtoolbarw)
+/// struct.
+#[repr(C)]
+pub struct NMTOOLBAR<'a> {
+	pub hdr: NMHDR,
+	pub iItem: i32,
+	pub tbButton: TBBUTTON<'a>,
+	cchText: i32,
+	pszText: *mut u16,
+	pub rcButton: RECT,
+
+	_pszText: PhantomData<&'a mut u16>,
+}
+
+impl_default!(NMTOOLBAR, 'a);
+
+impl<'a> NMTOOLBAR<'a> {
+	pub_fn_string_buf_get_set!('a, pszText, set_pszText, raw_pszText, cchText);
+}
+
 /// [`NMTRBTHUMBPOSCHANGING`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-nmtrbthumbposchanging)
 /// struct.
 #[repr(C)]
@@ -1104,6 +1235,27 @@ pub struct NMTREEVIEW<'a, 'b> {
 	pub ptDrag: POINT,
 }
 
+/// [`NMTTDISPINFO`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-nmttdispinfow)
+/// struct.
+#[repr(C)]
+pub struct NMTTDISPINFO<'a> {
+	pub hdr: NMHDR,
+	lpszText: *mut u16,
+	szText: [u16; 80],
+	pub hinst: HINSTANCE,
+	pub uFlags: co::TTF,
+	pub lParam: isize,
+
+	_lpszText: PhantomData<&'a mut u16>,
+}
+
+impl_default!(NMTTDISPINFO, 'a);
+
+impl<'a> NMTTDISPINFO<'a> {
+	pub_fn_string_ptr_get_set!('a, lpszText, set_lpszText);
+	pub_fn_string_arr_get_set!(szText, set_szText);
+}
+
 /// [`NMTVCUSTOMDRAW`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-nmtvcustomdraw)
 /// stuct.
 #[repr(C)]
@@ -1153,6 +1305,59 @@ pub struct PBRANGE {
 	pub iHigh: i32,
 }
 
+/// [`PARAFORMAT2`](https://learn.microsoft.com/en-us/windows/win32/api/richedit/ns-richedit-paraformat2)
+/// struct.
+#[repr(C)]
+pub struct PARAFORMAT2 {
+	cbSize: u32,
+	pub dwMask: co::PFM,
+	pub wNumbering: co::PFN,
+	pub wEffects: u16,
+	pub dxStartIndent: i32,
+	pub dxRightIndent: i32,
+	pub dxOffset: i32,
+	pub wAlignment: co::PFA,
+	cTabCount: i16,
+	rgxTabs: [i32; MAX_TAB_STOPS],
+	pub dySpaceBefore: i32,
+	pub dySpaceAfter: i32,
+	pub dyLineSpacing: i32,
+	pub sStyle: i16,
+	pub bLineSpacingRule: u8,
+	pub bOutlineLevel: u8,
+	pub wShadingWeight: u16,
+	pub wShadingStyle: u16,
+	pub wNumberingStart: u16,
+	pub wNumberingStyle: u16,
+	pub wNumberingTab: u16,
+	pub wBorderSpace: u16,
+	pub wBorderWidth: u16,
+	pub wBorders: u16,
+}
+
+impl_default_with_size!(PARAFORMAT2, cbSize);
+
+impl PARAFORMAT2 {
+	/// Returns the `rgxTabs` field, limited to `cTabCount` items.
+	#[must_use]
+	pub fn rgxTabs(&self) -> &[i32] {
+		&self.rgxTabs[..self.cTabCount as usize]
+	}
+
+	/// Sets the `rgxTabs` and `cTabCount` fields.
+	///
+	/// # Panics
+	///
+	/// Panics if `tabs` has more than 32 items.
+	pub fn set_rgxTabs(&mut self, tabs: &[i32]) {
+		if tabs.len() > MAX_TAB_STOPS {
+			panic!("Too many tab stops: {}.", tabs.len());
+		}
+		self.rgxTabs[..tabs.len()].copy_from_slice(tabs);
+		self.cTabCount = tabs.len() as _;
+	}
+}
+
 /// [`PROPSHEETHEADER`](https://learn.microsoft.com/en-us/windows/win32/controls/pss-propsheetheader)
 /// struct.
 #[repr(C)]
@@ -1331,6 +1536,15 @@ impl<'a, 'b, 'c, 'd, 'e, 'f, 'g> PROPSHEETPAGE<'a, 'b, 'c, 'd, 'e, 'f, 'g> {
 	}
 }
 
+/// [`SELCHANGE`](https://learn.microsoft.com/en-us/windows/win32/api/richedit/ns-richedit-selchange)
+/// struct.
+#[repr(C, packed(4))]
+pub struct SELCHANGE {
+	pub nmhdr: NMHDR,
+	pub chrg: CHARRANGE,
+	pub seltyp: co::SEL,
+}
+
 /// [`TBADDBITMAP`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-tbaddbitmap)
 /// struct.
 #[repr(C)]
@@ -1564,6 +1778,74 @@ impl<'a> TCITEM<'a> {
 	pub_fn_string_buf_get_set!('a, pszText, set_pszText, raw_pszText, cchTextMax);
 }
 
+/// [`TEXTRANGE`](https://learn.microsoft.com/en-us/windows/win32/api/richedit/ns-richedit-textrangew)
+/// struct.
+#[repr(C, packed(4))]
+pub struct TEXTRANGE<'a> {
+	pub chrg: CHARRANGE,
+	lpstrText: *mut u16,
+
+	_lpstrText: PhantomData<&'a mut u16>,
+}
+
+impl_default!(TEXTRANGE, 'a);
+
+impl<'a> TEXTRANGE<'a> {
+	pub_fn_string_ptr_get_set!('a, lpstrText, set_lpstrText);
+}
+
+/// [`TTTOOLINFO`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-tttoolinfow)
+/// struct.
+#[repr(C)]
+pub struct TTTOOLINFO<'a> {
+	cbSize: u32,
+	pub uFlags: co::TTF,
+	pub hwnd: HWND,
+	pub uId: usize,
+	pub rect: RECT,
+	pub hinst: HINSTANCE,
+	lpszText: *mut u16,
+	pub lParam: isize,
+	lpReserved: PVOID,
+
+	_lpszText: PhantomData<&'a mut u16>,
+}
+
+impl_default_with_size!(TTTOOLINFO, cbSize, 'a);
+
+impl<'a> TTTOOLINFO<'a> {
+	/// Returns the `lpszText` field, if any.
+	///
+	/// Returns `None` if the field is null or
+	/// [`LPSTR_TEXTCALLBACK`](crate::TTTOOLINFO::set_lpszText_callback).
+	#[must_use]
+	pub fn lpszText(&self) -> Option<String> {
+		if self.lpszText as isize == LPSTR_TEXTCALLBACK {
+			None
+		} else {
+			unsafe { self.lpszText.as_mut() }.map(|psz| {
+				unsafe { WString::from_wchars_nullt(psz) }.to_string()
+			})
+		}
+	}
+
+	/// Sets the `lpszText` field.
+	pub fn set_lpszText(&mut self, buf: Option<&'a mut WString>) {
+		self.lpszText = buf.map_or(
+			std::ptr::null_mut(),
+			|buf| unsafe { buf.as_mut_ptr() },
+		);
+	}
+
+	/// Sets the `lpszText` field to `LPSTR_TEXTCALLBACK`, so the text will be
+	/// requested through the
+	/// [`TTN_GETDISPINFO`](https://learn.microsoft.com/en-us/windows/win32/controls/ttn-getdispinfo)
+	/// notification.
+	pub fn set_lpszText_callback(&mut self) {
+		self.lpszText = LPSTR_TEXTCALLBACK as _;
+	}
+}
+
 /// [`TVHITTESTINFO`](https://learn.microsoft.com/en-us/windows/win32/api/commctrl/ns-commctrl-tvhittestinfo)
 /// struct.
 #[repr(C)]
@@ -0,0 +1,55 @@
+use crate::co;
+use crate::decl::*;
+use crate::msg::*;
+use crate::prelude::*;
+use crate::user::privs::*;
+
+/// [`EM_GETCHARFORMAT`](https://learn.microsoft.com/en-us/windows/win32/controls/em-getcharformat)
+/// message parameters.
+///
+/// Return type: `()`.
+pub struct GetCharFormat<'a> {
+	pub scope: co::SCF,
+	pub info: &'a mut CHARFORMAT2,
+}
+
+impl<'a> MsgSend for GetCharFormat<'a> {
+	type RetType = ();
+
+	unsafe fn isize_to_ret(&self, _: isize) -> Self::RetType {
+		()
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::EM::GETCHARFORMAT.into(),
+			wparam: self.scope.raw() as _,
+			lparam: self.info as *mut _ as _,
+		}
+	}
+}
+
+/// [`EM_SETCHARFORMAT`](https://learn.microsoft.com/en-us/windows/win32/controls/em-setcharformat)
+/// message parameters.
+///
+/// Return type: `SysResult<()>`.
+pub struct SetCharFormat<'a> {
+	pub scope: co::SCF,
+	pub info: &'a CHARFORMAT2,
+}
+
+impl<'a> MsgSend for SetCharFormat<'a> {
+	type RetType = SysResult<()>;
+
+	unsafe fn isize_to_ret(&self, v: isize) -> Self::RetType {
+		zero_as_badargs(v).map(|_| ())
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::EM::SETCHARFORMAT.into(),
+			wparam: self.scope.raw() as _,
+			lparam: self.info as *const _ as _,
+		}
+	}
+}
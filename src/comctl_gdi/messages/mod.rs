@@ -1 +1,2 @@
 pub mod dtm;
+pub mod em;
@@ -4,6 +4,39 @@ use std::marker::PhantomData;
 
 use crate::co;
 use crate::decl::*;
+use crate::gdi::privs::*;
+
+/// [`CHARFORMAT2`](https://learn.microsoft.com/en-us/windows/win32/api/richedit/ns-richedit-charformat2w)
+/// struct.
+#[repr(C)]
+pub struct CHARFORMAT2 {
+	cbSize: u32,
+	pub dwMask: co::CFM,
+	pub dwEffects: co::CFE,
+	pub yHeight: i32,
+	pub yOffset: i32,
+	pub crTextColor: COLORREF,
+	pub bCharSet: co::CHARSET,
+	pub bPitchAndFamily: co::PITCH,
+	szFaceName: [u16; LF_FACESIZE],
+	pub wWeight: u16,
+	pub sSpacing: i16,
+	pub crBackColor: COLORREF,
+	pub lcid: LCID,
+	pub dwCookie: u32,
+	pub sStyle: i16,
+	pub wKerning: u16,
+	pub bUnderlineType: u8,
+	pub bAnimation: u8,
+	pub bRevAuthor: u8,
+	pub bUnderlineColor: u8,
+}
+
+impl_default_with_size!(CHARFORMAT2, cbSize);
+
+impl CHARFORMAT2 {
+	pub_fn_string_arr_get_set!(szFaceName, set_szFaceName);
+}
 
 /// [`IMAGELISTDRAWPARAMS`](https://learn.microsoft.com/en-us/windows/win32/api/commoncontrols/ns-commoncontrols-imagelistdrawparams)
 /// struct.
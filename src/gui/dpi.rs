@@ -0,0 +1,213 @@
+//! DPI scaling arithmetic.
+//!
+//! Sizes and positions in the `*Opts` structs, as well as in
+//! [`layout`](crate::gui::layout), are given in DPI-independent units, which
+//! are pixels at [`USER_DEFAULT_SCREEN_DPI`] (100% scaling). They are scaled to
+//! the DPI of the window they're applied to, and rescaled when the window is
+//! moved to a monitor with another DPI, since
+//! [`WindowMain::run_main`](crate::gui::WindowMain::run_main) makes the process
+//! per-monitor DPI aware.
+//!
+//! These functions don't query the system, so they can be used with any DPI
+//! value, like the one carried by the
+//! [`wm_dpi_changed`](crate::gui::events::WindowEvents::wm_dpi_changed) event.
+//!
+//! # Examples
+//!
+//! Recreating an image list when the window moves to another monitor:
+//!
+//! ```no_run
+//! use winsafe::{self as w, prelude::*, co, gui};
+//!
+//! let wnd: gui::WindowMain; // initialized somewhere
+//! # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+//!
+//! wnd.on().wm_dpi_changed(move |p| {
+//!     let sz = gui::dpi::to_pixels_size(w::SIZE::new(16, 16), p.dpi_x as _);
+//!     let himgl = w::HIMAGELIST::Create(sz, co::ILC::COLOR32, 1, 1)?;
+//!     // ... fill and set the new image list
+//!     # let _ = himgl;
+//!     Ok(())
+//! });
+//! ```
+
+use crate::decl::*;
+
+/// The DPI in which one DPI-independent unit equals one pixel: 96.
+pub const USER_DEFAULT_SCREEN_DPI: u32 = 96;
+
+/// Scales a value from a DPI to another, rounding half away from zero, like
+/// [`MulDiv`](crate::MulDiv) does.
+///
+/// If any of the DPI values is zero, the value is returned unchanged. The
+/// result is saturated to the `i32` bounds, so `i32::MAX` and `i32::MIN` are
+/// kept as they are when scaling up.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::gui;
+///
+/// assert_eq!(gui::dpi::scale(10, 96, 144), 15);
+/// assert_eq!(gui::dpi::scale(-5, 96, 120), -6);
+/// ```
+#[must_use]
+pub const fn scale(value: i32, from_dpi: u32, to_dpi: u32) -> i32 {
+	if from_dpi == 0 || to_dpi == 0 || from_dpi == to_dpi {
+		return value;
+	}
+
+	let num = value as i64 * to_dpi as i64;
+	let den = from_dpi as i64;
+	let res = if num < 0 { (num - den / 2) / den } else { (num + den / 2) / den };
+
+	if res > i32::MAX as i64 {
+		i32::MAX
+	} else if res < i32::MIN as i64 {
+		i32::MIN
+	} else {
+		res as i32
+	}
+}
+
+/// Scales the coordinates of a point from a DPI to another.
+#[must_use]
+pub const fn scale_point(pt: POINT, from_dpi: u32, to_dpi: u32) -> POINT {
+	POINT::new(scale(pt.x, from_dpi, to_dpi), scale(pt.y, from_dpi, to_dpi))
+}
+
+/// Scales the dimensions of a size from a DPI to another.
+#[must_use]
+pub const fn scale_size(sz: SIZE, from_dpi: u32, to_dpi: u32) -> SIZE {
+	SIZE::new(scale(sz.cx, from_dpi, to_dpi), scale(sz.cy, from_dpi, to_dpi))
+}
+
+/// Scales the edges of a rectangle from a DPI to another.
+///
+/// Each edge is scaled independently, so rectangles which share an edge keep
+/// sharing it after the scaling.
+#[must_use]
+pub const fn scale_rect(rc: RECT, from_dpi: u32, to_dpi: u32) -> RECT {
+	RECT {
+		left: scale(rc.left, from_dpi, to_dpi),
+		top: scale(rc.top, from_dpi, to_dpi),
+		right: scale(rc.right, from_dpi, to_dpi),
+		bottom: scale(rc.bottom, from_dpi, to_dpi),
+	}
+}
+
+/// Converts DPI-independent units to pixels in the given DPI.
+#[must_use]
+pub const fn to_pixels(value: i32, dpi: u32) -> i32 {
+	scale(value, USER_DEFAULT_SCREEN_DPI, dpi)
+}
+
+/// Converts pixels in the given DPI to DPI-independent units.
+#[must_use]
+pub const fn to_dips(value: i32, dpi: u32) -> i32 {
+	scale(value, dpi, USER_DEFAULT_SCREEN_DPI)
+}
+
+/// Converts a point in DPI-independent units to pixels in the given DPI.
+#[must_use]
+pub const fn to_pixels_point(pt: POINT, dpi: u32) -> POINT {
+	scale_point(pt, USER_DEFAULT_SCREEN_DPI, dpi)
+}
+
+/// Converts a size in DPI-independent units to pixels in the given DPI.
+#[must_use]
+pub const fn to_pixels_size(sz: SIZE, dpi: u32) -> SIZE {
+	scale_size(sz, USER_DEFAULT_SCREEN_DPI, dpi)
+}
+
+/// Returns the height of a font with the given point size, in the given DPI,
+/// to be used as the `lfHeight` field of
+/// [`LOGFONT`](crate::LOGFONT).
+///
+/// The result is negative, which means the font will be matched by its
+/// character height, rather than its cell height.
+#[must_use]
+pub const fn font_height(points: i32, dpi: u32) -> i32 {
+	-scale(points, 72, dpi)
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn scale_rounding() {
+		let cases: &[(i32, u32, u32, i32)] = &[
+			(10, 96, 144, 15),
+			(15, 144, 96, 10),
+			(0, 96, 144, 0),
+			// half away from zero
+			(1, 96, 144, 2),
+			(-1, 96, 144, -2),
+			(3, 96, 120, 4),
+			(-3, 96, 120, -4),
+			(-5, 96, 120, -6),
+			// below half
+			(1, 96, 120, 1),
+			(-1, 96, 120, -1),
+			(1, 144, 96, 1),
+			(-1, 144, 96, -1),
+			(1, 96, 24, 0),
+			(-1, 96, 24, 0),
+		];
+
+		for (value, from_dpi, to_dpi, expected) in cases.iter().copied() {
+			assert_eq!(scale(value, from_dpi, to_dpi), expected,
+				"scale({}, {}, {})", value, from_dpi, to_dpi);
+		}
+	}
+
+	#[test]
+	fn scale_saturation() {
+		assert_eq!(scale(i32::MAX, 96, 192), i32::MAX);
+		assert_eq!(scale(i32::MIN, 96, 192), i32::MIN);
+		assert_eq!(scale(i32::MAX / 2 + 1, 96, 288), i32::MAX);
+		assert_eq!(scale(i32::MIN / 2 - 1, 96, 288), i32::MIN);
+		assert_eq!(scale(i32::MAX, 1, u32::MAX), i32::MAX);
+		assert_eq!(scale(i32::MIN, 1, u32::MAX), i32::MIN);
+		assert_eq!(scale(i32::MAX, 192, 96), 1_073_741_824);
+		assert_eq!(scale(i32::MIN, 192, 96), -1_073_741_824);
+	}
+
+	#[test]
+	fn scale_same_or_zero_dpi() {
+		for value in [0, 1, -1, 1234, i32::MAX, i32::MIN] {
+			assert_eq!(scale(value, 96, 96), value);
+			assert_eq!(scale(value, 0, 144), value);
+			assert_eq!(scale(value, 144, 0), value);
+			assert_eq!(scale(value, 0, 0), value);
+			assert_eq!(to_pixels(value, 0), value);
+			assert_eq!(to_dips(value, 0), value);
+		}
+	}
+
+	#[test]
+	fn scale_structs() {
+		let pt = scale_point(POINT::new(-1, 3), 96, 144);
+		assert_eq!((pt.x, pt.y), (-2, 5));
+
+		let sz = to_pixels_size(SIZE::new(16, 16), 120);
+		assert_eq!((sz.cx, sz.cy), (20, 20));
+
+		let rc = scale_rect(RECT { left: -1, top: 0, right: 1, bottom: 3 }, 96, 144);
+		assert_eq!((rc.left, rc.top, rc.right, rc.bottom), (-2, 0, 2, 5));
+
+		// adjacent rectangles still share an edge
+		let a = scale_rect(RECT { left: 0, top: 0, right: 7, bottom: 10 }, 96, 120);
+		let b = scale_rect(RECT { left: 7, top: 0, right: 13, bottom: 10 }, 96, 120);
+		assert_eq!(a.right, b.left);
+	}
+
+	#[test]
+	fn font_heights() {
+		assert_eq!(font_height(9, 96), -12);
+		assert_eq!(font_height(10, 96), -13);
+		assert_eq!(font_height(9, 144), -18);
+		assert_eq!(font_height(9, 0), -9);
+	}
+}
@@ -118,7 +118,8 @@ impl From<HeaderJustify> for co::HDF {
 /// Specifies the horizontal behavior of the control when the parent window is
 /// resized.
 ///
-/// The values are analog to [`gui::Vert`](crate::gui::Vert).
+/// The values are analog to [`gui::Vert`](crate::gui::Vert). For more complex
+/// arrangements, see the [`layout`](crate::gui::layout) module.
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Horz {
 	/// Nothing will be done when parent window is resized.
@@ -168,9 +169,10 @@ impl Icon {
 /// [`StatusBar::new`](crate::gui::StatusBar::new).
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub enum SbPart {
-	/// A part that has a fixed size, in pixels.
+	/// A part that has a fixed size, in DPI-independent pixels.
 	///
-	/// Will be adjusted to match current system DPI.
+	/// Will be scaled to the DPI of the parent window, and rescaled when the
+	/// window is moved to a monitor with another DPI.
 	Fixed(u32),
 	/// A part that will resize when the parent window resizes, filling the
 	/// space left by the fixed-size parts. Has the resizing proportion.
@@ -188,7 +190,8 @@ pub enum SbPart {
 /// Specifies the vertical behavior of the control when the parent window is
 /// resized.
 ///
-/// The values are analog to [`gui::Horz`](crate::gui::Horz).
+/// The values are analog to [`gui::Horz`](crate::gui::Horz). For more complex
+/// arrangements, see the [`layout`](crate::gui::layout) module.
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Vert {
 	/// Nothing will be done when parent window is resized.
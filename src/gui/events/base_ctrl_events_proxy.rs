@@ -30,6 +30,19 @@ impl BaseCtrlEventsProxy {
 		parent_base_ref.on().wm_command(self.ctrl_id, code, func);
 	}
 
+	/// Adds a `WM_COMMAND` event to the parent window, for a command ID other
+	/// than the control's own, like the ones of toolbar buttons.
+	pub(in crate::gui) fn wm_command_id<F>(&self,
+		cmd_id: u16,
+		code: impl Into<co::CMD>,
+		func: F,
+	)
+		where F: Fn() -> AnyResult<WmRet> + 'static,
+	{
+		let parent_base_ref = unsafe { self.parent_ptr.as_ref() };
+		parent_base_ref.on().wm_command(cmd_id, code, func);
+	}
+
 	/// Adds a `WM_NOTIFY` event to the parent window.
 	pub(in crate::gui) fn wm_notify<F>(&self,
 		code: impl Into<NmhdrCode>,
@@ -13,8 +13,10 @@ mod list_box_events;
 mod list_view_events;
 mod month_calendar_events;
 mod radio_group_events;
+mod rich_edit_events;
 mod status_bar_events;
 mod tab_events;
+mod toolbar_events;
 mod trackbar_events;
 mod tree_view_events;
 mod up_down_events;
@@ -35,8 +37,10 @@ pub use list_box_events::ListBoxEvents;
 pub use list_view_events::ListViewEvents;
 pub use month_calendar_events::MonthCalendarEvents;
 pub use radio_group_events::RadioGroupEvents;
+pub use rich_edit_events::RichEditEvents;
 pub use status_bar_events::StatusBarEvents;
 pub use tab_events::TabEvents;
+pub use toolbar_events::ToolbarEvents;
 pub use trackbar_events::TrackbarEvents;
 pub use tree_view_events::TreeViewEvents;
 pub use up_down_events::UpDownEvents;
@@ -0,0 +1,102 @@
+use crate::co;
+use crate::decl::*;
+use crate::gui::privs::*;
+
+/// Exposes rich edit control
+/// [notifications](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-rich-edit-control-reference-notifications).
+///
+/// These event methods are just proxies to the
+/// [`WindowEvents`](crate::gui::events::WindowEvents) of the parent window, who
+/// is the real responsible for the child event handling.
+///
+/// You cannot directly instantiate this object, it is created internally by the
+/// control.
+pub struct RichEditEvents(BaseCtrlEventsProxy);
+
+impl RichEditEvents {
+	#[must_use]
+	pub(in crate::gui) fn new(parent: &impl AsRef<Base>, ctrl_id: u16) -> Self {
+		Self(BaseCtrlEventsProxy::new(parent, ctrl_id))
+	}
+
+	pub_fn_cmd_noparm_noret! { en_change, co::EN::CHANGE;
+		/// [`EN_CHANGE`](https://learn.microsoft.com/en-us/windows/win32/controls/en-change)
+		/// command notification.
+	}
+
+	pub_fn_cmd_noparm_noret! { en_h_scroll, co::EN::HSCROLL;
+		/// [`EN_HSCROLL`](https://learn.microsoft.com/en-us/windows/win32/controls/en-hscroll)
+		/// command notification.
+	}
+
+	pub_fn_cmd_noparm_noret! { en_kill_focus, co::EN::KILLFOCUS;
+		/// [`EN_KILLFOCUS`](https://learn.microsoft.com/en-us/windows/win32/controls/en-killfocus)
+		/// command notification.
+	}
+
+	pub_fn_nfy_withparm_boolret! { en_link, co::REN::LINK, ENLINK;
+		/// [`EN_LINK`](https://learn.microsoft.com/en-us/windows/win32/controls/en-link)
+		/// notification, sent when the mouse acts upon text formatted as a
+		/// link, either with [`CFE::LINK`](crate::co::CFE::LINK) or by
+		/// [automatic URL detection](crate::gui::RichEdit::set_auto_url_detect).
+		///
+		/// Return `true` to prevent the control from processing the mouse
+		/// message.
+		///
+		/// # Examples
+		///
+		/// Opening the clicked URL:
+		///
+		/// ```no_run
+		/// use winsafe::{self as w, prelude::*, co, gui};
+		///
+		/// let wnd: gui::WindowMain; // initialized somewhere
+		/// let txt: gui::RichEdit;
+		/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+		/// # let txt = gui::RichEdit::new(&wnd, gui::RichEditOpts::default());
+		///
+		/// let txt2 = txt.clone();
+		/// txt.on().en_link(move |p| {
+		///     if p.msg == co::WM::LBUTTONUP {
+		///         let chrg = p.chrg;
+		///         let url = txt2.text_range(chrg.cpMin, chrg.cpMax);
+		///         println!("URL: {}", url);
+		///     }
+		///     Ok(false)
+		/// });
+		/// ```
+	}
+
+	pub_fn_cmd_noparm_noret! { en_max_text, co::EN::MAXTEXT;
+		/// [`EN_MAXTEXT`](https://learn.microsoft.com/en-us/windows/win32/controls/en-maxtext)
+		/// command notification.
+	}
+
+	pub_fn_nfy_withparm_boolret! { en_protected, co::REN::PROTECTED, ENPROTECTED;
+		/// [`EN_PROTECTED`](https://learn.microsoft.com/en-us/windows/win32/controls/en-protected)
+		/// notification, sent when the user attempts to modify text formatted
+		/// with [`CFE::PROTECTED`](crate::co::CFE::PROTECTED).
+		///
+		/// Return `true` to prevent the modification.
+	}
+
+	pub_fn_nfy_withparm_noret! { en_sel_change, co::REN::SELCHANGE, SELCHANGE;
+		/// [`EN_SELCHANGE`](https://learn.microsoft.com/en-us/windows/win32/controls/en-selchange)
+		/// notification.
+	}
+
+	pub_fn_cmd_noparm_noret! { en_set_focus, co::EN::SETFOCUS;
+		/// [`EN_SETFOCUS`](https://learn.microsoft.com/en-us/windows/win32/controls/en-setfocus)
+		/// command notification.
+	}
+
+	pub_fn_cmd_noparm_noret! { en_update, co::EN::UPDATE;
+		/// [`EN_UPDATE`](https://learn.microsoft.com/en-us/windows/win32/controls/en-update)
+		/// command notification.
+	}
+
+	pub_fn_cmd_noparm_noret! { en_v_scroll, co::EN::VSCROLL;
+		/// [`EN_VSCROLL`](https://learn.microsoft.com/en-us/windows/win32/controls/en-vscroll)
+		/// command notification.
+	}
+}
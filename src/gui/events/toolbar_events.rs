@@ -0,0 +1,167 @@
+use crate::co;
+use crate::decl::*;
+use crate::gui::{*, privs::*};
+
+/// Exposes toolbar control
+/// [notifications](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-toolbar-control-reference-notifications).
+///
+/// These event methods are just proxies to the
+/// [`WindowEvents`](crate::gui::events::WindowEvents) of the parent window,
+/// who is the real responsible for the child event handling.
+///
+/// You cannot directly instantiate this object, it is created internally by
+/// the control.
+pub struct ToolbarEvents(BaseCtrlEventsProxy);
+
+impl ToolbarEvents {
+	#[must_use]
+	pub(in crate::gui) fn new(parent: &impl AsRef<Base>, ctrl_id: u16) -> Self {
+		Self(BaseCtrlEventsProxy::new(parent, ctrl_id))
+	}
+
+	/// [`BN_CLICKED`](https://learn.microsoft.com/en-us/windows/win32/controls/bn-clicked)
+	/// command notification, sent when the button with the given command ID is
+	/// clicked.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, gui};
+	///
+	/// const ID_SAVE: u16 = 102;
+	///
+	/// let toolbar: gui::Toolbar; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	/// # let toolbar = gui::Toolbar::new(&wnd, gui::ToolbarOpts::default());
+	///
+	/// toolbar.on().bn_clicked(ID_SAVE, move || {
+	///     println!("Save clicked.");
+	///     Ok(())
+	/// });
+	/// ```
+	pub fn bn_clicked<F>(&self, btn_cmd_id: u16, func: F)
+		where F: Fn() -> AnyResult<()> + 'static,
+	{
+		self.0.wm_command_id(btn_cmd_id, co::BN::CLICKED, move || {
+			func()?;
+			Ok(WmRet::HandledOk)
+		});
+	}
+
+	pub_fn_nfy_withparm_boolret! { nm_click, co::NM::CLICK, NMMOUSE;
+		/// [`NM_CLICK`](https://learn.microsoft.com/en-us/windows/win32/controls/nm-click-toolbar)
+		/// notification.
+	}
+
+	pub_fn_nfy_withparm_boolret! { nm_r_click, co::NM::RCLICK, NMMOUSE;
+		/// [`NM_RCLICK`](https://learn.microsoft.com/en-us/windows/win32/controls/nm-rclick-toolbar)
+		/// notification.
+	}
+
+	pub_fn_nfy_noparm_noret! { tbn_begin_adjust, co::TBN::BEGINADJUST;
+		/// [`TBN_BEGINADJUST`](https://learn.microsoft.com/en-us/windows/win32/controls/tbn-beginadjust)
+		/// notification.
+	}
+
+	pub_fn_nfy_withparm_noret! { tbn_begin_drag, co::TBN::BEGINDRAG, NMTOOLBAR;
+		/// [`TBN_BEGINDRAG`](https://learn.microsoft.com/en-us/windows/win32/controls/tbn-begindrag)
+		/// notification.
+	}
+
+	pub_fn_nfy_noparm_noret! { tbn_cust_help, co::TBN::CUSTHELP;
+		/// [`TBN_CUSTHELP`](https://learn.microsoft.com/en-us/windows/win32/controls/tbn-custhelp)
+		/// notification.
+	}
+
+	pub_fn_nfy_withparm_noret! { tbn_deleting_button, co::TBN::DELETINGBUTTON, NMTOOLBAR;
+		/// [`TBN_DELETINGBUTTON`](https://learn.microsoft.com/en-us/windows/win32/controls/tbn-deletingbutton)
+		/// notification.
+	}
+
+	pub_fn_nfy_withparm_noret! { tbn_drag_out, co::TBN::DRAGOUT, NMTOOLBAR;
+		/// [`TBN_DRAGOUT`](https://learn.microsoft.com/en-us/windows/win32/controls/tbn-dragout)
+		/// notification.
+	}
+
+	/// [`TBN_DROPDOWN`](https://learn.microsoft.com/en-us/windows/win32/controls/tbn-dropdown)
+	/// notification, sent when the arrow of a dropdown button is clicked.
+	///
+	/// # Examples
+	///
+	/// Showing a popup menu below the button:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co, gui};
+	///
+	/// let wnd: gui::WindowMain; // initialized somewhere
+	/// let toolbar: gui::Toolbar;
+	/// let hmenu: w::HMENU;
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	/// # let toolbar = gui::Toolbar::new(&wnd, gui::ToolbarOpts::default());
+	/// # let hmenu = w::HMENU::NULL;
+	///
+	/// let wnd2 = wnd.clone();
+	/// let toolbar2 = toolbar.clone();
+	/// toolbar.on().tbn_drop_down(move |p| {
+	///     hmenu.track_popup_menu_at_point(
+	///         w::POINT::new(p.rcButton.left, p.rcButton.bottom),
+	///         wnd2.hwnd(),
+	///         toolbar2.hwnd(),
+	///     )?;
+	///     Ok(co::TBDDRET::DEFAULT)
+	/// });
+	/// ```
+	pub fn tbn_drop_down<F>(&self, func: F)
+		where F: Fn(&NMTOOLBAR) -> AnyResult<co::TBDDRET> + 'static,
+	{
+		self.0.wm_notify(co::TBN::DROPDOWN, move |p| {
+			let ret_val = func(unsafe { p.cast_nmhdr::<NMTOOLBAR>() })?;
+			Ok(WmRet::HandledWithRet(ret_val.raw() as _))
+		});
+	}
+
+	pub_fn_nfy_noparm_noret! { tbn_end_adjust, co::TBN::ENDADJUST;
+		/// [`TBN_ENDADJUST`](https://learn.microsoft.com/en-us/windows/win32/controls/tbn-endadjust)
+		/// notification.
+	}
+
+	pub_fn_nfy_withparm_noret! { tbn_end_drag, co::TBN::ENDDRAG, NMTOOLBAR;
+		/// [`TBN_ENDDRAG`](https://learn.microsoft.com/en-us/windows/win32/controls/tbn-enddrag)
+		/// notification.
+	}
+
+	pub_fn_nfy_withmutparm_noret! { tbn_get_disp_info, co::TBN::GETDISPINFO, NMTBDISPINFO;
+		/// [`TBN_GETDISPINFO`](https://learn.microsoft.com/en-us/windows/win32/controls/tbn-getdispinfo)
+		/// notification.
+	}
+
+	pub_fn_nfy_withmutparm_noret! { tbn_get_info_tip, co::TBN::GETINFOTIP, NMTBGETINFOTIP;
+		/// [`TBN_GETINFOTIP`](https://learn.microsoft.com/en-us/windows/win32/controls/tbn-getinfotip)
+		/// notification.
+	}
+
+	pub_fn_nfy_withparm_boolret! { tbn_hot_item_change, co::TBN::HOTITEMCHANGE, NMTBHOTITEM;
+		/// [`TBN_HOTITEMCHANGE`](https://learn.microsoft.com/en-us/windows/win32/controls/tbn-hotitemchange)
+		/// notification.
+	}
+
+	pub_fn_nfy_withparm_boolret! { tbn_query_delete, co::TBN::QUERYDELETE, NMTOOLBAR;
+		/// [`TBN_QUERYDELETE`](https://learn.microsoft.com/en-us/windows/win32/controls/tbn-querydelete)
+		/// notification.
+	}
+
+	pub_fn_nfy_withparm_boolret! { tbn_query_insert, co::TBN::QUERYINSERT, NMTOOLBAR;
+		/// [`TBN_QUERYINSERT`](https://learn.microsoft.com/en-us/windows/win32/controls/tbn-queryinsert)
+		/// notification.
+	}
+
+	pub_fn_nfy_noparm_noret! { tbn_reset, co::TBN::RESET;
+		/// [`TBN_RESET`](https://learn.microsoft.com/en-us/windows/win32/controls/tbn-reset)
+		/// notification.
+	}
+
+	pub_fn_nfy_noparm_noret! { tbn_toolbar_change, co::TBN::TOOLBARCHANGE;
+		/// [`TBN_TOOLBARCHANGE`](https://learn.microsoft.com/en-us/windows/win32/controls/tbn-toolbarchange)
+		/// notification.
+	}
+}
@@ -578,6 +578,17 @@ impl WindowEvents {
 		/// message.
 	}
 
+	pub_fn_wm_withparm_noret! { wm_dpi_changed, co::WM::DPICHANGED, wm::DpiChanged;
+		/// [`WM_DPICHANGED`](https://learn.microsoft.com/en-us/windows/win32/hi-dpi/wm-dpichanged)
+		/// message.
+		///
+		/// Raw windows already rescale their child controls, their fonts and
+		/// themselves before this event is fired. Image lists, however, must be
+		/// recreated here, since the library has no means to redraw their
+		/// bitmaps; [`gui::dpi`](crate::gui::dpi) has helpers to compute the
+		/// new sizes.
+	}
+
 	#[cfg(feature = "shell")]
 	pub_fn_wm_withparm_noret! { wm_drop_files, co::WM::DROPFILES, wm::DropFiles;
 		/// [`WM_DROPFILES`](https://learn.microsoft.com/en-us/windows/win32/shell/wm-dropfiles)
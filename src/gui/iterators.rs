@@ -326,6 +326,39 @@ impl<'a, T> ListViewItemIter<'a, T> {
 
 //------------------------------------------------------------------------------
 
+pub(in crate::gui) struct ToolbarButtonIter<'a> {
+	owner: &'a Toolbar,
+	count: u32,
+	current: u32,
+}
+
+impl<'a> Iterator for ToolbarButtonIter<'a> {
+	type Item = ToolbarButton<'a>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.current == self.count {
+			return None;
+		}
+
+		let button = self.owner.buttons().get(self.current);
+		self.current += 1;
+		Some(button)
+	}
+}
+
+impl<'a> ToolbarButtonIter<'a> {
+	#[must_use]
+	pub(in crate::gui) fn new(owner: &'a Toolbar) -> Self {
+		Self {
+			owner,
+			count: owner.buttons().count(),
+			current: 0,
+		}
+	}
+}
+
+//------------------------------------------------------------------------------
+
 pub(in crate::gui) struct TreeViewItemIter<'a, T: 'static> {
 	owner: &'a TreeView<T>,
 	current: Option<TreeViewItem<'a, T>>,
@@ -0,0 +1,155 @@
+use crate::decl::*;
+use crate::gui::layout::item::*;
+
+/// The side of a [`Dock`](crate::gui::layout::Dock) an item is docked to.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Side {
+	/// Left side; the item receives its natural width.
+	Left,
+	/// Top side; the item receives its natural height.
+	Top,
+	/// Right side; the item receives its natural width.
+	Right,
+	/// Bottom side; the item receives its natural height.
+	Bottom,
+}
+
+/// A container which docks its items to its sides, in the order they're
+/// added, with an optional item filling the remaining area.
+///
+/// Each docked item receives its natural size perpendicular to its side, and
+/// the whole remaining space along it.
+///
+/// # Examples
+///
+/// A tree on the left, a status bar at the bottom, and a list filling the rest:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, gui};
+/// use winsafe::gui::layout::{Dock, Item, Side};
+///
+/// let tree: gui::TreeView<()>; // initialized somewhere
+/// let status: gui::Label;
+/// let list: gui::ListView<()>;
+/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+/// # let tree = gui::TreeView::<()>::new(&wnd, gui::TreeViewOpts::default());
+/// # let status = gui::Label::new(&wnd, gui::LabelOpts::default());
+/// # let list = gui::ListView::<()>::new(&wnd, gui::ListViewOpts::default());
+///
+/// let dock = Dock::new()
+///     .spacing(4)
+///     .dock(Side::Bottom, Item::window(&status).height(20))
+///     .dock(Side::Left, Item::window(&tree).width(200).min_size(100, 0))
+///     .fill(Item::window(&list));
+/// ```
+#[derive(Clone, Default)]
+pub struct Dock {
+	spacing: i32,
+	padding: Margins,
+	docked: Vec<(Side, Item)>,
+	fill: Option<Box<Item>>,
+}
+
+impl Dock {
+	/// Creates a new, empty dock.
+	#[must_use]
+	pub const fn new() -> Self {
+		Self {
+			spacing: 0,
+			padding: Margins::all(0),
+			docked: Vec::new(),
+			fill: None,
+		}
+	}
+
+	/// Sets the space between two consecutive items. Defaults to zero.
+	#[must_use]
+	pub const fn spacing(mut self, spacing: i32) -> Self {
+		self.spacing = spacing;
+		self
+	}
+
+	/// Sets the space between the borders of the dock and its items. Defaults
+	/// to zero.
+	#[must_use]
+	pub const fn padding(mut self, padding: Margins) -> Self {
+		self.padding = padding;
+		self
+	}
+
+	/// Docks an item to the given side of the area left by the previously
+	/// docked items.
+	#[must_use]
+	pub fn dock(mut self, side: Side, item: impl Into<Item>) -> Self {
+		self.docked.push((side, item.into()));
+		self
+	}
+
+	/// Sets the item which fills the area left by the docked items, replacing
+	/// any previous one.
+	#[must_use]
+	pub fn fill(mut self, item: impl Into<Item>) -> Self {
+		self.fill = Some(Box::new(item.into()));
+		self
+	}
+
+	pub(in crate::gui::layout) fn items(&self) -> impl Iterator<Item = &Item> {
+		self.docked.iter()
+			.map(|(_, item)| item)
+			.chain(self.fill.iter().map(|item| item.as_ref()))
+	}
+
+	#[must_use]
+	pub(in crate::gui::layout) fn natural(&self) -> SIZE {
+		let mut sz = self.fill.as_ref()
+			.map_or(SIZE::default(), |item| item.outer_natural());
+		let mut has_inner = self.fill.is_some();
+
+		for (side, item) in self.docked.iter().rev() {
+			let item_sz = item.outer_natural();
+			let spacing = if has_inner { self.spacing } else { 0 };
+			if matches!(side, Side::Left | Side::Right) {
+				sz = SIZE::new(sz.cx + item_sz.cx + spacing, sz.cy.max(item_sz.cy));
+			} else {
+				sz = SIZE::new(sz.cx.max(item_sz.cx), sz.cy + item_sz.cy + spacing);
+			}
+			has_inner = true;
+		}
+		self.padding.grow(sz)
+	}
+
+	pub(in crate::gui::layout) fn arrange(&self, rc: RECT, out: &mut Vec<RECT>) {
+		let mut rest = self.padding.shrink(rc);
+
+		for (side, item) in self.docked.iter() {
+			let horz = matches!(side, Side::Left | Side::Right);
+			let avail = if horz { rest.right - rest.left } else { rest.bottom - rest.top };
+			let len = axis(item.outer_natural(), horz).min(avail.max(0));
+
+			let mut cell = rest;
+			match side {
+				Side::Left => {
+					cell.right = cell.left + len;
+					rest.left = cell.right + self.spacing;
+				},
+				Side::Top => {
+					cell.bottom = cell.top + len;
+					rest.top = cell.bottom + self.spacing;
+				},
+				Side::Right => {
+					cell.left = cell.right - len;
+					rest.right = cell.left - self.spacing;
+				},
+				Side::Bottom => {
+					cell.top = cell.bottom - len;
+					rest.bottom = cell.top - self.spacing;
+				},
+			}
+			item.arrange(cell, out);
+		}
+
+		if let Some(item) = self.fill.as_ref() {
+			item.arrange(rest, out);
+		}
+	}
+}
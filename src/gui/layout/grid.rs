@@ -0,0 +1,254 @@
+use crate::decl::*;
+use crate::gui::layout::item::*;
+
+/// A column or a row of a [`Grid`](crate::gui::layout::Grid).
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Track {
+	size: Option<i32>, // None = auto
+	min: i32,
+	max: i32,
+	weight: u32,
+}
+
+impl Track {
+	/// Creates a new track with a fixed size.
+	#[must_use]
+	pub const fn fixed(size: i32) -> Self {
+		Self { size: Some(size), min: size, max: size, weight: 0 }
+	}
+
+	/// Creates a new track sized to the largest natural size of its items,
+	/// considering only the items which span a single track.
+	#[must_use]
+	pub const fn auto() -> Self {
+		Self { size: None, min: 0, max: i32::MAX, weight: 0 }
+	}
+
+	/// Creates a new track which takes the extra space of the grid, shared
+	/// among the weighted tracks proportionally.
+	#[must_use]
+	pub const fn weighted(weight: u32) -> Self {
+		Self { size: Some(0), min: 0, max: i32::MAX, weight }
+	}
+
+	/// Sets the minimum size of the track.
+	#[must_use]
+	pub const fn min(mut self, min: i32) -> Self {
+		self.min = min;
+		self
+	}
+
+	/// Sets the maximum size of the track.
+	#[must_use]
+	pub const fn max(mut self, max: i32) -> Self {
+		self.max = max;
+		self
+	}
+}
+
+#[derive(Clone)]
+struct GridCell {
+	row: usize,
+	col: usize,
+	row_span: usize,
+	col_span: usize,
+	item: Item,
+}
+
+/// A container which places its items in the cells formed by columns and
+/// rows, each one possibly spanning multiple tracks.
+///
+/// The columns and rows receive their natural sizes, and the extra space is
+/// distributed among the [weighted](crate::gui::layout::Track::weighted)
+/// ones. The [weight](crate::gui::layout::Item::weight) of the items is
+/// ignored.
+///
+/// # Examples
+///
+/// A form with labels and edits:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, gui};
+/// use winsafe::gui::layout::{Align, Grid, Item, Track};
+///
+/// let lbl_name: gui::Label; // initialized somewhere
+/// let txt_name: gui::Edit;
+/// let lbl_notes: gui::Label;
+/// let txt_notes: gui::Edit;
+/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+/// # let lbl_name = gui::Label::new(&wnd, gui::LabelOpts::default());
+/// # let txt_name = gui::Edit::new(&wnd, gui::EditOpts::default());
+/// # let lbl_notes = gui::Label::new(&wnd, gui::LabelOpts::default());
+/// # let txt_notes = gui::Edit::new(&wnd, gui::EditOpts::default());
+///
+/// let form = Grid::new(
+///     [Track::auto(), Track::weighted(1)],
+///     [Track::auto(), Track::weighted(1).min(50)],
+/// )
+///     .spacing(8, 6)
+///     .cell(0, 0, Item::window(&lbl_name).align(Align::Start, Align::Center))
+///     .cell(0, 1, Item::window(&txt_name))
+///     .cell(1, 0, Item::window(&lbl_notes).align(Align::Start, Align::Start))
+///     .cell(1, 1, Item::window(&txt_notes));
+/// ```
+#[derive(Clone)]
+pub struct Grid {
+	columns: Vec<Track>,
+	rows: Vec<Track>,
+	col_spacing: i32,
+	row_spacing: i32,
+	padding: Margins,
+	cells: Vec<GridCell>,
+}
+
+impl Grid {
+	/// Creates a new grid with the given columns and rows.
+	#[must_use]
+	pub fn new(
+		columns: impl IntoIterator<Item = Track>,
+		rows: impl IntoIterator<Item = Track>,
+	) -> Self
+	{
+		Self {
+			columns: columns.into_iter().collect(),
+			rows: rows.into_iter().collect(),
+			col_spacing: 0,
+			row_spacing: 0,
+			padding: Margins::all(0),
+			cells: Vec::new(),
+		}
+	}
+
+	/// Sets the space between two consecutive columns, and between two
+	/// consecutive rows. Defaults to zero.
+	#[must_use]
+	pub const fn spacing(mut self, col_spacing: i32, row_spacing: i32) -> Self {
+		self.col_spacing = col_spacing;
+		self.row_spacing = row_spacing;
+		self
+	}
+
+	/// Sets the space between the borders of the grid and its cells. Defaults
+	/// to zero.
+	#[must_use]
+	pub const fn padding(mut self, padding: Margins) -> Self {
+		self.padding = padding;
+		self
+	}
+
+	/// Places an item in the cell at the given zero-based row and column.
+	///
+	/// # Panics
+	///
+	/// Panics if the row or the column doesn't exist.
+	#[must_use]
+	pub fn cell(self, row: usize, col: usize, item: impl Into<Item>) -> Self {
+		self.cell_span(row, col, 1, 1, item)
+	}
+
+	/// Places an item in the cell at the given zero-based row and column,
+	/// spanning the given number of rows and columns.
+	///
+	/// # Panics
+	///
+	/// Panics if a spanned row or column doesn't exist, or if a span is zero.
+	#[must_use]
+	pub fn cell_span(mut self,
+		row: usize,
+		col: usize,
+		row_span: usize,
+		col_span: usize,
+		item: impl Into<Item>,
+	) -> Self
+	{
+		if row_span == 0 || col_span == 0
+			|| row + row_span > self.rows.len()
+			|| col + col_span > self.columns.len()
+		{
+			panic!("Grid cell out of the columns and rows.");
+		}
+		self.cells.push(GridCell { row, col, row_span, col_span, item: item.into() });
+		self
+	}
+
+	pub(in crate::gui::layout) fn items(&self) -> impl Iterator<Item = &Item> {
+		self.cells.iter().map(|cell| &cell.item)
+	}
+
+	/// Returns the size constraints of the columns or the rows.
+	#[must_use]
+	fn spans(&self, horz: bool) -> Vec<Span> {
+		let tracks = if horz { &self.columns } else { &self.rows };
+		tracks.iter()
+			.enumerate()
+			.map(|(idx, track)| Span {
+				natural: track.size.unwrap_or_else(|| {
+					self.cells.iter()
+						.filter(|cell| if horz {
+							cell.col == idx && cell.col_span == 1
+						} else {
+							cell.row == idx && cell.row_span == 1
+						})
+						.map(|cell| axis(cell.item.outer_natural(), horz))
+						.max()
+						.unwrap_or(0)
+				}),
+				min: track.min,
+				max: track.max,
+				weight: track.weight,
+			})
+			.collect()
+	}
+
+	/// Returns the total space between the columns or the rows.
+	#[must_use]
+	fn total_spacing(&self, horz: bool) -> i32 {
+		let (num, spacing) = if horz {
+			(self.columns.len(), self.col_spacing)
+		} else {
+			(self.rows.len(), self.row_spacing)
+		};
+		spacing * (num.max(1) - 1) as i32
+	}
+
+	/// Returns the start position and the length of each column or row.
+	#[must_use]
+	fn tracks(&self, horz: bool, start: i32, len: i32) -> Vec<(i32, i32)> {
+		let spacing = if horz { self.col_spacing } else { self.row_spacing };
+		let lens = distribute(len - self.total_spacing(horz), &self.spans(horz));
+		let mut pos = start;
+		lens.iter()
+			.map(|len| {
+				let track = (pos, *len);
+				pos += len + spacing;
+				track
+			})
+			.collect()
+	}
+
+	#[must_use]
+	pub(in crate::gui::layout) fn natural(&self) -> SIZE {
+		let total = |horz: bool| -> i32 {
+			self.spans(horz).iter().map(|span| span.clamped()).sum::<i32>()
+				+ self.total_spacing(horz)
+		};
+		self.padding.grow(SIZE::new(total(true), total(false)))
+	}
+
+	pub(in crate::gui::layout) fn arrange(&self, rc: RECT, out: &mut Vec<RECT>) {
+		let inner = self.padding.shrink(rc);
+		let cols = self.tracks(true, inner.left, inner.right - inner.left);
+		let rows = self.tracks(false, inner.top, inner.bottom - inner.top);
+
+		for cell in self.cells.iter() {
+			let (first_col, last_col) = (cols[cell.col], cols[cell.col + cell.col_span - 1]);
+			let (first_row, last_row) = (rows[cell.row], rows[cell.row + cell.row_span - 1]);
+			cell.item.arrange(RECT {
+				left: first_col.0,
+				top: first_row.0,
+				right: last_col.0 + last_col.1,
+				bottom: last_row.0 + last_row.1,
+			}, out);
+		}
+	}
+}
@@ -0,0 +1,511 @@
+use std::cell::Cell;
+use std::sync::Arc;
+
+use crate::decl::*;
+use crate::gui::layout::*;
+use crate::prelude::*;
+
+/// Space around an [`Item`](crate::gui::layout::Item), or inside a container.
+#[derive(Default, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Margins {
+	pub left: i32,
+	pub top: i32,
+	pub right: i32,
+	pub bottom: i32,
+}
+
+impl Margins {
+	/// Creates new `Margins`.
+	#[must_use]
+	pub const fn new(left: i32, top: i32, right: i32, bottom: i32) -> Self {
+		Self { left, top, right, bottom }
+	}
+
+	/// Creates new `Margins` with the same value on all sides.
+	#[must_use]
+	pub const fn all(margin: i32) -> Self {
+		Self::new(margin, margin, margin, margin)
+	}
+
+	/// Creates new `Margins` with a value for the left and right sides, and
+	/// another for the top and bottom sides.
+	#[must_use]
+	pub const fn symmetric(horz: i32, vert: i32) -> Self {
+		Self::new(horz, vert, horz, vert)
+	}
+
+	/// Returns the sum of the margins along the horizontal or vertical axis.
+	#[must_use]
+	pub(in crate::gui::layout) const fn sum(&self, horz: bool) -> i32 {
+		if horz { self.left + self.right } else { self.top + self.bottom }
+	}
+
+	/// Returns the rectangle shrunk by the margins.
+	#[must_use]
+	pub(in crate::gui::layout) const fn shrink(&self, rc: RECT) -> RECT {
+		RECT {
+			left: rc.left + self.left,
+			top: rc.top + self.top,
+			right: rc.right - self.right,
+			bottom: rc.bottom - self.bottom,
+		}
+	}
+
+	/// Returns the size grown by the margins.
+	#[must_use]
+	pub(in crate::gui::layout) const fn grow(&self, sz: SIZE) -> SIZE {
+		SIZE::new(sz.cx + self.sum(true), sz.cy + self.sum(false))
+	}
+}
+
+/// Alignment of an [`Item`](crate::gui::layout::Item) within the space it
+/// receives from its container, along one axis.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Align {
+	/// Natural size, at the left or top.
+	Start,
+	/// Natural size, centered.
+	Center,
+	/// Natural size, at the right or bottom.
+	End,
+	/// Takes all the space, respecting the maximum size.
+	Fill,
+}
+
+/// Contents of an [`Item`](crate::gui::layout::Item).
+#[derive(Clone)]
+pub(in crate::gui::layout) enum Content {
+	Spacer,
+	Window(Arc<dyn GuiWindow>, Cell<Option<SIZE>>), // original size, retrieved on first apply
+	Stack(Stack),
+	Grid(Grid),
+	Dock(Dock),
+}
+
+/// An element of a [`Layout`](crate::gui::layout::Layout): a window, an empty
+/// spacer, or a container with other items.
+///
+/// The natural size of an item is, in this order of precedence:
+///
+/// * the size set with [`Item::size`](crate::gui::layout::Item::size),
+///   [`Item::width`](crate::gui::layout::Item::width) or
+///   [`Item::height`](crate::gui::layout::Item::height);
+/// * for a window, its size when the layout is first applied;
+/// * for a container, the space needed by the natural size of its items;
+/// * the minimum size.
+///
+/// The natural size is always kept between the minimum and maximum sizes.
+#[derive(Clone)]
+pub struct Item {
+	pub(in crate::gui::layout) content: Content,
+	width: Option<i32>,
+	height: Option<i32>,
+	min: SIZE,
+	max: SIZE,
+	pub(in crate::gui::layout) weight: u32,
+	pub(in crate::gui::layout) margins: Margins,
+	halign: Align,
+	valign: Align,
+}
+
+impl From<Stack> for Item {
+	fn from(stack: Stack) -> Self {
+		Self::new(Content::Stack(stack))
+	}
+}
+
+impl From<Grid> for Item {
+	fn from(grid: Grid) -> Self {
+		Self::new(Content::Grid(grid))
+	}
+}
+
+impl From<Dock> for Item {
+	fn from(dock: Dock) -> Self {
+		Self::new(Content::Dock(dock))
+	}
+}
+
+impl Item {
+	#[must_use]
+	const fn new(content: Content) -> Self {
+		Self {
+			content,
+			width: None,
+			height: None,
+			min: SIZE::new(0, 0),
+			max: SIZE::new(i32::MAX, i32::MAX),
+			weight: 0,
+			margins: Margins::all(0),
+			halign: Align::Fill,
+			valign: Align::Fill,
+		}
+	}
+
+	/// Creates a new item with a window, usually a control.
+	///
+	/// The window is kept by the layout, and its handle is retrieved only when
+	/// the layout is applied, so the item can be created before the window
+	/// itself.
+	#[must_use]
+	pub fn window(wnd: &(impl GuiWindow + Clone + 'static)) -> Self {
+		Self::new(Content::Window(Arc::new(wnd.clone()), Cell::new(None)))
+	}
+
+	/// Creates a new empty item, which only takes space.
+	#[must_use]
+	pub const fn spacer() -> Self {
+		Self::new(Content::Spacer)
+	}
+
+	/// Sets the natural width and height.
+	#[must_use]
+	pub const fn size(mut self, cx: i32, cy: i32) -> Self {
+		self.width = Some(cx);
+		self.height = Some(cy);
+		self
+	}
+
+	/// Sets the natural width.
+	#[must_use]
+	pub const fn width(mut self, cx: i32) -> Self {
+		self.width = Some(cx);
+		self
+	}
+
+	/// Sets the natural height.
+	#[must_use]
+	pub const fn height(mut self, cy: i32) -> Self {
+		self.height = Some(cy);
+		self
+	}
+
+	/// Sets the minimum width and height. Defaults to zero.
+	///
+	/// If the minimum is greater than the maximum, the minimum prevails.
+	#[must_use]
+	pub const fn min_size(mut self, cx: i32, cy: i32) -> Self {
+		self.min = SIZE::new(cx, cy);
+		self
+	}
+
+	/// Sets the maximum width and height. Defaults to no limit.
+	#[must_use]
+	pub const fn max_size(mut self, cx: i32, cy: i32) -> Self {
+		self.max = SIZE::new(cx, cy);
+		self
+	}
+
+	/// Sets the weight used by a [`Stack`](crate::gui::layout::Stack) to
+	/// distribute its extra space among the items, proportionally. Items with
+	/// zero weight, the default, keep their natural size.
+	#[must_use]
+	pub const fn weight(mut self, weight: u32) -> Self {
+		self.weight = weight;
+		self
+	}
+
+	/// Sets the space around the item. Defaults to zero.
+	#[must_use]
+	pub const fn margins(mut self, margins: Margins) -> Self {
+		self.margins = margins;
+		self
+	}
+
+	/// Sets the horizontal and vertical alignment of the item within the space
+	/// it receives. Defaults to [`Align::Fill`](crate::gui::layout::Align::Fill).
+	#[must_use]
+	pub const fn align(mut self, horz: Align, vert: Align) -> Self {
+		self.halign = horz;
+		self.valign = vert;
+		self
+	}
+
+	/// Returns the minimum size along the axis.
+	#[must_use]
+	pub(in crate::gui::layout) const fn min(&self, horz: bool) -> i32 {
+		axis(self.min, horz)
+	}
+
+	/// Returns the maximum size along the axis, never below the minimum.
+	#[must_use]
+	pub(in crate::gui::layout) const fn max(&self, horz: bool) -> i32 {
+		let (min, max) = (axis(self.min, horz), axis(self.max, horz));
+		if max < min { min } else { max }
+	}
+
+	/// Returns the natural size, without the margins.
+	#[must_use]
+	pub(in crate::gui::layout) fn natural(&self) -> SIZE {
+		let content = match &self.content {
+			Content::Spacer => SIZE::default(),
+			Content::Window(_, orig) => orig.get().unwrap_or_default(),
+			Content::Stack(stack) => stack.natural(),
+			Content::Grid(grid) => grid.natural(),
+			Content::Dock(dock) => dock.natural(),
+		};
+		SIZE::new(
+			self.width.unwrap_or(content.cx).min(self.max(true)).max(self.min(true)),
+			self.height.unwrap_or(content.cy).min(self.max(false)).max(self.min(false)),
+		)
+	}
+
+	/// Returns the natural size, plus the margins.
+	#[must_use]
+	pub(in crate::gui::layout) fn outer_natural(&self) -> SIZE {
+		self.margins.grow(self.natural())
+	}
+
+	/// Positions the item within the given cell, which includes the margins,
+	/// and appends the rectangles of all leaf items to `out`.
+	pub(in crate::gui::layout) fn arrange(&self, cell: RECT, out: &mut Vec<RECT>) {
+		let inner = self.margins.shrink(cell);
+		let natural = self.natural();
+		let (left, right) = align_span(inner.left, inner.right,
+			natural.cx, self.min(true), self.max(true), self.halign);
+		let (top, bottom) = align_span(inner.top, inner.bottom,
+			natural.cy, self.min(false), self.max(false), self.valign);
+		let rc = RECT { left, top, right, bottom };
+
+		match &self.content {
+			Content::Spacer | Content::Window(_, _) => out.push(rc),
+			Content::Stack(stack) => stack.arrange(rc, out),
+			Content::Grid(grid) => grid.arrange(rc, out),
+			Content::Dock(dock) => dock.arrange(rc, out),
+		}
+	}
+
+	/// Calls the function for all leaf items, in the same order of
+	/// [`Item::arrange`].
+	pub(in crate::gui::layout) fn visit_leaves<'a>(&'a self, func: &mut dyn FnMut(&'a Item)) {
+		match &self.content {
+			Content::Spacer | Content::Window(_, _) => func(self),
+			Content::Stack(stack) => stack.items().for_each(|item| item.visit_leaves(func)),
+			Content::Grid(grid) => grid.items().for_each(|item| item.visit_leaves(func)),
+			Content::Dock(dock) => dock.items().for_each(|item| item.visit_leaves(func)),
+		}
+	}
+}
+
+/// Returns the horizontal or vertical dimension of the size.
+#[must_use]
+pub(in crate::gui::layout) const fn axis(sz: SIZE, horz: bool) -> i32 {
+	if horz { sz.cx } else { sz.cy }
+}
+
+/// Builds a rectangle from its position and length along the main and cross
+/// axes.
+#[must_use]
+pub(in crate::gui::layout) const fn axis_rect(
+	horz: bool,
+	main_pos: i32,
+	main_len: i32,
+	cross_pos: i32,
+	cross_len: i32,
+) -> RECT
+{
+	if horz {
+		RECT {
+			left: main_pos,
+			top: cross_pos,
+			right: main_pos + main_len,
+			bottom: cross_pos + cross_len,
+		}
+	} else {
+		RECT {
+			left: cross_pos,
+			top: main_pos,
+			right: cross_pos + cross_len,
+			bottom: main_pos + main_len,
+		}
+	}
+}
+
+/// Computes the start and end of an item along one axis, within the available
+/// span.
+#[must_use]
+const fn align_span(
+	start: i32,
+	end: i32,
+	natural: i32,
+	min: i32,
+	max: i32,
+	align: Align,
+) -> (i32, i32)
+{
+	let avail = if end > start { end - start } else { 0 };
+	let len = match align {
+		Align::Fill => clamp(avail, min, max),
+		_ => clamp(if natural < avail { natural } else { avail }, min, max),
+	};
+	let free = if avail > len { avail - len } else { 0 };
+	let offset = match align {
+		Align::Start | Align::Fill => 0,
+		Align::Center => free / 2,
+		Align::End => free,
+	};
+	(start + offset, start + offset + len)
+}
+
+/// Clamps the value, with the minimum prevailing over the maximum.
+#[must_use]
+const fn clamp(val: i32, min: i32, max: i32) -> i32 {
+	let val = if val > max { max } else { val };
+	if val < min { min } else { val }
+}
+
+/// Size constraints of an item or track, to be resolved by [`distribute`].
+pub(in crate::gui::layout) struct Span {
+	pub(in crate::gui::layout) natural: i32,
+	pub(in crate::gui::layout) min: i32,
+	pub(in crate::gui::layout) max: i32,
+	pub(in crate::gui::layout) weight: u32,
+}
+
+impl Span {
+	/// Returns the natural length, kept between the minimum and maximum.
+	#[must_use]
+	pub(in crate::gui::layout) const fn clamped(&self) -> i32 {
+		clamp(self.natural, self.min, self.max)
+	}
+}
+
+/// Distributes the total length among the spans.
+///
+/// Each span starts with its natural length. Extra space goes to the spans with
+/// weight, proportionally, up to their maximum. Missing space is taken first
+/// from the spans with weight, proportionally, then from all spans equally,
+/// down to their minimum; beyond that, the total is exceeded.
+#[must_use]
+pub(in crate::gui::layout) fn distribute(total: i32, spans: &[Span]) -> Vec<i32> {
+	let mut lens = spans.iter()
+		.map(|span| span.clamped())
+		.collect::<Vec<_>>();
+	let delta = total as i64 - lens.iter().map(|len| *len as i64).sum::<i64>();
+
+	if delta > 0 {
+		adjust(&mut lens, spans, delta, |span| span.weight as _);
+	} else if delta < 0 {
+		let delta = adjust(&mut lens, spans, delta, |span| span.weight as _);
+		adjust(&mut lens, spans, delta, |_| 1);
+	}
+	lens
+}
+
+/// Grows (positive delta) or shrinks (negative delta) the lengths, in
+/// proportion to the factor of each span, within their limits. Returns the
+/// remaining delta.
+fn adjust(lens: &mut [i32], spans: &[Span], mut delta: i64, factor: impl Fn(&Span) -> i64) -> i64 {
+	loop {
+		let room = |idx: usize, lens: &[i32]| -> i64 {
+			let span = &spans[idx];
+			if delta > 0 {
+				span.max.max(span.min) as i64 - lens[idx] as i64
+			} else {
+				lens[idx] as i64 - span.min as i64
+			}
+		};
+		let active = (0..spans.len())
+			.filter(|idx| factor(&spans[*idx]) > 0 && room(*idx, lens) > 0)
+			.collect::<Vec<_>>();
+		if delta == 0 || active.is_empty() {
+			return delta;
+		}
+
+		let factor_sum = active.iter().map(|idx| factor(&spans[*idx])).sum::<i64>();
+		let mut given = 0;
+		for idx in active.iter().copied() {
+			let share = (delta.abs() * factor(&spans[idx]) / factor_sum).min(room(idx, lens));
+			lens[idx] += (share * delta.signum()) as i32;
+			given += share;
+		}
+		if given == 0 { // all shares rounded down to zero: one pixel each
+			for idx in active.iter().copied().take(delta.unsigned_abs() as _) {
+				lens[idx] += delta.signum() as i32;
+				given += 1;
+			}
+		}
+		delta -= given * delta.signum();
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	fn span(natural: i32, min: i32, max: i32, weight: u32) -> Span {
+		Span { natural, min, max, weight }
+	}
+
+	#[test]
+	fn align_span_rules() {
+		const MAX: i32 = i32::MAX;
+		type Args = (i32, i32, i32, i32, i32, Align); // start, end, natural, min, max, align
+		let cases: &[(Args, (i32, i32))] = &[
+			// fill takes all the space, up to the maximum
+			((0, 100, 30, 0, MAX, Align::Fill), (0, 100)),
+			((10, 110, 30, 0, 40, Align::Fill), (10, 50)),
+			// natural size, positioned
+			((10, 110, 30, 0, MAX, Align::Start), (10, 40)),
+			((0, 100, 30, 0, MAX, Align::Center), (35, 65)),
+			((0, 101, 30, 0, MAX, Align::Center), (35, 65)),
+			((0, 100, 30, 0, MAX, Align::End), (70, 100)),
+			// natural size larger than the space
+			((0, 20, 30, 0, MAX, Align::Center), (0, 20)),
+			((0, 20, 30, 0, MAX, Align::End), (0, 20)),
+			// minimum prevails over the space and the maximum
+			((0, 20, 30, 25, MAX, Align::Start), (0, 25)),
+			((0, 20, 30, 25, MAX, Align::Fill), (0, 25)),
+			((0, 100, 30, 50, 10, Align::Fill), (0, 50)),
+			// empty or inverted span
+			((40, 40, 10, 0, MAX, Align::Fill), (40, 40)),
+			((50, 40, 10, 0, MAX, Align::Center), (50, 50)),
+		];
+
+		for ((start, end, natural, min, max, align), expected) in cases.iter().copied() {
+			assert_eq!(align_span(start, end, natural, min, max, align), expected,
+				"align_span({}, {}, {}, {}, {}, {:?})", start, end, natural, min, max, align);
+		}
+	}
+
+	#[test]
+	fn distribute_natural() {
+		assert_eq!(distribute(100, &[]), [] as [i32; 0]);
+		assert_eq!(distribute(100, &[span(30, 0, 100, 0), span(70, 0, 100, 0)]), [30, 70]);
+		assert_eq!(distribute(100, &[span(200, 0, 100, 1)]), [100]); // natural above max
+		assert_eq!(distribute(100, &[span(20, 50, 10, 0), span(50, 0, 100, 0)]), [50, 50]); // min above max
+	}
+
+	#[test]
+	fn distribute_grow() {
+		// proportionally to the weights
+		assert_eq!(distribute(400, &[span(0, 0, i32::MAX, 1), span(0, 0, i32::MAX, 3)]), [100, 300]);
+		assert_eq!(distribute(130, &[span(30, 0, i32::MAX, 1), span(0, 0, i32::MAX, 0)]), [130, 0]);
+		// unweighted spans keep their natural size
+		assert_eq!(distribute(100, &[span(10, 0, i32::MAX, 0), span(10, 0, i32::MAX, 0)]), [10, 10]);
+		// rounding leftovers given one pixel at a time
+		assert_eq!(distribute(100, &[
+			span(0, 0, i32::MAX, 1),
+			span(0, 0, i32::MAX, 1),
+			span(0, 0, i32::MAX, 1),
+		]), [34, 33, 33]);
+		// a span at its maximum passes the rest to the others
+		assert_eq!(distribute(300, &[span(0, 0, 50, 1), span(0, 0, i32::MAX, 1)]), [50, 250]);
+		// all spans at their maximum: the total is not reached
+		assert_eq!(distribute(300, &[span(0, 0, 50, 1), span(0, 0, 50, 1)]), [50, 50]);
+	}
+
+	#[test]
+	fn distribute_shrink() {
+		// weighted spans shrink first, down to their minimum, then all equally
+		assert_eq!(distribute(120, &[span(100, 0, i32::MAX, 0), span(100, 40, i32::MAX, 1)]), [80, 40]);
+		assert_eq!(distribute(160, &[span(100, 0, i32::MAX, 0), span(100, 0, i32::MAX, 1)]), [100, 60]);
+		// unweighted spans shrink equally
+		assert_eq!(distribute(100, &[span(100, 0, i32::MAX, 0), span(50, 0, i32::MAX, 0)]), [75, 25]);
+		// a span at its minimum passes the rest to the others
+		assert_eq!(distribute(100, &[span(100, 90, i32::MAX, 0), span(100, 0, i32::MAX, 0)]), [90, 10]);
+		// all spans at their minimum: the total is exceeded
+		assert_eq!(distribute(100, &[span(100, 60, i32::MAX, 0), span(100, 60, i32::MAX, 0)]), [60, 60]);
+		assert_eq!(distribute(-10, &[span(10, 0, i32::MAX, 0)]), [0]);
+	}
+}
@@ -0,0 +1,78 @@
+//! Layout containers, which compute the position and size of child controls
+//! when the parent window is resized.
+//!
+//! A [`Layout`] is a tree of [`Item`]s. Each item is either a window, an empty
+//! spacer, or one of the containers:
+//!
+//! * [`Stack`] – items in a row or column, with proportional weights;
+//! * [`Grid`] – items in cells of fixed, automatic or weighted tracks;
+//! * [`Dock`] – items docked to the sides, and one filling the remaining area.
+//!
+//! Every item can have margins, minimum and maximum sizes, and an alignment
+//! within the space it receives. The rectangles are computed in pure Rust with
+//! [`Layout::compute`], and applied to the windows in a single
+//! [`HDWP`](crate::HDWP) batch with [`Layout::apply`]. All sizes are in
+//! DPI-independent pixels, as described in [`gui::dpi`](crate::gui::dpi).
+//!
+//! This is an alternative to the [`Horz`](crate::gui::Horz) and
+//! [`Vert`](crate::gui::Vert) resize behaviors of the controls, which only
+//! move or stretch a control along with the parent window. Controls arranged
+//! by a layout should keep these behaviors as `None`.
+//!
+//! # Examples
+//!
+//! A list filling the window, with a row of buttons at the bottom:
+//!
+//! ```no_run
+//! use winsafe::{self as w, prelude::*, co, gui};
+//! use winsafe::gui::layout::{Align, Item, Layout, Margins, Stack};
+//!
+//! let wnd: gui::WindowMain; // initialized somewhere
+//! let list: gui::ListView<()>;
+//! let btn_ok: gui::Button;
+//! let btn_cancel: gui::Button;
+//! # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+//! # let list = gui::ListView::<()>::new(&wnd, gui::ListViewOpts::default());
+//! # let btn_ok = gui::Button::new(&wnd, gui::ButtonOpts::default());
+//! # let btn_cancel = gui::Button::new(&wnd, gui::ButtonOpts::default());
+//!
+//! let layout = Layout::new(
+//!     Stack::vertical()
+//!         .padding(Margins::all(10))
+//!         .spacing(8)
+//!         .item(Item::window(&list).weight(1))
+//!         .item(
+//!             Item::from(
+//!                 Stack::horizontal()
+//!                     .spacing(6)
+//!                     .item(Item::spacer().weight(1))
+//!                     .item(Item::window(&btn_ok).size(80, 26))
+//!                     .item(Item::window(&btn_cancel).size(80, 26)),
+//!             ).align(Align::Fill, Align::End),
+//!         ),
+//! );
+//!
+//! wnd.on().wm_size(move |p| {
+//!     if p.request != co::SIZE_R::MINIMIZED {
+//!         layout.apply(w::RECT {
+//!             left: 0,
+//!             top: 0,
+//!             right: p.client_area.cx,
+//!             bottom: p.client_area.cy,
+//!         })?;
+//!     }
+//!     Ok(())
+//! });
+//! ```
+
+mod dock;
+mod grid;
+mod item;
+mod root;
+mod stack;
+
+pub use dock::{Dock, Side};
+pub use grid::{Grid, Track};
+pub use item::{Align, Item, Margins};
+pub use root::Layout;
+pub use stack::Stack;
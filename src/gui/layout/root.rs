@@ -0,0 +1,238 @@
+use crate::co;
+use crate::decl::*;
+use crate::gui::{dpi, privs::*};
+use crate::gui::layout::item::*;
+use crate::prelude::*;
+
+/// The root of a tree of [`Item`](crate::gui::layout::Item)s, which computes
+/// their rectangles and moves the windows accordingly.
+///
+/// A layout is usually applied in the
+/// [`wm_size`](crate::gui::events::WindowEvents::wm_size) event of the parent
+/// window. For a complete example, see the [`layout`](crate::gui::layout)
+/// module.
+#[derive(Clone)]
+pub struct Layout {
+	root: Item,
+}
+
+impl Layout {
+	/// Creates a new layout with the given root item, usually a container.
+	#[must_use]
+	pub fn new(root: impl Into<Item>) -> Self {
+		Self { root: root.into() }
+	}
+
+	/// Computes the rectangles of all windows and spacers within the given
+	/// area, in the order they were added, depth-first.
+	///
+	/// No system calls are made: windows whose natural size wasn't set are
+	/// considered with their size at the first
+	/// [`Layout::apply`](crate::gui::layout::Layout::apply) call, or with their
+	/// minimum size before that.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	/// use winsafe::gui::layout::{Item, Layout, Stack};
+	///
+	/// let layout = Layout::new(
+	///     Stack::horizontal()
+	///         .spacing(10)
+	///         .item(Item::spacer().width(50))
+	///         .item(Item::spacer().weight(1)),
+	/// );
+	///
+	/// let rcs = layout.compute(w::RECT { left: 0, top: 0, right: 200, bottom: 30 });
+	/// assert_eq!((rcs[1].left, rcs[1].right), (60, 200));
+	/// ```
+	#[must_use]
+	pub fn compute(&self, area: RECT) -> Vec<RECT> {
+		let mut rcs = Vec::new();
+		self.root.arrange(area, &mut rcs);
+		rcs
+	}
+
+	/// Computes the rectangles of all items within the given area, usually the
+	/// client area of the parent window, and moves the windows with
+	/// [`HDWP::DeferWindowPos`](crate::prelude::user_Hdwp::DeferWindowPos), all
+	/// at once.
+	///
+	/// The sizes of the items are taken as DPI-independent pixels, which are
+	/// scaled to the DPI of the windows, while the area is in actual pixels.
+	/// Windows not yet created are ignored.
+	pub fn apply(&self, area: RECT) -> SysResult<()> {
+		let mut leaves = Vec::<&Item>::new();
+		self.root.visit_leaves(&mut |item| leaves.push(item));
+
+		let hwnds = leaves.iter()
+			.filter_map(|item| match &item.content {
+				Content::Window(wnd, orig) if *wnd.hwnd() != HWND::NULL => Some((wnd.hwnd(), orig)),
+				_ => None,
+			})
+			.collect::<Vec<_>>();
+		let cur_dpi = hwnds.first()
+			.map_or(dpi::USER_DEFAULT_SCREEN_DPI, |(hwnd, _)| window_dpi(hwnd));
+
+		for (hwnd, orig) in hwnds.iter() {
+			if orig.get().is_none() {
+				let rc = hwnd.GetWindowRect()?;
+				orig.set(Some(dpi::scale_size( // cache in DPI-independent pixels
+					SIZE::new(rc.right - rc.left, rc.bottom - rc.top),
+					cur_dpi,
+					dpi::USER_DEFAULT_SCREEN_DPI,
+				)));
+			}
+		}
+
+		let area_dips = dpi::scale_rect(area, cur_dpi, dpi::USER_DEFAULT_SCREEN_DPI);
+		let wnds = leaves.iter()
+			.zip(self.compute(area_dips))
+			.filter_map(|(item, rc)| match &item.content {
+				Content::Window(wnd, _) if *wnd.hwnd() != HWND::NULL => Some((
+					wnd.hwnd(),
+					dpi::scale_rect(rc, dpi::USER_DEFAULT_SCREEN_DPI, cur_dpi),
+				)),
+				_ => None,
+			})
+			.collect::<Vec<_>>();
+		if wnds.is_empty() {
+			return Ok(());
+		}
+
+		let mut hdwp = HDWP::BeginDeferWindowPos(wnds.len() as _)?;
+		for (hwnd, rc) in wnds.iter() {
+			hdwp.DeferWindowPos(
+				hwnd,
+				HwndPlace::None,
+				POINT::new(rc.left, rc.top),
+				SIZE::new(rc.right - rc.left, rc.bottom - rc.top),
+				co::SWP::NOZORDER | co::SWP::NOACTIVATE,
+			)?;
+		}
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::gui::layout::{Dock, Grid, Side, Stack, Track};
+
+	fn compute(root: impl Into<Item>, cx: i32, cy: i32) -> Vec<(i32, i32, i32, i32)> {
+		Layout::new(root)
+			.compute(RECT { left: 0, top: 0, right: cx, bottom: cy })
+			.iter()
+			.map(|rc| (rc.left, rc.top, rc.right, rc.bottom))
+			.collect()
+	}
+
+	#[test]
+	fn stack() {
+		assert_eq!(compute(Stack::horizontal()
+			.spacing(10)
+			.item(Item::spacer().width(50))
+			.item(Item::spacer().weight(1)),
+			200, 30,
+		), [(0, 0, 50, 30), (60, 0, 200, 30)]);
+
+		assert_eq!(compute(Stack::horizontal()
+			.item(Item::spacer().weight(1))
+			.item(Item::spacer().weight(3)),
+			400, 30,
+		), [(0, 0, 100, 30), (100, 0, 400, 30)]);
+
+		assert_eq!(compute(Stack::vertical()
+			.padding(Margins::all(10))
+			.spacing(5)
+			.item(Item::spacer().height(20))
+			.item(Item::spacer().weight(1).margins(Margins::new(2, 3, 4, 5))),
+			100, 200,
+		), [(10, 10, 90, 30), (12, 38, 86, 185)]);
+
+		assert_eq!(compute(Stack::horizontal() // cross-axis alignment
+			.item(Item::spacer().size(20, 20).align(Align::Start, Align::Center)),
+			100, 100,
+		), [(0, 40, 20, 60)]);
+	}
+
+	#[test]
+	fn stack_clamp() {
+		assert_eq!(compute(Stack::horizontal()
+			.item(Item::spacer().weight(1).max_size(50, i32::MAX))
+			.item(Item::spacer().weight(1)),
+			300, 10,
+		), [(0, 0, 50, 10), (50, 0, 300, 10)]);
+
+		assert_eq!(compute(Stack::horizontal() // shrink below natural size
+			.item(Item::spacer().width(100))
+			.item(Item::spacer().width(100).weight(1).min_size(40, 0)),
+			120, 10,
+		), [(0, 0, 80, 10), (80, 0, 120, 10)]);
+
+		assert_eq!(compute(Stack::horizontal() // minimums exceed the area
+			.item(Item::spacer().min_size(60, 0))
+			.item(Item::spacer().min_size(60, 0)),
+			100, 10,
+		), [(0, 0, 60, 10), (60, 0, 120, 10)]);
+	}
+
+	#[test]
+	fn grid() {
+		assert_eq!(compute(Grid::new(
+				[Track::fixed(50), Track::weighted(1)],
+				[Track::auto(), Track::weighted(1)],
+			)
+			.spacing(10, 5)
+			.cell(0, 0, Item::spacer().height(20))
+			.cell(0, 1, Item::spacer())
+			.cell_span(1, 0, 1, 2, Item::spacer()),
+			200, 100,
+		), [(0, 0, 50, 20), (60, 0, 200, 20), (0, 25, 200, 100)]);
+
+		assert_eq!(compute(Grid::new(
+				[Track::weighted(1).max(50), Track::weighted(1)],
+				[Track::weighted(1)],
+			)
+			.cell(0, 0, Item::spacer())
+			.cell(0, 1, Item::spacer()),
+			300, 10,
+		), [(0, 0, 50, 10), (50, 0, 300, 10)]);
+
+		assert_eq!(compute(Grid::new( // shrink below natural size
+				[Track::auto(), Track::auto()],
+				[Track::weighted(1)],
+			)
+			.cell(0, 0, Item::spacer().width(100))
+			.cell(0, 1, Item::spacer().width(100)),
+			150, 10,
+		), [(0, 0, 75, 10), (75, 0, 150, 10)]);
+	}
+
+	#[test]
+	fn dock() {
+		assert_eq!(compute(Dock::new()
+			.padding(Margins::all(2))
+			.spacing(4)
+			.dock(Side::Bottom, Item::spacer().height(20))
+			.dock(Side::Left, Item::spacer().width(50))
+			.dock(Side::Right, Item::spacer().width(30))
+			.dock(Side::Top, Item::spacer().height(10))
+			.fill(Item::spacer()),
+			300, 200,
+		), [
+			(2, 178, 298, 198),
+			(2, 2, 52, 174),
+			(268, 2, 298, 174),
+			(56, 2, 264, 12),
+			(56, 16, 264, 174),
+		]);
+
+		assert_eq!(compute(Dock::new() // shrink below natural size
+			.dock(Side::Left, Item::spacer().width(100))
+			.fill(Item::spacer()),
+			60, 10,
+		), [(0, 0, 60, 10), (60, 0, 60, 10)]);
+	}
+}
@@ -0,0 +1,131 @@
+use crate::decl::*;
+use crate::gui::layout::item::*;
+
+/// A container which places its items side by side, in a row or in a column.
+///
+/// Along the stack direction, each item receives its natural size, and the
+/// extra space is distributed among the items with
+/// [weight](crate::gui::layout::Item::weight). Across the stack direction,
+/// each item receives the whole space.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, gui};
+/// use winsafe::gui::layout::{Item, Stack};
+///
+/// let txt_name: gui::Edit; // initialized somewhere
+/// let btn_go: gui::Button;
+/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+/// # let txt_name = gui::Edit::new(&wnd, gui::EditOpts::default());
+/// # let btn_go = gui::Button::new(&wnd, gui::ButtonOpts::default());
+///
+/// let row = Stack::horizontal()
+///     .spacing(4)
+///     .item(Item::window(&txt_name).weight(1))
+///     .item(Item::window(&btn_go).width(60));
+/// ```
+#[derive(Clone)]
+pub struct Stack {
+	horz: bool,
+	spacing: i32,
+	padding: Margins,
+	items: Vec<Item>,
+}
+
+impl Stack {
+	#[must_use]
+	const fn new(horz: bool) -> Self {
+		Self {
+			horz,
+			spacing: 0,
+			padding: Margins::all(0),
+			items: Vec::new(),
+		}
+	}
+
+	/// Creates a new stack which places its items from left to right.
+	#[must_use]
+	pub const fn horizontal() -> Self {
+		Self::new(true)
+	}
+
+	/// Creates a new stack which places its items from top to bottom.
+	#[must_use]
+	pub const fn vertical() -> Self {
+		Self::new(false)
+	}
+
+	/// Sets the space between two consecutive items. Defaults to zero.
+	#[must_use]
+	pub const fn spacing(mut self, spacing: i32) -> Self {
+		self.spacing = spacing;
+		self
+	}
+
+	/// Sets the space between the borders of the stack and its items. Defaults
+	/// to zero.
+	#[must_use]
+	pub const fn padding(mut self, padding: Margins) -> Self {
+		self.padding = padding;
+		self
+	}
+
+	/// Appends an item.
+	#[must_use]
+	pub fn item(mut self, item: impl Into<Item>) -> Self {
+		self.items.push(item.into());
+		self
+	}
+
+	pub(in crate::gui::layout) fn items(&self) -> impl Iterator<Item = &Item> {
+		self.items.iter()
+	}
+
+	/// Returns the total space between the items.
+	#[must_use]
+	fn total_spacing(&self) -> i32 {
+		self.spacing * (self.items.len().max(1) - 1) as i32
+	}
+
+	#[must_use]
+	pub(in crate::gui::layout) fn natural(&self) -> SIZE {
+		let (mut main, mut cross) = (self.total_spacing(), 0);
+		for item in self.items.iter() {
+			let sz = item.outer_natural();
+			main += axis(sz, self.horz);
+			cross = cross.max(axis(sz, !self.horz));
+		}
+		let sz = if self.horz { SIZE::new(main, cross) } else { SIZE::new(cross, main) };
+		self.padding.grow(sz)
+	}
+
+	pub(in crate::gui::layout) fn arrange(&self, rc: RECT, out: &mut Vec<RECT>) {
+		let inner = self.padding.shrink(rc);
+		let (main_start, main_len, cross_start, cross_len) = if self.horz {
+			(inner.left, inner.right - inner.left, inner.top, inner.bottom - inner.top)
+		} else {
+			(inner.top, inner.bottom - inner.top, inner.left, inner.right - inner.left)
+		};
+
+		let spans = self.items.iter()
+			.map(|item| Span {
+				natural: axis(item.natural(), self.horz),
+				min: item.min(self.horz),
+				max: item.max(self.horz),
+				weight: item.weight,
+			})
+			.collect::<Vec<_>>();
+		let margins = self.items.iter()
+			.map(|item| item.margins.sum(self.horz))
+			.sum::<i32>();
+		let lens = distribute(main_len - self.total_spacing() - margins, &spans);
+
+		let mut pos = main_start;
+		for (item, len) in self.items.iter().zip(lens) {
+			let outer_len = len + item.margins.sum(self.horz);
+			item.arrange(axis_rect(self.horz, pos, outer_len, cross_start, cross_len), out);
+			pos += outer_len + self.spacing;
+		}
+	}
+}
@@ -16,7 +16,9 @@ mod proc;
 mod traits_gui;
 mod windows;
 
+pub mod dpi;
 pub mod events;
+pub mod layout;
 
 pub(in crate::gui) mod privs {
 	pub(in crate::gui) use super::events::privs::*;
@@ -131,6 +131,55 @@ impl BaseNativeControl {
 		Ok(())
 	}
 
+	/// Creates the control as a popup window owned by the parent, with
+	/// `CreateWindowEx`.
+	pub(in crate::gui) fn create_popup(&self,
+		class_name: &str,
+		ex_styles: co::WS_EX,
+		styles: co::WS,
+	) -> SysResult<()>
+	{
+		let hparent = self.parent().hwnd();
+
+		if *self.hwnd() != HWND::NULL {
+			panic!("Cannot create control twice.");
+		} else if *hparent == HWND::NULL {
+			panic!("Cannot create control before parent window creation.");
+		}
+
+		self.assign_hctrl(
+			unsafe {
+				HWND::CreateWindowEx(
+					ex_styles,
+					AtomStr::from_str(class_name),
+					None, styles,
+					POINT::default(), SIZE::default(),
+					Some(hparent),
+					IdMenu::None,
+					&hparent.hinstance(),
+					None,
+				)?
+			},
+		);
+		self.install_subclass_if_needed()?;
+		Ok(())
+	}
+
+	/// Sets the text of the tooltip shared by the controls of the parent
+	/// window. An empty text removes the tooltip.
+	pub(in crate::gui) fn set_tooltip(&self, text: &str) -> SysResult<()> {
+		if *self.hwnd() == HWND::NULL {
+			panic!("Cannot set the tooltip before control creation.");
+		}
+
+		let htooltip = self.parent().shared_tooltip()?;
+		if text.is_empty() {
+			remove_tool(htooltip, self.hwnd())
+		} else {
+			set_tool_text(htooltip, self.hwnd(), text)
+		}
+	}
+
 	/// Assigns the control ID with `GetDlgItem`.
 	pub(in crate::gui) fn create_dlg(&self) -> SysResult<()> {
 		if !self.parent().is_dialog() {
@@ -164,7 +164,7 @@ impl Button {
 
 				unsafe {
 					self.hwnd().SendMessage(wm::SetFont {
-						hfont: ui_font(),
+						hfont: ui_font_for(self.hwnd())?,
 						redraw: true,
 					});
 				}
@@ -198,8 +198,8 @@ pub struct ButtonOpts {
 	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
 	///
 	/// If the parent window is a dialog, the values are in Dialog Template
-	/// Units; otherwise in pixels, which will be multiplied to match current
-	/// system DPI.
+	/// Units; otherwise in DPI-independent pixels, which will be scaled to the
+	/// DPI of the parent window.
 	///
 	/// Defaults to `(0, 0)`.
 	pub position: (i32, i32),
@@ -207,8 +207,8 @@ pub struct ButtonOpts {
 	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
 	///
 	/// If the parent window is a dialog, the value is in Dialog Template Units;
-	/// otherwise in pixels, which will be multiplied to match current system
-	/// DPI.
+	/// otherwise in DPI-independent pixels, which will be scaled to the DPI of
+	/// the parent window.
 	///
 	/// Defaults to `88`.
 	pub width: u32,
@@ -216,8 +216,8 @@ pub struct ButtonOpts {
 	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
 	///
 	/// If the parent window is a dialog, the value is in Dialog Template Units;
-	/// otherwise in pixels, which will be multiplied to match current system
-	/// DPI.
+	/// otherwise in DPI-independent pixels, which will be scaled to the DPI of
+	/// the parent window.
 	///
 	/// Defaults to `26`.
 	pub height: u32,
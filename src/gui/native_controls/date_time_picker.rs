@@ -163,7 +163,7 @@ impl DateTimePicker {
 
 				unsafe {
 					self.hwnd().SendMessage(wm::SetFont {
-						hfont: ui_font(),
+						hfont: ui_font_for(self.hwnd())?,
 						redraw: true,
 					});
 				}
@@ -208,8 +208,8 @@ pub struct DateTimePickerOpts {
 	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
 	///
 	/// If the parent window is a dialog, the values are in Dialog Template
-	/// Units; otherwise in pixels, which will be multiplied to match current
-	/// system DPI.
+	/// Units; otherwise in DPI-independent pixels, which will be scaled to the
+	/// DPI of the parent window.
 	///
 	/// Defaults to `(0, 0)`.
 	pub position: (i32, i32),
@@ -217,8 +217,8 @@ pub struct DateTimePickerOpts {
 	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
 	///
 	/// If the parent window is a dialog, the values are in Dialog Template
-	/// Units; otherwise in pixels, which will be multiplied to match current
-	/// system DPI.
+	/// Units; otherwise in DPI-independent pixels, which will be scaled to the
+	/// DPI of the parent window.
 	///
 	/// Defaults to ideal width retrieved with
 	/// [`dtm::GetIdealSize`](crate::msg::dtm::GetIdealSize) message, usually
@@ -194,10 +194,11 @@ impl<'a> HeaderItem<'a> {
 	/// Sets the width of the item by sending a
 	/// [`hdm::SetItem`](crate::msg::hdm::SetItem) message.
 	///
-	/// Width will be adjusted to match current system DPI.
+	/// Width is in DPI-independent pixels, which will be scaled to the DPI of
+	/// the header.
 	pub fn set_width(&self, width: u32) {
 		let mut col_cx = SIZE::new(width as _, 0);
-		multiply_dpi(None, Some(&mut col_cx)).unwrap();
+		multiply_dpi(self.owner.hwnd(), None, Some(&mut col_cx)).unwrap();
 
 		let mut hdi = HDITEM::default();
 		hdi.mask = co::HDI::WIDTH;
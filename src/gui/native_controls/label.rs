@@ -138,7 +138,8 @@ impl Label {
 
 				let mut sz = SIZE::new(opts.size.0 as _, opts.size.1 as _);
 				if sz.cx == -1 && sz.cy == -1 {
-					sz = calc_text_bound_box(&opts.text)?; // resize to fit text
+					sz = calc_text_bound_box(
+						&opts.text, window_dpi(self.0.base.parent().hwnd()))?; // resize to fit text
 				} else {
 					multiply_dpi_or_dtu(
 						self.0.base.parent(), None, Some(&mut sz))?; // user-defined size
@@ -152,7 +153,7 @@ impl Label {
 
 				unsafe {
 					self.hwnd().SendMessage(wm::SetFont {
-						hfont: ui_font(),
+						hfont: ui_font_for(self.hwnd())?,
 						redraw: true,
 					});
 				}
@@ -180,7 +181,7 @@ impl Label {
 	/// ```
 	pub fn set_text_and_resize(&self, text: &str) {
 		self.set_text(text);
-		let bound_box = calc_text_bound_box(text).unwrap();
+		let bound_box = calc_text_bound_box(text, window_dpi(self.hwnd())).unwrap();
 		self.hwnd().SetWindowPos(
 			HwndPlace::None, POINT::default(), bound_box,
 			co::SWP::NOZORDER | co::SWP::NOMOVE).unwrap();
@@ -202,8 +203,8 @@ pub struct LabelOpts {
 	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
 	///
 	/// If the parent window is a dialog, the values are in Dialog Template
-	/// Units; otherwise in pixels, which will be multiplied to match current
-	/// system DPI.
+	/// Units; otherwise in DPI-independent pixels, which will be scaled to the
+	/// DPI of the parent window.
 	///
 	/// Defaults to `(0, 0)`.
 	pub position: (i32, i32),
@@ -211,8 +212,8 @@ pub struct LabelOpts {
 	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
 	///
 	/// If the parent window is a dialog, the values are in Dialog Template
-	/// Units; otherwise in pixels, which will be multiplied to match current
-	/// system DPI.
+	/// Units; otherwise in DPI-independent pixels, which will be scaled to the
+	/// DPI of the parent window.
 	///
 	/// Defaults to the size needed to fit the text.
 	pub size: (u32, u32),
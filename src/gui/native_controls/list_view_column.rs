@@ -46,10 +46,11 @@ impl<'a, T> ListViewColumn<'a, T> {
 	/// Sets the width of the column by sending an
 	/// [`lvm::SetColumnWidth`](crate::msg::lvm::SetColumnWidth) message.
 	///
-	/// Width will be adjusted to match current system DPI.
+	/// Width is in DPI-independent pixels, which will be scaled to the DPI of
+	/// the list view.
 	pub fn set_width(&self, width: u32) {
 		let mut col_cx = SIZE::new(width as _, 0);
-		multiply_dpi(None, Some(&mut col_cx)).unwrap();
+		multiply_dpi(self.owner.hwnd(), None, Some(&mut col_cx)).unwrap();
 
 		unsafe {
 			self.owner.hwnd()
@@ -21,7 +21,8 @@ impl<'a, T> ListViewColumns<'a, T> {
 	/// Adds many columns at once by sending an
 	/// [`lvm::InsertColumn`](crate::msg::lvm::InsertColumn) message.
 	///
-	/// Widths will be adjusted to match current system DPI.
+	/// Widths are in DPI-independent pixels, which will be scaled to the DPI
+	/// of the list view.
 	///
 	/// # Examples
 	///
@@ -40,7 +41,7 @@ impl<'a, T> ListViewColumns<'a, T> {
 	pub fn add(&self, texts_and_widths: &[(impl AsRef<str>, u32)]) {
 		for (text, width) in texts_and_widths.iter() {
 			let mut col_cx = SIZE::new(*width as _, 0);
-			multiply_dpi(None, Some(&mut col_cx)).unwrap();
+			multiply_dpi(self.owner.hwnd(), None, Some(&mut col_cx)).unwrap();
 
 			let mut lvc = LVCOLUMN::default();
 			lvc.mask = co::LVCF::TEXT | co::LVCF::WIDTH;
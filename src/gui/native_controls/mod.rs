@@ -22,12 +22,17 @@ mod month_calendar;
 mod progress_bar;
 mod radio_button;
 mod radio_group;
+mod rich_edit;
 mod status_bar_part;
 mod status_bar_parts;
 mod status_bar;
 mod tab_item;
 mod tab_items;
 mod tab;
+mod toolbar_button;
+mod toolbar_buttons;
+mod toolbar;
+mod tooltip;
 mod trackbar;
 mod tree_view_item;
 mod tree_view_items;
@@ -36,6 +41,7 @@ mod up_down;
 
 pub(in crate::gui) mod privs {
 	pub(in crate::gui) use super::base_native_control::*;
+	pub(in crate::gui) use super::tooltip::{create_tooltip_window, remove_tool, set_tool_text, SHARED_TOOLTIP_WIDTH};
 }
 
 pub mod decl {
@@ -52,8 +58,11 @@ pub mod decl {
 	pub use super::progress_bar::{ProgressBar, ProgressBarOpts};
 	pub use super::radio_button::{RadioButton, RadioButtonOpts};
 	pub use super::radio_group::RadioGroup;
+	pub use super::rich_edit::{RichEdit, RichEditOpts};
 	pub use super::status_bar::StatusBar;
 	pub use super::tab::{Tab, TabOpts};
+	pub use super::toolbar::{Toolbar, ToolbarOpts};
+	pub use super::tooltip::{Tooltip, TooltipOpts};
 	pub use super::trackbar::{Trackbar, TrackbarOpts};
 	pub use super::tree_view::{TreeView, TreeViewOpts};
 	pub use super::up_down::{UpDown, UpDownOpts};
@@ -72,6 +81,8 @@ pub mod spec {
 	pub use super::status_bar_parts::StatusBarParts;
 	pub use super::tab_item::TabItem;
 	pub use super::tab_items::TabItems;
+	pub use super::toolbar_button::ToolbarButton;
+	pub use super::toolbar_buttons::ToolbarButtons;
 	pub use super::tree_view_item::TreeViewItem;
 	pub use super::tree_view_items::TreeViewItems;
 }
@@ -0,0 +1,695 @@
+use std::any::Any;
+use std::io::{self, Read, Write};
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use crate::co;
+use crate::decl::*;
+use crate::gui::{*, events::*, privs::*, proc};
+use crate::msg::*;
+use crate::prelude::*;
+
+struct Obj { // actual fields of RichEdit
+	base: BaseNativeControl,
+	events: RichEditEvents,
+	_pin: PhantomPinned,
+}
+
+//------------------------------------------------------------------------------
+
+/// Native
+/// [rich edit](https://learn.microsoft.com/en-us/windows/win32/controls/about-rich-edit-controls)
+/// control, version 4.1, whose window class is `RICHEDIT50W`.
+///
+/// Character positions are counted in UTF-16 code units, and paragraphs are
+/// terminated by a single `\r`.
+#[derive(Clone)]
+pub struct RichEdit(Pin<Arc<Obj>>);
+
+unsafe impl Send for RichEdit {}
+
+impl AsRef<BaseNativeControl> for RichEdit {
+	fn as_ref(&self) -> &BaseNativeControl {
+		&self.0.base
+	}
+}
+
+impl GuiWindow for RichEdit {
+	fn hwnd(&self) -> &HWND {
+		self.0.base.hwnd()
+	}
+
+	fn as_any(&self) -> &dyn Any {
+		self
+	}
+}
+
+impl GuiWindowText for RichEdit {}
+
+impl GuiChild for RichEdit {
+	fn ctrl_id(&self) -> u16 {
+		self.0.base.ctrl_id()
+	}
+}
+
+impl GuiChildFocus for RichEdit {}
+
+impl GuiNativeControl for RichEdit {}
+
+impl GuiNativeControlEvents<RichEditEvents> for RichEdit {
+	fn on(&self) -> &RichEditEvents {
+		if *self.hwnd() != HWND::NULL {
+			panic!("Cannot add events after the control creation.");
+		} else if *self.0.base.parent().hwnd() != HWND::NULL {
+			panic!("Cannot add events after the parent window creation.");
+		}
+		&self.0.events
+	}
+}
+
+impl RichEdit {
+	/// Instantiates a new `RichEdit` object, to be created on the parent
+	/// window with
+	/// [`HWND::CreateWindowEx`](crate::prelude::user_Hwnd::CreateWindowEx).
+	///
+	/// # Panics
+	///
+	/// Panics if the parent window was already created – that is, you cannot
+	/// dynamically create a `RichEdit` in an event closure.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, gui};
+	///
+	/// let wnd: gui::WindowMain; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	///
+	/// let txt = gui::RichEdit::new(
+	///     &wnd,
+	///     gui::RichEditOpts {
+	///         position: (10, 10),
+	///         width: 300,
+	///         height: 200,
+	///         resize_behavior: (gui::Horz::Resize, gui::Vert::Resize),
+	///         ..Default::default()
+	///     },
+	/// );
+	/// ```
+	#[must_use]
+	pub fn new(parent: &impl GuiParent, opts: RichEditOpts) -> Self {
+		let opts = auto_ctrl_id_if_zero(opts);
+		let ctrl_id = opts.ctrl_id;
+
+		let new_self = Self(
+			Arc::pin(
+				Obj {
+					base: BaseNativeControl::new(parent, ctrl_id),
+					events: RichEditEvents::new(parent, ctrl_id),
+					_pin: PhantomPinned,
+				},
+			),
+		);
+
+		let self2 = new_self.clone();
+		parent.as_ref().before_user_on().wm_create_or_initdialog(move |_, _| {
+			self2.create(OptsResz::Wnd(&opts))?;
+			Ok(WmRet::NotHandled)
+		});
+
+		new_self
+	}
+
+	/// Instantiates a new `RichEdit` object, to be loaded from a dialog
+	/// resource with
+	/// [`HWND::GetDlgItem`](crate::prelude::user_Hwnd::GetDlgItem).
+	///
+	/// The `RICHEDIT50W` window class is registered right away, so the dialog
+	/// can be created.
+	///
+	/// # Panics
+	///
+	/// Panics if the parent dialog was already created – that is, you cannot
+	/// dynamically create a `RichEdit` in an event closure.
+	#[must_use]
+	pub fn new_dlg(
+		parent: &impl GuiParent,
+		ctrl_id: u16,
+		resize_behavior: (Horz, Vert),
+	) -> Self
+	{
+		load_msftedit().unwrap();
+
+		let new_self = Self(
+			Arc::pin(
+				Obj {
+					base: BaseNativeControl::new(parent, ctrl_id),
+					events: RichEditEvents::new(parent, ctrl_id),
+					_pin: PhantomPinned,
+				},
+			),
+		);
+
+		let self2 = new_self.clone();
+		parent.as_ref().before_user_on().wm_init_dialog(move |_| {
+			self2.create(OptsResz::Dlg(resize_behavior))?;
+			Ok(false) // return value is discarded
+		});
+
+		new_self
+	}
+
+	fn create(&self, opts_resz: OptsResz<&RichEditOpts>) -> SysResult<()> {
+		match opts_resz {
+			OptsResz::Wnd(opts) => {
+				let mut pos = POINT::new(opts.position.0, opts.position.1);
+				let mut sz = SIZE::new(opts.width as _, opts.height as _);
+				multiply_dpi_or_dtu(
+					self.0.base.parent(), Some(&mut pos), Some(&mut sz))?;
+
+				load_msftedit()?;
+				self.0.base.create_window(
+					"RICHEDIT50W", Some(&opts.text), pos, sz,
+					opts.window_ex_style,
+					opts.window_style | opts.edit_style.into(),
+				)?;
+
+				unsafe {
+					self.hwnd().SendMessage(wm::SetFont {
+						hfont: ui_font_for(self.hwnd())?,
+						redraw: true,
+					});
+				}
+			},
+			OptsResz::Dlg(_) => self.0.base.create_dlg()?,
+		}
+
+		unsafe {
+			self.hwnd().SendMessage(em::SetEventMask { // notifications exposed by RichEditEvents
+				mask: co::ENM::CHANGE | co::ENM::UPDATE | co::ENM::SCROLL
+					| co::ENM::SELCHANGE | co::ENM::LINK | co::ENM::PROTECTED,
+			});
+		}
+
+		self.0.base.parent()
+			.add_to_layout_arranger(self.hwnd(), opts_resz.resize_behavior())
+	}
+
+	/// Appends text at the end of the contents, with the given character
+	/// format, by sending [`em::ExSetSel`](crate::msg::em::ExSetSel),
+	/// [`em::SetCharFormat`](crate::msg::em::SetCharFormat) and
+	/// [`em::ReplaceSel`](crate::msg::em::ReplaceSel) messages.
+	///
+	/// If `format` is `None`, the format of the last character is used.
+	///
+	/// # Examples
+	///
+	/// Appending a red line:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co, gui};
+	///
+	/// let txt: gui::RichEdit; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	/// # let txt = gui::RichEdit::new(&wnd, gui::RichEditOpts::default());
+	///
+	/// let mut cf = w::CHARFORMAT2::default();
+	/// cf.dwMask = co::CFM::COLOR;
+	/// cf.crTextColor = w::COLORREF::new(0xff, 0x00, 0x00);
+	///
+	/// txt.append_text("Error: file not found.\r", Some(&cf));
+	/// ```
+	pub fn append_text(&self, text: &str, format: Option<&CHARFORMAT2>) {
+		self.set_selection(-1, -1); // caret at the end
+		if let Some(format) = format {
+			self.set_char_format(co::SCF::SELECTION, format);
+		}
+		unsafe {
+			self.hwnd()
+				.SendMessage(em::ReplaceSel {
+					can_be_undone: false,
+					replacement_text: WString::from_str(text),
+				});
+		}
+	}
+
+	/// Retrieves the character format of the selection, or the default one, by
+	/// sending an [`em::GetCharFormat`](crate::msg::em::GetCharFormat) message.
+	///
+	/// When retrieving the selection format, the `dwMask` field tells which
+	/// attributes are consistent throughout the selection.
+	#[must_use]
+	pub fn char_format(&self, scope: co::SCF) -> CHARFORMAT2 {
+		let mut cf = CHARFORMAT2::default();
+		unsafe {
+			self.hwnd()
+				.SendMessage(em::GetCharFormat { scope, info: &mut cf });
+		}
+		cf
+	}
+
+	/// Searches for the given text, returning the start and end positions of
+	/// the first match, by sending an
+	/// [`em::FindTextEx`](crate::msg::em::FindTextEx) message.
+	///
+	/// If `flags` has [`FR::DOWN`](crate::co::FR::DOWN), the search goes from
+	/// `start` to the end of the text; otherwise, it goes backwards from
+	/// `start` to the beginning.
+	///
+	/// # Examples
+	///
+	/// Selecting the next occurrence of a word:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co, gui};
+	///
+	/// let txt: gui::RichEdit; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	/// # let txt = gui::RichEdit::new(&wnd, gui::RichEditOpts::default());
+	///
+	/// let (_, sel_end) = txt.selection();
+	/// if let Some((start, end)) = txt.find("error", sel_end, co::FR::DOWN | co::FR::WHOLEWORD) {
+	///     txt.set_selection(start, end);
+	/// }
+	/// ```
+	#[must_use]
+	pub fn find(&self, text: &str, start: i32, flags: co::FR) -> Option<(i32, i32)> {
+		let mut wtext = WString::from_str(text);
+		let mut fte = FINDTEXTEX::default();
+		fte.chrg = CHARRANGE {
+			cpMin: start,
+			cpMax: if flags.has(co::FR::DOWN) { -1 } else { 0 },
+		};
+		fte.set_lpstrText(Some(&mut wtext));
+
+		unsafe {
+			self.hwnd()
+				.SendMessage(em::FindTextEx { flags, info: &mut fte })
+		}.map(|_| {
+			let found = fte.chrgText;
+			(found.cpMin, found.cpMax)
+		})
+	}
+
+	/// Limits the number of characters that can be typed by sending an
+	/// [`em::ExLimitText`](crate::msg::em::ExLimitText) message.
+	///
+	/// If `max_chars` is zero, the default limit of 64,000 characters is
+	/// restored.
+	pub fn limit_text(&self, max_chars: u32) {
+		unsafe { self.hwnd().SendMessage(em::ExLimitText { max_chars }); }
+	}
+
+	/// Retrieves the paragraph format of the selection by sending an
+	/// [`em::GetParaFormat`](crate::msg::em::GetParaFormat) message.
+	///
+	/// The `dwMask` field tells which attributes are consistent throughout the
+	/// selection.
+	#[must_use]
+	pub fn para_format(&self) -> PARAFORMAT2 {
+		let mut pf = PARAFORMAT2::default();
+		unsafe {
+			self.hwnd()
+				.SendMessage(em::GetParaFormat { info: &mut pf });
+		}
+		pf
+	}
+
+	/// Replaces all occurrences of a text, returning how many were replaced, by
+	/// sending [`em::FindTextEx`](crate::msg::em::FindTextEx),
+	/// [`em::ExSetSel`](crate::msg::em::ExSetSel) and
+	/// [`em::ReplaceSel`](crate::msg::em::ReplaceSel) messages.
+	///
+	/// The search always goes from the beginning to the end of the text, so
+	/// [`FR::DOWN`](crate::co::FR::DOWN) is implied.
+	pub fn replace_all(&self, text: &str, replacement: &str, flags: co::FR) -> u32 {
+		if text.is_empty() {
+			return 0;
+		}
+
+		let replacement_len = replacement.encode_utf16().count() as i32;
+		let mut pos = 0;
+		let mut count = 0;
+
+		while let Some((start, end)) = self.find(text, pos, flags | co::FR::DOWN) {
+			self.set_selection(start, end);
+			self.replace_selection(replacement);
+			pos = start + replacement_len; // skip the replacement itself
+			count += 1;
+		}
+		count
+	}
+
+	/// Replaces the selected text, which can be undone, by sending an
+	/// [`em::ReplaceSel`](crate::msg::em::ReplaceSel) message.
+	///
+	/// If there is no selection, the text is inserted at the caret.
+	pub fn replace_selection(&self, text: &str) {
+		unsafe {
+			self.hwnd()
+				.SendMessage(em::ReplaceSel {
+					can_be_undone: true,
+					replacement_text: WString::from_str(text),
+				});
+		}
+	}
+
+	/// Retrieves the start and end positions of the selection by sending an
+	/// [`em::ExGetSel`](crate::msg::em::ExGetSel) message.
+	#[must_use]
+	pub fn selection(&self) -> (i32, i32) {
+		let mut range = CHARRANGE::default();
+		unsafe { self.hwnd().SendMessage(em::ExGetSel { range: &mut range }); }
+		(range.cpMin, range.cpMax)
+	}
+
+	/// Enables or disables the automatic detection of URLs, which are then
+	/// formatted as links and trigger
+	/// [`en_link`](crate::gui::events::RichEditEvents::en_link) events, by
+	/// sending an [`em::AutoUrlDetect`](crate::msg::em::AutoUrlDetect)
+	/// message.
+	pub fn set_auto_url_detect(&self, enable: bool) {
+		unsafe {
+			self.hwnd()
+				.SendMessage(em::AutoUrlDetect { enable })
+		}.unwrap();
+	}
+
+	/// Sets the background color by sending an
+	/// [`em::SetBkgndColor`](crate::msg::em::SetBkgndColor) message.
+	///
+	/// If `color` is `None`, the system window background color is used.
+	pub fn set_background_color(&self, color: Option<COLORREF>) {
+		unsafe { self.hwnd().SendMessage(em::SetBkgndColor { color }); }
+	}
+
+	/// Sets the character format of the selection, of the word at the caret,
+	/// of the whole text, or the default one, by sending an
+	/// [`em::SetCharFormat`](crate::msg::em::SetCharFormat) message.
+	///
+	/// Only the attributes in the `dwMask` field are changed.
+	///
+	/// # Examples
+	///
+	/// Making the selection bold and protected, so that
+	/// [`en_protected`](crate::gui::events::RichEditEvents::en_protected) is
+	/// triggered when the user tries to change it:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co, gui};
+	///
+	/// let txt: gui::RichEdit; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	/// # let txt = gui::RichEdit::new(&wnd, gui::RichEditOpts::default());
+	///
+	/// let mut cf = w::CHARFORMAT2::default();
+	/// cf.dwMask = co::CFM::BOLD | co::CFM::PROTECTED;
+	/// cf.dwEffects = co::CFE::BOLD | co::CFE::PROTECTED;
+	///
+	/// txt.set_char_format(co::SCF::SELECTION, &cf);
+	/// ```
+	pub fn set_char_format(&self, scope: co::SCF, format: &CHARFORMAT2) {
+		unsafe {
+			self.hwnd()
+				.SendMessage(em::SetCharFormat { scope, info: format })
+		}.unwrap();
+	}
+
+	/// Sets the font to the `RichEdit` by sending an
+	/// [`wm::SetFont`](crate::msg::wm::SetFont) message.
+	///
+	/// Note that the font must remain alive while being used in the control.
+	pub fn set_font(&self, font: &HFONT) {
+		unsafe {
+			self.hwnd().SendMessage(wm::SetFont {
+				hfont: font.raw_copy(),
+				redraw: true,
+			});
+		}
+	}
+
+	/// Sets the paragraph format of the selection by sending an
+	/// [`em::SetParaFormat`](crate::msg::em::SetParaFormat) message.
+	///
+	/// Only the attributes in the `dwMask` field are changed.
+	pub fn set_para_format(&self, format: &PARAFORMAT2) {
+		unsafe {
+			self.hwnd()
+				.SendMessage(em::SetParaFormat { info: format })
+		}.unwrap();
+	}
+
+	/// Sets the selection range of the text by sending an
+	/// [`em::ExSetSel`](crate::msg::em::ExSetSel) message.
+	///
+	/// Use `(0, -1)` to select all text, and `(-1, -1)` to place the caret at
+	/// the end.
+	pub fn set_selection(&self, start: i32, end: i32) {
+		unsafe {
+			self.hwnd()
+				.SendMessage(em::ExSetSel {
+					range: CHARRANGE { cpMin: start, cpMax: end },
+				});
+		}
+	}
+
+	/// Replaces the contents – or only the selection, if `format` has
+	/// [`SF::SFF_SELECTION`](crate::co::SF::SFF_SELECTION) – with data read
+	/// from the given source, by sending an
+	/// [`em::StreamIn`](crate::msg::em::StreamIn) message.
+	///
+	/// Plain text ([`SF::TEXT`](crate::co::SF::TEXT)) is read as UTF-8, unless
+	/// [`SF::UNICODE`](crate::co::SF::UNICODE) or
+	/// [`SF::USECODEPAGE`](crate::co::SF::USECODEPAGE) is given.
+	///
+	/// # Examples
+	///
+	/// Loading an RTF file:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co, gui};
+	///
+	/// let txt: gui::RichEdit; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	/// # let txt = gui::RichEdit::new(&wnd, gui::RichEditOpts::default());
+	///
+	/// let mut file = std::fs::File::open("C:\\Temp\\log.rtf")?;
+	/// txt.stream_in(co::SF::RTF, &mut file)?;
+	/// # std::io::Result::Ok(())
+	/// ```
+	pub fn stream_in<R>(&self, format: co::SF, source: &mut R) -> io::Result<()>
+		where R: Read,
+	{
+		let mut data = (source, None::<io::Error>);
+		let mut es = EDITSTREAM {
+			dwCookie: &mut data as *mut _ as _,
+			dwError: 0,
+			pfnCallback: Some(proc::rich_edit_stream_in::<R>),
+		};
+
+		unsafe {
+			self.hwnd()
+				.SendMessage(em::StreamIn { format: text_as_utf8(format), info: &mut es });
+		}
+		stream_result(data.1, es.dwError)
+	}
+
+	/// Writes the contents – or only the selection, if `format` has
+	/// [`SF::SFF_SELECTION`](crate::co::SF::SFF_SELECTION) – to the given
+	/// destination, by sending an [`em::StreamOut`](crate::msg::em::StreamOut)
+	/// message.
+	///
+	/// Plain text ([`SF::TEXT`](crate::co::SF::TEXT)) is written as UTF-8,
+	/// unless [`SF::UNICODE`](crate::co::SF::UNICODE) or
+	/// [`SF::USECODEPAGE`](crate::co::SF::USECODEPAGE) is given.
+	///
+	/// # Examples
+	///
+	/// Retrieving the contents as RTF:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co, gui};
+	///
+	/// let txt: gui::RichEdit; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	/// # let txt = gui::RichEdit::new(&wnd, gui::RichEditOpts::default());
+	///
+	/// let mut rtf = Vec::<u8>::new();
+	/// txt.stream_out(co::SF::RTF, &mut rtf)?;
+	/// println!("{}", String::from_utf8_lossy(&rtf));
+	/// # std::io::Result::Ok(())
+	/// ```
+	pub fn stream_out<W>(&self, format: co::SF, dest: &mut W) -> io::Result<()>
+		where W: Write,
+	{
+		let mut data = (dest, None::<io::Error>);
+		let mut es = EDITSTREAM {
+			dwCookie: &mut data as *mut _ as _,
+			dwError: 0,
+			pfnCallback: Some(proc::rich_edit_stream_out::<W>),
+		};
+
+		unsafe {
+			self.hwnd()
+				.SendMessage(em::StreamOut { format: text_as_utf8(format), info: &mut es });
+		}
+		stream_result(data.1, es.dwError)
+	}
+
+	/// Retrieves the text between the given positions by sending an
+	/// [`em::GetTextRange`](crate::msg::em::GetTextRange) message.
+	///
+	/// A negative `end` means the end of the text. Positions beyond the text
+	/// are clamped to it, and an `end` before `start` returns an empty string.
+	#[must_use]
+	pub fn text_range(&self, start: i32, end: i32) -> String {
+		// Upper bound, since WM_GETTEXTLENGTH counts paragraph breaks as CRLF.
+		let len = unsafe { self.hwnd().SendMessage(wm::GetTextLength {}) }
+			.min(i32::MAX as _) as i32;
+		let start = start.clamp(0, len);
+		let end = if end < 0 { len } else { end.clamp(start, len) };
+
+		let mut buf = WString::new_alloc_buf((end - start) as usize + 1); // room for terminating null
+		let mut tr = TEXTRANGE::default();
+		tr.chrg = CHARRANGE { cpMin: start, cpMax: end };
+		tr.set_lpstrText(Some(&mut buf));
+
+		unsafe { self.hwnd().SendMessage(em::GetTextRange { info: &mut tr }); }
+		buf.to_string()
+	}
+}
+
+/// Loads the library which registers the `RICHEDIT50W` window class; it's
+/// kept loaded until the process exits.
+fn load_msftedit() -> SysResult<()> {
+	let _ = HINSTANCE::LoadLibrary("Msftedit.dll")?.leak();
+	Ok(())
+}
+
+/// Adds the UTF-8 code page to plain text formats without an explicit
+/// encoding.
+fn text_as_utf8(format: co::SF) -> co::SF {
+	if format.has(co::SF::TEXT)
+		&& !format.has(co::SF::RTF)
+		&& !format.has(co::SF::UNICODE)
+		&& !format.has(co::SF::USECODEPAGE)
+	{
+		unsafe {
+			co::SF::from_raw(
+				((co::CP::UTF8.raw() as u32) << 16) // code page goes in the high word
+					| (format | co::SF::USECODEPAGE).raw(),
+			)
+		}
+	} else {
+		format
+	}
+}
+
+/// Returns the error stored by the stream callback, if any, or the error
+/// reported by the control.
+fn stream_result(cb_err: Option<io::Error>, dw_error: u32) -> io::Result<()> {
+	match cb_err {
+		Some(err) => Err(err),
+		None if dw_error != 0 => Err(io::Error::other(
+			format!("Rich edit stream failed with error {}.", dw_error as i32))),
+		None => Ok(()),
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Options to create a [`RichEdit`](crate::gui::RichEdit) programmatically
+/// with [`RichEdit::new`](crate::gui::RichEdit::new).
+pub struct RichEditOpts {
+	/// Text of the control to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to empty string.
+	pub text: String,
+	/// Left and top position coordinates of control within parent's client
+	/// area, to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// If the parent window is a dialog, the values are in Dialog Template
+	/// Units; otherwise in DPI-independent pixels, which will be scaled to the
+	/// DPI of the parent window.
+	///
+	/// Defaults to `(0, 0)`.
+	pub position: (i32, i32),
+	/// Control width to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// If the parent window is a dialog, the value is in Dialog Template Units;
+	/// otherwise in DPI-independent pixels, which will be scaled to the DPI of
+	/// the parent window.
+	///
+	/// Defaults to `200`.
+	pub width: u32,
+	/// Control height to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// If the parent window is a dialog, the value is in Dialog Template Units;
+	/// otherwise in DPI-independent pixels, which will be scaled to the DPI of
+	/// the parent window.
+	///
+	/// Defaults to `100`.
+	pub height: u32,
+	/// Edit styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `ES::MULTILINE | ES::WANTRETURN | ES::AUTOVSCROLL | ES::NOHIDESEL`.
+	///
+	/// Suggestions:
+	/// * add `ES::READONLY` for a log viewer;
+	/// * add `ES::SAVESEL` to keep the selection when the focus is lost.
+	pub edit_style: co::ES,
+	/// Window styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `WS::CHILD | WS::VISIBLE | WS::TABSTOP | WS::GROUP | WS::VSCROLL`.
+	pub window_style: co::WS,
+	/// Extended window styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `WS_EX::LEFT | WS_EX::CLIENTEDGE`.
+	pub window_ex_style: co::WS_EX,
+
+	/// The control ID.
+	///
+	/// Defaults to an auto-generated ID.
+	pub ctrl_id: u16,
+	/// Horizontal and vertical behavior of the control when the parent window
+	/// is resized.
+	///
+	/// Defaults to `(gui::Horz::None, gui::Vert::None)`.
+	pub resize_behavior: (Horz, Vert),
+}
+
+impl Default for RichEditOpts {
+	fn default() -> Self {
+		Self {
+			text: "".to_owned(),
+			position: (0, 0),
+			width: 200,
+			height: 100,
+			edit_style: co::ES::MULTILINE | co::ES::WANTRETURN | co::ES::AUTOVSCROLL | co::ES::NOHIDESEL,
+			window_style: co::WS::CHILD | co::WS::VISIBLE | co::WS::TABSTOP | co::WS::GROUP | co::WS::VSCROLL,
+			window_ex_style: co::WS_EX::LEFT | co::WS_EX::CLIENTEDGE,
+			ctrl_id: 0,
+			resize_behavior: (Horz::None, Vert::None),
+		}
+	}
+}
+
+impl ResizeBehavior for &RichEditOpts {
+	fn resize_behavior(&self) -> (Horz, Vert) {
+		self.resize_behavior
+	}
+}
+
+impl AutoCtrlId for RichEditOpts {
+	fn ctrl_id_mut(&mut self) -> &mut u16 {
+		&mut self.ctrl_id
+	}
+}
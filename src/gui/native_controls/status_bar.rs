@@ -15,6 +15,7 @@ struct Obj { // actual fields of StatusBar
 	events: StatusBarEvents,
 	parts_info: UnsafeCell<Vec<SbPart>>,
 	right_edges: UnsafeCell<Vec<i32>>, // buffer to speed up resize calls
+	dpi: UnsafeCell<u32>, // DPI of fixed-width parts
 	_pin: PhantomPinned,
 }
 
@@ -101,6 +102,7 @@ impl StatusBar {
 					events: StatusBarEvents::new(parent, ctrl_id),
 					parts_info: UnsafeCell::new(parts.to_vec()),
 					right_edges: UnsafeCell::new(vec![0; parts.len()]),
+					dpi: UnsafeCell::new(0),
 					_pin: PhantomPinned,
 				},
 			),
@@ -132,6 +134,7 @@ impl StatusBar {
 		}
 
 		let hparent = self.0.base.parent().hwnd();
+		*unsafe { &mut *self.0.dpi.get() } = window_dpi(hparent);
 		let parent_style = hparent.style();
 		let is_parent_resizable = parent_style.has(co::WS::MAXIMIZEBOX)
 			|| parent_style.has(co::WS::SIZEBOX);
@@ -169,6 +172,16 @@ impl StatusBar {
 		let mut cx_available = p.client_area.cx as u32;
 
 		let parts_info = unsafe { &mut *self.0.parts_info.get() };
+		let dpi = window_dpi(self.hwnd());
+		let old_dpi = std::mem::replace(unsafe { &mut *self.0.dpi.get() }, dpi);
+		if old_dpi != dpi { // window moved to a monitor with another DPI
+			for part_info in parts_info.iter_mut() {
+				if let SbPart::Fixed(pixels) = part_info {
+					*pixels = dpi::scale(*pixels as _, old_dpi, dpi) as _;
+				}
+			}
+		}
+
 		for part_info in parts_info.iter() {
 			match part_info {
 				SbPart::Fixed(pixels) => {
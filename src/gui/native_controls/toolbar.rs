@@ -0,0 +1,364 @@
+use std::any::Any;
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use crate::co;
+use crate::decl::*;
+use crate::guard::*;
+use crate::gui::{*, events::*, privs::*, spec::*};
+use crate::msg::*;
+use crate::prelude::*;
+
+struct Obj { // actual fields of Toolbar
+	base: BaseNativeControl,
+	events: ToolbarEvents,
+	_pin: PhantomPinned,
+}
+
+//------------------------------------------------------------------------------
+
+/// Native
+/// [toolbar](https://learn.microsoft.com/en-us/windows/win32/controls/toolbar-controls-overview)
+/// control, which has one or more buttons.
+///
+/// Unless [`CCS::NOPARENTALIGN`](crate::co::CCS::NOPARENTALIGN) is given, the
+/// toolbar is automatically aligned to its parent window whenever it's
+/// resized.
+#[derive(Clone)]
+pub struct Toolbar(Pin<Arc<Obj>>);
+
+unsafe impl Send for Toolbar {}
+
+impl AsRef<BaseNativeControl> for Toolbar {
+	fn as_ref(&self) -> &BaseNativeControl {
+		&self.0.base
+	}
+}
+
+impl GuiWindow for Toolbar {
+	fn hwnd(&self) -> &HWND {
+		self.0.base.hwnd()
+	}
+
+	fn as_any(&self) -> &dyn Any {
+		self
+	}
+}
+
+impl GuiChild for Toolbar {
+	fn ctrl_id(&self) -> u16 {
+		self.0.base.ctrl_id()
+	}
+}
+
+impl GuiNativeControl for Toolbar {}
+
+impl GuiNativeControlEvents<ToolbarEvents> for Toolbar {
+	fn on(&self) -> &ToolbarEvents {
+		if *self.hwnd() != HWND::NULL {
+			panic!("Cannot add events after the control creation.");
+		} else if *self.0.base.parent().hwnd() != HWND::NULL {
+			panic!("Cannot add events after the parent window creation.");
+		}
+		&self.0.events
+	}
+}
+
+impl Toolbar {
+	/// Instantiates a new `Toolbar` object, to be created on the parent window
+	/// with
+	/// [`HWND::CreateWindowEx`](crate::prelude::user_Hwnd::CreateWindowEx).
+	///
+	/// # Panics
+	///
+	/// Panics if the parent window was already created – that is, you cannot
+	/// dynamically create a `Toolbar` in an event closure.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co, gui};
+	///
+	/// const ID_OPEN: u16 = 101;
+	///
+	/// let wnd: gui::WindowMain; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	///
+	/// let toolbar = gui::Toolbar::new(&wnd, gui::ToolbarOpts::default());
+	///
+	/// toolbar.on().bn_clicked(ID_OPEN, move || {
+	///     println!("Open clicked.");
+	///     Ok(())
+	/// });
+	///
+	/// let toolbar2 = toolbar.clone();
+	/// wnd.on().wm_create(move |_| {
+	///     toolbar2.buttons().add(ID_OPEN, "&Open", w::IdxCbNone::None, co::BTNS::BUTTON);
+	///     Ok(0)
+	/// });
+	/// ```
+	#[must_use]
+	pub fn new(parent: &impl GuiParent, opts: ToolbarOpts) -> Self {
+		let opts = auto_ctrl_id_if_zero(opts);
+		let ctrl_id = opts.ctrl_id;
+
+		let new_self = Self(
+			Arc::pin(
+				Obj {
+					base: BaseNativeControl::new(parent, ctrl_id),
+					events: ToolbarEvents::new(parent, ctrl_id),
+					_pin: PhantomPinned,
+				},
+			),
+		);
+
+		let self2 = new_self.clone();
+		parent.as_ref().before_user_on().wm_create_or_initdialog(move |_, _| {
+			self2.create(&opts)?;
+			Ok(WmRet::NotHandled)
+		});
+
+		let self2 = new_self.clone();
+		parent.as_ref().before_user_on().wm_size(move |p| {
+			if p.request != co::SIZE_R::MINIMIZED && *self2.hwnd() != HWND::NULL {
+				self2.auto_size();
+			}
+			Ok(())
+		});
+
+		let self2 = new_self.clone();
+		parent.as_ref().after_user_on().wm_destroy(move || {
+			unsafe {
+				[
+					self2.hwnd().SendMessage(tbm::GetImageList {}),
+					self2.hwnd().SendMessage(tbm::GetHotImageList {}),
+					self2.hwnd().SendMessage(tbm::GetDisabledImageList {}),
+				]
+			}.into_iter()
+				.flatten()
+				.for_each(|hil| { // destroy each image list, if any
+					let _ = unsafe { ImageListDestroyGuard::new(hil) };
+				});
+			Ok(())
+		});
+
+		new_self
+	}
+
+	fn create(&self, opts: &ToolbarOpts) -> SysResult<()> {
+		let mut pos = POINT::new(opts.position.0, opts.position.1);
+		let mut sz = SIZE::new(opts.size.0 as _, opts.size.1 as _);
+		multiply_dpi_or_dtu(self.0.base.parent(), Some(&mut pos), Some(&mut sz))?;
+
+		self.0.base.create_window( // may panic
+			"ToolbarWindow32", None, pos, sz,
+			opts.window_ex_style,
+			opts.window_style | opts.toolbar_style.into() | opts.common_style.into(),
+		)?;
+
+		unsafe {
+			self.hwnd().SendMessage(tbm::ButtonStructSize {
+				size: std::mem::size_of::<TBBUTTON>() as _,
+			});
+			self.hwnd().SendMessage(tbm::SetExtendedStyle {
+				style: opts.toolbar_ex_style,
+			});
+			self.hwnd().SendMessage(wm::SetFont {
+				hfont: ui_font_for(self.hwnd())?,
+				redraw: true,
+			});
+		}
+
+		self.0.base.parent().add_to_layout_arranger(self.hwnd(), opts.resize_behavior)
+	}
+
+	/// Resizes the toolbar to fit its buttons and its parent window, by sending
+	/// a [`tbm::AutoSize`](crate::msg::tbm::AutoSize) message.
+	///
+	/// This is automatically called when the parent window is resized, and
+	/// when buttons are added.
+	pub fn auto_size(&self) {
+		unsafe { self.hwnd().SendMessage(tbm::AutoSize {}); }
+	}
+
+	/// Exposes the button methods.
+	#[must_use]
+	pub const fn buttons(&self) -> ToolbarButtons<'_> {
+		ToolbarButtons::new(self)
+	}
+
+	/// Retrieves a reference to the image list of the normal buttons by sending
+	/// a [`tbm::GetImageList`](crate::msg::tbm::GetImageList) message.
+	///
+	/// The image list is owned by the control.
+	#[must_use]
+	pub fn image_list(&self) -> Option<&HIMAGELIST> {
+		unsafe {
+			self.hwnd()
+				.SendMessage(tbm::GetImageList {})
+		}.map(|hil| {
+			let hil_ptr = &hil as *const HIMAGELIST;
+			unsafe { &*hil_ptr }
+		})
+	}
+
+	/// Sets the image list of the disabled buttons by sending a
+	/// [`tbm::SetDisabledImageList`](crate::msg::tbm::SetDisabledImageList)
+	/// message.
+	///
+	/// The image list will be owned by the control. Returns the previous one,
+	/// if any.
+	pub fn set_disabled_image_list(&self,
+		himagelist: ImageListDestroyGuard,
+	) -> Option<ImageListDestroyGuard>
+	{
+		let mut himagelist = himagelist;
+		let hil = himagelist.leak();
+
+		unsafe {
+			self.hwnd()
+				.SendMessage(tbm::SetDisabledImageList { himagelist: &hil })
+				.map(|prev_hil| ImageListDestroyGuard::new(prev_hil))
+		}
+	}
+
+	/// Sets the image list of the hot buttons – the ones under the mouse
+	/// pointer – by sending a
+	/// [`tbm::SetHotImageList`](crate::msg::tbm::SetHotImageList) message.
+	///
+	/// The image list will be owned by the control. Returns the previous one,
+	/// if any.
+	pub fn set_hot_image_list(&self,
+		himagelist: ImageListDestroyGuard,
+	) -> Option<ImageListDestroyGuard>
+	{
+		let mut himagelist = himagelist;
+		let hil = himagelist.leak();
+
+		unsafe {
+			self.hwnd()
+				.SendMessage(tbm::SetHotImageList { himagelist: &hil })
+				.map(|prev_hil| ImageListDestroyGuard::new(prev_hil))
+		}
+	}
+
+	/// Sets the image list of the normal buttons by sending a
+	/// [`tbm::SetImageList`](crate::msg::tbm::SetImageList) message.
+	///
+	/// The image list will be owned by the control. Returns the previous one,
+	/// if any.
+	pub fn set_image_list(&self,
+		himagelist: ImageListDestroyGuard,
+	) -> Option<ImageListDestroyGuard>
+	{
+		let mut himagelist = himagelist;
+		let hil = himagelist.leak();
+
+		let prev = unsafe {
+			self.hwnd()
+				.SendMessage(tbm::SetImageList { himagelist: hil })
+				.map(|prev_hil| ImageListDestroyGuard::new(prev_hil))
+		};
+		self.auto_size(); // button sizes depend on the image size
+		prev
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Options to create a [`Toolbar`](crate::gui::Toolbar) programmatically with
+/// [`Toolbar::new`](crate::gui::Toolbar::new).
+pub struct ToolbarOpts {
+	/// Left and top position coordinates of control within parent's client
+	/// area, to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// If the parent window is a dialog, the values are in Dialog Template
+	/// Units; otherwise in DPI-independent pixels, which will be scaled to the
+	/// DPI of the parent window.
+	///
+	/// Only meaningful with [`CCS::NOPARENTALIGN`](crate::co::CCS::NOPARENTALIGN),
+	/// otherwise the toolbar is aligned to the parent window.
+	///
+	/// Defaults to `(0, 0)`.
+	pub position: (i32, i32),
+	/// Width and height of control to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// If the parent window is a dialog, the values are in Dialog Template
+	/// Units; otherwise in DPI-independent pixels, which will be scaled to the
+	/// DPI of the parent window.
+	///
+	/// Only meaningful with [`CCS::NORESIZE`](crate::co::CCS::NORESIZE),
+	/// otherwise the toolbar sizes itself to fit its buttons.
+	///
+	/// Defaults to `(0, 0)`.
+	pub size: (u32, u32),
+	/// Toolbar styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `TBSTYLE::TOOLTIPS | TBSTYLE::FLAT | TBSTYLE::LIST`.
+	pub toolbar_style: co::TBSTYLE,
+	/// Extended toolbar styles to be set with
+	/// [`tbm::SetExtendedStyle`](crate::msg::tbm::SetExtendedStyle).
+	///
+	/// Defaults to `TBSTYLE_EX::DRAWDDARROWS`.
+	pub toolbar_ex_style: co::TBSTYLE_EX,
+	/// Common control styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `CCS::TOP`.
+	pub common_style: co::CCS,
+	/// Window styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `WS::CHILD | WS::VISIBLE`.
+	pub window_style: co::WS,
+	/// Extended window styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `WS_EX::NoValue`.
+	pub window_ex_style: co::WS_EX,
+
+	/// The control ID.
+	///
+	/// Defaults to an auto-generated ID.
+	pub ctrl_id: u16,
+	/// Horizontal and vertical behavior of the control when the parent window
+	/// is resized.
+	///
+	/// Only meaningful with [`CCS::NOPARENTALIGN`](crate::co::CCS::NOPARENTALIGN),
+	/// otherwise the toolbar is aligned to the parent window.
+	///
+	/// Defaults to `(gui::Horz::None, gui::Vert::None)`.
+	pub resize_behavior: (Horz, Vert),
+}
+
+impl Default for ToolbarOpts {
+	fn default() -> Self {
+		Self {
+			position: (0, 0),
+			size: (0, 0),
+			toolbar_style: co::TBSTYLE::TOOLTIPS | co::TBSTYLE::FLAT | co::TBSTYLE::LIST,
+			toolbar_ex_style: co::TBSTYLE_EX::DRAWDDARROWS,
+			common_style: co::CCS::TOP,
+			window_style: co::WS::CHILD | co::WS::VISIBLE,
+			window_ex_style: co::WS_EX::NoValue,
+			ctrl_id: 0,
+			resize_behavior: (Horz::None, Vert::None),
+		}
+	}
+}
+
+impl ResizeBehavior for &ToolbarOpts {
+	fn resize_behavior(&self) -> (Horz, Vert) {
+		self.resize_behavior
+	}
+}
+
+impl AutoCtrlId for ToolbarOpts {
+	fn ctrl_id_mut(&mut self) -> &mut u16 {
+		&mut self.ctrl_id
+	}
+}
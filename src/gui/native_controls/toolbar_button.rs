@@ -0,0 +1,252 @@
+use crate::co;
+use crate::decl::*;
+use crate::gui::*;
+use crate::msg::*;
+use crate::prelude::*;
+
+/// A single button of a [`Toolbar`](crate::gui::Toolbar) control.
+///
+/// **Note:** Each object keeps the zero-based index of a button. If new buttons
+/// are added/removed from the toolbar control, the object may then point to a
+/// different button.
+///
+/// You cannot directly instantiate this object, it is created internally by the
+/// control.
+#[derive(Clone, Copy)]
+pub struct ToolbarButton<'a> {
+	owner: &'a Toolbar,
+	index: u32,
+}
+
+impl<'a> ToolbarButton<'a> {
+	#[must_use]
+	pub(in crate::gui) const fn new(owner: &'a Toolbar, index: u32) -> Self {
+		Self { owner, index }
+	}
+
+	/// Sets or clears the checked state of the button by sending a
+	/// [`tbm::CheckButton`](crate::msg::tbm::CheckButton) message.
+	///
+	/// Only meaningful for buttons with [`BTNS::CHECK`](crate::co::BTNS::CHECK).
+	pub fn check(&self, check: bool) {
+		unsafe {
+			self.owner.hwnd()
+				.SendMessage(tbm::CheckButton { btn_cmd_id: self.cmd_id(), check })
+		}.unwrap();
+	}
+
+	/// Retrieves the command ID of the button by sending a
+	/// [`tbm::GetButton`](crate::msg::tbm::GetButton) message.
+	#[must_use]
+	pub fn cmd_id(&self) -> u16 {
+		let mut tbb = TBBUTTON::default();
+
+		unsafe {
+			self.owner.hwnd()
+				.SendMessage(tbm::GetButton {
+					btn_index: self.index,
+					info: &mut tbb,
+				})
+		}.unwrap();
+
+		tbb.idCommand as _
+	}
+
+	/// Deletes the button by sending a
+	/// [`tbm::DeleteButton`](crate::msg::tbm::DeleteButton) message.
+	pub fn delete(&self) {
+		unsafe {
+			self.owner.hwnd()
+				.SendMessage(tbm::DeleteButton { btn_index: self.index })
+		}.unwrap();
+	}
+
+	/// Retrieves the bounding rectangle of the dropdown arrow, relative to the
+	/// toolbar, by sending a
+	/// [`tbm::GetItemDropdownRect`](crate::msg::tbm::GetItemDropdownRect)
+	/// message.
+	///
+	/// Only meaningful for buttons with
+	/// [`BTNS::DROPDOWN`](crate::co::BTNS::DROPDOWN).
+	#[must_use]
+	pub fn dropdown_rect(&self) -> RECT {
+		let mut rc = RECT::default();
+
+		unsafe {
+			self.owner.hwnd()
+				.SendMessage(tbm::GetItemDropdownRect {
+					item_index: self.index,
+					rect: &mut rc,
+				});
+		}
+
+		rc
+	}
+
+	/// Enables or disables the button by sending a
+	/// [`tbm::EnableButton`](crate::msg::tbm::EnableButton) message.
+	pub fn enable(&self, enable: bool) {
+		unsafe {
+			self.owner.hwnd()
+				.SendMessage(tbm::EnableButton { btn_cmd_id: self.cmd_id(), enable })
+		}.unwrap();
+	}
+
+	/// Shows or hides the button by sending a
+	/// [`tbm::HideButton`](crate::msg::tbm::HideButton) message.
+	pub fn hide(&self, hide: bool) {
+		unsafe {
+			self.owner.hwnd()
+				.SendMessage(tbm::HideButton { cmd_id: self.cmd_id(), hide })
+		}.unwrap();
+	}
+
+	/// Returns the zero-based index of the button.
+	#[must_use]
+	pub const fn index(&self) -> u32 {
+		self.index
+	}
+
+	/// Tells whether the button is checked by sending a
+	/// [`tbm::IsButtonChecked`](crate::msg::tbm::IsButtonChecked) message.
+	#[must_use]
+	pub fn is_checked(&self) -> bool {
+		unsafe {
+			self.owner.hwnd()
+				.SendMessage(tbm::IsButtonChecked { cmd_id: self.cmd_id() })
+		}
+	}
+
+	/// Tells whether the button is enabled by sending a
+	/// [`tbm::IsButtonEnabled`](crate::msg::tbm::IsButtonEnabled) message.
+	#[must_use]
+	pub fn is_enabled(&self) -> bool {
+		unsafe {
+			self.owner.hwnd()
+				.SendMessage(tbm::IsButtonEnabled { cmd_id: self.cmd_id() })
+		}
+	}
+
+	/// Tells whether the button is hidden by sending a
+	/// [`tbm::IsButtonHidden`](crate::msg::tbm::IsButtonHidden) message.
+	#[must_use]
+	pub fn is_hidden(&self) -> bool {
+		unsafe {
+			self.owner.hwnd()
+				.SendMessage(tbm::IsButtonHidden { cmd_id: self.cmd_id() })
+		}
+	}
+
+	/// Retrieves the user-defined value by sending a
+	/// [`tbm::GetButton`](crate::msg::tbm::GetButton) message.
+	#[must_use]
+	pub fn lparam(&self) -> usize {
+		let mut tbb = TBBUTTON::default();
+
+		unsafe {
+			self.owner.hwnd()
+				.SendMessage(tbm::GetButton {
+					btn_index: self.index,
+					info: &mut tbb,
+				})
+		}.unwrap();
+
+		tbb.dwData
+	}
+
+	/// Retrieves the bounding rectangle of the button, relative to the toolbar,
+	/// by sending a [`tbm::GetItemRect`](crate::msg::tbm::GetItemRect) message.
+	#[must_use]
+	pub fn rect(&self) -> RECT {
+		let mut rc = RECT::default();
+
+		unsafe {
+			self.owner.hwnd()
+				.SendMessage(tbm::GetItemRect {
+					btn_index: self.index,
+					rect: &mut rc,
+				})
+		}.unwrap();
+
+		rc
+	}
+
+	/// Sets the image of the button by sending a
+	/// [`tbm::SetButtonInfo`](crate::msg::tbm::SetButtonInfo) message.
+	pub fn set_image(&self, image: IdxCbNone) {
+		let mut tbbi = TBBUTTONINFO::default();
+		tbbi.dwMask = co::TBIF::BYINDEX | co::TBIF::IMAGE;
+		tbbi.iImage = isize::from(image) as _;
+
+		unsafe {
+			self.owner.hwnd()
+				.SendMessage(tbm::SetButtonInfo {
+					btn_cmd_id: self.index as _,
+					info: &tbbi,
+				})
+		}.unwrap();
+	}
+
+	/// Sets or clears the indeterminate state of the button by sending a
+	/// [`tbm::Indeterminate`](crate::msg::tbm::Indeterminate) message.
+	pub fn set_indeterminate(&self, indeterminate: bool) {
+		unsafe {
+			self.owner.hwnd()
+				.SendMessage(tbm::Indeterminate { cmd_id: self.cmd_id(), indeterminate })
+		}.unwrap();
+	}
+
+	/// Sets the user-defined value by sending a
+	/// [`tbm::SetButtonInfo`](crate::msg::tbm::SetButtonInfo) message.
+	pub fn set_lparam(&self, lparam: usize) {
+		let mut tbbi = TBBUTTONINFO::default();
+		tbbi.dwMask = co::TBIF::BYINDEX | co::TBIF::LPARAM;
+		tbbi.lParam = lparam;
+
+		unsafe {
+			self.owner.hwnd()
+				.SendMessage(tbm::SetButtonInfo {
+					btn_cmd_id: self.index as _,
+					info: &tbbi,
+				})
+		}.unwrap();
+	}
+
+	/// Sets the text of the button by sending a
+	/// [`tbm::SetButtonInfo`](crate::msg::tbm::SetButtonInfo) message.
+	pub fn set_text(&self, text: &str) {
+		let mut wtext = WString::from_str(text);
+		let mut tbbi = TBBUTTONINFO::default();
+		tbbi.dwMask = co::TBIF::BYINDEX | co::TBIF::TEXT;
+		tbbi.set_pszText(Some(&mut wtext));
+
+		unsafe {
+			self.owner.hwnd()
+				.SendMessage(tbm::SetButtonInfo {
+					btn_cmd_id: self.index as _,
+					info: &tbbi,
+				})
+		}.unwrap();
+		self.owner.auto_size();
+	}
+
+	/// Retrieves the text of the button by sending a
+	/// [`tbm::GetButtonInfo`](crate::msg::tbm::GetButtonInfo) message.
+	#[must_use]
+	pub fn text(&self) -> String {
+		let mut buf = WString::new_alloc_buf(128); // arbitrary
+		let mut tbbi = TBBUTTONINFO::default();
+		tbbi.dwMask = co::TBIF::BYINDEX | co::TBIF::TEXT;
+		tbbi.set_pszText(Some(&mut buf));
+
+		unsafe {
+			self.owner.hwnd()
+				.SendMessage(tbm::GetButtonInfo {
+					btn_cmd_id: self.index as _,
+					info: &mut tbbi,
+				})
+		}.unwrap();
+
+		buf.to_string()
+	}
+}
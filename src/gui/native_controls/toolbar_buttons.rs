@@ -0,0 +1,133 @@
+use crate::co;
+use crate::decl::*;
+use crate::gui::{*, iterators::*, spec::*};
+use crate::msg::*;
+use crate::prelude::*;
+
+/// Exposes the button methods of a [`Toolbar`](crate::gui::Toolbar) control.
+///
+/// You cannot directly instantiate this object, it is created internally by the
+/// control.
+pub struct ToolbarButtons<'a> {
+	owner: &'a Toolbar,
+}
+
+impl<'a> ToolbarButtons<'a> {
+	#[must_use]
+	pub(in crate::gui) const fn new(owner: &'a Toolbar) -> Self {
+		Self { owner }
+	}
+
+	/// Adds a new button by sending a
+	/// [`tbm::AddButtons`](crate::msg::tbm::AddButtons) message, returning the
+	/// new button.
+	///
+	/// When clicked, the button sends a `WM_COMMAND` with `cmd_id`, which can
+	/// be handled with [`bn_clicked`](crate::gui::events::ToolbarEvents::bn_clicked).
+	///
+	/// The `image` is the zero-based index of the image in the image list set
+	/// with [`Toolbar::set_image_list`](crate::gui::Toolbar::set_image_list);
+	/// with [`IdxCbNone::Cb`](crate::IdxCbNone::Cb), it will be requested with
+	/// [`tbn_get_disp_info`](crate::gui::events::ToolbarEvents::tbn_get_disp_info).
+	///
+	/// Use [`BTNS::DROPDOWN`](crate::co::BTNS::DROPDOWN) or
+	/// [`BTNS::WHOLEDROPDOWN`](crate::co::BTNS::WHOLEDROPDOWN) to create a
+	/// dropdown button, whose arrow click can be handled with
+	/// [`tbn_drop_down`](crate::gui::events::ToolbarEvents::tbn_drop_down).
+	pub fn add(&self,
+		cmd_id: u16,
+		text: &str,
+		image: IdxCbNone,
+		style: co::BTNS,
+	) -> ToolbarButton<'a>
+	{
+		self.insert(self.count(), cmd_id, text, image, style)
+	}
+
+	/// Adds a new separator by sending a
+	/// [`tbm::AddButtons`](crate::msg::tbm::AddButtons) message.
+	pub fn add_separator(&self) {
+		let mut tbb = TBBUTTON::default();
+		tbb.fsStyle = co::BTNS::SEP;
+
+		unsafe {
+			self.owner.hwnd()
+				.SendMessage(tbm::AddButtons { buttons: &mut [tbb] })
+		}.unwrap();
+		self.owner.auto_size();
+	}
+
+	/// Retrieves the button with the given command ID by sending a
+	/// [`tbm::CommandToIndex`](crate::msg::tbm::CommandToIndex) message.
+	#[must_use]
+	pub fn by_cmd_id(&self, cmd_id: u16) -> Option<ToolbarButton<'a>> {
+		unsafe {
+			self.owner.hwnd()
+				.SendMessage(tbm::CommandToIndex { btn_cmd_id: cmd_id })
+		}.map(|index| self.get(index))
+	}
+
+	/// Retrieves the total number of buttons, including the separators, by
+	/// sending a [`tbm::ButtonCount`](crate::msg::tbm::ButtonCount) message.
+	#[must_use]
+	pub fn count(&self) -> u32 {
+		unsafe {
+			self.owner.hwnd()
+				.SendMessage(tbm::ButtonCount {})
+		}
+	}
+
+	/// Deletes all buttons by sending
+	/// [`tbm::DeleteButton`](crate::msg::tbm::DeleteButton) messages.
+	pub fn delete_all(&self) {
+		for _ in 0..self.count() {
+			self.get(0).delete();
+		}
+	}
+
+	/// Retrieves the button at the given zero-based position.
+	///
+	/// **Note:** This method is cheap – even if `index` is beyond the range of
+	/// existing buttons, an object will still be returned. However, operations
+	/// upon this object will fail.
+	#[must_use]
+	pub const fn get(&self, index: u32) -> ToolbarButton<'a> {
+		ToolbarButton::new(self.owner, index)
+	}
+
+	/// Inserts a new button at the given zero-based position by sending a
+	/// [`tbm::InsertButton`](crate::msg::tbm::InsertButton) message, returning
+	/// the new button.
+	///
+	/// See [`add`](crate::gui::spec::ToolbarButtons::add) for the parameters.
+	pub fn insert(&self,
+		index: u32,
+		cmd_id: u16,
+		text: &str,
+		image: IdxCbNone,
+		style: co::BTNS,
+	) -> ToolbarButton<'a>
+	{
+		let mut wtext = IdxStr::Str(WString::from_str(text));
+
+		let mut tbb = TBBUTTON::default();
+		tbb.iBitmap = isize::from(image) as _;
+		tbb.idCommand = cmd_id as _;
+		tbb.fsState = co::TBSTATE::ENABLED;
+		tbb.fsStyle = style;
+		tbb.set_iString(&mut wtext);
+
+		unsafe {
+			self.owner.hwnd()
+				.SendMessage(tbm::InsertButton { index, button: &tbb })
+		}.unwrap();
+		self.owner.auto_size();
+
+		self.get(index)
+	}
+
+	/// Returns an iterator over all buttons, including the separators.
+	pub fn iter(&self) -> impl Iterator<Item = ToolbarButton<'a>> + 'a {
+		ToolbarButtonIter::new(self.owner)
+	}
+}
@@ -0,0 +1,411 @@
+use std::any::Any;
+use std::cell::UnsafeCell;
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use crate::co;
+use crate::decl::*;
+use crate::gui::{*, privs::*};
+use crate::msg::*;
+use crate::prelude::*;
+
+/// Maximum width of the tooltip shared by the controls of a window, in
+/// DPI-independent pixels.
+pub(in crate::gui) const SHARED_TOOLTIP_WIDTH: i32 = 400;
+
+/// ID of the tool used by the tracking tooltip; never a valid window handle.
+const TRACKING_TOOL_ID: usize = 0;
+
+/// Dynamic text closures, keyed by the handle of the tool window.
+type TextFuncs = Vec<(HWND, Box<dyn Fn() -> AnyResult<String>>)>;
+
+struct Obj { // actual fields of Tooltip
+	base: BaseNativeControl,
+	text_funcs: UnsafeCell<TextFuncs>,
+	disp_buf: UnsafeCell<WString>, // must outlive the TTN_GETDISPINFO processing
+	_pin: PhantomPinned,
+}
+
+//------------------------------------------------------------------------------
+
+/// Native
+/// [tooltip](https://learn.microsoft.com/en-us/windows/win32/controls/tooltip-controls)
+/// control, which can display tips for any number of controls.
+///
+/// For a simple tip on a single control, consider using
+/// [`GuiNativeControl::set_tooltip`](crate::prelude::GuiNativeControl::set_tooltip),
+/// which uses a tooltip shared by all controls of the parent window.
+#[derive(Clone)]
+pub struct Tooltip(Pin<Arc<Obj>>);
+
+unsafe impl Send for Tooltip {}
+
+impl AsRef<BaseNativeControl> for Tooltip {
+	fn as_ref(&self) -> &BaseNativeControl {
+		&self.0.base
+	}
+}
+
+impl GuiWindow for Tooltip {
+	fn hwnd(&self) -> &HWND {
+		self.0.base.hwnd()
+	}
+
+	fn as_any(&self) -> &dyn Any {
+		self
+	}
+}
+
+impl GuiChild for Tooltip {
+	fn ctrl_id(&self) -> u16 {
+		self.0.base.ctrl_id()
+	}
+}
+
+impl GuiNativeControl for Tooltip {}
+
+impl Tooltip {
+	/// Instantiates a new `Tooltip` object, to be created as a popup owned by
+	/// the parent window with
+	/// [`HWND::CreateWindowEx`](crate::prelude::user_Hwnd::CreateWindowEx).
+	///
+	/// # Panics
+	///
+	/// Panics if the parent window was already created – that is, you cannot
+	/// dynamically create a `Tooltip` in an event closure.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co, gui};
+	///
+	/// let wnd: gui::WindowMain; // initialized somewhere
+	/// let txt_name: gui::Edit;
+	/// let txt_age: gui::Edit;
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	/// # let txt_name = gui::Edit::new(&wnd, gui::EditOpts::default());
+	/// # let txt_age = gui::Edit::new(&wnd, gui::EditOpts::default());
+	///
+	/// let tooltip = gui::Tooltip::new(
+	///     &wnd,
+	///     gui::TooltipOpts {
+	///         tooltip_style: co::TTS::ALWAYSTIP | co::TTS::BALLOON,
+	///         ..Default::default()
+	///     },
+	/// );
+	///
+	/// wnd.on().wm_create({
+	///     let (tooltip, txt_name, txt_age) = (tooltip.clone(), txt_name.clone(), txt_age.clone());
+	///     move |_| {
+	///         tooltip.add_tool(&txt_name, "Your full name,\nas in your passport.");
+	///         tooltip.add_tool_dynamic(&txt_age, {
+	///             let txt_age = txt_age.clone();
+	///             move || Ok(format!("{} characters typed.", txt_age.text().len()))
+	///         });
+	///         Ok(0)
+	///     }
+	/// });
+	/// ```
+	#[must_use]
+	pub fn new(parent: &impl GuiParent, opts: TooltipOpts) -> Self {
+		let ctrl_id = next_auto_ctrl_id();
+
+		let new_self = Self(
+			Arc::pin(
+				Obj {
+					base: BaseNativeControl::new(parent, ctrl_id),
+					text_funcs: UnsafeCell::new(Vec::new()),
+					disp_buf: UnsafeCell::new(WString::new()),
+					_pin: PhantomPinned,
+				},
+			),
+		);
+
+		let self2 = new_self.clone();
+		parent.as_ref().before_user_on().wm_create_or_initdialog(move |_, _| {
+			self2.create(&opts)?;
+			Ok(WmRet::NotHandled)
+		});
+
+		let self2 = new_self.clone();
+		parent.as_ref().before_user_on().wm(co::WM::NOTIFY, move |p| {
+			Ok(if self2.get_disp_info(unsafe { wm::Notify::from_generic_wm(p) })? {
+				WmRet::HandledOk
+			} else {
+				WmRet::NotHandled // let other handlers and the default processing run
+			})
+		});
+
+		let self2 = new_self.clone();
+		parent.as_ref().after_user_on().wm_destroy(move || {
+			unsafe { &mut *self2.0.text_funcs.get() }.clear(); // prevents circular references
+			Ok(())
+		});
+
+		new_self
+	}
+
+	fn create(&self, opts: &TooltipOpts) -> SysResult<()> {
+		self.0.base.create_popup( // may panic
+			"tooltips_class32",
+			opts.window_ex_style,
+			opts.window_style | opts.tooltip_style.into(),
+		)?;
+
+		if opts.max_width != 0 {
+			unsafe {
+				self.hwnd().SendMessage(ttm::SetMaxTipWidth {
+					width: dpi::to_pixels(opts.max_width as _, window_dpi(self.hwnd())),
+				});
+			}
+		}
+		Ok(())
+	}
+
+	/// Returns whether the notification was a `TTN_GETDISPINFO` sent by this
+	/// tooltip, thus handled.
+	fn get_disp_info(&self, p: wm::Notify) -> AnyResult<bool> {
+		if p.nmhdr.hwndFrom != *self.hwnd()
+			|| p.nmhdr.code != co::TTN::GETDISPINFO.into()
+		{
+			return Ok(false); // not for us
+		}
+
+		let di = unsafe { p.cast_nmhdr_mut::<NMTTDISPINFO>() };
+		let text_funcs = unsafe { &*self.0.text_funcs.get() };
+		if let Some((_, func)) = text_funcs.iter()
+			.find(|(hctrl, _)| hctrl.ptr() as isize == di.lParam)
+		{
+			let disp_buf = unsafe { &mut *self.0.disp_buf.get() };
+			*disp_buf = WString::from_str(&func()?);
+			di.set_lpszText(Some(disp_buf));
+		}
+		Ok(true)
+	}
+
+	/// Activates or deactivates the tooltip by sending a
+	/// [`ttm::Activate`](crate::msg::ttm::Activate) message. A deactivated
+	/// tooltip doesn't show any tips.
+	pub fn activate(&self, activate: bool) {
+		unsafe { self.hwnd().SendMessage(ttm::Activate { activate }); }
+	}
+
+	/// Adds a tip with a fixed text to the given control, or replaces its text,
+	/// by sending [`ttm::AddTool`](crate::msg::ttm::AddTool) or
+	/// [`ttm::UpdateTipText`](crate::msg::ttm::UpdateTipText) messages.
+	///
+	/// Line breaks are honored only if
+	/// [`max_width`](crate::gui::TooltipOpts::max_width) is set.
+	pub fn add_tool(&self, ctrl: &impl GuiWindow, text: &str) {
+		self.remove_text_func(ctrl.hwnd());
+		set_tool_text(self.hwnd(), ctrl.hwnd(), text).unwrap();
+	}
+
+	/// Adds a tip to the given control whose text is retrieved from the given
+	/// closure each time the tip is about to be shown, which happens in the
+	/// [`TTN_GETDISPINFO`](https://learn.microsoft.com/en-us/windows/win32/controls/ttn-getdispinfo)
+	/// notification.
+	///
+	/// If the control already has a tip, it's replaced.
+	pub fn add_tool_dynamic<F>(&self, ctrl: &impl GuiWindow, func: F)
+		where F: Fn() -> AnyResult<String> + 'static,
+	{
+		let _ = remove_tool(self.hwnd(), ctrl.hwnd()); // the text source may have changed
+		self.remove_text_func(ctrl.hwnd());
+
+		let mut ti = tool_info(ctrl.hwnd()).unwrap();
+		ti.lParam = ctrl.hwnd().ptr() as _; // will identify the closure
+		ti.set_lpszText_callback();
+
+		unsafe {
+			self.hwnd()
+				.SendMessage(ttm::AddTool { info: &ti })
+		}.unwrap();
+
+		unsafe { &mut *self.0.text_funcs.get() }.push(
+			(unsafe { ctrl.hwnd().raw_copy() }, Box::new(func)),
+		);
+	}
+
+	/// Hides the tracking tip shown with
+	/// [`show_tracking`](crate::gui::Tooltip::show_tracking), by sending a
+	/// [`ttm::TrackActivate`](crate::msg::ttm::TrackActivate) message.
+	pub fn hide_tracking(&self) {
+		let ti = self.tracking_tool_info();
+		unsafe {
+			self.hwnd()
+				.SendMessage(ttm::TrackActivate { activate: false, info: &ti });
+		}
+	}
+
+	/// Removes the tip of the given control, if any, by sending a
+	/// [`ttm::DelTool`](crate::msg::ttm::DelTool) message.
+	pub fn remove_tool(&self, ctrl: &impl GuiWindow) {
+		self.remove_text_func(ctrl.hwnd());
+		remove_tool(self.hwnd(), ctrl.hwnd()).unwrap();
+	}
+
+	fn remove_text_func(&self, hctrl: &HWND) {
+		unsafe { &mut *self.0.text_funcs.get() }
+			.retain(|(h, _)| h != hctrl);
+	}
+
+	/// Sets the time, in milliseconds, of the given delay by sending a
+	/// [`ttm::SetDelayTime`](crate::msg::ttm::SetDelayTime) message. If
+	/// `time_ms` is `None`, the default delay is restored.
+	pub fn set_delay_time(&self, which: co::TTDT, time_ms: Option<u16>) {
+		unsafe {
+			self.hwnd()
+				.SendMessage(ttm::SetDelayTime { which, time_ms });
+		}
+	}
+
+	/// Sets the title and the icon displayed above the tip text by sending a
+	/// [`ttm::SetTitle`](crate::msg::ttm::SetTitle) message. An empty title
+	/// removes both.
+	pub fn set_title(&self, icon: co::TTI, title: &str) {
+		unsafe {
+			self.hwnd()
+				.SendMessage(ttm::SetTitle { icon, title: WString::from_str(title) })
+		}.unwrap();
+	}
+
+	/// Shows a tip with the given text at the given position, relative to the
+	/// client area of the parent window, until
+	/// [`hide_tracking`](crate::gui::Tooltip::hide_tracking) is called.
+	///
+	/// This is useful to point at something the mouse is not hovering, like a
+	/// validation error. Each call moves the tip and replaces its text.
+	pub fn show_tracking(&self, text: &str, pos: POINT) {
+		let mut wtext = WString::from_str(text);
+		let mut ti = self.tracking_tool_info();
+		ti.set_lpszText(Some(&mut wtext));
+
+		unsafe {
+			let hwnd = self.hwnd();
+			if hwnd.SendMessage(ttm::GetToolInfo { info: &mut self.tracking_tool_info() }).is_err() {
+				hwnd.SendMessage(ttm::AddTool { info: &ti }).unwrap(); // first call
+			} else {
+				hwnd.SendMessage(ttm::UpdateTipText { info: &ti });
+			}
+
+			let pos = self.0.base.parent().hwnd().ClientToScreen(pos).unwrap();
+			hwnd.SendMessage(ttm::TrackPosition { pos });
+			hwnd.SendMessage(ttm::TrackActivate { activate: true, info: &ti });
+		}
+	}
+
+	#[must_use]
+	fn tracking_tool_info(&self) -> TTTOOLINFO<'_> {
+		let mut ti = TTTOOLINFO::default();
+		ti.uFlags = co::TTF::TRACK | co::TTF::ABSOLUTE;
+		ti.hwnd = unsafe { self.0.base.parent().hwnd().raw_copy() };
+		ti.uId = TRACKING_TOOL_ID;
+		ti
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Creates a tooltip window owned by the given window.
+pub(in crate::gui) fn create_tooltip_window(
+	hparent: &HWND,
+	styles: co::WS,
+	ex_styles: co::WS_EX,
+) -> SysResult<HWND>
+{
+	unsafe {
+		HWND::CreateWindowEx(
+			ex_styles,
+			AtomStr::from_str("tooltips_class32"),
+			None, styles,
+			POINT::default(), SIZE::default(),
+			Some(hparent),
+			IdMenu::None,
+			&hparent.hinstance(),
+			None,
+		)
+	}
+}
+
+/// Returns the identification of the tool which covers the whole control.
+fn tool_info(hctrl: &HWND) -> SysResult<TTTOOLINFO<'static>> {
+	let mut ti = TTTOOLINFO::default();
+	ti.uFlags = co::TTF::IDISHWND | co::TTF::SUBCLASS;
+	ti.hwnd = hctrl.GetParent()?;
+	ti.uId = hctrl.ptr() as _;
+	Ok(ti)
+}
+
+/// Adds a tool with the given text for the control, or replaces the text of the
+/// existing one.
+pub(in crate::gui) fn set_tool_text(
+	htooltip: &HWND,
+	hctrl: &HWND,
+	text: &str,
+) -> SysResult<()>
+{
+	let mut wtext = WString::from_str(text);
+	let mut ti = tool_info(hctrl)?;
+	ti.set_lpszText(Some(&mut wtext));
+
+	unsafe {
+		if htooltip.SendMessage(ttm::GetToolInfo { info: &mut tool_info(hctrl)? }).is_err() {
+			htooltip.SendMessage(ttm::AddTool { info: &ti })
+		} else {
+			htooltip.SendMessage(ttm::UpdateTipText { info: &ti });
+			Ok(())
+		}
+	}
+}
+
+/// Removes the tool of the control, if any.
+pub(in crate::gui) fn remove_tool(htooltip: &HWND, hctrl: &HWND) -> SysResult<()> {
+	let ti = tool_info(hctrl)?;
+	unsafe { htooltip.SendMessage(ttm::DelTool { info: &ti }); }
+	Ok(())
+}
+
+//------------------------------------------------------------------------------
+
+/// Options to create a [`Tooltip`](crate::gui::Tooltip) programmatically with
+/// [`Tooltip::new`](crate::gui::Tooltip::new).
+pub struct TooltipOpts {
+	/// Tooltip styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Use [`TTS::BALLOON`](crate::co::TTS::BALLOON) for balloon tips.
+	///
+	/// Defaults to `TTS::ALWAYSTIP | TTS::NOPREFIX`.
+	pub tooltip_style: co::TTS,
+	/// Window styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `WS::POPUP`.
+	pub window_style: co::WS,
+	/// Extended window styles to be
+	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	///
+	/// Defaults to `WS_EX::TOPMOST`.
+	pub window_ex_style: co::WS_EX,
+	/// Maximum width of the tips, in DPI-independent pixels, which will be
+	/// scaled to the DPI of the parent window. Longer texts are wrapped, and
+	/// line breaks are honored.
+	///
+	/// If zero, the tips are displayed in a single line.
+	///
+	/// Defaults to `400`.
+	pub max_width: u32,
+}
+
+impl Default for TooltipOpts {
+	fn default() -> Self {
+		Self {
+			tooltip_style: co::TTS::ALWAYSTIP | co::TTS::NOPREFIX,
+			window_style: co::WS::POPUP,
+			window_ex_style: co::WS_EX::TOPMOST,
+			max_width: SHARED_TOOLTIP_WIDTH as _,
+		}
+	}
+}
@@ -240,8 +240,8 @@ pub struct UpDownOpts {
 	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
 	///
 	/// If the parent window is a dialog, the values are in Dialog Template
-	/// Units; otherwise in pixels, which will be multiplied to match current
-	/// system DPI.
+	/// Units; otherwise in DPI-independent pixels, which will be scaled to the
+	/// DPI of the parent window.
 	///
 	/// Note that the `UDS::AUTOBUDDY` style automatically positions the
 	/// `UpDown`; thus, with this style, `position` is meaningless.
@@ -252,8 +252,8 @@ pub struct UpDownOpts {
 	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
 	///
 	/// If the parent window is a dialog, the value is in Dialog Template Units;
-	/// otherwise in pixels, which will be multiplied to match current system
-	/// DPI.
+	/// otherwise in DPI-independent pixels, which will be scaled to the DPI of
+	/// the parent window.
 	///
 	/// Note that the `UDS::AUTOBUDDY` style automatically resizes the `UpDown`;
 	/// thus, with this style, `height` is meaningless.
@@ -26,38 +26,46 @@ pub(in crate::gui) fn post_quit_error(
 
 //------------------------------------------------------------------------------
 
-/// Global UI font object.
-static mut UI_HFONT: Option<DeleteObjectGuard<HFONT>> = None;
+/// Global UI font objects, one for each DPI in use.
+static mut UI_HFONTS: Vec<(u32, DeleteObjectGuard<HFONT>)> = Vec::new();
 
-/// Creates the global UI font object.
+/// Creates the global UI font object for the system DPI.
 pub(in crate::gui) fn create_ui_font() -> SysResult<()> {
+	ui_font_for_dpi(GetDpiForSystem()).map(|_| ())
+}
+
+/// Frees all the global UI font objects.
+pub(in crate::gui) fn delete_ui_font() {
+	unsafe { (*std::ptr::addr_of_mut!(UI_HFONTS)).clear(); } // https://users.rust-lang.org/t/why-drop-trait-not-called-when-use-global-static
+}
+
+/// Retrieves the global UI font object for the given DPI, creating it if not
+/// created yet.
+pub(in crate::gui) fn ui_font_for_dpi(dpi: u32) -> SysResult<HFONT> {
+	let hfonts = unsafe { &mut *std::ptr::addr_of_mut!(UI_HFONTS) };
+	if let Some((_, hfont)) = hfonts.iter().find(|(font_dpi, _)| *font_dpi == dpi) {
+		return Ok(unsafe { hfont.raw_copy() });
+	}
+
 	let mut ncm = NONCLIENTMETRICS::default();
-	unsafe {
-		SystemParametersInfo(
+	let hfont = unsafe {
+		SystemParametersInfoForDpi(
 			co::SPI::GETNONCLIENTMETRICS,
 			std::mem::size_of::<NONCLIENTMETRICS>() as _,
 			&mut ncm,
 			co::SPIF::NoValue,
+			dpi,
 		)?;
-		UI_HFONT = Some(HFONT::CreateFontIndirect(&ncm.lfMenuFont)?);
-	}
-	Ok(())
+		HFONT::CreateFontIndirect(&ncm.lfMenuFont)?
+	};
+	let hfont_ret = unsafe { hfont.raw_copy() };
+	hfonts.push((dpi, hfont));
+	Ok(hfont_ret)
 }
 
-/// Frees the global UI font object.
-pub(in crate::gui) fn delete_ui_font() {
-	unsafe { UI_HFONT = None; } // https://users.rust-lang.org/t/why-drop-trait-not-called-when-use-global-static
-}
-
-/// Retrieves the global UI font object, or panics if not created yet.
-#[must_use]
-pub(in crate::gui) fn ui_font() -> HFONT {
-	unsafe {
-		match &*std::ptr::addr_of!(UI_HFONT) {
-			Some(hfont) => hfont.raw_copy(),
-			None => panic!("Global UI font not created."),
-		}
-	}
+/// Retrieves the global UI font object for the DPI of the given window.
+pub(in crate::gui) fn ui_font_for(hwnd: &HWND) -> SysResult<HFONT> {
+	ui_font_for_dpi(window_dpi(hwnd))
 }
 
 //------------------------------------------------------------------------------
@@ -91,35 +99,39 @@ pub(in crate::gui) fn auto_ctrl_id_if_zero<T: AutoCtrlId>(mut obj: T) -> T {
 
 //------------------------------------------------------------------------------
 
-static mut DPI: POINT = POINT::new(0, 0);
+/// Returns the DPI of the given window, or the system DPI if the window is
+/// null or not DPI aware.
+#[must_use]
+pub(in crate::gui) fn window_dpi(hwnd: &HWND) -> u32 {
+	if *hwnd == HWND::NULL {
+		return GetDpiForSystem();
+	}
+	match hwnd.GetDpiForWindow() {
+		0 => GetDpiForSystem(), // invalid window
+		dpi => dpi,
+	}
+}
 
-/// Multiplies the given coordinates by current system DPI.
+/// Converts the given DPI-independent coordinates to pixels, in the DPI of the
+/// given window; if the window is null, in the system DPI.
 pub(in crate::gui) fn multiply_dpi(
+	hwnd: &HWND,
 	pt: Option<&mut POINT>,
 	sz: Option<&mut SIZE>,
 ) -> SysResult<()>
 {
-	unsafe {
-		if (pt.is_some() || sz.is_some()) && DPI.x == 0 { // DPI not cached yet?
-			let screen_dc = HWND::NULL.GetDC()?;
-			DPI.x = screen_dc.GetDeviceCaps(co::GDC::LOGPIXELSX); // cache
-			DPI.y = screen_dc.GetDeviceCaps(co::GDC::LOGPIXELSY);
-		}
-
-		if let Some(pt) = pt {
-			pt.x = MulDiv(pt.x, DPI.x, 96);
-			pt.y = MulDiv(pt.y, DPI.y, 96);
-		}
-		if let Some(sz) = sz {
-			sz.cx = MulDiv(sz.cx, DPI.x, 96);
-			sz.cy = MulDiv(sz.cy, DPI.y, 96);
-		}
+	let dpi = window_dpi(hwnd);
+	if let Some(pt) = pt {
+		*pt = dpi::to_pixels_point(*pt, dpi);
+	}
+	if let Some(sz) = sz {
+		*sz = dpi::to_pixels_size(*sz, dpi);
 	}
 	Ok(())
 }
 
 /// If parent is a dialog, converts Dialog Template Units to pixels; otherwise
-/// multiplies by current DPI factor.
+/// converts DPI-independent units to pixels in the DPI of the parent.
 pub(in crate::gui) fn multiply_dpi_or_dtu(
 	parent_base: &Base,
 	pt: Option<&mut POINT>,
@@ -149,7 +161,7 @@ pub(in crate::gui) fn multiply_dpi_or_dtu(
 		});
 
 	} else {
-		multiply_dpi(pt, sz)?;
+		multiply_dpi(parent_base.hwnd(), pt, sz)?;
 	}
 
 	Ok(())
@@ -157,13 +169,14 @@ pub(in crate::gui) fn multiply_dpi_or_dtu(
 
 //------------------------------------------------------------------------------
 
-/// Calculates the bound rectangle to fit the text with current system font.
+/// Calculates the bound rectangle to fit the text with the UI font of the
+/// given DPI.
 #[must_use]
-pub(in crate::gui) fn calc_text_bound_box(text: &str) -> SysResult<SIZE> {
+pub(in crate::gui) fn calc_text_bound_box(text: &str, dpi: u32) -> SysResult<SIZE> {
 	let desktop_hwnd = HWND::GetDesktopWindow();
 	let desktop_hdc = desktop_hwnd.GetDC()?;
 	let clone_dc = desktop_hdc.CreateCompatibleDC()?;
-	let _prev_font = clone_dc.SelectObject(&ui_font())?;
+	let _prev_font = clone_dc.SelectObject(&ui_font_for_dpi(dpi)?)?;
 
 	let mut bounds = if text.is_empty() {
 		clone_dc.GetTextExtentPoint32("Pj")? // just a placeholder to get the text height
@@ -177,15 +190,15 @@ pub(in crate::gui) fn calc_text_bound_box(text: &str) -> SysResult<SIZE> {
 	Ok(bounds)
 }
 
-/// Calculates the bound rectangle to fit the text with current system font,
-/// adding a check box.
+/// Calculates the bound rectangle to fit the text with the UI font of the
+/// given DPI, adding a check box.
 #[must_use]
-pub(in crate::gui) fn calc_text_bound_box_check(text: &str) -> SysResult<SIZE> {
-	let mut bound_box = calc_text_bound_box(text)?;
-	bound_box.cx += GetSystemMetrics(co::SM::CXMENUCHECK) // https://stackoverflow.com/a/1165052/6923555
-		+ GetSystemMetrics(co::SM::CXEDGE);
+pub(in crate::gui) fn calc_text_bound_box_check(text: &str, dpi: u32) -> SysResult<SIZE> {
+	let mut bound_box = calc_text_bound_box(text, dpi)?;
+	bound_box.cx += GetSystemMetricsForDpi(co::SM::CXMENUCHECK, dpi)? // https://stackoverflow.com/a/1165052/6923555
+		+ GetSystemMetricsForDpi(co::SM::CXEDGE, dpi)?;
 
-	let cy_check = GetSystemMetrics(co::SM::CYMENUCHECK);
+	let cy_check = GetSystemMetricsForDpi(co::SM::CYMENUCHECK, dpi)?;
 	if cy_check > bound_box.cy {
 		bound_box.cy = cy_check; // if the check is taller than the font, use its height
 	}
@@ -1,4 +1,5 @@
 use std::cmp::Ordering;
+use std::io::{self, Read, Write};
 
 use crate::gui::{*, spec::*};
 
@@ -14,3 +15,50 @@ pub(in crate::gui) extern "system" fn list_view_item_sort<F>(
 	let item2 = data.0.items().get(lparam2 as _);
 	data.1(item1, item2) as _
 }
+
+pub(in crate::gui) extern "system" fn rich_edit_stream_in<R>(
+	cookie: usize,
+	buf: *mut u8,
+	cb: i32,
+	pcb: *mut i32,
+) -> u32
+	where R: Read,
+{
+	let data = unsafe { &mut *(cookie as *mut (&mut R, Option<io::Error>)) };
+	let buf = unsafe { std::slice::from_raw_parts_mut(buf, cb as _) };
+	loop {
+		match data.0.read(buf) {
+			Ok(n) => {
+				unsafe { *pcb = n as _; } // zero means end of stream
+				return 0;
+			},
+			Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+			Err(e) => {
+				data.1 = Some(e); // will be returned to the caller
+				return 1;
+			},
+		}
+	}
+}
+
+pub(in crate::gui) extern "system" fn rich_edit_stream_out<W>(
+	cookie: usize,
+	buf: *mut u8,
+	cb: i32,
+	pcb: *mut i32,
+) -> u32
+	where W: Write,
+{
+	let data = unsafe { &mut *(cookie as *mut (&mut W, Option<io::Error>)) };
+	let buf = unsafe { std::slice::from_raw_parts(buf, cb as _) };
+	match data.0.write_all(buf) {
+		Ok(_) => {
+			unsafe { *pcb = cb; }
+			0
+		},
+		Err(e) => {
+			data.1 = Some(e); // will be returned to the caller
+			1
+		},
+	}
+}
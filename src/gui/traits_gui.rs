@@ -312,6 +312,37 @@ pub trait GuiNativeControl: GuiChild + AsRef<BaseNativeControl> {
 	fn on_subclass(&self) -> &WindowEvents {
 		self.as_ref().on_subclass()
 	}
+
+	/// Sets the text of a tooltip displayed when the mouse hovers the control.
+	/// Line breaks are honored, and an empty text removes the tooltip.
+	///
+	/// All controls of the parent window share a single tooltip. For balloon
+	/// tips, tracking tips or texts computed on demand, use a
+	/// [`Tooltip`](crate::gui::Tooltip) control.
+	///
+	/// # Panics
+	///
+	/// Panics if the control is not created yet.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, gui};
+	///
+	/// let wnd: gui::WindowMain; // initialized somewhere
+	/// let btn: gui::Button;
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	/// # let btn = gui::Button::new(&wnd, gui::ButtonOpts::default());
+	///
+	/// let btn2 = btn.clone();
+	/// wnd.on().wm_create(move |_| {
+	///     btn2.set_tooltip("Saves the file.\nExisting contents are replaced.");
+	///     Ok(0)
+	/// });
+	/// ```
+	fn set_tooltip(&self, text: &str) {
+		self.as_ref().set_tooltip(text).unwrap();
+	}
 }
 
 /// Events of a native control.
@@ -1,3 +1,4 @@
+use std::cell::UnsafeCell;
 use std::ptr::NonNull;
 
 use crate::co;
@@ -22,6 +23,7 @@ pub(in crate::gui) struct Base {
 	user_events: WindowEvents, // ordinary window events, inserted by user: only last added is executed (overwrite previous)
 	after_user_events: WindowEvents, // all will be executed after user events
 	layout_arranger: LayoutArranger,
+	htooltip: UnsafeCell<HWND>, // shared by the child controls, created on demand
 }
 
 impl AsRef<Base> for Base {
@@ -47,6 +49,7 @@ impl Base {
 			user_events: WindowEvents::new(is_dialog),
 			after_user_events: WindowEvents::new(is_dialog),
 			layout_arranger: LayoutArranger::new(),
+			htooltip: UnsafeCell::new(HWND::NULL),
 		};
 		new_self.default_message_handlers();
 		new_self
@@ -58,7 +61,8 @@ impl Base {
 	}
 
 	pub(in crate::gui) fn set_hwnd(&mut self, hwnd: HWND) {
-		self.hwnd = hwnd
+		self.hwnd = hwnd;
+		*self.htooltip.get_mut() = HWND::NULL; // owned by the previous window, if any, so already destroyed
 	}
 
 	#[must_use]
@@ -154,6 +158,23 @@ impl Base {
 		self.layout_arranger.add_child(&self.hwnd, hchild, resize_behavior)
 	}
 
+	/// Returns the tooltip shared by the child controls, creating it on the
+	/// first call.
+	pub(in crate::gui) fn shared_tooltip(&self) -> SysResult<&HWND> {
+		let htooltip = unsafe { &mut *self.htooltip.get() };
+		if *htooltip == HWND::NULL {
+			*htooltip = create_tooltip_window(&self.hwnd,
+				co::WS::POPUP | co::TTS::ALWAYSTIP.into() | co::TTS::NOPREFIX.into(),
+				co::WS_EX::TOPMOST)?;
+			unsafe {
+				htooltip.SendMessage(ttm::SetMaxTipWidth { // allows multiline texts
+					width: dpi::to_pixels(SHARED_TOOLTIP_WIDTH, window_dpi(&self.hwnd)),
+				});
+			}
+		}
+		Ok(htooltip)
+	}
+
 	pub(in crate::gui) fn spawn_new_thread<F>(&self, func: F)
 		where F: FnOnce() -> AnyResult<()> + Send + 'static,
 	{
@@ -217,6 +238,20 @@ impl Base {
 			Ok(())
 		});
 
+		if !self.is_dialog { // dialogs are rescaled by the dialog manager
+			let layout_arranger = self.layout_arranger.clone();
+			self.before_user_events.wm_dpi_changed(move |p| { // top-level windows
+				layout_arranger.change_dpi(p.dpi_x as _, Some(p.suggested_rect))?;
+				Ok(())
+			});
+
+			let layout_arranger = self.layout_arranger.clone();
+			self.before_user_events.wm(co::WM::DPICHANGED_AFTERPARENT, move |_| { // child windows
+				layout_arranger.change_to_current_dpi()?;
+				Ok(WmRet::HandledOk)
+			});
+		}
+
 		self.before_user_events.wm(Self::WM_UI_THREAD, |p| {
 			if unsafe { co::WM::from_raw(p.wparam as _) } == Self::WM_UI_THREAD { // additional safety check
 				let ptr_pack = p.lparam as *mut ThreadPack; // retrieve pointer
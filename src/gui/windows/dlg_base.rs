@@ -1,15 +1,31 @@
 use crate::co;
 use crate::decl::*;
 use crate::gui::{*, privs::*};
+use crate::kernel::privs::*;
 use crate::msg::*;
 use crate::prelude::*;
 
+/// Where the dialog is loaded from.
+pub(in crate::gui) enum DlgSource {
+	/// A dialog resource ID.
+	Id(u16),
+	/// A `DLGTEMPLATEEX` block, stored as `u32` to keep it properly aligned.
+	Template(Vec<u32>),
+}
+
+impl DlgSource {
+	/// Serializes the template into an aligned memory block.
+	pub(in crate::gui) fn from_template(template: &DialogTemplate) -> SysResult<Self> {
+		Ok(Self::Template(u32_aligned(&template.serialize()?)))
+	}
+}
+
 /// Base to all dialog windows.
 ///
 /// Owns the window procedure for all dialog windows.
 pub(in crate::gui) struct DlgBase {
 	base: Base,
-	dialog_id: u16,
+	source: DlgSource,
 }
 
 impl Drop for DlgBase {
@@ -24,12 +40,12 @@ impl DlgBase {
 	#[must_use]
 	pub(in crate::gui) fn new(
 		parent: Option<&impl AsRef<Base>>,
-		dialog_id: u16,
+		source: DlgSource,
 	) -> Self
 	{
 		Self {
 			base: Base::new(true, parent),
-			dialog_id,
+			source,
 		}
 	}
 
@@ -45,15 +61,25 @@ impl DlgBase {
 
 		// Our hwnd member is set during WM_INITDIALOG processing; already set
 		// when CreateDialogParam returns.
+		// Pass pointer to Self.
+		// At this moment, the parent struct is already created and pinned.
+		let hinst = self.base.parent_hinstance()?;
+		let hparent = self.base.parent().map(|parent| parent.hwnd());
 		unsafe {
-			self.base.parent_hinstance()?.CreateDialogParam(
-				IdStr::Id(self.dialog_id),
-				self.base.parent().map(|parent| parent.hwnd()),
-				Self::dialog_proc,
-				// Pass pointer to Self.
-				// At this moment, the parent struct is already created and pinned.
-				Some(self as *const _ as _),
-			)?;
+			match &self.source {
+				DlgSource::Id(dialog_id) => hinst.CreateDialogParam(
+					IdStr::Id(*dialog_id),
+					hparent,
+					Self::dialog_proc,
+					Some(self as *const _ as _),
+				)?,
+				DlgSource::Template(buf) => hinst.CreateDialogIndirectParam(
+					&*(buf.as_ptr() as *const DLGTEMPLATE),
+					hparent,
+					Self::dialog_proc,
+					Some(self as *const _ as _),
+				)?,
+			};
 		}
 
 		Ok(())
@@ -66,15 +92,25 @@ impl DlgBase {
 
 		// Our hwnd member is set during WM_INITDIALOG processing; already set
 		// when DialogBoxParam returns.
+		// Pass pointer to Self.
+		// At this moment, the parent struct is already created and pinned.
+		let hinst = self.base.parent_hinstance()?;
+		let hparent = self.base.parent().map(|parent| parent.hwnd());
 		let ret = unsafe {
-			self.base.parent_hinstance()?.DialogBoxParam(
-				IdStr::Id(self.dialog_id),
-				self.base.parent().map(|parent| parent.hwnd()),
-				Self::dialog_proc,
-				// Pass pointer to Self.
-				// At this moment, the parent struct is already created and pinned.
-				Some(self as *const _ as _),
-			)?
+			match &self.source {
+				DlgSource::Id(dialog_id) => hinst.DialogBoxParam(
+					IdStr::Id(*dialog_id),
+					hparent,
+					Self::dialog_proc,
+					Some(self as *const _ as _),
+				)?,
+				DlgSource::Template(buf) => hinst.DialogBoxIndirectParam(
+					&*(buf.as_ptr() as *const DLGTEMPLATE),
+					hparent,
+					Self::dialog_proc,
+					Some(self as *const _ as _),
+				)?,
+			}
 		};
 
 		Ok(ret as _)
@@ -118,16 +154,17 @@ impl DlgBase {
 		if wm_any.msg_id == co::WM::INITDIALOG {
 			// Child controls are created in before-user closures, so we set the
 			// system font only after all them.
+			let hfont = ui_font_for(ref_self.base.hwnd())?;
 			unsafe {
 				ref_self.base.hwnd().SendMessage(wm::SetFont { // on the window itself
-					hfont: ui_font(),
+					hfont: hfont.raw_copy(),
 					redraw: false,
 				});
 			}
 			ref_self.base.hwnd().EnumChildWindows(|hchild| {
 				unsafe {
 					hchild.SendMessage(wm::SetFont { // on each child control
-						hfont: ui_font(),
+						hfont: hfont.raw_copy(),
 						redraw: false,
 					});
 				}
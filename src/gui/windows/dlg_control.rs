@@ -33,7 +33,7 @@ impl DlgControl {
 		let new_self = Self(
 			Arc::pin(
 				Obj {
-					dlg_base: DlgBase::new(Some(&parent), dialog_id),
+					dlg_base: DlgBase::new(Some(&parent), DlgSource::Id(dialog_id)),
 					position,
 					ctrl_id: ctrl_id.unwrap_or_else(|| next_auto_ctrl_id()),
 					_pin: PhantomPinned,
@@ -30,7 +30,7 @@ impl DlgModeless {
 		let new_self = Self(
 			Arc::pin(
 				Obj {
-					dlg_base: DlgBase::new(Some(parent), dialog_id),
+					dlg_base: DlgBase::new(Some(parent), DlgSource::Id(dialog_id)),
 					position,
 					_pin: PhantomPinned,
 				},
@@ -5,7 +5,7 @@ use std::sync::Arc;
 
 use crate::co;
 use crate::decl::*;
-use crate::gui::*;
+use crate::gui::{*, privs::*};
 use crate::msg::*;
 use crate::prelude::*;
 
@@ -19,6 +19,8 @@ struct ChildInfo {
 struct Obj { // actual fields of LayoutArranger
 	ctrls: UnsafeCell<Vec<ChildInfo>>,
 	sz_parent_orig: UnsafeCell<Option<SIZE>>, // original parent client area, filled at WM_CREATE/INITDIALOG
+	hparent: UnsafeCell<HWND>, // filled at WM_CREATE/INITDIALOG
+	dpi: UnsafeCell<u32>, // current DPI of parent, filled at WM_CREATE/INITDIALOG
 	_pin: PhantomPinned,
 }
 
@@ -36,6 +38,8 @@ impl LayoutArranger {
 				Obj {
 					ctrls: UnsafeCell::new(Vec::new()),
 					sz_parent_orig: UnsafeCell::new(None),
+					hparent: UnsafeCell::new(HWND::NULL),
+					dpi: UnsafeCell::new(0),
 					_pin: PhantomPinned,
 				},
 			),
@@ -69,7 +73,7 @@ impl LayoutArranger {
 		Ok(())
 	}
 
-	/// Saves the original client area of the parent window.
+	/// Saves the original client area and the DPI of the parent window.
 	pub(in crate::gui) fn save_original_client_area(&self,
 		hparent: &HWND,
 	) -> SysResult<()>
@@ -78,6 +82,104 @@ impl LayoutArranger {
 		*unsafe { &mut *self.0.sz_parent_orig.get() } = Some(
 			SIZE::new(rc_parent.right, rc_parent.bottom),
 		);
+		*unsafe { &mut *self.0.hparent.get() } = unsafe { hparent.raw_copy() };
+		*unsafe { &mut *self.0.dpi.get() } = window_dpi(hparent);
+		Ok(())
+	}
+
+	/// Rescales the child controls, their UI fonts and the stored coordinates
+	/// to the new DPI of the parent window. Then the parent window is moved to
+	/// the suggested rectangle, which rearranges the controls; without a
+	/// suggested rectangle, the controls are rearranged in place.
+	pub(in crate::gui) fn change_dpi(&self,
+		new_dpi: u32,
+		suggested_rc: Option<&RECT>,
+	) -> SysResult<()>
+	{
+		let hparent = unsafe { &*self.0.hparent.get() };
+		let old_dpi = std::mem::replace(unsafe { &mut *self.0.dpi.get() }, new_dpi);
+		if *hparent == HWND::NULL || old_dpi == new_dpi {
+			return Ok(());
+		}
+
+		if let Some(sz) = unsafe { &mut *self.0.sz_parent_orig.get() } {
+			*sz = dpi::scale_size(*sz, old_dpi, new_dpi);
+		}
+		for ctrl in unsafe { &mut *self.0.ctrls.get() }.iter_mut() {
+			if let Some(rc) = ctrl.rc_orig.as_mut() {
+				*rc = dpi::scale_rect(*rc, old_dpi, new_dpi);
+			}
+		}
+		Self::rescale_children(hparent, old_dpi, new_dpi)?;
+
+		match suggested_rc {
+			Some(rc) => hparent.SetWindowPos( // will trigger WM_SIZE
+				HwndPlace::None,
+				POINT::new(rc.left, rc.top),
+				SIZE::new(rc.right - rc.left, rc.bottom - rc.top),
+				co::SWP::NOZORDER | co::SWP::NOACTIVATE,
+			),
+			None => {
+				let rc_parent = hparent.GetClientRect()?;
+				self.rearrange(wm::Size {
+					request: co::SIZE_R::RESTORED,
+					client_area: SIZE::new(rc_parent.right, rc_parent.bottom),
+				})
+			},
+		}
+	}
+
+	/// Calls [`change_dpi`](Self::change_dpi) with the current DPI of the
+	/// parent window, which has been moved and resized by its own parent.
+	pub(in crate::gui) fn change_to_current_dpi(&self) -> SysResult<()> {
+		let hparent = unsafe { &*self.0.hparent.get() };
+		self.change_dpi(window_dpi(hparent), None)
+	}
+
+	/// Rescales the position and size of the direct children of the parent
+	/// window, and replaces their UI font, if they're using it.
+	fn rescale_children(
+		hparent: &HWND,
+		old_dpi: u32,
+		new_dpi: u32,
+	) -> SysResult<()>
+	{
+		let mut children = Vec::<(HWND, RECT)>::new();
+		let mut hchild = hparent.GetWindow(co::GW::CHILD).ok();
+		while let Some(h) = hchild {
+			let rc = hparent.ScreenToClientRc(h.GetWindowRect()?)?;
+			hchild = h.GetWindow(co::GW::HWNDNEXT).ok();
+			children.push((h, dpi::scale_rect(rc, old_dpi, new_dpi)));
+		}
+		if children.is_empty() {
+			return Ok(());
+		}
+
+		{
+			let mut hdwp = HDWP::BeginDeferWindowPos(children.len() as _)?;
+			for (h, rc) in children.iter() {
+				hdwp.DeferWindowPos(
+					h,
+					HwndPlace::None,
+					POINT::new(rc.left, rc.top),
+					SIZE::new(rc.right - rc.left, rc.bottom - rc.top),
+					co::SWP::NOZORDER | co::SWP::NOACTIVATE,
+				)?;
+			}
+		} // EndDeferWindowPos
+
+		let old_hfont = ui_font_for_dpi(old_dpi)?;
+		let new_hfont = ui_font_for_dpi(new_dpi)?;
+		for (h, _) in children.iter() {
+			unsafe {
+				if h.SendMessage(wm::GetFont {}).as_ref() == Some(&old_hfont) {
+					h.SendMessage(wm::SetFont {
+						hfont: new_hfont.raw_copy(),
+						redraw: true,
+					});
+				}
+			}
+		}
 		Ok(())
 	}
 
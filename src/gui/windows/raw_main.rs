@@ -62,7 +62,7 @@ impl RawMain {
 		let atom = self.0.raw_base.register_class(&mut wcx)?;
 
 		let mut wnd_sz = SIZE::new(opts.size.0 as _, opts.size.1 as _);
-		multiply_dpi(None, Some(&mut wnd_sz))?;
+		multiply_dpi(&HWND::NULL, None, Some(&mut wnd_sz))?;
 
 		let screen_sz = SIZE::new(
 			GetSystemMetrics(co::SM::CXSCREEN),
@@ -171,11 +171,11 @@ pub struct WindowMainOpts {
 	///
 	/// Defaults to empty string.
 	pub title: String,
-	/// Width and height of window client area, in pixels, to be
-	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	/// Width and height of window client area, in DPI-independent pixels,
+	/// to be [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
 	/// Does not include title bar or borders.
 	///
-	/// Will be adjusted to match current system DPI.
+	/// Will be scaled to the system DPI.
 	///
 	/// Defaults to `(600, 500)`.
 	pub size: (u32, u32),
@@ -67,7 +67,7 @@ impl RawModal {
 		hparent.EnableWindow(false); // https://devblogs.microsoft.com/oldnewthing/20040227-00/?p=40463
 
 		let mut wnd_sz = SIZE::new(opts.size.0 as _, opts.size.1 as _);
-		multiply_dpi(None, Some(&mut wnd_sz))?;
+		multiply_dpi(hparent, None, Some(&mut wnd_sz))?;
 
 		let mut wnd_rc = RECT { // client area, will be adjusted to size with title bar and borders
 			left: 0,
@@ -196,11 +196,11 @@ pub struct WindowModalOpts {
 	///
 	/// Defaults to empty string.
 	pub title: String,
-	/// Width and height of window client area, in pixels, to be
-	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	/// Width and height of window client area, in DPI-independent pixels,
+	/// to be [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
 	/// Does not include title bar or borders.
 	///
-	/// Will be adjusted to match current system DPI.
+	/// Will be scaled to the DPI of the parent window.
 	///
 	/// Defaults to `(500, 400)`.
 	pub size: (u32, u32),
@@ -122,16 +122,16 @@ pub struct WindowModelessOpts {
 	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
 	///
 	/// If the parent window is a dialog, the values are in Dialog Template
-	/// Units; otherwise in pixels, which will be multiplied to match current
-	/// system DPI.
+	/// Units; otherwise in DPI-independent pixels, which will be scaled to the
+	/// DPI of the parent window.
 	///
 	/// Defaults to `(0, 0)`.
 	pub position: (i32, i32),
-	/// Width and height of window client area, in pixels, to be
-	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
+	/// Width and height of window client area, in DPI-independent pixels,
+	/// to be [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
 	/// Does not include title bar or borders.
 	///
-	/// Will be adjusted to match current system DPI.
+	/// Will be scaled to the DPI of the parent window.
 	///
 	/// Defaults to `(220, 150)`.
 	pub size: (u32, u32),
@@ -73,8 +73,8 @@ impl WindowControl {
 	/// [`HINSTANCE::CreateDialogParam`](crate::prelude::user_Hinstance::CreateDialogParam).
 	///
 	/// If the parent window is a dialog, position is in Dialog Template Units;
-	/// otherwise in pixels, which will be multiplied to match current system
-	/// DPI.
+	/// otherwise in DPI-independent pixels, which will be scaled to the DPI of
+	/// the parent window.
 	///
 	/// # Panics
 	///
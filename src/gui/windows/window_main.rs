@@ -69,22 +69,58 @@ impl WindowMain {
 	{
 		Self(
 			RawDlg::Dlg(
-				DlgMain::new(dialog_id, icon_id, accel_table_id),
+				DlgMain::new(DlgSource::Id(dialog_id), icon_id, accel_table_id),
 			),
 		)
 	}
 
+	/// Instantiates a new `WindowMain` object, to be created from an in-memory
+	/// dialog template with
+	/// [`HINSTANCE::CreateDialogIndirectParam`](crate::prelude::user_Hinstance::CreateDialogIndirectParam).
+	///
+	/// The window behaves exactly like one created with
+	/// [`WindowMain::new_dlg`](crate::gui::WindowMain::new_dlg).
+	///
+	/// Fails if the template cannot be
+	/// [serialized](crate::DialogTemplate::serialize).
+	pub fn new_dlg_template(
+		template: &DialogTemplate,
+		icon_id: Option<u16>,
+		accel_table_id: Option<u16>,
+	) -> SysResult<Self>
+	{
+		Ok(Self(
+			RawDlg::Dlg(
+				DlgMain::new(DlgSource::from_template(template)?, icon_id, accel_table_id),
+			),
+		))
+	}
+
 	/// Physically creates the window, then runs the main application loop. This
 	/// method will block until the window is closed.
 	///
 	/// The `cmd_show` parameter defaults to
 	/// [`co::SW::SHOW`](crate::co::SW::SHOW).
 	///
+	/// If the process DPI awareness was not already set, the process is made
+	/// [per-monitor v2](https://learn.microsoft.com/en-us/windows/win32/hi-dpi/dpi-awareness-context#dpi_awareness_context_per_monitor_aware_v2)
+	/// DPI aware, so the windows are rescaled when moved to a monitor with
+	/// another DPI; see [`gui::dpi`](crate::gui::dpi) for details. Before
+	/// Windows 10 version 1703, the process is made system DPI aware.
+	///
 	/// # Panics
 	///
 	/// Panics if the window is already created.
 	pub fn run_main(&self, cmd_show: Option<co::SW>) -> AnyResult<i32> {
-		if IsWindowsVistaOrGreater()? {
+		if IsWindows10OrGreater()? {
+			match SetProcessDpiAwarenessContext(
+				co::DPI_AWARENESS_CONTEXT::PER_MONITOR_AWARE_V2,
+			) {
+				Err(e) if e == co::ERROR::ACCESS_DENIED => {}, // already set, possibly by the manifest
+				Err(_) => SetProcessDPIAware()?, // before Windows 10 version 1703
+				_ => {},
+			}
+		} else if IsWindowsVistaOrGreater()? {
 			SetProcessDPIAware()?;
 		}
 
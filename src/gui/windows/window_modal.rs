@@ -61,11 +61,32 @@ impl WindowModal {
 	pub fn new_dlg(parent: &impl GuiParent, dialog_id: u16) -> Self {
 		Self(
 			RawDlg::Dlg(
-				DlgModal::new(parent, dialog_id),
+				DlgModal::new(parent, DlgSource::Id(dialog_id)),
 			),
 		)
 	}
 
+	/// Instantiates a new `WindowModal` object, to be created from an in-memory
+	/// dialog template with
+	/// [`HINSTANCE::DialogBoxIndirectParam`](crate::prelude::user_Hinstance::DialogBoxIndirectParam).
+	///
+	/// The window behaves exactly like one created with
+	/// [`WindowModal::new_dlg`](crate::gui::WindowModal::new_dlg).
+	///
+	/// Fails if the template cannot be
+	/// [serialized](crate::DialogTemplate::serialize).
+	pub fn new_dlg_template(
+		parent: &impl GuiParent,
+		template: &DialogTemplate,
+	) -> SysResult<Self>
+	{
+		Ok(Self(
+			RawDlg::Dlg(
+				DlgModal::new(parent, DlgSource::from_template(template)?),
+			),
+		))
+	}
+
 	/// Physically creates the window, then runs the modal loop. This method
 	/// will block until the window is closed.
 	///
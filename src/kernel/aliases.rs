@@ -1,4 +1,5 @@
 use crate::co;
+use crate::decl::*;
 
 /// A [`Result` alias](crate#errors-and-result-aliases) which returns a `Box<dyn
 /// Error + Send + Sync>` on failure.
@@ -7,6 +8,14 @@ use crate::co;
 /// other `Result` can be converted into it.
 pub type AnyResult<T> = Result<T, Box<dyn std::error::Error + Send + Sync>>;
 
+/// A [`Result` alias](crate#errors-and-result-aliases) which returns a
+/// [`ContextError`](crate::ContextError) on failure, carrying the name of the
+/// function which failed, its arguments and a cause chain.
+///
+/// Usually created with the [`ResultContext`](crate::ResultContext)
+/// methods.
+pub type CtxResult<T> = Result<T, ContextError>;
+
 /// A [`Result` alias](crate#errors-and-result-aliases) for native system error
 /// codes, which returns an [`ERROR`](crate::co::ERROR) on failure.
 ///
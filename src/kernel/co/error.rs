@@ -60,6 +60,63 @@ impl std::fmt::Debug for ERROR {
 
 impl FormattedError for ERROR {}
 
+#[cfg(feature = "error-catalog")]
+impl ERROR {
+	/// Returns the name of the constant, like `"FILE_NOT_FOUND"`, taken from
+	/// an embedded catalog, without system calls.
+	#[must_use]
+	pub fn name(self) -> Option<&'static str> {
+		self.catalog_entry().map(|(_, name, _)| *name)
+	}
+
+	/// Returns the English message of the error, taken from an embedded
+	/// catalog, without system calls.
+	///
+	/// Unlike [`FormatMessage`](crate::prelude::FormattedError::FormatMessage),
+	/// the text doesn't depend on the machine or its language, which makes it
+	/// suitable for logs produced on build servers. Not all constants have a
+	/// message in the catalog.
+	#[must_use]
+	pub fn message(self) -> Option<&'static str> {
+		self.catalog_entry()
+			.map(|(_, _, msg)| *msg)
+			.filter(|msg| !msg.is_empty())
+	}
+
+	/// Returns a description with the error code, the constant name and the
+	/// English message, whenever available, without system calls.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// assert_eq!(
+	///     co::ERROR::FILE_NOT_FOUND.describe(),
+	///     "[0x0002 2] FILE_NOT_FOUND: The system cannot find the file specified.",
+	/// );
+	/// ```
+	#[must_use]
+	pub fn describe(self) -> String {
+		let code = if self.0 > 0xffff {
+			format!("[{:#010x} {}]", self.0, self.0)
+		} else {
+			format!("[{:#06x} {}]", self.0, self.0)
+		};
+		match self.message() {
+			Some(msg) => format!("{} {}: {}", code, self.name().unwrap_or("Unknown error"), msg),
+			None => format!("{} {}", code, self.name().unwrap_or("Unknown error")),
+		}
+	}
+
+	fn catalog_entry(self) -> Option<&'static (u32, &'static str, &'static str)> {
+		super::error_catalog::ERROR_CATALOG
+			.binary_search_by_key(&self.0, |(code, _, _)| *code)
+			.ok()
+			.map(|idx| &super::error_catalog::ERROR_CATALOG[idx])
+	}
+}
+
 const_values! { ERROR;
 	/// The operation completed successfully.
 	SUCCESS 0
@@ -2668,3 +2725,46 @@ const_values! { ERROR;
 	STATE_CONTAINER_NAME_SIZE_LIMIT_EXCEEDED 15818
 	API_UNAVAILABLE 15841
 }
+
+#[cfg(all(test, feature = "error-catalog"))]
+mod test {
+	use super::*;
+
+	#[test]
+	fn catalog_sorted() {
+		let catalog = super::super::error_catalog::ERROR_CATALOG;
+		assert!(catalog.windows(2).all(|w| w[0].0 < w[1].0));
+		assert!(catalog.iter().all(|(_, name, _)| !name.is_empty()));
+	}
+
+	#[test]
+	fn lookups() {
+		let cases: &[(ERROR, Option<&str>, Option<&str>)] = &[
+			(ERROR::SUCCESS, Some("SUCCESS"), Some("The operation completed successfully.")),
+			(ERROR::FILE_NOT_FOUND, Some("FILE_NOT_FOUND"), Some("The system cannot find the file specified.")),
+			(ERROR::ACCESS_DENIED, Some("ACCESS_DENIED"), Some("Access is denied.")),
+			(ERROR::INVALID_PARAMETER, Some("INVALID_PARAMETER"), Some("The parameter is incorrect.")),
+			(ERROR::API_UNAVAILABLE, Some("API_UNAVAILABLE"),
+				Some("This API cannot be used in the context of the caller's application type.")),
+			(unsafe { ERROR::from_raw(0xfffe) }, None, None),
+			(unsafe { ERROR::from_raw(0x2000_0001) }, None, None),
+		];
+		for (err, name, msg) in cases.iter() {
+			assert_eq!(err.name(), *name, "{:#x}", err.raw());
+			assert_eq!(err.message(), *msg, "{:#x}", err.raw());
+		}
+	}
+
+	#[test]
+	fn describe() {
+		let cases: &[(ERROR, &str)] = &[
+			(ERROR::FILE_NOT_FOUND, "[0x0002 2] FILE_NOT_FOUND: The system cannot find the file specified."),
+			(ERROR::OUT_OF_PAPER, "[0x001c 28] OUT_OF_PAPER: The printer is out of paper."),
+			(unsafe { ERROR::from_raw(0xfffe) }, "[0xfffe 65534] Unknown error"),
+			(unsafe { ERROR::from_raw(0x2000_0001) }, "[0x20000001 536870913] Unknown error"),
+		];
+		for (err, expected) in cases.iter() {
+			assert_eq!(err.describe(), *expected, "{:#x}", err.raw());
+		}
+	}
+}
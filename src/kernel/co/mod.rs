@@ -1,5 +1,6 @@
 mod consts;
 mod error;
+mod error_catalog;
 mod guids;
 
 pub use consts::*;
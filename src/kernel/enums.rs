@@ -133,6 +133,93 @@ pub enum PowerSettingLid {
 	Opened,
 }
 
+/// An owned resource type, name or identifier, as stored in binary resource
+/// formats. Unlike [`RtStr`](crate::RtStr) and [`IdStr`](crate::IdStr), it
+/// holds a Rust [`String`](std::string::String), so it can be used without
+/// system calls.
+///
+/// Variant parameter for:
+///
+/// * [`PeResources`](crate::PeResources).
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ResId {
+	/// A numeric identifier.
+	Id(u16),
+	/// A string identifier.
+	Str(String),
+}
+
+impl std::fmt::Display for ResId {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Self::Id(id) => write!(f, "ID: {}", id),
+			Self::Str(str) => write!(f, "Str: {}", str),
+		}
+	}
+}
+
+impl From<co::RT> for ResId {
+	fn from(v: co::RT) -> Self {
+		Self::Id(v.raw())
+	}
+}
+
+impl From<u16> for ResId {
+	fn from(v: u16) -> Self {
+		Self::Id(v)
+	}
+}
+
+impl From<&str> for ResId {
+	fn from(v: &str) -> Self {
+		Self::Str(v.to_owned())
+	}
+}
+
+impl ResId {
+	/// Tells whether both identifiers refer to the same resource: numeric
+	/// identifiers must be equal, and string identifiers are compared ASCII
+	/// case-insensitively, like
+	/// [`HINSTANCE::FindResource`](crate::prelude::kernel_Hinstance::FindResource)
+	/// does.
+	#[must_use]
+	pub fn matches(&self, other: &ResId) -> bool {
+		match (self, other) {
+			(Self::Id(a), Self::Id(b)) => a == b,
+			(Self::Str(a), Self::Str(b)) => a.eq_ignore_ascii_case(b),
+			_ => false,
+		}
+	}
+
+	/// Returns the numeric identifier as a predefined resource type, if the
+	/// identifier is numeric.
+	#[must_use]
+	pub const fn rt(&self) -> Option<co::RT> {
+		match self {
+			Self::Id(id) => Some(unsafe { co::RT::from_raw(*id) }),
+			Self::Str(_) => None,
+		}
+	}
+
+	/// Converts the identifier into an [`IdStr`](crate::IdStr).
+	#[must_use]
+	pub fn to_idstr(&self) -> IdStr {
+		match self {
+			Self::Id(id) => IdStr::Id(*id),
+			Self::Str(s) => IdStr::from_str(s),
+		}
+	}
+
+	/// Converts the identifier into an [`RtStr`](crate::RtStr).
+	#[must_use]
+	pub fn to_rtstr(&self) -> RtStr {
+		match self {
+			Self::Id(id) => RtStr::Rt(unsafe { co::RT::from_raw(*id) }),
+			Self::Str(s) => RtStr::from_str(s),
+		}
+	}
+}
+
 /// A predefined resource identifier.
 ///
 /// Variant parameter for:
@@ -323,6 +323,9 @@ pub fn GetDiskSpaceInformation(
 /// environment block with
 /// [`FreeEnvironmentStrings`](https://learn.microsoft.com/en-us/windows/win32/api/processenv/nf-processenv-freeenvironmentstringsw).
 ///
+/// To build the environment of a child process, see
+/// [`EnvironmentBlock`](crate::EnvironmentBlock).
+///
 /// # Examples
 ///
 /// Retrieving and printing the key/value pairs of all environment strings:
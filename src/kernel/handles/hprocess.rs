@@ -35,6 +35,13 @@ pub trait kernel_Hprocess: Handle {
 
 	/// [`CreateProcess`](https://learn.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-createprocessw)
 	/// function.
+	///
+	/// The `environment` name/value pairs are converted into an
+	/// [`EnvironmentBlock`](crate::EnvironmentBlock), so they're validated as
+	/// in [`EnvironmentBlock::set`](crate::EnvironmentBlock::set). To pass an
+	/// existing `EnvironmentBlock`, use
+	/// [`HPROCESS::CreateProcessWithEnv`](crate::prelude::kernel_Hprocess::CreateProcessWithEnv).
+	/// To build the command line, see the [`cmd_line`](crate::cmd_line) module.
 	#[must_use]
 	fn CreateProcess(
 		application_name: Option<&str>,
@@ -47,8 +54,42 @@ pub trait kernel_Hprocess: Handle {
 		current_dir: Option<&str>,
 		si: &mut STARTUPINFO,
 	) -> SysResult<CloseHandlePiGuard>
+	{
+		let environment = environment
+			.map(EnvironmentBlock::from_pairs)
+			.transpose()?;
+		Self::CreateProcessWithEnv(application_name, command_line, process_attrs,
+			thread_attrs, inherit_handles, creation_flags, environment.as_ref(),
+			current_dir, si)
+	}
+
+	/// [`CreateProcess`](https://learn.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-createprocessw)
+	/// function, taking the environment as an
+	/// [`EnvironmentBlock`](crate::EnvironmentBlock).
+	///
+	/// If an environment is passed,
+	/// [`co::CREATE::UNICODE_ENVIRONMENT`](crate::co::CREATE::UNICODE_ENVIRONMENT)
+	/// is automatically added to `creation_flags`.
+	#[must_use]
+	fn CreateProcessWithEnv(
+		application_name: Option<&str>,
+		command_line: Option<&str>,
+		process_attrs: Option<&mut SECURITY_ATTRIBUTES>,
+		thread_attrs: Option<&mut SECURITY_ATTRIBUTES>,
+		inherit_handles: bool,
+		creation_flags: co::CREATE,
+		environment: Option<&EnvironmentBlock>,
+		current_dir: Option<&str>,
+		si: &mut STARTUPINFO,
+	) -> SysResult<CloseHandlePiGuard>
 	{
 		let mut buf_cmd_line = WString::from_opt_str(command_line);
+		let buf_env = environment.map(|env| env.to_wide());
+		let creation_flags = if buf_env.is_some() {
+			creation_flags | co::CREATE::UNICODE_ENVIRONMENT
+		} else {
+			creation_flags
+		};
 		let mut pi = PROCESS_INFORMATION::default();
 
 		unsafe {
@@ -60,13 +101,7 @@ pub trait kernel_Hprocess: Handle {
 					thread_attrs.map_or(std::ptr::null_mut(), |lp| lp as *mut _ as _),
 					inherit_handles as _,
 					creation_flags.raw(),
-					environment.map_or(std::ptr::null_mut(), |environment| {
-						WString::from_str_vec(
-							&environment.iter()
-								.map(|(name, val)| format!("{}={}", name, val))
-								.collect::<Vec<_>>()
-						).as_ptr() as _
-					}),
+					buf_env.as_ref().map_or(std::ptr::null_mut(), |buf| buf.as_ptr() as _),
 					WString::from_opt_str(current_dir).as_ptr(),
 					si as *mut _ as _,
 					&mut pi as *mut _ as _,
@@ -173,3 +173,204 @@ pub(crate) fn create_wstr_ptr_vecs(
 		None => (Vec::default(), Vec::default()),
 	}
 }
+
+/// Sequential little-endian reader over a raw memory block, used by the binary
+/// format parsers. Any out-of-bounds read yields
+/// [`ERROR::INVALID_DATA`](crate::co::ERROR::INVALID_DATA).
+#[derive(Clone)]
+pub(crate) struct RawReader<'a> {
+	data: &'a [u8],
+	pos: usize,
+}
+
+impl<'a> RawReader<'a> {
+	#[must_use]
+	pub(crate) const fn new(data: &'a [u8]) -> Self {
+		Self { data, pos: 0 }
+	}
+
+	/// Returns the whole underlying block.
+	#[must_use]
+	pub(crate) const fn data(&self) -> &'a [u8] {
+		self.data
+	}
+
+	/// Returns the current offset from the beginning of the block.
+	#[must_use]
+	pub(crate) const fn pos(&self) -> usize {
+		self.pos
+	}
+
+	/// Returns the number of bytes left to be read.
+	#[must_use]
+	pub(crate) const fn remaining(&self) -> usize {
+		self.data.len().saturating_sub(self.pos)
+	}
+
+	/// Moves to the given absolute offset.
+	pub(crate) fn seek(&mut self, pos: usize) -> SysResult<()> {
+		if pos > self.data.len() {
+			Err(co::ERROR::INVALID_DATA)
+		} else {
+			self.pos = pos;
+			Ok(())
+		}
+	}
+
+	/// Advances the given number of bytes.
+	pub(crate) fn skip(&mut self, num_bytes: usize) -> SysResult<()> {
+		self.seek(self.pos.checked_add(num_bytes).ok_or(co::ERROR::INVALID_DATA)?)
+	}
+
+	/// Advances until the offset is a multiple of `alignment`.
+	pub(crate) fn align(&mut self, alignment: usize) -> SysResult<()> {
+		let rem = self.pos % alignment;
+		if rem != 0 {
+			self.skip(alignment - rem)?;
+		}
+		Ok(())
+	}
+
+	/// Advances until the offset is a multiple of `alignment`, stopping at the
+	/// end of the block instead of failing.
+	pub(crate) fn align_lenient(&mut self, alignment: usize) {
+		let rem = self.pos % alignment;
+		if rem != 0 {
+			self.pos = (self.pos + alignment - rem).min(self.data.len());
+		}
+	}
+
+	pub(crate) fn bytes(&mut self, num_bytes: usize) -> SysResult<&'a [u8]> {
+		let end = self.pos.checked_add(num_bytes)
+			.filter(|end| *end <= self.data.len())
+			.ok_or(co::ERROR::INVALID_DATA)?;
+		let slice = &self.data[self.pos..end];
+		self.pos = end;
+		Ok(slice)
+	}
+
+	pub(crate) fn u8(&mut self) -> SysResult<u8> {
+		self.bytes(1).map(|b| b[0])
+	}
+
+	pub(crate) fn u16(&mut self) -> SysResult<u16> {
+		self.bytes(2).map(|b| u16::from_le_bytes([b[0], b[1]]))
+	}
+
+	pub(crate) fn u32(&mut self) -> SysResult<u32> {
+		self.bytes(4).map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+	}
+
+	pub(crate) fn u64(&mut self) -> SysResult<u64> {
+		self.bytes(8).map(|b| u64::from_le_bytes(b.try_into().unwrap()))
+	}
+
+	/// Reads UTF-16 chars until a null is found, which is consumed.
+	pub(crate) fn wstr_nullt(&mut self) -> SysResult<String> {
+		let mut wchars = Vec::<u16>::new();
+		loop {
+			match self.u16()? {
+				0x0000 => break,
+				wch => wchars.push(wch),
+			}
+		}
+		Ok(String::from_utf16_lossy(&wchars))
+	}
+
+	/// Reads exactly `num_chars` UTF-16 chars.
+	pub(crate) fn wstr_len(&mut self, num_chars: usize) -> SysResult<String> {
+		let raw = self.bytes(num_chars.checked_mul(2).ok_or(co::ERROR::INVALID_DATA)?)?;
+		Ok(String::from_utf16_lossy(
+			&raw.chunks_exact(2)
+				.map(|ch2| u16::from_le_bytes([ch2[0], ch2[1]]))
+				.collect::<Vec<_>>(),
+		))
+	}
+}
+
+/// Sequential little-endian writer into a growable buffer, the counterpart of
+/// [`RawReader`](crate::kernel::privs::RawReader).
+#[derive(Default)]
+pub(crate) struct RawWriter {
+	buf: Vec<u8>,
+}
+
+impl RawWriter {
+	#[must_use]
+	pub(crate) fn new() -> Self {
+		Self::default()
+	}
+
+	/// Returns the current offset, which is the number of bytes written so far.
+	#[must_use]
+	pub(crate) fn pos(&self) -> usize {
+		self.buf.len()
+	}
+
+	/// Writes zeros until the offset is a multiple of `alignment`.
+	pub(crate) fn align(&mut self, alignment: usize) {
+		while self.buf.len() % alignment != 0 {
+			self.buf.push(0x00);
+		}
+	}
+
+	pub(crate) fn bytes(&mut self, data: &[u8]) {
+		self.buf.extend_from_slice(data);
+	}
+
+	pub(crate) fn u8(&mut self, val: u8) {
+		self.buf.push(val);
+	}
+
+	pub(crate) fn u16(&mut self, val: u16) {
+		self.buf.extend_from_slice(&val.to_le_bytes());
+	}
+
+	pub(crate) fn u32(&mut self, val: u32) {
+		self.buf.extend_from_slice(&val.to_le_bytes());
+	}
+
+	pub(crate) fn u64(&mut self, val: u64) {
+		self.buf.extend_from_slice(&val.to_le_bytes());
+	}
+
+	/// Writes the string as UTF-16, without a terminating null.
+	pub(crate) fn wstr(&mut self, s: &str) {
+		s.encode_utf16().for_each(|wch| self.u16(wch));
+	}
+
+	/// Writes the string as UTF-16, followed by a terminating null.
+	pub(crate) fn wstr_nullt(&mut self, s: &str) {
+		self.wstr(s);
+		self.u16(0x0000);
+	}
+
+	/// Overwrites a previously written `u16` at the given offset.
+	pub(crate) fn patch_u16(&mut self, pos: usize, val: u16) {
+		self.buf[pos..pos + 2].copy_from_slice(&val.to_le_bytes());
+	}
+
+	/// Overwrites a previously written `u32` at the given offset.
+	pub(crate) fn patch_u32(&mut self, pos: usize, val: u32) {
+		self.buf[pos..pos + 4].copy_from_slice(&val.to_le_bytes());
+	}
+
+	#[must_use]
+	pub(crate) fn into_vec(self) -> Vec<u8> {
+		self.buf
+	}
+}
+
+/// Copies the bytes into a `u32` buffer, so the data is aligned on a `u32`
+/// boundary, as required by in-memory templates. The last element is padded
+/// with zeros.
+#[must_use]
+pub(crate) fn u32_aligned(data: &[u8]) -> Vec<u32> {
+	data.chunks(4)
+		.map(|ch| {
+			let mut buf = [0u8; 4];
+			buf[..ch.len()].copy_from_slice(ch);
+			u32::from_le_bytes(buf)
+		})
+		.collect()
+}
@@ -5,6 +5,7 @@ use std::marker::PhantomData;
 use crate::co;
 use crate::decl::*;
 use crate::kernel::privs::*;
+use crate::kernel::utilities::{calendar, date_format};
 use crate::prelude::*;
 
 /// [`ACL`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-acl)
@@ -222,7 +223,9 @@ pub struct DISK_SPACE_INFORMATION {
 /// struct.
 ///
 /// Can be converted to [`SYSTEMTIME`](crate::SYSTEMTIME) with
-/// [`FileTimeToSystemTime`](crate::FileTimeToSystemTime) function.
+/// [`FileTimeToSystemTime`](crate::FileTimeToSystemTime) function, or
+/// [`SYSTEMTIME::from_filetime`](crate::SYSTEMTIME::from_filetime), which
+/// doesn't perform system calls.
 #[repr(C)]
 #[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct FILETIME {
@@ -275,6 +278,100 @@ impl FILETIME {
 	pub fn add_days(self, days: i64) -> Self {
 		self.add_hours(days * 24)
 	}
+
+	/// Creates a new `FILETIME` from the seconds since the Unix epoch,
+	/// January 1, 1970.
+	///
+	/// Returns
+	/// [`ERROR::INVALID_PARAMETER`](crate::co::ERROR::INVALID_PARAMETER) if the
+	/// time is out of range.
+	pub fn from_unix_secs(secs: i64) -> SysResult<Self> {
+		secs.checked_mul(1000)
+			.ok_or(co::ERROR::INVALID_PARAMETER)
+			.and_then(Self::from_unix_ms)
+	}
+
+	/// Creates a new `FILETIME` from the milliseconds since the Unix epoch,
+	/// January 1, 1970.
+	///
+	/// Returns
+	/// [`ERROR::INVALID_PARAMETER`](crate::co::ERROR::INVALID_PARAMETER) if the
+	/// time is out of range.
+	pub fn from_unix_ms(ms: i64) -> SysResult<Self> {
+		ms.checked_mul(calendar::TICKS_PER_MS)
+			.and_then(|ticks| ticks.checked_add(calendar::UNIX_EPOCH_TICKS))
+			.ok_or(co::ERROR::INVALID_PARAMETER)
+			.and_then(calendar::ticks_to_filetime)
+	}
+
+	/// Returns the seconds since the Unix epoch, January 1, 1970, which are
+	/// negative for earlier times.
+	#[must_use]
+	pub fn to_unix_secs(self) -> i64 {
+		self.to_unix_ms().div_euclid(1000)
+	}
+
+	/// Returns the milliseconds since the Unix epoch, January 1, 1970, which
+	/// are negative for earlier times.
+	#[must_use]
+	pub fn to_unix_ms(self) -> i64 {
+		((u64::from(self) as i128 - calendar::UNIX_EPOCH_TICKS as i128)
+			.div_euclid(calendar::TICKS_PER_MS as _)) as _
+	}
+
+	/// Creates a new `FILETIME` from a
+	/// [`std::time::SystemTime`](std::time::SystemTime).
+	///
+	/// Returns
+	/// [`ERROR::INVALID_PARAMETER`](crate::co::ERROR::INVALID_PARAMETER) if the
+	/// time is before January 1, 1601.
+	pub fn from_system_time(time: std::time::SystemTime) -> SysResult<Self> {
+		let ticks = match time.duration_since(std::time::UNIX_EPOCH) {
+			Ok(after) => calendar::UNIX_EPOCH_TICKS as i128 + (after.as_nanos() / 100) as i128,
+			Err(e) => calendar::UNIX_EPOCH_TICKS as i128 - (e.duration().as_nanos() / 100) as i128,
+		};
+		i64::try_from(ticks)
+			.map_err(|_| co::ERROR::INVALID_PARAMETER)
+			.and_then(calendar::ticks_to_filetime)
+	}
+
+	/// Converts the `FILETIME` into a
+	/// [`std::time::SystemTime`](std::time::SystemTime).
+	///
+	/// Returns
+	/// [`ERROR::INVALID_PARAMETER`](crate::co::ERROR::INVALID_PARAMETER) if the
+	/// time can't be represented.
+	pub fn to_system_time(self) -> SysResult<std::time::SystemTime> {
+		let ticks = calendar::filetime_to_ticks(self)? - calendar::UNIX_EPOCH_TICKS;
+		let dur = std::time::Duration::new(
+			ticks.unsigned_abs() / 10_000_000,
+			(ticks.unsigned_abs() % 10_000_000 * 100) as _,
+		);
+		if ticks >= 0 {
+			std::time::UNIX_EPOCH.checked_add(dur)
+		} else {
+			std::time::UNIX_EPOCH.checked_sub(dur)
+		}.ok_or(co::ERROR::INVALID_PARAMETER)
+	}
+
+	/// Returns the time elapsed from `earlier` to `self`, or `None` if
+	/// `earlier` is later than `self`.
+	#[must_use]
+	pub fn duration_since(self, earlier: FILETIME) -> Option<std::time::Duration> {
+		u64::from(self).checked_sub(u64::from(earlier))
+			.map(|ticks| std::time::Duration::new(
+				ticks / 10_000_000,
+				(ticks % 10_000_000 * 100) as _,
+			))
+	}
+
+	/// Returns the milliseconds from `other` to `self`, which are negative if
+	/// `other` is later than `self`.
+	#[must_use]
+	pub fn diff_ms(self, other: FILETIME) -> i64 {
+		((u64::from(self) as i128 - u64::from(other) as i128)
+			/ calendar::TICKS_PER_MS as i128) as _
+	}
 }
 
 /// [`HEAPLIST32`](https://learn.microsoft.com/en-us/windows/win32/api/tlhelp32/ns-tlhelp32-heaplist32)
@@ -398,6 +495,21 @@ impl std::fmt::Display for LUID {
 	}
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for LUID {
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.serialize_u64(((self.HighPart as u32 as u64) << 32) | self.LowPart as u64)
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for LUID {
+	fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		<u64 as serde::Deserialize>::deserialize(deserializer)
+			.map(|v| Self::new(v as u32, (v >> 32) as u32 as i32))
+	}
+}
+
 impl LUID {
 	pub const SYSTEM: Self = Self::new(0x3e7, 0x0);
 	pub const ANONYMOUS_LOGON: Self = Self::new(0x3e6, 0x0);
@@ -794,7 +906,9 @@ impl_default!(SYSTEM_INFO);
 /// struct.
 ///
 /// Can be converted to [`FILETIME`](crate::FILETIME) with
-/// [`SystemTimeToFileTime`](crate::SystemTimeToFileTime) function.
+/// [`SystemTimeToFileTime`](crate::SystemTimeToFileTime) function, or
+/// [`SYSTEMTIME::to_filetime`](crate::SYSTEMTIME::to_filetime), which
+/// doesn't perform system calls.
 #[repr(C)]
 #[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SYSTEMTIME {
@@ -817,18 +931,129 @@ impl std::fmt::Display for SYSTEMTIME {
 }
 
 impl SYSTEMTIME {
+	/// Converts a [`FILETIME`](crate::FILETIME) into a `SYSTEMTIME`, without
+	/// system calls. Same result of
+	/// [`FileTimeToSystemTime`](crate::FileTimeToSystemTime).
+	///
+	/// Returns
+	/// [`ERROR::INVALID_PARAMETER`](crate::co::ERROR::INVALID_PARAMETER) if the
+	/// time is out of range.
+	pub fn from_filetime(ft: &FILETIME) -> SysResult<Self> {
+		calendar::ticks_to_systemtime(calendar::filetime_to_ticks(*ft)?)
+	}
+
+	/// Converts the `SYSTEMTIME` into a [`FILETIME`](crate::FILETIME), without
+	/// system calls. Same result of
+	/// [`SystemTimeToFileTime`](crate::SystemTimeToFileTime).
+	///
+	/// The `wDayOfWeek` field is ignored.
+	///
+	/// Returns
+	/// [`ERROR::INVALID_PARAMETER`](crate::co::ERROR::INVALID_PARAMETER) if any
+	/// field is invalid.
+	pub fn to_filetime(&self) -> SysResult<FILETIME> {
+		calendar::ticks_to_filetime(calendar::systemtime_to_ticks(self)?)
+	}
+
+	/// Converts an OLE automation date, used by
+	/// [`VariantTimeToSystemTime`](crate::VariantTimeToSystemTime), into a
+	/// `SYSTEMTIME`, without system calls.
+	///
+	/// An OLE automation date is the number of days since December 30, 1899,
+	/// and the fraction is the time of the day, regardless of the sign. Unlike
+	/// `VariantTimeToSystemTime`, milliseconds are kept.
+	///
+	/// Returns
+	/// [`ERROR::INVALID_PARAMETER`](crate::co::ERROR::INVALID_PARAMETER) if the
+	/// date is outside years 100 to 9999.
+	pub fn from_variant_time(var_time: f64) -> SysResult<Self> {
+		if !(-657_434.0..2_958_466.0).contains(&var_time) {
+			return Err(co::ERROR::INVALID_PARAMETER);
+		}
+		let mut days = var_time.trunc() as i64 + calendar::days_from_civil(1899, 12, 30);
+		let mut ms = ((var_time - var_time.trunc()).abs() * 86_400_000.0).round() as i64;
+		if ms == 86_400_000 {
+			days += 1;
+			ms = 0;
+		}
+		let st = calendar::systemtime_from_parts(days, ms);
+		if st.wYear > 9999 {
+			return Err(co::ERROR::INVALID_PARAMETER);
+		}
+		Ok(st)
+	}
+
+	/// Converts the `SYSTEMTIME` into an OLE automation date, used by
+	/// [`SystemTimeToVariantTime`](crate::SystemTimeToVariantTime), without
+	/// system calls.
+	///
+	/// Unlike `SystemTimeToVariantTime`, milliseconds are kept. The
+	/// `wDayOfWeek` field is ignored.
+	///
+	/// Returns
+	/// [`ERROR::INVALID_PARAMETER`](crate::co::ERROR::INVALID_PARAMETER) if any
+	/// field is invalid, or the year is outside 100 to 9999.
+	pub fn to_variant_time(&self) -> SysResult<f64> {
+		let (days, ms) = calendar::systemtime_parts(self, 100, 9999)?;
+		let days = days - calendar::days_from_civil(1899, 12, 30);
+		let frac = ms as f64 / 86_400_000.0;
+		Ok(if days < 0 { days as f64 - frac } else { days as f64 + frac })
+	}
+
+	/// Computes the day of the week of the date, where 0 is Sunday, ignoring
+	/// the `wDayOfWeek` field.
+	///
+	/// Returns
+	/// [`ERROR::INVALID_PARAMETER`](crate::co::ERROR::INVALID_PARAMETER) if any
+	/// field is invalid.
+	pub fn day_of_week(&self) -> SysResult<u16> {
+		let (days, _) = calendar::systemtime_parts(self, 1601, calendar::MAX_YEAR)?;
+		Ok(calendar::day_of_week(days) as _)
+	}
+
+	/// Computes the day of the year of the date, where 1 is January 1.
+	///
+	/// Returns
+	/// [`ERROR::INVALID_PARAMETER`](crate::co::ERROR::INVALID_PARAMETER) if any
+	/// field is invalid.
+	pub fn day_of_year(&self) -> SysResult<u16> {
+		let (days, _) = calendar::systemtime_parts(self, 1601, calendar::MAX_YEAR)?;
+		Ok((days - calendar::days_from_civil(self.wYear as _, 1, 1) + 1) as _)
+	}
+
+	/// Tells whether the year is a leap year in the Gregorian calendar.
+	#[must_use]
+	pub const fn is_leap_year(year: u16) -> bool {
+		calendar::is_leap_year(year as _)
+	}
+
+	/// Returns the number of days in the month of the given year, or zero if
+	/// the month is not in `1..=12`.
+	#[must_use]
+	pub const fn days_in_month(year: u16, month: u16) -> u16 {
+		if month >= 1 && month <= 12 {
+			calendar::days_in_month(year as _, month as _) as _
+		} else {
+			0
+		}
+	}
+
 	/// Returns a new `SYSTEMTIME` with the milliseconds difference.
 	///
-	/// Performs intermediate [`FILETIME`](crate::FILETIME) conversions.
+	/// Performs intermediate [`FILETIME`](crate::FILETIME) conversions, without
+	/// system calls.
 	#[must_use]
 	pub fn add_ms(self, ms: i64) -> SysResult<Self> {
-		let ft = SystemTimeToFileTime(&self)?;
-		FileTimeToSystemTime(&ft.add_ms(ms))
+		calendar::systemtime_to_ticks(&self)?
+			.checked_add(ms.checked_mul(calendar::TICKS_PER_MS).ok_or(co::ERROR::INVALID_PARAMETER)?)
+			.ok_or(co::ERROR::INVALID_PARAMETER)
+			.and_then(calendar::ticks_to_systemtime)
 	}
 
 	/// Returns a new `SYSTEMTIME` with the seconds difference.
 	///
-	/// Performs intermediate [`FILETIME`](crate::FILETIME) conversions.
+	/// Performs intermediate [`FILETIME`](crate::FILETIME) conversions, without
+	/// system calls.
 	#[must_use]
 	pub fn add_secs(self, secs: i64) -> SysResult<Self> {
 		self.add_ms(secs * 1000)
@@ -836,7 +1061,8 @@ impl SYSTEMTIME {
 
 	/// Returns a new `SYSTEMTIME` with the minutes difference.
 	///
-	/// Performs intermediate [`FILETIME`](crate::FILETIME) conversions.
+	/// Performs intermediate [`FILETIME`](crate::FILETIME) conversions, without
+	/// system calls.
 	#[must_use]
 	pub fn add_mins(self, mins: i64) -> SysResult<Self> {
 		self.add_secs(mins * 60)
@@ -844,7 +1070,8 @@ impl SYSTEMTIME {
 
 	/// Returns a new `SYSTEMTIME` with the hours difference.
 	///
-	/// Performs intermediate [`FILETIME`](crate::FILETIME) conversions.
+	/// Performs intermediate [`FILETIME`](crate::FILETIME) conversions, without
+	/// system calls.
 	#[must_use]
 	pub fn add_hours(self, hours: i64) -> SysResult<Self> {
 		self.add_mins(hours * 60)
@@ -852,11 +1079,167 @@ impl SYSTEMTIME {
 
 	/// Returns a new `SYSTEMTIME` with the days difference.
 	///
-	/// Performs intermediate [`FILETIME`](crate::FILETIME) conversions.
+	/// Performs intermediate [`FILETIME`](crate::FILETIME) conversions, without
+	/// system calls.
 	#[must_use]
 	pub fn add_days(self, days: i64) -> SysResult<Self> {
 		self.add_hours(days * 24)
 	}
+
+	/// Returns a new `SYSTEMTIME` with the months difference, keeping the time
+	/// of the day.
+	///
+	/// If the day doesn't exist in the resulting month, it's clamped to the
+	/// last day of the month, so January 31 plus one month is February 28 or
+	/// 29.
+	pub fn add_months(self, months: i64) -> SysResult<Self> {
+		calendar::systemtime_parts(&self, 1601, calendar::MAX_YEAR)?;
+		let total = (self.wYear as i64 * 12 + self.wMonth as i64 - 1)
+			.checked_add(months)
+			.ok_or(co::ERROR::INVALID_PARAMETER)?;
+		let (year, month) = (total.div_euclid(12), total.rem_euclid(12) + 1);
+		if !(1601..=calendar::MAX_YEAR).contains(&year) {
+			return Err(co::ERROR::INVALID_PARAMETER);
+		}
+		let day = (self.wDay as i64).min(calendar::days_in_month(year, month));
+		let days = calendar::days_from_civil(year, month, day);
+		Ok(Self {
+			wYear: year as _,
+			wMonth: month as _,
+			wDayOfWeek: calendar::day_of_week(days) as _,
+			wDay: day as _,
+			..self
+		})
+	}
+
+	/// Returns a new `SYSTEMTIME` with the years difference, keeping the time
+	/// of the day.
+	///
+	/// February 29 is clamped to February 28 in non-leap years.
+	pub fn add_years(self, years: i64) -> SysResult<Self> {
+		years.checked_mul(12)
+			.ok_or(co::ERROR::INVALID_PARAMETER)
+			.and_then(|months| self.add_months(months))
+	}
+
+	/// Returns the milliseconds from `other` to `self`, which are negative if
+	/// `other` is later than `self`.
+	pub fn diff_ms(&self, other: &SYSTEMTIME) -> SysResult<i64> {
+		Ok((calendar::systemtime_to_ticks(self)? - calendar::systemtime_to_ticks(other)?)
+			/ calendar::TICKS_PER_MS)
+	}
+
+	/// Formats the date and time with a
+	/// [`GetDateFormat`](https://learn.microsoft.com/en-us/windows/win32/intl/day--month--year--and-era-format-pictures)
+	/// and
+	/// [`GetTimeFormat`](https://learn.microsoft.com/en-us/windows/win32/intl/hour--minute--and-second-format-pictures)
+	/// picture string, without system calls.
+	///
+	/// Day and month names are the invariant English ones. Date and time
+	/// pictures can be mixed, since they're case-sensitive:
+	///
+	/// | Picture | Meaning |
+	/// | - | - |
+	/// | `d`, `dd` | Day, with or without leading zero. |
+	/// | `ddd`, `dddd` | Abbreviated and full day of the week. |
+	/// | `M`, `MM` | Month, with or without leading zero. |
+	/// | `MMM`, `MMMM` | Abbreviated and full month name. |
+	/// | `y`, `yy` | Year without century, with or without leading zero. |
+	/// | `yyyy` | Full year. |
+	/// | `g`, `gg` | Era, always "A.D.". |
+	/// | `h`, `hh` | 12-hour, with or without leading zero. |
+	/// | `H`, `HH` | 24-hour, with or without leading zero. |
+	/// | `m`, `mm` | Minutes, with or without leading zero. |
+	/// | `s`, `ss` | Seconds, with or without leading zero. |
+	/// | `t`, `tt` | "A" or "P", and "AM" or "PM". |
+	///
+	/// Text between single quotes is copied verbatim, and two single quotes
+	/// produce one. Other characters are also copied.
+	///
+	/// Returns
+	/// [`ERROR::INVALID_PARAMETER`](crate::co::ERROR::INVALID_PARAMETER) if any
+	/// field is invalid, or the picture has an unterminated quote.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let st = w::SYSTEMTIME {
+	///     wYear: 2024, wMonth: 7, wDay: 1, wHour: 15, wMinute: 5,
+	///     ..Default::default()
+	/// };
+	/// let s = st.format("dddd, MMMM d, yyyy 'at' h:mm tt")?;
+	/// assert_eq!(s, "Monday, July 1, 2024 at 3:05 PM");
+	/// # w::SysResult::Ok(())
+	/// ```
+	pub fn format(&self, picture: &str) -> SysResult<String> {
+		date_format::format_picture(self, picture)
+	}
+
+	/// Parses a date and time with a picture string, as described in
+	/// [`SYSTEMTIME::format`](crate::SYSTEMTIME::format), without system
+	/// calls.
+	///
+	/// Names are matched case-insensitively. Two-digit years are in the range
+	/// 1950 to 2049. Fields not present in the picture are zero; if the whole
+	/// date is present, `wDayOfWeek` is filled, and a parsed day of the week
+	/// must match it.
+	///
+	/// Returns
+	/// [`ERROR::INVALID_DATA`](crate::co::ERROR::INVALID_DATA) if the text
+	/// doesn't match the picture, or a field is out of range.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let st = w::SYSTEMTIME::parse("01/07/2024 15:05", "dd/MM/yyyy HH:mm")?;
+	/// assert_eq!(st.wMonth, 7);
+	/// # w::SysResult::Ok(())
+	/// ```
+	pub fn parse(text: &str, picture: &str) -> SysResult<Self> {
+		date_format::parse_picture(text, picture)
+	}
+
+	/// Formats the date and time in
+	/// [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) extended format, like
+	/// `2024-07-01T15:05:00`. Milliseconds are written only if not zero.
+	///
+	/// If `utc_offset` is given, in minutes, it's appended as `Z` when zero, or
+	/// as `+hh:mm`/`-hh:mm` otherwise. Note that this offset has the opposite
+	/// sign of a [`TimeZoneRule`](crate::TimeZoneRule) bias.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let st = w::SYSTEMTIME {
+	///     wYear: 2024, wMonth: 7, wDay: 1, wHour: 15, wMinute: 5,
+	///     ..Default::default()
+	/// };
+	/// assert_eq!(st.to_iso8601(Some(-300)), "2024-07-01T15:05:00-05:00");
+	/// ```
+	#[must_use]
+	pub fn to_iso8601(&self, utc_offset: Option<i32>) -> String {
+		date_format::format_iso8601(self, utc_offset)
+	}
+
+	/// Parses a date, like `2024-07-01`, or a date and time, like
+	/// `2024-07-01T15:05:00.250Z`, in
+	/// [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) extended format.
+	///
+	/// Seconds and fraction are optional, and the date can also be separated
+	/// from the time by a space. Returns the UTC offset in minutes, if present.
+	///
+	/// Returns
+	/// [`ERROR::INVALID_DATA`](crate::co::ERROR::INVALID_DATA) if the text is
+	/// not valid.
+	pub fn parse_iso8601(text: &str) -> SysResult<(Self, Option<i32>)> {
+		date_format::parse_iso8601(text)
+	}
 }
 
 /// [`THREADENTRY32`](https://learn.microsoft.com/en-us/windows/win32/api/tlhelp32/ns-tlhelp32-threadentry32)
@@ -954,3 +1337,106 @@ impl WIN32_FIND_DATA {
 	pub_fn_string_arr_get_set!(cFileName, set_cFileName);
 	pub_fn_string_arr_get_set!(cAlternateFileName, set_cAlternateFileName);
 }
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	const fn st(y: u16, mo: u16, d: u16, dow: u16, h: u16, mi: u16, s: u16, ms: u16) -> SYSTEMTIME {
+		SYSTEMTIME {
+			wYear: y, wMonth: mo, wDayOfWeek: dow, wDay: d,
+			wHour: h, wMinute: mi, wSecond: s, wMilliseconds: ms,
+		}
+	}
+
+	#[test]
+	fn variant_time() {
+		let cases: &[(f64, SYSTEMTIME)] = &[
+			(0.0, st(1899, 12, 30, 6, 0, 0, 0, 0)),
+			(2.5, st(1900, 1, 1, 1, 12, 0, 0, 0)),
+			(-1.25, st(1899, 12, 29, 5, 6, 0, 0, 0)), // fraction regardless of the sign
+			(45352.75, st(2024, 3, 1, 5, 18, 0, 0, 0)),
+			(-657_434.0, st(100, 1, 1, 5, 0, 0, 0, 0)),
+			(2_958_465.0, st(9999, 12, 31, 5, 0, 0, 0, 0)),
+		];
+		for (var_time, st) in cases.iter() {
+			assert_eq!(SYSTEMTIME::from_variant_time(*var_time), Ok(*st), "{}", var_time);
+			assert_eq!(st.to_variant_time(), Ok(*var_time), "{}", st);
+		}
+
+		for st in [st(2024, 3, 5, 2, 14, 7, 9, 45), st(1804, 2, 29, 3, 23, 59, 59, 999), st(9999, 12, 31, 5, 23, 59, 59, 999)] {
+			let var_time = st.to_variant_time().unwrap();
+			assert_eq!(SYSTEMTIME::from_variant_time(var_time), Ok(st), "{}", st);
+		}
+
+		assert_eq!(SYSTEMTIME::from_variant_time(-0.5), Ok(st(1899, 12, 30, 6, 12, 0, 0, 0)));
+		assert_eq!(SYSTEMTIME::from_variant_time(1.999_999_999), Ok(st(1900, 1, 1, 1, 0, 0, 0, 0)));
+		for var_time in [-657_435.0, 2_958_466.0, f64::NAN, f64::INFINITY] {
+			assert_eq!(SYSTEMTIME::from_variant_time(var_time), Err(co::ERROR::INVALID_PARAMETER), "{}", var_time);
+		}
+		for st in [st(99, 12, 31, 0, 0, 0, 0, 0), st(10000, 1, 1, 0, 0, 0, 0, 0), st(2024, 2, 30, 0, 0, 0, 0, 0)] {
+			assert_eq!(st.to_variant_time(), Err(co::ERROR::INVALID_PARAMETER), "{}", st);
+		}
+	}
+
+	#[test]
+	fn filetime_conversions() {
+		let cases: &[(SYSTEMTIME, u64, i64)] = &[ // time, FILETIME, Unix ms
+			(st(1601, 1, 1, 1, 0, 0, 0, 0), 0, -11_644_473_600_000),
+			(st(1970, 1, 1, 4, 0, 0, 0, 0), 116_444_736_000_000_000, 0),
+			(st(1969, 12, 31, 3, 23, 59, 59, 999), 116_444_735_999_990_000, -1),
+			(st(2024, 3, 5, 2, 14, 7, 9, 45), 133_541_212_290_450_000, 1_709_647_629_045),
+		];
+		for (st, ft, unix_ms) in cases.iter() {
+			let ft = FILETIME::from(*ft);
+			assert_eq!(st.to_filetime(), Ok(ft), "{}", st);
+			assert_eq!(SYSTEMTIME::from_filetime(&ft), Ok(*st), "{}", st);
+			assert_eq!(ft.to_unix_ms(), *unix_ms, "{}", st);
+			assert_eq!(ft.to_unix_secs(), unix_ms.div_euclid(1000), "{}", st);
+			assert_eq!(FILETIME::from_unix_ms(*unix_ms), Ok(ft), "{}", st);
+			let sys_time = ft.to_system_time().unwrap();
+			assert_eq!(FILETIME::from_system_time(sys_time), Ok(ft), "{}", st);
+		}
+
+		assert_eq!(FILETIME::from_unix_secs(-11_644_473_601), Err(co::ERROR::INVALID_PARAMETER));
+		assert_eq!(FILETIME::from_unix_secs(i64::MAX), Err(co::ERROR::INVALID_PARAMETER));
+		assert_eq!(SYSTEMTIME::from_filetime(&FILETIME::from(u64::MAX)), Err(co::ERROR::INVALID_PARAMETER));
+		let ft = FILETIME::from(116_444_736_000_000_000);
+		assert_eq!(ft.add_days(1).diff_ms(ft), 86_400_000);
+		assert_eq!(ft.duration_since(ft.add_secs(1)), None);
+		assert_eq!(ft.add_secs(1).duration_since(ft), Some(std::time::Duration::from_secs(1)));
+	}
+
+	#[test]
+	fn arithmetic() {
+		let cases: &[(SYSTEMTIME, i64, SYSTEMTIME)] = &[ // time, months, result
+			(st(2024, 1, 31, 3, 10, 0, 0, 0), 1, st(2024, 2, 29, 4, 10, 0, 0, 0)),
+			(st(2023, 1, 31, 2, 10, 0, 0, 0), 1, st(2023, 2, 28, 2, 10, 0, 0, 0)),
+			(st(2024, 1, 15, 1, 0, 0, 0, 0), -1, st(2023, 12, 15, 5, 0, 0, 0, 0)),
+			(st(2024, 3, 31, 0, 0, 0, 0, 0), -13, st(2023, 2, 28, 2, 0, 0, 0, 0)),
+			(st(2024, 2, 29, 4, 0, 0, 0, 0), 12, st(2025, 2, 28, 5, 0, 0, 0, 0)),
+			(st(2024, 2, 29, 4, 0, 0, 0, 0), 48, st(2028, 2, 29, 2, 0, 0, 0, 0)),
+		];
+		for (from, months, to) in cases.iter() {
+			assert_eq!(from.add_months(*months), Ok(*to), "{} + {}", from, months);
+		}
+		assert_eq!(st(2024, 2, 29, 0, 0, 0, 0, 0).add_years(1), Ok(st(2025, 2, 28, 5, 0, 0, 0, 0)));
+		assert_eq!(st(1601, 1, 1, 0, 0, 0, 0, 0).add_months(-1), Err(co::ERROR::INVALID_PARAMETER));
+		assert_eq!(st(2024, 1, 1, 0, 0, 0, 0, 0).add_years(i64::MAX), Err(co::ERROR::INVALID_PARAMETER));
+		assert_eq!(st(2024, 4, 31, 0, 0, 0, 0, 0).add_months(1), Err(co::ERROR::INVALID_PARAMETER));
+
+		let t = st(2024, 2, 28, 3, 23, 30, 0, 0);
+		assert_eq!(t.add_hours(1), Ok(st(2024, 2, 29, 4, 0, 30, 0, 0)));
+		assert_eq!(t.add_days(2), Ok(st(2024, 3, 1, 5, 23, 30, 0, 0)));
+		assert_eq!(t.add_ms(-1), Ok(st(2024, 2, 28, 3, 23, 29, 59, 999)));
+		assert_eq!(t.add_days(2).unwrap().diff_ms(&t), Ok(2 * 86_400_000));
+		assert_eq!(st(1601, 1, 1, 1, 0, 0, 0, 0).add_ms(-1), Err(co::ERROR::INVALID_PARAMETER));
+
+		assert_eq!(st(2024, 12, 31, 0, 0, 0, 0, 0).day_of_year(), Ok(366));
+		assert_eq!(st(2023, 3, 1, 0, 0, 0, 0, 0).day_of_year(), Ok(60));
+		assert_eq!(st(2024, 3, 5, 0, 0, 0, 0, 0).day_of_week(), Ok(2));
+		assert_eq!(SYSTEMTIME::days_in_month(2024, 2), 29);
+		assert_eq!(SYSTEMTIME::days_in_month(2024, 13), 0);
+		assert!(SYSTEMTIME::is_leap_year(2000) && !SYSTEMTIME::is_leap_year(2100));
+	}
+}
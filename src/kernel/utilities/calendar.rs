@@ -0,0 +1,232 @@
+use crate::co;
+use crate::decl::*;
+
+/// Number of 100-nanosecond intervals in a millisecond.
+pub(in crate::kernel) const TICKS_PER_MS: i64 = 10_000;
+/// Number of 100-nanosecond intervals in a minute.
+pub(in crate::kernel) const TICKS_PER_MIN: i64 = TICKS_PER_MS * 60_000;
+/// Number of 100-nanosecond intervals in a day.
+pub(in crate::kernel) const TICKS_PER_DAY: i64 = TICKS_PER_MIN * 1440;
+
+/// Number of 100-nanosecond intervals between January 1, 1601, and the Unix
+/// epoch, January 1, 1970.
+pub(in crate::kernel) const UNIX_EPOCH_TICKS: i64 = 116_444_736_000_000_000;
+
+/// Last year representable by a [`SYSTEMTIME`](crate::SYSTEMTIME).
+pub(in crate::kernel) const MAX_YEAR: i64 = 30827;
+
+/// Tells whether the year is a leap year in the Gregorian calendar.
+#[must_use]
+pub(in crate::kernel) const fn is_leap_year(year: i64) -> bool {
+	(year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+/// Returns the number of days of the month, which must be in `1..=12`.
+#[must_use]
+pub(in crate::kernel) const fn days_in_month(year: i64, month: i64) -> i64 {
+	match month {
+		2 => if is_leap_year(year) { 29 } else { 28 },
+		4 | 6 | 9 | 11 => 30,
+		_ => 31,
+	}
+}
+
+/// Returns the number of days since January 1, 1601, which may be negative.
+#[must_use]
+pub(in crate::kernel) const fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+	// https://howardhinnant.github.io/date_algorithms.html#days_from_civil
+	let y = if month <= 2 { year - 1 } else { year };
+	let era = y.div_euclid(400);
+	let yoe = y - era * 400;
+	let mp = (month + 9) % 12;
+	let doy = (153 * mp + 2) / 5 + day - 1;
+	let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+	era * 146_097 + doe - 584_694 // 584694 days from 0000-03-01 to 1601-01-01
+}
+
+/// Returns the year, month and day of the given number of days since
+/// January 1, 1601.
+#[must_use]
+pub(in crate::kernel) const fn civil_from_days(days: i64) -> (i64, i64, i64) {
+	// https://howardhinnant.github.io/date_algorithms.html#civil_from_days
+	let z = days + 584_694;
+	let era = z.div_euclid(146_097);
+	let doe = z - era * 146_097;
+	let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+	let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+	let mp = (5 * doy + 2) / 153;
+	let day = doy - (153 * mp + 2) / 5 + 1;
+	let month = if mp < 10 { mp + 3 } else { mp - 9 };
+	let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+	(year, month, day)
+}
+
+/// Returns the day of the week, where 0 is Sunday, of the given number of days
+/// since January 1, 1601, which was a Monday.
+#[must_use]
+pub(in crate::kernel) const fn day_of_week(days: i64) -> i64 {
+	(days + 1).rem_euclid(7)
+}
+
+/// Validates all fields of a [`SYSTEMTIME`](crate::SYSTEMTIME), except
+/// `wDayOfWeek`, which is ignored, and returns the number of days since
+/// January 1, 1601, and the milliseconds since midnight.
+pub(in crate::kernel) fn systemtime_parts(
+	st: &SYSTEMTIME,
+	min_year: i64,
+	max_year: i64,
+) -> SysResult<(i64, i64)>
+{
+	let (year, month, day) = (st.wYear as i64, st.wMonth as i64, st.wDay as i64);
+	if !(min_year..=max_year).contains(&year)
+		|| !(1..=12).contains(&month)
+		|| day < 1 || day > days_in_month(year, month)
+		|| st.wHour > 23 || st.wMinute > 59 || st.wSecond > 59
+		|| st.wMilliseconds > 999
+	{
+		return Err(co::ERROR::INVALID_PARAMETER);
+	}
+
+	let ms = ((st.wHour as i64 * 60 + st.wMinute as i64) * 60 + st.wSecond as i64)
+		* 1000 + st.wMilliseconds as i64;
+	Ok((days_from_civil(year, month, day), ms))
+}
+
+/// Builds a [`SYSTEMTIME`](crate::SYSTEMTIME) from the number of days since
+/// January 1, 1601, and the milliseconds since midnight, filling
+/// `wDayOfWeek`.
+#[must_use]
+pub(in crate::kernel) const fn systemtime_from_parts(days: i64, ms: i64) -> SYSTEMTIME {
+	let (year, month, day) = civil_from_days(days);
+	SYSTEMTIME {
+		wYear: year as _,
+		wMonth: month as _,
+		wDayOfWeek: day_of_week(days) as _,
+		wDay: day as _,
+		wHour: (ms / 3_600_000) as _,
+		wMinute: (ms / 60_000 % 60) as _,
+		wSecond: (ms / 1000 % 60) as _,
+		wMilliseconds: (ms % 1000) as _,
+	}
+}
+
+/// Converts a [`SYSTEMTIME`](crate::SYSTEMTIME) into 100-nanosecond intervals
+/// since January 1, 1601, validating all fields except `wDayOfWeek`, which is
+/// ignored.
+pub(in crate::kernel) fn systemtime_to_ticks(st: &SYSTEMTIME) -> SysResult<i64> {
+	let (days, ms) = systemtime_parts(st, 1601, MAX_YEAR)?;
+	Ok(days * TICKS_PER_DAY + ms * TICKS_PER_MS)
+}
+
+/// Converts 100-nanosecond intervals since January 1, 1601 into a
+/// [`SYSTEMTIME`](crate::SYSTEMTIME), filling `wDayOfWeek`. Sub-millisecond
+/// precision is truncated.
+pub(in crate::kernel) fn ticks_to_systemtime(ticks: i64) -> SysResult<SYSTEMTIME> {
+	if ticks < 0 {
+		return Err(co::ERROR::INVALID_PARAMETER);
+	}
+	let days = ticks / TICKS_PER_DAY;
+	if civil_from_days(days).0 > MAX_YEAR {
+		return Err(co::ERROR::INVALID_PARAMETER);
+	}
+	Ok(systemtime_from_parts(days, (ticks % TICKS_PER_DAY) / TICKS_PER_MS))
+}
+
+/// Converts a [`FILETIME`](crate::FILETIME) into signed 100-nanosecond
+/// intervals.
+pub(in crate::kernel) fn filetime_to_ticks(ft: FILETIME) -> SysResult<i64> {
+	i64::try_from(u64::from(ft)).map_err(|_| co::ERROR::INVALID_PARAMETER)
+}
+
+/// Converts signed 100-nanosecond intervals into a
+/// [`FILETIME`](crate::FILETIME).
+pub(in crate::kernel) fn ticks_to_filetime(ticks: i64) -> SysResult<FILETIME> {
+	u64::try_from(ticks)
+		.map(FILETIME::from)
+		.map_err(|_| co::ERROR::INVALID_PARAMETER)
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn civil_days() {
+		let cases: &[((i64, i64, i64), i64, i64)] = &[ // date, days, day of week
+			((1601, 1, 1), 0, 1),
+			((1600, 12, 31), -1, 0),
+			((1970, 1, 1), 134_774, 4),
+			((2000, 2, 29), 145_790, 2),
+			((2000, 3, 1), 145_791, 3),
+			((2024, 3, 10), 154_566, 0),
+			((MAX_YEAR, 12, 31), 10_674_941, 5),
+		];
+		for &(date, days, dow) in cases.iter() {
+			assert_eq!(days_from_civil(date.0, date.1, date.2), days, "days_from_civil({:?})", date);
+			assert_eq!(civil_from_days(days), date, "civil_from_days({})", days);
+			assert_eq!(day_of_week(days), dow, "day_of_week({:?})", date);
+		}
+		assert_eq!(days_from_civil(1970, 1, 1) * TICKS_PER_DAY, UNIX_EPOCH_TICKS);
+	}
+
+	#[test]
+	fn month_lengths() {
+		let cases: &[(i64, i64, i64)] = &[
+			(2023, 2, 28),
+			(2024, 2, 29),
+			(1900, 2, 28),
+			(2000, 2, 29),
+			(2024, 4, 30),
+			(2024, 12, 31),
+		];
+		for &(year, month, days) in cases.iter() {
+			assert_eq!(days_in_month(year, month), days, "days_in_month({}, {})", year, month);
+		}
+	}
+
+	#[test]
+	fn systemtime_ticks() {
+		let st = SYSTEMTIME {
+			wYear: 2024, wMonth: 2, wDay: 29, wHour: 23, wMinute: 59,
+			wSecond: 58, wMilliseconds: 999, ..Default::default()
+		};
+		let ticks = systemtime_to_ticks(&st).unwrap();
+		let back = ticks_to_systemtime(ticks + TICKS_PER_MS - 1).unwrap(); // truncated
+		assert_eq!(back, SYSTEMTIME { wDayOfWeek: 4, ..st });
+		assert_eq!(ticks_to_systemtime(0).unwrap(),
+			SYSTEMTIME { wYear: 1601, wMonth: 1, wDay: 1, wDayOfWeek: 1, ..Default::default() });
+
+		let last = SYSTEMTIME {
+			wYear: MAX_YEAR as _, wMonth: 12, wDay: 31, wHour: 23, wMinute: 59,
+			wSecond: 59, wMilliseconds: 999, ..Default::default()
+		};
+		let ticks = systemtime_to_ticks(&last).unwrap();
+		assert!(ticks_to_systemtime(ticks).is_ok());
+		assert_eq!(ticks_to_systemtime(ticks + TICKS_PER_MS), Err(co::ERROR::INVALID_PARAMETER));
+		assert_eq!(ticks_to_systemtime(-1), Err(co::ERROR::INVALID_PARAMETER));
+
+		let day = SYSTEMTIME { wYear: 2023, wMonth: 1, wDay: 1, ..Default::default() };
+		let invalid = [
+			SYSTEMTIME { wYear: 1600, ..day },
+			SYSTEMTIME { wMonth: 0, ..day },
+			SYSTEMTIME { wMonth: 13, ..day },
+			SYSTEMTIME { wMonth: 2, wDay: 29, ..day },
+			SYSTEMTIME { wDay: 0, ..day },
+			SYSTEMTIME { wHour: 24, ..day },
+			SYSTEMTIME { wMinute: 60, ..day },
+			SYSTEMTIME { wSecond: 60, ..day },
+			SYSTEMTIME { wMilliseconds: 1000, ..day },
+		];
+		for st in invalid.iter() {
+			assert_eq!(systemtime_to_ticks(st), Err(co::ERROR::INVALID_PARAMETER), "{:?}", st);
+		}
+	}
+
+	#[test]
+	fn filetime_ticks() {
+		assert_eq!(filetime_to_ticks(FILETIME::from(UNIX_EPOCH_TICKS as u64)), Ok(UNIX_EPOCH_TICKS));
+		assert_eq!(ticks_to_filetime(UNIX_EPOCH_TICKS), Ok(FILETIME::from(UNIX_EPOCH_TICKS as u64)));
+		assert_eq!(filetime_to_ticks(FILETIME::from(u64::MAX)), Err(co::ERROR::INVALID_PARAMETER));
+		assert_eq!(ticks_to_filetime(-1), Err(co::ERROR::INVALID_PARAMETER));
+	}
+}
@@ -0,0 +1,420 @@
+//! Command line splitting and quoting, without system calls.
+//!
+//! The splitting rules are the ones of the Microsoft C runtime, used by most
+//! programs to build their `argv` – including Rust's
+//! [`std::env::args`](std::env::args). Unlike
+//! [`CommandLineToArgv`](crate::CommandLineToArgv), these functions don't
+//! require shell32, and they can also perform the inverse operation, which is
+//! needed to build the command line of
+//! [`HPROCESS::CreateProcess`](crate::prelude::kernel_Hprocess::CreateProcess)
+//! or
+//! [`IExecAction::put_Arguments`](crate::prelude::taskschd_IExecAction::put_Arguments).
+
+use crate::co;
+use crate::decl::*;
+
+/// Splits a full command line, which starts with the program name, into its
+/// arguments, following the rules of the Microsoft C runtime:
+///
+/// * the program name ends at the first space or tab outside quotes, and
+///   backslashes have no special meaning in it;
+/// * other arguments are separated by spaces or tabs outside quotes;
+/// * `2n` backslashes followed by a quote produce `n` backslashes, and the
+///   quote starts or ends a quoted section;
+/// * `2n + 1` backslashes followed by a quote produce `n` backslashes and a
+///   literal quote;
+/// * two quotes inside a quoted section produce a literal quote;
+/// * backslashes not followed by a quote are literal.
+///
+/// The returned vector always has at least the program name, which may be
+/// empty.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let argv = w::cmd_line::split(r#""C:\Program Files\app.exe" /a "b c" d\"e"#);
+/// assert_eq!(argv, [r"C:\Program Files\app.exe", "/a", "b c", "d\"e"]);
+/// ```
+#[must_use]
+pub fn split(cmd_line: &str) -> Vec<String> {
+	let mut program = String::new();
+	let mut rest = "";
+	let mut in_quotes = false;
+
+	for (idx, ch) in cmd_line.char_indices() {
+		match ch {
+			'"' => in_quotes = !in_quotes,
+			' ' | '\t' if !in_quotes => {
+				rest = &cmd_line[idx + 1..];
+				break;
+			},
+			ch => program.push(ch),
+		}
+	}
+
+	let mut argv = vec![program];
+	argv.append(&mut split_args(rest));
+	argv
+}
+
+/// Splits a command line which contains only the arguments, without the
+/// program name, following the rules of the Microsoft C runtime described in
+/// [`cmd_line::split`](crate::cmd_line::split).
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let args = w::cmd_line::split_args(r#"a\\\"b "c d"" e"#);
+/// assert_eq!(args, [r#"a\"b"#, "c d\" e"]);
+/// ```
+#[must_use]
+pub fn split_args(args: &str) -> Vec<String> {
+	let mut chars = args.chars().peekable();
+	let mut argv = Vec::<String>::new();
+
+	loop {
+		while chars.next_if(|ch| *ch == ' ' || *ch == '\t').is_some() {}
+		if chars.peek().is_none() {
+			break;
+		}
+
+		let mut arg = String::new();
+		let mut in_quotes = false;
+		loop {
+			let mut num_backslashes = 0;
+			while chars.next_if_eq(&'\\').is_some() {
+				num_backslashes += 1;
+			}
+
+			match chars.peek().copied() {
+				Some('"') => {
+					arg.extend(std::iter::repeat_n('\\', num_backslashes / 2));
+					chars.next();
+					if num_backslashes % 2 == 1 {
+						arg.push('"'); // escaped quote
+					} else if in_quotes && chars.next_if_eq(&'"').is_some() {
+						arg.push('"'); // double quote inside quotes
+					} else {
+						in_quotes = !in_quotes;
+					}
+				},
+				next => {
+					arg.extend(std::iter::repeat_n('\\', num_backslashes));
+					match next {
+						None => break,
+						Some(' ' | '\t') if !in_quotes => break,
+						Some(ch) => {
+							arg.push(ch);
+							chars.next();
+						},
+					}
+				},
+			}
+		}
+		argv.push(arg);
+	}
+
+	argv
+}
+
+/// Quotes a single argument so that it's parsed back unchanged by
+/// [`cmd_line::split_args`](crate::cmd_line::split_args). Arguments without
+/// whitespace or quotes are returned as they are.
+///
+/// The result is not safe to pass through `cmd.exe`; for that, see
+/// [`cmd_line::escape_cmd`](crate::cmd_line::escape_cmd).
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// assert_eq!(w::cmd_line::quote_arg("abc"), "abc");
+/// assert_eq!(w::cmd_line::quote_arg(r"C:\My Dir\"), r#""C:\My Dir\\""#);
+/// assert_eq!(w::cmd_line::quote_arg(r#"say "hi""#), r#""say \"hi\"""#);
+/// ```
+#[must_use]
+pub fn quote_arg(arg: &str) -> String {
+	if !arg.is_empty() && !arg.contains([' ', '\t', '\n', '\x0b', '"']) {
+		return arg.to_owned();
+	}
+
+	let mut quoted = String::with_capacity(arg.len() + 2);
+	quoted.push('"');
+	let mut num_backslashes = 0;
+	for ch in arg.chars() {
+		match ch {
+			'\\' => num_backslashes += 1,
+			'"' => {
+				quoted.extend(std::iter::repeat_n('\\', num_backslashes * 2 + 1));
+				quoted.push('"');
+				num_backslashes = 0;
+			},
+			ch => {
+				quoted.extend(std::iter::repeat_n('\\', num_backslashes));
+				quoted.push(ch);
+				num_backslashes = 0;
+			},
+		}
+	}
+	quoted.extend(std::iter::repeat_n('\\', num_backslashes * 2));
+	quoted.push('"');
+	quoted
+}
+
+/// Builds a full command line from the program name and its arguments, so
+/// that [`cmd_line::split`](crate::cmd_line::split) returns them unchanged.
+///
+/// Returns
+/// [`ERROR::INVALID_PARAMETER`](crate::co::ERROR::INVALID_PARAMETER) if the
+/// slice is empty, or if the program name contains a quote, which cannot be
+/// represented.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let cmd = w::cmd_line::join(&[r"C:\Program Files\app.exe", "/open", "my file.txt"])?;
+/// assert_eq!(cmd, r#""C:\Program Files\app.exe" /open "my file.txt""#);
+/// # w::SysResult::Ok(())
+/// ```
+pub fn join(argv: &[impl AsRef<str>]) -> SysResult<String> {
+	let (program, args) = argv.split_first()
+		.ok_or(co::ERROR::INVALID_PARAMETER)?;
+	let program = program.as_ref();
+	if program.contains('"') {
+		return Err(co::ERROR::INVALID_PARAMETER);
+	}
+
+	let mut cmd_line = if program.is_empty() || program.contains([' ', '\t']) {
+		format!("\"{}\"", program)
+	} else {
+		program.to_owned()
+	};
+	if !args.is_empty() {
+		cmd_line.push(' ');
+		cmd_line.push_str(&join_args(args));
+	}
+	Ok(cmd_line)
+}
+
+/// Builds a command line which contains only the arguments, without the
+/// program name, quoting each one with
+/// [`cmd_line::quote_arg`](crate::cmd_line::quote_arg), so that
+/// [`cmd_line::split_args`](crate::cmd_line::split_args) returns them
+/// unchanged.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let args = w::cmd_line::join_args(&["/c", "", r"a\b c\"]);
+/// assert_eq!(args, r#"/c "" "a\b c\\""#);
+/// ```
+#[must_use]
+pub fn join_args(args: &[impl AsRef<str>]) -> String {
+	args.iter()
+		.map(|arg| quote_arg(arg.as_ref()))
+		.collect::<Vec<_>>()
+		.join(" ")
+}
+
+/// Escapes the `cmd.exe` metacharacters `( ) % ! ^ " < > & |` by prefixing
+/// each one with `^`, so the text is passed literally to the program being
+/// run.
+///
+/// Arguments must be quoted first, with
+/// [`cmd_line::quote_arg`](crate::cmd_line::quote_arg) or
+/// [`cmd_line::join`](crate::cmd_line::join), and then the whole command line
+/// escaped. Note that the escaping is undone once by each `cmd.exe` which
+/// parses the text, and `%` is still expanded inside batch files.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let cmd = w::cmd_line::join(&["findstr", "a&b", "file.txt"])?;
+/// let escaped = w::cmd_line::escape_cmd(&cmd);
+/// assert_eq!(escaped, "findstr a^&b file.txt");
+///
+/// let full = format!("cmd.exe /c {}", escaped);
+/// # w::SysResult::Ok(())
+/// ```
+#[must_use]
+pub fn escape_cmd(text: &str) -> String {
+	let mut escaped = String::with_capacity(text.len());
+	for ch in text.chars() {
+		if matches!(ch, '(' | ')' | '%' | '!' | '^' | '"' | '<' | '>' | '&' | '|') {
+			escaped.push('^');
+		}
+		escaped.push(ch);
+	}
+	escaped
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn split_args_rules() {
+		let cases: &[(&str, &[&str])] = &[
+			// whitespace
+			("", &[]),
+			(" \t  ", &[]),
+			("a b\tc", &["a", "b", "c"]),
+			("  a   b  ", &["a", "b"]),
+			("a\nb", &["a\nb"]),
+			// empty arguments
+			(r#""""#, &[""]),
+			(r#"a "" b"#, &["a", "", "b"]),
+			(r#""" """#, &["", ""]),
+			// quoted sections
+			(r#""a b""#, &["a b"]),
+			(r#"a"b c"d"#, &["ab cd"]),
+			(r#""a b"c d"#, &["a bc", "d"]),
+			(r#""a b"#, &["a b"]),
+			// two quotes inside quotes
+			(r#""a""b""#, &[r#"a"b"#]),
+			(r#""a"" b""#, &[r#"a" b"#]),
+			(r#""""""#, &[r#"""#]),
+			(r#"a""b"#, &["ab"]),
+			// backslashes not followed by a quote
+			(r"a\b", &[r"a\b"]),
+			(r"a\\b", &[r"a\\b"]),
+			(r#""a\\b c""#, &[r"a\\b c"]),
+			// 2n backslashes before a quote
+			(r#"a\\"b c""#, &[r"a\b c"]),
+			(r#"a\\\\"b c""#, &[r"a\\b c"]),
+			(r#""a b\\""#, &[r"a b\"]),
+			// 2n + 1 backslashes before a quote
+			(r#"\""#, &[r#"""#]),
+			(r#"a\"b c"#, &[r#"a"b"#, "c"]),
+			(r#"a\\\"b"#, &[r#"a\"b"#]),
+			(r#"a\\\\\"b"#, &[r#"a\\"b"#]),
+			(r#""a\" b""#, &[r#"a" b"#]),
+			// trailing backslashes
+			(r"a\", &[r"a\"]),
+			(r"a\\", &[r"a\\"]),
+			(r"a\ b\", &[r"a\", r"b\"]),
+			(r#""a\""#, &[r#"a""#]),
+		];
+
+		for (cmd_line, expected) in cases {
+			assert_eq!(split_args(cmd_line), *expected, "split_args({:?})", cmd_line);
+		}
+	}
+
+	#[test]
+	fn split_program_rules() {
+		let cases: &[(&str, &[&str])] = &[
+			("", &[""]),
+			("app.exe", &["app.exe"]),
+			("app.exe a b", &["app.exe", "a", "b"]),
+			("app.exe\ta", &["app.exe", "a"]),
+			("app.exe   a", &["app.exe", "a"]),
+			(" a", &["", "a"]),
+			(r#""" a"#, &["", "a"]),
+			(r#""C:\Program Files\app.exe" /a"#, &[r"C:\Program Files\app.exe", "/a"]),
+			(r#"C:\a\"b c"\d.exe /x"#, &[r"C:\a\b c\d.exe", "/x"]),
+			// backslashes are literal in the program name
+			(r#"app\" x"#, &[r"app\ x"]),
+			(r#""C:\a b\" x"#, &[r"C:\a b\", "x"]),
+			(r#"app.exe a\"b"#, &["app.exe", r#"a"b"#]),
+		];
+
+		for (cmd_line, expected) in cases {
+			assert_eq!(split(cmd_line), *expected, "split({:?})", cmd_line);
+		}
+	}
+
+	#[test]
+	fn quote_arg_rules() {
+		let cases: &[(&str, &str)] = &[
+			("abc", "abc"),
+			("", r#""""#),
+			("a b", r#""a b""#),
+			("a\tb", "\"a\tb\""),
+			("a\nb", "\"a\nb\""),
+			(r"a\b", r"a\b"),
+			(r"\", r"\"),
+			(r#"a"b"#, r#""a\"b""#),
+			(r#"a\"b"#, r#""a\\\"b""#),
+			(r"a b\", r#""a b\\""#),
+			(r"a b\\", r#""a b\\\\""#),
+			(r"a\ b", r#""a\ b""#),
+		];
+
+		for (arg, expected) in cases {
+			assert_eq!(quote_arg(arg), *expected, "quote_arg({:?})", arg);
+		}
+	}
+
+	#[test]
+	fn join_rules() {
+		assert_eq!(join(&[] as &[&str]), Err(co::ERROR::INVALID_PARAMETER));
+		assert_eq!(join(&[r#"a"b.exe"#]), Err(co::ERROR::INVALID_PARAMETER));
+		assert_eq!(join(&["app.exe"]), Ok("app.exe".to_owned()));
+		assert_eq!(join(&[""]), Ok(r#""""#.to_owned()));
+		assert_eq!(join(&["a b.exe", "x"]), Ok(r#""a b.exe" x"#.to_owned()));
+		assert_eq!(join(&["app.exe", ""]), Ok(r#"app.exe """#.to_owned()));
+		assert_eq!(join_args(&[] as &[&str]), "");
+	}
+
+	#[test]
+	fn escape_cmd_rules() {
+		let cases: &[(&str, &str)] = &[
+			("abc", "abc"),
+			("a&b", "a^&b"),
+			(r#""a b""#, r#"^"a b^""#),
+			("(%PATH%)", "^(^%PATH^%^)"),
+			("a|b<c>d^e!f", "a^|b^<c^>d^^e^!f"),
+		];
+
+		for (text, expected) in cases {
+			assert_eq!(escape_cmd(text), *expected, "escape_cmd({:?})", text);
+		}
+	}
+
+	/// All strings up to `max_len` chars made of `alphabet`.
+	fn all_strings(alphabet: &[char], max_len: usize) -> Vec<String> {
+		let mut all = vec![String::new()];
+		let mut prev = all.clone();
+		for _ in 0..max_len {
+			prev = prev.iter()
+				.flat_map(|s| alphabet.iter().map(move |ch| format!("{}{}", s, ch)))
+				.collect();
+			all.extend(prev.iter().cloned());
+		}
+		all
+	}
+
+	#[test]
+	fn quote_split_round_trip() {
+		let args = all_strings(&['a', ' ', '\t', '\\', '"'], 4);
+
+		for arg in args.iter() {
+			let joined = join_args(&[arg]);
+			assert_eq!(split_args(&joined), [arg.as_str()], "single {:?} -> {:?}", arg, joined);
+		}
+
+		for pair in args.windows(2) {
+			let joined = join_args(pair);
+			assert_eq!(split_args(&joined), pair, "pair {:?} -> {:?}", pair, joined);
+		}
+
+		let programs = all_strings(&['a', ' ', '\t', '\\'], 3);
+		for program in programs.iter() {
+			let argv = [program.as_str(), r#"x\"#, "", r#"a "b""#];
+			let cmd_line = join(&argv).unwrap();
+			assert_eq!(split(&cmd_line), argv, "program {:?} -> {:?}", program, cmd_line);
+		}
+	}
+}
@@ -0,0 +1,334 @@
+use crate::co;
+use crate::decl::*;
+
+/// The error code carried by a [`ContextError`](crate::ContextError).
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ErrorCode {
+	/// A system error code, from a [`SysResult`](crate::SysResult).
+	Sys(co::ERROR),
+	/// A COM error code, from an [`HrResult`](crate::HrResult).
+	#[cfg(feature = "ole")]
+	Hr(co::HRESULT),
+}
+
+impl std::fmt::Display for ErrorCode {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Self::Sys(err) => std::fmt::Display::fmt(err, f),
+			#[cfg(feature = "ole")]
+			Self::Hr(hr) => std::fmt::Display::fmt(hr, f),
+		}
+	}
+}
+
+impl ErrorCode {
+	/// Returns the system error code.
+	///
+	/// An [`HRESULT`](crate::co::HRESULT) is converted with
+	/// [`HRESULT::to_win32`](crate::co::HRESULT::to_win32); if it doesn't
+	/// carry a system error, returns
+	/// [`ERROR::GEN_FAILURE`](crate::co::ERROR::GEN_FAILURE).
+	#[must_use]
+	pub const fn to_sys_error(self) -> co::ERROR {
+		match self {
+			Self::Sys(err) => err,
+			#[cfg(feature = "ole")]
+			Self::Hr(hr) => match hr.to_win32() {
+				Some(err) => err,
+				None => co::ERROR::GEN_FAILURE,
+			},
+		}
+	}
+
+	/// Returns the COM error code.
+	///
+	/// An [`ERROR`](crate::co::ERROR) is converted with
+	/// [`ERROR::to_hresult`](crate::co::ERROR::to_hresult).
+	#[cfg(feature = "ole")]
+	#[must_use]
+	pub const fn to_hresult(self) -> co::HRESULT {
+		match self {
+			Self::Sys(err) => err.to_hresult(),
+			Self::Hr(hr) => hr,
+		}
+	}
+}
+
+/// An error code along with the name of the function which failed, its key
+/// arguments, and the error which caused it, if any.
+///
+/// This is an opt-in alternative to the bare [`ERROR`](crate::co::ERROR) and
+/// [`HRESULT`](crate::co::HRESULT) codes. It's usually created with the
+/// [`ResultContext`](crate::ResultContext) methods, or converted from these
+/// codes, so `?` works on a [`SysResult`](crate::SysResult) or
+/// [`HrResult`](crate::HrResult) in functions returning
+/// [`CtxResult`](crate::CtxResult). Its [`code`](crate::ContextError::code)
+/// can be converted back into these codes.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co, ResultContext};
+///
+/// fn open_file(path: &str) -> w::CtxResult<w::guard::CloseHandleGuard<w::HFILE>> {
+///     w::HFILE::CreateFile(
+///         path,
+///         co::GENERIC::READ,
+///         Some(co::FILE_SHARE::READ),
+///         None,
+///         co::DISPOSITION::OPEN_EXISTING,
+///         co::FILE_ATTRIBUTE::NORMAL,
+///         None,
+///         None,
+///         None,
+///     )
+///     .map(|(hfile, _)| hfile)
+///     .context("CreateFile")
+///     .with_arg("file_name", path)
+/// }
+///
+/// fn file_size(path: &str) -> w::CtxResult<u64> {
+///     let hfile = open_file(path)?;
+///     let size = hfile.GetFileSizeEx()?; // ERROR converted into ContextError
+///     Ok(size)
+/// }
+///
+/// fn load() -> w::SysResult<u64> {
+///     file_size("C:\\Temp\\test.txt")
+///         .map_err(|e| e.code().to_sys_error())
+/// }
+///
+/// if let Err(e) = open_file("C:\\Temp\\test.txt") {
+///     eprintln!("{}", e); // CreateFile(file_name: "C:\\Temp\\test.txt") failed: ...
+/// }
+/// ```
+#[derive(Debug)]
+pub struct ContextError {
+	code: ErrorCode,
+	function: &'static str,
+	args: Vec<(&'static str, String)>,
+	source: Option<Box<dyn std::error::Error + Send + Sync + 'static>>,
+}
+
+impl std::error::Error for ContextError {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		self.source.as_ref().map(|e| e.as_ref() as _)
+	}
+}
+
+impl std::fmt::Display for ContextError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		if !self.function.is_empty() {
+			write!(f, "{}(", self.function)?;
+			for (idx, (name, value)) in self.args.iter().enumerate() {
+				write!(f, "{}{}: {}", if idx == 0 { "" } else { ", " }, name, value)?;
+			}
+			write!(f, ") failed: ")?;
+		} else if !self.args.is_empty() {
+			for (name, value) in self.args.iter() {
+				write!(f, "{}: {}, ", name, value)?;
+			}
+		}
+		write!(f, "{}", self.code)
+	}
+}
+
+impl From<co::ERROR> for ContextError {
+	fn from(err: co::ERROR) -> Self {
+		Self::new(ErrorCode::Sys(err), "")
+	}
+}
+
+#[cfg(feature = "ole")]
+impl From<co::HRESULT> for ContextError {
+	fn from(hr: co::HRESULT) -> Self {
+		Self::new(ErrorCode::Hr(hr), "")
+	}
+}
+
+impl ContextError {
+	/// Creates a new `ContextError` with the given error code and name of the
+	/// function which failed.
+	#[must_use]
+	pub fn new(code: ErrorCode, function: &'static str) -> Self {
+		Self {
+			code,
+			function,
+			args: Vec::new(),
+			source: None,
+		}
+	}
+
+	/// Appends an argument of the function which failed, formatted with its
+	/// [`Debug`](std::fmt::Debug) implementation.
+	#[must_use]
+	pub fn arg(mut self, name: &'static str, value: impl std::fmt::Debug) -> Self {
+		self.args.push((name, format!("{:?}", value)));
+		self
+	}
+
+	/// Sets the error which caused this one, returned by
+	/// [`Error::source`](std::error::Error::source).
+	#[must_use]
+	pub fn caused_by(mut self,
+		source: impl Into<Box<dyn std::error::Error + Send + Sync + 'static>>,
+	) -> Self
+	{
+		self.source = Some(source.into());
+		self
+	}
+
+	/// Returns the error code.
+	///
+	/// When errors are chained with
+	/// [`ResultContext::context`](crate::ResultContext::context), all
+	/// of them carry the code of the innermost one.
+	#[must_use]
+	pub const fn code(&self) -> ErrorCode {
+		self.code
+	}
+
+	/// Returns the name of the function which failed, or an empty string if
+	/// not set.
+	#[must_use]
+	pub const fn function(&self) -> &'static str {
+		self.function
+	}
+
+	/// Returns the name and the formatted value of the arguments of the
+	/// function which failed.
+	#[must_use]
+	pub fn args(&self) -> &[(&'static str, String)] {
+		&self.args
+	}
+
+	/// Returns an iterator over this error and all its sources, outermost
+	/// first.
+	pub fn chain(&self) -> impl Iterator<Item = &(dyn std::error::Error + 'static)> {
+		std::iter::successors(
+			Some(self as &(dyn std::error::Error + 'static)),
+			|e| e.source(),
+		)
+	}
+}
+
+/// Adds context to the error of a [`SysResult`](crate::SysResult),
+/// [`HrResult`](crate::HrResult) or [`CtxResult`](crate::CtxResult),
+/// converting it into a [`ContextError`](crate::ContextError).
+///
+/// This trait is not in the [`prelude`](crate::prelude), so its methods don't
+/// clash with similar ones from other crates, like `anyhow::Context`; import it
+/// explicitly:
+///
+/// ```no_run
+/// use winsafe::ResultContext;
+/// ```
+pub trait ResultContext<T>: Sized {
+	/// Sets the name of the function which failed.
+	///
+	/// If the error already has a function name, it's wrapped as the source of
+	/// a new error, building a cause chain.
+	fn context(self, function: &'static str) -> CtxResult<T>;
+
+	/// Appends an argument of the function which failed, formatted with its
+	/// [`Debug`](std::fmt::Debug) implementation only if there's an error.
+	fn with_arg(self, name: &'static str, value: impl std::fmt::Debug) -> CtxResult<T>;
+}
+
+impl<T> ResultContext<T> for SysResult<T> {
+	fn context(self, function: &'static str) -> CtxResult<T> {
+		self.map_err(|err| ContextError::new(ErrorCode::Sys(err), function))
+	}
+
+	fn with_arg(self, name: &'static str, value: impl std::fmt::Debug) -> CtxResult<T> {
+		self.map_err(|err| ContextError::from(err).arg(name, value))
+	}
+}
+
+#[cfg(feature = "ole")]
+impl<T> ResultContext<T> for HrResult<T> {
+	fn context(self, function: &'static str) -> CtxResult<T> {
+		self.map_err(|hr| ContextError::new(ErrorCode::Hr(hr), function))
+	}
+
+	fn with_arg(self, name: &'static str, value: impl std::fmt::Debug) -> CtxResult<T> {
+		self.map_err(|hr| ContextError::from(hr).arg(name, value))
+	}
+}
+
+impl<T> ResultContext<T> for CtxResult<T> {
+	fn context(self, function: &'static str) -> CtxResult<T> {
+		self.map_err(|mut err| {
+			if err.function.is_empty() {
+				err.function = function;
+				err
+			} else {
+				ContextError::new(err.code, function).caused_by(err)
+			}
+		})
+	}
+
+	fn with_arg(self, name: &'static str, value: impl std::fmt::Debug) -> CtxResult<T> {
+		self.map_err(|err| err.arg(name, value))
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	fn fails(err: co::ERROR) -> SysResult<()> {
+		Err(err)
+	}
+
+	fn question_mark(err: co::ERROR) -> CtxResult<()> {
+		fails(err)?;
+		Ok(())
+	}
+
+	#[test]
+	fn from_sys_error() {
+		let err = question_mark(co::ERROR::FILE_NOT_FOUND).unwrap_err();
+		assert_eq!(err.code(), ErrorCode::Sys(co::ERROR::FILE_NOT_FOUND));
+		assert_eq!(err.function(), "");
+		assert_eq!(err.code().to_sys_error(), co::ERROR::FILE_NOT_FOUND);
+	}
+
+	#[test]
+	fn context_chain() {
+		let err = fails(co::ERROR::ACCESS_DENIED)
+			.context("CreateFile")
+			.with_arg("file_name", "a.txt")
+			.context("load")
+			.unwrap_err();
+		assert_eq!(err.function(), "load");
+		assert_eq!(err.code(), ErrorCode::Sys(co::ERROR::ACCESS_DENIED));
+		assert_eq!(err.chain().count(), 2);
+
+		let inner = std::error::Error::source(&err).unwrap().downcast_ref::<ContextError>().unwrap();
+		assert_eq!(inner.function(), "CreateFile");
+		assert_eq!(inner.args(), [("file_name", "\"a.txt\"".to_owned())]);
+		assert_eq!(inner.code(), err.code());
+
+		let err = fails(co::ERROR::ACCESS_DENIED).with_arg("n", 1).unwrap_err();
+		assert_eq!((err.function(), err.args()), ("", &[("n", "1".to_owned())][..]));
+	}
+
+	#[cfg(feature = "ole")]
+	#[test]
+	fn from_hresult() {
+		fn hr_fails() -> HrResult<()> {
+			Err(co::HRESULT::E_NOTIMPL)
+		}
+		fn question_mark() -> CtxResult<()> {
+			hr_fails()?;
+			Ok(())
+		}
+
+		let err = question_mark().unwrap_err();
+		assert_eq!(err.code(), ErrorCode::Hr(co::HRESULT::E_NOTIMPL));
+		assert_eq!(err.code().to_sys_error(), co::ERROR::GEN_FAILURE); // not a Win32 error
+		assert_eq!(ErrorCode::Sys(co::ERROR::ACCESS_DENIED).to_hresult(),
+			co::ERROR::ACCESS_DENIED.to_hresult());
+	}
+}
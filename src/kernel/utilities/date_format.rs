@@ -0,0 +1,439 @@
+use crate::co;
+use crate::decl::*;
+use crate::kernel::utilities::calendar::*;
+
+const MONTHS: [&str; 12] = ["January", "February", "March", "April", "May",
+	"June", "July", "August", "September", "October", "November", "December"];
+const DAYS: [&str; 7] = ["Sunday", "Monday", "Tuesday", "Wednesday",
+	"Thursday", "Friday", "Saturday"];
+const ERA: &str = "A.D.";
+
+/// A piece of a `GetDateFormat`/`GetTimeFormat` picture string.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Token<'a> {
+	/// Format character and how many times it's repeated.
+	Field(char, usize),
+	/// Literal text, with quotes already removed.
+	Literal(&'a str),
+	/// A quote character, from a doubled quote.
+	Quote,
+}
+
+fn tokenize(picture: &str) -> SysResult<Vec<Token<'_>>> {
+	let mut tokens = Vec::new();
+	let mut rest = picture;
+	let mut quoted = false;
+	while let Some(ch) = rest.chars().next() {
+		if ch == '\'' {
+			if rest[1..].starts_with('\'') { // also inside quotes, like 'o''clock'
+				tokens.push(Token::Quote);
+				rest = &rest[2..];
+			} else {
+				quoted = !quoted;
+				rest = &rest[1..];
+			}
+		} else if quoted {
+			let len = rest.find('\'')
+				.ok_or(co::ERROR::INVALID_PARAMETER)?; // unterminated quote
+			tokens.push(Token::Literal(&rest[..len]));
+			rest = &rest[len..];
+		} else if "dMygHhmst".contains(ch) {
+			let count = rest.chars().take_while(|c| *c == ch).count();
+			tokens.push(Token::Field(ch, count));
+			rest = &rest[count..];
+		} else {
+			let len = rest.find(|c: char| c == '\'' || "dMygHhmst".contains(c))
+				.unwrap_or(rest.len());
+			tokens.push(Token::Literal(&rest[..len]));
+			rest = &rest[len..];
+		}
+	}
+	if quoted {
+		return Err(co::ERROR::INVALID_PARAMETER); // unterminated quote
+	}
+	Ok(tokens)
+}
+
+/// Formats the date and time with a picture string, using invariant English
+/// names.
+pub(in crate::kernel) fn format_picture(st: &SYSTEMTIME, picture: &str) -> SysResult<String> {
+	let (days, _) = systemtime_parts(st, 1601, MAX_YEAR)?;
+	let dow = day_of_week(days) as usize;
+	let hour12 = if st.wHour % 12 == 0 { 12 } else { st.wHour % 12 };
+	let pm = st.wHour >= 12;
+
+	let mut out = String::with_capacity(picture.len() * 2);
+	for token in tokenize(picture)? {
+		match token {
+			Token::Literal(s) => out.push_str(s),
+			Token::Quote => out.push('\''),
+			Token::Field(ch, count) => {
+				let s = match (ch, count) {
+					('d', 1) => st.wDay.to_string(),
+					('d', 2) => format!("{:02}", st.wDay),
+					('d', 3) => DAYS[dow][..3].to_owned(),
+					('d', _) => DAYS[dow].to_owned(),
+					('M', 1) => st.wMonth.to_string(),
+					('M', 2) => format!("{:02}", st.wMonth),
+					('M', 3) => MONTHS[st.wMonth as usize - 1][..3].to_owned(),
+					('M', _) => MONTHS[st.wMonth as usize - 1].to_owned(),
+					('y', 1) => (st.wYear % 100).to_string(),
+					('y', 2) => format!("{:02}", st.wYear % 100),
+					('y', _) => format!("{:04}", st.wYear),
+					('g', _) => ERA.to_owned(),
+					('h', 1) => hour12.to_string(),
+					('h', _) => format!("{:02}", hour12),
+					('H', 1) => st.wHour.to_string(),
+					('H', _) => format!("{:02}", st.wHour),
+					('m', 1) => st.wMinute.to_string(),
+					('m', _) => format!("{:02}", st.wMinute),
+					('s', 1) => st.wSecond.to_string(),
+					('s', _) => format!("{:02}", st.wSecond),
+					('t', 1) => (if pm { "P" } else { "A" }).to_owned(),
+					(_, _) => (if pm { "PM" } else { "AM" }).to_owned(),
+				};
+				out.push_str(&s);
+			},
+		}
+	}
+	Ok(out)
+}
+
+/// Consumes up to `max` ASCII digits, requiring at least `min`.
+fn take_number(text: &mut &str, min: usize, max: usize) -> SysResult<u32> {
+	let len = text.bytes().take(max).take_while(|b| b.is_ascii_digit()).count();
+	if len < min {
+		return Err(co::ERROR::INVALID_DATA);
+	}
+	let n = text[..len].parse().map_err(|_| co::ERROR::INVALID_DATA)?;
+	*text = &text[len..];
+	Ok(n)
+}
+
+/// Consumes the longest of the names which matches, case-insensitively,
+/// returning its index.
+fn take_name<'a>(text: &mut &str, names: impl Iterator<Item = &'a str>) -> SysResult<usize> {
+	let (idx, len) = names.enumerate()
+		.filter(|(_, name)| text.get(..name.len())
+			.is_some_and(|t| t.eq_ignore_ascii_case(name)))
+		.map(|(idx, name)| (idx, name.len()))
+		.max_by_key(|(_, len)| *len)
+		.ok_or(co::ERROR::INVALID_DATA)?;
+	*text = &text[len..];
+	Ok(idx)
+}
+
+/// Parses a date and time with a picture string, using invariant English
+/// names.
+pub(in crate::kernel) fn parse_picture(text: &str, picture: &str) -> SysResult<SYSTEMTIME> {
+	let mut st = SYSTEMTIME::default();
+	let mut text = text;
+	let (mut hour12, mut pm, mut dow) = (None, None, None);
+	let (mut has_year, mut has_month, mut has_day) = (false, false, false);
+
+	for token in tokenize(picture).map_err(|_| co::ERROR::INVALID_PARAMETER)? {
+		match token {
+			Token::Literal(s) => text = text.strip_prefix(s).ok_or(co::ERROR::INVALID_DATA)?,
+			Token::Quote => text = text.strip_prefix('\'').ok_or(co::ERROR::INVALID_DATA)?,
+			Token::Field(ch, count) => match (ch, count) {
+				('d', 1 | 2) => {
+					st.wDay = take_number(&mut text, count, 2)? as _;
+					has_day = true;
+				},
+				('d', 3) => dow = Some(take_name(&mut text, DAYS.iter().map(|d| &d[..3]))?),
+				('d', _) => dow = Some(take_name(&mut text, DAYS.iter().copied())?),
+				('M', 1 | 2) => {
+					st.wMonth = take_number(&mut text, count, 2)? as _;
+					has_month = true;
+				},
+				('M', 3) => {
+					st.wMonth = take_name(&mut text, MONTHS.iter().map(|m| &m[..3]))? as u16 + 1;
+					has_month = true;
+				},
+				('M', _) => {
+					st.wMonth = take_name(&mut text, MONTHS.iter().copied())? as u16 + 1;
+					has_month = true;
+				},
+				('y', 1 | 2) => {
+					let yy = take_number(&mut text, count, 2)? as u16;
+					st.wYear = if yy < 50 { 2000 + yy } else { 1900 + yy };
+					has_year = true;
+				},
+				('y', _) => {
+					st.wYear = take_number(&mut text, 4, 5)? as _;
+					has_year = true;
+				},
+				('g', _) => { take_name(&mut text, [ERA, "AD"].into_iter())?; },
+				('h', _) => hour12 = Some(take_number(&mut text, count.min(2), 2)? as u16),
+				('H', _) => st.wHour = take_number(&mut text, count.min(2), 2)? as _,
+				('m', _) => st.wMinute = take_number(&mut text, count.min(2), 2)? as _,
+				('s', _) => st.wSecond = take_number(&mut text, count.min(2), 2)? as _,
+				('t', 1) => pm = Some(take_name(&mut text, ["A", "P"].into_iter())? == 1),
+				(_, _) => pm = Some(take_name(&mut text, ["AM", "PM"].into_iter())? == 1),
+			},
+		}
+	}
+	if !text.is_empty() {
+		return Err(co::ERROR::INVALID_DATA);
+	}
+
+	if let Some(h) = hour12 {
+		if !(1..=12).contains(&h) {
+			return Err(co::ERROR::INVALID_DATA);
+		}
+		st.wHour = h % 12 + if pm == Some(true) { 12 } else { 0 };
+	} else if let Some(pm) = pm {
+		if pm != (st.wHour >= 12) {
+			return Err(co::ERROR::INVALID_DATA);
+		}
+	}
+	if st.wHour > 23 || st.wMinute > 59 || st.wSecond > 59 {
+		return Err(co::ERROR::INVALID_DATA);
+	}
+
+	if has_year && has_month && has_day {
+		let (days, _) = systemtime_parts(&st, 1601, MAX_YEAR)
+			.map_err(|_| co::ERROR::INVALID_DATA)?;
+		st.wDayOfWeek = day_of_week(days) as _;
+		if dow.is_some_and(|dow| dow != st.wDayOfWeek as usize) {
+			return Err(co::ERROR::INVALID_DATA);
+		}
+	} else if (has_month && !(1..=12).contains(&st.wMonth))
+		|| (has_day && !(1..=31).contains(&st.wDay))
+	{
+		return Err(co::ERROR::INVALID_DATA);
+	} else if let Some(dow) = dow {
+		st.wDayOfWeek = dow as _;
+	}
+	Ok(st)
+}
+
+/// Formats the date and time in ISO 8601 extended format, with an optional
+/// UTC offset in minutes.
+#[must_use]
+pub(in crate::kernel) fn format_iso8601(st: &SYSTEMTIME, utc_offset: Option<i32>) -> String {
+	let mut out = format!("{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+		st.wYear, st.wMonth, st.wDay, st.wHour, st.wMinute, st.wSecond);
+	if st.wMilliseconds != 0 {
+		out.push_str(&format!(".{:03}", st.wMilliseconds));
+	}
+	match utc_offset {
+		None => {},
+		Some(0) => out.push('Z'),
+		Some(off) => out.push_str(&format!("{}{:02}:{:02}",
+			if off < 0 { '-' } else { '+' }, off.abs() / 60, off.abs() % 60)),
+	}
+	out
+}
+
+/// Parses a date, or a date and time, in ISO 8601 extended format, returning
+/// the UTC offset in minutes, if present.
+pub(in crate::kernel) fn parse_iso8601(text: &str) -> SysResult<(SYSTEMTIME, Option<i32>)> {
+	let mut st = SYSTEMTIME::default();
+	let mut text = text;
+	let expect = |text: &mut &str, ch: char| -> SysResult<()> {
+		*text = text.strip_prefix(ch).ok_or(co::ERROR::INVALID_DATA)?;
+		Ok(())
+	};
+
+	st.wYear = take_number(&mut text, 4, 4)? as _;
+	expect(&mut text, '-')?;
+	st.wMonth = take_number(&mut text, 2, 2)? as _;
+	expect(&mut text, '-')?;
+	st.wDay = take_number(&mut text, 2, 2)? as _;
+
+	let mut utc_offset = None;
+	if let Some(ch) = text.chars().next() {
+		if !"Tt ".contains(ch) {
+			return Err(co::ERROR::INVALID_DATA);
+		}
+		text = &text[1..];
+		st.wHour = take_number(&mut text, 2, 2)? as _;
+		expect(&mut text, ':')?;
+		st.wMinute = take_number(&mut text, 2, 2)? as _;
+		if text.starts_with(':') {
+			text = &text[1..];
+			st.wSecond = take_number(&mut text, 2, 2)? as _;
+			if text.starts_with(['.', ',']) {
+				text = &text[1..];
+				let digits = text.bytes().take_while(|b| b.is_ascii_digit()).count();
+				if digits == 0 {
+					return Err(co::ERROR::INVALID_DATA);
+				}
+				let ms = format!("{:0<3}", &text[..digits.min(3)]); // extra digits are truncated
+				st.wMilliseconds = ms.parse().map_err(|_| co::ERROR::INVALID_DATA)?;
+				text = &text[digits..];
+			}
+		}
+
+		if text.eq_ignore_ascii_case("z") {
+			utc_offset = Some(0);
+			text = "";
+		} else if let Some(sign) = text.chars().next().filter(|c| *c == '+' || *c == '-') {
+			text = &text[1..];
+			let hh = take_number(&mut text, 2, 2)? as i32;
+			if text.starts_with(':') {
+				text = &text[1..];
+			}
+			let mm = if text.is_empty() { 0 } else { take_number(&mut text, 2, 2)? as i32 };
+			if hh > 23 || mm > 59 {
+				return Err(co::ERROR::INVALID_DATA);
+			}
+			utc_offset = Some(if sign == '-' { -(hh * 60 + mm) } else { hh * 60 + mm });
+		}
+	}
+	if !text.is_empty() {
+		return Err(co::ERROR::INVALID_DATA);
+	}
+
+	let (days, _) = systemtime_parts(&st, 1601, MAX_YEAR)
+		.map_err(|_| co::ERROR::INVALID_DATA)?;
+	st.wDayOfWeek = day_of_week(days) as _;
+	Ok((st, utc_offset))
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	/// Tuesday, March 5, 2024, 14:07:09.045.
+	const ST: SYSTEMTIME = SYSTEMTIME {
+		wYear: 2024, wMonth: 3, wDayOfWeek: 2, wDay: 5,
+		wHour: 14, wMinute: 7, wSecond: 9, wMilliseconds: 45,
+	};
+
+	const fn date(year: u16, month: u16, day: u16, dow: u16) -> SYSTEMTIME {
+		SYSTEMTIME { wYear: year, wMonth: month, wDay: day, wDayOfWeek: dow,
+			wHour: 0, wMinute: 0, wSecond: 0, wMilliseconds: 0 }
+	}
+
+	#[test]
+	fn format() {
+		let cases: &[(&str, &str)] = &[
+			("yyyy-MM-dd", "2024-03-05"),
+			("d/M/yy", "5/3/24"),
+			("ddd, dd MMM yyyy", "Tue, 05 Mar 2024"),
+			("dddd, MMMM d, y gg", "Tuesday, March 5, 24 A.D."),
+			("hh:mm:ss tt", "02:07:09 PM"),
+			("h:m:s t", "2:7:9 P"),
+			("HH'h'mm", "14h07"),
+			("h 'o''clock'", "2 o'clock"),
+			("''H''", "'14'"),
+			("'d M y' d", "d M y 5"),
+			("'Time:' H.mm", "Time: 14.07"),
+			("Time", "Ti7e"), // unquoted letters are fields
+			("", ""),
+		];
+		for (picture, text) in cases.iter() {
+			assert_eq!(format_picture(&ST, picture).as_deref(), Ok(*text), "{:?}", picture);
+		}
+
+		let midnight = date(2024, 3, 5, 2);
+		assert_eq!(format_picture(&midnight, "h:mm tt").as_deref(), Ok("12:00 AM"));
+		assert_eq!(format_picture(&SYSTEMTIME { wHour: 12, ..midnight }, "h tt").as_deref(), Ok("12 PM"));
+		assert_eq!(format_picture(&ST, "'unterminated"), Err(co::ERROR::INVALID_PARAMETER));
+		assert_eq!(format_picture(&ST, "d'"), Err(co::ERROR::INVALID_PARAMETER));
+		assert_eq!(format_picture(&SYSTEMTIME { wMonth: 13, ..ST }, "d"), Err(co::ERROR::INVALID_PARAMETER));
+	}
+
+	#[test]
+	fn parse() {
+		let cases: &[(&str, &str, SYSTEMTIME)] = &[
+			("2024-03-05", "yyyy-MM-dd", date(2024, 3, 5, 2)),
+			("Tue, 5 Mar 24", "ddd, d MMM yy", date(2024, 3, 5, 2)),
+			("tuesday, MARCH 05, 2024", "dddd, MMMM dd, yyyy", date(2024, 3, 5, 2)),
+			("5/3/99", "d/M/yy", date(1999, 3, 5, 5)),
+			("5/3/49", "d/M/yy", date(2049, 3, 5, 5)),
+			("5 March", "d MMMM", date(0, 3, 5, 0)),
+			("Mon", "ddd", date(0, 0, 0, 1)),
+			("2024-03-05 A.D.", "yyyy-MM-dd g", date(2024, 3, 5, 2)),
+			("14:07:09", "HH:mm:ss", SYSTEMTIME { wHour: 14, wMinute: 7, wSecond: 9, ..Default::default() }),
+			("2:07 PM", "h:mm tt", SYSTEMTIME { wHour: 14, wMinute: 7, ..Default::default() }),
+			("12:30 am", "h:mm tt", SYSTEMTIME { wMinute: 30, ..Default::default() }),
+			("12 P", "h t", SYSTEMTIME { wHour: 12, ..Default::default() }),
+			("14 PM", "H tt", SYSTEMTIME { wHour: 14, ..Default::default() }),
+			("2 o'clock", "h 'o''clock'", SYSTEMTIME { wHour: 2, ..Default::default() }),
+		];
+		for (text, picture, st) in cases.iter() {
+			assert_eq!(parse_picture(text, picture), Ok(*st), "{:?} {:?}", text, picture);
+		}
+
+		let malformed: &[(&str, &str)] = &[
+			("2024-03-05", "yyyy/MM/dd"),
+			("2024-03-05 ", "yyyy-MM-dd"),
+			("2023-02-29", "yyyy-MM-dd"),
+			("1600-01-01", "yyyy-MM-dd"),
+			("Wed, 5 Mar 24", "ddd, d MMM yy"), // wrong day of the week
+			("5 Foo", "d MMM"),
+			("13", "M"),
+			("32", "d"),
+			("5", "dd"),
+			("13:00", "h:mm"),
+			("0:00 AM", "h:mm tt"),
+			("14:00 AM", "HH:mm tt"),
+			("24:00", "HH:mm"),
+			("12:60", "HH:mm"),
+			("x", ""),
+		];
+		for (text, picture) in malformed.iter() {
+			assert_eq!(parse_picture(text, picture), Err(co::ERROR::INVALID_DATA), "{:?} {:?}", text, picture);
+		}
+		assert_eq!(parse_picture("5", "'d"), Err(co::ERROR::INVALID_PARAMETER));
+
+		for picture in ["yyyy-MM-dd HH:mm:ss", "dddd, MMMM d, yyyy h:mm:ss tt", "ddd dd MMM yy H'h'mm's's"] {
+			let text = format_picture(&ST, picture).unwrap();
+			assert_eq!(parse_picture(&text, picture), Ok(SYSTEMTIME { wMilliseconds: 0, ..ST }), "{:?}", picture);
+		}
+	}
+
+	#[test]
+	fn iso8601() {
+		let cases: &[(SYSTEMTIME, Option<i32>, &str)] = &[
+			(ST, None, "2024-03-05T14:07:09.045"),
+			(ST, Some(0), "2024-03-05T14:07:09.045Z"),
+			(ST, Some(-300), "2024-03-05T14:07:09.045-05:00"),
+			(ST, Some(330), "2024-03-05T14:07:09.045+05:30"),
+			(SYSTEMTIME { wMilliseconds: 0, ..ST }, Some(60), "2024-03-05T14:07:09+01:00"),
+			(date(1601, 1, 1, 1), None, "1601-01-01T00:00:00"),
+		];
+		for (st, offset, text) in cases.iter() {
+			assert_eq!(format_iso8601(st, *offset), *text, "{}", st);
+			assert_eq!(parse_iso8601(text), Ok((*st, *offset)), "{:?}", text);
+		}
+
+		let parsed: &[(&str, SYSTEMTIME, Option<i32>)] = &[
+			("2024-03-05", date(2024, 3, 5, 2), None),
+			("2024-03-05 14:07", SYSTEMTIME { wHour: 14, wMinute: 7, ..date(2024, 3, 5, 2) }, None),
+			("2024-03-05t14:07:09,5z", SYSTEMTIME { wMilliseconds: 500, ..ST }, Some(0)),
+			("2024-03-05T14:07:09.0456789", ST, None),
+			("2024-03-05T14:07:09.045+0530", ST, Some(330)),
+			("2024-03-05T14:07:09.045-05", ST, Some(-300)),
+		];
+		for (text, st, offset) in parsed.iter() {
+			assert_eq!(parse_iso8601(text), Ok((*st, *offset)), "{:?}", text);
+		}
+
+		let malformed = [
+			"",
+			"2024",
+			"2024-3-05",
+			"24-03-05",
+			"2024-03-05T",
+			"2024-03-05T14",
+			"2024-03-05T14:7",
+			"2024-03-05X14:07",
+			"2024-03-05T14:07:09.",
+			"2024-03-05T14:07:09Zjunk",
+			"2024-03-05T14:07+24:00",
+			"2024-03-05T14:07+05:60",
+			"2024-03-05T14:07+5",
+			"2024-03-05T24:00",
+			"2024-02-30",
+			"1600-12-31",
+			"2024-03-05é",
+		];
+		for text in malformed.iter() {
+			assert_eq!(parse_iso8601(text), Err(co::ERROR::INVALID_DATA), "{:?}", text);
+		}
+	}
+}
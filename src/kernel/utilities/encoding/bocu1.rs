@@ -0,0 +1,211 @@
+//! [BOCU-1](https://www.unicode.org/notes/tn6/) decoder and encoder, following
+//! the reference implementation.
+
+use crate::decl::*;
+use crate::kernel::utilities::encoding::codecs::*;
+
+const ASCII_PREV: i32 = 0x40;
+const MIN: i32 = 0x21;
+const MIDDLE: i32 = 0x90;
+const RESET: u8 = 0xff;
+
+const TRAIL_CONTROLS_COUNT: i32 = 20;
+const TRAIL_BYTE_OFFSET: i32 = MIN - TRAIL_CONTROLS_COUNT;
+const TRAIL_COUNT: i32 = (0xff - MIN + 1) + TRAIL_CONTROLS_COUNT;
+
+const SINGLE: i32 = 64;
+const LEAD_2: i32 = 43;
+const LEAD_3: i32 = 3;
+
+const REACH_POS_1: i32 = SINGLE - 1;
+const REACH_NEG_1: i32 = -SINGLE;
+const REACH_POS_2: i32 = REACH_POS_1 + LEAD_2 * TRAIL_COUNT;
+const REACH_NEG_2: i32 = REACH_NEG_1 - LEAD_2 * TRAIL_COUNT;
+const REACH_POS_3: i32 = REACH_POS_2 + LEAD_3 * TRAIL_COUNT * TRAIL_COUNT;
+const REACH_NEG_3: i32 = REACH_NEG_2 - LEAD_3 * TRAIL_COUNT * TRAIL_COUNT;
+
+const START_POS_2: i32 = MIDDLE + REACH_POS_1 + 1;
+const START_POS_3: i32 = START_POS_2 + LEAD_2;
+const START_POS_4: i32 = START_POS_3 + LEAD_3;
+const START_NEG_2: i32 = MIDDLE + REACH_NEG_1;
+const START_NEG_3: i32 = START_NEG_2 - LEAD_2;
+const START_NEG_4: i32 = START_NEG_3 - LEAD_3;
+
+/// C0 controls used as trail bytes, so the trail byte range is contiguous.
+const TRAIL_CONTROLS: [u8; TRAIL_CONTROLS_COUNT as usize] = [
+	0x01, 0x02, 0x03, 0x04, 0x05, 0x06,
+	0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19,
+	0x1c, 0x1d, 0x1e, 0x1f,
+];
+
+/// Computes the new base code point after the given one.
+#[must_use]
+const fn prev_of(cp: i32) -> i32 {
+	match cp {
+		0x3040..=0x309f => 0x3070, // Hiragana is not 128-aligned
+		0x4e00..=0x9fa5 => 0x4e00 - REACH_NEG_2, // CJK Unihan
+		0xac00..=0xd7a3 => (0xd7a3 + 0xac00) / 2, // Korean Hangul
+		_ => (cp & !0x7f) + ASCII_PREV, // mostly small scripts
+	}
+}
+
+#[must_use]
+fn trail_to_byte(t: i32) -> u8 {
+	if t >= TRAIL_CONTROLS_COUNT {
+		(t + TRAIL_BYTE_OFFSET) as _
+	} else {
+		TRAIL_CONTROLS[t as usize]
+	}
+}
+
+#[must_use]
+fn byte_to_trail(b: u8) -> Option<i32> {
+	if b as i32 >= MIN {
+		Some(b as i32 - TRAIL_BYTE_OFFSET)
+	} else {
+		TRAIL_CONTROLS.iter()
+			.position(|ctrl| *ctrl == b)
+			.map(|t| t as _)
+	}
+}
+
+pub(in crate::kernel::utilities::encoding) fn decode(
+	data: &[u8],
+	strict: bool,
+) -> SysResult<String>
+{
+	let mut s = String::with_capacity(data.len());
+	let mut prev = ASCII_PREV;
+	let mut count = 0; // trail bytes still expected
+	let mut diff = 0;
+
+	for b in data.iter().copied() {
+		if count == 0 { // lead byte
+			let bi = b as i32;
+			if b <= 0x20 {
+				if b != 0x20 {
+					prev = ASCII_PREV; // C0 controls reset the state, space doesn't
+				}
+				s.push(b as char);
+			} else if (START_NEG_2..START_POS_2).contains(&bi) {
+				let cp = prev + (bi - MIDDLE);
+				prev = prev_of(cp);
+				s.push(char::from_u32(cp as _).map_or_else(|| invalid(strict), Ok)?);
+			} else if b == RESET {
+				prev = ASCII_PREV;
+			} else {
+				(diff, count) = if bi >= START_NEG_2 { // positive difference
+					if bi < START_POS_3 {
+						((bi - START_POS_2) * TRAIL_COUNT + REACH_POS_1 + 1, 1)
+					} else if bi < START_POS_4 {
+						((bi - START_POS_3) * TRAIL_COUNT * TRAIL_COUNT + REACH_POS_2 + 1, 2)
+					} else {
+						(REACH_POS_3 + 1, 3)
+					}
+				} else if bi >= START_NEG_3 { // negative difference
+					((bi - START_NEG_2) * TRAIL_COUNT + REACH_NEG_1, 1)
+				} else if bi > MIN {
+					((bi - START_NEG_3) * TRAIL_COUNT * TRAIL_COUNT + REACH_NEG_2, 2)
+				} else {
+					(-TRAIL_COUNT * TRAIL_COUNT * TRAIL_COUNT + REACH_NEG_3, 3)
+				};
+			}
+		} else { // trail byte
+			let t = match byte_to_trail(b) {
+				Some(t) => t,
+				None => {
+					prev = ASCII_PREV;
+					count = 0;
+					s.push(invalid(strict)?);
+					continue;
+				},
+			};
+
+			match count {
+				1 => {
+					count = 0;
+					let cp = prev + diff + t;
+					match char::from_u32(cp as _).filter(|_| cp >= 0) {
+						Some(ch) => {
+							prev = prev_of(cp);
+							s.push(ch);
+						},
+						None => {
+							prev = ASCII_PREV;
+							s.push(invalid(strict)?);
+						},
+					}
+				},
+				2 => {
+					diff += t * TRAIL_COUNT;
+					count = 1;
+				},
+				_ => {
+					diff += t * TRAIL_COUNT * TRAIL_COUNT;
+					count = 2;
+				},
+			}
+		}
+	}
+
+	if count != 0 {
+		s.push(invalid(strict)?); // truncated sequence
+	}
+	Ok(s)
+}
+
+/// Writes the bytes of a difference between code points.
+fn pack_diff(mut diff: i32, out: &mut Vec<u8>) {
+	let (lead, count) = if diff >= REACH_NEG_1 {
+		if diff <= REACH_POS_1 {
+			out.push((MIDDLE + diff) as _); // single byte
+			return;
+		} else if diff <= REACH_POS_2 {
+			diff -= REACH_POS_1 + 1;
+			(START_POS_2, 1)
+		} else if diff <= REACH_POS_3 {
+			diff -= REACH_POS_2 + 1;
+			(START_POS_3, 2)
+		} else {
+			diff -= REACH_POS_3 + 1;
+			(START_POS_4, 3)
+		}
+	} else if diff >= REACH_NEG_2 {
+		diff -= REACH_NEG_1;
+		(START_NEG_2, 1)
+	} else if diff >= REACH_NEG_3 {
+		diff -= REACH_NEG_2;
+		(START_NEG_3, 2)
+	} else {
+		diff -= REACH_NEG_3;
+		(START_NEG_4, 3)
+	};
+
+	let mut trails = [0u8; 3];
+	for trail in trails[..count].iter_mut().rev() { // like digits in itoa()
+		*trail = trail_to_byte(diff.rem_euclid(TRAIL_COUNT));
+		diff = diff.div_euclid(TRAIL_COUNT);
+	}
+	out.push((lead + diff) as _);
+	out.extend_from_slice(&trails[..count]);
+}
+
+pub(in crate::kernel::utilities::encoding) fn encode(s: &str) -> Vec<u8> {
+	let mut out = Vec::with_capacity(s.len());
+	let mut prev = ASCII_PREV;
+
+	for ch in s.chars() {
+		let cp = ch as i32;
+		if cp <= 0x20 {
+			if cp != 0x20 {
+				prev = ASCII_PREV; // C0 controls reset the state, space doesn't
+			}
+			out.push(cp as _);
+		} else {
+			pack_diff(cp - prev, &mut out);
+			prev = prev_of(cp);
+		}
+	}
+
+	out
+}
@@ -0,0 +1,167 @@
+//! Decoders and encoders of the single-byte and UTF encodings.
+
+use crate::co;
+use crate::decl::*;
+
+/// Returns the replacement character, or an error if in strict mode.
+pub(in crate::kernel::utilities::encoding) fn invalid(strict: bool) -> SysResult<char> {
+	if strict {
+		Err(co::ERROR::NO_UNICODE_TRANSLATION)
+	} else {
+		Ok(char::REPLACEMENT_CHARACTER)
+	}
+}
+
+/// Returns the substitution byte, or an error if in strict mode.
+fn unmappable(strict: bool) -> SysResult<u8> {
+	if strict {
+		Err(co::ERROR::NO_UNICODE_TRANSLATION)
+	} else {
+		Ok(b'?')
+	}
+}
+
+pub(in crate::kernel::utilities::encoding) fn decode_ansi(
+	data: &[u8],
+	strict: bool,
+) -> SysResult<String>
+{
+	data.iter()
+		.map(|b| if *b < 0x80 { Ok(*b as char) } else { invalid(strict) })
+		.collect()
+}
+
+pub(in crate::kernel::utilities::encoding) fn encode_ansi(
+	s: &str,
+	strict: bool,
+) -> SysResult<Vec<u8>>
+{
+	s.chars()
+		.map(|ch| if ch.is_ascii() { Ok(ch as u8) } else { unmappable(strict) })
+		.collect()
+}
+
+/// Characters of the 0x80-0x9f range of Windows-1252. The 5 undefined bytes
+/// are mapped to the C1 control with the same value, as Windows does.
+const WIN1252_HIGH: [u16; 32] = [
+	0x20ac, 0x0081, 0x201a, 0x0192, 0x201e, 0x2026, 0x2020, 0x2021,
+	0x02c6, 0x2030, 0x0160, 0x2039, 0x0152, 0x008d, 0x017d, 0x008f,
+	0x0090, 0x2018, 0x2019, 0x201c, 0x201d, 0x2022, 0x2013, 0x2014,
+	0x02dc, 0x2122, 0x0161, 0x203a, 0x0153, 0x009d, 0x017e, 0x0178,
+];
+
+pub(in crate::kernel::utilities::encoding) fn decode_win1252(data: &[u8]) -> String {
+	data.iter()
+		.map(|b| match *b {
+			0x80..=0x9f => char::from_u32(WIN1252_HIGH[(*b - 0x80) as usize] as _).unwrap(),
+			b => b as char,
+		})
+		.collect()
+}
+
+pub(in crate::kernel::utilities::encoding) fn encode_win1252(
+	s: &str,
+	strict: bool,
+) -> SysResult<Vec<u8>>
+{
+	s.chars()
+		.map(|ch| match ch as u32 {
+			0x00..=0x7f | 0xa0..=0xff => Ok(ch as u8),
+			cp => WIN1252_HIGH.iter()
+				.position(|high| *high as u32 == cp)
+				.map(|idx| Ok(0x80 + idx as u8))
+				.unwrap_or_else(|| unmappable(strict)),
+		})
+		.collect()
+}
+
+pub(in crate::kernel::utilities::encoding) fn decode_utf8(
+	data: &[u8],
+	strict: bool,
+) -> SysResult<String>
+{
+	if strict {
+		std::str::from_utf8(data)
+			.map(|s| s.to_owned())
+			.map_err(|_| co::ERROR::NO_UNICODE_TRANSLATION)
+	} else {
+		Ok(String::from_utf8_lossy(data).into_owned())
+	}
+}
+
+/// Decodes UTF-16 code units, which may come from any encoding.
+pub(in crate::kernel::utilities::encoding) fn decode_wchars(
+	wchars: &[u16],
+	strict: bool,
+) -> SysResult<String>
+{
+	if strict {
+		String::from_utf16(wchars)
+			.map_err(|_| co::ERROR::NO_UNICODE_TRANSLATION)
+	} else {
+		Ok(String::from_utf16_lossy(wchars))
+	}
+}
+
+pub(in crate::kernel::utilities::encoding) fn decode_utf16(
+	data: &[u8],
+	is_big_endian: bool,
+	strict: bool,
+) -> SysResult<String>
+{
+	let wchars = data.chunks_exact(2)
+		.map(|ch2| {
+			let ch2 = [ch2[0], ch2[1]];
+			if is_big_endian { u16::from_be_bytes(ch2) } else { u16::from_le_bytes(ch2) }
+		})
+		.collect::<Vec<_>>();
+
+	let mut s = decode_wchars(&wchars, strict)?;
+	if data.len() % 2 != 0 {
+		s.push(invalid(strict)?); // dangling byte
+	}
+	Ok(s)
+}
+
+pub(in crate::kernel::utilities::encoding) fn encode_utf16(
+	s: &str,
+	is_big_endian: bool,
+) -> Vec<u8>
+{
+	s.encode_utf16()
+		.flat_map(|wch| if is_big_endian { wch.to_be_bytes() } else { wch.to_le_bytes() })
+		.collect()
+}
+
+pub(in crate::kernel::utilities::encoding) fn decode_utf32(
+	data: &[u8],
+	is_big_endian: bool,
+	strict: bool,
+) -> SysResult<String>
+{
+	let mut s = data.chunks_exact(4)
+		.map(|ch4| {
+			let ch4 = [ch4[0], ch4[1], ch4[2], ch4[3]];
+			let cp = if is_big_endian { u32::from_be_bytes(ch4) } else { u32::from_le_bytes(ch4) };
+			char::from_u32(cp).map_or_else(|| invalid(strict), Ok)
+		})
+		.collect::<SysResult<String>>()?;
+
+	if data.len() % 4 != 0 {
+		s.push(invalid(strict)?); // dangling bytes
+	}
+	Ok(s)
+}
+
+pub(in crate::kernel::utilities::encoding) fn encode_utf32(
+	s: &str,
+	is_big_endian: bool,
+) -> Vec<u8>
+{
+	s.chars()
+		.flat_map(|ch| {
+			let cp = ch as u32;
+			if is_big_endian { cp.to_be_bytes() } else { cp.to_le_bytes() }
+		})
+		.collect()
+}
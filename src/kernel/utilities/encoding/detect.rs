@@ -0,0 +1,416 @@
+use crate::decl::*;
+
+/// A possible encoding of the data analyzed by
+/// [`EncodingDetector`](crate::EncodingDetector).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EncodingCandidate {
+	/// The encoding.
+	pub encoding: Encoding,
+	/// Size of the [BOM](https://en.wikipedia.org/wiki/Byte_order_mark) found
+	/// at the beginning of the data, which must be skipped before decoding.
+	pub bom_size: usize,
+	/// How likely this encoding is, from 0.0 to 1.0. A BOM gives 1.0.
+	pub confidence: f32,
+}
+
+/// Incremental, statistics-based detector of text encodings.
+///
+/// Data can be fed in chunks of any size, like when reading a stream; the
+/// candidates can be retrieved at any moment, and are ranked by confidence.
+///
+/// Besides BOMs and UTF-8 validation, null bytes are counted at even and odd
+/// positions, so UTF-16 and UTF-32 text without BOM is detected, as long as it
+/// contains mostly Latin characters – like log exports and PowerShell output.
+/// SCSU and BOCU-1 are only detected by their BOMs.
+///
+/// For a single slice, [`Encoding::detect`](crate::Encoding::detect) can be
+/// used.
+///
+/// # Examples
+///
+/// Detecting the encoding of a file by reading only its first blocks:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let file_in = w::FileMapped::open(
+///     "C:\\Temp\\foo.txt",
+///     w::FileAccess::ExistingReadOnly,
+/// )?;
+///
+/// let mut detector = w::EncodingDetector::new();
+/// for block in file_in.as_slice().chunks(4096).take(4) {
+///     detector.feed(block);
+/// }
+///
+/// for cand in detector.candidates() {
+///     println!("{}: {:.2}", cand.encoding, cand.confidence);
+/// }
+/// # w::SysResult::Ok(())
+/// ```
+#[derive(Clone, Default)]
+pub struct EncodingDetector {
+	head: Vec<u8>, // first bytes, to check the BOM
+	total: usize,
+	nulls: [usize; 4], // by position modulo 4
+	trailing_nulls: usize, // probably a terminator, not counted
+	high: usize, // bytes above 0x7f
+	controls: usize, // C0 controls other than tab, line feed and carriage return
+	win1252_undefined: usize,
+
+	quad: [u8; 4], // last bytes, to assemble UTF-16 and UTF-32 units
+	utf16_invalid: [usize; 2], // LE and BE
+	utf16_high_pending: [bool; 2],
+	utf32_invalid: [usize; 2],
+
+	utf8_need: u8, // continuation bytes still expected
+	utf8_range: (u8, u8), // valid range of the next continuation byte
+	utf8_multibyte: usize,
+	utf8_invalid: usize,
+}
+
+impl EncodingDetector {
+	const BOM_MAX_LEN: usize = 4;
+
+	/// Creates a new detector, without data.
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Analyzes the next chunk of data.
+	pub fn feed(&mut self, chunk: &[u8]) {
+		for b in chunk.iter().copied() {
+			if self.head.len() < Self::BOM_MAX_LEN {
+				self.head.push(b);
+			}
+
+			let pos = self.total % 4;
+			self.quad[pos] = b;
+			self.total += 1;
+
+			self.trailing_nulls = if b == 0x00 { self.trailing_nulls + 1 } else { 0 };
+			match b {
+				0x00 => self.nulls[pos] += 1,
+				0x09 | 0x0a | 0x0d | 0x20..=0x7f => {},
+				0x01..=0x1f => self.controls += 1,
+				0x81 | 0x8d | 0x8f | 0x90 | 0x9d => {
+					self.high += 1;
+					self.win1252_undefined += 1;
+				},
+				_ => self.high += 1,
+			}
+
+			if pos % 2 == 1 {
+				let pair = [self.quad[pos - 1], b];
+				self.feed_utf16(0, u16::from_le_bytes(pair));
+				self.feed_utf16(1, u16::from_be_bytes(pair));
+			}
+			if pos == 3 {
+				if char::from_u32(u32::from_le_bytes(self.quad)).is_none() {
+					self.utf32_invalid[0] += 1;
+				}
+				if char::from_u32(u32::from_be_bytes(self.quad)).is_none() {
+					self.utf32_invalid[1] += 1;
+				}
+			}
+
+			self.feed_utf8(b);
+		}
+	}
+
+	fn feed_utf16(&mut self, endian: usize, unit: u16) {
+		let is_high = (0xd800..=0xdbff).contains(&unit);
+		let is_low = (0xdc00..=0xdfff).contains(&unit);
+		let pending = &mut self.utf16_high_pending[endian];
+
+		if *pending != is_low {
+			self.utf16_invalid[endian] += 1; // unpaired surrogate
+		}
+		*pending = is_high;
+	}
+
+	fn feed_utf8(&mut self, b: u8) {
+		if self.utf8_need > 0 {
+			if (self.utf8_range.0..=self.utf8_range.1).contains(&b) {
+				self.utf8_need -= 1;
+				self.utf8_range = (0x80, 0xbf);
+				if self.utf8_need == 0 {
+					self.utf8_multibyte += 1;
+				}
+				return;
+			}
+			self.utf8_invalid += 1; // truncated sequence, b is processed as a lead byte
+			self.utf8_need = 0;
+		}
+
+		(self.utf8_need, self.utf8_range) = match b {
+			0x00..=0x7f => (0, (0x80, 0xbf)),
+			0xc2..=0xdf => (1, (0x80, 0xbf)),
+			0xe0 => (2, (0xa0, 0xbf)), // excluding overlongs
+			0xe1..=0xec | 0xee..=0xef => (2, (0x80, 0xbf)),
+			0xed => (2, (0x80, 0x9f)), // excluding surrogates
+			0xf0 => (3, (0x90, 0xbf)), // planes 1-3
+			0xf1..=0xf3 => (3, (0x80, 0xbf)), // planes 4-15
+			0xf4 => (3, (0x80, 0x8f)), // plane 16
+			_ => {
+				self.utf8_invalid += 1;
+				(0, (0x80, 0xbf))
+			},
+		};
+	}
+
+	/// Returns the number of bytes analyzed so far.
+	#[must_use]
+	pub const fn len(&self) -> usize {
+		self.total
+	}
+
+	/// Returns `true` if no data has been analyzed.
+	#[must_use]
+	pub const fn is_empty(&self) -> bool {
+		self.total == 0
+	}
+
+	/// Returns the candidate encodings for the data analyzed so far, ranked
+	/// from the most to the least likely. Encodings which are impossible are
+	/// not included.
+	///
+	/// The result is never empty: if nothing else fits, Windows-1252 is
+	/// returned with a low confidence, since any data can be decoded with it.
+	#[must_use]
+	pub fn candidates(&self) -> Vec<EncodingCandidate> {
+		let mut cands = Vec::<EncodingCandidate>::with_capacity(8);
+		let mut push = |encoding: Encoding, bom_size: usize, confidence: f32| {
+			if confidence > 0.0 && !cands.iter().any(|c| c.encoding == encoding) {
+				cands.push(EncodingCandidate {
+					encoding,
+					bom_size,
+					confidence: confidence.min(1.0),
+				});
+			}
+		};
+
+		if let Some((encoding, bom_size)) = Encoding::guess_bom(&self.head) {
+			push(encoding, bom_size, 1.0);
+		}
+
+		let mut nulls = self.nulls;
+		let num_bytes = self.total - self.trailing_nulls;
+		(num_bytes..self.total)
+			.for_each(|pos| nulls[pos % 4] -= 1);
+
+		if num_bytes == 0 {
+			push(Encoding::Utf8, 0, 0.5); // no evidence at all
+			return cands;
+		}
+
+		let total = num_bytes as f32;
+		let half = (num_bytes / 2).max(1) as f32;
+		let quarter = (num_bytes / 4).max(1) as f32;
+		let null_ratio = nulls.iter().sum::<usize>() as f32 / total;
+		let text_ratio = 1.0 - (self.controls as f32 / total).min(1.0); // binary data is unlikely text
+
+		// UTF-32: 3 of every 4 bytes are usually zero, and all units must be valid.
+		let utf32 = |invalid: usize, zero_hi: usize, zero_mid: usize| -> f32 {
+			if num_bytes < 4 || invalid > 0 {
+				0.0
+			} else {
+				0.5 * (zero_hi as f32 / quarter) + 0.45 * (zero_mid as f32 / quarter)
+			}
+		};
+		let utf32le = utf32(self.utf32_invalid[0], nulls[3], nulls[2]);
+		let utf32be = utf32(self.utf32_invalid[1], nulls[0], nulls[1]);
+
+		// UTF-16: for Latin text, the high byte of each unit is zero, and the low
+		// byte is not.
+		let utf16 = |invalid: usize, zero_hi: usize, zero_lo: usize| -> f32 {
+			let zero_hi = zero_hi as f32 / half;
+			let zero_lo = zero_lo as f32 / half;
+			if num_bytes < 2 || zero_hi < 0.3 || zero_lo > zero_hi / 2.0 {
+				return 0.0;
+			}
+			let mut conf = 0.5 + 0.45 * zero_hi;
+			if invalid > 0 {
+				conf *= 0.2;
+			}
+			if self.total % 2 != 0 { // terminator nulls still count here
+				conf *= 0.8;
+			}
+			conf
+		};
+		let utf16le = utf16(
+			self.utf16_invalid[0],
+			nulls[1] + nulls[3],
+			nulls[0] + nulls[2],
+		) * (1.0 - utf32le);
+		let utf16be = utf16(
+			self.utf16_invalid[1],
+			nulls[0] + nulls[2],
+			nulls[1] + nulls[3],
+		) * (1.0 - utf32be);
+
+		// Single-byte and UTF-8: nulls are unusual in text.
+		let byte_text = text_ratio * (1.0 - null_ratio);
+		let utf8_invalid = self.utf8_invalid + (self.utf8_need > 0) as usize; // sequence truncated so far
+		let utf8 = if utf8_invalid > 0 {
+			0.05
+		} else if self.utf8_multibyte > 0 {
+			0.9 + 0.1 * (1.0 - 1.0 / (1.0 + self.utf8_multibyte as f32))
+		} else {
+			0.9 // plain ASCII is valid UTF-8
+		} * byte_text;
+		let ansi = if self.high == 0 { 0.85 * byte_text } else { 0.0 };
+		let win1252 = if self.high == 0 {
+			0.3
+		} else if self.win1252_undefined > 0 {
+			0.1
+		} else if utf8_invalid == 0 {
+			0.2 // valid UTF-8 is hardly a coincidence
+		} else {
+			0.6
+		} * byte_text;
+
+		let mut stats = [
+			(Encoding::Utf32le, utf32le),
+			(Encoding::Utf32be, utf32be),
+			(Encoding::Utf16le, utf16le),
+			(Encoding::Utf16be, utf16be),
+			(Encoding::Utf8, utf8),
+			(Encoding::Ansi, ansi),
+			(Encoding::Win1252, win1252),
+		];
+		stats.sort_by(|a, b| b.1.total_cmp(&a.1)); // stable, ties keep the order above
+		stats.iter()
+			.for_each(|(encoding, conf)| push(*encoding, 0, *conf));
+		push(Encoding::Win1252, 0, 0.01); // anything can be decoded as Windows-1252
+
+		cands
+	}
+
+	/// Returns the most likely candidate; the same as the first element
+	/// returned by
+	/// [`EncodingDetector::candidates`](crate::EncodingDetector::candidates).
+	#[must_use]
+	pub fn best(&self) -> EncodingCandidate {
+		self.candidates()[0]
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	fn best(data: &[u8]) -> (Encoding, usize) {
+		let best = Encoding::detect(data)[0];
+		(best.encoding, best.bom_size)
+	}
+
+	fn with_nul(mut data: Vec<u8>, nulls: usize) -> Vec<u8> {
+		data.extend(std::iter::repeat_n(0, nulls));
+		data
+	}
+
+	#[test]
+	fn bom() {
+		let cases: &[(&[u8], Encoding, usize)] = &[
+			(&[0xef, 0xbb, 0xbf, b'a'], Encoding::Utf8, 3),
+			(&[0xff, 0xfe, b'a', 0x00], Encoding::Utf16le, 2),
+			(&[0xfe, 0xff, 0x00, b'a'], Encoding::Utf16be, 2),
+			(&[0xff, 0xfe, 0x00, 0x00, b'a', 0, 0, 0], Encoding::Utf32le, 4),
+			(&[0x00, 0x00, 0xfe, 0xff, 0, 0, 0, b'a'], Encoding::Utf32be, 4),
+			(&[0x0e, 0xfe, 0xff, b'a'], Encoding::Scsu, 3),
+			(&[0xfb, 0xee, 0x28, b'a'], Encoding::Bocu1, 3),
+		];
+
+		for (data, encoding, bom_size) in cases.iter() {
+			assert_eq!(best(data), (*encoding, *bom_size), "detect({:02x?})", data);
+			assert_eq!(Encoding::detect(data)[0].confidence, 1.0);
+			assert_eq!(Encoding::guess(data), (*encoding, *bom_size), "guess({:02x?})", data);
+		}
+	}
+
+	#[test]
+	fn utf16_without_bom() {
+		let texts = [
+			"Hi!",
+			"Get-ChildItem -Path C:\\Windows\r\n",
+			"2024-01-15 10:32:07 ERROR Größe überschritten: 1024 KiB\r\n",
+			"Ελληνικά mixed with ASCII text, mostly ASCII text",
+		];
+
+		for text in texts {
+			let le = Encoding::Utf16le.encode(text);
+			let be = Encoding::Utf16be.encode(text);
+			assert_eq!(best(&le), (Encoding::Utf16le, 0), "{:?} LE", text);
+			assert_eq!(best(&be), (Encoding::Utf16be, 0), "{:?} BE", text);
+			assert_eq!(Encoding::guess(&le), (Encoding::Utf16le, 0), "{:?} LE", text);
+			assert_eq!(Encoding::guess(&be), (Encoding::Utf16be, 0), "{:?} BE", text);
+
+			// null terminators, even or odd in number
+			for nulls in 1..=3 {
+				assert_eq!(best(&with_nul(le.clone(), nulls)).0, Encoding::Utf16le, "{:?} LE + {}", text, nulls);
+				assert_eq!(best(&with_nul(be.clone(), nulls)).0, Encoding::Utf16be, "{:?} BE + {}", text, nulls);
+			}
+		}
+	}
+
+	#[test]
+	fn utf16_invalid() {
+		let mut le = Encoding::Utf16le.encode("abcdefgh");
+		le.extend_from_slice(&[0x00, 0xdc]); // lone low surrogate
+		let cands = Encoding::detect(&le);
+		let conf = cands.iter().find(|c| c.encoding == Encoding::Utf16le).unwrap().confidence;
+		assert!(conf < 0.5, "{}", conf);
+
+		assert!(Encoding::detect(b"a").iter().all(|c| c.encoding != Encoding::Utf16le));
+		assert!(Encoding::detect(b"plain ASCII").iter().all(|c| c.encoding != Encoding::Utf16be));
+	}
+
+	#[test]
+	fn utf32_without_bom() {
+		let text = "Hello, world!\r\n";
+		assert_eq!(best(&Encoding::Utf32le.encode(text)), (Encoding::Utf32le, 0));
+		assert_eq!(best(&Encoding::Utf32be.encode(text)), (Encoding::Utf32be, 0));
+		assert_eq!(Encoding::guess(&Encoding::Utf32le.encode(text)), (Encoding::Utf32le, 0));
+	}
+
+	#[test]
+	fn single_byte() {
+		let cases: &[(&[u8], Encoding)] = &[ // data, guess
+			(b"", Encoding::Utf8),
+			(b"plain ASCII\r\n", Encoding::Utf8),
+			(b"null-terminated\0", Encoding::Utf8),
+			("café ünïcödé".as_bytes(), Encoding::Utf8),
+			(b"caf\xe9 \xfcber", Encoding::Win1252),
+			(b"\xc3", Encoding::Win1252), // truncated UTF-8
+			(b"bell\x07", Encoding::Ansi),
+		];
+
+		for (data, guess) in cases.iter() {
+			assert_eq!(Encoding::guess(data), (*guess, 0), "guess({:02x?})", data);
+		}
+
+		assert_eq!(best(b"plain ASCII").0, Encoding::Utf8);
+		assert_eq!(best("café".as_bytes()).0, Encoding::Utf8);
+		assert_eq!(best(b"caf\xe9").0, Encoding::Win1252);
+		assert!(Encoding::detect(b"caf\xe9").iter().all(|c| c.encoding != Encoding::Utf8 || c.confidence < 0.1));
+	}
+
+	#[test]
+	fn streaming() {
+		let mut data = Encoding::Utf16le.encode("Streamed PowerShell output, line 1\r\nline 2\r\n");
+		data.extend_from_slice(&[0, 0]);
+
+		for chunk_size in [1, 2, 3, 7, 64] {
+			let mut detector = EncodingDetector::new();
+			data.chunks(chunk_size).for_each(|chunk| detector.feed(chunk));
+			assert_eq!(detector.len(), data.len());
+			assert_eq!(detector.candidates(), Encoding::detect(&data), "chunks of {}", chunk_size);
+		}
+
+		let detector = EncodingDetector::new();
+		assert!(detector.is_empty());
+		assert_eq!(detector.best().encoding, Encoding::Utf8);
+	}
+}
@@ -0,0 +1,375 @@
+mod bocu1;
+mod codecs;
+mod detect;
+mod scsu;
+
+pub use detect::{EncodingCandidate, EncodingDetector};
+
+use std::cmp::Ordering;
+
+use crate::decl::*;
+
+/// String encodings.
+///
+/// Besides guessing the encoding of raw data, each encoding can decode and
+/// encode text with pure-Rust codecs, without calling
+/// [`MultiByteToWideChar`](crate::MultiByteToWideChar).
+///
+/// # Examples
+///
+/// Reading a text file in any of the supported encodings:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let file_in = w::FileMapped::open(
+///     "C:\\Temp\\foo.txt",
+///     w::FileAccess::ExistingReadOnly,
+/// )?;
+/// let data = file_in.as_slice();
+///
+/// let (encoding, bom_sz) = w::Encoding::guess(data);
+/// let text = encoding.decode_strict(&data[bom_sz..])?;
+/// # w::SysResult::Ok(())
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Encoding {
+	/// Unknown encoding.
+	Unknown,
+	/// Common [US_ASCII](https://en.wikipedia.org/wiki/ASCII) encoding.
+	Ansi,
+	/// [Windows-1252](https://en.wikipedia.org/wiki/Windows-1252) encoding.
+	Win1252,
+	/// [UTF-8](https://en.wikipedia.org/wiki/UTF-8) encoding.
+	Utf8,
+	/// [UTF-16](https://en.wikipedia.org/wiki/UTF-16) encoding, big-endian.
+	Utf16be,
+	/// [UTF-16](https://en.wikipedia.org/wiki/UTF-16) encoding, little-endian.
+	Utf16le,
+	/// [UTF-32](https://en.wikipedia.org/wiki/UTF-32) encoding, big-endian.
+	Utf32be,
+	/// [UTF-32](https://en.wikipedia.org/wiki/UTF-32) encoding, little-endian.
+	Utf32le,
+	/// [Standard Compression Scheme for Unicode](https://en.wikipedia.org/wiki/Standard_Compression_Scheme_for_Unicode).
+	Scsu,
+	/// [Binary Ordered Compression for Unicode](https://en.wikipedia.org/wiki/Binary_Ordered_Compression_for_Unicode).
+	Bocu1,
+}
+
+impl std::fmt::Display for Encoding {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		std::fmt::Display::fmt(
+			match self {
+				Self::Unknown => "Unknown",
+				Self::Ansi => "ANSI",
+				Self::Win1252 => "Windows 1252",
+				Self::Utf8 => "UTF-8",
+				Self::Utf16be => "UTF-16 BE",
+				Self::Utf16le => "UTF-16 LE",
+				Self::Utf32be => "UTF-32 BE",
+				Self::Utf32le => "UTF-32 LE",
+				Self::Scsu => "SCSU",
+				Self::Bocu1 => "BOCU1",
+			},
+			f,
+		)
+	}
+}
+
+impl Encoding {
+	/// Returns the [BOM](https://en.wikipedia.org/wiki/Byte_order_mark) of the
+	/// encoding, which is empty for `Unknown`, `Ansi` and `Win1252`.
+	#[must_use]
+	pub const fn bom(&self) -> &'static [u8] {
+		match self {
+			Self::Unknown | Self::Ansi | Self::Win1252 => &[],
+			Self::Utf8 => &[0xef, 0xbb, 0xbf],
+			Self::Utf16be => &[0xfe, 0xff],
+			Self::Utf16le => &[0xff, 0xfe],
+			Self::Utf32be => &[0x00, 0x00, 0xfe, 0xff],
+			Self::Utf32le => &[0xff, 0xfe, 0x00, 0x00],
+			Self::Scsu => &[0x0e, 0xfe, 0xff],
+			Self::Bocu1 => &[0xfb, 0xee, 0x28],
+		}
+	}
+
+	/// Decodes the raw data into a string. Invalid sequences are replaced by
+	/// [`U+FFFD`](std::char::REPLACEMENT_CHARACTER).
+	///
+	/// The BOM is not skipped, and null characters are kept. If the encoding is
+	/// `Unknown`, it's guessed with [`Encoding::guess`](crate::Encoding::guess)
+	/// and the BOM, if any, is skipped.
+	///
+	/// Windows-1252 bytes without an assigned character are mapped to the C1
+	/// control of the same value, like Windows does.
+	#[must_use]
+	pub fn decode(&self, data: &[u8]) -> String {
+		self.decode_impl(data, false)
+			.unwrap_or_default() // lossy mode never fails
+	}
+
+	/// Decodes the raw data into a string, like
+	/// [`Encoding::decode`](crate::Encoding::decode), but failing with
+	/// [`ERROR::NO_UNICODE_TRANSLATION`](crate::co::ERROR::NO_UNICODE_TRANSLATION)
+	/// if an invalid sequence is found.
+	pub fn decode_strict(&self, data: &[u8]) -> SysResult<String> {
+		self.decode_impl(data, true)
+	}
+
+	fn decode_impl(&self, data: &[u8], strict: bool) -> SysResult<String> {
+		match self {
+			Self::Unknown => {
+				let (encoding, bom_sz) = Self::guess(data);
+				encoding.decode_impl(&data[bom_sz..], strict)
+			},
+			Self::Ansi => codecs::decode_ansi(data, strict),
+			Self::Win1252 => Ok(codecs::decode_win1252(data)),
+			Self::Utf8 => codecs::decode_utf8(data, strict),
+			Self::Utf16be => codecs::decode_utf16(data, true, strict),
+			Self::Utf16le => codecs::decode_utf16(data, false, strict),
+			Self::Utf32be => codecs::decode_utf32(data, true, strict),
+			Self::Utf32le => codecs::decode_utf32(data, false, strict),
+			Self::Scsu => scsu::decode(data, strict),
+			Self::Bocu1 => bocu1::decode(data, strict),
+		}
+	}
+
+	/// Encodes the string into raw data. Characters which cannot be
+	/// represented in `Ansi` or `Win1252` are replaced by `?`; all other
+	/// encodings can represent any character.
+	///
+	/// No BOM is written; if needed, prepend
+	/// [`Encoding::bom`](crate::Encoding::bom). If the encoding is `Unknown`,
+	/// UTF-8 is used.
+	#[must_use]
+	pub fn encode(&self, s: &str) -> Vec<u8> {
+		self.encode_impl(s, false)
+			.unwrap_or_default() // lossy mode never fails
+	}
+
+	/// Encodes the string into raw data, like
+	/// [`Encoding::encode`](crate::Encoding::encode), but failing with
+	/// [`ERROR::NO_UNICODE_TRANSLATION`](crate::co::ERROR::NO_UNICODE_TRANSLATION)
+	/// if a character cannot be represented.
+	pub fn encode_strict(&self, s: &str) -> SysResult<Vec<u8>> {
+		self.encode_impl(s, true)
+	}
+
+	fn encode_impl(&self, s: &str, strict: bool) -> SysResult<Vec<u8>> {
+		Ok(match self {
+			Self::Unknown | Self::Utf8 => s.as_bytes().to_vec(),
+			Self::Ansi => codecs::encode_ansi(s, strict)?,
+			Self::Win1252 => codecs::encode_win1252(s, strict)?,
+			Self::Utf16be => codecs::encode_utf16(s, true),
+			Self::Utf16le => codecs::encode_utf16(s, false),
+			Self::Utf32be => codecs::encode_utf32(s, true),
+			Self::Utf32le => codecs::encode_utf32(s, false),
+			Self::Scsu => scsu::encode(s),
+			Self::Bocu1 => bocu1::encode(s),
+		})
+	}
+
+	/// Guesses the encoding of the given raw data, also returning the size of
+	/// its [BOM](https://en.wikipedia.org/wiki/Byte_order_mark), if any.
+	///
+	/// Without a BOM, data which is valid UTF-8 – including plain ASCII – is
+	/// [`Encoding::Utf8`](crate::Encoding::Utf8); other data with bytes above
+	/// `0x7f` is [`Encoding::Win1252`](crate::Encoding::Win1252), and the rest
+	/// is [`Encoding::Ansi`](crate::Encoding::Ansi). The exception is UTF-16
+	/// and UTF-32 without BOM, which are returned when they're the most likely
+	/// candidate of [`Encoding::detect`](crate::Encoding::detect), instead of
+	/// being mistaken for the UTF-8 text before their first null byte.
+	#[must_use]
+	pub fn guess(data: &[u8]) -> (Self, usize) {
+		let best = Self::detect(data)[0];
+		match best.encoding {
+			_ if best.bom_size > 0 => (best.encoding, best.bom_size),
+			Self::Utf16le | Self::Utf16be | Self::Utf32le | Self::Utf32be => (best.encoding, 0),
+			_ if Self::guess_utf8(data) => (Self::Utf8, 0),
+			_ if data.iter().any(|ch| *ch > 0x7f) => (Self::Win1252, 0), // by exclusion, not assertive
+			_ => (Self::Ansi, 0),
+		}
+	}
+
+	/// Returns the possible encodings of the given raw data, ranked by
+	/// confidence. The result is never empty.
+	///
+	/// To analyze data in chunks, use
+	/// [`EncodingDetector`](crate::EncodingDetector).
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let data = [0x48, 0x00, 0x69, 0x00, 0x21, 0x00]; // "Hi!" in UTF-16 LE
+	///
+	/// let best = w::Encoding::detect(&data)[0];
+	/// assert_eq!(best.encoding, w::Encoding::Utf16le);
+	/// ```
+	#[must_use]
+	pub fn detect(data: &[u8]) -> Vec<EncodingCandidate> {
+		let mut detector = EncodingDetector::new();
+		detector.feed(data);
+		detector.candidates()
+	}
+
+	/// Whether the data, up to the first null, is valid UTF-8 without control
+	/// characters other than tab, line feed and carriage return.
+	#[must_use]
+	fn guess_utf8(data: &[u8]) -> bool {
+		let text = data.split(|ch| *ch == 0x00).next().unwrap_or_default(); // until the terminator
+		std::str::from_utf8(text)
+			.is_ok_and(|s| s.bytes().all(|ch| matches!(ch, 0x09 | 0x0a | 0x0d | 0x20..=0x7e | 0x80..)))
+	}
+
+	#[must_use]
+	pub(in crate::kernel::utilities::encoding) fn guess_bom(data: &[u8]) -> Option<(Self, usize)> {
+		let has_bom = |bom_bytes: &[u8]| -> bool {
+			data.len() >= bom_bytes.len()
+				&& data[..bom_bytes.len()].cmp(bom_bytes) == Ordering::Equal
+		};
+
+		const UTF8: [u8; 3] = [0xef, 0xbb, 0xbf];
+		if has_bom(&UTF8) { // UTF-8 BOM
+			return Some((Self::Utf8, UTF8.len()));
+		}
+
+		const UTF16BE: [u8; 2] = [0xfe, 0xff];
+		if has_bom(&UTF16BE) {
+			return Some((Self::Utf16be, UTF16BE.len()));
+		}
+
+		const UTF32BE: [u8; 4] = [0x00, 0x00, 0xfe, 0xff];
+		if has_bom(&UTF32BE) {
+			return Some((Self::Utf32be, UTF32BE.len()));
+		}
+
+		const UTF32LE: [u8; 4] = [0xff, 0xfe, 0x00, 0x00];
+		if has_bom(&UTF32LE) { // before UTF-16 LE, which is a prefix of it
+			return Some((Self::Utf32le, UTF32LE.len()));
+		}
+
+		const UTF16LE: [u8; 2] = [0xff, 0xfe];
+		if has_bom(&UTF16LE) {
+			return Some((Self::Utf16le, UTF16LE.len()));
+		}
+
+		const SCSU: [u8; 3] = [0x0e, 0xfe, 0xff];
+		if has_bom(&SCSU) {
+			return Some((Self::Scsu, SCSU.len()));
+		}
+
+		const BOCU1: [u8; 3] = [0xfb, 0xee, 0x28];
+		if has_bom(&BOCU1) {
+			return Some((Self::Bocu1, BOCU1.len()));
+		}
+
+		None // no BOM found
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::co;
+
+	const UNICODE: [Encoding; 7] = [
+		Encoding::Utf8,
+		Encoding::Utf16le, Encoding::Utf16be,
+		Encoding::Utf32le, Encoding::Utf32be,
+		Encoding::Scsu, Encoding::Bocu1,
+	];
+
+	#[test]
+	fn round_trip() {
+		let texts = [
+			"",
+			"Hello, world!\r\n",
+			"a\0b\0", // not leading, FF FE 00 00 is the UTF-32 LE BOM
+			"Öl fließt",
+			"Москва",
+			"日本語のテキスト",
+			"한국어 텍스트",
+			"emoji 😀 and 𝄞 outside the BMP",
+			"mixed: abc Ωμέγα 中文 ﾊﾝｶｸ €",
+			"\u{fffd}\u{feff}\u{10ffff}",
+		];
+		for enc in UNICODE.iter() {
+			for text in texts.iter() {
+				let data = enc.encode_strict(text).unwrap();
+				assert_eq!(data, enc.encode(text), "{} {:?}", enc, text);
+				assert_eq!(enc.decode_strict(&data).as_deref(), Ok(*text), "{} {:?}", enc, text);
+				assert_eq!(enc.decode(&data), *text, "{} {:?}", enc, text);
+
+				let with_bom = [enc.bom(), &data].concat();
+				assert_eq!(Encoding::Unknown.decode(&with_bom), *text, "{} {:?}", enc, text);
+			}
+		}
+
+		let single_byte: &[(Encoding, &str)] = &[
+			(Encoding::Ansi, "plain ASCII\t~\x7f"),
+			(Encoding::Win1252, "€‚ƒ„…†‡ˆ‰Š‹ŒŽ‘’“”•–—˜™š›œžŸ"),
+			(Encoding::Win1252, "\u{81}\u{8d}\u{8f}\u{90}\u{9d} àéîõü ÿ"),
+		];
+		for (enc, text) in single_byte.iter() {
+			let data = enc.encode_strict(text).unwrap();
+			assert_eq!(data.len(), text.chars().count(), "{} {:?}", enc, text);
+			assert_eq!(enc.decode_strict(&data).as_deref(), Ok(*text), "{} {:?}", enc, text);
+		}
+	}
+
+	#[test]
+	fn known_bytes() {
+		let cases: &[(Encoding, &str, &[u8])] = &[
+			(Encoding::Ansi, "Az", b"Az"),
+			(Encoding::Win1252, "€ÿ", &[0x80, 0xff]),
+			(Encoding::Utf8, "é", &[0xc3, 0xa9]),
+			(Encoding::Utf16le, "A😀", &[0x41, 0x00, 0x3d, 0xd8, 0x00, 0xde]),
+			(Encoding::Utf16be, "A😀", &[0x00, 0x41, 0xd8, 0x3d, 0xde, 0x00]),
+			(Encoding::Utf32le, "A😀", &[0x41, 0, 0, 0, 0x00, 0xf6, 0x01, 0x00]),
+			(Encoding::Utf32be, "A😀", &[0, 0, 0, 0x41, 0x00, 0x01, 0xf6, 0x00]),
+			// examples from the SCSU specification
+			(Encoding::Scsu, "Öl fließt", &[0xd6, 0x6c, 0x20, 0x66, 0x6c, 0x69, 0x65, 0xdf, 0x74]),
+			(Encoding::Scsu, "Москва", &[0x12, 0x9c, 0xbe, 0xc1, 0xba, 0xb2, 0xb0]),
+			(Encoding::Bocu1, "ABC a", &[0x91, 0x92, 0x93, 0x20, 0xb1]),
+		];
+		for (enc, text, data) in cases.iter() {
+			assert_eq!(enc.decode_strict(data).as_deref(), Ok(*text), "{} {:?}", enc, data);
+			assert_eq!(enc.encode_strict(text).as_deref(), Ok(*data), "{} {:?}", enc, text);
+		}
+	}
+
+	#[test]
+	fn invalid_sequences() {
+		let cases: &[(Encoding, &[u8], &str)] = &[
+			(Encoding::Ansi, &[0x41, 0x80], "A\u{fffd}"),
+			(Encoding::Utf8, &[0x41, 0xc3], "A\u{fffd}"),
+			(Encoding::Utf8, &[0xed, 0xa0, 0x80], "\u{fffd}\u{fffd}\u{fffd}"), // surrogate
+			(Encoding::Utf16le, &[0x41, 0x00, 0x00, 0xd8], "A\u{fffd}"), // lone surrogate
+			(Encoding::Utf16be, &[0x00, 0x41, 0x00], "A\u{fffd}"), // dangling byte
+			(Encoding::Utf32le, &[0x00, 0x00, 0x11, 0x00], "\u{fffd}"), // above U+10FFFF
+			(Encoding::Utf32be, &[0x00, 0x00, 0xd8, 0x00], "\u{fffd}"), // surrogate
+			(Encoding::Utf32be, &[0x00, 0x00, 0x00], "\u{fffd}"), // dangling bytes
+		];
+		for (enc, data, lossy) in cases.iter() {
+			assert_eq!(enc.decode(data), *lossy, "{} {:?}", enc, data);
+			assert_eq!(enc.decode_strict(data), Err(co::ERROR::NO_UNICODE_TRANSLATION), "{} {:?}", enc, data);
+		}
+	}
+
+	#[test]
+	fn unmappable_chars() {
+		let cases: &[(Encoding, &str, &[u8])] = &[
+			(Encoding::Ansi, "é!", b"?!"),
+			(Encoding::Ansi, "😀", b"?"),
+			(Encoding::Win1252, "€ Ω", &[0x80, 0x20, b'?']),
+			(Encoding::Win1252, "\u{100}", b"?"),
+		];
+		for (enc, text, lossy) in cases.iter() {
+			assert_eq!(enc.encode(text), *lossy, "{} {:?}", enc, text);
+			assert_eq!(enc.encode_strict(text), Err(co::ERROR::NO_UNICODE_TRANSLATION), "{} {:?}", enc, text);
+		}
+		assert_eq!(Encoding::Unknown.encode("é"), "é".as_bytes());
+	}
+}
@@ -0,0 +1,271 @@
+//! [SCSU](https://www.unicode.org/reports/tr6/) decoder and encoder.
+
+use crate::decl::*;
+use crate::kernel::utilities::encoding::codecs::*;
+
+const STATIC_WINDOWS: [u32; 8] = [
+	0x0000, 0x0080, 0x0100, 0x0300, 0x2000, 0x2080, 0x2100, 0x3000,
+];
+const INITIAL_DYNAMIC_WINDOWS: [u32; 8] = [
+	0x0080, 0x00c0, 0x0400, 0x0600, 0x0900, 0x3040, 0x30a0, 0xff00,
+];
+
+// Single-byte mode tags.
+const SQ0: u8 = 0x01;
+const SDX: u8 = 0x0b;
+const SQU: u8 = 0x0e;
+const SCU: u8 = 0x0f;
+const SC0: u8 = 0x10;
+const SD0: u8 = 0x18;
+
+// Unicode mode tags.
+const UC0: u8 = 0xe0;
+const UD0: u8 = 0xe8;
+const UQU: u8 = 0xf0;
+const UDX: u8 = 0xf1;
+const URS: u8 = 0xf2; // reserved
+
+/// Offset of the dynamic window defined by the `SDn`/`UDn` tags.
+#[must_use]
+fn window_offset(x: u8) -> Option<u32> {
+	match x {
+		0x01..=0x67 => Some(x as u32 * 0x80),
+		0x68..=0xa7 => Some(x as u32 * 0x80 + 0xac00),
+		0xf9 => Some(0x00c0),
+		0xfa => Some(0x0250),
+		0xfb => Some(0x0370),
+		0xfc => Some(0x0530),
+		0xfd => Some(0x3040),
+		0xfe => Some(0x30a0),
+		0xff => Some(0xff60),
+		_ => None, // reserved
+	}
+}
+
+/// Window index and offset defined by the `SDX`/`UDX` tags.
+#[must_use]
+const fn extended_window(hi: u8, lo: u8) -> (usize, u32) {
+	let hl = (hi as u32) << 8 | lo as u32;
+	((hl >> 13) as usize, 0x10000 + ((hl & 0x1fff) << 7))
+}
+
+struct Decoder<'a> {
+	data: &'a [u8],
+	pos: usize,
+	strict: bool,
+	wchars: Vec<u16>,
+	dynamic: [u32; 8],
+	active: usize,
+}
+
+impl<'a> Decoder<'a> {
+	fn next(&mut self) -> Option<u8> {
+		let b = self.data.get(self.pos).copied();
+		self.pos += 1;
+		b
+	}
+
+	fn push(&mut self, cp: u32) {
+		if cp < 0x10000 {
+			self.wchars.push(cp as _);
+		} else {
+			let cp = cp - 0x10000;
+			self.wchars.push(0xd800 | (cp >> 10) as u16);
+			self.wchars.push(0xdc00 | (cp & 0x3ff) as u16);
+		}
+	}
+
+	fn malformed(&mut self) -> SysResult<()> {
+		let ch = invalid(self.strict)?;
+		self.push(ch as _);
+		Ok(())
+	}
+
+	fn define(&mut self, n: usize, x: u8) -> SysResult<()> {
+		match window_offset(x) {
+			Some(offset) => {
+				self.dynamic[n] = offset;
+				self.active = n;
+				Ok(())
+			},
+			None => self.malformed(),
+		}
+	}
+
+	fn run(&mut self) -> SysResult<()> {
+		let mut unicode_mode = false;
+
+		while let Some(b) = self.next() {
+			if unicode_mode {
+				match b {
+					UC0..=0xe7 => {
+						self.active = (b - UC0) as _;
+						unicode_mode = false;
+					},
+					UD0..=0xef => match self.next() {
+						Some(x) => {
+							self.define((b - UD0) as _, x)?;
+							unicode_mode = false;
+						},
+						None => return self.malformed(),
+					},
+					UQU => match (self.next(), self.next()) {
+						(Some(hi), Some(lo)) => self.wchars.push(u16::from_be_bytes([hi, lo])),
+						_ => return self.malformed(),
+					},
+					UDX => match (self.next(), self.next()) {
+						(Some(hi), Some(lo)) => {
+							let (n, offset) = extended_window(hi, lo);
+							self.dynamic[n] = offset;
+							self.active = n;
+							unicode_mode = false;
+						},
+						_ => return self.malformed(),
+					},
+					URS => self.malformed()?,
+					hi => match self.next() {
+						Some(lo) => self.wchars.push(u16::from_be_bytes([hi, lo])),
+						None => return self.malformed(),
+					},
+				}
+			} else {
+				match b {
+					0x00 | 0x09 | 0x0a | 0x0d | 0x20..=0x7f => self.push(b as _),
+					0x80..=0xff => self.push(self.dynamic[self.active] + (b - 0x80) as u32),
+					SQ0..=0x08 => match self.next() {
+						Some(q) => {
+							let n = (b - SQ0) as usize;
+							if q < 0x80 {
+								self.push(STATIC_WINDOWS[n] + q as u32);
+							} else {
+								self.push(self.dynamic[n] + (q - 0x80) as u32);
+							}
+						},
+						None => return self.malformed(),
+					},
+					SDX => match (self.next(), self.next()) {
+						(Some(hi), Some(lo)) => {
+							let (n, offset) = extended_window(hi, lo);
+							self.dynamic[n] = offset;
+							self.active = n;
+						},
+						_ => return self.malformed(),
+					},
+					SQU => match (self.next(), self.next()) {
+						(Some(hi), Some(lo)) => self.wchars.push(u16::from_be_bytes([hi, lo])),
+						_ => return self.malformed(),
+					},
+					SCU => unicode_mode = true,
+					SC0..=0x17 => self.active = (b - SC0) as _,
+					SD0..=0x1f => match self.next() {
+						Some(x) => self.define((b - SD0) as _, x)?,
+						None => return self.malformed(),
+					},
+					_ => self.malformed()?, // 0x0c is reserved
+				}
+			}
+		}
+		Ok(())
+	}
+}
+
+pub(in crate::kernel::utilities::encoding) fn decode(
+	data: &[u8],
+	strict: bool,
+) -> SysResult<String>
+{
+	let mut decoder = Decoder {
+		data,
+		pos: 0,
+		strict,
+		wchars: Vec::with_capacity(data.len()),
+		dynamic: INITIAL_DYNAMIC_WINDOWS,
+		active: 0,
+	};
+	decoder.run()?;
+	decode_wchars(&decoder.wchars, strict)
+}
+
+/// Returns the `SDn` argument and the offset of a window which contains the
+/// given BMP character, if any.
+#[must_use]
+fn definable_window(cp: u32) -> Option<(u8, u32)> {
+	match cp {
+		0x0080..=0x33ff => Some(((cp >> 7) as u8, cp & !0x7f)),
+		0xe000..=0xffff => Some((((cp - 0xac00) >> 7) as u8, cp & !0x7f)),
+		_ => None,
+	}
+}
+
+pub(in crate::kernel::utilities::encoding) fn encode(s: &str) -> Vec<u8> {
+	let mut out = Vec::with_capacity(s.len());
+	let mut dynamic = INITIAL_DYNAMIC_WINDOWS;
+	let mut last_used = [0usize; 8]; // to pick the least recently used window
+	let mut active = 0;
+	let mut unicode_mode = false;
+
+	let in_window = |dynamic: &[u32; 8], cp: u32| {
+		dynamic.iter().position(|off| cp >= *off && cp < *off + 0x80)
+	};
+
+	for (tick, ch) in s.chars().enumerate() {
+		let cp = ch as u32;
+		last_used[active] = tick;
+
+		if unicode_mode {
+			if cp < 0x80 || in_window(&dynamic, cp).is_some() {
+				let n = in_window(&dynamic, cp).unwrap_or(active);
+				out.push(UC0 + n as u8);
+				active = n;
+				unicode_mode = false; // proceed in single-byte mode
+			} else {
+				let mut buf = [0u16; 2];
+				for wch in ch.encode_utf16(&mut buf).iter() {
+					let [hi, lo] = wch.to_be_bytes();
+					if (UC0..=URS).contains(&hi) {
+						out.push(UQU); // would be taken as a tag
+					}
+					out.extend_from_slice(&[hi, lo]);
+				}
+				continue;
+			}
+		}
+
+		if matches!(cp, 0x00 | 0x09 | 0x0a | 0x0d | 0x20..=0x7f) {
+			out.push(cp as _);
+		} else if cp < 0x20 {
+			out.extend_from_slice(&[SQ0, cp as _]); // static window 0
+		} else if let Some(n) = in_window(&dynamic, cp) {
+			if n != active {
+				out.push(SC0 + n as u8);
+				active = n;
+			}
+			out.push(0x80 + (cp - dynamic[n]) as u8);
+		} else if let Some(n) = STATIC_WINDOWS.iter()
+			.position(|off| cp >= *off && cp < *off + 0x80)
+		{
+			out.extend_from_slice(&[SQ0 + n as u8, (cp - STATIC_WINDOWS[n]) as _]);
+		} else {
+			let n = (0..8).min_by_key(|n| last_used[*n]).unwrap();
+			if let Some((x, offset)) = definable_window(cp) {
+				out.extend_from_slice(&[SD0 + n as u8, x]);
+				dynamic[n] = offset;
+			} else if cp >= 0x10000 {
+				let offset = cp & !0x7f;
+				let hl = (n as u32) << 13 | (offset - 0x10000) >> 7;
+				out.extend_from_slice(&[SDX, (hl >> 8) as _, hl as _]);
+				dynamic[n] = offset;
+			} else {
+				out.push(SCU); // CJK and Hangul are better in Unicode mode
+				out.extend_from_slice(&(cp as u16).to_be_bytes());
+				unicode_mode = true;
+				continue;
+			}
+			active = n;
+			last_used[n] = tick;
+			out.push(0x80 + (cp - dynamic[n]) as u8);
+		}
+	}
+
+	out
+}
@@ -0,0 +1,386 @@
+use std::collections::BTreeMap;
+
+use crate::co;
+use crate::decl::*;
+use crate::kernel::{ffi, privs::*};
+
+/// An environment block: a set of environment variables whose names are
+/// compared case-insensitively, kept sorted in the order required by the
+/// system.
+///
+/// It can be parsed from and emitted to the native double-null-terminated
+/// UTF-16 format, including the hidden `=C:` entries which store the current
+/// directory of each drive, and it can be passed to
+/// [`HPROCESS::CreateProcessWithEnv`](crate::prelude::kernel_Hprocess::CreateProcessWithEnv)
+/// to define the environment of a child process.
+///
+/// # Examples
+///
+/// Running a child process with the current environment plus an additional
+/// variable:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let mut env = w::EnvironmentBlock::current()?;
+/// env.set("MY_APP_MODE", "test")?;
+/// env.remove("TEMP");
+///
+/// let mut si = w::STARTUPINFO::default();
+/// let pi = w::HPROCESS::CreateProcessWithEnv(
+///     None,
+///     Some(env.expand("%SystemRoot%\\notepad.exe").as_str()),
+///     None,
+///     None,
+///     false,
+///     co::CREATE::NoValue,
+///     Some(&env),
+///     None,
+///     &mut si,
+/// )?;
+/// # w::SysResult::Ok(())
+/// ```
+#[derive(Clone, Default, PartialEq, Eq, Debug)]
+pub struct EnvironmentBlock {
+	vars: BTreeMap<String, (String, String)>, // uppercase name => (name, value)
+}
+
+impl EnvironmentBlock {
+	/// Creates a new, empty environment block.
+	#[must_use]
+	pub const fn new() -> Self {
+		Self { vars: BTreeMap::new() }
+	}
+
+	/// Creates a new environment block with the environment of the current
+	/// process, retrieved with
+	/// [`GetEnvironmentStrings`](https://learn.microsoft.com/en-us/windows/win32/api/processenv/nf-processenv-getenvironmentstringsw).
+	pub fn current() -> SysResult<Self> {
+		let ptr = ptr_to_sysresult(unsafe { ffi::GetEnvironmentStringsW() } as _)?
+			as *const u16;
+		let mut len = 0;
+		while unsafe { *ptr.add(len) != 0 || *ptr.add(len + 1) != 0 } {
+			len += 1;
+		}
+		let parsed = Self::parse(unsafe { std::slice::from_raw_parts(ptr, len + 2) });
+		unsafe { ffi::FreeEnvironmentStringsW(ptr as _); }
+		parsed
+	}
+
+	/// Creates a new environment block with the given name/value pairs.
+	///
+	/// Returns
+	/// [`ERROR::INVALID_PARAMETER`](crate::co::ERROR::INVALID_PARAMETER) if a
+	/// name is invalid, as in
+	/// [`EnvironmentBlock::set`](crate::EnvironmentBlock::set).
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let env = w::EnvironmentBlock::from_pairs([
+	///     ("PATH", "C:\\Tools"),
+	///     ("LANG", "en_US"),
+	/// ])?;
+	/// # w::SysResult::Ok(())
+	/// ```
+	pub fn from_pairs(
+		pairs: impl IntoIterator<Item = (impl AsRef<str>, impl AsRef<str>)>,
+	) -> SysResult<Self>
+	{
+		let mut new_self = Self::new();
+		for (name, value) in pairs {
+			new_self.set(name.as_ref(), value.as_ref())?;
+		}
+		Ok(new_self)
+	}
+
+	/// Parses a native environment block: a sequence of null-terminated
+	/// `name=value` UTF-16 strings, ending with an additional null. Parsing
+	/// stops at the double null or at the end of the slice, whichever comes
+	/// first.
+	///
+	/// Names starting with `=`, like `=C:`, are accepted. If a name appears
+	/// more than once, the last value is kept.
+	///
+	/// Returns
+	/// [`ERROR::INVALID_DATA`](crate::co::ERROR::INVALID_DATA) if an entry has
+	/// no name or `=` separator, or isn't valid UTF-16.
+	pub fn parse(block: &[u16]) -> SysResult<Self> {
+		let mut new_self = Self::new();
+		for entry in block.split(|ch| *ch == 0) {
+			if entry.is_empty() {
+				break;
+			}
+			let entry = String::from_utf16(entry)
+				.map_err(|_| co::ERROR::INVALID_DATA)?;
+			let (name, value) = Self::split_entry(&entry)
+				.ok_or(co::ERROR::INVALID_DATA)?;
+			new_self.vars.insert(upper_name(name), (name.to_owned(), value.to_owned()));
+		}
+		Ok(new_self)
+	}
+
+	/// Emits the native environment block: the `name=value` UTF-16 strings,
+	/// sorted case-insensitively by name, each one null-terminated, followed by
+	/// an additional null.
+	///
+	/// An empty environment block is emitted as two nulls.
+	#[must_use]
+	pub fn to_wide(&self) -> Vec<u16> {
+		let mut block = Vec::<u16>::new();
+		for (name, value) in self.iter() {
+			block.extend(name.encode_utf16());
+			block.push(b'=' as _);
+			block.extend(value.encode_utf16());
+			block.push(0);
+		}
+		if block.is_empty() {
+			block.push(0);
+		}
+		block.push(0);
+		block
+	}
+
+	/// Returns the value of the variable, if present. The name is compared
+	/// case-insensitively.
+	#[must_use]
+	pub fn get(&self, name: &str) -> Option<&str> {
+		self.vars.get(&upper_name(name))
+			.map(|(_, value)| value.as_str())
+	}
+
+	/// Tells whether the variable is present. The name is compared
+	/// case-insensitively.
+	#[must_use]
+	pub fn contains(&self, name: &str) -> bool {
+		self.vars.contains_key(&upper_name(name))
+	}
+
+	/// Adds a variable, or replaces the value of an existing one, whose name
+	/// is compared case-insensitively. When replacing, the case of the new name
+	/// is kept.
+	///
+	/// Returns
+	/// [`ERROR::INVALID_PARAMETER`](crate::co::ERROR::INVALID_PARAMETER) if the
+	/// name is empty, contains a `=` other than the first char, or if the name
+	/// or the value contains a null.
+	pub fn set(&mut self, name: &str, value: &str) -> SysResult<()> {
+		if name.is_empty() || name.chars().skip(1).any(|ch| ch == '=')
+			|| name.contains('\0') || value.contains('\0')
+		{
+			return Err(co::ERROR::INVALID_PARAMETER);
+		}
+		self.vars.insert(upper_name(name), (name.to_owned(), value.to_owned()));
+		Ok(())
+	}
+
+	/// Removes the variable, returning its value, if present. The name is
+	/// compared case-insensitively.
+	pub fn remove(&mut self, name: &str) -> Option<String> {
+		self.vars.remove(&upper_name(name))
+			.map(|(_, value)| value)
+	}
+
+	/// Removes all variables.
+	pub fn clear(&mut self) {
+		self.vars.clear();
+	}
+
+	/// Returns the number of variables.
+	#[must_use]
+	pub fn len(&self) -> usize {
+		self.vars.len()
+	}
+
+	/// Tells whether there are no variables.
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.vars.is_empty()
+	}
+
+	/// Returns an iterator over the name/value pairs, in the order they're
+	/// emitted in the native block.
+	pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+		self.vars.values()
+			.map(|(name, value)| (name.as_str(), value.as_str()))
+	}
+
+	/// Replaces each `%NAME%` reference in the text with the value of the
+	/// variable in this block, following the same rules as
+	/// [`ExpandEnvironmentStrings`](crate::ExpandEnvironmentStrings): names are
+	/// compared case-insensitively, and references to unknown variables are
+	/// kept as they are.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let env = w::EnvironmentBlock::from_pairs([("ROOT", "C:\\App")])?;
+	/// assert_eq!(env.expand("%root%\\bin;%NOPE%"), "C:\\App\\bin;%NOPE%");
+	/// # w::SysResult::Ok(())
+	/// ```
+	#[must_use]
+	pub fn expand(&self, text: &str) -> String {
+		let mut expanded = String::with_capacity(text.len());
+		let mut rest = text;
+
+		while let Some(start) = rest.find('%') {
+			expanded.push_str(&rest[..start]);
+			let after = &rest[start + 1..];
+			match after.find('%') {
+				None => {
+					rest = &rest[start..];
+					break;
+				},
+				Some(end) => match self.get(&after[..end]) {
+					Some(value) if end > 0 => {
+						expanded.push_str(value);
+						rest = &after[end + 1..];
+					},
+					_ => {
+						// Unknown variable: keep it, and let the closing %
+						// start the next reference.
+						expanded.push('%');
+						expanded.push_str(&after[..end]);
+						rest = &after[end..];
+					},
+				},
+			}
+		}
+
+		expanded.push_str(rest);
+		expanded
+	}
+
+	/// Splits a `name=value` entry; the name may start with `=`.
+	#[must_use]
+	fn split_entry(entry: &str) -> Option<(&str, &str)> {
+		let (sep, _) = entry.char_indices().skip(1).find(|(_, ch)| *ch == '=')?;
+		Some((&entry[..sep], &entry[sep + 1..]))
+	}
+}
+
+/// Converts the name to uppercase, char by char, which is how the system
+/// compares and sorts environment variable names.
+#[must_use]
+fn upper_name(name: &str) -> String {
+	name.chars()
+		.map(|ch| {
+			let mut upper = ch.to_uppercase();
+			match (upper.next(), upper.next()) {
+				(Some(up), None) => up,
+				_ => ch, // no single-char uppercase
+			}
+		})
+		.collect()
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	fn wide(text: &str) -> Vec<u16> {
+		text.encode_utf16().collect()
+	}
+
+	#[test]
+	fn parse() {
+		let cases: &[(&str, &[(&str, &str)])] = &[
+			("\0\0", &[]),
+			("\0", &[]),
+			("", &[]),
+			("A=1\0\0", &[("A", "1")]),
+			("A=1", &[("A", "1")]), // no terminator
+			("b=2\0A=1\0\0", &[("A", "1"), ("b", "2")]),
+			("=C:=C:\\Temp\0PATH=C:\\x;C:\\y\0\0", &[("=C:", "C:\\Temp"), ("PATH", "C:\\x;C:\\y")]),
+			("EMPTY=\0X==y\0\0", &[("EMPTY", ""), ("X", "=y")]),
+			("Path=a\0PATH=b\0\0", &[("PATH", "b")]), // last one wins
+			("A=1\0\0B=2\0\0", &[("A", "1")]), // stops at double null
+			("_X=1\0z=2\0Y=3\0\0", &[("Y", "3"), ("z", "2"), ("_X", "1")]),
+		];
+		for (block, pairs) in cases.iter() {
+			let env = EnvironmentBlock::parse(&wide(block)).unwrap();
+			assert_eq!(env.iter().collect::<Vec<_>>(), *pairs, "{:?}", block);
+		}
+
+		let bad: &[&[u16]] = &[
+			&wide("A\0\0"),
+			&wide("=\0\0"),
+			&wide("=C:\0\0"),
+			&wide("A=1\0B\0\0"),
+			&[b'A' as _, b'=' as _, 0xd800, 0, 0], // lone surrogate
+		];
+		for block in bad.iter() {
+			assert_eq!(EnvironmentBlock::parse(block), Err(co::ERROR::INVALID_DATA), "{:?}", block);
+		}
+	}
+
+	#[test]
+	fn to_wide() {
+		let cases: &[(&[(&str, &str)], &str)] = &[
+			(&[], "\0\0"),
+			(&[("A", "1")], "A=1\0\0"),
+			(&[("b", "2"), ("A", "1"), ("=D:", "D:\\")], "=D:=D:\\\0A=1\0b=2\0\0"),
+			(&[("Path", "a"), ("PATH", "b")], "PATH=b\0\0"),
+			(&[("EMPTY", ""), ("ß", "x")], "EMPTY=\0ß=x\0\0"),
+		];
+		for (pairs, block) in cases.iter() {
+			let env = EnvironmentBlock::from_pairs(pairs.iter().copied()).unwrap();
+			assert_eq!(env.to_wide(), wide(block), "{:?}", pairs);
+			assert_eq!(EnvironmentBlock::parse(&env.to_wide()), Ok(env), "{:?}", pairs);
+		}
+	}
+
+	#[test]
+	fn set_and_get() {
+		let mut env = EnvironmentBlock::new();
+		for name in ["", "A=B", "A\0", "=C:="] {
+			assert_eq!(env.set(name, "x"), Err(co::ERROR::INVALID_PARAMETER), "{:?}", name);
+		}
+		assert_eq!(env.set("A", "x\0"), Err(co::ERROR::INVALID_PARAMETER));
+		assert!(env.is_empty());
+
+		env.set("=C:", "C:\\").unwrap();
+		env.set("Temp", "1").unwrap();
+		env.set("TEMP", "2").unwrap();
+		assert_eq!(env.len(), 2);
+		assert_eq!(env.get("temp"), Some("2"));
+		assert!(env.contains("=c:"));
+		assert_eq!(env.iter().nth(1), Some(("TEMP", "2")));
+		assert_eq!(env.remove("tEmP"), Some("2".to_owned()));
+		assert_eq!(env.remove("TEMP"), None);
+		env.clear();
+		assert!(env.is_empty());
+	}
+
+	#[test]
+	fn expand() {
+		let env = EnvironmentBlock::from_pairs([
+			("ROOT", "C:\\App"),
+			("A", "1"),
+			("B", "2"),
+			("Pct", "%A%"),
+		]).unwrap();
+		let cases: &[(&str, &str)] = &[
+			("", ""),
+			("plain", "plain"),
+			("%ROOT%", "C:\\App"),
+			("%root%\\bin;%NOPE%", "C:\\App\\bin;%NOPE%"),
+			("%A%%B%", "12"),
+			("%A%B%", "1B%"),
+			("%X%A%", "%X1"),
+			("100%", "100%"),
+			("%%", "%%"),
+			("%%A%", "%1"),
+			("50% of %A", "50% of %A"),
+			("%PCT%", "%A%"), // not expanded twice
+			("ä%A%ö", "ä1ö"),
+		];
+		for (text, expanded) in cases.iter() {
+			assert_eq!(env.expand(text), *expanded, "{:?}", text);
+		}
+	}
+}
@@ -1,11 +1,26 @@
+mod context_error;
 mod encoding;
+mod environment_block;
 mod file_mapped;
 mod file;
+mod pe_resources;
+mod res_data;
+mod res_file;
+mod time_zone;
 mod w_string;
 
+pub(in crate::kernel) mod calendar;
+pub(in crate::kernel) mod date_format;
+pub mod cmd_line;
 pub mod path;
 
-pub use encoding::Encoding;
+pub use context_error::{ContextError, ErrorCode, ResultContext};
+pub use encoding::{Encoding, EncodingCandidate, EncodingDetector};
+pub use environment_block::EnvironmentBlock;
 pub use file_mapped::FileMapped;
 pub use file::{File, FileAccess};
+pub use pe_resources::{PeResource, PeResources};
+pub use res_data::ResIcon;
+pub use res_file::{ResFile, ResFileEntry};
+pub use time_zone::{LocalTime, TimeZone, TimeZoneRule};
 pub use w_string::WString;
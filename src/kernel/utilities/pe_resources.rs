@@ -0,0 +1,462 @@
+use crate::co;
+use crate::decl::*;
+use crate::kernel::privs::*;
+use crate::kernel::utilities::res_data::*;
+
+/// Read-only, pure-Rust reader of the resource section of a
+/// [Portable Executable](https://learn.microsoft.com/en-us/windows/win32/debug/pe-format)
+/// file (EXE or DLL).
+///
+/// The resource directory tree – type, name and language – is walked once,
+/// when the object is created, and each resource is exposed as a slice over
+/// the original data. No system calls are made, so any byte slice can be
+/// parsed, not only memory-mapped files.
+///
+/// This is the read counterpart of [`HUPDATERSRC`](crate::HUPDATERSRC).
+///
+/// # Examples
+///
+/// Listing all resources of an executable:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let f = w::FileMapped::open(
+///     "C:\\Temp\\foo.exe",
+///     w::FileAccess::ExistingReadOnly,
+/// )?;
+/// let pe = w::PeResources::parse(f.as_slice())?;
+///
+/// for res in pe.resources() {
+///     println!("{} {} {} - {} bytes",
+///         res.res_type, res.name, res.lang_id, res.data.len());
+/// }
+///
+/// if let Some(manifest) = pe.manifest() {
+///     println!("{}", manifest);
+/// }
+/// # w::SysResult::Ok(())
+/// ```
+pub struct PeResources<'a> {
+	resources: Vec<PeResource<'a>>,
+}
+
+/// A single resource found by [`PeResources`](crate::PeResources).
+#[derive(Clone)]
+pub struct PeResource<'a> {
+	/// Resource type, usually a [`co::RT`](crate::co::RT) value.
+	pub res_type: ResId,
+	/// Resource name.
+	pub name: ResId,
+	/// Resource language.
+	pub lang_id: LANGID,
+	/// Code page of the resource data, usually zero.
+	pub code_page: u32,
+	/// Raw resource data.
+	pub data: &'a [u8],
+}
+
+impl<'a> PeResources<'a> {
+	const RESOURCE_DIR_INDEX: usize = 2; // IMAGE_DIRECTORY_ENTRY_RESOURCE
+
+	/// Parses the PE headers of the given file contents and walks its
+	/// resource directory.
+	///
+	/// A file without a resource section yields an empty object. Malformed
+	/// data yields [`ERROR::BAD_EXE_FORMAT`](crate::co::ERROR::BAD_EXE_FORMAT).
+	pub fn parse(data: &'a [u8]) -> SysResult<Self> {
+		Self::parse_headers(data)
+			.map_err(|_| co::ERROR::BAD_EXE_FORMAT)
+	}
+
+	fn parse_headers(data: &'a [u8]) -> SysResult<Self> {
+		let mut rd = RawReader::new(data);
+		if rd.u16()? != 0x5a4d { // MZ
+			return Err(co::ERROR::INVALID_DATA);
+		}
+		rd.seek(0x3c)?;
+		let e_lfanew = rd.u32()? as usize;
+
+		rd.seek(e_lfanew)?;
+		if rd.u32()? != 0x0000_4550 { // PE\0\0
+			return Err(co::ERROR::INVALID_DATA);
+		}
+		rd.skip(2)?; // Machine
+		let num_sections = rd.u16()? as usize;
+		rd.skip(12)?; // TimeDateStamp, PointerToSymbolTable, NumberOfSymbols
+		let opt_hdr_sz = rd.u16()? as usize;
+		rd.skip(2)?; // Characteristics
+
+		let opt_hdr_pos = rd.pos();
+		let (num_dirs_off, dirs_off) = match rd.u16()? {
+			0x10b => (92, 96), // PE32
+			0x20b => (108, 112), // PE32+
+			_ => return Err(co::ERROR::INVALID_DATA),
+		};
+		rd.seek(opt_hdr_pos + num_dirs_off)?;
+		let num_dirs = rd.u32()? as usize;
+		if num_dirs <= Self::RESOURCE_DIR_INDEX {
+			return Ok(Self { resources: Vec::new() });
+		}
+		rd.seek(opt_hdr_pos + dirs_off + Self::RESOURCE_DIR_INDEX * 8)?;
+		let rsrc_rva = rd.u32()?;
+		let rsrc_sz = rd.u32()?;
+		if rsrc_rva == 0 || rsrc_sz == 0 {
+			return Ok(Self { resources: Vec::new() });
+		}
+
+		rd.seek(opt_hdr_pos + opt_hdr_sz)?;
+		let mut sections = Vec::with_capacity(num_sections);
+		for _ in 0..num_sections {
+			rd.skip(8)?; // Name
+			let virtual_sz = rd.u32()?;
+			let virtual_addr = rd.u32()?;
+			let raw_sz = rd.u32()?;
+			let raw_ptr = rd.u32()?;
+			rd.skip(16)?; // relocations, line numbers, Characteristics
+			sections.push(PeSection { virtual_addr, virtual_sz, raw_ptr, raw_sz });
+		}
+
+		let rva_to_offset = |rva: u32| -> SysResult<usize> {
+			sections.iter()
+				.find(|s| rva >= s.virtual_addr
+					&& rva < s.virtual_addr.saturating_add(s.virtual_sz.max(s.raw_sz)))
+				.and_then(|s| ((rva - s.virtual_addr) as usize).checked_add(s.raw_ptr as _))
+				.ok_or(co::ERROR::INVALID_DATA)
+		};
+
+		let rsrc_off = rva_to_offset(rsrc_rva)?;
+		let rsrc = data.get(rsrc_off..).ok_or(co::ERROR::INVALID_DATA)?;
+
+		let mut resources = Vec::new();
+		let mut budget = rsrc.len() / 8; // each entry takes 8 bytes, unless shared by crafted data
+		for (res_type, type_off) in Self::read_dir(rsrc, 0, true, &mut budget)? {
+			for (name, name_off) in Self::read_dir(rsrc, type_off, true, &mut budget)? {
+				for (lang, data_entry_off) in Self::read_dir(rsrc, name_off, false, &mut budget)? {
+					let mut rd = RawReader::new(rsrc);
+					rd.seek(data_entry_off)?;
+					let data_rva = rd.u32()?;
+					let data_sz = rd.u32()? as usize;
+					let code_page = rd.u32()?;
+
+					let data_off = rva_to_offset(data_rva)?;
+					resources.push(PeResource {
+						res_type: res_type.clone(),
+						name: name.clone(),
+						lang_id: match lang {
+							ResId::Id(id) => unsafe { LANGID::from_raw(id) },
+							ResId::Str(_) => LANGID::USER_DEFAULT, // shouldn't happen
+						},
+						code_page,
+						data: data_off.checked_add(data_sz)
+							.and_then(|data_end| data.get(data_off..data_end))
+							.ok_or(co::ERROR::INVALID_DATA)?,
+					});
+				}
+			}
+		}
+
+		Ok(Self { resources })
+	}
+
+	/// Reads the entries of an `IMAGE_RESOURCE_DIRECTORY`, returning their
+	/// identifiers and offsets. If `subdirs` is true, entries must point to
+	/// other directories, otherwise to data entries.
+	///
+	/// The number of entries is subtracted from `budget`, which bounds the walk
+	/// when crafted directories are referenced many times.
+	fn read_dir(
+		rsrc: &[u8],
+		dir_off: usize,
+		subdirs: bool,
+		budget: &mut usize,
+	) -> SysResult<Vec<(ResId, usize)>>
+	{
+		let mut rd = RawReader::new(rsrc);
+		rd.seek(dir_off + 12)?; // skip Characteristics, TimeDateStamp, versions
+		let num_entries = rd.u16()? as usize + rd.u16()? as usize;
+		*budget = budget.checked_sub(num_entries).ok_or(co::ERROR::INVALID_DATA)?;
+
+		let mut entries = Vec::with_capacity(num_entries);
+		for _ in 0..num_entries {
+			let name = rd.u32()?;
+			let off = rd.u32()?;
+
+			let id = if name & 0x8000_0000 != 0 {
+				let mut rd_name = RawReader::new(rsrc);
+				rd_name.seek((name & 0x7fff_ffff) as _)?;
+				let len = rd_name.u16()? as usize;
+				ResId::Str(rd_name.wstr_len(len)?)
+			} else {
+				ResId::Id(name as _)
+			};
+
+			let is_subdir = off & 0x8000_0000 != 0;
+			if is_subdir != subdirs {
+				return Err(co::ERROR::INVALID_DATA);
+			}
+			let off = (off & 0x7fff_ffff) as usize;
+			if subdirs && off <= dir_off {
+				return Err(co::ERROR::INVALID_DATA); // would loop
+			}
+			entries.push((id, off));
+		}
+		Ok(entries)
+	}
+
+	/// Returns all the resources, in directory order.
+	#[must_use]
+	pub fn resources(&self) -> &[PeResource<'a>] {
+		&self.resources
+	}
+
+	/// Returns all resources of the given type. String types are compared
+	/// case-insensitively.
+	#[must_use]
+	pub fn by_type(&self, res_type: &ResId) -> Vec<&PeResource<'a>> {
+		self.resources.iter()
+			.filter(|r| r.res_type.matches(res_type))
+			.collect()
+	}
+
+	/// Returns the data of the given resource. String types and names are
+	/// compared case-insensitively. If `lang_id` is `None`, the first language
+	/// found is returned.
+	#[must_use]
+	pub fn find(&self,
+		res_type: &ResId,
+		name: &ResId,
+		lang_id: Option<LANGID>,
+	) -> Option<&'a [u8]>
+	{
+		self.resources.iter()
+			.find(|r| r.res_type.matches(res_type)
+				&& r.name.matches(name)
+				&& lang_id.is_none_or(|lang_id| r.lang_id == lang_id))
+			.map(|r| r.data)
+	}
+
+	/// Returns the first `RT_MANIFEST` resource, decoded as UTF-8.
+	#[must_use]
+	pub fn manifest(&self) -> Option<String> {
+		self.by_type(&co::RT::MANIFEST.into())
+			.first()
+			.map(|r| decode_manifest(r.data))
+	}
+
+	/// Returns the raw data of the first `RT_VERSION` resource, which can be
+	/// parsed with `VersionInfo::parse`, if the `version` feature is enabled.
+	#[must_use]
+	pub fn version(&self) -> Option<&'a [u8]> {
+		self.by_type(&co::RT::VERSION.into())
+			.first()
+			.map(|r| r.data)
+	}
+
+	/// Returns the string with the given ID from the `RT_STRING` tables. If
+	/// `lang_id` is `None`, the first language found is used.
+	#[must_use]
+	pub fn string(&self, id: u16, lang_id: Option<LANGID>) -> Option<String> {
+		let (block_id, index) = string_block_id(id);
+		self.find(&co::RT::STRING.into(), &ResId::Id(block_id), lang_id)
+			.and_then(|block| string_from_block(block, index))
+	}
+
+	/// Returns the icon images of the given `RT_GROUP_ICON` resource. If
+	/// `lang_id` is `None`, the first language found is used.
+	///
+	/// Icons referenced by the group but not present in the file are skipped.
+	pub fn icons(&self,
+		group_name: &ResId,
+		lang_id: Option<LANGID>,
+	) -> SysResult<Vec<ResIcon<'a>>>
+	{
+		let group = self.find(&co::RT::GROUP_ICON.into(), group_name, lang_id)
+			.ok_or(co::ERROR::RESOURCE_NAME_NOT_FOUND)?;
+		parse_group_icon(group, |id| {
+			self.find(&co::RT::ICON.into(), &ResId::Id(id), lang_id)
+				.or_else(|| self.find(&co::RT::ICON.into(), &ResId::Id(id), None))
+		})
+	}
+}
+
+struct PeSection {
+	virtual_addr: u32,
+	virtual_sz: u32,
+	raw_ptr: u32,
+	raw_sz: u32,
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	const RSRC_RVA: u32 = 0x1000;
+	const RSRC_PTR: usize = 0x200;
+
+	fn put_u16(buf: &mut [u8], off: usize, val: u16) {
+		buf[off..off + 2].copy_from_slice(&val.to_le_bytes());
+	}
+
+	fn put_u32(buf: &mut [u8], off: usize, val: u32) {
+		buf[off..off + 4].copy_from_slice(&val.to_le_bytes());
+	}
+
+	/// Appends an empty `IMAGE_RESOURCE_DIRECTORY`, returning its offset.
+	fn new_dir(buf: &mut Vec<u8>, num_entries: usize) -> usize {
+		let off = buf.len();
+		buf.resize(off + 16 + num_entries * 8, 0);
+		put_u16(buf, off + 14, num_entries as _);
+		off
+	}
+
+	fn set_entry(buf: &mut [u8], dir_off: usize, idx: usize, name: u32, target: u32) {
+		put_u32(buf, dir_off + 16 + idx * 8, name);
+		put_u32(buf, dir_off + 20 + idx * 8, target);
+	}
+
+	/// Builds a resource section with one language per name.
+	fn build_rsrc(res: &[(u16, ResId, u16, &[u8])]) -> Vec<u8> {
+		let mut types = Vec::<(u16, Vec<usize>)>::new();
+		for (idx, (res_type, ..)) in res.iter().enumerate() {
+			match types.iter_mut().find(|(ty, _)| ty == res_type) {
+				Some((_, idxs)) => idxs.push(idx),
+				None => types.push((*res_type, vec![idx])),
+			}
+		}
+
+		let mut buf = Vec::new();
+		let mut names = Vec::new(); // entry offset, string
+		let mut blobs = Vec::new(); // data entry offset, data
+		let root = new_dir(&mut buf, types.len());
+		for (type_idx, (res_type, idxs)) in types.iter().enumerate() {
+			let type_dir = new_dir(&mut buf, idxs.len());
+			set_entry(&mut buf, root, type_idx, *res_type as _, 0x8000_0000 | type_dir as u32);
+			for (name_idx, idx) in idxs.iter().enumerate() {
+				let (_, name, lang, data) = &res[*idx];
+				let name_dir = new_dir(&mut buf, 1);
+				let name_field = match name {
+					ResId::Id(id) => *id as u32,
+					ResId::Str(s) => {
+						names.push((type_dir + 16 + name_idx * 8, s.clone()));
+						0 // patched below
+					},
+				};
+				set_entry(&mut buf, type_dir, name_idx, name_field, 0x8000_0000 | name_dir as u32);
+				let data_entry = buf.len();
+				buf.resize(data_entry + 16, 0);
+				set_entry(&mut buf, name_dir, 0, *lang as _, data_entry as _);
+				blobs.push((data_entry, *data));
+			}
+		}
+
+		for (entry, name) in names {
+			let off = buf.len();
+			let wide = name.encode_utf16().collect::<Vec<_>>();
+			buf.extend_from_slice(&(wide.len() as u16).to_le_bytes());
+			wide.iter().for_each(|ch| buf.extend_from_slice(&ch.to_le_bytes()));
+			put_u32(&mut buf, entry, 0x8000_0000 | off as u32);
+		}
+		for (data_entry, data) in blobs {
+			buf.resize(buf.len().next_multiple_of(4), 0);
+			let off = buf.len();
+			buf.extend_from_slice(data);
+			put_u32(&mut buf, data_entry, RSRC_RVA + off as u32);
+			put_u32(&mut buf, data_entry + 4, data.len() as _);
+		}
+		buf
+	}
+
+	/// Builds a PE32 file with a single `.rsrc` section.
+	fn build_pe(rsrc: &[u8]) -> Vec<u8> {
+		let mut pe = vec![0; RSRC_PTR];
+		pe[..2].copy_from_slice(b"MZ");
+		put_u32(&mut pe, 0x3c, 0x40); // e_lfanew
+		pe[0x40..0x44].copy_from_slice(b"PE\0\0");
+		put_u16(&mut pe, 0x46, 1); // NumberOfSections
+		put_u16(&mut pe, 0x54, 224); // SizeOfOptionalHeader
+		put_u16(&mut pe, 0x58, 0x10b); // PE32
+		put_u32(&mut pe, 0x58 + 92, 16); // NumberOfRvaAndSizes
+		put_u32(&mut pe, 0x58 + 96 + 16, RSRC_RVA);
+		put_u32(&mut pe, 0x58 + 96 + 20, rsrc.len() as _);
+
+		let sec = 0x58 + 224;
+		pe[sec..sec + 8].copy_from_slice(b".rsrc\0\0\0");
+		put_u32(&mut pe, sec + 8, rsrc.len() as _); // VirtualSize
+		put_u32(&mut pe, sec + 12, RSRC_RVA);
+		put_u32(&mut pe, sec + 16, rsrc.len() as _); // SizeOfRawData
+		put_u32(&mut pe, sec + 20, RSRC_PTR as _);
+		pe.extend_from_slice(rsrc);
+		pe
+	}
+
+	#[test]
+	fn parse() {
+		let manifest = b"\xef\xbb\xbf<assembly/>";
+		let pe = build_pe(&build_rsrc(&[
+			(co::RT::MANIFEST.raw(), ResId::Id(1), 0x0409, manifest),
+			(co::RT::RCDATA.raw(), ResId::Str("CONFIG".to_owned()), 0x0409, b"abc"),
+			(co::RT::RCDATA.raw(), ResId::Id(7), 0x0416, b"xyz!"),
+		]));
+		let res = PeResources::parse(&pe).unwrap();
+
+		assert_eq!(res.resources().len(), 3);
+		assert_eq!(res.manifest().as_deref(), Some("<assembly/>"));
+		assert_eq!(res.by_type(&co::RT::RCDATA.into()).len(), 2);
+		assert_eq!(res.find(&co::RT::RCDATA.into(), &"CONFIG".into(), None), Some(&b"abc"[..]));
+		assert_eq!(res.find(&co::RT::RCDATA.into(), &"config".into(), None), Some(&b"abc"[..]));
+		assert_eq!(res.find(&co::RT::RCDATA.into(), &"CONFIGS".into(), None), None);
+		assert_eq!(res.find(&"RCDATA".into(), &"CONFIG".into(), None), None); // string isn't the numeric type
+		assert_eq!(res.find(&co::RT::RCDATA.into(), &7.into(), None), Some(&b"xyz!"[..]));
+		assert_eq!(res.find(&co::RT::RCDATA.into(), &7.into(),
+			Some(unsafe { LANGID::from_raw(0x0409) })), None);
+		assert_eq!(res.version(), None);
+
+		let empty = build_pe(&build_rsrc(&[]));
+		assert!(PeResources::parse(&empty).unwrap().resources().is_empty());
+	}
+
+	#[test]
+	fn malformed() {
+		let rsrc = build_rsrc(&[(co::RT::RCDATA.raw(), ResId::Id(1), 0, b"data")]);
+		let pe = build_pe(&rsrc);
+		assert!(PeResources::parse(&pe).is_ok());
+
+		for len in [0, 2, 0x3c, 0x40, 0x58, 0x138, RSRC_PTR, RSRC_PTR + 20, pe.len() - 1] {
+			assert_eq!(PeResources::parse(&pe[..len]).err(), Some(co::ERROR::BAD_EXE_FORMAT),
+				"truncated to {}", len);
+		}
+
+		let data_entry = rsrc.len() - 4 - 16; // data entry, then the 4 data bytes
+		let patched = |off: usize, val: u32| {
+			let mut pe = pe.clone();
+			put_u32(&mut pe, RSRC_PTR + off, val);
+			PeResources::parse(&pe).err()
+		};
+		assert_eq!(patched(data_entry + 4, u32::MAX), Some(co::ERROR::BAD_EXE_FORMAT)); // size
+		assert_eq!(patched(data_entry, u32::MAX), Some(co::ERROR::BAD_EXE_FORMAT)); // RVA
+		assert_eq!(patched(20, 0x8000_0000), Some(co::ERROR::BAD_EXE_FORMAT)); // back-reference
+		assert_eq!(patched(20, 16), Some(co::ERROR::BAD_EXE_FORMAT)); // data entry at type level
+	}
+
+	#[test]
+	fn shared_directories() {
+		// Each level has N entries all pointing to the same next directory, so
+		// a naive walk visits N^3 data entries.
+		const N: usize = 200;
+		let mut rsrc = Vec::new();
+		let root = new_dir(&mut rsrc, N);
+		let type_dir = new_dir(&mut rsrc, N);
+		let name_dir = new_dir(&mut rsrc, N);
+		let data_entry = rsrc.len();
+		rsrc.resize(data_entry + 16, 0);
+		put_u32(&mut rsrc, data_entry, RSRC_RVA);
+		for idx in 0..N {
+			set_entry(&mut rsrc, root, idx, idx as _, 0x8000_0000 | type_dir as u32);
+			set_entry(&mut rsrc, type_dir, idx, idx as _, 0x8000_0000 | name_dir as u32);
+			set_entry(&mut rsrc, name_dir, idx, idx as _, data_entry as _);
+		}
+
+		assert_eq!(PeResources::parse(&build_pe(&rsrc)).err(), Some(co::ERROR::BAD_EXE_FORMAT));
+	}
+}
@@ -0,0 +1,86 @@
+//! Decoding of resource data formats shared by
+//! [`PeResources`](crate::PeResources) and [`ResFile`](crate::ResFile).
+
+use crate::co;
+use crate::decl::*;
+use crate::kernel::privs::*;
+
+/// An icon image referenced by an `RT_GROUP_ICON` resource, returned by
+/// [`PeResources::icons`](crate::PeResources::icons) and
+/// [`ResFile::icons`](crate::ResFile::icons).
+#[derive(Clone)]
+pub struct ResIcon<'a> {
+	/// Width in pixels; zero means 256.
+	pub width: u8,
+	/// Height in pixels; zero means 256.
+	pub height: u8,
+	/// Number of colors, or zero if 8 bpp or more.
+	pub color_count: u8,
+	/// Number of color planes.
+	pub planes: u16,
+	/// Bits per pixel.
+	pub bit_count: u16,
+	/// Identifier of the `RT_ICON` resource.
+	pub id: u16,
+	/// Raw image data of the `RT_ICON` resource, either a DIB or a PNG.
+	pub data: &'a [u8],
+}
+
+/// Decodes an `RT_MANIFEST` resource as UTF-8, skipping the BOM, if any.
+#[must_use]
+pub(in crate::kernel) fn decode_manifest(data: &[u8]) -> String {
+	let (_, bom_sz) = Encoding::guess(data);
+	String::from_utf8_lossy(&data[bom_sz..]).into_owned()
+}
+
+/// Returns the `RT_STRING` block which contains the given string ID, and the
+/// index of the string within the block.
+#[must_use]
+pub(in crate::kernel) const fn string_block_id(id: u16) -> (u16, u16) {
+	((id >> 4) + 1, id & 0x0f)
+}
+
+/// Extracts a string from an `RT_STRING` block. Strings are stored in blocks of
+/// 16, each one prefixed with its length.
+#[must_use]
+pub(in crate::kernel) fn string_from_block(block: &[u8], index: u16) -> Option<String> {
+	let mut rd = RawReader::new(block);
+	for _ in 0..index {
+		let len = rd.u16().ok()? as usize;
+		rd.skip(len * 2).ok()?;
+	}
+	let len = rd.u16().ok()? as usize;
+	rd.wstr_len(len).ok()
+}
+
+/// Parses an `RT_GROUP_ICON` resource, retrieving each `RT_ICON` with the given
+/// closure. Icons not found are skipped.
+pub(in crate::kernel) fn parse_group_icon<'a>(
+	group: &[u8],
+	find_icon: impl Fn(u16) -> Option<&'a [u8]>,
+) -> SysResult<Vec<ResIcon<'a>>>
+{
+	let mut rd = RawReader::new(group);
+	rd.skip(2)?; // idReserved
+	if rd.u16()? != 1 { // idType
+		return Err(co::ERROR::INVALID_DATA);
+	}
+	let count = rd.u16()?;
+
+	let mut icons = Vec::with_capacity(count as _);
+	for _ in 0..count { // GRPICONDIRENTRY
+		let width = rd.u8()?;
+		let height = rd.u8()?;
+		let color_count = rd.u8()?;
+		rd.skip(1)?; // bReserved
+		let planes = rd.u16()?;
+		let bit_count = rd.u16()?;
+		rd.skip(4)?; // dwBytesInRes
+		let id = rd.u16()?;
+
+		if let Some(data) = find_icon(id) {
+			icons.push(ResIcon { width, height, color_count, planes, bit_count, id, data });
+		}
+	}
+	Ok(icons)
+}
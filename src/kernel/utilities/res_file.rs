@@ -0,0 +1,418 @@
+use crate::co;
+use crate::decl::*;
+use crate::kernel::privs::*;
+use crate::kernel::utilities::res_data::*;
+use crate::prelude::*;
+
+/// Size of a `RESOURCEHEADER` with ordinal type and name, the smallest one.
+const RES_HEADER_MIN_SZ: usize = 32;
+
+/// Owned, pure-Rust representation of a compiled 32-bit resource file, usually
+/// with `.res` extension, as produced by resource compilers and editors.
+///
+/// The file is a sequence of
+/// [`RESOURCEHEADER`](https://learn.microsoft.com/en-us/windows/win32/menurc/resourceheader)
+/// entries, each one followed by its data. Entries can be inspected, added,
+/// replaced, removed and merged from another file, then serialized back.
+///
+/// Entries are identified by type, name and language; string types and names
+/// are compared ASCII case-insensitively, as the system does.
+///
+/// # Examples
+///
+/// Merging the resources of two files, and writing the result:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let mut base = w::ResFile::parse(
+///     w::FileMapped::open("C:\\Temp\\base.res", w::FileAccess::ExistingReadOnly)?
+///         .as_slice(),
+/// )?;
+/// let extra = w::ResFile::parse(
+///     w::FileMapped::open("C:\\Temp\\extra.res", w::FileAccess::ExistingReadOnly)?
+///         .as_slice(),
+/// )?;
+/// base.merge(extra);
+///
+/// let fout = w::File::open("C:\\Temp\\merged.res", w::FileAccess::OpenOrCreateRW)?;
+/// fout.erase_and_write(&base.serialize())?;
+/// # w::SysResult::Ok(())
+/// ```
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct ResFile {
+	entries: Vec<ResFileEntry>,
+}
+
+/// A single resource of a [`ResFile`](crate::ResFile).
+#[derive(Clone, PartialEq, Eq)]
+pub struct ResFileEntry {
+	/// Resource type, usually a [`co::RT`](crate::co::RT) value.
+	pub res_type: ResId,
+	/// Resource name.
+	pub name: ResId,
+	/// Resource language.
+	pub lang_id: LANGID,
+	/// `DataVersion` field, usually zero.
+	pub data_version: u32,
+	/// `MemoryFlags` field, a combination of the `MOVEABLE` (`0x0010`),
+	/// `PURE` (`0x0020`), `PRELOAD` (`0x0040`) and `DISCARDABLE` (`0x1000`)
+	/// flags.
+	pub memory_flags: u16,
+	/// User-defined `Version` field.
+	pub version: u32,
+	/// User-defined `Characteristics` field.
+	pub characteristics: u32,
+	/// Raw resource data.
+	pub data: Vec<u8>,
+}
+
+impl ResFileEntry {
+	/// Creates a new entry with the usual `MOVEABLE | PURE | DISCARDABLE`
+	/// memory flags, and zero in all other fields.
+	#[must_use]
+	pub fn new(res_type: ResId, name: ResId, lang_id: LANGID, data: Vec<u8>) -> Self {
+		Self {
+			res_type,
+			name,
+			lang_id,
+			data_version: 0,
+			memory_flags: 0x1030,
+			version: 0,
+			characteristics: 0,
+			data,
+		}
+	}
+}
+
+impl ResFile {
+	/// Creates a new, empty object.
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Parses the contents of a 32-bit `.res` file.
+	///
+	/// The file must start with the empty entry which identifies the 32-bit
+	/// format, otherwise
+	/// [`ERROR::INVALID_DATA`](crate::co::ERROR::INVALID_DATA) is returned.
+	pub fn parse(data: &[u8]) -> SysResult<Self> {
+		let mut rd = RawReader::new(data);
+		let mut entries = Vec::new();
+		let mut first = true;
+
+		while rd.remaining() >= 8 {
+			let start = rd.pos();
+			let data_sz = rd.u32()? as usize;
+			let header_sz = rd.u32()? as usize;
+			let res_type = Self::read_id(&mut rd)?;
+			let name = Self::read_id(&mut rd)?;
+			rd.align(4)?;
+			let data_version = rd.u32()?;
+			let memory_flags = rd.u16()?;
+			let lang_id = unsafe { LANGID::from_raw(rd.u16()?) };
+			let version = rd.u32()?;
+			let characteristics = rd.u32()?;
+
+			if header_sz < RES_HEADER_MIN_SZ || start + header_sz < rd.pos() {
+				return Err(co::ERROR::INVALID_DATA); // header overlaps its own fields, and could loop forever
+			}
+			rd.seek(start + header_sz)?;
+			let res_data = rd.bytes(data_sz)?;
+			rd.align_lenient(4);
+
+			let is_empty = data_sz == 0 && res_type == ResId::Id(0) && name == ResId::Id(0);
+			if first && !is_empty {
+				return Err(co::ERROR::INVALID_DATA); // 16-bit or garbage
+			}
+			first = false;
+
+			if !is_empty {
+				entries.push(ResFileEntry {
+					res_type,
+					name,
+					lang_id,
+					data_version,
+					memory_flags,
+					version,
+					characteristics,
+					data: res_data.to_vec(),
+				});
+			}
+		}
+
+		if first {
+			return Err(co::ERROR::INVALID_DATA); // not even the empty entry
+		}
+		Ok(Self { entries })
+	}
+
+	fn read_id(rd: &mut RawReader) -> SysResult<ResId> {
+		let mut wchars = Vec::<u16>::new();
+		loop {
+			match rd.u16()? {
+				0xffff if wchars.is_empty() => return Ok(ResId::Id(rd.u16()?)),
+				0x0000 => break,
+				wch => wchars.push(wch),
+			}
+		}
+		Ok(ResId::Str(String::from_utf16_lossy(&wchars)))
+	}
+
+	fn write_id(wr: &mut RawWriter, id: &ResId) {
+		match id {
+			ResId::Id(id) => {
+				wr.u16(0xffff);
+				wr.u16(*id);
+			},
+			ResId::Str(s) => wr.wstr_nullt(s),
+		}
+	}
+
+	/// Serializes all the entries into the contents of a 32-bit `.res` file,
+	/// which can be parsed back with [`ResFile::parse`](crate::ResFile::parse).
+	#[must_use]
+	pub fn serialize(&self) -> Vec<u8> {
+		let mut wr = RawWriter::new();
+		Self::write_entry(&mut wr, &ResFileEntry {
+			res_type: ResId::Id(0),
+			name: ResId::Id(0),
+			lang_id: unsafe { LANGID::from_raw(0) },
+			data_version: 0,
+			memory_flags: 0,
+			version: 0,
+			characteristics: 0,
+			data: Vec::new(),
+		});
+		self.entries.iter()
+			.for_each(|entry| Self::write_entry(&mut wr, entry));
+		wr.into_vec()
+	}
+
+	fn write_entry(wr: &mut RawWriter, entry: &ResFileEntry) {
+		let start = wr.pos();
+		wr.u32(entry.data.len() as _);
+		wr.u32(0); // HeaderSize, patched below
+		Self::write_id(wr, &entry.res_type);
+		Self::write_id(wr, &entry.name);
+		wr.align(4);
+		wr.u32(entry.data_version);
+		wr.u16(entry.memory_flags);
+		wr.u16(entry.lang_id.into());
+		wr.u32(entry.version);
+		wr.u32(entry.characteristics);
+		wr.patch_u32(start + 4, (wr.pos() - start) as _);
+		wr.bytes(&entry.data);
+		wr.align(4);
+	}
+
+	/// Returns all the entries, in file order.
+	#[must_use]
+	pub fn entries(&self) -> &[ResFileEntry] {
+		&self.entries
+	}
+
+	/// Returns all the entries, allowing them to be modified.
+	#[must_use]
+	pub fn entries_mut(&mut self) -> &mut Vec<ResFileEntry> {
+		&mut self.entries
+	}
+
+	/// Returns the entry with the given type, name and language, if any. String
+	/// types and names are compared case-insensitively. If `lang_id` is `None`,
+	/// the first language found is returned.
+	#[must_use]
+	pub fn find(&self,
+		res_type: &ResId,
+		name: &ResId,
+		lang_id: Option<LANGID>,
+	) -> Option<&ResFileEntry>
+	{
+		self.entries.iter()
+			.find(|e| e.res_type.matches(res_type)
+				&& e.name.matches(name)
+				&& lang_id.is_none_or(|lang_id| e.lang_id == lang_id))
+	}
+
+	/// Returns all entries of the given type. String types are compared
+	/// case-insensitively.
+	#[must_use]
+	pub fn by_type(&self, res_type: &ResId) -> Vec<&ResFileEntry> {
+		self.entries.iter()
+			.filter(|e| e.res_type.matches(res_type))
+			.collect()
+	}
+
+	/// Adds an entry, replacing the one with the same type, name and language,
+	/// which is returned.
+	pub fn insert(&mut self, entry: ResFileEntry) -> Option<ResFileEntry> {
+		match self.entries.iter_mut()
+			.find(|e| e.res_type.matches(&entry.res_type)
+				&& e.name.matches(&entry.name)
+				&& e.lang_id == entry.lang_id)
+		{
+			Some(cur) => Some(std::mem::replace(cur, entry)),
+			None => {
+				self.entries.push(entry);
+				None
+			},
+		}
+	}
+
+	/// Removes the entry with the given type, name and language, returning it.
+	pub fn remove(&mut self,
+		res_type: &ResId,
+		name: &ResId,
+		lang_id: LANGID,
+	) -> Option<ResFileEntry>
+	{
+		self.entries.iter()
+			.position(|e| e.res_type.matches(res_type)
+				&& e.name.matches(name)
+				&& e.lang_id == lang_id)
+			.map(|idx| self.entries.remove(idx))
+	}
+
+	/// Inserts all entries of `other`, replacing the existing ones with the
+	/// same type, name and language.
+	pub fn merge(&mut self, other: ResFile) {
+		other.entries.into_iter()
+			.for_each(|entry| { self.insert(entry); });
+	}
+
+	/// Returns the entries which are different between the two files: those
+	/// only present in `self`, and those only present in `other` or with
+	/// different contents, respectively.
+	#[must_use]
+	pub fn diff<'a>(&'a self,
+		other: &'a ResFile,
+	) -> (Vec<&'a ResFileEntry>, Vec<&'a ResFileEntry>)
+	{
+		let key_eq = |a: &ResFileEntry, b: &ResFileEntry| {
+			a.res_type.matches(&b.res_type) && a.name.matches(&b.name) && a.lang_id == b.lang_id
+		};
+		(
+			self.entries.iter()
+				.filter(|e| !other.entries.iter().any(|o| key_eq(e, o)))
+				.collect(),
+			other.entries.iter()
+				.filter(|o| !self.entries.iter().any(|e| *e == **o))
+				.collect(),
+		)
+	}
+
+	/// Returns the first `RT_MANIFEST` resource, decoded as UTF-8.
+	#[must_use]
+	pub fn manifest(&self) -> Option<String> {
+		self.by_type(&co::RT::MANIFEST.into())
+			.first()
+			.map(|e| decode_manifest(&e.data))
+	}
+
+	/// Returns the string with the given ID from the `RT_STRING` tables. If
+	/// `lang_id` is `None`, the first language found is used.
+	#[must_use]
+	pub fn string(&self, id: u16, lang_id: Option<LANGID>) -> Option<String> {
+		let (block_id, index) = string_block_id(id);
+		self.find(&co::RT::STRING.into(), &ResId::Id(block_id), lang_id)
+			.and_then(|e| string_from_block(&e.data, index))
+	}
+
+	/// Returns the icon images of the given `RT_GROUP_ICON` resource. If
+	/// `lang_id` is `None`, the first language found is used.
+	///
+	/// Icons referenced by the group but not present in the file are skipped.
+	pub fn icons(&self,
+		group_name: &ResId,
+		lang_id: Option<LANGID>,
+	) -> SysResult<Vec<ResIcon<'_>>>
+	{
+		let group = self.find(&co::RT::GROUP_ICON.into(), group_name, lang_id)
+			.ok_or(co::ERROR::RESOURCE_NAME_NOT_FOUND)?;
+		parse_group_icon(&group.data, |id| {
+			self.find(&co::RT::ICON.into(), &ResId::Id(id), lang_id)
+				.or_else(|| self.find(&co::RT::ICON.into(), &ResId::Id(id), None))
+				.map(|e| e.data.as_slice())
+		})
+	}
+
+	/// Writes all entries into an executable or DLL with
+	/// [`HUPDATERSRC::UpdateResource`](crate::prelude::kernel_Hupdatersrc::UpdateResource).
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let res = w::ResFile::parse(
+	///     w::FileMapped::open("C:\\Temp\\app.res", w::FileAccess::ExistingReadOnly)?
+	///         .as_slice(),
+	/// )?;
+	///
+	/// let hupsrc = w::HUPDATERSRC::BeginUpdateResource("C:\\Temp\\app.exe", false)?;
+	/// res.update_resources(&hupsrc)?;
+	/// // EndUpdateResource() called automatically
+	/// # w::SysResult::Ok(())
+	/// ```
+	pub fn update_resources(&self, hupsrc: &HUPDATERSRC) -> SysResult<()> {
+		self.entries.iter()
+			.try_for_each(|e| {
+				hupsrc.UpdateResource(
+					e.res_type.to_rtstr(),
+					e.name.to_idstr(),
+					e.lang_id,
+					&e.data,
+				)
+			})
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	fn entry(res_type: ResId, name: ResId, lang: u16, data: &[u8]) -> ResFileEntry {
+		ResFileEntry::new(res_type, name, unsafe { LANGID::from_raw(lang) }, data.to_vec())
+	}
+
+	#[test]
+	fn lookups() {
+		let mut res = ResFile::new();
+		res.insert(entry("MyType".into(), "Config".into(), 0x0409, b"abc"));
+		res.insert(entry(co::RT::RCDATA.into(), 7.into(), 0x0409, b"xyz"));
+		let en = Some(unsafe { LANGID::from_raw(0x0409) });
+
+		type Key = (ResId, ResId, Option<LANGID>);
+		let cases: &[(Key, Option<&[u8]>)] = &[
+			(("MyType".into(), "Config".into(), None), Some(b"abc")),
+			(("MYTYPE".into(), "config".into(), en), Some(b"abc")),
+			(("mytype".into(), "CONFIG".into(), None), Some(b"abc")),
+			(("MyType".into(), "Config2".into(), None), None),
+			(("MyType".into(), "Config".into(), Some(unsafe { LANGID::from_raw(0x0416) })), None),
+			((co::RT::RCDATA.into(), 7.into(), None), Some(b"xyz")),
+			((co::RT::RCDATA.into(), "7".into(), None), None),
+		];
+		for ((res_type, name, lang_id), data) in cases.iter() {
+			assert_eq!(res.find(res_type, name, *lang_id).map(|e| e.data.as_slice()), *data,
+				"{} {}", res_type, name);
+		}
+		assert_eq!(res.by_type(&"MYTYPE".into()).len(), 1);
+	}
+
+	#[test]
+	fn insert_and_remove() {
+		let mut res = ResFile::new();
+		assert!(res.insert(entry("MyType".into(), "Config".into(), 0x0409, b"abc")).is_none());
+		let old = res.insert(entry("MYTYPE".into(), "CONFIG".into(), 0x0409, b"def"));
+		assert_eq!(old.map(|e| e.data), Some(b"abc".to_vec()));
+		assert_eq!(res.entries().len(), 1);
+
+		let other = ResFile::parse(&res.serialize()).unwrap();
+		assert!(res.diff(&other).0.is_empty());
+
+		let removed = res.remove(&"mytype".into(), &"config".into(), unsafe { LANGID::from_raw(0x0409) });
+		assert_eq!(removed.map(|e| e.data), Some(b"def".to_vec()));
+		assert!(res.entries().is_empty());
+	}
+}
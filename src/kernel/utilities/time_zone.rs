@@ -0,0 +1,581 @@
+use std::collections::BTreeMap;
+
+use crate::co;
+use crate::decl::*;
+use crate::kernel::utilities::calendar::*;
+
+/// A single set of time zone transition rules, as found in
+/// [`TIME_ZONE_INFORMATION`](crate::TIME_ZONE_INFORMATION) and in the
+/// `REG_TZI_FORMAT` values of the registry time zone database.
+///
+/// Biases are in minutes, following the Windows convention
+/// `UTC = local time + bias`.
+///
+/// Each transition date is either:
+///
+/// * a "day-in-month" rule, when `wYear` is zero: `wDay` is the occurrence
+///   (1 to 5, where 5 means the last one) of the `wDayOfWeek` weekday in
+///   `wMonth`;
+/// * an absolute date, when `wYear` is not zero, which applies only to that
+///   year.
+///
+/// If the `wMonth` of either date is zero, the rule has no daylight saving
+/// time.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TimeZoneRule {
+	/// Base bias, in minutes.
+	pub bias: i32,
+	/// Bias added to `bias` during standard time, in minutes.
+	pub standard_bias: i32,
+	/// Bias added to `bias` during daylight saving time, in minutes.
+	pub daylight_bias: i32,
+	/// Transition from daylight saving time to standard time, in daylight
+	/// local time.
+	pub standard_date: SYSTEMTIME,
+	/// Transition from standard time to daylight saving time, in standard
+	/// local time.
+	pub daylight_date: SYSTEMTIME,
+}
+
+impl From<&TIME_ZONE_INFORMATION> for TimeZoneRule {
+	fn from(tzi: &TIME_ZONE_INFORMATION) -> Self {
+		Self {
+			bias: tzi.bias,
+			standard_bias: tzi.standardBias,
+			daylight_bias: tzi.daylightBias,
+			standard_date: tzi.standardDate,
+			daylight_date: tzi.daylightDate,
+		}
+	}
+}
+
+impl TimeZoneRule {
+	/// Size of the binary `REG_TZI_FORMAT` struct.
+	pub const REG_TZI_SIZE: usize = 44;
+
+	/// Parses the binary `REG_TZI_FORMAT` struct stored in the `TZI` values of
+	/// the registry time zone database.
+	///
+	/// Returns
+	/// [`ERROR::INVALID_DATA`](crate::co::ERROR::INVALID_DATA) if the data has
+	/// the wrong size.
+	pub fn from_reg_tzi(data: &[u8]) -> SysResult<Self> {
+		if data.len() != Self::REG_TZI_SIZE {
+			return Err(co::ERROR::INVALID_DATA);
+		}
+		let i32_at = |off: usize| i32::from_le_bytes(data[off..off + 4].try_into().unwrap());
+		let st_at = |off: usize| {
+			let w = |n: usize| u16::from_le_bytes(data[off + n * 2..off + n * 2 + 2].try_into().unwrap());
+			SYSTEMTIME {
+				wYear: w(0),
+				wMonth: w(1),
+				wDayOfWeek: w(2),
+				wDay: w(3),
+				wHour: w(4),
+				wMinute: w(5),
+				wSecond: w(6),
+				wMilliseconds: w(7),
+			}
+		};
+		Ok(Self {
+			bias: i32_at(0),
+			standard_bias: i32_at(4),
+			daylight_bias: i32_at(8),
+			standard_date: st_at(12),
+			daylight_date: st_at(28),
+		})
+	}
+
+	/// Serializes the rule into the binary `REG_TZI_FORMAT` struct.
+	#[must_use]
+	pub fn to_reg_tzi(&self) -> Vec<u8> {
+		let mut buf = Vec::with_capacity(Self::REG_TZI_SIZE);
+		for n in [self.bias, self.standard_bias, self.daylight_bias] {
+			buf.extend_from_slice(&n.to_le_bytes());
+		}
+		for st in [&self.standard_date, &self.daylight_date] {
+			for w in [st.wYear, st.wMonth, st.wDayOfWeek, st.wDay,
+				st.wHour, st.wMinute, st.wSecond, st.wMilliseconds]
+			{
+				buf.extend_from_slice(&w.to_le_bytes());
+			}
+		}
+		buf
+	}
+
+	/// Tells whether the rule has daylight saving time transitions.
+	#[must_use]
+	pub const fn has_daylight(&self) -> bool {
+		self.standard_date.wMonth != 0 && self.daylight_date.wMonth != 0
+	}
+
+	/// Returns the UTC instants when daylight saving time starts and ends in
+	/// the given year, or `None` if the rule has no transitions in that year.
+	///
+	/// In the southern hemisphere, daylight saving time starts after it ends
+	/// within the same year.
+	pub fn transitions(&self, year: u16) -> SysResult<Option<(FILETIME, FILETIME)>> {
+		Ok(match self.transition_ticks(year as _)? {
+			Some((start, end)) => Some((ticks_to_filetime(start)?, ticks_to_filetime(end)?)),
+			None => None,
+		})
+	}
+
+	fn transition_ticks(&self, year: i64) -> SysResult<Option<(i64, i64)>> {
+		if !self.has_daylight() {
+			return Ok(None);
+		}
+		let start = match Self::local_transition(&self.daylight_date, year)? {
+			Some(t) => t + (self.bias + self.standard_bias) as i64 * TICKS_PER_MIN,
+			None => return Ok(None),
+		};
+		let end = match Self::local_transition(&self.standard_date, year)? {
+			Some(t) => t + (self.bias + self.daylight_bias) as i64 * TICKS_PER_MIN,
+			None => return Ok(None),
+		};
+		Ok(Some((start, end)))
+	}
+
+	fn local_transition(date: &SYSTEMTIME, year: i64) -> SysResult<Option<i64>> {
+		if date.wYear != 0 { // absolute date
+			return if date.wYear as i64 == year {
+				systemtime_to_ticks(date).map(Some)
+			} else {
+				Ok(None)
+			};
+		}
+
+		let (month, week, dow) = (date.wMonth as i64, date.wDay as i64, date.wDayOfWeek as i64);
+		if !(1..=12).contains(&month) || !(1..=5).contains(&week) || dow > 6 {
+			return Err(co::ERROR::INVALID_PARAMETER);
+		}
+		let first_dow = day_of_week(days_from_civil(year, month, 1));
+		let mut day = 1 + (dow - first_dow).rem_euclid(7) + (week - 1) * 7;
+		while day > days_in_month(year, month) {
+			day -= 7; // 5th occurrence doesn't exist, use the last one
+		}
+
+		systemtime_to_ticks(&SYSTEMTIME {
+			wYear: year as _,
+			wMonth: month as _,
+			wDay: day as _,
+			..*date
+		}).map(Some)
+	}
+}
+
+/// Result of converting a local time to UTC with
+/// [`TimeZone::local_to_utc`](crate::TimeZone::local_to_utc).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LocalTime<T> {
+	/// The local time corresponds to a single UTC time.
+	Unique(T),
+	/// The local time happens twice, because the clocks were set back.
+	Ambiguous {
+		/// The UTC time of the first occurrence, before the transition.
+		earlier: T,
+		/// The UTC time of the second occurrence, after the transition.
+		later: T,
+	},
+	/// The local time never happens, because the clocks were set forward.
+	Skipped {
+		/// The UTC time obtained with the bias in effect before the
+		/// transition, which lies after it.
+		shifted: T,
+	},
+}
+
+impl<T> LocalTime<T> {
+	/// Returns the earliest UTC time: the unique one, the first occurrence of
+	/// an ambiguous time, or the shifted time of a skipped one.
+	#[must_use]
+	pub fn earliest(self) -> T {
+		match self {
+			Self::Unique(t) | Self::Skipped { shifted: t } => t,
+			Self::Ambiguous { earlier, .. } => earlier,
+		}
+	}
+
+	/// Converts the contained times.
+	pub fn try_map<U, E>(self, mut f: impl FnMut(T) -> Result<U, E>) -> Result<LocalTime<U>, E> {
+		Ok(match self {
+			Self::Unique(t) => LocalTime::Unique(f(t)?),
+			Self::Ambiguous { earlier, later } => LocalTime::Ambiguous {
+				earlier: f(earlier)?,
+				later: f(later)?,
+			},
+			Self::Skipped { shifted } => LocalTime::Skipped { shifted: f(shifted)? },
+		})
+	}
+}
+
+/// Time zone rules, with optional per-year dynamic daylight saving time rules,
+/// which convert between UTC and local time without calling the operating
+/// system.
+///
+/// Can be built from a
+/// [`TIME_ZONE_INFORMATION`](crate::TIME_ZONE_INFORMATION), or loaded from the
+/// registry time zone database with
+/// [`TimeZone::from_registry`](crate::TimeZone::from_registry).
+///
+/// # Examples
+///
+/// US Eastern time:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let tz = w::TimeZone::from_rule(w::TimeZoneRule {
+///     bias: 300,
+///     standard_bias: 0,
+///     daylight_bias: -60,
+///     standard_date: w::SYSTEMTIME { // 1st Sunday of November, 2 AM
+///         wMonth: 11, wDay: 1, wDayOfWeek: 0, wHour: 2, ..Default::default()
+///     },
+///     daylight_date: w::SYSTEMTIME { // 2nd Sunday of March, 2 AM
+///         wMonth: 3, wDay: 2, wDayOfWeek: 0, wHour: 2, ..Default::default()
+///     },
+/// });
+///
+/// let utc = w::SYSTEMTIME {
+///     wYear: 2024, wMonth: 7, wDay: 1, wHour: 12, ..Default::default()
+/// };
+/// let local = tz.utc_to_local_st(&utc)?;
+/// assert_eq!(local.wHour, 8);
+/// # w::SysResult::Ok(())
+/// ```
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+pub struct TimeZone {
+	/// Name displayed to the user, like "(UTC-05:00) Eastern Time (US &
+	/// Canada)".
+	pub display_name: String,
+	/// Name of the standard time.
+	pub standard_name: String,
+	/// Name of the daylight saving time.
+	pub daylight_name: String,
+	/// Rule used when there are no dynamic rules.
+	pub rule: TimeZoneRule,
+	/// Dynamic rules, keyed by the first year they apply to. Years before the
+	/// first key use the first rule.
+	pub dynamic_rules: BTreeMap<u16, TimeZoneRule>,
+}
+
+impl From<&TIME_ZONE_INFORMATION> for TimeZone {
+	fn from(tzi: &TIME_ZONE_INFORMATION) -> Self {
+		Self {
+			display_name: String::new(),
+			standard_name: tzi.standardName(),
+			daylight_name: tzi.daylightName(),
+			rule: tzi.into(),
+			dynamic_rules: BTreeMap::new(),
+		}
+	}
+}
+
+impl TimeZone {
+	/// Creates a new `TimeZone` with a single rule and no names.
+	#[must_use]
+	pub fn from_rule(rule: TimeZoneRule) -> Self {
+		Self { rule, ..Default::default() }
+	}
+
+	/// Returns the rule in effect for the given year.
+	#[must_use]
+	pub fn rule_for_year(&self, year: u16) -> &TimeZoneRule {
+		self.dynamic_rules.range(..=year)
+			.next_back()
+			.or_else(|| self.dynamic_rules.iter().next())
+			.map(|(_, rule)| rule)
+			.unwrap_or(&self.rule)
+	}
+
+	/// Returns a [`TIME_ZONE_INFORMATION`](crate::TIME_ZONE_INFORMATION) with
+	/// the names and the rule in effect for the given year.
+	#[must_use]
+	pub fn to_tzi(&self, year: u16) -> TIME_ZONE_INFORMATION {
+		let rule = self.rule_for_year(year);
+		let mut tzi = TIME_ZONE_INFORMATION::default();
+		tzi.bias = rule.bias;
+		tzi.standardDate = rule.standard_date;
+		tzi.standardBias = rule.standard_bias;
+		tzi.daylightDate = rule.daylight_date;
+		tzi.daylightBias = rule.daylight_bias;
+		tzi.set_standardName(&self.standard_name);
+		tzi.set_daylightName(&self.daylight_name);
+		tzi
+	}
+
+	/// Returns the UTC instants when daylight saving time starts and ends in
+	/// the given year, using the rule in effect for that year.
+	pub fn transitions(&self, year: u16) -> SysResult<Option<(FILETIME, FILETIME)>> {
+		self.rule_for_year(year).transitions(year)
+	}
+
+	/// Returns the total bias, in minutes, in effect at the given UTC time.
+	pub fn bias_at(&self, utc: FILETIME) -> SysResult<i32> {
+		self.bias_at_ticks(filetime_to_ticks(utc)?)
+	}
+
+	/// Tells whether daylight saving time is in effect at the given UTC time.
+	pub fn is_daylight(&self, utc: FILETIME) -> SysResult<bool> {
+		let ticks = filetime_to_ticks(utc)?;
+		let rule = self.rule_for_ticks(ticks)?;
+		self.is_daylight_ticks(rule, ticks)
+	}
+
+	/// Converts an UTC time into local time.
+	pub fn utc_to_local(&self, utc: FILETIME) -> SysResult<FILETIME> {
+		let ticks = filetime_to_ticks(utc)?;
+		ticks_to_filetime(ticks - self.bias_at_ticks(ticks)? as i64 * TICKS_PER_MIN)
+	}
+
+	/// Converts an UTC time into local time.
+	///
+	/// The `wDayOfWeek` field of the input is ignored, and filled in the
+	/// output.
+	pub fn utc_to_local_st(&self, utc: &SYSTEMTIME) -> SysResult<SYSTEMTIME> {
+		let ticks = systemtime_to_ticks(utc)?;
+		ticks_to_systemtime(ticks - self.bias_at_ticks(ticks)? as i64 * TICKS_PER_MIN)
+	}
+
+	/// Converts a local time into UTC, reporting local times which are
+	/// ambiguous or skipped due to daylight saving time transitions.
+	pub fn local_to_utc(&self, local: FILETIME) -> SysResult<LocalTime<FILETIME>> {
+		self.local_to_utc_ticks(filetime_to_ticks(local)?)?
+			.try_map(ticks_to_filetime)
+	}
+
+	/// Converts a local time into UTC, reporting local times which are
+	/// ambiguous or skipped due to daylight saving time transitions.
+	///
+	/// The `wDayOfWeek` field of the input is ignored, and filled in the
+	/// output.
+	pub fn local_to_utc_st(&self, local: &SYSTEMTIME) -> SysResult<LocalTime<SYSTEMTIME>> {
+		self.local_to_utc_ticks(systemtime_to_ticks(local)?)?
+			.try_map(ticks_to_systemtime)
+	}
+
+	fn rule_for_ticks(&self, utc: i64) -> SysResult<&TimeZoneRule> {
+		// The year is picked from the standard local time, like Windows does.
+		let std_bias = (self.rule.bias + self.rule.standard_bias) as i64;
+		let (year, _, _) = civil_from_days(
+			(utc - std_bias * TICKS_PER_MIN).div_euclid(TICKS_PER_DAY));
+		let year = u16::try_from(year).map_err(|_| co::ERROR::INVALID_PARAMETER)?;
+		Ok(self.rule_for_year(year))
+	}
+
+	fn is_daylight_ticks(&self, rule: &TimeZoneRule, utc: i64) -> SysResult<bool> {
+		let std_bias = (rule.bias + rule.standard_bias) as i64;
+		let (year, _, _) = civil_from_days(
+			(utc - std_bias * TICKS_PER_MIN).div_euclid(TICKS_PER_DAY));
+		Ok(match rule.transition_ticks(year)? {
+			None => false,
+			Some((start, end)) if start < end => utc >= start && utc < end,
+			Some((start, end)) => utc >= start || utc < end, // southern hemisphere
+		})
+	}
+
+	fn bias_at_ticks(&self, utc: i64) -> SysResult<i32> {
+		let rule = self.rule_for_ticks(utc)?;
+		Ok(rule.bias + if self.is_daylight_ticks(rule, utc)? {
+			rule.daylight_bias
+		} else {
+			rule.standard_bias
+		})
+	}
+
+	fn local_to_utc_ticks(&self, local: i64) -> SysResult<LocalTime<i64>> {
+		let (year, _, _) = civil_from_days(local.div_euclid(TICKS_PER_DAY));
+		let year = u16::try_from(year).map_err(|_| co::ERROR::INVALID_PARAMETER)?;
+		let rule = self.rule_for_year(year);
+
+		let mut candidates = [
+			local + (rule.bias + rule.standard_bias) as i64 * TICKS_PER_MIN,
+			local + (rule.bias + rule.daylight_bias) as i64 * TICKS_PER_MIN,
+		];
+		candidates.sort_unstable();
+		let is_valid = |utc: i64| self.bias_at_ticks(utc)
+			.map(|bias| utc - bias as i64 * TICKS_PER_MIN == local);
+
+		Ok(match (is_valid(candidates[0])?, is_valid(candidates[1])?) {
+			(true, true) if candidates[0] != candidates[1] => LocalTime::Ambiguous {
+				earlier: candidates[0],
+				later: candidates[1],
+			},
+			(true, _) => LocalTime::Unique(candidates[0]),
+			(false, true) => LocalTime::Unique(candidates[1]),
+			(false, false) => LocalTime::Skipped {
+				shifted: candidates[1],
+			},
+		})
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	/// Builds a day-in-month transition date.
+	fn nth(month: u16, week: u16, dow: u16, hour: u16) -> SYSTEMTIME {
+		SYSTEMTIME { wMonth: month, wDay: week, wDayOfWeek: dow, wHour: hour, ..Default::default() }
+	}
+
+	fn ft(year: u16, month: u16, day: u16, hour: u16, minute: u16) -> FILETIME {
+		let st = SYSTEMTIME {
+			wYear: year, wMonth: month, wDay: day, wHour: hour, wMinute: minute,
+			..Default::default()
+		};
+		ticks_to_filetime(systemtime_to_ticks(&st).unwrap()).unwrap()
+	}
+
+	fn us_eastern() -> TimeZone {
+		TimeZone::from_rule(TimeZoneRule {
+			bias: 300,
+			standard_bias: 0,
+			daylight_bias: -60,
+			standard_date: nth(11, 1, 0, 2), // 1st Sunday of November
+			daylight_date: nth(3, 2, 0, 2), // 2nd Sunday of March
+		})
+	}
+
+	fn uk() -> TimeZone {
+		TimeZone::from_rule(TimeZoneRule {
+			bias: 0,
+			standard_bias: 0,
+			daylight_bias: -60,
+			standard_date: nth(10, 5, 0, 2), // last Sunday of October
+			daylight_date: nth(3, 5, 0, 1), // last Sunday of March
+		})
+	}
+
+	fn sydney() -> TimeZone {
+		TimeZone::from_rule(TimeZoneRule {
+			bias: -600,
+			standard_bias: 0,
+			daylight_bias: -60,
+			standard_date: nth(4, 1, 0, 3), // 1st Sunday of April
+			daylight_date: nth(10, 1, 0, 2), // 1st Sunday of October
+		})
+	}
+
+	#[test]
+	fn transitions() {
+		let cases: &[(TimeZone, u16, FILETIME, FILETIME)] = &[
+			(us_eastern(), 2024, ft(2024, 3, 10, 7, 0), ft(2024, 11, 3, 6, 0)),
+			(us_eastern(), 2023, ft(2023, 3, 12, 7, 0), ft(2023, 11, 5, 6, 0)),
+			// March 2024 has 5 Sundays, October 2024 only 4
+			(uk(), 2024, ft(2024, 3, 31, 1, 0), ft(2024, 10, 27, 1, 0)),
+			(uk(), 2025, ft(2025, 3, 30, 1, 0), ft(2025, 10, 26, 1, 0)),
+			// southern hemisphere: starts after it ends
+			(sydney(), 2024, ft(2024, 10, 5, 16, 0), ft(2024, 4, 6, 16, 0)),
+		];
+		for (tz, year, start, end) in cases.iter() {
+			assert_eq!(tz.transitions(*year), Ok(Some((*start, *end))), "{:?} {}", tz.rule, year);
+		}
+
+		assert_eq!(TimeZone::from_rule(TimeZoneRule { bias: -60, ..Default::default() })
+			.transitions(2024), Ok(None));
+		let bad_week = TimeZone::from_rule(TimeZoneRule {
+			daylight_date: nth(3, 6, 0, 2),
+			..us_eastern().rule
+		});
+		assert_eq!(bad_week.transitions(2024), Err(co::ERROR::INVALID_PARAMETER));
+	}
+
+	#[test]
+	fn utc_to_local() {
+		let cases: &[(TimeZone, FILETIME, FILETIME, bool)] = &[
+			(us_eastern(), ft(2024, 1, 15, 12, 0), ft(2024, 1, 15, 7, 0), false),
+			(us_eastern(), ft(2024, 7, 1, 12, 0), ft(2024, 7, 1, 8, 0), true),
+			(us_eastern(), ft(2024, 3, 10, 6, 59), ft(2024, 3, 10, 1, 59), false),
+			(us_eastern(), ft(2024, 3, 10, 7, 0), ft(2024, 3, 10, 3, 0), true),
+			(us_eastern(), ft(2024, 11, 3, 5, 59), ft(2024, 11, 3, 1, 59), true),
+			(us_eastern(), ft(2024, 11, 3, 6, 0), ft(2024, 11, 3, 1, 0), false),
+			(sydney(), ft(2024, 1, 1, 0, 0), ft(2024, 1, 1, 11, 0), true),
+			(sydney(), ft(2024, 7, 1, 0, 0), ft(2024, 7, 1, 10, 0), false),
+			(sydney(), ft(2024, 12, 31, 13, 30), ft(2025, 1, 1, 0, 30), true),
+		];
+		for (tz, utc, local, daylight) in cases.iter() {
+			assert_eq!(tz.utc_to_local(*utc), Ok(*local), "{:?} {:?}", tz.rule, utc);
+			assert_eq!(tz.is_daylight(*utc), Ok(*daylight), "{:?} {:?}", tz.rule, utc);
+		}
+	}
+
+	#[test]
+	fn local_to_utc() {
+		let cases: &[(TimeZone, FILETIME, LocalTime<FILETIME>)] = &[
+			(us_eastern(), ft(2024, 7, 1, 12, 0), LocalTime::Unique(ft(2024, 7, 1, 16, 0))),
+			(us_eastern(), ft(2024, 1, 1, 12, 0), LocalTime::Unique(ft(2024, 1, 1, 17, 0))),
+			(us_eastern(), ft(2024, 3, 10, 2, 30),
+				LocalTime::Skipped { shifted: ft(2024, 3, 10, 7, 30) }),
+			(us_eastern(), ft(2024, 11, 3, 1, 30), LocalTime::Ambiguous {
+				earlier: ft(2024, 11, 3, 5, 30),
+				later: ft(2024, 11, 3, 6, 30),
+			}),
+			(uk(), ft(2024, 3, 31, 1, 0), LocalTime::Skipped { shifted: ft(2024, 3, 31, 1, 0) }),
+			(uk(), ft(2024, 10, 27, 1, 59), LocalTime::Ambiguous {
+				earlier: ft(2024, 10, 27, 0, 59),
+				later: ft(2024, 10, 27, 1, 59),
+			}),
+			(sydney(), ft(2024, 10, 6, 2, 30),
+				LocalTime::Skipped { shifted: ft(2024, 10, 5, 16, 30) }),
+			(sydney(), ft(2024, 4, 7, 2, 30), LocalTime::Ambiguous {
+				earlier: ft(2024, 4, 6, 15, 30),
+				later: ft(2024, 4, 6, 16, 30),
+			}),
+		];
+		for (tz, local, utc) in cases.iter() {
+			assert_eq!(tz.local_to_utc(*local), Ok(*utc), "{:?} {:?}", tz.rule, local);
+		}
+
+		let local = SYSTEMTIME { wYear: 2024, wMonth: 11, wDay: 3, wHour: 1, ..Default::default() };
+		let utc = us_eastern().local_to_utc_st(&local).unwrap().earliest();
+		assert_eq!((utc.wDay, utc.wHour, utc.wDayOfWeek), (3, 5, 0));
+	}
+
+	#[test]
+	fn dynamic_rules() {
+		let mut tz = us_eastern();
+		let old = TimeZoneRule {
+			standard_date: nth(10, 5, 0, 2), // last Sunday of October
+			daylight_date: nth(4, 1, 0, 2), // 1st Sunday of April
+			..tz.rule
+		};
+		tz.dynamic_rules.insert(2006, old);
+		tz.dynamic_rules.insert(2007, tz.rule);
+
+		assert_eq!(tz.rule_for_year(1990), &old);
+		assert_eq!(tz.rule_for_year(2006), &old);
+		assert_eq!(tz.rule_for_year(2024), &tz.rule);
+		assert_eq!(tz.transitions(2006), Ok(Some((ft(2006, 4, 2, 7, 0), ft(2006, 10, 29, 6, 0)))));
+		assert_eq!(tz.transitions(2007), Ok(Some((ft(2007, 3, 11, 7, 0), ft(2007, 11, 4, 6, 0)))));
+		assert_eq!(tz.to_tzi(2006).daylightDate, old.daylight_date);
+	}
+
+	#[test]
+	fn reg_tzi() {
+		let rules = [
+			TimeZoneRule::default(),
+			us_eastern().rule,
+			sydney().rule,
+			TimeZoneRule {
+				bias: -345,
+				standard_bias: 0,
+				daylight_bias: -60,
+				standard_date: SYSTEMTIME { wYear: 2024, wMonth: 10, wDay: 27, wHour: 3, ..Default::default() },
+				daylight_date: SYSTEMTIME { wYear: 2024, wMonth: 3, wDay: 31, wHour: 2, ..Default::default() },
+			},
+		];
+		for rule in rules.iter() {
+			let data = rule.to_reg_tzi();
+			assert_eq!(data.len(), TimeZoneRule::REG_TZI_SIZE);
+			assert_eq!(TimeZoneRule::from_reg_tzi(&data).as_ref(), Ok(rule));
+		}
+
+		let data = us_eastern().rule.to_reg_tzi();
+		assert_eq!(&data[..12], &[0x2c, 0x01, 0, 0, 0, 0, 0, 0, 0xc4, 0xff, 0xff, 0xff]);
+		assert_eq!(&data[12..20], &[0, 0, 11, 0, 0, 0, 1, 0]); // year, month, dow, day
+		assert_eq!(TimeZoneRule::from_reg_tzi(&data[1..]), Err(co::ERROR::INVALID_DATA));
+		assert_eq!(TimeZoneRule::from_reg_tzi(&[]), Err(co::ERROR::INVALID_DATA));
+	}
+}
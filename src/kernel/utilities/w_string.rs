@@ -309,7 +309,7 @@ impl WString {
 				| Encoding::Utf32le
 				| Encoding::Scsu
 				| Encoding::Bocu1
-				| Encoding::Unknown => panic!("Encoding {} not implemented.", encoding),
+				| Encoding::Unknown => encoding.decode(data).encode_utf16().collect(),
 			}
 		))
 	}
@@ -6,6 +6,9 @@
 
 #[macro_use] mod macros;
 
+// The derive macros refer to ::winsafe, so they can be tested in the crate.
+#[cfg(all(test, feature = "derive"))] extern crate self as winsafe;
+
 // Declarations of modules themselves.
 
 #[cfg(feature = "advapi")] mod advapi;
@@ -157,6 +160,7 @@ pub mod msg {
 
 		pub use super::super::user::messages::em::*;
 		#[cfg(feature = "comctl")] pub use super::super::comctl::messages::em::*;
+		#[cfg(all(feature = "comctl", feature = "gdi"))] pub use super::super::comctl_gdi::messages::em::*;
 	}
 
 	#[cfg(feature = "comctl")]
@@ -252,6 +256,15 @@ pub mod msg {
 		pub use super::super::comctl::messages::trbm::*;
 	}
 
+	#[cfg(feature = "comctl")]
+	pub mod ttm {
+		//! Tooltip control
+		//! [messages](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-tooltip-control-reference-messages),
+		//! whose constants have [`TTM`](crate::co::TTM) prefix.
+
+		pub use super::super::comctl::messages::ttm::*;
+	}
+
 	#[cfg(feature = "comctl")]
 	pub mod tvm {
 		//! Tree view control
@@ -447,3 +447,49 @@ macro_rules! const_str {
 		}
 	};
 }
+
+/// Implements serde `Serialize` and `Deserialize` for a constant type, as the
+/// underlying integer. Deserialization only accepts the listed `values` or,
+/// for `flags`, any combination of them; other integers are rejected, so safe
+/// code can't build arbitrary constants.
+macro_rules! const_serde {
+	(
+		$name:ty : $ntype:ty; values
+		$( $valname:ident )*
+	) => {
+		const_serde!(@impl $name: $ntype,
+			|v: $ntype| [$( <$name>::$valname.raw() ),*].contains(&v));
+	};
+
+	(
+		$name:ty : $ntype:ty; flags
+		$( $valname:ident )*
+	) => {
+		const_serde!(@impl $name: $ntype,
+			|v: $ntype| v & !(0 $( | <$name>::$valname.raw() )*) == 0);
+	};
+
+	(@impl $name:ty : $ntype:ty, $is_valid:expr) => {
+		#[cfg(feature = "serde")]
+		impl serde::Serialize for $name {
+			fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+				serde::Serialize::serialize(&self.raw(), serializer)
+			}
+		}
+
+		#[cfg(feature = "serde")]
+		impl<'de> serde::Deserialize<'de> for $name {
+			fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+				let v = <$ntype as serde::Deserialize>::deserialize(deserializer)?;
+				if ($is_valid)(v) {
+					Ok(unsafe { <$name>::from_raw(v) })
+				} else {
+					Err(serde::de::Error::invalid_value(
+						serde::de::Unexpected::Unsigned(v as _),
+						&concat!("a valid ", stringify!($name), " value"),
+					))
+				}
+			}
+		}
+	};
+}
@@ -112,6 +112,114 @@ impl HRESULT {
 	pub const fn severity(self) -> co::SEVERITY {
 		unsafe { co::SEVERITY::from_raw(((self.0 >> 31) & 0x1) as _) }
 	}
+
+	/// [`MAKE_HRESULT`](https://learn.microsoft.com/en-us/windows/win32/api/winerror/nf-winerror-make_hresult)
+	/// macro.
+	#[must_use]
+	pub const fn from_parts(
+		severity: co::SEVERITY,
+		facility: co::FACILITY,
+		code: u16,
+	) -> Self
+	{
+		Self(((severity.raw() as u32) << 31) | ((facility.raw() & 0x1fff) << 16) | code as u32)
+	}
+
+	/// [`SUCCEEDED`](https://learn.microsoft.com/en-us/windows/win32/api/winerror/nf-winerror-succeeded)
+	/// macro.
+	#[must_use]
+	pub const fn succeeded(self) -> bool {
+		(self.0 as i32) >= 0
+	}
+
+	/// Returns the [`ERROR`](crate::co::ERROR) wrapped by
+	/// [`ERROR::to_hresult`](crate::co::ERROR::to_hresult), if the facility is
+	/// [`FACILITY::WIN32`](crate::co::FACILITY::WIN32) and the severity is
+	/// [`SEVERITY::FAILURE`](crate::co::SEVERITY::FAILURE). Also returns
+	/// [`ERROR::SUCCESS`](crate::co::ERROR::SUCCESS) for
+	/// [`S_OK`](crate::co::HRESULT::S_OK).
+	///
+	/// This is the inverse of the
+	/// [`HRESULT_FROM_WIN32`](https://learn.microsoft.com/en-us/windows/win32/api/winerror/nf-winerror-hresult_from_win32)
+	/// macro.
+	#[must_use]
+	pub const fn to_win32(self) -> Option<co::ERROR> {
+		if self.0 == 0 {
+			Some(co::ERROR::SUCCESS)
+		} else if self.facility().raw() == co::FACILITY::WIN32.raw()
+			&& self.severity().raw() == co::SEVERITY::FAILURE.raw()
+		{
+			Some(unsafe { co::ERROR::from_raw(self.code() as _) })
+		} else {
+			None
+		}
+	}
+}
+
+#[cfg(feature = "error-catalog")]
+impl HRESULT {
+	/// Returns the name of the constant, like `"E_INVALIDARG"`, taken from an
+	/// embedded catalog, without system calls.
+	///
+	/// If the `HRESULT` wraps an [`ERROR`](crate::co::ERROR) not listed as an
+	/// `HRESULT` constant, the name of the `ERROR` constant is returned.
+	#[must_use]
+	pub fn name(self) -> Option<&'static str> {
+		self.catalog_entry()
+			.map(|(_, name, _)| *name)
+			.or_else(|| self.to_win32().and_then(|err| err.name()))
+	}
+
+	/// Returns the English message of the error, taken from an embedded
+	/// catalog, without system calls.
+	///
+	/// If the `HRESULT` wraps an [`ERROR`](crate::co::ERROR), the message of
+	/// the `ERROR` is used when the `HRESULT` itself has none. Not all
+	/// constants have a message in the catalog.
+	#[must_use]
+	pub fn message(self) -> Option<&'static str> {
+		self.catalog_entry()
+			.map(|(_, _, msg)| *msg)
+			.filter(|msg| !msg.is_empty())
+			.or_else(|| self.to_win32().and_then(|err| err.message()))
+	}
+
+	/// Returns a description with the error code, the constant name, the
+	/// facility and the English message, whenever available, without system
+	/// calls.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// assert_eq!(
+	///     co::HRESULT::E_INVALIDARG.describe(),
+	///     "[0x80070057] E_INVALIDARG (facility 7, code 87): The parameter is incorrect.",
+	/// );
+	/// ```
+	#[must_use]
+	pub fn describe(self) -> String {
+		let mut s = if self.0 > 0xffff {
+			format!("[{:#010x}] {} (facility {}, code {})",
+				self.0, self.name().unwrap_or("Unknown error"),
+				self.facility().raw(), self.code())
+		} else {
+			format!("[{:#06x}] {}", self.0, self.name().unwrap_or("Unknown error"))
+		};
+		if let Some(msg) = self.message() {
+			s.push_str(": ");
+			s.push_str(msg);
+		}
+		s
+	}
+
+	fn catalog_entry(self) -> Option<&'static (u32, &'static str, &'static str)> {
+		super::hresult_catalog::HRESULT_CATALOG
+			.binary_search_by_key(&self.0, |(code, _, _)| *code)
+			.ok()
+			.map(|idx| &super::hresult_catalog::HRESULT_CATALOG[idx])
+	}
 }
 
 const_values! { HRESULT;
@@ -3337,3 +3445,61 @@ const_values! { HRESULT;
 	WINML_ERR_VALUE_NOTFOUND 0x8890_0003
 	WINML_ERR_SIZE_MISMATCH 0x8890_0004
 }
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn parts() {
+		let cases: &[(co::SEVERITY, co::FACILITY, u16, u32, Option<u32>)] = &[
+			(co::SEVERITY::SUCCESS, co::FACILITY::NULL, 0, 0x0000_0000, Some(0)),
+			(co::SEVERITY::SUCCESS, co::FACILITY::NULL, 1, 0x0000_0001, None),
+			(co::SEVERITY::FAILURE, co::FACILITY::WIN32, 87, 0x8007_0057, Some(87)),
+			(co::SEVERITY::FAILURE, co::FACILITY::WIN32, 2, 0x8007_0002, Some(2)),
+			(co::SEVERITY::SUCCESS, co::FACILITY::WIN32, 2, 0x0007_0002, None),
+			(co::SEVERITY::FAILURE, co::FACILITY::ITF, 0x0200, 0x8004_0200, None),
+		];
+		for (severity, facility, code, raw, win32) in cases.iter() {
+			let hr = HRESULT::from_parts(*severity, *facility, *code);
+			assert_eq!(hr.raw(), *raw);
+			assert_eq!(hr.severity(), *severity, "{:#x}", raw);
+			assert_eq!(hr.facility(), *facility, "{:#x}", raw);
+			assert_eq!(hr.code(), *code, "{:#x}", raw);
+			assert_eq!(hr.succeeded(), *severity == co::SEVERITY::SUCCESS, "{:#x}", raw);
+			assert_eq!(hr.to_win32().map(|e| e.raw()), *win32, "{:#x}", raw);
+			if let Some(err) = hr.to_win32().filter(|_| *raw != 0) {
+				assert_eq!(err.to_hresult().raw(), *raw);
+			}
+		}
+		assert_eq!(co::ERROR::SUCCESS.to_hresult().raw(), 0);
+	}
+
+	#[cfg(feature = "error-catalog")]
+	#[test]
+	fn catalog_sorted() {
+		let catalog = super::super::hresult_catalog::HRESULT_CATALOG;
+		assert!(catalog.windows(2).all(|w| w[0].0 < w[1].0));
+		assert!(catalog.iter().all(|(_, name, _)| !name.is_empty()));
+	}
+
+	#[cfg(feature = "error-catalog")]
+	#[test]
+	fn describe() {
+		let cases: &[(u32, Option<&str>, &str)] = &[
+			(0x0000_0000, Some("S_OK"), "[0x0000] S_OK: The operation completed successfully."),
+			(0x0000_0001, Some("S_FALSE"), "[0x0001] S_FALSE: The operation completed successfully, but returned false."),
+			(0x8000_4001, Some("E_NOTIMPL"), "[0x80004001] E_NOTIMPL (facility 0, code 16385): Not implemented"),
+			(0x8007_0057, Some("E_INVALIDARG"), "[0x80070057] E_INVALIDARG (facility 7, code 87): The parameter is incorrect."),
+			(0x8007_0002, Some("FILE_NOT_FOUND"), // falls back to the wrapped ERROR
+				"[0x80070002] FILE_NOT_FOUND (facility 7, code 2): The system cannot find the file specified."),
+			(0x8007_fffe, None, "[0x8007fffe] Unknown error (facility 7, code 65534)"),
+			(0x0000_0002, None, "[0x0002] Unknown error"),
+		];
+		for (raw, name, expected) in cases.iter() {
+			let hr = unsafe { HRESULT::from_raw(*raw) };
+			assert_eq!(hr.name(), *name, "{:#x}", raw);
+			assert_eq!(hr.describe(), *expected, "{:#x}", raw);
+		}
+	}
+}
@@ -0,0 +1,3228 @@
+#![cfg(feature = "error-catalog")]
+
+//! Offline catalog of [`HRESULT`](crate::co::HRESULT) constants. Generated by
+//! `error-catalog/gen.py` from hresult.rs and `error-catalog/hresult.txt`; do
+//! not edit by hand.
+
+/// Sorted by code: code, constant name, English message (empty if unknown).
+pub(in crate::ole) const HRESULT_CATALOG: &[(u32, &str, &str)] = &[
+	(0x0, "S_OK", "The operation completed successfully."),
+	(0x1, "S_FALSE", "The operation completed successfully, but returned false."),
+	(0x30200, "STG_S_CONVERTED", "The underlying file was converted to compound file format."),
+	(0x30201, "STG_S_BLOCK", "The storage operation should block until more data is available."),
+	(0x30202, "STG_S_RETRYNOW", "The storage operation should retry immediately."),
+	(0x30203, "STG_S_MONITORING", "The notified event sink will not influence the storage operation."),
+	(0x30204, "STG_S_MULTIPLEOPENS", "Multiple opens prevent consolidated (commit succeeded)."),
+	(0x30205, "STG_S_CONSOLIDATIONFAILED", "Consolidation of the storage file failed (commit succeeded)."),
+	(0x30206, "STG_S_CANNOTCONSOLIDATE", "Consolidation of the storage file is inappropriate (commit succeeded)."),
+	(0x30207, "STG_S_POWER_CYCLE_REQUIRED", "The device needs to be power cycled. (commit succeeded)."),
+	(0x40000, "OLE_S_USEREG", "Use the registry database to provide the requested information."),
+	(0x40001, "OLE_S_STATIC", "Success, but static."),
+	(0x40002, "OLE_S_MAC_CLIPFORMAT", "Macintosh clipboard format."),
+	(0x40100, "DRAGDROP_S_DROP", "Successful drop took place."),
+	(0x40101, "DRAGDROP_S_CANCEL", "Drag-drop operation canceled."),
+	(0x40102, "DRAGDROP_S_USEDEFAULTCURSORS", "Use the default cursor."),
+	(0x40130, "DATA_S_SAMEFORMATETC", "Data has same FORMATETC."),
+	(0x40140, "VIEW_S_ALREADY_FROZEN", "View is already frozen."),
+	(0x40170, "CACHE_S_FORMATETC_NOTSUPPORTED", "FORMATETC not supported."),
+	(0x40171, "CACHE_S_SAMECACHE", "Same cache."),
+	(0x40172, "CACHE_S_SOMECACHES_NOTUPDATED", "Some cache(s) not updated."),
+	(0x40180, "OLEOBJ_S_INVALIDVERB", "Invalid verb for OLE object."),
+	(0x40181, "OLEOBJ_S_CANNOT_DOVERB_NOW", "Verb number is valid but verb cannot be done now."),
+	(0x40182, "OLEOBJ_S_INVALIDHWND", "Invalid window handle passed."),
+	(0x401a0, "INPLACE_S_TRUNCATED", "Message is too long; some of it had to be truncated before displaying."),
+	(0x401c0, "CONVERT10_S_NO_PRESENTATION", "Unable to convert OLESTREAM to IStorage."),
+	(0x401e2, "MK_S_REDUCED_TO_SELF", "Moniker reduced to itself."),
+	(0x401e4, "MK_S_ME", "Common prefix is this moniker."),
+	(0x401e5, "MK_S_HIM", "Common prefix is input moniker."),
+	(0x401e6, "MK_S_US", "Common prefix is both monikers."),
+	(0x401e7, "MK_S_MONIKERALREADYREGISTERED", "Moniker is already registered in running object table."),
+	(0x40200, "EVENT_S_SOME_SUBSCRIBERS_FAILED", "An event was able to invoke some but not all of the subscribers."),
+	(0x40202, "EVENT_S_NOSUBSCRIBERS", "An event was delivered but there were no subscribers."),
+	(0x40252, "TPC_S_TRUNCATED", "The data has been truncated."),
+	(0x40253, "TPC_S_INTERRUPTED", "The collection has been interrupted."),
+	(0x40254, "TPC_S_NO_DATA_TO_PROCESS", "No personalization update is necessary."),
+	(0x41300, "SCHED_S_TASK_READY", "The task is ready to run at its next scheduled time."),
+	(0x41301, "SCHED_S_TASK_RUNNING", "The task is currently running."),
+	(0x41302, "SCHED_S_TASK_DISABLED", "The task will not run at the scheduled times because it has been disabled."),
+	(0x41303, "SCHED_S_TASK_HAS_NOT_RUN", "The task has not yet run."),
+	(0x41304, "SCHED_S_TASK_NO_MORE_RUNS", "There are no more runs scheduled for this task."),
+	(0x41305, "SCHED_S_TASK_NOT_SCHEDULED", "One or more of the properties that are needed to run this task on a schedule have not been set."),
+	(0x41306, "SCHED_S_TASK_TERMINATED", "The last run of the task was terminated by the user."),
+	(0x41307, "SCHED_S_TASK_NO_VALID_TRIGGERS", "Either the task has no triggers or the existing triggers are disabled or not set."),
+	(0x41308, "SCHED_S_EVENT_TRIGGER", "Event triggers do not have set run times."),
+	(0x4131b, "SCHED_S_SOME_TRIGGERS_FAILED", "The task is registered, but not all specified triggers will start the task."),
+	(0x4131c, "SCHED_S_BATCH_LOGON_PROBLEM", "The task is registered, but may fail to start. Batch logon privilege needs to be enabled for the task principal."),
+	(0x41325, "SCHED_S_TASK_QUEUED", "The Task Scheduler service has asked the task to run."),
+	(0x4d000, "XACT_S_ASYNC", "An asynchronous operation was specified. The operation has begun, but its outcome is not known yet."),
+	(0x4d001, "XACT_S_DEFECT", "The transaction completed with a defect."),
+	(0x4d002, "XACT_S_READONLY", "The method call succeeded because the transaction was read-only."),
+	(0x4d003, "XACT_S_SOMENORETAIN", "The transaction was successfully aborted. However, this is a coordinated transaction, and some number of enlisted resources were aborted outright because they could not support abort-retaining semantics."),
+	(0x4d004, "XACT_S_OKINFORM", "No changes were made during this call, but the sink wants another chance to look if any other sinks make further changes."),
+	(0x4d005, "XACT_S_MADECHANGESCONTENT", "The sink is content and wishes the transaction to proceed. Changes were made to one or more resources during this call."),
+	(0x4d006, "XACT_S_MADECHANGESINFORM", "The sink is content for the moment and wishes the transaction to proceed, but if other changes are made following this return by other event sinks then this sink wants another chance to look."),
+	(0x4d007, "XACT_S_ALLNORETAIN", "The transaction was successfully aborted. However, the abort was non-retaining."),
+	(0x4d008, "XACT_S_ABORTING", "An abort operation was already in progress."),
+	(0x4d009, "XACT_S_SINGLEPHASE", "The resource manager has performed a single-phase commit of the transaction."),
+	(0x4d00a, "XACT_S_LOCALLY_OK", "The local transaction has not aborted."),
+	(0x4d010, "XACT_S_LASTRESOURCEMANAGER", "The resource manager has requested to be the coordinator (last resource manager) for the transaction."),
+	(0x80012, "CO_S_NOTALLINTERFACES", "Not all the requested interfaces were available."),
+	(0x80013, "CO_S_MACHINENAMENOTFOUND", "The specified machine name was not found in the cache."),
+	(0x90312, "SEC_I_CONTINUE_NEEDED", "The function completed successfully, but must be called again to complete the context."),
+	(0x90313, "SEC_I_COMPLETE_NEEDED", "The function completed successfully, but CompleteToken must be called."),
+	(0x90314, "SEC_I_COMPLETE_AND_CONTINUE", "The function completed successfully, but both CompleteToken and this function must be called to complete the context."),
+	(0x90315, "SEC_I_LOCAL_LOGON", "The logon was completed, but no network authority was available. The logon was made using locally known information."),
+	(0x90316, "SEC_I_GENERIC_EXTENSION_RECEIVED", "Schannel has received a TLS extension the SSPI caller subscribed to."),
+	(0x90317, "SEC_I_CONTEXT_EXPIRED", "The context has expired and can no longer be used."),
+	(0x90320, "SEC_I_INCOMPLETE_CREDENTIALS", "The credentials supplied were not complete, and could not be verified. Additional information can be returned from the context."),
+	(0x90321, "SEC_I_RENEGOTIATE", "The context data must be renegotiated with the peer."),
+	(0x90323, "SEC_I_NO_LSA_CONTEXT", "There is no LSA mode context associated with this context."),
+	(0x9035c, "SEC_I_SIGNATURE_NEEDED", "A signature operation must be performed before the user can authenticate."),
+	(0x90360, "SEC_I_NO_RENEGOTIATION", "The recipient rejected the renegotiation request."),
+	(0x90364, "SEC_I_MESSAGE_FRAGMENT", "The returned buffer is only a fragment of the message. More fragments need to be returned."),
+	(0x90366, "SEC_I_CONTINUE_NEEDED_MESSAGE_OK", "The function completed successfully, but must be called again to complete the context. Early start can be used."),
+	(0x90368, "SEC_I_ASYNC_CALL_PENDING", "An asynchronous SSPI routine has been called and the work is pending completion."),
+	(0x91012, "CRYPT_I_NEW_PROTECTION_REQUIRED", "Protected data needs to be re-protected."),
+	(0x1b0000, "WER_S_REPORT_DEBUG", "Debugger was attached."),
+	(0x1b0001, "WER_S_REPORT_UPLOADED", "Report was uploaded."),
+	(0x1b0002, "WER_S_REPORT_QUEUED", "Report was queued."),
+	(0x1b0003, "WER_S_DISABLED", "Reporting was disabled."),
+	(0x1b0004, "WER_S_SUSPENDED_UPLOAD", "Reporting was temporarily suspended."),
+	(0x1b0005, "WER_S_DISABLED_QUEUE", "Report was not queued to queuing being disabled."),
+	(0x1b0006, "WER_S_DISABLED_ARCHIVE", "Report was uploaded, but not archived due to archiving being disabled."),
+	(0x1b0007, "WER_S_REPORT_ASYNC", "Reporting was successfully spun off as an asynchronous operation."),
+	(0x1b0008, "WER_S_IGNORE_ASSERT_INSTANCE", "The assertion was handled."),
+	(0x1b0009, "WER_S_IGNORE_ALL_ASSERTS", "The assertion was handled and added to a permanent ignore list."),
+	(0x1b000a, "WER_S_ASSERT_CONTINUE", "The assertion was resumed as unhandled."),
+	(0x1b000b, "WER_S_THROTTLED", "Report was throttled."),
+	(0x1b000c, "WER_S_REPORT_UPLOADED_CAB", "Report was uploaded with cab."),
+	(0x1f0001, "ERROR_FLT_IO_COMPLETE", "The IO was completed by a filter."),
+	(0x261001, "ERROR_MONITOR_NO_DESCRIPTOR", "Monitor descriptor could not be obtained."),
+	(0x261002, "ERROR_MONITOR_UNKNOWN_DESCRIPTOR_FORMAT", "Format of the obtained monitor descriptor is not supported by this release."),
+	(0x262307, "ERROR_GRAPHICS_MODE_NOT_PINNED", "No mode is pinned on the specified VidPN source/target."),
+	(0x26231e, "ERROR_GRAPHICS_NO_PREFERRED_MODE", "Specified mode set does not specify preference for one of its modes."),
+	(0x26234b, "ERROR_GRAPHICS_DATASET_IS_EMPTY", "Specified data set (e.g. mode set, frequency range set, descriptor set, topology, etc.) is empty."),
+	(0x26234c, "ERROR_GRAPHICS_NO_MORE_ELEMENTS_IN_DATASET", "Specified data set (e.g. mode set, frequency range set, descriptor set, topology, etc.) does not contain any more elements."),
+	(0x262351, "ERROR_GRAPHICS_PATH_CONTENT_GEOMETRY_TRANSFORMATION_NOT_PINNED", "Specified content transformation is not pinned on the specified VidPN present path."),
+	(0x263005, "DWM_S_GDI_REDIRECTION_SURFACE", "The window is redirected using a legacy GDI redirection surface."),
+	(0x263008, "DWM_S_GDI_REDIRECTION_SURFACE_BLT_VIA_GDI", "Success. The window is redirected using a legacy GDI redirection surface and bitblt via GDI."),
+	(0x27000d, "NAP_S_CERT_ALREADY_PRESENT", "The health certificate is already present."),
+	(0x270258, "S_STORE_LAUNCHED_FOR_REMEDIATION", "The Store was launched instead of the specified app because the app's package was in an invalid state."),
+	(0x270259, "S_APPLICATION_ACTIVATION_ERROR_HANDLED_BY_DIALOG", "This app failed to launch, but the error was handled with a dialog."),
+	(0x300100, "PLA_S_PROPERTY_IGNORED", "Property value will be ignored."),
+	(0x3d0000, "WS_S_ASYNC", "The function call is completing asynchronously."),
+	(0x3d0001, "WS_S_END", "There are no more messages available on the channel."),
+	(0x630001, "PEER_S_GRAPH_DATA_CREATED", "The graph data was created."),
+	(0x630002, "PEER_S_NO_EVENT_DATA", "There is not more event data."),
+	(0x630005, "PEER_S_NO_CONNECTIVITY", "The graph is not connected."),
+	(0x630006, "PEER_S_ALREADY_A_MEMBER", "The group is already connected."),
+	(0x632000, "PEER_S_ALREADY_CONNECTED", "The graph is already connected."),
+	(0x636000, "PEER_S_SUBSCRIPTION_EXISTS", "The subscription already exists."),
+	(0x670013, "STATEREPOSITORY_TRANSACTION_CALLER_ID_CHANGED", "The transaction caller ID has changed."),
+	(0x670014, "STATEREPOSITORY_TRANSACTION_IN_PROGRESS", "A transaction is in progress for this connection."),
+	(0xe70001, "ERROR_SPACES_POOL_WAS_DELETED", "The storage pool was deleted by the driver. The object cache should be updated."),
+	(0x4026200a, "ERROR_GRAPHICS_PARTIAL_DATA_POPULATED", "Data was only partially populated."),
+	(0x40262201, "ERROR_GRAPHICS_SKIP_ALLOCATION_PREPARATION", "Skip preparation of allocations referenced by the DMA buffer."),
+	(0x4026242f, "ERROR_GRAPHICS_UNKNOWN_CHILD_STATUS", "Child device presence was not reliably detected."),
+	(0x40262437, "ERROR_GRAPHICS_LEADLINK_START_DEFERRED", "Starting the leadlink adapter has been deferred temporarily."),
+	(0x40262439, "ERROR_GRAPHICS_POLLING_TOO_FREQUENTLY", "The display adapter is being polled for children too frequently at the same polling level."),
+	(0x4026243a, "ERROR_GRAPHICS_START_DEFERRED", "Starting the adapter has been deferred temporarily."),
+	(0x4026243c, "ERROR_GRAPHICS_DEPENDABLE_CHILD_STATUS", "We can depend on the child device presence returned by the driver."),
+	(0x40290423, "TPM_E_PCP_UNSUPPORTED_PSS_SALT", "Validation of the platform claim failed."),
+	(0x8000000a, "E_PENDING", "The data necessary to complete this operation is not yet available."),
+	(0x8000000b, "E_BOUNDS", "The operation attempted to access data outside the valid range"),
+	(0x8000000c, "E_CHANGED_STATE", "A concurrent or interleaved operation changed the state of the object, invalidating this operation."),
+	(0x8000000d, "E_ILLEGAL_STATE_CHANGE", "An illegal state change was requested."),
+	(0x8000000e, "E_ILLEGAL_METHOD_CALL", "A method was called at an unexpected time."),
+	(0x8000000f, "RO_E_METADATA_NAME_NOT_FOUND", "Typename or Namespace was not found in metadata file."),
+	(0x80000010, "RO_E_METADATA_NAME_IS_NAMESPACE", "Name is an existing namespace rather than a typename."),
+	(0x80000011, "RO_E_METADATA_INVALID_TYPE_FORMAT", "Typename has an invalid format."),
+	(0x80000012, "RO_E_INVALID_METADATA_FILE", "Metadata file is invalid or corrupted."),
+	(0x80000013, "RO_E_CLOSED", "The object has been closed."),
+	(0x80000014, "RO_E_EXCLUSIVE_WRITE", "Only one thread may access the object during a write operation."),
+	(0x80000015, "RO_E_CHANGE_NOTIFICATION_IN_PROGRESS", "Operation is prohibited during change notification."),
+	(0x80000016, "RO_E_ERROR_STRING_NOT_FOUND", "The text associated with this error code could not be found."),
+	(0x80000017, "E_STRING_NOT_NULL_TERMINATED", "String not null terminated."),
+	(0x80000018, "E_ILLEGAL_DELEGATE_ASSIGNMENT", "A delegate was assigned when not allowed."),
+	(0x80000019, "E_ASYNC_OPERATION_NOT_STARTED", "An async operation was not properly started."),
+	(0x8000001a, "E_APPLICATION_EXITING", "The application is exiting and cannot service this request."),
+	(0x8000001b, "E_APPLICATION_VIEW_EXITING", "The application view is exiting and cannot service this request."),
+	(0x8000001c, "RO_E_MUST_BE_AGILE", "The object must support the IAgileObject interface."),
+	(0x8000001d, "RO_E_UNSUPPORTED_FROM_MTA", "Activating a single-threaded class from MTA is not supported."),
+	(0x8000001e, "RO_E_COMMITTED", "The object has been committed."),
+	(0x8000001f, "RO_E_BLOCKED_CROSS_ASTA_CALL", "A COM call to an ASTA was blocked because the call chain originated in or passed through another ASTA. This pattern is deadlock-prone and disallowed by apartment call control."),
+	(0x80000020, "RO_E_CANNOT_ACTIVATE_FULL_TRUST_SERVER", "A universal application process cannot activate a packaged WinRT server that is declared to run full trust."),
+	(0x80000021, "RO_E_CANNOT_ACTIVATE_UNIVERSAL_APPLICATION_SERVER", "A full trust packaged application process cannot activate a packaged WinRT server unless it is also declared to run full trust."),
+	(0x80004001, "E_NOTIMPL", "Not implemented"),
+	(0x80004002, "E_NOINTERFACE", "No such interface supported"),
+	(0x80004003, "E_POINTER", "Invalid pointer"),
+	(0x80004004, "E_ABORT", "Operation aborted"),
+	(0x80004005, "E_FAIL", "Unspecified error"),
+	(0x80004006, "CO_E_INIT_TLS", "Thread local storage failure"),
+	(0x80004007, "CO_E_INIT_SHARED_ALLOCATOR", "Get shared memory allocator failure"),
+	(0x80004008, "CO_E_INIT_MEMORY_ALLOCATOR", "Get memory allocator failure"),
+	(0x80004009, "CO_E_INIT_CLASS_CACHE", "Unable to initialize class cache"),
+	(0x8000400a, "CO_E_INIT_RPC_CHANNEL", "Unable to initialize RPC services"),
+	(0x8000400b, "CO_E_INIT_TLS_SET_CHANNEL_CONTROL", "Cannot set thread local storage channel control"),
+	(0x8000400c, "CO_E_INIT_TLS_CHANNEL_CONTROL", "Could not allocate thread local storage channel control"),
+	(0x8000400d, "CO_E_INIT_UNACCEPTED_USER_ALLOCATOR", "The user supplied memory allocator is unacceptable"),
+	(0x8000400e, "CO_E_INIT_SCM_MUTEX_EXISTS", "The OLE service mutex already exists"),
+	(0x8000400f, "CO_E_INIT_SCM_FILE_MAPPING_EXISTS", "The OLE service file mapping already exists"),
+	(0x80004010, "CO_E_INIT_SCM_MAP_VIEW_OF_FILE", "Unable to map view of file for OLE service"),
+	(0x80004011, "CO_E_INIT_SCM_EXEC_FAILURE", "Failure attempting to launch OLE service"),
+	(0x80004012, "CO_E_INIT_ONLY_SINGLE_THREADED", "There was an attempt to call CoInitialize a second time while single threaded"),
+	(0x80004013, "CO_E_CANT_REMOTE", "A Remote activation was necessary but was not allowed"),
+	(0x80004014, "CO_E_BAD_SERVER_NAME", "A Remote activation was necessary but the server name provided was invalid"),
+	(0x80004015, "CO_E_WRONG_SERVER_IDENTITY", "The class is configured to run as a security id different from the caller"),
+	(0x80004016, "CO_E_OLE1DDE_DISABLED", "Use of Ole1 services requiring DDE windows is disabled"),
+	(0x80004017, "CO_E_RUNAS_SYNTAX", "A RunAs specification must be <domain name>\\<user name> or simply <user name>"),
+	(0x80004018, "CO_E_CREATEPROCESS_FAILURE", "The server process could not be started. The pathname may be incorrect."),
+	(0x80004019, "CO_E_RUNAS_CREATEPROCESS_FAILURE", "The server process could not be started as the configured identity. The pathname may be incorrect or unavailable."),
+	(0x8000401a, "CO_E_RUNAS_LOGON_FAILURE", "The server process could not be started because the configured identity is incorrect. Check the user name and password."),
+	(0x8000401b, "CO_E_LAUNCH_PERMSSION_DENIED", "The client is not allowed to launch this server."),
+	(0x8000401c, "CO_E_START_SERVICE_FAILURE", "The service providing this server could not be started."),
+	(0x8000401d, "CO_E_REMOTE_COMMUNICATION_FAILURE", "This computer was unable to communicate with the computer providing the server."),
+	(0x8000401e, "CO_E_SERVER_START_TIMEOUT", "The server did not respond after being launched."),
+	(0x8000401f, "CO_E_CLSREG_INCONSISTENT", "The registration information for this server is inconsistent or incomplete."),
+	(0x80004020, "CO_E_IIDREG_INCONSISTENT", "The registration information for this interface is inconsistent or incomplete."),
+	(0x80004021, "CO_E_NOT_SUPPORTED", "The operation attempted is not supported."),
+	(0x80004022, "CO_E_RELOAD_DLL", "A dll must be loaded."),
+	(0x80004023, "CO_E_MSI_ERROR", "A Microsoft Software Installer error was encountered."),
+	(0x80004024, "CO_E_ATTEMPT_TO_CREATE_OUTSIDE_CLIENT_CONTEXT", "Attempt to create a class object failed."),
+	(0x80004025, "CO_E_SERVER_PAUSED", "Activations on the server are paused."),
+	(0x80004026, "CO_E_SERVER_NOT_PAUSED", "Activations on the server are not paused."),
+	(0x80004027, "CO_E_CLASS_DISABLED", "The component or application containing the component has been disabled."),
+	(0x80004028, "CO_E_CLRNOTAVAILABLE", "The common language runtime is not available."),
+	(0x80004029, "CO_E_ASYNC_WORK_REJECTED", "The thread-pool rejected the submitted asynchronous work."),
+	(0x8000402a, "CO_E_SERVER_INIT_TIMEOUT", "The server started, but did not finish initializing in a timely fashion."),
+	(0x8000402b, "CO_E_NO_SECCTX_IN_ACTIVATE", "Unable to complete the call since there is no COM+ security context inside IObjectControl.Activate."),
+	(0x80004030, "CO_E_TRACKER_CONFIG", "The provided tracker configuration is invalid."),
+	(0x80004031, "CO_E_THREADPOOL_CONFIG", "The provided thread pool configuration is invalid."),
+	(0x80004032, "CO_E_SXS_CONFIG", "The provided side-by-side configuration is invalid."),
+	(0x80004033, "CO_E_MALFORMED_SPN", "The server principal name (SPN) obtained during security negotiation is malformed."),
+	(0x80004034, "CO_E_UNREVOKED_REGISTRATION_ON_APARTMENT_SHUTDOWN", "The activation of a COM server was blocked because the server's class registration was not revoked before the apartment shut down."),
+	(0x80004035, "CO_E_PREMATURE_STUB_RUNDOWN", "The object has been rundown by the stub manager while there are external clients."),
+	(0x8000ffff, "E_UNEXPECTED", "Catastrophic failure"),
+	(0x80010001, "RPC_E_CALL_REJECTED", "Call was rejected by callee."),
+	(0x80010002, "RPC_E_CALL_CANCELED", "Call was canceled by the message filter."),
+	(0x80010003, "RPC_E_CANTPOST_INSENDCALL", "The caller is dispatching an intertask SendMessage call and cannot call out via PostMessage."),
+	(0x80010004, "RPC_E_CANTCALLOUT_INASYNCCALL", "The caller is dispatching an asynchronous call and cannot make an outgoing call on behalf of this call."),
+	(0x80010005, "RPC_E_CANTCALLOUT_INEXTERNALCALL", "It is illegal to call out while inside message filter."),
+	(0x80010006, "RPC_E_CONNECTION_TERMINATED", "The connection terminated or is in a bogus state and cannot be used any more. Other connections are still valid."),
+	(0x80010007, "RPC_E_SERVER_DIED", "The callee (server [not server application]) is not available and disappeared; all connections are invalid. The call may have executed."),
+	(0x80010008, "RPC_E_CLIENT_DIED", "The caller (client) disappeared while the callee (server) was processing a call."),
+	(0x80010009, "RPC_E_INVALID_DATAPACKET", "The data packet with the marshalled parameter data is incorrect."),
+	(0x8001000a, "RPC_E_CANTTRANSMIT_CALL", "The call was not transmitted properly; the message queue was full and was not emptied after yielding."),
+	(0x8001000b, "RPC_E_CLIENT_CANTMARSHAL_DATA", "The caller (client) cannot marshal the parameter data - low memory, etc."),
+	(0x8001000c, "RPC_E_CLIENT_CANTUNMARSHAL_DATA", "The caller (client) cannot unmarshal the return data - low memory, etc."),
+	(0x8001000d, "RPC_E_SERVER_CANTMARSHAL_DATA", "The callee (server [not server application]) cannot marshal the return data - low memory, etc."),
+	(0x8001000e, "RPC_E_SERVER_CANTUNMARSHAL_DATA", "The callee (server [not server application]) cannot unmarshal the parameter data - low memory, etc."),
+	(0x8001000f, "RPC_E_INVALID_DATA", "Received data is invalid; could be server or client data."),
+	(0x80010010, "RPC_E_INVALID_PARAMETER", "A particular parameter is invalid and cannot be (un)marshaled."),
+	(0x80010011, "RPC_E_CANTCALLOUT_AGAIN", "There is no second outgoing call on same channel in DDE conversation."),
+	(0x80010012, "RPC_E_SERVER_DIED_DNE", "The callee (server [not server application]) is not available and disappeared; all connections are invalid. The call did not execute."),
+	(0x80010100, "RPC_E_SYS_CALL_FAILED", "System call failed."),
+	(0x80010101, "RPC_E_OUT_OF_RESOURCES", "Could not allocate some required resource (memory, events, ...)"),
+	(0x80010102, "RPC_E_ATTEMPTED_MULTITHREAD", "Attempted to make calls on more than one thread in single threaded mode."),
+	(0x80010103, "RPC_E_NOT_REGISTERED", "The requested interface is not registered on the server object."),
+	(0x80010104, "RPC_E_FAULT", "RPC could not call the server or could not return the results of calling the server."),
+	(0x80010105, "RPC_E_SERVERFAULT", "The server threw an exception."),
+	(0x80010106, "RPC_E_CHANGED_MODE", "Cannot change thread mode after it is set."),
+	(0x80010107, "RPC_E_INVALIDMETHOD", "The requested method is not valid on this object."),
+	(0x80010108, "RPC_E_DISCONNECTED", "The object invoked has disconnected from its clients."),
+	(0x80010109, "RPC_E_RETRY", "The object invoked chose not to process the call now. Try again later."),
+	(0x8001010a, "RPC_E_SERVERCALL_RETRYLATER", "The message filter indicated that the application is busy."),
+	(0x8001010b, "RPC_E_SERVERCALL_REJECTED", "The message filter rejected the call."),
+	(0x8001010c, "RPC_E_INVALID_CALLDATA", "A call control interfaces was called with invalid data."),
+	(0x8001010d, "RPC_E_CANTCALLOUT_ININPUTSYNCCALL", "An outgoing call cannot be made since the application is dispatching an input-synchronous call."),
+	(0x8001010e, "RPC_E_WRONG_THREAD", "The application called an interface that was marshalled for a different thread."),
+	(0x8001010f, "RPC_E_THREAD_NOT_INIT", "CoInitialize has not been called on the current thread."),
+	(0x80010110, "RPC_E_VERSION_MISMATCH", "The version of OLE on the client and server machines does not match."),
+	(0x80010111, "RPC_E_INVALID_HEADER", "OLE received a packet with an invalid header."),
+	(0x80010112, "RPC_E_INVALID_EXTENSION", "OLE received a packet with an invalid extension."),
+	(0x80010113, "RPC_E_INVALID_IPID", "The requested object or interface does not exist."),
+	(0x80010114, "RPC_E_INVALID_OBJECT", "The requested object does not exist."),
+	(0x80010115, "RPC_S_CALLPENDING", "OLE has sent a request and is waiting for a reply."),
+	(0x80010116, "RPC_S_WAITONTIMER", "OLE is waiting before retrying a request."),
+	(0x80010117, "RPC_E_CALL_COMPLETE", "Call context cannot be accessed after call completed."),
+	(0x80010118, "RPC_E_UNSECURE_CALL", "Impersonate on unsecure calls is not supported."),
+	(0x80010119, "RPC_E_TOO_LATE", "Security must be initialized before any interfaces are marshalled or unmarshalled. It cannot be changed once initialized."),
+	(0x8001011a, "RPC_E_NO_GOOD_SECURITY_PACKAGES", "No security packages are installed on this machine or the user is not logged on or there are no compatible security packages between the client and server."),
+	(0x8001011b, "RPC_E_ACCESS_DENIED", "Access is denied."),
+	(0x8001011c, "RPC_E_REMOTE_DISABLED", "Remote calls are not allowed for this process."),
+	(0x8001011d, "RPC_E_INVALID_OBJREF", "The marshaled interface data packet (OBJREF) has an invalid or unknown format."),
+	(0x8001011e, "RPC_E_NO_CONTEXT", "No context is associated with this call. This happens for some custom marshaled calls and on the client side of the call."),
+	(0x8001011f, "RPC_E_TIMEOUT", "This operation returned because the timeout period expired."),
+	(0x80010120, "RPC_E_NO_SYNC", "There are no synchronize objects to wait on."),
+	(0x80010121, "RPC_E_FULLSIC_REQUIRED", "Full subject issuer chain SSL principal name expected from the server."),
+	(0x80010122, "RPC_E_INVALID_STD_NAME", "Principal name is not a valid MSSTD name."),
+	(0x80010123, "CO_E_FAILEDTOIMPERSONATE", "Unable to impersonate DCOM client."),
+	(0x80010124, "CO_E_FAILEDTOGETSECCTX", "Unable to obtain server's security context."),
+	(0x80010125, "CO_E_FAILEDTOOPENTHREADTOKEN", "Unable to open the access token of the current thread."),
+	(0x80010126, "CO_E_FAILEDTOGETTOKENINFO", "Unable to obtain user information from an access token."),
+	(0x80010127, "CO_E_TRUSTEEDOESNTMATCHCLIENT", "The client who called IAccessControl::IsAccessPermitted was not the trustee provided to the method."),
+	(0x80010128, "CO_E_FAILEDTOQUERYCLIENTBLANKET", "Unable to obtain the client's security blanket."),
+	(0x80010129, "CO_E_FAILEDTOSETDACL", "Unable to set a discretionary ACL into a security descriptor."),
+	(0x8001012a, "CO_E_ACCESSCHECKFAILED", "The system function, AccessCheck, returned false."),
+	(0x8001012b, "CO_E_NETACCESSAPIFAILED", "Either NetAccessDel or NetAccessAdd returned an error code."),
+	(0x8001012c, "CO_E_WRONGTRUSTEENAMESYNTAX", "One of the trustee strings provided by the user did not conform to the <Domain>\\<Name> syntax and it was not the \"*\" string."),
+	(0x8001012d, "CO_E_INVALIDSID", "One of the security identifiers provided by the user was invalid."),
+	(0x8001012e, "CO_E_CONVERSIONFAILED", "Unable to convert a wide character trustee string to a multibyte trustee string."),
+	(0x8001012f, "CO_E_NOMATCHINGSIDFOUND", "Unable to find a security identifier that corresponds to a trustee string provided by the user."),
+	(0x80010130, "CO_E_LOOKUPACCSIDFAILED", "The system function, LookupAccountSID, failed."),
+	(0x80010131, "CO_E_NOMATCHINGNAMEFOUND", "Unable to find a trustee name that corresponds to a security identifier provided by the user."),
+	(0x80010132, "CO_E_LOOKUPACCNAMEFAILED", "The system function, LookupAccountName, failed."),
+	(0x80010133, "CO_E_SETSERLHNDLFAILED", "Unable to set or reset a serialization handle."),
+	(0x80010134, "CO_E_FAILEDTOGETWINDIR", "Unable to obtain the Windows directory."),
+	(0x80010135, "CO_E_PATHTOOLONG", "Path too long."),
+	(0x80010136, "CO_E_FAILEDTOGENUUID", "Unable to generate a uuid."),
+	(0x80010137, "CO_E_FAILEDTOCREATEFILE", "Unable to create file."),
+	(0x80010138, "CO_E_FAILEDTOCLOSEHANDLE", "Unable to close a serialization handle or a file handle."),
+	(0x80010139, "CO_E_EXCEEDSYSACLLIMIT", "The number of ACEs in an ACL exceeds the system limit."),
+	(0x8001013a, "CO_E_ACESINWRONGORDER", "Not all the DENY_ACCESS ACEs are arranged in front of the GRANT_ACCESS ACEs in the stream."),
+	(0x8001013b, "CO_E_INCOMPATIBLESTREAMVERSION", "The version of ACL format in the stream is not supported by this implementation of IAccessControl."),
+	(0x8001013c, "CO_E_FAILEDTOOPENPROCESSTOKEN", "Unable to open the access token of the server process."),
+	(0x8001013d, "CO_E_DECODEFAILED", "Unable to decode the ACL in the stream provided by the user."),
+	(0x8001013f, "CO_E_ACNOTINITIALIZED", "The COM IAccessControl object is not initialized."),
+	(0x80010140, "CO_E_CANCEL_DISABLED", "Call Cancellation is disabled."),
+	(0x8001ffff, "RPC_E_UNEXPECTED", "An internal error occurred."),
+	(0x80020001, "DISP_E_UNKNOWNINTERFACE", "Unknown interface."),
+	(0x80020003, "DISP_E_MEMBERNOTFOUND", "Member not found."),
+	(0x80020004, "DISP_E_PARAMNOTFOUND", "Parameter not found."),
+	(0x80020005, "DISP_E_TYPEMISMATCH", "Type mismatch."),
+	(0x80020006, "DISP_E_UNKNOWNNAME", "Unknown name."),
+	(0x80020007, "DISP_E_NONAMEDARGS", "No named arguments."),
+	(0x80020008, "DISP_E_BADVARTYPE", "Bad variable type."),
+	(0x80020009, "DISP_E_EXCEPTION", "Exception occurred."),
+	(0x8002000a, "DISP_E_OVERFLOW", "Out of present range."),
+	(0x8002000b, "DISP_E_BADINDEX", "Invalid index."),
+	(0x8002000c, "DISP_E_UNKNOWNLCID", "Unknown language."),
+	(0x8002000d, "DISP_E_ARRAYISLOCKED", "Memory is locked."),
+	(0x8002000e, "DISP_E_BADPARAMCOUNT", "Invalid number of parameters."),
+	(0x8002000f, "DISP_E_PARAMNOTOPTIONAL", "Parameter not optional."),
+	(0x80020010, "DISP_E_BADCALLEE", "Invalid callee."),
+	(0x80020011, "DISP_E_NOTACOLLECTION", "Does not support a collection."),
+	(0x80020012, "DISP_E_DIVBYZERO", "Division by zero."),
+	(0x80020013, "DISP_E_BUFFERTOOSMALL", "Buffer too small"),
+	(0x80028016, "TYPE_E_BUFFERTOOSMALL", "Buffer too small."),
+	(0x80028017, "TYPE_E_FIELDNOTFOUND", "Field name not defined in the record."),
+	(0x80028018, "TYPE_E_INVDATAREAD", "Old format or invalid type library."),
+	(0x80028019, "TYPE_E_UNSUPFORMAT", "Old format or invalid type library."),
+	(0x8002801c, "TYPE_E_REGISTRYACCESS", "Error accessing the OLE registry."),
+	(0x8002801d, "TYPE_E_LIBNOTREGISTERED", "Library not registered."),
+	(0x80028027, "TYPE_E_UNDEFINEDTYPE", "Bound to unknown type."),
+	(0x80028028, "TYPE_E_QUALIFIEDNAMEDISALLOWED", "Qualified name disallowed."),
+	(0x80028029, "TYPE_E_INVALIDSTATE", "Invalid forward reference, or reference to uncompiled type."),
+	(0x8002802a, "TYPE_E_WRONGTYPEKIND", "Type mismatch."),
+	(0x8002802b, "TYPE_E_ELEMENTNOTFOUND", "Element not found."),
+	(0x8002802c, "TYPE_E_AMBIGUOUSNAME", "Ambiguous name."),
+	(0x8002802d, "TYPE_E_NAMECONFLICT", "Name already exists in the library."),
+	(0x8002802e, "TYPE_E_UNKNOWNLCID", "Unknown LCID."),
+	(0x8002802f, "TYPE_E_DLLFUNCTIONNOTFOUND", "Function not defined in specified DLL."),
+	(0x800288bd, "TYPE_E_BADMODULEKIND", "Wrong module kind for the operation."),
+	(0x800288c5, "TYPE_E_SIZETOOBIG", "Size may not exceed 64K."),
+	(0x800288c6, "TYPE_E_DUPLICATEID", "Duplicate ID in inheritance hierarchy."),
+	(0x800288cf, "TYPE_E_INVALIDID", "Incorrect inheritance depth in standard OLE hmember."),
+	(0x80028ca0, "TYPE_E_TYPEMISMATCH", "Type mismatch."),
+	(0x80028ca1, "TYPE_E_OUTOFBOUNDS", "Invalid number of arguments."),
+	(0x80028ca2, "TYPE_E_IOERROR", "I/O Error."),
+	(0x80028ca3, "TYPE_E_CANTCREATETMPFILE", "Error creating unique tmp file."),
+	(0x80029c4a, "TYPE_E_CANTLOADLIBRARY", "Error loading type library/DLL."),
+	(0x80029c83, "TYPE_E_INCONSISTENTPROPFUNCS", "Inconsistent property functions."),
+	(0x80029c84, "TYPE_E_CIRCULARTYPE", "Circular dependency between types/modules."),
+	(0x80030001, "STG_E_INVALIDFUNCTION", "Unable to perform requested operation."),
+	(0x80030002, "STG_E_FILENOTFOUND", "%1 could not be found."),
+	(0x80030003, "STG_E_PATHNOTFOUND", "The path %1 could not be found."),
+	(0x80030004, "STG_E_TOOMANYOPENFILES", "There are insufficient resources to open another file."),
+	(0x80030005, "STG_E_ACCESSDENIED", "Access Denied."),
+	(0x80030006, "STG_E_INVALIDHANDLE", "Attempted an operation on an invalid object."),
+	(0x80030008, "STG_E_INSUFFICIENTMEMORY", "There is insufficient memory available to complete operation."),
+	(0x80030009, "STG_E_INVALIDPOINTER", "Invalid pointer error."),
+	(0x80030012, "STG_E_NOMOREFILES", "There are no more entries to return."),
+	(0x80030013, "STG_E_DISKISWRITEPROTECTED", "Disk is write-protected."),
+	(0x80030019, "STG_E_SEEKERROR", "An error occurred during a seek operation."),
+	(0x8003001d, "STG_E_WRITEFAULT", "A disk error occurred during a write operation."),
+	(0x8003001e, "STG_E_READFAULT", "A disk error occurred during a read operation."),
+	(0x80030020, "STG_E_SHAREVIOLATION", "A share violation has occurred."),
+	(0x80030021, "STG_E_LOCKVIOLATION", "A lock violation has occurred."),
+	(0x80030050, "STG_E_FILEALREADYEXISTS", "%1 already exists."),
+	(0x80030057, "STG_E_INVALIDPARAMETER", "Invalid parameter error."),
+	(0x80030070, "STG_E_MEDIUMFULL", "There is insufficient disk space to complete operation."),
+	(0x800300f0, "STG_E_PROPSETMISMATCHED", "Illegal write of non-simple property to simple property set."),
+	(0x800300fa, "STG_E_ABNORMALAPIEXIT", "An API call exited abnormally."),
+	(0x800300fb, "STG_E_INVALIDHEADER", "The file %1 is not a valid compound file."),
+	(0x800300fc, "STG_E_INVALIDNAME", "The name %1 is not valid."),
+	(0x800300fd, "STG_E_UNKNOWN", "An unexpected error occurred."),
+	(0x800300fe, "STG_E_UNIMPLEMENTEDFUNCTION", "Unimplemented function."),
+	(0x800300ff, "STG_E_INVALIDFLAG", "Invalid flag error."),
+	(0x80030100, "STG_E_INUSE", "The storage has been changed since the last commit."),
+	(0x80030101, "STG_E_NOTCURRENT", "The storage has been changed since the last commit."),
+	(0x80030102, "STG_E_REVERTED", "Attempted to use an object that has ceased to exist."),
+	(0x80030103, "STG_E_CANTSAVE", "Can't save."),
+	(0x80030104, "STG_E_OLDFORMAT", "The compound file %1 was produced with an incompatible version of storage."),
+	(0x80030105, "STG_E_OLDDLL", "The compound file %1 was produced with a newer version of storage."),
+	(0x80030106, "STG_E_SHAREREQUIRED", "Share.exe or equivalent is required for operation."),
+	(0x80030107, "STG_E_NOTFILEBASEDSTORAGE", "Illegal operation called on non-file based storage."),
+	(0x80030108, "STG_E_EXTANTMARSHALLINGS", "Illegal operation called on object with extant marshallings."),
+	(0x80030109, "STG_E_DOCFILECORRUPT", "The docfile has been corrupted."),
+	(0x80030110, "STG_E_BADBASEADDRESS", "OLE32.DLL has been loaded at the wrong address."),
+	(0x80030111, "STG_E_DOCFILETOOLARGE", "The compound file is too large for the current implementation."),
+	(0x80030112, "STG_E_NOTSIMPLEFORMAT", "The compound file was not created with the STGM_SIMPLE flag."),
+	(0x80030201, "STG_E_INCOMPLETE", "The file download was aborted abnormally. The file is incomplete."),
+	(0x80030202, "STG_E_TERMINATED", "The file download has been terminated."),
+	(0x80030208, "STG_E_FIRMWARE_SLOT_INVALID", "The specified firmware slot is invalid."),
+	(0x80030209, "STG_E_FIRMWARE_IMAGE_INVALID", "The specified firmware image is invalid."),
+	(0x8003020a, "STG_E_DEVICE_UNRESPONSIVE", "The storage device is unresponsive."),
+	(0x80030305, "STG_E_STATUS_COPY_PROTECTION_FAILURE", "Generic Copy Protection Error."),
+	(0x80030306, "STG_E_CSS_AUTHENTICATION_FAILURE", "Copy Protection Error - DVD CSS Authentication failed."),
+	(0x80030307, "STG_E_CSS_KEY_NOT_PRESENT", "Copy Protection Error - The given sector does not have a valid CSS key."),
+	(0x80030308, "STG_E_CSS_KEY_NOT_ESTABLISHED", "Copy Protection Error - DVD session key not established."),
+	(0x80030309, "STG_E_CSS_SCRAMBLED_SECTOR", "Copy Protection Error - The read failed because the sector is encrypted."),
+	(0x8003030a, "STG_E_CSS_REGION_MISMATCH", "Copy Protection Error - The current DVD's region does not correspond to the region setting of the drive."),
+	(0x8003030b, "STG_E_RESETS_EXHAUSTED", "Copy Protection Error - The drive's region setting may be permanent or the number of user resets has been exhausted."),
+	(0x80040000, "OLE_E_OLEVERB", "Invalid OLEVERB structure."),
+	(0x80040001, "OLE_E_ADVF", "Invalid advise flags."),
+	(0x80040002, "OLE_E_ENUM_NOMORE", "Can't enumerate any more, because the associated data is missing."),
+	(0x80040003, "OLE_E_ADVISENOTSUPPORTED", "This implementation doesn't take advises."),
+	(0x80040004, "OLE_E_NOCONNECTION", "There is no connection for this connection ID."),
+	(0x80040005, "OLE_E_NOTRUNNING", "Object is not running"),
+	(0x80040006, "OLE_E_NOCACHE", "There is no cache to operate on."),
+	(0x80040007, "OLE_E_BLANK", "Uninitialized object"),
+	(0x80040008, "OLE_E_CLASSDIFF", "Linked object's source class has changed."),
+	(0x80040009, "OLE_E_CANT_GETMONIKER", "Not able to get the moniker of the object."),
+	(0x8004000a, "OLE_E_CANT_BINDTOSOURCE", "Not able to bind to the source."),
+	(0x8004000b, "OLE_E_STATIC", "Object is static; operation not allowed."),
+	(0x8004000c, "OLE_E_PROMPTSAVECANCELLED", "User canceled out of the Save dialog box."),
+	(0x8004000d, "OLE_E_INVALIDRECT", "Invalid rectangle."),
+	(0x8004000e, "OLE_E_WRONGCOMPOBJ", "compobj.dll is too old for the ole2.dll initialized."),
+	(0x8004000f, "OLE_E_INVALIDHWND", "Invalid window handle."),
+	(0x80040010, "OLE_E_NOT_INPLACEACTIVE", "Object is not in any of the inplace active states."),
+	(0x80040011, "OLE_E_CANTCONVERT", "Not able to convert object."),
+	(0x80040012, "OLE_E_NOSTORAGE", "Not able to perform the operation because object is not given storage yet."),
+	(0x80040064, "DV_E_FORMATETC", "Invalid FORMATETC structure"),
+	(0x80040065, "DV_E_DVTARGETDEVICE", "Invalid DVTARGETDEVICE structure."),
+	(0x80040066, "DV_E_STGMEDIUM", "Invalid STDGMEDIUM structure."),
+	(0x80040067, "DV_E_STATDATA", "Invalid STATDATA structure."),
+	(0x80040068, "DV_E_LINDEX", "Invalid lindex."),
+	(0x80040069, "DV_E_TYMED", "Invalid tymed"),
+	(0x8004006a, "DV_E_CLIPFORMAT", "Invalid clipboard format"),
+	(0x8004006b, "DV_E_DVASPECT", "Invalid aspect(s)"),
+	(0x8004006c, "DV_E_DVTARGETDEVICE_SIZE", "Invalid size for DVTARGETDEVICE."),
+	(0x8004006d, "DV_E_NOIVIEWOBJECT", "Object doesn't support IViewObject interface."),
+	(0x80040100, "DRAGDROP_E_NOTREGISTERED", "Trying to revoke a drop target that has not been registered"),
+	(0x80040101, "DRAGDROP_E_ALREADYREGISTERED", "This window has already been registered as a drop target"),
+	(0x80040102, "DRAGDROP_E_INVALIDHWND", "Invalid window handle"),
+	(0x80040103, "DRAGDROP_E_CONCURRENT_DRAG_ATTEMPTED", "Another drag-drop operation is already in progress."),
+	(0x80040110, "CLASS_E_NOAGGREGATION", "Class does not support aggregation (or class object is remote)"),
+	(0x80040111, "CLASS_E_CLASSNOTAVAILABLE", "ClassFactory cannot supply requested class"),
+	(0x80040112, "CLASS_E_NOTLICENSED", "Class is not licensed for use"),
+	(0x80040140, "VIEW_E_DRAW", "Error drawing view."),
+	(0x80040150, "REGDB_E_READREGDB", "Could not read key from registry"),
+	(0x80040151, "REGDB_E_WRITEREGDB", "Could not write key to registry"),
+	(0x80040152, "REGDB_E_KEYMISSING", "Could not find the key in the registry"),
+	(0x80040153, "REGDB_E_INVALIDVALUE", "Invalid value for registry"),
+	(0x80040154, "REGDB_E_CLASSNOTREG", "Class not registered"),
+	(0x80040155, "REGDB_E_IIDNOTREG", "Interface not registered"),
+	(0x80040156, "REGDB_E_BADTHREADINGMODEL", "Threading model entry is not valid"),
+	(0x80040157, "REGDB_E_PACKAGEPOLICYVIOLATION", "A registration in a package violates package-specific policies."),
+	(0x80040160, "CAT_E_CATIDNOEXIST", "CATID does not exist."),
+	(0x80040161, "CAT_E_NODESCRIPTION", "Description not found."),
+	(0x80040164, "CS_E_PACKAGE_NOTFOUND", "No package in the software installation data in the Active Directory meets this criteria."),
+	(0x80040165, "CS_E_NOT_DELETABLE", "Deleting this will break the referential integrity of the software installation data in the Active Directory."),
+	(0x80040166, "CS_E_CLASS_NOTFOUND", "The CLSID was not found in the software installation data in the Active Directory."),
+	(0x80040167, "CS_E_INVALID_VERSION", "The software installation data in the Active Directory is corrupt."),
+	(0x80040168, "CS_E_NO_CLASSSTORE", "There is no software installation data in the Active Directory."),
+	(0x80040169, "CS_E_OBJECT_NOTFOUND", "There is no software installation data object in the Active Directory."),
+	(0x8004016a, "CS_E_OBJECT_ALREADY_EXISTS", "The software installation data object in the Active Directory already exists."),
+	(0x8004016b, "CS_E_INVALID_PATH", "The path to the software installation data in the Active Directory is not correct."),
+	(0x8004016c, "CS_E_NETWORK_ERROR", "A network error interrupted the operation."),
+	(0x8004016d, "CS_E_ADMIN_LIMIT_EXCEEDED", "The size of this object exceeds the maximum size set by the Administrator."),
+	(0x8004016e, "CS_E_SCHEMA_MISMATCH", "The schema for the software installation data in the Active Directory does not match the required schema."),
+	(0x8004016f, "CS_E_INTERNAL_ERROR", "An error occurred in the software installation data in the Active Directory."),
+	(0x80040170, "CACHE_E_NOCACHE_UPDATED", "Cache not updated."),
+	(0x80040180, "OLEOBJ_E_NOVERBS", "No verbs for OLE object."),
+	(0x80040181, "OLEOBJ_E_INVALIDVERB", "Invalid verb for OLE object."),
+	(0x800401a0, "INPLACE_E_NOTUNDOABLE", "Undo is not available."),
+	(0x800401a1, "INPLACE_E_NOTOOLSPACE", "Space for tools is not available."),
+	(0x800401c0, "CONVERT10_E_OLESTREAM_GET", "OLESTREAM Get method failed."),
+	(0x800401c1, "CONVERT10_E_OLESTREAM_PUT", "OLESTREAM Put method failed."),
+	(0x800401c2, "CONVERT10_E_OLESTREAM_FMT", "Contents of the OLESTREAM not in correct format."),
+	(0x800401c3, "CONVERT10_E_OLESTREAM_BITMAP_TO_DIB", "There was an error in a Windows GDI call while converting the bitmap to a DIB."),
+	(0x800401c4, "CONVERT10_E_STG_FMT", "Contents of the IStorage not in correct format."),
+	(0x800401c5, "CONVERT10_E_STG_NO_STD_STREAM", "Contents of IStorage is missing one of the standard streams."),
+	(0x800401c6, "CONVERT10_E_STG_DIB_TO_BITMAP", "There was an error in a Windows GDI call while converting the DIB to a bitmap."),
+	(0x800401d0, "CLIPBRD_E_CANT_OPEN", "OpenClipboard Failed"),
+	(0x800401d1, "CLIPBRD_E_CANT_EMPTY", "EmptyClipboard Failed"),
+	(0x800401d2, "CLIPBRD_E_CANT_SET", "SetClipboard Failed"),
+	(0x800401d3, "CLIPBRD_E_BAD_DATA", "Data on clipboard is invalid"),
+	(0x800401d4, "CLIPBRD_E_CANT_CLOSE", "CloseClipboard Failed"),
+	(0x800401e0, "MK_E_CONNECTMANUALLY", "Moniker needs to be connected manually."),
+	(0x800401e1, "MK_E_EXCEEDEDDEADLINE", "Operation exceeded deadline."),
+	(0x800401e2, "MK_E_NEEDGENERIC", "Moniker needs to be generic."),
+	(0x800401e3, "MK_E_UNAVAILABLE", "Operation unavailable."),
+	(0x800401e4, "MK_E_SYNTAX", "Invalid syntax"),
+	(0x800401e5, "MK_E_NOOBJECT", "Object is not registered"),
+	(0x800401e6, "MK_E_INVALIDEXTENSION", "Invalid extension."),
+	(0x800401e7, "MK_E_INTERMEDIATEINTERFACENOTSUPPORTED", "Intermediate operation failed."),
+	(0x800401e8, "MK_E_NOTBINDABLE", "Moniker is not bindable."),
+	(0x800401e9, "MK_E_NOTBOUND", "Moniker is not bound."),
+	(0x800401ea, "MK_E_CANTOPENFILE", "Moniker cannot open file."),
+	(0x800401eb, "MK_E_MUSTBOTHERUSER", "User input required for operation to succeed."),
+	(0x800401ec, "MK_E_NOINVERSE", "Moniker class has no inverse."),
+	(0x800401ed, "MK_E_NOSTORAGE", "Moniker does not refer to storage."),
+	(0x800401ee, "MK_E_NOPREFIX", "No common prefix."),
+	(0x800401ef, "MK_E_ENUMERATION_FAILED", "Moniker could not be enumerated."),
+	(0x800401f0, "CO_E_NOTINITIALIZED", "CoInitialize has not been called."),
+	(0x800401f1, "CO_E_ALREADYINITIALIZED", "CoInitialize has already been called."),
+	(0x800401f2, "CO_E_CANTDETERMINECLASS", "Class of object cannot be determined"),
+	(0x800401f3, "CO_E_CLASSSTRING", "Invalid class string"),
+	(0x800401f4, "CO_E_IIDSTRING", "Invalid interface string"),
+	(0x800401f5, "CO_E_APPNOTFOUND", "Application not found"),
+	(0x800401f6, "CO_E_APPSINGLEUSE", "Application cannot be run more than once"),
+	(0x800401f7, "CO_E_ERRORINAPP", "Some error in application program"),
+	(0x800401f8, "CO_E_DLLNOTFOUND", "DLL for class not found"),
+	(0x800401f9, "CO_E_ERRORINDLL", "Error in the DLL"),
+	(0x800401fa, "CO_E_WRONGOSFORAPP", "Wrong operating system or operating system version for application"),
+	(0x800401fb, "CO_E_OBJNOTREG", "Object is not registered"),
+	(0x800401fc, "CO_E_OBJISREG", "Object is already registered"),
+	(0x800401fd, "CO_E_OBJNOTCONNECTED", "Object is not connected to server"),
+	(0x800401fe, "CO_E_APPDIDNTREG", "Application was launched but it didn't register a class factory"),
+	(0x800401ff, "CO_E_RELEASED", "Object has been released"),
+	(0x80040201, "EVENT_E_ALL_SUBSCRIBERS_FAILED", "An event was unable to invoke any of the subscribers."),
+	(0x80040203, "EVENT_E_QUERYSYNTAX", "A syntax error occurred trying to evaluate a query string."),
+	(0x80040204, "EVENT_E_QUERYFIELD", "An invalid field name was used in a query string."),
+	(0x80040205, "EVENT_E_INTERNALEXCEPTION", "An unexpected exception was raised."),
+	(0x80040206, "EVENT_E_INTERNALERROR", "An unexpected internal error was detected."),
+	(0x80040207, "EVENT_E_INVALID_PER_USER_SID", "The owner SID on a per-user subscription doesn't exist."),
+	(0x80040208, "EVENT_E_USER_EXCEPTION", "A user-supplied component or subscriber raised an exception."),
+	(0x80040209, "EVENT_E_TOO_MANY_METHODS", "An interface has too many methods to fire events from."),
+	(0x8004020a, "EVENT_E_MISSING_EVENTCLASS", "A subscription cannot be stored unless its event class already exists."),
+	(0x8004020b, "EVENT_E_NOT_ALL_REMOVED", "Not all the objects requested could be removed."),
+	(0x8004020c, "EVENT_E_COMPLUS_NOT_INSTALLED", "COM+ is required for this operation, but is not installed."),
+	(0x8004020d, "EVENT_E_CANT_MODIFY_OR_DELETE_UNCONFIGURED_OBJECT", "Cannot modify or delete an object that was not added using the COM+ Admin SDK."),
+	(0x8004020e, "EVENT_E_CANT_MODIFY_OR_DELETE_CONFIGURED_OBJECT", "Cannot modify or delete an object that was added using the COM+ Admin SDK."),
+	(0x8004020f, "EVENT_E_INVALID_EVENT_CLASS_PARTITION", "The event class for this subscription is in an invalid partition."),
+	(0x80040210, "EVENT_E_PER_USER_SID_NOT_LOGGED_ON", "The owner of the PerUser subscription is not logged on to the system specified."),
+	(0x80040212, "TPC_E_NO_DEFAULT_TABLET", "No default tablet."),
+	(0x80040219, "TPC_E_INVALID_INPUT_RECT", "Invalid input rectangle."),
+	(0x8004021b, "TPC_E_UNKNOWN_PROPERTY", "Unknown property specified."),
+	(0x80040222, "TPC_E_INVALID_STROKE", "Invalid stroke object."),
+	(0x80040223, "TPC_E_INITIALIZE_FAIL", "Tablet context did not initialize."),
+	(0x80040232, "TPC_E_NOT_RELEVANT", "The data required for the operation was not supplied."),
+	(0x80040233, "TPC_E_INVALID_PACKET_DESCRIPTION", "Invalid packet description."),
+	(0x80040235, "TPC_E_RECOGNIZER_NOT_REGISTERED", "There are no handwriting recognizers registered."),
+	(0x80040236, "TPC_E_INVALID_RIGHTS", "User does not have the necessary rights to read recognizer information."),
+	(0x80040237, "TPC_E_OUT_OF_ORDER_CALL", "API calls were made in an incorrect order."),
+	(0x80040238, "TPC_E_QUEUE_FULL", "Queue is full."),
+	(0x80040239, "TPC_E_INVALID_CONFIGURATION", "RtpEnabled called multiple times."),
+	(0x8004023a, "TPC_E_INVALID_DATA_FROM_RECOGNIZER", "A recognizer returned invalid data."),
+	(0x80040241, "TPC_E_INVALID_PROPERTY", "Property not found, or property not supported by recognizer."),
+	(0x80041309, "SCHED_E_TRIGGER_NOT_FOUND", "Trigger not found."),
+	(0x8004130a, "SCHED_E_TASK_NOT_READY", "One or more of the properties that are needed to run this task have not been set."),
+	(0x8004130b, "SCHED_E_TASK_NOT_RUNNING", "There is no running instance of the task."),
+	(0x8004130c, "SCHED_E_SERVICE_NOT_INSTALLED", "The Task Scheduler Service is not installed on this computer."),
+	(0x8004130d, "SCHED_E_CANNOT_OPEN_TASK", "The task object could not be opened."),
+	(0x8004130e, "SCHED_E_INVALID_TASK", "The object is either an invalid task object or is not a task object."),
+	(0x8004130f, "SCHED_E_ACCOUNT_INFORMATION_NOT_SET", "No account information could be found in the Task Scheduler security database for the task indicated."),
+	(0x80041310, "SCHED_E_ACCOUNT_NAME_NOT_FOUND", "Unable to establish existence of the account specified."),
+	(0x80041311, "SCHED_E_ACCOUNT_DBASE_CORRUPT", "Corruption was detected in the Task Scheduler security database; the database has been reset."),
+	(0x80041312, "SCHED_E_NO_SECURITY_SERVICES", "Task Scheduler security services are available only on Windows NT."),
+	(0x80041313, "SCHED_E_UNKNOWN_OBJECT_VERSION", "The task object version is either unsupported or invalid."),
+	(0x80041314, "SCHED_E_UNSUPPORTED_ACCOUNT_OPTION", "The task has been configured with an unsupported combination of account settings and run time options."),
+	(0x80041315, "SCHED_E_SERVICE_NOT_RUNNING", "The Task Scheduler Service is not running."),
+	(0x80041316, "SCHED_E_UNEXPECTEDNODE", "The task XML contains an unexpected node."),
+	(0x80041317, "SCHED_E_NAMESPACE", "The task XML contains an element or attribute from an unexpected namespace."),
+	(0x80041318, "SCHED_E_INVALIDVALUE", "The task XML contains a value which is incorrectly formatted or out of range."),
+	(0x80041319, "SCHED_E_MISSINGNODE", "The task XML is missing a required element or attribute."),
+	(0x8004131a, "SCHED_E_MALFORMEDXML", "The task XML is malformed."),
+	(0x8004131d, "SCHED_E_TOO_MANY_NODES", "The task XML contains too many nodes of the same type."),
+	(0x8004131e, "SCHED_E_PAST_END_BOUNDARY", "The task cannot be started after the trigger end boundary."),
+	(0x8004131f, "SCHED_E_ALREADY_RUNNING", "An instance of this task is already running."),
+	(0x80041320, "SCHED_E_USER_NOT_LOGGED_ON", "The task will not run because the user is not logged on."),
+	(0x80041321, "SCHED_E_INVALID_TASK_HASH", "The task image is corrupt or has been tampered with."),
+	(0x80041322, "SCHED_E_SERVICE_NOT_AVAILABLE", "The Task Scheduler service is not available."),
+	(0x80041323, "SCHED_E_SERVICE_TOO_BUSY", "The Task Scheduler service is too busy to handle your request. Please try again later."),
+	(0x80041324, "SCHED_E_TASK_ATTEMPTED", "The Task Scheduler service attempted to run the task, but the task did not run due to one of the constraints in the task definition."),
+	(0x80041326, "SCHED_E_TASK_DISABLED", "The task is disabled."),
+	(0x80041327, "SCHED_E_TASK_NOT_V1_COMPAT", "The task has properties that are not compatible with previous versions of Windows."),
+	(0x80041328, "SCHED_E_START_ON_DEMAND", "The task settings do not allow the task to start on demand."),
+	(0x80041329, "SCHED_E_TASK_NOT_UBPM_COMPAT", "The combination of properties that task is using is not compatible with the scheduling engine."),
+	(0x80041330, "SCHED_E_DEPRECATED_FEATURE_USED", "The task definition uses a deprecated feature."),
+	(0x8004d000, "XACT_E_ALREADYOTHERSINGLEPHASE", "Another single phase resource manager has already been enlisted in this transaction."),
+	(0x8004d001, "XACT_E_CANTRETAIN", "A retaining commit or abort is not supported."),
+	(0x8004d002, "XACT_E_COMMITFAILED", "The transaction failed to commit for an unknown reason. The transaction was aborted."),
+	(0x8004d003, "XACT_E_COMMITPREVENTED", "Cannot call commit on this transaction object because the calling application did not initiate the transaction."),
+	(0x8004d004, "XACT_E_HEURISTICABORT", "Instead of committing, the resource heuristically aborted."),
+	(0x8004d005, "XACT_E_HEURISTICCOMMIT", "Instead of aborting, the resource heuristically committed."),
+	(0x8004d006, "XACT_E_HEURISTICDAMAGE", "Some of the states of the resource were committed while others were aborted, likely because of heuristic decisions."),
+	(0x8004d007, "XACT_E_HEURISTICDANGER", "Some of the states of the resource may have been committed while others may have been aborted, likely because of heuristic decisions."),
+	(0x8004d008, "XACT_E_ISOLATIONLEVEL", "The requested isolation level is not valid or supported."),
+	(0x8004d009, "XACT_E_NOASYNC", "The transaction manager doesn't support an asynchronous operation for this method."),
+	(0x8004d00a, "XACT_E_NOENLIST", "Unable to enlist in the transaction."),
+	(0x8004d00b, "XACT_E_NOISORETAIN", "The requested semantics of retention of isolation across retaining commit and abort boundaries cannot be supported by this transaction implementation, or isoFlags was not equal to zero."),
+	(0x8004d00c, "XACT_E_NORESOURCE", "There is no resource presently associated with this enlistment."),
+	(0x8004d00d, "XACT_E_NOTCURRENT", "The transaction failed to commit due to the failure of optimistic concurrency control in at least one of the resource managers."),
+	(0x8004d00e, "XACT_E_NOTRANSACTION", "The transaction has already been implicitly or explicitly committed or aborted."),
+	(0x8004d00f, "XACT_E_NOTSUPPORTED", "An invalid combination of flags was specified."),
+	(0x8004d010, "XACT_E_UNKNOWNRMGRID", "The resource manager id is not associated with this transaction or the transaction manager."),
+	(0x8004d011, "XACT_E_WRONGSTATE", "This method was called in the wrong state."),
+	(0x8004d012, "XACT_E_WRONGUOW", "The indicated unit of work does not match the unit of work expected by the resource manager."),
+	(0x8004d013, "XACT_E_XTIONEXISTS", "An enlistment in a transaction already exists."),
+	(0x8004d014, "XACT_E_NOIMPORTOBJECT", "An import object for the transaction could not be found."),
+	(0x8004d015, "XACT_E_INVALIDCOOKIE", "The transaction cookie is invalid."),
+	(0x8004d016, "XACT_E_INDOUBT", "The transaction status is in doubt. A communication failure occurred, or a transaction manager or resource manager has failed."),
+	(0x8004d017, "XACT_E_NOTIMEOUT", "A time-out was specified, but time-outs are not supported."),
+	(0x8004d018, "XACT_E_ALREADYINPROGRESS", "The requested operation is already in progress for the transaction."),
+	(0x8004d019, "XACT_E_ABORTED", "The transaction has already been aborted."),
+	(0x8004d01a, "XACT_E_LOGFULL", "The Transaction Manager returned a log full error."),
+	(0x8004d01b, "XACT_E_TMNOTAVAILABLE", "The Transaction Manager is not available."),
+	(0x8004d01c, "XACT_E_CONNECTION_DOWN", "A connection with the transaction manager was lost."),
+	(0x8004d01d, "XACT_E_CONNECTION_DENIED", "A request to establish a connection with the transaction manager was denied."),
+	(0x8004d01e, "XACT_E_REENLISTTIMEOUT", "Resource manager reenlistment to determine transaction status timed out."),
+	(0x8004d01f, "XACT_E_TIP_CONNECT_FAILED", "The transaction manager failed to establish a connection with another Transaction Internet Protocol (TIP) transaction manager."),
+	(0x8004d020, "XACT_E_TIP_PROTOCOL_ERROR", "The transaction manager encountered a protocol error with another TIP transaction manager."),
+	(0x8004d021, "XACT_E_TIP_PULL_FAILED", "The transaction manager could not propagate a transaction from another TIP transaction manager."),
+	(0x8004d022, "XACT_E_DEST_TMNOTAVAILABLE", "The Transaction Manager on the destination machine is not available."),
+	(0x8004d023, "XACT_E_TIP_DISABLED", "The Transaction Manager has disabled its support for TIP."),
+	(0x8004d024, "XACT_E_NETWORK_TX_DISABLED", "The transaction manager has disabled its support for remote/network transactions."),
+	(0x8004d025, "XACT_E_PARTNER_NETWORK_TX_DISABLED", "The partner transaction manager has disabled its support for remote/network transactions."),
+	(0x8004d026, "XACT_E_XA_TX_DISABLED", "The transaction manager has disabled its support for XA transactions."),
+	(0x8004d027, "XACT_E_UNABLE_TO_READ_DTC_CONFIG", "MSDTC was unable to read its configuration information."),
+	(0x8004d028, "XACT_E_UNABLE_TO_LOAD_DTC_PROXY", "MSDTC was unable to load the dtc proxy dll."),
+	(0x8004d029, "XACT_E_ABORTING", "The local transaction has aborted."),
+	(0x8004d02a, "XACT_E_PUSH_COMM_FAILURE", "The MSDTC transaction manager was unable to push the transaction to the destination transaction manager due to communication problems. Possible causes are: a firewall is present and it doesn't have an exception for the MSDTC process, the two machines cannot find each other by their NetBIOS names, or the support for network transactions is not enabled for one of the two transaction managers."),
+	(0x8004d02b, "XACT_E_PULL_COMM_FAILURE", "The MSDTC transaction manager was unable to pull the transaction from the source transaction manager due to communication problems. Possible causes are: a firewall is present and it doesn't have an exception for the MSDTC process, the two machines cannot find each other by their NetBIOS names, or the support for network transactions is not enabled for one of the two transaction managers."),
+	(0x8004d02c, "XACT_E_LU_TX_DISABLED", "The MSDTC transaction manager has disabled its support for SNA LU 6.2 transactions."),
+	(0x8004d080, "XACT_E_CLERKNOTFOUND", "The specified CRM clerk was not found."),
+	(0x8004d081, "XACT_E_CLERKEXISTS", "An identical CRM clerk already exists."),
+	(0x8004d082, "XACT_E_RECOVERYINPROGRESS", "The CRM recovery is in progress."),
+	(0x8004d083, "XACT_E_TRANSACTIONCLOSED", "The transaction has been closed."),
+	(0x8004d084, "XACT_E_INVALIDLSN", "The log sequence number is invalid."),
+	(0x8004d085, "XACT_E_REPLAYREQUEST", "The replay request is invalid."),
+	(0x8004e002, "CONTEXT_E_ABORTED", "The root transaction wanted to commit, but transaction aborted."),
+	(0x8004e003, "CONTEXT_E_ABORTING", "You made a method call on a COM+ component that has a transaction that has already aborted or in the process of aborting."),
+	(0x8004e004, "CONTEXT_E_NOCONTEXT", "There is no MTS object context."),
+	(0x8004e005, "CONTEXT_E_WOULD_DEADLOCK", "The component is configured to use synchronization and this method call would cause a deadlock to occur."),
+	(0x8004e006, "CONTEXT_E_SYNCH_TIMEOUT", "The component is configured to use synchronization and a thread has timed out waiting to enter the context."),
+	(0x8004e007, "CONTEXT_E_OLDREF", "You made a method call on a COM+ component that has a transaction that has already committed or aborted."),
+	(0x8004e00c, "CONTEXT_E_ROLENOTFOUND", "The specified role was not configured for the application."),
+	(0x8004e00f, "CONTEXT_E_TMNOTAVAILABLE", "COM+ was unable to talk to the Microsoft Distributed Transaction Coordinator."),
+	(0x8004e021, "CO_E_ACTIVATIONFAILED", "An unexpected error occurred during COM+ Activation."),
+	(0x8004e022, "CO_E_ACTIVATIONFAILED_EVENTLOGGED", "COM+ Activation failed. Check the event log for more information."),
+	(0x8004e023, "CO_E_ACTIVATIONFAILED_CATALOGERROR", "COM+ Activation failed due to a catalog or configuration error."),
+	(0x8004e024, "CO_E_ACTIVATIONFAILED_TIMEOUT", "COM+ activation failed because the activation could not be completed in the specified amount of time."),
+	(0x8004e025, "CO_E_INITIALIZATIONFAILED", "COM+ Activation failed because an initialization function failed. Check the event log for more information."),
+	(0x8004e026, "CONTEXT_E_NOJIT", "The requested operation requires that JIT be in the current context and it is not."),
+	(0x8004e027, "CONTEXT_E_NOTRANSACTION", "The requested operation requires that the current context have a Transaction, and it does not."),
+	(0x8004e028, "CO_E_THREADINGMODEL_CHANGED", "The components threading model has changed after install into a COM+ Application. Please re-install component."),
+	(0x8004e029, "CO_E_NOIISINTRINSICS", "IIS intrinsics not available. Start your work with IIS."),
+	(0x8004e02a, "CO_E_NOCOOKIES", "An attempt to write a cookie failed."),
+	(0x8004e02b, "CO_E_DBERROR", "An attempt to use a database generated a database specific error."),
+	(0x8004e02c, "CO_E_NOTPOOLED", "The COM+ component you created must use object pooling to work."),
+	(0x8004e02d, "CO_E_NOTCONSTRUCTED", "The COM+ component you created must use object construction to work correctly."),
+	(0x8004e02e, "CO_E_NOSYNCHRONIZATION", "The COM+ component requires synchronization, and it is not configured for it."),
+	(0x8004e02f, "CO_E_ISOLEVELMISMATCH", "The TxIsolation Level property for the COM+ component being created is stronger than the TxIsolationLevel for the \"root\" component for the transaction. The creation failed."),
+	(0x8004e030, "CO_E_CALL_OUT_OF_TX_SCOPE_NOT_ALLOWED", "The component attempted to make a cross-context call between invocations of EnterTransactionScope and ExitTransactionScope. This is not allowed. Cross-context calls cannot be made while inside of a transaction scope."),
+	(0x8004e031, "CO_E_EXIT_TRANSACTION_SCOPE_NOT_CALLED", "The component made a call to EnterTransactionScope, but did not make a corresponding call to ExitTransactionScope before returning."),
+	(0x80070005, "E_ACCESSDENIED", "Access is denied."),
+	(0x80070006, "E_HANDLE", "The handle is invalid."),
+	(0x8007000e, "E_OUTOFMEMORY", "Not enough memory resources are available to complete this operation."),
+	(0x80070057, "E_INVALIDARG", "The parameter is incorrect."),
+	(0x80072ee1, "WININET_E_OUT_OF_HANDLES", "No more Internet handles can be allocated."),
+	(0x80072ee2, "WININET_E_TIMEOUT", "The operation timed out."),
+	(0x80072ee3, "WININET_E_EXTENDED_ERROR", "The server returned extended information."),
+	(0x80072ee4, "WININET_E_INTERNAL_ERROR", "An internal error occurred in the Microsoft Internet extensions."),
+	(0x80072ee5, "WININET_E_INVALID_URL", "The URL is invalid."),
+	(0x80072ee6, "WININET_E_UNRECOGNIZED_SCHEME", "The URL does not use a recognized protocol."),
+	(0x80072ee7, "WININET_E_NAME_NOT_RESOLVED", "The server name or address could not be resolved."),
+	(0x80072ee8, "WININET_E_PROTOCOL_NOT_FOUND", "A protocol with the required capabilities was not found."),
+	(0x80072ee9, "WININET_E_INVALID_OPTION", "The option is invalid."),
+	(0x80072eea, "WININET_E_BAD_OPTION_LENGTH", "The length is incorrect for the option type."),
+	(0x80072eeb, "WININET_E_OPTION_NOT_SETTABLE", "The option value cannot be set."),
+	(0x80072eec, "WININET_E_SHUTDOWN", "Microsoft Internet Extension support has been shut down."),
+	(0x80072eed, "WININET_E_INCORRECT_USER_NAME", "The user name was not allowed."),
+	(0x80072eee, "WININET_E_INCORRECT_PASSWORD", "The password was not allowed."),
+	(0x80072eef, "WININET_E_LOGIN_FAILURE", "The login request was denied."),
+	(0x80072ef0, "WININET_E_INVALID_OPERATION", "The requested operation is invalid."),
+	(0x80072ef1, "WININET_E_OPERATION_CANCELLED", "The operation has been canceled."),
+	(0x80072ef2, "WININET_E_INCORRECT_HANDLE_TYPE", "The supplied handle is the wrong type for the requested operation."),
+	(0x80072ef3, "WININET_E_INCORRECT_HANDLE_STATE", "The handle is in the wrong state for the requested operation."),
+	(0x80072ef4, "WININET_E_NOT_PROXY_REQUEST", "The request cannot be made on a Proxy session."),
+	(0x80072ef5, "WININET_E_REGISTRY_VALUE_NOT_FOUND", "The registry value could not be found."),
+	(0x80072ef6, "WININET_E_BAD_REGISTRY_PARAMETER", "The registry parameter is incorrect."),
+	(0x80072ef7, "WININET_E_NO_DIRECT_ACCESS", "Direct Internet access is not available."),
+	(0x80072ef8, "WININET_E_NO_CONTEXT", "No context value was supplied."),
+	(0x80072ef9, "WININET_E_NO_CALLBACK", "No status callback was supplied."),
+	(0x80072efa, "WININET_E_REQUEST_PENDING", "There are outstanding requests."),
+	(0x80072efb, "WININET_E_INCORRECT_FORMAT", "The information format is incorrect."),
+	(0x80072efc, "WININET_E_ITEM_NOT_FOUND", "The requested item could not be found."),
+	(0x80072efd, "WININET_E_CANNOT_CONNECT", "A connection with the server could not be established."),
+	(0x80072efe, "WININET_E_CONNECTION_ABORTED", "The connection with the server was terminated abnormally."),
+	(0x80072eff, "WININET_E_CONNECTION_RESET", "The connection with the server was reset."),
+	(0x80072f00, "WININET_E_FORCE_RETRY", "The action must be retried."),
+	(0x80072f01, "WININET_E_INVALID_PROXY_REQUEST", "The proxy request is invalid."),
+	(0x80072f02, "WININET_E_NEED_UI", "User interaction is required to complete the operation."),
+	(0x80072f04, "WININET_E_HANDLE_EXISTS", "The handle already exists."),
+	(0x80072f05, "WININET_E_SEC_CERT_DATE_INVALID", "The date in the certificate is invalid or has expired."),
+	(0x80072f06, "WININET_E_SEC_CERT_CN_INVALID", "The host name in the certificate is invalid or does not match."),
+	(0x80072f07, "WININET_E_HTTP_TO_HTTPS_ON_REDIR", "A redirect request will change a non-secure to a secure connection."),
+	(0x80072f08, "WININET_E_HTTPS_TO_HTTP_ON_REDIR", "A redirect request will change a secure to a non-secure connection."),
+	(0x80072f09, "WININET_E_MIXED_SECURITY", "Mixed secure and non-secure connections."),
+	(0x80072f0a, "WININET_E_CHG_POST_IS_NON_SECURE", "Changing to non-secure post."),
+	(0x80072f0b, "WININET_E_POST_IS_NON_SECURE", "Data is being posted on a non-secure connection."),
+	(0x80072f0c, "WININET_E_CLIENT_AUTH_CERT_NEEDED", "A certificate is required to complete client authentication."),
+	(0x80072f0d, "WININET_E_INVALID_CA", "The certificate authority is invalid or incorrect."),
+	(0x80072f0e, "WININET_E_CLIENT_AUTH_NOT_SETUP", "Client authentication has not been correctly installed."),
+	(0x80072f0f, "WININET_E_ASYNC_THREAD_FAILED", "An error has occurred in a Wininet asynchronous thread. You may need to restart."),
+	(0x80072f10, "WININET_E_REDIRECT_SCHEME_CHANGE", "The protocol scheme has changed during a redirect operation."),
+	(0x80072f11, "WININET_E_DIALOG_PENDING", "There are operations awaiting retry."),
+	(0x80072f12, "WININET_E_RETRY_DIALOG", "The operation must be retried."),
+	(0x80072f13, "WININET_E_NO_NEW_CONTAINERS", "There are no new cache containers."),
+	(0x80072f14, "WININET_E_HTTPS_HTTP_SUBMIT_REDIR", "A security zone check indicates the operation must be retried."),
+	(0x80072f17, "WININET_E_SEC_CERT_ERRORS", "The SSL certificate contains errors."),
+	(0x80072f19, "WININET_E_SEC_CERT_REV_FAILED", "It was not possible to connect to the revocation server or a definitive response could not be obtained."),
+	(0x80072f76, "WININET_E_HEADER_NOT_FOUND", "The requested header was not found."),
+	(0x80072f77, "WININET_E_DOWNLEVEL_SERVER", "The server does not support the requested protocol level."),
+	(0x80072f78, "WININET_E_INVALID_SERVER_RESPONSE", "The server returned an invalid or unrecognized response."),
+	(0x80072f79, "WININET_E_INVALID_HEADER", "The supplied HTTP header is invalid."),
+	(0x80072f7a, "WININET_E_INVALID_QUERY_REQUEST", "The request for a HTTP header is invalid."),
+	(0x80072f7b, "WININET_E_HEADER_ALREADY_EXISTS", "The HTTP header already exists."),
+	(0x80072f7c, "WININET_E_REDIRECT_FAILED", "The HTTP redirect request failed."),
+	(0x80072f7d, "WININET_E_SECURITY_CHANNEL_ERROR", "An error occurred in the secure channel support."),
+	(0x80072f7e, "WININET_E_UNABLE_TO_CACHE_FILE", "The file could not be written to the cache."),
+	(0x80072f7f, "WININET_E_TCPIP_NOT_INSTALLED", "The TCP/IP protocol is not installed properly."),
+	(0x80072f80, "WININET_E_NOT_REDIRECTED", "The HTTP request was not redirected."),
+	(0x80072f81, "WININET_E_COOKIE_NEEDS_CONFIRMATION", "A cookie from the server must be confirmed by the user."),
+	(0x80072f82, "WININET_E_COOKIE_DECLINED", "A cookie from the server has been declined acceptance."),
+	(0x80072f83, "WININET_E_DISCONNECTED", "The computer is disconnected from the network."),
+	(0x80072f84, "WININET_E_SERVER_UNREACHABLE", "The server is unreachable."),
+	(0x80072f85, "WININET_E_PROXY_SERVER_UNREACHABLE", "The proxy server is unreachable."),
+	(0x80072f86, "WININET_E_BAD_AUTO_PROXY_SCRIPT", "The proxy auto-configuration script is in error."),
+	(0x80072f87, "WININET_E_UNABLE_TO_DOWNLOAD_SCRIPT", "Could not download the proxy auto-configuration script file."),
+	(0x80072f88, "WININET_E_REDIRECT_NEEDS_CONFIRMATION", "The HTTP redirect request must be confirmed by the user."),
+	(0x80072f89, "WININET_E_SEC_INVALID_CERT", "The SSL certificate is invalid."),
+	(0x80072f8a, "WININET_E_SEC_CERT_REVOKED", "The SSL certificate was revoked."),
+	(0x80072f8b, "WININET_E_FAILED_DUETOSECURITYCHECK", "The Dialup failed because file sharing was turned on and a failure was requested if security check was needed."),
+	(0x80072f8c, "WININET_E_NOT_INITIALIZED", "Initialization of the WinINet API has not occurred."),
+	(0x80072f8e, "WININET_E_LOGIN_FAILURE_DISPLAY_ENTITY_BODY", "Login failed and the client should display the entity body to the user."),
+	(0x80072f8f, "WININET_E_DECODING_FAILED", "Content decoding has failed."),
+	(0x80080001, "CO_E_CLASS_CREATE_FAILED", "Class factory could not create the object."),
+	(0x80080002, "CO_E_SCM_ERROR", "OLE service could not bind object."),
+	(0x80080003, "CO_E_SCM_RPC_FAILURE", "RPC communication failed with OLE service."),
+	(0x80080004, "CO_E_BAD_PATH", "Bad path to object."),
+	(0x80080005, "CO_E_SERVER_EXEC_FAILURE", "Server execution failed"),
+	(0x80080006, "CO_E_OBJSRV_RPC_FAILURE", "OLE service could not communicate with the object server."),
+	(0x80080007, "MK_E_NO_NORMALIZED", "Moniker path could not be normalized."),
+	(0x80080008, "CO_E_SERVER_STOPPING", "Object server is stopping when OLE service contacts it."),
+	(0x80080009, "MEM_E_INVALID_ROOT", "An invalid root block pointer was specified."),
+	(0x80080010, "MEM_E_INVALID_LINK", "An allocation chain contained an invalid link pointer."),
+	(0x80080011, "MEM_E_INVALID_SIZE", "The requested allocation size was too large."),
+	(0x80080015, "CO_E_MISSING_DISPLAYNAME", "The activation requires a display name to be present under the class ID key."),
+	(0x80080016, "CO_E_RUNAS_VALUE_MUST_BE_AAA", "The activation requires that the RunAs value for the application is Activate As Activator."),
+	(0x80080017, "CO_E_ELEVATION_DISABLED", "The class is not configured to support elevated activation."),
+	(0x80080200, "APPX_E_PACKAGING_INTERNAL", "Appx packaging API has encountered an internal error."),
+	(0x80080201, "APPX_E_INTERLEAVING_NOT_ALLOWED", "The file is not a valid Appx package because its contents are interleaved."),
+	(0x80080202, "APPX_E_RELATIONSHIPS_NOT_ALLOWED", "The file is not a valid Appx package because it contains OPC relationships."),
+	(0x80080203, "APPX_E_MISSING_REQUIRED_FILE", "The file is not a valid Appx package because it is missing a manifest or block map, or missing a signature file when the code integrity file is present."),
+	(0x80080204, "APPX_E_INVALID_MANIFEST", "The Appx package's manifest is invalid."),
+	(0x80080205, "APPX_E_INVALID_BLOCKMAP", "The Appx package's block map is invalid."),
+	(0x80080206, "APPX_E_CORRUPT_CONTENT", "The Appx package's content cannot be read because it is corrupt."),
+	(0x80080207, "APPX_E_BLOCK_HASH_INVALID", "The computed hash value of the block does not match the one stored in the block map."),
+	(0x80080208, "APPX_E_REQUESTED_RANGE_TOO_LARGE", "The requested byte range is over 4GB when translated to byte range of blocks."),
+	(0x80080209, "APPX_E_INVALID_SIP_CLIENT_DATA", "The SIP_SUBJECTINFO structure used to sign the package didn't contain the required data."),
+	(0x8008020a, "APPX_E_INVALID_KEY_INFO", "The APPX_KEY_INFO structure used to encrypt or decrypt the package contains invalid data."),
+	(0x8008020b, "APPX_E_INVALID_CONTENTGROUPMAP", "The Appx package's content group map is invalid."),
+	(0x8008020c, "APPX_E_INVALID_APPINSTALLER", "The .appinstaller file is invalid."),
+	(0x8008020d, "APPX_E_DELTA_BASELINE_VERSION_MISMATCH", "The baseline package version in delta package does not match the version in the baseline package to be updated."),
+	(0x8008020e, "APPX_E_DELTA_PACKAGE_MISSING_FILE", "The delta package is missing a file from the updated package."),
+	(0x8008020f, "APPX_E_INVALID_DELTA_PACKAGE", "The delta package is invalid."),
+	(0x80080210, "APPX_E_DELTA_APPENDED_PACKAGE_NOT_ALLOWED", "The delta appended package is not allowed for the current operation."),
+	(0x80080211, "APPX_E_INVALID_PACKAGING_LAYOUT", "The packaging layout file is invalid."),
+	(0x80080212, "APPX_E_INVALID_PACKAGESIGNCONFIG", "The packageSignConfig file is invalid."),
+	(0x80080213, "APPX_E_RESOURCESPRI_NOT_ALLOWED", "The resources.pri file is not allowed when there are no resource elements in the package manifest."),
+	(0x80080214, "APPX_E_FILE_COMPRESSION_MISMATCH", "The compression state of file in the payload and source package is not the same."),
+	(0x80080215, "APPX_E_INVALID_PAYLOAD_PACKAGE_EXTENSION", "Non appx extensions are not allowed for payload packages targeting older platforms."),
+	(0x80080216, "APPX_E_INVALID_ENCRYPTION_EXCLUSION_FILE_LIST", "The encryptionExclusionFileList file is invalid."),
+	(0x80080300, "BT_E_SPURIOUS_ACTIVATION", "The background task activation is spurious."),
+	(0x80090001, "NTE_BAD_UID", "Bad UID."),
+	(0x80090002, "NTE_BAD_HASH", "Bad Hash."),
+	(0x80090003, "NTE_BAD_KEY", "Bad Key."),
+	(0x80090004, "NTE_BAD_LEN", "Bad Length."),
+	(0x80090005, "NTE_BAD_DATA", "Bad Data."),
+	(0x80090006, "NTE_BAD_SIGNATURE", "Invalid Signature."),
+	(0x80090007, "NTE_BAD_VER", "Bad Version of provider."),
+	(0x80090008, "NTE_BAD_ALGID", "Invalid algorithm specified."),
+	(0x80090009, "NTE_BAD_FLAGS", "Invalid flags specified."),
+	(0x8009000a, "NTE_BAD_TYPE", "Invalid type specified."),
+	(0x8009000b, "NTE_BAD_KEY_STATE", "Key not valid for use in specified state."),
+	(0x8009000c, "NTE_BAD_HASH_STATE", "Hash not valid for use in specified state."),
+	(0x8009000d, "NTE_NO_KEY", "Key does not exist."),
+	(0x8009000e, "NTE_NO_MEMORY", "Insufficient memory available for the operation."),
+	(0x8009000f, "NTE_EXISTS", "Object already exists."),
+	(0x80090010, "NTE_PERM", "Access denied."),
+	(0x80090011, "NTE_NOT_FOUND", "Object was not found."),
+	(0x80090012, "NTE_DOUBLE_ENCRYPT", "Data already encrypted."),
+	(0x80090013, "NTE_BAD_PROVIDER", "Invalid provider specified."),
+	(0x80090014, "NTE_BAD_PROV_TYPE", "Invalid provider type specified."),
+	(0x80090015, "NTE_BAD_PUBLIC_KEY", "Provider's public key is invalid."),
+	(0x80090016, "NTE_BAD_KEYSET", "Keyset does not exist."),
+	(0x80090017, "NTE_PROV_TYPE_NOT_DEF", "Provider type not defined."),
+	(0x80090018, "NTE_PROV_TYPE_ENTRY_BAD", "Provider type as registered is invalid."),
+	(0x80090019, "NTE_KEYSET_NOT_DEF", "The keyset is not defined."),
+	(0x8009001a, "NTE_KEYSET_ENTRY_BAD", "Keyset as registered is invalid."),
+	(0x8009001b, "NTE_PROV_TYPE_NO_MATCH", "Provider type does not match registered value."),
+	(0x8009001c, "NTE_SIGNATURE_FILE_BAD", "The digital signature file is corrupt."),
+	(0x8009001d, "NTE_PROVIDER_DLL_FAIL", "Provider DLL failed to initialize correctly."),
+	(0x8009001e, "NTE_PROV_DLL_NOT_FOUND", "Provider DLL could not be found."),
+	(0x8009001f, "NTE_BAD_KEYSET_PARAM", "The Keyset parameter is invalid."),
+	(0x80090020, "NTE_FAIL", "An internal error occurred."),
+	(0x80090021, "NTE_SYS_ERR", "A base error occurred."),
+	(0x80090022, "NTE_SILENT_CONTEXT", "Provider could not perform the action since the context was acquired as silent."),
+	(0x80090023, "NTE_TOKEN_KEYSET_STORAGE_FULL", "The security token does not have storage space available for an additional container."),
+	(0x80090024, "NTE_TEMPORARY_PROFILE", "The profile for the user is a temporary profile."),
+	(0x80090025, "NTE_FIXEDPARAMETER", "The key parameters could not be set because the CSP uses fixed parameters."),
+	(0x80090026, "NTE_INVALID_HANDLE", "The supplied handle is invalid."),
+	(0x80090027, "NTE_INVALID_PARAMETER", "The parameter is incorrect."),
+	(0x80090028, "NTE_BUFFER_TOO_SMALL", "The buffer supplied to a function was too small."),
+	(0x80090029, "NTE_NOT_SUPPORTED", "The requested operation is not supported."),
+	(0x8009002a, "NTE_NO_MORE_ITEMS", "No more data is available."),
+	(0x8009002b, "NTE_BUFFERS_OVERLAP", "The supplied buffers overlap incorrectly."),
+	(0x8009002c, "NTE_DECRYPTION_FAILURE", "The specified data could not be decrypted."),
+	(0x8009002d, "NTE_INTERNAL_ERROR", "An internal consistency check failed."),
+	(0x8009002e, "NTE_UI_REQUIRED", "This operation requires input from the user."),
+	(0x8009002f, "NTE_HMAC_NOT_SUPPORTED", "The cryptographic provider does not support HMAC."),
+	(0x80090030, "NTE_DEVICE_NOT_READY", "The device that is required by this cryptographic provider is not ready for use."),
+	(0x80090031, "NTE_AUTHENTICATION_IGNORED", "The dictionary attack mitigation is triggered and the provided authorization was ignored by the provider."),
+	(0x80090032, "NTE_VALIDATION_FAILED", "The validation of the provided data failed the integrity verification."),
+	(0x80090033, "NTE_INCORRECT_PASSWORD", "Incorrect password."),
+	(0x80090034, "NTE_ENCRYPTION_FAILURE", "Encryption failed."),
+	(0x80090035, "NTE_DEVICE_NOT_FOUND", "The device that is required by this cryptographic provider is not found on this platform."),
+	(0x80090036, "NTE_USER_CANCELLED", "The action was cancelled by the user."),
+	(0x80090037, "NTE_PASSWORD_CHANGE_REQUIRED", "The password is no longer valid and must be changed."),
+	(0x80090038, "NTE_NOT_ACTIVE_CONSOLE", "The operation cannot be completed from Terminal Server client sessions."),
+	(0x80090300, "SEC_E_INSUFFICIENT_MEMORY", "Not enough memory is available to complete this request."),
+	(0x80090301, "SEC_E_INVALID_HANDLE", "The handle specified is invalid."),
+	(0x80090302, "SEC_E_UNSUPPORTED_FUNCTION", "The function requested is not supported."),
+	(0x80090303, "SEC_E_TARGET_UNKNOWN", "The specified target is unknown or unreachable."),
+	(0x80090304, "SEC_E_INTERNAL_ERROR", "The Local Security Authority cannot be contacted."),
+	(0x80090305, "SEC_E_SECPKG_NOT_FOUND", "The requested security package does not exist."),
+	(0x80090306, "SEC_E_NOT_OWNER", "The caller is not the owner of the desired credentials."),
+	(0x80090307, "SEC_E_CANNOT_INSTALL", "The security package failed to initialize, and cannot be installed."),
+	(0x80090308, "SEC_E_INVALID_TOKEN", "The token supplied to the function is invalid."),
+	(0x80090309, "SEC_E_CANNOT_PACK", "The security package is not able to marshal the logon buffer, so the logon attempt has failed."),
+	(0x8009030a, "SEC_E_QOP_NOT_SUPPORTED", "The per-message Quality of Protection is not supported by the security package."),
+	(0x8009030b, "SEC_E_NO_IMPERSONATION", "The security context does not allow impersonation of the client."),
+	(0x8009030c, "SEC_E_LOGON_DENIED", "The logon attempt failed."),
+	(0x8009030d, "SEC_E_UNKNOWN_CREDENTIALS", "The credentials supplied to the package were not recognized."),
+	(0x8009030e, "SEC_E_NO_CREDENTIALS", "No credentials are available in the security package."),
+	(0x8009030f, "SEC_E_MESSAGE_ALTERED", "The message or signature supplied for verification has been altered."),
+	(0x80090310, "SEC_E_OUT_OF_SEQUENCE", "The message supplied for verification is out of sequence."),
+	(0x80090311, "SEC_E_NO_AUTHENTICATING_AUTHORITY", "No authority could be contacted for authentication."),
+	(0x80090316, "SEC_E_BAD_PKGID", "The requested security package does not exist."),
+	(0x80090317, "SEC_E_CONTEXT_EXPIRED", "The context has expired and can no longer be used."),
+	(0x80090318, "SEC_E_INCOMPLETE_MESSAGE", "The supplied message is incomplete. The signature was not verified."),
+	(0x80090320, "SEC_E_INCOMPLETE_CREDENTIALS", "The credentials supplied were not complete, and could not be verified. The context could not be initialized."),
+	(0x80090321, "SEC_E_BUFFER_TOO_SMALL", "The buffers supplied to a function was too small."),
+	(0x80090322, "SEC_E_WRONG_PRINCIPAL", "The target principal name is incorrect."),
+	(0x80090324, "SEC_E_TIME_SKEW", "The clocks on the client and server machines are skewed."),
+	(0x80090325, "SEC_E_UNTRUSTED_ROOT", "The certificate chain was issued by an authority that is not trusted."),
+	(0x80090326, "SEC_E_ILLEGAL_MESSAGE", "The message received was unexpected or badly formatted."),
+	(0x80090327, "SEC_E_CERT_UNKNOWN", "An unknown error occurred while processing the certificate."),
+	(0x80090328, "SEC_E_CERT_EXPIRED", "The received certificate has expired."),
+	(0x80090329, "SEC_E_ENCRYPT_FAILURE", "The specified data could not be encrypted."),
+	(0x80090330, "SEC_E_DECRYPT_FAILURE", "The specified data could not be decrypted."),
+	(0x80090331, "SEC_E_ALGORITHM_MISMATCH", "The client and server cannot communicate, because they do not possess a common algorithm."),
+	(0x80090332, "SEC_E_SECURITY_QOS_FAILED", "The security context could not be established due to a failure in the requested quality of service (e.g. mutual authentication or delegation)."),
+	(0x80090333, "SEC_E_UNFINISHED_CONTEXT_DELETED", "A security context was deleted before the context was completed. This is considered a logon failure."),
+	(0x80090334, "SEC_E_NO_TGT_REPLY", "The client is trying to negotiate a context and the server requires user-to-user but didn't send a TGT reply."),
+	(0x80090335, "SEC_E_NO_IP_ADDRESSES", "Unable to accomplish the requested task because the local machine does not have any IP addresses."),
+	(0x80090336, "SEC_E_WRONG_CREDENTIAL_HANDLE", "The supplied credential handle does not match the credential associated with the security context."),
+	(0x80090337, "SEC_E_CRYPTO_SYSTEM_INVALID", "The crypto system or checksum function is invalid because a required function is unavailable."),
+	(0x80090338, "SEC_E_MAX_REFERRALS_EXCEEDED", "The number of maximum ticket referrals has been exceeded."),
+	(0x80090339, "SEC_E_MUST_BE_KDC", "The local machine must be a Kerberos KDC (domain controller) and it is not."),
+	(0x8009033a, "SEC_E_STRONG_CRYPTO_NOT_SUPPORTED", "The other end of the security negotiation is requires strong crypto but it is not supported on the local machine."),
+	(0x8009033b, "SEC_E_TOO_MANY_PRINCIPALS", "The KDC reply contained more than one principal name."),
+	(0x8009033c, "SEC_E_NO_PA_DATA", "Expected to find PA data for a hint of what etype to use, but it was not found."),
+	(0x8009033d, "SEC_E_PKINIT_NAME_MISMATCH", "The client certificate does not contain a valid UPN, or does not match the client name in the logon request. Please contact your administrator."),
+	(0x8009033e, "SEC_E_SMARTCARD_LOGON_REQUIRED", "Smartcard logon is required and was not used."),
+	(0x8009033f, "SEC_E_SHUTDOWN_IN_PROGRESS", "A system shutdown is in progress."),
+	(0x80090340, "SEC_E_KDC_INVALID_REQUEST", "An invalid request was sent to the KDC."),
+	(0x80090341, "SEC_E_KDC_UNABLE_TO_REFER", "The KDC was unable to generate a referral for the service requested."),
+	(0x80090342, "SEC_E_KDC_UNKNOWN_ETYPE", "The encryption type requested is not supported by the KDC."),
+	(0x80090343, "SEC_E_UNSUPPORTED_PREAUTH", "An unsupported preauthentication mechanism was presented to the Kerberos package."),
+	(0x80090345, "SEC_E_DELEGATION_REQUIRED", "The requested operation cannot be completed. The computer must be trusted for delegation and the current user account must be configured to allow delegation."),
+	(0x80090346, "SEC_E_BAD_BINDINGS", "Client's supplied SSPI channel bindings were incorrect."),
+	(0x80090347, "SEC_E_MULTIPLE_ACCOUNTS", "The received certificate was mapped to multiple accounts."),
+	(0x80090348, "SEC_E_NO_KERB_KEY", "No Kerberos key was found."),
+	(0x80090349, "SEC_E_CERT_WRONG_USAGE", "The certificate is not valid for the requested usage."),
+	(0x80090350, "SEC_E_DOWNGRADE_DETECTED", "The system cannot contact a domain controller to service the authentication request. Please try again later."),
+	(0x80090351, "SEC_E_SMARTCARD_CERT_REVOKED", "The smartcard certificate used for authentication has been revoked. Please contact your system administrator. There may be additional information in the event log."),
+	(0x80090352, "SEC_E_ISSUING_CA_UNTRUSTED", "An untrusted certificate authority was detected while processing the smartcard certificate used for authentication. Please contact your system administrator."),
+	(0x80090353, "SEC_E_REVOCATION_OFFLINE_C", "The revocation status of the smartcard certificate used for authentication could not be determined. Please contact your system administrator."),
+	(0x80090354, "SEC_E_PKINIT_CLIENT_FAILURE", "The smartcard certificate used for authentication was not trusted. Please contact your system administrator."),
+	(0x80090355, "SEC_E_SMARTCARD_CERT_EXPIRED", "The smartcard certificate used for authentication has expired. Please contact your system administrator."),
+	(0x80090356, "SEC_E_NO_S4U_PROT_SUPPORT", "The Kerberos subsystem encountered an error. A service for user protocol request was made against a domain controller which does not support service for user."),
+	(0x80090357, "SEC_E_CROSSREALM_DELEGATION_FAILURE", "An attempt was made by this server to make a Kerberos constrained delegation request for a target outside of the server's realm. This is not supported, and indicates a misconfiguration on this server's allowed to delegate to list. Please contact your administrator."),
+	(0x80090358, "SEC_E_REVOCATION_OFFLINE_KDC", "The revocation status of the domain controller certificate used for smartcard authentication could not be determined. There is additional information in the system event log. Please contact your system administrator."),
+	(0x80090359, "SEC_E_ISSUING_CA_UNTRUSTED_KDC", "An untrusted certificate authority was detected while processing the domain controller certificate used for authentication. There is additional information in the system event log. Please contact your system administrator."),
+	(0x8009035a, "SEC_E_KDC_CERT_EXPIRED", "The domain controller certificate used for smartcard logon has expired. Please contact your system administrator with the contents of your system event log."),
+	(0x8009035b, "SEC_E_KDC_CERT_REVOKED", "The domain controller certificate used for smartcard logon has been revoked. Please contact your system administrator with the contents of your system event log."),
+	(0x8009035d, "SEC_E_INVALID_PARAMETER", "One or more of the parameters passed to the function was invalid."),
+	(0x8009035e, "SEC_E_DELEGATION_POLICY", "Client policy does not allow credential delegation to target server."),
+	(0x8009035f, "SEC_E_POLICY_NLTM_ONLY", "Client policy does not allow credential delegation to target server with NLTM only authentication."),
+	(0x80090361, "SEC_E_NO_CONTEXT", "The required security context does not exist."),
+	(0x80090362, "SEC_E_PKU2U_CERT_FAILURE", "The PKU2U protocol encountered an error while attempting to utilize the associated certificates."),
+	(0x80090363, "SEC_E_MUTUAL_AUTH_FAILED", "The identity of the server computer could not be verified."),
+	(0x80090365, "SEC_E_ONLY_HTTPS_ALLOWED", "Only https scheme is allowed."),
+	(0x80090367, "SEC_E_APPLICATION_PROTOCOL_MISMATCH", "No common application protocol exists between the client and the server. Application protocol negotiation failed."),
+	(0x80090369, "SEC_E_INVALID_UPN_NAME", "You can't sign in with a user ID in this format. Try using your email address instead."),
+	(0x8009036a, "SEC_E_EXT_BUFFER_TOO_SMALL", "The buffer supplied by the SSPI caller to receive generic extensions is too small."),
+	(0x8009036b, "SEC_E_INSUFFICIENT_BUFFERS", "Not enough secbuffers were supplied to generate a token."),
+	(0x80091001, "CRYPT_E_MSG_ERROR", "An error occurred while performing an operation on a cryptographic message."),
+	(0x80091002, "CRYPT_E_UNKNOWN_ALGO", "Unknown cryptographic algorithm."),
+	(0x80091003, "CRYPT_E_OID_FORMAT", "The object identifier is poorly formatted."),
+	(0x80091004, "CRYPT_E_INVALID_MSG_TYPE", "Invalid cryptographic message type."),
+	(0x80091005, "CRYPT_E_UNEXPECTED_ENCODING", "Unexpected cryptographic message encoding."),
+	(0x80091006, "CRYPT_E_AUTH_ATTR_MISSING", "The cryptographic message does not contain an expected authenticated attribute."),
+	(0x80091007, "CRYPT_E_HASH_VALUE", "The hash value is not correct."),
+	(0x80091008, "CRYPT_E_INVALID_INDEX", "The index value is not valid."),
+	(0x80091009, "CRYPT_E_ALREADY_DECRYPTED", "The content of the cryptographic message has already been decrypted."),
+	(0x8009100a, "CRYPT_E_NOT_DECRYPTED", "The content of the cryptographic message has not been decrypted yet."),
+	(0x8009100b, "CRYPT_E_RECIPIENT_NOT_FOUND", "The enveloped-data message does not contain the specified recipient."),
+	(0x8009100c, "CRYPT_E_CONTROL_TYPE", "Invalid control type."),
+	(0x8009100d, "CRYPT_E_ISSUER_SERIALNUMBER", "Invalid issuer and/or serial number."),
+	(0x8009100e, "CRYPT_E_SIGNER_NOT_FOUND", "Cannot find the original signer."),
+	(0x8009100f, "CRYPT_E_ATTRIBUTES_MISSING", "The cryptographic message does not contain all of the requested attributes."),
+	(0x80091010, "CRYPT_E_STREAM_MSG_NOT_READY", "The streamed cryptographic message is not ready to return data."),
+	(0x80091011, "CRYPT_E_STREAM_INSUFFICIENT_DATA", "The streamed cryptographic message requires more data to complete the decode operation."),
+	(0x80092001, "CRYPT_E_BAD_LEN", "The length specified for the output data was insufficient."),
+	(0x80092002, "CRYPT_E_BAD_ENCODE", "An error occurred during encode or decode operation."),
+	(0x80092003, "CRYPT_E_FILE_ERROR", "An error occurred while reading or writing to a file."),
+	(0x80092004, "CRYPT_E_NOT_FOUND", "Cannot find object or property."),
+	(0x80092005, "CRYPT_E_EXISTS", "The object or property already exists."),
+	(0x80092006, "CRYPT_E_NO_PROVIDER", "No provider was specified for the store or object."),
+	(0x80092007, "CRYPT_E_SELF_SIGNED", "The specified certificate is self signed."),
+	(0x80092008, "CRYPT_E_DELETED_PREV", "The previous certificate or CRL context was deleted."),
+	(0x80092009, "CRYPT_E_NO_MATCH", "Cannot find the requested object."),
+	(0x8009200a, "CRYPT_E_UNEXPECTED_MSG_TYPE", "The cryptographic message type is not expected."),
+	(0x8009200b, "CRYPT_E_NO_KEY_PROPERTY", "The certificate does not have a property that references a private key."),
+	(0x8009200c, "CRYPT_E_NO_DECRYPT_CERT", "Cannot find the certificate and private key to use for decryption."),
+	(0x8009200d, "CRYPT_E_BAD_MSG", "Not a cryptographic message or the cryptographic message is not formatted correctly."),
+	(0x8009200e, "CRYPT_E_NO_SIGNER", "The signed cryptographic message does not have a signer for the specified signer index."),
+	(0x8009200f, "CRYPT_E_PENDING_CLOSE", "Final closure is pending until additional frees or closes."),
+	(0x80092010, "CRYPT_E_REVOKED", "The certificate is revoked."),
+	(0x80092011, "CRYPT_E_NO_REVOCATION_DLL", "No Dll or exported function was found to verify revocation."),
+	(0x80092012, "CRYPT_E_NO_REVOCATION_CHECK", "The revocation function was unable to check revocation for the certificate."),
+	(0x80092013, "CRYPT_E_REVOCATION_OFFLINE", "The revocation function was unable to check revocation because the revocation server was offline."),
+	(0x80092014, "CRYPT_E_NOT_IN_REVOCATION_DATABASE", "The certificate is not in the revocation server's database."),
+	(0x80092020, "CRYPT_E_INVALID_NUMERIC_STRING", "The string contains a non-numeric character."),
+	(0x80092021, "CRYPT_E_INVALID_PRINTABLE_STRING", "The string contains a non-printable character."),
+	(0x80092022, "CRYPT_E_INVALID_IA5_STRING", "The string contains a character not in the 7 bit ASCII character set."),
+	(0x80092023, "CRYPT_E_INVALID_X500_STRING", "The string contains an invalid X500 name attribute key, oid, value or delimiter."),
+	(0x80092024, "CRYPT_E_NOT_CHAR_STRING", "The dwValueType for the CERT_NAME_VALUE is not one of the character strings. Most likely it is either a CERT_RDN_ENCODED_BLOB or CERT_RDN_OCTET_STRING."),
+	(0x80092025, "CRYPT_E_FILERESIZED", "The Put operation cannot continue. The file needs to be resized. However, there is already a signature present. A complete signing operation must be done."),
+	(0x80092026, "CRYPT_E_SECURITY_SETTINGS", "The cryptographic operation failed due to a local security option setting."),
+	(0x80092027, "CRYPT_E_NO_VERIFY_USAGE_DLL", "No DLL or exported function was found to verify subject usage."),
+	(0x80092028, "CRYPT_E_NO_VERIFY_USAGE_CHECK", "The called function was unable to do a usage check on the subject."),
+	(0x80092029, "CRYPT_E_VERIFY_USAGE_OFFLINE", "Since the server was offline, the called function was unable to complete the usage check."),
+	(0x8009202a, "CRYPT_E_NOT_IN_CTL", "The subject was not found in a Certificate Trust List (CTL)."),
+	(0x8009202b, "CRYPT_E_NO_TRUSTED_SIGNER", "None of the signers of the cryptographic message or certificate trust list is trusted."),
+	(0x8009202c, "CRYPT_E_MISSING_PUBKEY_PARA", "The public key's algorithm parameters are missing."),
+	(0x8009202d, "CRYPT_E_OBJECT_LOCATOR_OBJECT_NOT_FOUND", "An object could not be located using the object locator infrastructure with the given name."),
+	(0x80093000, "CRYPT_E_OSS_ERROR", "OSS Certificate encode/decode error code base."),
+	(0x80093001, "OSS_MORE_BUF", "OSS ASN.1 Error: Output Buffer is too small."),
+	(0x80093002, "OSS_NEGATIVE_UINTEGER", "OSS ASN.1 Error: Signed integer is encoded as a unsigned integer."),
+	(0x80093003, "OSS_PDU_RANGE", "OSS ASN.1 Error: Unknown ASN.1 data type."),
+	(0x80093004, "OSS_MORE_INPUT", "OSS ASN.1 Error: Output buffer is too small, the decoded data has been truncated."),
+	(0x80093005, "OSS_DATA_ERROR", "OSS ASN.1 Error: Invalid data."),
+	(0x80093006, "OSS_BAD_ARG", "OSS ASN.1 Error: Invalid argument."),
+	(0x80093007, "OSS_BAD_VERSION", "OSS ASN.1 Error: Encode/Decode version mismatch."),
+	(0x80093008, "OSS_OUT_MEMORY", "OSS ASN.1 Error: Out of memory."),
+	(0x80093009, "OSS_PDU_MISMATCH", "OSS ASN.1 Error: Encode/Decode Error."),
+	(0x8009300a, "OSS_LIMITED", "OSS ASN.1 Error: Internal Error."),
+	(0x8009300b, "OSS_BAD_PTR", "OSS ASN.1 Error: Invalid data."),
+	(0x8009300c, "OSS_BAD_TIME", "OSS ASN.1 Error: Invalid data."),
+	(0x8009300d, "OSS_INDEFINITE_NOT_SUPPORTED", "OSS ASN.1 Error: Unsupported BER indefinite-length encoding."),
+	(0x8009300e, "OSS_MEM_ERROR", "OSS ASN.1 Error: Access violation."),
+	(0x8009300f, "OSS_BAD_TABLE", "OSS ASN.1 Error: Invalid data."),
+	(0x80093010, "OSS_TOO_LONG", "OSS ASN.1 Error: Invalid data."),
+	(0x80093011, "OSS_CONSTRAINT_VIOLATED", "OSS ASN.1 Error: Invalid data."),
+	(0x80093012, "OSS_FATAL_ERROR", "OSS ASN.1 Error: Internal Error."),
+	(0x80093013, "OSS_ACCESS_SERIALIZATION_ERROR", "OSS ASN.1 Error: Multi-threading conflict."),
+	(0x80093014, "OSS_NULL_TBL", "OSS ASN.1 Error: Invalid data."),
+	(0x80093015, "OSS_NULL_FCN", "OSS ASN.1 Error: Invalid data."),
+	(0x80093016, "OSS_BAD_ENCRULES", "OSS ASN.1 Error: Invalid data."),
+	(0x80093017, "OSS_UNAVAIL_ENCRULES", "OSS ASN.1 Error: Encode/Decode function not implemented."),
+	(0x80093018, "OSS_CANT_OPEN_TRACE_WINDOW", "OSS ASN.1 Error: Trace file error."),
+	(0x80093019, "OSS_UNIMPLEMENTED", "OSS ASN.1 Error: Function not implemented."),
+	(0x8009301a, "OSS_OID_DLL_NOT_LINKED", "OSS ASN.1 Error: Program link error."),
+	(0x8009301b, "OSS_CANT_OPEN_TRACE_FILE", "OSS ASN.1 Error: Trace file error."),
+	(0x8009301c, "OSS_TRACE_FILE_ALREADY_OPEN", "OSS ASN.1 Error: Trace file error."),
+	(0x8009301d, "OSS_TABLE_MISMATCH", "OSS ASN.1 Error: Invalid data."),
+	(0x8009301e, "OSS_TYPE_NOT_SUPPORTED", "OSS ASN.1 Error: Invalid data."),
+	(0x8009301f, "OSS_REAL_DLL_NOT_LINKED", "OSS ASN.1 Error: Program link error."),
+	(0x80093020, "OSS_REAL_CODE_NOT_LINKED", "OSS ASN.1 Error: Program link error."),
+	(0x80093021, "OSS_OUT_OF_RANGE", "OSS ASN.1 Error: Program link error."),
+	(0x80093022, "OSS_COPIER_DLL_NOT_LINKED", "OSS ASN.1 Error: Program link error."),
+	(0x80093023, "OSS_CONSTRAINT_DLL_NOT_LINKED", "OSS ASN.1 Error: Program link error."),
+	(0x80093024, "OSS_COMPARATOR_DLL_NOT_LINKED", "OSS ASN.1 Error: Program link error."),
+	(0x80093025, "OSS_COMPARATOR_CODE_NOT_LINKED", "OSS ASN.1 Error: Program link error."),
+	(0x80093026, "OSS_MEM_MGR_DLL_NOT_LINKED", "OSS ASN.1 Error: Program link error."),
+	(0x80093027, "OSS_PDV_DLL_NOT_LINKED", "OSS ASN.1 Error: Program link error."),
+	(0x80093028, "OSS_PDV_CODE_NOT_LINKED", "OSS ASN.1 Error: Program link error."),
+	(0x80093029, "OSS_API_DLL_NOT_LINKED", "OSS ASN.1 Error: Program link error."),
+	(0x8009302a, "OSS_BERDER_DLL_NOT_LINKED", "OSS ASN.1 Error: Program link error."),
+	(0x8009302b, "OSS_PER_DLL_NOT_LINKED", "OSS ASN.1 Error: Program link error."),
+	(0x8009302c, "OSS_OPEN_TYPE_ERROR", "OSS ASN.1 Error: Program link error."),
+	(0x8009302d, "OSS_MUTEX_NOT_CREATED", "OSS ASN.1 Error: System resource error."),
+	(0x8009302e, "OSS_CANT_CLOSE_TRACE_FILE", "OSS ASN.1 Error: Trace file error."),
+	(0x80093100, "CRYPT_E_ASN1_ERROR", "ASN1 Certificate encode/decode error code base."),
+	(0x80093101, "CRYPT_E_ASN1_INTERNAL", "ASN1 internal encode or decode error."),
+	(0x80093102, "CRYPT_E_ASN1_EOD", "ASN1 unexpected end of data."),
+	(0x80093103, "CRYPT_E_ASN1_CORRUPT", "ASN1 corrupted data."),
+	(0x80093104, "CRYPT_E_ASN1_LARGE", "ASN1 value too large."),
+	(0x80093105, "CRYPT_E_ASN1_CONSTRAINT", "ASN1 constraint violated."),
+	(0x80093106, "CRYPT_E_ASN1_MEMORY", "ASN1 out of memory."),
+	(0x80093107, "CRYPT_E_ASN1_OVERFLOW", "ASN1 buffer overflow."),
+	(0x80093108, "CRYPT_E_ASN1_BADPDU", "ASN1 function not supported for this PDU."),
+	(0x80093109, "CRYPT_E_ASN1_BADARGS", "ASN1 bad arguments to function call."),
+	(0x8009310a, "CRYPT_E_ASN1_BADREAL", "ASN1 bad real value."),
+	(0x8009310b, "CRYPT_E_ASN1_BADTAG", "ASN1 bad tag value met."),
+	(0x8009310c, "CRYPT_E_ASN1_CHOICE", "ASN1 bad choice value."),
+	(0x8009310d, "CRYPT_E_ASN1_RULE", "ASN1 bad encoding rule."),
+	(0x8009310e, "CRYPT_E_ASN1_UTF8", "ASN1 bad unicode (UTF8)."),
+	(0x80093133, "CRYPT_E_ASN1_PDU_TYPE", "ASN1 bad PDU type."),
+	(0x80093134, "CRYPT_E_ASN1_NYI", "ASN1 not yet implemented."),
+	(0x80093201, "CRYPT_E_ASN1_EXTENDED", "ASN1 skipped unknown extension(s)."),
+	(0x80093202, "CRYPT_E_ASN1_NOEOD", "ASN1 end of data expected."),
+	(0x80094001, "CERTSRV_E_BAD_REQUESTSUBJECT", "The request subject name is invalid or too long."),
+	(0x80094002, "CERTSRV_E_NO_REQUEST", "The request does not exist."),
+	(0x80094003, "CERTSRV_E_BAD_REQUESTSTATUS", "The request's current status does not allow this operation."),
+	(0x80094004, "CERTSRV_E_PROPERTY_EMPTY", "The requested property value is empty."),
+	(0x80094005, "CERTSRV_E_INVALID_CA_CERTIFICATE", "The certification authority's certificate contains invalid data."),
+	(0x80094006, "CERTSRV_E_SERVER_SUSPENDED", "Certificate service has been suspended for a database restore operation."),
+	(0x80094007, "CERTSRV_E_ENCODING_LENGTH", "The certificate contains an encoded length that is potentially incompatible with older enrollment software."),
+	(0x80094008, "CERTSRV_E_ROLECONFLICT", "The operation is denied. The user has multiple roles assigned and the certification authority is configured to enforce role separation."),
+	(0x80094009, "CERTSRV_E_RESTRICTEDOFFICER", "The operation is denied. It can only be performed by a certificate manager that is allowed to manage certificates for the current requester."),
+	(0x8009400a, "CERTSRV_E_KEY_ARCHIVAL_NOT_CONFIGURED", "Cannot archive private key. The certification authority is not configured for key archival."),
+	(0x8009400b, "CERTSRV_E_NO_VALID_KRA", "Cannot archive private key. The certification authority could not verify one or more key recovery certificates."),
+	(0x8009400c, "CERTSRV_E_BAD_REQUEST_KEY_ARCHIVAL", "The request is incorrectly formatted. The encrypted private key must be in an unauthenticated attribute in an outermost signature."),
+	(0x8009400d, "CERTSRV_E_NO_CAADMIN_DEFINED", "At least one security principal must have the permission to manage this CA."),
+	(0x8009400e, "CERTSRV_E_BAD_RENEWAL_CERT_ATTRIBUTE", "The request contains an invalid renewal certificate attribute."),
+	(0x8009400f, "CERTSRV_E_NO_DB_SESSIONS", "An attempt was made to open a Certification Authority database session, but there are already too many active sessions. The server may need to be configured to allow additional sessions."),
+	(0x80094010, "CERTSRV_E_ALIGNMENT_FAULT", "A memory reference caused a data alignment fault."),
+	(0x80094011, "CERTSRV_E_ENROLL_DENIED", "The permissions on this certification authority do not allow the current user to enroll for certificates."),
+	(0x80094012, "CERTSRV_E_TEMPLATE_DENIED", "The permissions on the certificate template do not allow the current user to enroll for this type of certificate."),
+	(0x80094013, "CERTSRV_E_DOWNLEVEL_DC_SSL_OR_UPGRADE", "The contacted domain controller cannot support signed LDAP traffic. Update the domain controller or configure Certificate Services to use SSL for Active Directory access."),
+	(0x80094014, "CERTSRV_E_ADMIN_DENIED_REQUEST", "The request was denied by a certificate manager or CA administrator."),
+	(0x80094015, "CERTSRV_E_NO_POLICY_SERVER", "An enrollment policy server cannot be located."),
+	(0x80094016, "CERTSRV_E_WEAK_SIGNATURE_OR_KEY", "A signature algorithm or public key length does not meet the system's minimum required strength."),
+	(0x80094017, "CERTSRV_E_KEY_ATTESTATION_NOT_SUPPORTED", "Failed to create an attested key. This computer or the cryptographic provider may not meet the hardware requirements to support key attestation."),
+	(0x80094018, "CERTSRV_E_ENCRYPTION_CERT_REQUIRED", "No encryption certificate was specified."),
+	(0x80094800, "CERTSRV_E_UNSUPPORTED_CERT_TYPE", "The requested certificate template is not supported by this CA."),
+	(0x80094801, "CERTSRV_E_NO_CERT_TYPE", "The request contains no certificate template information."),
+	(0x80094802, "CERTSRV_E_TEMPLATE_CONFLICT", "The request contains conflicting template information."),
+	(0x80094803, "CERTSRV_E_SUBJECT_ALT_NAME_REQUIRED", "The request is missing a required Subject Alternate name extension."),
+	(0x80094804, "CERTSRV_E_ARCHIVED_KEY_REQUIRED", "The request is missing a required private key for archival by the server."),
+	(0x80094805, "CERTSRV_E_SMIME_REQUIRED", "The request is missing a required SMIME capabilities extension."),
+	(0x80094806, "CERTSRV_E_BAD_RENEWAL_SUBJECT", "The request was made on behalf of a subject other than the caller. The certificate template must be configured to require at least one signature to authorize the request."),
+	(0x80094807, "CERTSRV_E_BAD_TEMPLATE_VERSION", "The request template version is newer than the supported template version."),
+	(0x80094808, "CERTSRV_E_TEMPLATE_POLICY_REQUIRED", "The template is missing a required signature policy attribute."),
+	(0x80094809, "CERTSRV_E_SIGNATURE_POLICY_REQUIRED", "The request is missing required signature policy information."),
+	(0x8009480a, "CERTSRV_E_SIGNATURE_COUNT", "The request is missing one or more required signatures."),
+	(0x8009480b, "CERTSRV_E_SIGNATURE_REJECTED", "One or more signatures did not include the required application or issuance policies. The request is missing one or more required valid signatures."),
+	(0x8009480c, "CERTSRV_E_ISSUANCE_POLICY_REQUIRED", "The request is missing one or more required signature issuance policies."),
+	(0x8009480d, "CERTSRV_E_SUBJECT_UPN_REQUIRED", "The UPN is unavailable and cannot be added to the Subject Alternate name."),
+	(0x8009480e, "CERTSRV_E_SUBJECT_DIRECTORY_GUID_REQUIRED", "The Active Directory GUID is unavailable and cannot be added to the Subject Alternate name."),
+	(0x8009480f, "CERTSRV_E_SUBJECT_DNS_REQUIRED", "The DNS name is unavailable and cannot be added to the Subject Alternate name."),
+	(0x80094810, "CERTSRV_E_ARCHIVED_KEY_UNEXPECTED", "The request includes a private key for archival by the server, but key archival is not enabled for the specified certificate template."),
+	(0x80094811, "CERTSRV_E_KEY_LENGTH", "The public key does not meet the minimum size required by the specified certificate template."),
+	(0x80094812, "CERTSRV_E_SUBJECT_EMAIL_REQUIRED", "The EMail name is unavailable and cannot be added to the Subject or Subject Alternate name."),
+	(0x80094813, "CERTSRV_E_UNKNOWN_CERT_TYPE", "One or more certificate templates to be enabled on this certification authority could not be found."),
+	(0x80094814, "CERTSRV_E_CERT_TYPE_OVERLAP", "The certificate template renewal period is longer than the certificate validity period. The template should be reconfigured or the CA certificate renewed."),
+	(0x80094815, "CERTSRV_E_TOO_MANY_SIGNATURES", "The certificate template requires too many RA signatures. Only one RA signature is allowed."),
+	(0x80094816, "CERTSRV_E_RENEWAL_BAD_PUBLIC_KEY", "The certificate template requires renewal with the same public key, but the request uses a different public key."),
+	(0x80094817, "CERTSRV_E_INVALID_EK", "The certification authority cannot interpret or verify the endorsement key information supplied in the request, or the information is inconsistent."),
+	(0x80094818, "CERTSRV_E_INVALID_IDBINDING", "The certification authority cannot validate the Attestation Identity Key Id Binding."),
+	(0x80094819, "CERTSRV_E_INVALID_ATTESTATION", "The certification authority cannot validate the private key attestation data."),
+	(0x8009481a, "CERTSRV_E_KEY_ATTESTATION", "The request does not support private key attestation as defined in the certificate template."),
+	(0x8009481b, "CERTSRV_E_CORRUPT_KEY_ATTESTATION", "The request public key is not consistent with the private key attestation data."),
+	(0x8009481c, "CERTSRV_E_EXPIRED_CHALLENGE", "The private key attestation challenge cannot be validated because the encryption certificate has expired, or the certificate or key is unavailable."),
+	(0x8009481d, "CERTSRV_E_INVALID_RESPONSE", "The attestation response could not be validated. It is either unexpected or incorrect."),
+	(0x8009481e, "CERTSRV_E_INVALID_REQUESTID", "A valid Request ID was not detected in the request attributes, or an invalid one was submitted."),
+	(0x8009481f, "CERTSRV_E_REQUEST_PRECERTIFICATE_MISMATCH", "The request is not consistent with the previously issued precertificate."),
+	(0x80094820, "CERTSRV_E_PENDING_CLIENT_RESPONSE", "The request is locked against edits until a client response is received."),
+	(0x80095000, "XENROLL_E_KEY_NOT_EXPORTABLE", "The key is not exportable."),
+	(0x80095001, "XENROLL_E_CANNOT_ADD_ROOT_CERT", "You cannot add the root CA certificate into your local store."),
+	(0x80095002, "XENROLL_E_RESPONSE_KA_HASH_NOT_FOUND", "The key archival hash attribute was not found in the response."),
+	(0x80095003, "XENROLL_E_RESPONSE_UNEXPECTED_KA_HASH", "An unexpected key archival hash attribute was found in the response."),
+	(0x80095004, "XENROLL_E_RESPONSE_KA_HASH_MISMATCH", "There is a key archival hash mismatch between the request and the response."),
+	(0x80095005, "XENROLL_E_KEYSPEC_SMIME_MISMATCH", "Signing certificate cannot include SMIME extension."),
+	(0x80096001, "TRUST_E_SYSTEM_ERROR", "A system-level error occurred while verifying trust."),
+	(0x80096002, "TRUST_E_NO_SIGNER_CERT", "The certificate for the signer of the message is invalid or not found."),
+	(0x80096003, "TRUST_E_COUNTER_SIGNER", "One of the counter signatures was invalid."),
+	(0x80096004, "TRUST_E_CERT_SIGNATURE", "The signature of the certificate cannot be verified."),
+	(0x80096005, "TRUST_E_TIME_STAMP", "The timestamp signature and/or certificate could not be verified or is malformed."),
+	(0x80096010, "TRUST_E_BAD_DIGEST", "The digital signature of the object did not verify."),
+	(0x80096011, "TRUST_E_MALFORMED_SIGNATURE", "The digital signature of the object is malformed. For technical detail, see security bulletin MS13-098."),
+	(0x80096019, "TRUST_E_BASIC_CONSTRAINTS", "A certificate's basic constraint extension has not been observed."),
+	(0x8009601e, "TRUST_E_FINANCIAL_CRITERIA", "The certificate does not meet or contain the Authenticode(tm) financial extensions."),
+	(0x80097001, "MSSIPOTF_E_OUTOFMEMRANGE", "Tried to reference a part of the file outside the proper range."),
+	(0x80097002, "MSSIPOTF_E_CANTGETOBJECT", "Could not retrieve an object from the file."),
+	(0x80097003, "MSSIPOTF_E_NOHEADTABLE", "Could not find the head table in the file."),
+	(0x80097004, "MSSIPOTF_E_BAD_MAGICNUMBER", "The magic number in the head table is incorrect."),
+	(0x80097005, "MSSIPOTF_E_BAD_OFFSET_TABLE", "The offset table has incorrect values."),
+	(0x80097006, "MSSIPOTF_E_TABLE_TAGORDER", "Duplicate table tags or tags out of alphabetical order."),
+	(0x80097007, "MSSIPOTF_E_TABLE_LONGWORD", "A table does not start on a long word boundary."),
+	(0x80097008, "MSSIPOTF_E_BAD_FIRST_TABLE_PLACEMENT", "First table does not appear after header information."),
+	(0x80097009, "MSSIPOTF_E_TABLES_OVERLAP", "Two or more tables overlap."),
+	(0x8009700a, "MSSIPOTF_E_TABLE_PADBYTES", "Too many pad bytes between tables or pad bytes are not 0."),
+	(0x8009700b, "MSSIPOTF_E_FILETOOSMALL", "File is too small to contain the last table."),
+	(0x8009700c, "MSSIPOTF_E_TABLE_CHECKSUM", "A table checksum is incorrect."),
+	(0x8009700d, "MSSIPOTF_E_FILE_CHECKSUM", "The file checksum is incorrect."),
+	(0x80097010, "MSSIPOTF_E_FAILED_POLICY", "The signature does not have the correct attributes for the policy."),
+	(0x80097011, "MSSIPOTF_E_FAILED_HINTS_CHECK", "The file did not pass the hints check."),
+	(0x80097012, "MSSIPOTF_E_NOT_OPENTYPE", "The file is not an OpenType file."),
+	(0x80097013, "MSSIPOTF_E_FILE", "Failed on a file operation (open, map, read, write)."),
+	(0x80097014, "MSSIPOTF_E_CRYPT", "A call to a CryptoAPI function failed."),
+	(0x80097015, "MSSIPOTF_E_BADVERSION", "There is a bad version number in the file."),
+	(0x80097016, "MSSIPOTF_E_DSIG_STRUCTURE", "The structure of the DSIG table is incorrect."),
+	(0x80097017, "MSSIPOTF_E_PCONST_CHECK", "A check failed in a partially constant table."),
+	(0x80097018, "MSSIPOTF_E_STRUCTURE", "Some kind of structural error."),
+	(0x80097019, "ERROR_CRED_REQUIRES_CONFIRMATION", "The requested credential requires confirmation."),
+	(0x800b0001, "TRUST_E_PROVIDER_UNKNOWN", "Unknown trust provider."),
+	(0x800b0002, "TRUST_E_ACTION_UNKNOWN", "The trust verification action specified is not supported by the specified trust provider."),
+	(0x800b0003, "TRUST_E_SUBJECT_FORM_UNKNOWN", "The form specified for the subject is not one supported or known by the specified trust provider."),
+	(0x800b0004, "TRUST_E_SUBJECT_NOT_TRUSTED", "The subject is not trusted for the specified action."),
+	(0x800b0005, "DIGSIG_E_ENCODE", "Error due to problem in ASN.1 encoding process."),
+	(0x800b0006, "DIGSIG_E_DECODE", "Error due to problem in ASN.1 decoding process."),
+	(0x800b0007, "DIGSIG_E_EXTENSIBILITY", "Reading / writing Extensions where Attributes are appropriate, and vice versa."),
+	(0x800b0008, "DIGSIG_E_CRYPTO", "Unspecified cryptographic failure."),
+	(0x800b0009, "PERSIST_E_SIZEDEFINITE", "The size of the data could not be determined."),
+	(0x800b000a, "PERSIST_E_SIZEINDEFINITE", "The size of the indefinite-sized data could not be determined."),
+	(0x800b000b, "PERSIST_E_NOTSELFSIZING", "This object does not read and write self-sizing data."),
+	(0x800b0100, "TRUST_E_NOSIGNATURE", "No signature was present in the subject."),
+	(0x800b0101, "CERT_E_EXPIRED", "A required certificate is not within its validity period when verifying against the current system clock or the timestamp in the signed file."),
+	(0x800b0102, "CERT_E_VALIDITYPERIODNESTING", "The validity periods of the certification chain do not nest correctly."),
+	(0x800b0103, "CERT_E_ROLE", "A certificate that can only be used as an end-entity is being used as a CA or vice versa."),
+	(0x800b0104, "CERT_E_PATHLENCONST", "A path length constraint in the certification chain has been violated."),
+	(0x800b0105, "CERT_E_CRITICAL", "A certificate contains an unknown extension that is marked 'critical'."),
+	(0x800b0106, "CERT_E_PURPOSE", "A certificate being used for a purpose other than the ones specified by its CA."),
+	(0x800b0107, "CERT_E_ISSUERCHAINING", "A parent of a given certificate in fact did not issue that child certificate."),
+	(0x800b0108, "CERT_E_MALFORMED", "A certificate is missing or has an empty value for an important field, such as a subject or issuer name."),
+	(0x800b0109, "CERT_E_UNTRUSTEDROOT", "A certificate chain processed, but terminated in a root certificate which is not trusted by the trust provider."),
+	(0x800b010a, "CERT_E_CHAINING", "A certificate chain could not be built to a trusted root authority."),
+	(0x800b010b, "TRUST_E_FAIL", "Generic trust failure."),
+	(0x800b010c, "CERT_E_REVOKED", "A certificate was explicitly revoked by its issuer."),
+	(0x800b010d, "CERT_E_UNTRUSTEDTESTROOT", "The certification path terminates in the test root which is not trusted with the current policy settings."),
+	(0x800b010e, "CERT_E_REVOCATION_FAILURE", "The revocation process could not continue - the certificate(s) could not be checked."),
+	(0x800b010f, "CERT_E_CN_NO_MATCH", "The certificate's CN name does not match the passed value."),
+	(0x800b0110, "CERT_E_WRONG_USAGE", "The certificate is not valid for the requested usage."),
+	(0x800b0111, "TRUST_E_EXPLICIT_DISTRUST", "The certificate was explicitly marked as untrusted by the user."),
+	(0x800b0112, "CERT_E_UNTRUSTEDCA", "A certification chain processed correctly, but one of the CA certificates is not trusted by the policy provider."),
+	(0x800b0113, "CERT_E_INVALID_POLICY", "The certificate has invalid policy."),
+	(0x800b0114, "CERT_E_INVALID_NAME", "The certificate has an invalid name. The name is not included in the permitted list or is explicitly excluded."),
+	(0x800c0002, "INET_E_INVALID_URL", "The URL is invalid."),
+	(0x800c0003, "INET_E_NO_SESSION", "No Internet session has been established."),
+	(0x800c0004, "INET_E_CANNOT_CONNECT", "Unable to connect to the target server."),
+	(0x800c0005, "INET_E_RESOURCE_NOT_FOUND", "The system cannot locate the resource specified."),
+	(0x800c0006, "INET_E_OBJECT_NOT_FOUND", "The system cannot locate the object specified."),
+	(0x800c0007, "INET_E_DATA_NOT_AVAILABLE", "No data is available for the requested resource."),
+	(0x800c0008, "INET_E_DOWNLOAD_FAILURE", "The download of the specified resource has failed."),
+	(0x800c0009, "INET_E_AUTHENTICATION_REQUIRED", "Authentication is required to access this resource."),
+	(0x800c000a, "INET_E_NO_VALID_MEDIA", "The server could not recognize the provided mime type."),
+	(0x800c000b, "INET_E_CONNECTION_TIMEOUT", "The operation was timed out."),
+	(0x800c000c, "INET_E_INVALID_REQUEST", "The server did not understand the request, or the request was invalid."),
+	(0x800c000d, "INET_E_UNKNOWN_PROTOCOL", "The specified protocol is unknown."),
+	(0x800c000e, "INET_E_SECURITY_PROBLEM", "A security problem occurred."),
+	(0x800c000f, "INET_E_CANNOT_LOAD_DATA", "The system could not load the persisted data."),
+	(0x800c0010, "INET_E_CANNOT_INSTANTIATE_OBJECT", "Unable to instantiate the object."),
+	(0x800c0014, "INET_E_REDIRECT_FAILED", "Unable to redirect the request."),
+	(0x800c0015, "INET_E_REDIRECT_TO_DIR", "The request is being redirected to a directory."),
+	(0x800c0019, "INET_E_INVALID_CERTIFICATE", "The SSL certificate is invalid."),
+	(0x800f0000, "SPAPI_E_EXPECTED_SECTION_NAME", "A non-empty line was encountered in the INF before the start of a section."),
+	(0x800f0001, "SPAPI_E_BAD_SECTION_NAME_LINE", "A section name marker in the INF is not complete, or does not exist on a line by itself."),
+	(0x800f0002, "SPAPI_E_SECTION_NAME_TOO_LONG", "An INF section was encountered whose name exceeds the maximum section name length."),
+	(0x800f0003, "SPAPI_E_GENERAL_SYNTAX", "The syntax of the INF is invalid."),
+	(0x800f0100, "SPAPI_E_WRONG_INF_STYLE", "The style of the INF is different than what was requested."),
+	(0x800f0101, "SPAPI_E_SECTION_NOT_FOUND", "The required section was not found in the INF."),
+	(0x800f0102, "SPAPI_E_LINE_NOT_FOUND", "The required line was not found in the INF."),
+	(0x800f0103, "SPAPI_E_NO_BACKUP", "The files affected by the installation of this file queue have not been backed up for uninstall."),
+	(0x800f0200, "SPAPI_E_NO_ASSOCIATED_CLASS", "The INF or the device information set or element does not have an associated install class."),
+	(0x800f0201, "SPAPI_E_CLASS_MISMATCH", "The INF or the device information set or element does not match the specified install class."),
+	(0x800f0202, "SPAPI_E_DUPLICATE_FOUND", "An existing device was found that is a duplicate of the device being manually installed."),
+	(0x800f0203, "SPAPI_E_NO_DRIVER_SELECTED", "There is no driver selected for the device information set or element."),
+	(0x800f0204, "SPAPI_E_KEY_DOES_NOT_EXIST", "The requested device registry key does not exist."),
+	(0x800f0205, "SPAPI_E_INVALID_DEVINST_NAME", "The device instance name is invalid."),
+	(0x800f0206, "SPAPI_E_INVALID_CLASS", "The install class is not present or is invalid."),
+	(0x800f0207, "SPAPI_E_DEVINST_ALREADY_EXISTS", "The device instance cannot be created because it already exists."),
+	(0x800f0208, "SPAPI_E_DEVINFO_NOT_REGISTERED", "The operation cannot be performed on a device information element that has not been registered."),
+	(0x800f0209, "SPAPI_E_INVALID_REG_PROPERTY", "The device property code is invalid."),
+	(0x800f020a, "SPAPI_E_NO_INF", "The INF from which a driver list is to be built does not exist."),
+	(0x800f020b, "SPAPI_E_NO_SUCH_DEVINST", "The device instance does not exist in the hardware tree."),
+	(0x800f020c, "SPAPI_E_CANT_LOAD_CLASS_ICON", "The icon representing this install class cannot be loaded."),
+	(0x800f020d, "SPAPI_E_INVALID_CLASS_INSTALLER", "The class installer registry entry is invalid."),
+	(0x800f020e, "SPAPI_E_DI_DO_DEFAULT", "The class installer has indicated that the default action should be performed for this installation request."),
+	(0x800f020f, "SPAPI_E_DI_NOFILECOPY", "The operation does not require any files to be copied."),
+	(0x800f0210, "SPAPI_E_INVALID_HWPROFILE", "The specified hardware profile does not exist."),
+	(0x800f0211, "SPAPI_E_NO_DEVICE_SELECTED", "There is no device information element currently selected for this device information set."),
+	(0x800f0212, "SPAPI_E_DEVINFO_LIST_LOCKED", "The operation cannot be performed because the device information set is locked."),
+	(0x800f0213, "SPAPI_E_DEVINFO_DATA_LOCKED", "The operation cannot be performed because the device information element is locked."),
+	(0x800f0214, "SPAPI_E_DI_BAD_PATH", "The specified path does not contain any applicable device INFs."),
+	(0x800f0215, "SPAPI_E_NO_CLASSINSTALL_PARAMS", "No class installer parameters have been set for the device information set or element."),
+	(0x800f0216, "SPAPI_E_FILEQUEUE_LOCKED", "The operation cannot be performed because the file queue is locked."),
+	(0x800f0217, "SPAPI_E_BAD_SERVICE_INSTALLSECT", "A service installation section in this INF is invalid."),
+	(0x800f0218, "SPAPI_E_NO_CLASS_DRIVER_LIST", "There is no class driver list for the device information element."),
+	(0x800f0219, "SPAPI_E_NO_ASSOCIATED_SERVICE", "The installation failed because a function driver was not specified for this device instance."),
+	(0x800f021a, "SPAPI_E_NO_DEFAULT_DEVICE_INTERFACE", "There is presently no default device interface designated for this interface class."),
+	(0x800f021b, "SPAPI_E_DEVICE_INTERFACE_ACTIVE", "The operation cannot be performed because the device interface is currently active."),
+	(0x800f021c, "SPAPI_E_DEVICE_INTERFACE_REMOVED", "The operation cannot be performed because the device interface has been removed from the system."),
+	(0x800f021d, "SPAPI_E_BAD_INTERFACE_INSTALLSECT", "An interface installation section in this INF is invalid."),
+	(0x800f021e, "SPAPI_E_NO_SUCH_INTERFACE_CLASS", "This interface class does not exist in the system."),
+	(0x800f021f, "SPAPI_E_INVALID_REFERENCE_STRING", "The reference string supplied for this interface device is invalid."),
+	(0x800f0220, "SPAPI_E_INVALID_MACHINENAME", "The specified machine name does not conform to UNC naming conventions."),
+	(0x800f0221, "SPAPI_E_REMOTE_COMM_FAILURE", "A general remote communication error occurred."),
+	(0x800f0222, "SPAPI_E_MACHINE_UNAVAILABLE", "The machine selected for remote communication is not available at this time."),
+	(0x800f0223, "SPAPI_E_NO_CONFIGMGR_SERVICES", "The Plug and Play service is not available on the remote machine."),
+	(0x800f0224, "SPAPI_E_INVALID_PROPPAGE_PROVIDER", "The property page provider registry entry is invalid."),
+	(0x800f0225, "SPAPI_E_NO_SUCH_DEVICE_INTERFACE", "The requested device interface is not present in the system."),
+	(0x800f0226, "SPAPI_E_DI_POSTPROCESSING_REQUIRED", "The device's co-installer has additional work to perform after installation is complete."),
+	(0x800f0227, "SPAPI_E_INVALID_COINSTALLER", "The device's co-installer is invalid."),
+	(0x800f0228, "SPAPI_E_NO_COMPAT_DRIVERS", "There are no compatible drivers for this device."),
+	(0x800f0229, "SPAPI_E_NO_DEVICE_ICON", "There is no icon that represents this device or device type."),
+	(0x800f022a, "SPAPI_E_INVALID_INF_LOGCONFIG", "A logical configuration specified in this INF is invalid."),
+	(0x800f022b, "SPAPI_E_DI_DONT_INSTALL", "The class installer has denied the request to install or upgrade this device."),
+	(0x800f022c, "SPAPI_E_INVALID_FILTER_DRIVER", "One of the filter drivers installed for this device is invalid."),
+	(0x800f022d, "SPAPI_E_NON_WINDOWS_NT_DRIVER", "The driver selected for this device does not support this version of Windows."),
+	(0x800f022e, "SPAPI_E_NON_WINDOWS_DRIVER", "The driver selected for this device does not support Windows."),
+	(0x800f022f, "SPAPI_E_NO_CATALOG_FOR_OEM_INF", "The third-party INF does not contain digital signature information."),
+	(0x800f0230, "SPAPI_E_DEVINSTALL_QUEUE_NONNATIVE", "An invalid attempt was made to use a device installation file queue for verification of digital signatures relative to other platforms."),
+	(0x800f0231, "SPAPI_E_NOT_DISABLEABLE", "The device cannot be disabled."),
+	(0x800f0232, "SPAPI_E_CANT_REMOVE_DEVINST", "The device could not be dynamically removed."),
+	(0x800f0233, "SPAPI_E_INVALID_TARGET", "Cannot copy to specified target."),
+	(0x800f0234, "SPAPI_E_DRIVER_NONNATIVE", "Driver is not intended for this platform."),
+	(0x800f0235, "SPAPI_E_IN_WOW64", "Operation not allowed in WOW64."),
+	(0x800f0236, "SPAPI_E_SET_SYSTEM_RESTORE_POINT", "The operation involving unsigned file copying was rolled back, so that a system restore point could be set."),
+	(0x800f0237, "SPAPI_E_INCORRECTLY_COPIED_INF", "An INF was copied into the Windows INF directory in an improper manner."),
+	(0x800f0238, "SPAPI_E_SCE_DISABLED", "The Security Configuration Editor (SCE) APIs have been disabled on this Embedded product."),
+	(0x800f0239, "SPAPI_E_UNKNOWN_EXCEPTION", "An unknown exception was encountered."),
+	(0x800f023a, "SPAPI_E_PNP_REGISTRY_ERROR", "A problem was encountered when accessing the Plug and Play registry database."),
+	(0x800f023b, "SPAPI_E_REMOTE_REQUEST_UNSUPPORTED", "The requested operation is not supported for a remote machine."),
+	(0x800f023c, "SPAPI_E_NOT_AN_INSTALLED_OEM_INF", "The specified file is not an installed OEM INF."),
+	(0x800f023d, "SPAPI_E_INF_IN_USE_BY_DEVICES", "One or more devices are presently installed using the specified INF."),
+	(0x800f023e, "SPAPI_E_DI_FUNCTION_OBSOLETE", "The requested device install operation is obsolete."),
+	(0x800f023f, "SPAPI_E_NO_AUTHENTICODE_CATALOG", "A file could not be verified because it does not have an associated catalog signed via Authenticode(tm)."),
+	(0x800f0240, "SPAPI_E_AUTHENTICODE_DISALLOWED", "Authenticode(tm) signature verification is not supported for the specified INF."),
+	(0x800f0241, "SPAPI_E_AUTHENTICODE_TRUSTED_PUBLISHER", "The INF was signed with an Authenticode(tm) catalog from a trusted publisher."),
+	(0x800f0242, "SPAPI_E_AUTHENTICODE_TRUST_NOT_ESTABLISHED", "The publisher of an Authenticode(tm) signed catalog has not yet been established as trusted."),
+	(0x800f0243, "SPAPI_E_AUTHENTICODE_PUBLISHER_NOT_TRUSTED", "The publisher of an Authenticode(tm) signed catalog was not established as trusted."),
+	(0x800f0244, "SPAPI_E_SIGNATURE_OSATTRIBUTE_MISMATCH", "The software was tested for compliance with Windows Logo requirements on a different version of Windows, and may not be compatible with this version."),
+	(0x800f0245, "SPAPI_E_ONLY_VALIDATE_VIA_AUTHENTICODE", "The file may only be validated by a catalog signed via Authenticode(tm)."),
+	(0x800f0246, "SPAPI_E_DEVICE_INSTALLER_NOT_READY", "One of the installers for this device cannot perform the installation at this time."),
+	(0x800f0247, "SPAPI_E_DRIVER_STORE_ADD_FAILED", "A problem was encountered while attempting to add the driver to the store."),
+	(0x800f0248, "SPAPI_E_DEVICE_INSTALL_BLOCKED", "The installation of this device is forbidden by system policy. Contact your system administrator."),
+	(0x800f0249, "SPAPI_E_DRIVER_INSTALL_BLOCKED", "The installation of this driver is forbidden by system policy. Contact your system administrator."),
+	(0x800f024a, "SPAPI_E_WRONG_INF_TYPE", "The specified INF is the wrong type for this operation."),
+	(0x800f024b, "SPAPI_E_FILE_HASH_NOT_IN_CATALOG", "The hash for the file is not present in the specified catalog file. The file is likely corrupt or the victim of tampering."),
+	(0x800f024c, "SPAPI_E_DRIVER_STORE_DELETE_FAILED", "A problem was encountered while attempting to delete the driver from the store."),
+	(0x800f0300, "SPAPI_E_UNRECOVERABLE_STACK_OVERFLOW", "An unrecoverable stack overflow was encountered."),
+	(0x800f1000, "SPAPI_E_ERROR_NOT_INSTALLED", "No installed components were detected."),
+	(0x80100001, "SCARD_F_INTERNAL_ERROR", "An internal consistency check failed."),
+	(0x80100002, "SCARD_E_CANCELLED", "The action was cancelled by an SCardCancel request."),
+	(0x80100003, "SCARD_E_INVALID_HANDLE", "The supplied handle was invalid."),
+	(0x80100004, "SCARD_E_INVALID_PARAMETER", "One or more of the supplied parameters could not be properly interpreted."),
+	(0x80100005, "SCARD_E_INVALID_TARGET", "Registry startup information is missing or invalid."),
+	(0x80100006, "SCARD_E_NO_MEMORY", "Not enough memory available to complete this command."),
+	(0x80100007, "SCARD_F_WAITED_TOO_LONG", "An internal consistency timer has expired."),
+	(0x80100008, "SCARD_E_INSUFFICIENT_BUFFER", "The data buffer to receive returned data is too small for the returned data."),
+	(0x80100009, "SCARD_E_UNKNOWN_READER", "The specified reader name is not recognized."),
+	(0x8010000a, "SCARD_E_TIMEOUT", "The user-specified timeout value has expired."),
+	(0x8010000b, "SCARD_E_SHARING_VIOLATION", "The smart card cannot be accessed because of other connections outstanding."),
+	(0x8010000c, "SCARD_E_NO_SMARTCARD", "The operation requires a Smart Card, but no Smart Card is currently in the device."),
+	(0x8010000d, "SCARD_E_UNKNOWN_CARD", "The specified smart card name is not recognized."),
+	(0x8010000e, "SCARD_E_CANT_DISPOSE", "The system could not dispose of the media in the requested manner."),
+	(0x8010000f, "SCARD_E_PROTO_MISMATCH", "The requested protocols are incompatible with the protocol currently in use with the smart card."),
+	(0x80100010, "SCARD_E_NOT_READY", "The reader or smart card is not ready to accept commands."),
+	(0x80100011, "SCARD_E_INVALID_VALUE", "One or more of the supplied parameters values could not be properly interpreted."),
+	(0x80100012, "SCARD_E_SYSTEM_CANCELLED", "The action was cancelled by the system, presumably to log off or shut down."),
+	(0x80100013, "SCARD_F_COMM_ERROR", "An internal communications error has been detected."),
+	(0x80100014, "SCARD_F_UNKNOWN_ERROR", "An internal error has been detected, but the source is unknown."),
+	(0x80100015, "SCARD_E_INVALID_ATR", "An ATR obtained from the registry is not a valid ATR string."),
+	(0x80100016, "SCARD_E_NOT_TRANSACTED", "An attempt was made to end a non-existent transaction."),
+	(0x80100017, "SCARD_E_READER_UNAVAILABLE", "The specified reader is not currently available for use."),
+	(0x80100018, "SCARD_P_SHUTDOWN", "The operation has been aborted to allow the server application to exit."),
+	(0x80100019, "SCARD_E_PCI_TOO_SMALL", "The PCI Receive buffer was too small."),
+	(0x8010001a, "SCARD_E_READER_UNSUPPORTED", "The reader driver does not meet minimal requirements for support."),
+	(0x8010001b, "SCARD_E_DUPLICATE_READER", "The reader driver did not produce a unique reader name."),
+	(0x8010001c, "SCARD_E_CARD_UNSUPPORTED", "The smart card does not meet minimal requirements for support."),
+	(0x8010001d, "SCARD_E_NO_SERVICE", "The Smart Card Resource Manager is not running."),
+	(0x8010001e, "SCARD_E_SERVICE_STOPPED", "The Smart Card Resource Manager has shut down."),
+	(0x8010001f, "SCARD_E_UNEXPECTED", "An unexpected card error has occurred."),
+	(0x80100020, "SCARD_E_ICC_INSTALLATION", "No Primary Provider can be found for the smart card."),
+	(0x80100021, "SCARD_E_ICC_CREATEORDER", "The requested order of object creation is not supported."),
+	(0x80100022, "SCARD_E_UNSUPPORTED_FEATURE", "This smart card does not support the requested feature."),
+	(0x80100023, "SCARD_E_DIR_NOT_FOUND", "The identified directory does not exist in the smart card."),
+	(0x80100024, "SCARD_E_FILE_NOT_FOUND", "The identified file does not exist in the smart card."),
+	(0x80100025, "SCARD_E_NO_DIR", "The supplied path does not represent a smart card directory."),
+	(0x80100026, "SCARD_E_NO_FILE", "The supplied path does not represent a smart card file."),
+	(0x80100027, "SCARD_E_NO_ACCESS", "Access is denied to this file."),
+	(0x80100028, "SCARD_E_WRITE_TOO_MANY", "The smart card does not have enough memory to store the information."),
+	(0x80100029, "SCARD_E_BAD_SEEK", "There was an error trying to set the smart card file object pointer."),
+	(0x8010002a, "SCARD_E_INVALID_CHV", "The supplied PIN is incorrect."),
+	(0x8010002b, "SCARD_E_UNKNOWN_RES_MNG", "An unrecognized error code was returned from a layered component."),
+	(0x8010002c, "SCARD_E_NO_SUCH_CERTIFICATE", "The requested certificate does not exist."),
+	(0x8010002d, "SCARD_E_CERTIFICATE_UNAVAILABLE", "The requested certificate could not be obtained."),
+	(0x8010002e, "SCARD_E_NO_READERS_AVAILABLE", "Cannot find a smart card reader."),
+	(0x8010002f, "SCARD_E_COMM_DATA_LOST", "A communications error with the smart card has been detected. Retry the operation."),
+	(0x80100030, "SCARD_E_NO_KEY_CONTAINER", "The requested key container does not exist on the smart card."),
+	(0x80100031, "SCARD_E_SERVER_TOO_BUSY", "The Smart Card Resource Manager is too busy to complete this operation."),
+	(0x80100032, "SCARD_E_PIN_CACHE_EXPIRED", "The smart card PIN cache has expired."),
+	(0x80100033, "SCARD_E_NO_PIN_CACHE", "The smart card PIN cannot be cached."),
+	(0x80100034, "SCARD_E_READ_ONLY_CARD", "The smart card is read only and cannot be written to."),
+	(0x80100065, "SCARD_W_UNSUPPORTED_CARD", "The reader cannot communicate with the smart card, due to ATR configuration conflicts."),
+	(0x80100066, "SCARD_W_UNRESPONSIVE_CARD", "The smart card is not responding to a reset."),
+	(0x80100067, "SCARD_W_UNPOWERED_CARD", "Power has been removed from the smart card, so that further communication is not possible."),
+	(0x80100068, "SCARD_W_RESET_CARD", "The smart card has been reset, so any shared state information is invalid."),
+	(0x80100069, "SCARD_W_REMOVED_CARD", "The smart card has been removed, so that further communication is not possible."),
+	(0x8010006a, "SCARD_W_SECURITY_VIOLATION", "Access was denied because of a security violation."),
+	(0x8010006b, "SCARD_W_WRONG_CHV", "The card cannot be accessed because the wrong PIN was presented."),
+	(0x8010006c, "SCARD_W_CHV_BLOCKED", "The card cannot be accessed because the maximum number of PIN entry attempts has been reached."),
+	(0x8010006d, "SCARD_W_EOF", "The end of the smart card file has been reached."),
+	(0x8010006e, "SCARD_W_CANCELLED_BY_USER", "The action was cancelled by the user."),
+	(0x8010006f, "SCARD_W_CARD_NOT_AUTHENTICATED", "No PIN was presented to the smart card."),
+	(0x80100070, "SCARD_W_CACHE_ITEM_NOT_FOUND", "The requested item could not be found in the cache."),
+	(0x80100071, "SCARD_W_CACHE_ITEM_STALE", "The requested cache item is too old and was deleted from the cache."),
+	(0x80100072, "SCARD_W_CACHE_ITEM_TOO_BIG", "The new cache item exceeds the maximum per-item size defined for the cache."),
+	(0x80110401, "COMADMIN_E_OBJECTERRORS", "Errors occurred accessing one or more objects - the ErrorInfo collection may have more detail."),
+	(0x80110402, "COMADMIN_E_OBJECTINVALID", "One or more of the object's properties are missing or invalid."),
+	(0x80110403, "COMADMIN_E_KEYMISSING", "The object was not found in the catalog."),
+	(0x80110404, "COMADMIN_E_ALREADYINSTALLED", "The object is already registered."),
+	(0x80110407, "COMADMIN_E_APP_FILE_WRITEFAIL", "An error occurred writing to the application file."),
+	(0x80110408, "COMADMIN_E_APP_FILE_READFAIL", "An error occurred reading the application file."),
+	(0x80110409, "COMADMIN_E_APP_FILE_VERSION", "Invalid version number in application file."),
+	(0x8011040a, "COMADMIN_E_BADPATH", "The file path is invalid."),
+	(0x8011040b, "COMADMIN_E_APPLICATIONEXISTS", "The application is already installed."),
+	(0x8011040c, "COMADMIN_E_ROLEEXISTS", "The role already exists."),
+	(0x8011040d, "COMADMIN_E_CANTCOPYFILE", "An error occurred copying the file."),
+	(0x8011040f, "COMADMIN_E_NOUSER", "One or more users are not valid."),
+	(0x80110410, "COMADMIN_E_INVALIDUSERIDS", "One or more users in the application file are not valid."),
+	(0x80110411, "COMADMIN_E_NOREGISTRYCLSID", "The component's CLSID is missing or corrupt."),
+	(0x80110412, "COMADMIN_E_BADREGISTRYPROGID", "The component's progID is missing or corrupt."),
+	(0x80110413, "COMADMIN_E_AUTHENTICATIONLEVEL", "Unable to set required authentication level for update request."),
+	(0x80110414, "COMADMIN_E_USERPASSWDNOTVALID", "The identity or password set on the application is not valid."),
+	(0x80110418, "COMADMIN_E_CLSIDORIIDMISMATCH", "Application file CLSIDs or IIDs do not match corresponding DLLs."),
+	(0x80110419, "COMADMIN_E_REMOTEINTERFACE", "Interface information is either missing or changed."),
+	(0x8011041a, "COMADMIN_E_DLLREGISTERSERVER", "DllRegisterServer failed on component install."),
+	(0x8011041b, "COMADMIN_E_NOSERVERSHARE", "No server file share available."),
+	(0x8011041d, "COMADMIN_E_DLLLOADFAILED", "DLL could not be loaded."),
+	(0x8011041e, "COMADMIN_E_BADREGISTRYLIBID", "The registered TypeLib ID is not valid."),
+	(0x8011041f, "COMADMIN_E_APPDIRNOTFOUND", "Application install directory not found."),
+	(0x80110423, "COMADMIN_E_REGISTRARFAILED", "Errors occurred while in the component registrar."),
+	(0x80110424, "COMADMIN_E_COMPFILE_DOESNOTEXIST", "The file does not exist."),
+	(0x80110425, "COMADMIN_E_COMPFILE_LOADDLLFAIL", "The DLL could not be loaded."),
+	(0x80110426, "COMADMIN_E_COMPFILE_GETCLASSOBJ", "GetClassObject failed in the DLL."),
+	(0x80110427, "COMADMIN_E_COMPFILE_CLASSNOTAVAIL", "The DLL does not support the components listed in the TypeLib."),
+	(0x80110428, "COMADMIN_E_COMPFILE_BADTLB", "The TypeLib could not be loaded."),
+	(0x80110429, "COMADMIN_E_COMPFILE_NOTINSTALLABLE", "The file does not contain components or component information."),
+	(0x8011042a, "COMADMIN_E_NOTCHANGEABLE", "Changes to this object and its sub-objects have been disabled."),
+	(0x8011042b, "COMADMIN_E_NOTDELETEABLE", "The delete function has been disabled for this object."),
+	(0x8011042c, "COMADMIN_E_SESSION", "The server catalog version is not supported."),
+	(0x8011042d, "COMADMIN_E_COMP_MOVE_LOCKED", "The component move was disallowed, because the source or destination application is either a system application or currently locked against changes."),
+	(0x8011042e, "COMADMIN_E_COMP_MOVE_BAD_DEST", "The component move failed because the destination application no longer exists."),
+	(0x80110430, "COMADMIN_E_REGISTERTLB", "The system was unable to register the TypeLib."),
+	(0x80110433, "COMADMIN_E_SYSTEMAPP", "This operation cannot be performed on the system application."),
+	(0x80110434, "COMADMIN_E_COMPFILE_NOREGISTRAR", "The component registrar referenced in this file is not available."),
+	(0x80110435, "COMADMIN_E_COREQCOMPINSTALLED", "A component in the same DLL is already installed."),
+	(0x80110436, "COMADMIN_E_SERVICENOTINSTALLED", "The service is not installed."),
+	(0x80110437, "COMADMIN_E_PROPERTYSAVEFAILED", "One or more property settings are either invalid or in conflict with each other."),
+	(0x80110438, "COMADMIN_E_OBJECTEXISTS", "The object you are attempting to add or rename already exists."),
+	(0x80110439, "COMADMIN_E_COMPONENTEXISTS", "The component already exists."),
+	(0x8011043b, "COMADMIN_E_REGFILE_CORRUPT", "The registration file is corrupt."),
+	(0x8011043c, "COMADMIN_E_PROPERTY_OVERFLOW", "The property value is too large."),
+	(0x8011043e, "COMADMIN_E_NOTINREGISTRY", "Object was not found in registry."),
+	(0x8011043f, "COMADMIN_E_OBJECTNOTPOOLABLE", "This object is not poolable."),
+	(0x80110446, "COMADMIN_E_APPLID_MATCHES_CLSID", "A CLSID with the same GUID as the new application ID is already installed on this machine."),
+	(0x80110447, "COMADMIN_E_ROLE_DOES_NOT_EXIST", "A role assigned to a component, interface, or method did not exist in the application."),
+	(0x80110448, "COMADMIN_E_START_APP_NEEDS_COMPONENTS", "You must have components in an application in order to start the application."),
+	(0x80110449, "COMADMIN_E_REQUIRES_DIFFERENT_PLATFORM", "This operation is not enabled on this platform."),
+	(0x8011044a, "COMADMIN_E_CAN_NOT_EXPORT_APP_PROXY", "Application Proxy is not exportable."),
+	(0x8011044b, "COMADMIN_E_CAN_NOT_START_APP", "Failed to start application because it is either a library application or an application proxy."),
+	(0x8011044c, "COMADMIN_E_CAN_NOT_EXPORT_SYS_APP", "System application is not exportable."),
+	(0x8011044d, "COMADMIN_E_CANT_SUBSCRIBE_TO_COMPONENT", "Cannot subscribe to this component (the component may have been imported)."),
+	(0x8011044e, "COMADMIN_E_EVENTCLASS_CANT_BE_SUBSCRIBER", "An event class cannot also be a subscriber component."),
+	(0x8011044f, "COMADMIN_E_LIB_APP_PROXY_INCOMPATIBLE", "Library applications and application proxies are incompatible."),
+	(0x80110450, "COMADMIN_E_BASE_PARTITION_ONLY", "This function is valid for the base partition only."),
+	(0x80110451, "COMADMIN_E_START_APP_DISABLED", "You cannot start an application that has been disabled."),
+	(0x80110457, "COMADMIN_E_CAT_DUPLICATE_PARTITION_NAME", "The specified partition name is already in use on this computer."),
+	(0x80110458, "COMADMIN_E_CAT_INVALID_PARTITION_NAME", "The specified partition name is invalid. Check that the name contains at least one visible character."),
+	(0x80110459, "COMADMIN_E_CAT_PARTITION_IN_USE", "The partition cannot be deleted because it is the default partition for one or more users."),
+	(0x8011045a, "COMADMIN_E_FILE_PARTITION_DUPLICATE_FILES", "The partition cannot be exported, because one or more components in the partition have the same file name."),
+	(0x8011045b, "COMADMIN_E_CAT_IMPORTED_COMPONENTS_NOT_ALLOWED", "Applications that contain one or more imported components cannot be installed into a non-base partition."),
+	(0x8011045c, "COMADMIN_E_AMBIGUOUS_APPLICATION_NAME", "The application name is not unique and cannot be resolved to an application id."),
+	(0x8011045d, "COMADMIN_E_AMBIGUOUS_PARTITION_NAME", "The partition name is not unique and cannot be resolved to a partition id."),
+	(0x80110472, "COMADMIN_E_REGDB_NOTINITIALIZED", "The COM+ registry database has not been initialized."),
+	(0x80110473, "COMADMIN_E_REGDB_NOTOPEN", "The COM+ registry database is not open."),
+	(0x80110474, "COMADMIN_E_REGDB_SYSTEMERR", "The COM+ registry database detected a system error."),
+	(0x80110475, "COMADMIN_E_REGDB_ALREADYRUNNING", "The COM+ registry database is already running."),
+	(0x80110480, "COMADMIN_E_MIG_VERSIONNOTSUPPORTED", "This version of the COM+ registry database cannot be migrated."),
+	(0x80110481, "COMADMIN_E_MIG_SCHEMANOTFOUND", "The schema version to be migrated could not be found in the COM+ registry database."),
+	(0x80110482, "COMADMIN_E_CAT_BITNESSMISMATCH", "There was a type mismatch between binaries."),
+	(0x80110483, "COMADMIN_E_CAT_UNACCEPTABLEBITNESS", "A binary of unknown or invalid type was provided."),
+	(0x80110484, "COMADMIN_E_CAT_WRONGAPPBITNESS", "There was a type mismatch between a binary and an application."),
+	(0x80110485, "COMADMIN_E_CAT_PAUSE_RESUME_NOT_SUPPORTED", "The application cannot be paused or resumed."),
+	(0x80110486, "COMADMIN_E_CAT_SERVERFAULT", "The COM+ Catalog Server threw an exception during execution."),
+	(0x80110600, "COMQC_E_APPLICATION_NOT_QUEUED", "Only COM+ Applications marked \"queued\" can be invoked using the \"queue\" moniker."),
+	(0x80110601, "COMQC_E_NO_QUEUEABLE_INTERFACES", "At least one interface must be marked \"queued\" in order to create a queued component instance with the \"queue\" moniker."),
+	(0x80110602, "COMQC_E_QUEUING_SERVICE_NOT_AVAILABLE", "MSMQ is required for the requested operation and is not installed."),
+	(0x80110603, "COMQC_E_NO_IPERSISTSTREAM", "Unable to marshal an interface that does not support IPersistStream."),
+	(0x80110604, "COMQC_E_BAD_MESSAGE", "The message is improperly formatted or was damaged in transit."),
+	(0x80110605, "COMQC_E_UNAUTHENTICATED", "An unauthenticated message was received by an application that accepts only authenticated messages."),
+	(0x80110606, "COMQC_E_UNTRUSTED_ENQUEUER", "The message was requeued or moved by a user not in the \"QC Trusted User\" role."),
+	(0x80110701, "MSDTC_E_DUPLICATE_RESOURCE", "The resource manager ID already exists on this machine."),
+	(0x80110808, "COMADMIN_E_OBJECT_PARENT_MISSING", "One of the objects being inserted or updated does not belong to a valid parent collection."),
+	(0x80110809, "COMADMIN_E_OBJECT_DOES_NOT_EXIST", "One of the specified objects cannot be found."),
+	(0x8011080a, "COMADMIN_E_APP_NOT_RUNNING", "The specified application is not currently running."),
+	(0x8011080b, "COMADMIN_E_INVALID_PARTITION", "The partition(s) specified are not valid."),
+	(0x8011080d, "COMADMIN_E_SVCAPP_NOT_POOLABLE_OR_RECYCLABLE", "COM+ applications that run as NT service may not be pooled or recycled."),
+	(0x8011080e, "COMADMIN_E_USER_IN_SET", "One or more users are already assigned to a local partition set."),
+	(0x8011080f, "COMADMIN_E_CANTRECYCLELIBRARYAPPS", "Library applications may not be recycled."),
+	(0x80110811, "COMADMIN_E_CANTRECYCLESERVICEAPPS", "Applications running as NT services may not be recycled."),
+	(0x80110812, "COMADMIN_E_PROCESSALREADYRECYCLED", "The process has already been recycled."),
+	(0x80110813, "COMADMIN_E_PAUSEDPROCESSMAYNOTBERECYCLED", "A paused process may not be recycled."),
+	(0x80110814, "COMADMIN_E_CANTMAKEINPROCSERVICE", "Library applications may not be NT services."),
+	(0x80110815, "COMADMIN_E_PROGIDINUSEBYCLSID", "The ProgID provided to the copy operation is invalid. The ProgID is in use by another registered CLSID."),
+	(0x80110816, "COMADMIN_E_DEFAULT_PARTITION_NOT_IN_SET", "The partition specified as default is not a member of the partition set."),
+	(0x80110817, "COMADMIN_E_RECYCLEDPROCESSMAYNOTBEPAUSED", "A recycled process may not be paused."),
+	(0x80110818, "COMADMIN_E_PARTITION_ACCESSDENIED", "Access to the specified partition is denied."),
+	(0x80110819, "COMADMIN_E_PARTITION_MSI_ONLY", "Only Application Files (*.MSI files) can be installed into partitions."),
+	(0x8011081a, "COMADMIN_E_LEGACYCOMPS_NOT_ALLOWED_IN_1_0_FORMAT", "Applications containing one or more legacy components may not be exported to 1.0 format."),
+	(0x8011081b, "COMADMIN_E_LEGACYCOMPS_NOT_ALLOWED_IN_NONBASE_PARTITIONS", "Legacy components may not exist in non-base partitions."),
+	(0x8011081c, "COMADMIN_E_COMP_MOVE_SOURCE", "A component cannot be moved (or copied) from the System Application, an application proxy or a non-changeable application."),
+	(0x8011081d, "COMADMIN_E_COMP_MOVE_DEST", "A component cannot be moved (or copied) to the System Application, an application proxy or a non-changeable application."),
+	(0x8011081e, "COMADMIN_E_COMP_MOVE_PRIVATE", "A private component cannot be moved (or copied) to a library application or to the base partition."),
+	(0x8011081f, "COMADMIN_E_BASEPARTITION_REQUIRED_IN_SET", "The Base Application Partition exists in all partition sets and cannot be removed."),
+	(0x80110820, "COMADMIN_E_CANNOT_ALIAS_EVENTCLASS", "Alas, Event Class components cannot be aliased."),
+	(0x80110821, "COMADMIN_E_PRIVATE_ACCESSDENIED", "Access is denied because the component is private."),
+	(0x80110822, "COMADMIN_E_SAFERINVALID", "The specified SAFER level is invalid."),
+	(0x80110823, "COMADMIN_E_REGISTRY_ACCESSDENIED", "The specified user cannot write to the system registry."),
+	(0x80110824, "COMADMIN_E_PARTITIONS_DISABLED", "COM+ partitions are currently disabled."),
+	(0x80190001, "HTTP_E_STATUS_UNEXPECTED", "Unexpected HTTP status code."),
+	(0x80190003, "HTTP_E_STATUS_UNEXPECTED_REDIRECTION", "Unexpected redirection status code (3xx)."),
+	(0x80190004, "HTTP_E_STATUS_UNEXPECTED_CLIENT_ERROR", "Unexpected client error status code (4xx)."),
+	(0x80190005, "HTTP_E_STATUS_UNEXPECTED_SERVER_ERROR", "Unexpected server error status code (5xx)."),
+	(0x8019012c, "HTTP_E_STATUS_AMBIGUOUS", "Multiple choices (300)."),
+	(0x8019012d, "HTTP_E_STATUS_MOVED", "Moved permanently (301)."),
+	(0x8019012e, "HTTP_E_STATUS_REDIRECT", "Found (302)."),
+	(0x8019012f, "HTTP_E_STATUS_REDIRECT_METHOD", "See Other (303)."),
+	(0x80190130, "HTTP_E_STATUS_NOT_MODIFIED", "Not modified (304)."),
+	(0x80190131, "HTTP_E_STATUS_USE_PROXY", "Use proxy (305)."),
+	(0x80190133, "HTTP_E_STATUS_REDIRECT_KEEP_VERB", "Temporary redirect (307)."),
+	(0x80190190, "HTTP_E_STATUS_BAD_REQUEST", "Bad request (400)."),
+	(0x80190191, "HTTP_E_STATUS_DENIED", "Unauthorized (401)."),
+	(0x80190192, "HTTP_E_STATUS_PAYMENT_REQ", "Payment required (402)."),
+	(0x80190193, "HTTP_E_STATUS_FORBIDDEN", "Forbidden (403)."),
+	(0x80190194, "HTTP_E_STATUS_NOT_FOUND", "Not found (404)."),
+	(0x80190195, "HTTP_E_STATUS_BAD_METHOD", "Method not allowed (405)."),
+	(0x80190196, "HTTP_E_STATUS_NONE_ACCEPTABLE", "Not acceptable (406)."),
+	(0x80190197, "HTTP_E_STATUS_PROXY_AUTH_REQ", "Proxy authentication required (407)."),
+	(0x80190198, "HTTP_E_STATUS_REQUEST_TIMEOUT", "Request timeout (408)."),
+	(0x80190199, "HTTP_E_STATUS_CONFLICT", "Conflict (409)."),
+	(0x8019019a, "HTTP_E_STATUS_GONE", "Gone (410)."),
+	(0x8019019b, "HTTP_E_STATUS_LENGTH_REQUIRED", "Length required (411)."),
+	(0x8019019c, "HTTP_E_STATUS_PRECOND_FAILED", "Precondition failed (412)."),
+	(0x8019019d, "HTTP_E_STATUS_REQUEST_TOO_LARGE", "Request entity too large (413)."),
+	(0x8019019e, "HTTP_E_STATUS_URI_TOO_LONG", "Request-URI too long (414)."),
+	(0x8019019f, "HTTP_E_STATUS_UNSUPPORTED_MEDIA", "Unsupported media type (415)."),
+	(0x801901a0, "HTTP_E_STATUS_RANGE_NOT_SATISFIABLE", "Requested range not satisfiable (416)."),
+	(0x801901a1, "HTTP_E_STATUS_EXPECTATION_FAILED", "Expectation failed (417)."),
+	(0x801901f4, "HTTP_E_STATUS_SERVER_ERROR", "Internal server error (500)."),
+	(0x801901f5, "HTTP_E_STATUS_NOT_SUPPORTED", "Not implemented (501)."),
+	(0x801901f6, "HTTP_E_STATUS_BAD_GATEWAY", "Bad gateway (502)."),
+	(0x801901f7, "HTTP_E_STATUS_SERVICE_UNAVAIL", "Service unavailable (503)."),
+	(0x801901f8, "HTTP_E_STATUS_GATEWAY_TIMEOUT", "Gateway timeout (504)."),
+	(0x801901f9, "HTTP_E_STATUS_VERSION_NOT_SUP", "Version not supported (505)."),
+	(0x801b8000, "WER_E_CRASH_FAILURE", "Crash reporting failed."),
+	(0x801b8001, "WER_E_CANCELED", "Report aborted due to user cancellation."),
+	(0x801b8002, "WER_E_NETWORK_FAILURE", "Report aborted due to network failure."),
+	(0x801b8003, "WER_E_NOT_INITIALIZED", "Report not initialized."),
+	(0x801b8004, "WER_E_ALREADY_REPORTING", "Reporting is already in progress for the specified process."),
+	(0x801b8005, "WER_E_DUMP_THROTTLED", "Dump not generated due to a throttle."),
+	(0x801b8006, "WER_E_INSUFFICIENT_CONSENT", "Operation failed due to insufficient user consent."),
+	(0x801b8007, "WER_E_TOO_HEAVY", "Report aborted due to performance criteria."),
+	(0x801f0001, "ERROR_FLT_NO_HANDLER_DEFINED", "A handler was not defined by the filter for this operation."),
+	(0x801f0002, "ERROR_FLT_CONTEXT_ALREADY_DEFINED", "A context is already defined for this object."),
+	(0x801f0003, "ERROR_FLT_INVALID_ASYNCHRONOUS_REQUEST", "Asynchronous requests are not valid for this operation."),
+	(0x801f0004, "ERROR_FLT_DISALLOW_FAST_IO", "Disallow the Fast IO path for this operation."),
+	(0x801f0005, "ERROR_FLT_INVALID_NAME_REQUEST", "An invalid name request was made. The name requested cannot be retrieved at this time."),
+	(0x801f0006, "ERROR_FLT_NOT_SAFE_TO_POST_OPERATION", "Posting this operation to a worker thread for further processing is not safe at this time because it could lead to a system deadlock."),
+	(0x801f0007, "ERROR_FLT_NOT_INITIALIZED", "The Filter Manager was not initialized when a filter tried to register. Make sure that the Filter Manager is getting loaded as a driver."),
+	(0x801f0008, "ERROR_FLT_FILTER_NOT_READY", "The filter is not ready for attachment to volumes because it has not finished initializing (FltStartFiltering has not been called)."),
+	(0x801f0009, "ERROR_FLT_POST_OPERATION_CLEANUP", "The filter must cleanup any operation specific context at this time because it is being removed from the system before the operation is completed by the lower drivers."),
+	(0x801f000a, "ERROR_FLT_INTERNAL_ERROR", "The Filter Manager had an internal error from which it cannot recover, therefore the operation has been failed. This is usually the result of a filter returning an invalid value from a pre-operation callback."),
+	(0x801f000b, "ERROR_FLT_DELETING_OBJECT", "The object specified for this action is in the process of being deleted, therefore the action requested cannot be completed at this time."),
+	(0x801f000c, "ERROR_FLT_MUST_BE_NONPAGED_POOL", "Non-paged pool must be used for this type of context."),
+	(0x801f000d, "ERROR_FLT_DUPLICATE_ENTRY", "A duplicate handler definition has been provided for an operation."),
+	(0x801f000e, "ERROR_FLT_CBDQ_DISABLED", "The callback data queue has been disabled."),
+	(0x801f000f, "ERROR_FLT_DO_NOT_ATTACH", "Do not attach the filter to the volume at this time."),
+	(0x801f0010, "ERROR_FLT_DO_NOT_DETACH", "Do not detach the filter from the volume at this time."),
+	(0x801f0011, "ERROR_FLT_INSTANCE_ALTITUDE_COLLISION", "An instance already exists at this altitude on the volume specified."),
+	(0x801f0012, "ERROR_FLT_INSTANCE_NAME_COLLISION", "An instance already exists with this name on the volume specified."),
+	(0x801f0013, "ERROR_FLT_FILTER_NOT_FOUND", "The system could not find the filter specified."),
+	(0x801f0014, "ERROR_FLT_VOLUME_NOT_FOUND", "The system could not find the volume specified."),
+	(0x801f0015, "ERROR_FLT_INSTANCE_NOT_FOUND", "The system could not find the instance specified."),
+	(0x801f0016, "ERROR_FLT_CONTEXT_ALLOCATION_NOT_FOUND", "No registered context allocation definition was found for the given request."),
+	(0x801f0017, "ERROR_FLT_INVALID_CONTEXT_REGISTRATION", "An invalid parameter was specified during context registration."),
+	(0x801f0018, "ERROR_FLT_NAME_CACHE_MISS", "The name requested was not found in Filter Manager's name cache and could not be retrieved from the file system."),
+	(0x801f0019, "ERROR_FLT_NO_DEVICE_OBJECT", "The requested device object does not exist for the given volume."),
+	(0x801f001a, "ERROR_FLT_VOLUME_ALREADY_MOUNTED", "The specified volume is already mounted."),
+	(0x801f001b, "ERROR_FLT_ALREADY_ENLISTED", "The specified Transaction Context is already enlisted in a transaction."),
+	(0x801f001c, "ERROR_FLT_CONTEXT_ALREADY_LINKED", "The specified context is already attached to another object."),
+	(0x801f0020, "ERROR_FLT_NO_WAITER_FOR_REPLY", "No waiter is present for the filter's reply to this message."),
+	(0x801f0023, "ERROR_FLT_REGISTRATION_BUSY", "The filesystem database resource is in use. Registration cannot complete at this time."),
+	(0x80260001, "ERROR_HUNG_DISPLAY_DRIVER_THREAD", "%hs. Display driver %hs stopped responding and has been restarted."),
+	(0x80263001, "DWM_E_COMPOSITIONDISABLED", "Desktop composition is disabled."),
+	(0x80263002, "DWM_E_REMOTING_NOT_SUPPORTED", "Some desktop composition APIs are not supported while remoting."),
+	(0x80263003, "DWM_E_NO_REDIRECTION_SURFACE_AVAILABLE", "There is no DWM redirection surface available."),
+	(0x80263004, "DWM_E_NOT_QUEUING_PRESENTS", "DWM is not queuing presents for the specified window."),
+	(0x80263005, "DWM_E_ADAPTER_NOT_FOUND", "The adapter specified by the LUID is not found."),
+	(0x80263007, "DWM_E_TEXTURE_TOO_LARGE", "GDI redirection surface is either on a different adapter or in system memory. Perform redirection via GDI."),
+	(0x80270001, "NAP_E_INVALID_PACKET", "The NAP SoH packet is invalid."),
+	(0x80270002, "NAP_E_MISSING_SOH", "An SoH was missing from the NAP packet."),
+	(0x80270003, "NAP_E_CONFLICTING_ID", "The entity ID conflicts with an already registered id."),
+	(0x80270004, "NAP_E_NO_CACHED_SOH", "No cached SoH is present."),
+	(0x80270005, "NAP_E_STILL_BOUND", "The entity is still bound to the NAP system."),
+	(0x80270006, "NAP_E_NOT_REGISTERED", "The entity is not registered with the NAP system."),
+	(0x80270007, "NAP_E_NOT_INITIALIZED", "The entity is not initialized with the NAP system."),
+	(0x80270008, "NAP_E_MISMATCHED_ID", "The correlation id in the SoH-Request and SoH-Response do not match up."),
+	(0x80270009, "NAP_E_NOT_PENDING", "Completion was indicated on a request that is not currently pending."),
+	(0x8027000a, "NAP_E_ID_NOT_FOUND", "The NAP component's id was not found."),
+	(0x8027000b, "NAP_E_MAXSIZE_TOO_SMALL", "The maximum size of the connection is too small for an SoH packet."),
+	(0x8027000c, "NAP_E_SERVICE_NOT_RUNNING", "The NapAgent service is not running."),
+	(0x8027000e, "NAP_E_ENTITY_DISABLED", "The entity is disabled with the NapAgent service and will not be serviced."),
+	(0x8027000f, "NAP_E_NETSH_GROUPPOLICY_ERROR", "Group Policy is not configured."),
+	(0x80270010, "NAP_E_TOO_MANY_CALLS", "Too many simultaneous calls."),
+	(0x80270011, "NAP_E_SHV_CONFIG_EXISTED", "SHV configuration already existed."),
+	(0x80270012, "NAP_E_SHV_CONFIG_NOT_FOUND", "SHV configuration is not found."),
+	(0x80270013, "NAP_E_SHV_TIMEOUT", "SHV timed out on the request."),
+	(0x80270220, "FA_E_MAX_PERSISTED_ITEMS_REACHED", "The maximum number of items for the access list has been reached. An item must be removed before another item is added."),
+	(0x80270222, "FA_E_HOMEGROUP_NOT_AVAILABLE", "Cannot access a file in the Homegroup because the Homegroup is not available."),
+	(0x80270250, "E_MONITOR_RESOLUTION_TOO_LOW", "This app can't start because the screen resolution is below 1024x768. Choose a higher screen resolution and then try again."),
+	(0x80270251, "E_ELEVATED_ACTIVATION_NOT_SUPPORTED", "This app can't be activated from an elevated context."),
+	(0x80270252, "E_UAC_DISABLED", "This app can't be activated when UAC is disabled."),
+	(0x80270253, "E_FULL_ADMIN_NOT_SUPPORTED", "This app can't be activated by the Built-in Administrator."),
+	(0x80270254, "E_APPLICATION_NOT_REGISTERED", "This app does not support the contract specified or is not installed."),
+	(0x80270255, "E_MULTIPLE_EXTENSIONS_FOR_APPLICATION", "This app has mulitple extensions registered to support the specified contract. Activation by AppUserModelId is ambiguous."),
+	(0x80270256, "E_MULTIPLE_PACKAGES_FOR_FAMILY", "This app's package family has more than one package installed. This is not supported."),
+	(0x80270257, "E_APPLICATION_MANAGER_NOT_RUNNING", "The app manager is required to activate applications, but is not running."),
+	(0x8027025a, "E_APPLICATION_ACTIVATION_TIMED_OUT", "The app didn't start in the required time."),
+	(0x8027025b, "E_APPLICATION_ACTIVATION_EXEC_FAILURE", "The app didn't start."),
+	(0x8027025c, "E_APPLICATION_TEMPORARY_LICENSE_ERROR", "This app failed to launch because of an issue with its license. Please try again in a moment."),
+	(0x8027025d, "E_APPLICATION_TRIAL_LICENSE_EXPIRED", "This app failed to launch because its trial license has expired."),
+	(0x80270260, "E_SKYDRIVE_ROOT_TARGET_FILE_SYSTEM_NOT_SUPPORTED", "Please choose a folder on a drive that's formatted with the NTFS file system."),
+	(0x80270261, "E_SKYDRIVE_ROOT_TARGET_OVERLAP", "This location is already being used. Please choose a different location."),
+	(0x80270262, "E_SKYDRIVE_ROOT_TARGET_CANNOT_INDEX", "This location cannot be indexed. Please choose a different location."),
+	(0x80270263, "E_SKYDRIVE_FILE_NOT_UPLOADED", "Sorry, the action couldn't be completed because the file hasn't finished uploading. Try again later."),
+	(0x80270264, "E_SKYDRIVE_UPDATE_AVAILABILITY_FAIL", "Sorry, the action couldn't be completed."),
+	(0x80270265, "E_SKYDRIVE_ROOT_TARGET_VOLUME_ROOT_NOT_SUPPORTED", "This content can only be moved to a folder. To move the content to this drive, please choose or create a folder."),
+	(0x80280000, "TPM_E_ERROR_MASK", "This is an error mask to convert TPM hardware errors to win errors."),
+	(0x80280001, "TPM_E_AUTHFAIL", "Authentication failed."),
+	(0x80280002, "TPM_E_BADINDEX", "The index to a PCR, DIR or other register is incorrect."),
+	(0x80280003, "TPM_E_BAD_PARAMETER", "One or more parameter is bad."),
+	(0x80280004, "TPM_E_AUDITFAILURE", "An operation completed successfully but the auditing of that operation failed."),
+	(0x80280005, "TPM_E_CLEAR_DISABLED", "The clear disable flag is set and all clear operations now require physical access."),
+	(0x80280006, "TPM_E_DEACTIVATED", "Activate the Trusted Platform Module (TPM)."),
+	(0x80280007, "TPM_E_DISABLED", "Enable the Trusted Platform Module (TPM)."),
+	(0x80280008, "TPM_E_DISABLED_CMD", "The target command has been disabled."),
+	(0x80280009, "TPM_E_FAIL", "The operation failed."),
+	(0x8028000a, "TPM_E_BAD_ORDINAL", "The ordinal was unknown or inconsistent."),
+	(0x8028000b, "TPM_E_INSTALL_DISABLED", "The ability to install an owner is disabled."),
+	(0x8028000c, "TPM_E_INVALID_KEYHANDLE", "The key handle cannot be interpreted."),
+	(0x8028000d, "TPM_E_KEYNOTFOUND", "The key handle points to an invalid key."),
+	(0x8028000e, "TPM_E_INAPPROPRIATE_ENC", "Unacceptable encryption scheme."),
+	(0x8028000f, "TPM_E_MIGRATEFAIL", "Migration authorization failed."),
+	(0x80280010, "TPM_E_INVALID_PCR_INFO", "PCR information could not be interpreted."),
+	(0x80280011, "TPM_E_NOSPACE", "No room to load key."),
+	(0x80280012, "TPM_E_NOSRK", "There is no Storage Root Key (SRK) set."),
+	(0x80280013, "TPM_E_NOTSEALED_BLOB", "An encrypted blob is invalid or was not created by this TPM."),
+	(0x80280014, "TPM_E_OWNER_SET", "The Trusted Platform Module (TPM) already has an owner."),
+	(0x80280015, "TPM_E_RESOURCES", "The TPM has insufficient internal resources to perform the requested action."),
+	(0x80280016, "TPM_E_SHORTRANDOM", "A random string was too short."),
+	(0x80280017, "TPM_E_SIZE", "The TPM does not have the space to perform the operation."),
+	(0x80280018, "TPM_E_WRONGPCRVAL", "The named PCR value does not match the current PCR value."),
+	(0x80280019, "TPM_E_BAD_PARAM_SIZE", "The paramSize argument to the command has the incorrect value."),
+	(0x8028001a, "TPM_E_SHA_THREAD", "There is no existing SHA-1 thread."),
+	(0x8028001b, "TPM_E_SHA_ERROR", "The calculation is unable to proceed because the existing SHA-1 thread has already encountered an error."),
+	(0x8028001c, "TPM_E_FAILEDSELFTEST", "The TPM hardware device reported a failure during its internal self test. Try restarting the computer to resolve the problem. If the problem continues, check for the latest BIOS or firmware update for your TPM hardware. Consult the computer manufacturer's documentation for instructions."),
+	(0x8028001d, "TPM_E_AUTH2FAIL", "The authorization for the second key in a 2 key function failed authorization."),
+	(0x8028001e, "TPM_E_BADTAG", "The tag value sent to for a command is invalid."),
+	(0x8028001f, "TPM_E_IOERROR", "An IO error occurred transmitting information to the TPM."),
+	(0x80280020, "TPM_E_ENCRYPT_ERROR", "The encryption process had a problem."),
+	(0x80280021, "TPM_E_DECRYPT_ERROR", "The decryption process did not complete."),
+	(0x80280022, "TPM_E_INVALID_AUTHHANDLE", "An invalid handle was used."),
+	(0x80280023, "TPM_E_NO_ENDORSEMENT", "The TPM does not have an Endorsement Key (EK) installed."),
+	(0x80280024, "TPM_E_INVALID_KEYUSAGE", "The usage of a key is not allowed."),
+	(0x80280025, "TPM_E_WRONG_ENTITYTYPE", "The submitted entity type is not allowed."),
+	(0x80280026, "TPM_E_INVALID_POSTINIT", "The command was received in the wrong sequence relative to TPM_Init and a subsequent TPM_Startup."),
+	(0x80280027, "TPM_E_INAPPROPRIATE_SIG", "Signed data cannot include additional DER information."),
+	(0x80280028, "TPM_E_BAD_KEY_PROPERTY", "The key properties in TPM_KEY_PARMs are not supported by this TPM."),
+	(0x80280029, "TPM_E_BAD_MIGRATION", "The migration properties of this key are incorrect."),
+	(0x8028002a, "TPM_E_BAD_SCHEME", "The signature or encryption scheme for this key is incorrect or not permitted in this situation."),
+	(0x8028002b, "TPM_E_BAD_DATASIZE", "The size of the data (or blob) parameter is bad or inconsistent with the referenced key."),
+	(0x8028002c, "TPM_E_BAD_MODE", "A mode parameter is bad, such as capArea or subCapArea for TPM_GetCapability, physicalPresence parameter for TPM_PhysicalPresence, or migrationType for TPM_CreateMigrationBlob."),
+	(0x8028002d, "TPM_E_BAD_PRESENCE", "Either the physicalPresence or physicalPresenceLock bits have the wrong value."),
+	(0x8028002e, "TPM_E_BAD_VERSION", "The TPM cannot perform this version of the capability."),
+	(0x8028002f, "TPM_E_NO_WRAP_TRANSPORT", "The TPM does not allow for wrapped transport sessions."),
+	(0x80280030, "TPM_E_AUDITFAIL_UNSUCCESSFUL", "TPM audit construction failed and the underlying command was returning a failure code also."),
+	(0x80280031, "TPM_E_AUDITFAIL_SUCCESSFUL", "TPM audit construction failed and the underlying command was returning success."),
+	(0x80280032, "TPM_E_NOTRESETABLE", "Attempt to reset a PCR register that does not have the resettable attribute."),
+	(0x80280033, "TPM_E_NOTLOCAL", "Attempt to reset a PCR register that requires locality and locality modifier not part of command transport."),
+	(0x80280034, "TPM_E_BAD_TYPE", "Make identity blob not properly typed."),
+	(0x80280035, "TPM_E_INVALID_RESOURCE", "When saving context identified resource type does not match actual resource."),
+	(0x80280036, "TPM_E_NOTFIPS", "The TPM is attempting to execute a command only available when in FIPS mode."),
+	(0x80280037, "TPM_E_INVALID_FAMILY", "The command is attempting to use an invalid family ID."),
+	(0x80280038, "TPM_E_NO_NV_PERMISSION", "The permission to manipulate the NV storage is not available."),
+	(0x80280039, "TPM_E_REQUIRES_SIGN", "The operation requires a signed command."),
+	(0x8028003a, "TPM_E_KEY_NOTSUPPORTED", "Wrong operation to load an NV key."),
+	(0x8028003b, "TPM_E_AUTH_CONFLICT", "NV_LoadKey blob requires both owner and blob authorization."),
+	(0x8028003c, "TPM_E_AREA_LOCKED", "The NV area is locked and not writtable."),
+	(0x8028003d, "TPM_E_BAD_LOCALITY", "The locality is incorrect for the attempted operation."),
+	(0x8028003e, "TPM_E_READ_ONLY", "The NV area is read only and can't be written to."),
+	(0x8028003f, "TPM_E_PER_NOWRITE", "There is no protection on the write to the NV area."),
+	(0x80280040, "TPM_E_FAMILYCOUNT", "The family count value does not match."),
+	(0x80280041, "TPM_E_WRITE_LOCKED", "The NV area has already been written to."),
+	(0x80280042, "TPM_E_BAD_ATTRIBUTES", "The NV area attributes conflict."),
+	(0x80280043, "TPM_E_INVALID_STRUCTURE", "The structure tag and version are invalid or inconsistent."),
+	(0x80280044, "TPM_E_KEY_OWNER_CONTROL", "The key is under control of the TPM Owner and can only be evicted by the TPM Owner."),
+	(0x80280045, "TPM_E_BAD_COUNTER", "The counter handle is incorrect."),
+	(0x80280046, "TPM_E_NOT_FULLWRITE", "The write is not a complete write of the area."),
+	(0x80280047, "TPM_E_CONTEXT_GAP", "The gap between saved context counts is too large."),
+	(0x80280048, "TPM_E_MAXNVWRITES", "The maximum number of NV writes without an owner has been exceeded."),
+	(0x80280049, "TPM_E_NOOPERATOR", "No operator AuthData value is set."),
+	(0x8028004a, "TPM_E_RESOURCEMISSING", "The resource pointed to by context is not loaded."),
+	(0x8028004b, "TPM_E_DELEGATE_LOCK", "The delegate administration is locked."),
+	(0x8028004c, "TPM_E_DELEGATE_FAMILY", "Attempt to manage a family other than the delegated family."),
+	(0x8028004d, "TPM_E_DELEGATE_ADMIN", "Delegation table management not enabled."),
+	(0x8028004e, "TPM_E_TRANSPORT_NOTEXCLUSIVE", "There was a command executed outside of an exclusive transport session."),
+	(0x8028004f, "TPM_E_OWNER_CONTROL", "Attempt to context save an owner evict controlled key."),
+	(0x80280050, "TPM_E_DAA_RESOURCES", "The DAA command has no resources available to execute the command."),
+	(0x80280051, "TPM_E_DAA_INPUT_DATA0", "The consistency check on DAA parameter inputData0 has failed."),
+	(0x80280052, "TPM_E_DAA_INPUT_DATA1", "The consistency check on DAA parameter inputData1 has failed."),
+	(0x80280053, "TPM_E_DAA_ISSUER_SETTINGS", "The consistency check on DAA_issuerSettings has failed."),
+	(0x80280054, "TPM_E_DAA_TPM_SETTINGS", "The consistency check on DAA_tpmSpecific has failed."),
+	(0x80280055, "TPM_E_DAA_STAGE", "The atomic process indicated by the submitted DAA command is not the expected process."),
+	(0x80280056, "TPM_E_DAA_ISSUER_VALIDITY", "The issuer's validity check has detected an inconsistency."),
+	(0x80280057, "TPM_E_DAA_WRONG_W", "The consistency check on w has failed."),
+	(0x80280058, "TPM_E_BAD_HANDLE", "The handle is incorrect."),
+	(0x80280059, "TPM_E_BAD_DELEGATE", "Delegation is not correct."),
+	(0x8028005a, "TPM_E_BADCONTEXT", "The context blob is invalid."),
+	(0x8028005b, "TPM_E_TOOMANYCONTEXTS", "Too many contexts held by the TPM."),
+	(0x8028005c, "TPM_E_MA_TICKET_SIGNATURE", "Migration authority signature validation failure."),
+	(0x8028005d, "TPM_E_MA_DESTINATION", "Migration destination not authenticated."),
+	(0x8028005e, "TPM_E_MA_SOURCE", "Migration source incorrect."),
+	(0x8028005f, "TPM_E_MA_AUTHORITY", "Incorrect migration authority."),
+	(0x80280061, "TPM_E_PERMANENTEK", "Attempt to revoke the EK and the EK is not revocable."),
+	(0x80280062, "TPM_E_BAD_SIGNATURE", "Bad signature of CMK ticket."),
+	(0x80280063, "TPM_E_NOCONTEXTSPACE", "There is no room in the context list for additional contexts."),
+	(0x80280081, "TPM_20_E_ASYMMETRIC", "Asymmetric algorithm not supported or not correct."),
+	(0x80280082, "TPM_20_E_ATTRIBUTES", "Inconsistent attributes."),
+	(0x80280083, "TPM_20_E_HASH", "Hash algorithm not supported or not appropriate."),
+	(0x80280084, "TPM_20_E_VALUE", "Value is out of range or is not correct for the context."),
+	(0x80280085, "TPM_20_E_HIERARCHY", "Hierarchy is not enabled or is not correct for the use."),
+	(0x80280087, "TPM_20_E_KEY_SIZE", "Key size is not supported."),
+	(0x80280088, "TPM_20_E_MGF", "Mask generation function not supported."),
+	(0x80280089, "TPM_20_E_MODE", "Mode of operation not supported."),
+	(0x8028008a, "TPM_20_E_TYPE", "The type of the value is not appropriate for the use."),
+	(0x8028008b, "TPM_20_E_HANDLE", "The handle is not correct for the use."),
+	(0x8028008c, "TPM_20_E_KDF", "Unsupported key derivation function or function not appropriate for use."),
+	(0x8028008d, "TPM_20_E_RANGE", "Value was out of allowed range."),
+	(0x8028008e, "TPM_20_E_AUTH_FAIL", "The authorization HMAC check failed and DA counter incremented."),
+	(0x8028008f, "TPM_20_E_NONCE", "Invalid nonce size."),
+	(0x80280090, "TPM_20_E_PP", "Authorization requires assertion of PP."),
+	(0x80280092, "TPM_20_E_SCHEME", "Unsupported or incompatible scheme."),
+	(0x80280095, "TPM_20_E_SIZE", "Structure is wrong size."),
+	(0x80280096, "TPM_20_E_SYMMETRIC", "Unsupported symmetric algorithm or key size, or not appropriate for instance."),
+	(0x80280097, "TPM_20_E_TAG", "Incorrect structure tag."),
+	(0x80280098, "TPM_20_E_SELECTOR", "Union selector is incorrect."),
+	(0x8028009a, "TPM_20_E_INSUFFICIENT", "The TPM was unable to unmarshal a value because there were not enough octets in the input buffer."),
+	(0x8028009b, "TPM_20_E_SIGNATURE", "The signature is not valid."),
+	(0x8028009c, "TPM_20_E_KEY", "Key fields are not compatible with the selected use."),
+	(0x8028009d, "TPM_20_E_POLICY_FAIL", "A policy check failed."),
+	(0x8028009f, "TPM_20_E_INTEGRITY", "Integrity check failed."),
+	(0x802800a0, "TPM_20_E_TICKET", "Invalid ticket."),
+	(0x802800a1, "TPM_20_E_RESERVED_BITS", "Reserved bits not set to zero as required."),
+	(0x802800a2, "TPM_20_E_BAD_AUTH", "Authorization failure without DA implications."),
+	(0x802800a3, "TPM_20_E_EXPIRED", "The policy has expired."),
+	(0x802800a4, "TPM_20_E_POLICY_CC", "The command code in the policy is not the command code of the command or the command code in a policy command references a command that is not implemented."),
+	(0x802800a5, "TPM_20_E_BINDING", "Public and sensitive portions of an object are not cryptographically bound."),
+	(0x802800a6, "TPM_20_E_CURVE", "Curve not supported."),
+	(0x802800a7, "TPM_20_E_ECC_POINT", "Point is not on the required curve."),
+	(0x80280100, "TPM_20_E_INITIALIZE", "TPM not initialized."),
+	(0x80280101, "TPM_20_E_FAILURE", "Commands not being accepted because of a TPM failure."),
+	(0x80280103, "TPM_20_E_SEQUENCE", "Improper use of a sequence handle."),
+	(0x8028010b, "TPM_20_E_PRIVATE", "Sensitive area of an object was not properly loaded."),
+	(0x80280119, "TPM_20_E_HMAC", "HMAC check failed."),
+	(0x80280120, "TPM_20_E_DISABLED", "The command is disabled."),
+	(0x80280121, "TPM_20_E_EXCLUSIVE", "Command failed because audit sequence required exclusivity."),
+	(0x80280123, "TPM_20_E_ECC_CURVE", "Unsupported ECC curve."),
+	(0x80280124, "TPM_20_E_AUTH_TYPE", "Authorization handle is not correct for command."),
+	(0x80280125, "TPM_20_E_AUTH_MISSING", "Command requires an authorization session for handle and is not present."),
+	(0x80280126, "TPM_20_E_POLICY", "Policy failure in Math Operation or an invalid authPolicy value."),
+	(0x80280127, "TPM_20_E_PCR", "PCR check fail."),
+	(0x80280128, "TPM_20_E_PCR_CHANGED", "PCR have changed since checked."),
+	(0x8028012d, "TPM_20_E_UPGRADE", "The TPM is not in the right mode for upgrade."),
+	(0x8028012e, "TPM_20_E_TOO_MANY_CONTEXTS", "Context ID counter is at maximum."),
+	(0x8028012f, "TPM_20_E_AUTH_UNAVAILABLE", "authValue or authPolicy is not available for selected entity."),
+	(0x80280130, "TPM_20_E_REBOOT", "A _TPM_Init and Startup(CLEAR) is required before the TPM can resume operation."),
+	(0x80280131, "TPM_20_E_UNBALANCED", "The protection algorithms (hash and symmetric) are not reasonably balanced. The digest size of the hash must be larger than the key size of the symmetric algorithm."),
+	(0x80280142, "TPM_20_E_COMMAND_SIZE", "The TPM command's commandSize value is not consistent with contents of the command buffer; either the size is not the same as the bytes loaded by the hardware interface layer or the value is not large enough to hold a command header."),
+	(0x80280143, "TPM_20_E_COMMAND_CODE", "Command code not supported."),
+	(0x80280144, "TPM_20_E_AUTHSIZE", "The value of authorizationSize is out of range or the number of octets in the authorization Area is greater than required."),
+	(0x80280145, "TPM_20_E_AUTH_CONTEXT", "Use of an authorization session with a context command or another command that cannot have an authorization session."),
+	(0x80280146, "TPM_20_E_NV_RANGE", "NV offset+size is out of range."),
+	(0x80280147, "TPM_20_E_NV_SIZE", "Requested allocation size is larger than allowed."),
+	(0x80280148, "TPM_20_E_NV_LOCKED", "NV access locked."),
+	(0x80280149, "TPM_20_E_NV_AUTHORIZATION", "NV access authorization fails in command actions."),
+	(0x8028014a, "TPM_20_E_NV_UNINITIALIZED", "An NV index is used before being initialized or the state saved by TPM2_Shutdown(STATE) could not be restored."),
+	(0x8028014b, "TPM_20_E_NV_SPACE", "Insufficient space for NV allocation."),
+	(0x8028014c, "TPM_20_E_NV_DEFINED", "NV index or persistent object already defined."),
+	(0x80280150, "TPM_20_E_BAD_CONTEXT", "Context in TPM2_ContextLoad() is not valid."),
+	(0x80280151, "TPM_20_E_CPHASH", "chHash value already set or not correct for use."),
+	(0x80280152, "TPM_20_E_PARENT", "Handle for parent is not a valid parent."),
+	(0x80280153, "TPM_20_E_NEEDS_TEST", "Some function needs testing."),
+	(0x80280154, "TPM_20_E_NO_RESULT", "Returned when an internal function cannot process a request due to an unspecified problem. This code is usually related to invalid parameters that are not properly filtered by the input unmarshaling code."),
+	(0x80280155, "TPM_20_E_SENSITIVE", "The sensitive area did not unmarshal correctly after decryption - this code is used in lieu of the other unmarshaling errors so that an attacker cannot determine where the unmarshaling error occurred."),
+	(0x80280400, "TPM_E_COMMAND_BLOCKED", "The command was blocked."),
+	(0x80280401, "TPM_E_INVALID_HANDLE", "The specified handle was not found."),
+	(0x80280402, "TPM_E_DUPLICATE_VHANDLE", "The TPM returned a duplicate handle and the command needs to be resubmitted."),
+	(0x80280403, "TPM_E_EMBEDDED_COMMAND_BLOCKED", "The command within the transport was blocked."),
+	(0x80280404, "TPM_E_EMBEDDED_COMMAND_UNSUPPORTED", "The command within the transport is not supported."),
+	(0x80280800, "TPM_E_RETRY", "The TPM is too busy to respond to the command immediately, but the command could be resubmitted at a later time."),
+	(0x80280801, "TPM_E_NEEDS_SELFTEST", "SelfTestFull has not been run."),
+	(0x80280802, "TPM_E_DOING_SELFTEST", "The TPM is currently executing a full selftest."),
+	(0x80280803, "TPM_E_DEFEND_LOCK_RUNNING", "The TPM is defending against dictionary attacks and is in a time-out period."),
+	(0x80280901, "TPM_20_E_CONTEXT_GAP", "Gap for context ID is too large."),
+	(0x80280902, "TPM_20_E_OBJECT_MEMORY", "Out of memory for object contexts."),
+	(0x80280903, "TPM_20_E_SESSION_MEMORY", "Out of memory for session contexts."),
+	(0x80280904, "TPM_20_E_MEMORY", "Out of shared object/session memory or need space for internal operations."),
+	(0x80280905, "TPM_20_E_SESSION_HANDLES", "Out of session handles - a session must be flushed before a new session may be created."),
+	(0x80280906, "TPM_20_E_OBJECT_HANDLES", "Out of object handles - the handle space for objects is depleted and a reboot is required."),
+	(0x80280907, "TPM_20_E_LOCALITY", "Bad locality."),
+	(0x80280908, "TPM_20_E_YIELDED", "The TPM has suspended operation on the command; forward progress was made and the command may be retried."),
+	(0x80280909, "TPM_20_E_CANCELED", "The command was canceled."),
+	(0x8028090a, "TPM_20_E_TESTING", "TPM is performing self-tests."),
+	(0x80280920, "TPM_20_E_NV_RATE", "The TPM is rate-limiting accesses to prevent wearout of NV."),
+	(0x80280921, "TPM_20_E_LOCKOUT", "Authorization for objects subject to DA protection are not allowed at this time because the TPM is in DA lockout mode."),
+	(0x80280922, "TPM_20_E_RETRY", "The TPM was not able to start the command."),
+	(0x80280923, "TPM_20_E_NV_UNAVAILABLE", "The command may require writing of NV and NV is not current accessible."),
+	(0x80284001, "TBS_E_INTERNAL_ERROR", "An internal error has occurred within the Trusted Platform Module support program."),
+	(0x80284002, "TBS_E_BAD_PARAMETER", "One or more input parameters is bad."),
+	(0x80284003, "TBS_E_INVALID_OUTPUT_POINTER", "A specified output pointer is bad."),
+	(0x80284004, "TBS_E_INVALID_CONTEXT", "The specified context handle does not refer to a valid context."),
+	(0x80284005, "TBS_E_INSUFFICIENT_BUFFER", "A specified output buffer is too small."),
+	(0x80284006, "TBS_E_IOERROR", "An error occurred while communicating with the TPM."),
+	(0x80284007, "TBS_E_INVALID_CONTEXT_PARAM", "One or more context parameters is invalid."),
+	(0x80284008, "TBS_E_SERVICE_NOT_RUNNING", "The TBS service is not running and could not be started."),
+	(0x80284009, "TBS_E_TOO_MANY_TBS_CONTEXTS", "A new context could not be created because there are too many open contexts."),
+	(0x8028400a, "TBS_E_TOO_MANY_RESOURCES", "A new virtual resource could not be created because there are too many open virtual resources."),
+	(0x8028400b, "TBS_E_SERVICE_START_PENDING", "The TBS service has been started but is not yet running."),
+	(0x8028400c, "TBS_E_PPI_NOT_SUPPORTED", "The physical presence interface is not supported."),
+	(0x8028400d, "TBS_E_COMMAND_CANCELED", "The command was canceled."),
+	(0x8028400e, "TBS_E_BUFFER_TOO_LARGE", "The input or output buffer is too large."),
+	(0x8028400f, "TBS_E_TPM_NOT_FOUND", "A compatible Trusted Platform Module (TPM) Security Device cannot be found on this computer."),
+	(0x80284010, "TBS_E_SERVICE_DISABLED", "The TBS service has been disabled."),
+	(0x80284011, "TBS_E_NO_EVENT_LOG", "No TCG event log is available."),
+	(0x80284012, "TBS_E_ACCESS_DENIED", "The caller does not have the appropriate rights to perform the requested operation."),
+	(0x80284013, "TBS_E_PROVISIONING_NOT_ALLOWED", "The TPM provisioning action is not allowed by the specified flags. For provisioning to be successful, one of several actions may be required. The TPM management console (tpm.msc) action to make the TPM Ready may help. For further information, see the documentation for the Win32_Tpm WMI method 'Provision'. (The actions that may be required include importing the TPM Owner Authorization value into the system, calling the Win32_Tpm WMI method for provisioning the TPM and specifying TRUE for either 'ForceClear_Allowed' or 'PhysicalPresencePrompts_Allowed' (as indicated by the value returned in the Additional Information), or enabling the TPM in the system BIOS.)"),
+	(0x80284014, "TBS_E_PPI_FUNCTION_UNSUPPORTED", "The Physical Presence Interface of this firmware does not support the requested method."),
+	(0x80284015, "TBS_E_OWNERAUTH_NOT_FOUND", "The requested TPM OwnerAuth value was not found."),
+	(0x80284016, "TBS_E_PROVISIONING_INCOMPLETE", "The TPM provisioning did not complete. For more information on completing the provisioning, call the Win32_Tpm WMI method for provisioning the TPM ('Provision') and check the returned Information."),
+	(0x80290100, "TPMAPI_E_INVALID_STATE", "The command buffer is not in the correct state."),
+	(0x80290101, "TPMAPI_E_NOT_ENOUGH_DATA", "The command buffer does not contain enough data to satisfy the request."),
+	(0x80290102, "TPMAPI_E_TOO_MUCH_DATA", "The command buffer cannot contain any more data."),
+	(0x80290103, "TPMAPI_E_INVALID_OUTPUT_POINTER", "One or more output parameters was NULL or invalid."),
+	(0x80290104, "TPMAPI_E_INVALID_PARAMETER", "One or more input parameters is invalid."),
+	(0x80290105, "TPMAPI_E_OUT_OF_MEMORY", "Not enough memory was available to satisfy the request."),
+	(0x80290106, "TPMAPI_E_BUFFER_TOO_SMALL", "The specified buffer was too small."),
+	(0x80290107, "TPMAPI_E_INTERNAL_ERROR", "An internal error was detected."),
+	(0x80290108, "TPMAPI_E_ACCESS_DENIED", "The caller does not have the appropriate rights to perform the requested operation."),
+	(0x80290109, "TPMAPI_E_AUTHORIZATION_FAILED", "The specified authorization information was invalid."),
+	(0x8029010a, "TPMAPI_E_INVALID_CONTEXT_HANDLE", "The specified context handle was not valid."),
+	(0x8029010b, "TPMAPI_E_TBS_COMMUNICATION_ERROR", "An error occurred while communicating with the TBS."),
+	(0x8029010c, "TPMAPI_E_TPM_COMMAND_ERROR", "The TPM returned an unexpected result."),
+	(0x8029010d, "TPMAPI_E_MESSAGE_TOO_LARGE", "The message was too large for the encoding scheme."),
+	(0x8029010e, "TPMAPI_E_INVALID_ENCODING", "The encoding in the blob was not recognized."),
+	(0x8029010f, "TPMAPI_E_INVALID_KEY_SIZE", "The key size is not valid."),
+	(0x80290110, "TPMAPI_E_ENCRYPTION_FAILED", "The encryption operation failed."),
+	(0x80290111, "TPMAPI_E_INVALID_KEY_PARAMS", "The key parameters structure was not valid."),
+	(0x80290112, "TPMAPI_E_INVALID_MIGRATION_AUTHORIZATION_BLOB", "The requested supplied data does not appear to be a valid migration authorization blob."),
+	(0x80290113, "TPMAPI_E_INVALID_PCR_INDEX", "The specified PCR index was invalid."),
+	(0x80290114, "TPMAPI_E_INVALID_DELEGATE_BLOB", "The data given does not appear to be a valid delegate blob."),
+	(0x80290115, "TPMAPI_E_INVALID_CONTEXT_PARAMS", "One or more of the specified context parameters was not valid."),
+	(0x80290116, "TPMAPI_E_INVALID_KEY_BLOB", "The data given does not appear to be a valid key blob."),
+	(0x80290117, "TPMAPI_E_INVALID_PCR_DATA", "The specified PCR data was invalid."),
+	(0x80290118, "TPMAPI_E_INVALID_OWNER_AUTH", "The format of the owner auth data was invalid."),
+	(0x80290119, "TPMAPI_E_FIPS_RNG_CHECK_FAILED", "The random number generated did not pass FIPS RNG check."),
+	(0x8029011a, "TPMAPI_E_EMPTY_TCG_LOG", "The TCG Event Log does not contain any data."),
+	(0x8029011b, "TPMAPI_E_INVALID_TCG_LOG_ENTRY", "An entry in the TCG Event Log was invalid."),
+	(0x8029011c, "TPMAPI_E_TCG_SEPARATOR_ABSENT", "A TCG Separator was not found."),
+	(0x8029011d, "TPMAPI_E_TCG_INVALID_DIGEST_ENTRY", "A digest value in a TCG Log entry did not match hashed data."),
+	(0x8029011e, "TPMAPI_E_POLICY_DENIES_OPERATION", "The requested operation was blocked by current TPM policy. Please contact your system administrator for assistance."),
+	(0x8029011f, "TPMAPI_E_NV_BITS_NOT_DEFINED", "The Windows TPM NV bits are not defined."),
+	(0x80290120, "TPMAPI_E_NV_BITS_NOT_READY", "The Windows TPM NV bits are not ready."),
+	(0x80290121, "TPMAPI_E_SEALING_KEY_NOT_AVAILABLE", "The Windows TPM sealing key is not available."),
+	(0x80290122, "TPMAPI_E_NO_AUTHORIZATION_CHAIN_FOUND", "The authorization chain for the given key could not be found."),
+	(0x80290123, "TPMAPI_E_SVN_COUNTER_NOT_AVAILABLE", "The SVN counter is not available."),
+	(0x80290124, "TPMAPI_E_OWNER_AUTH_NOT_NULL", "The TPM owner authorization is not NULL."),
+	(0x80290125, "TPMAPI_E_ENDORSEMENT_AUTH_NOT_NULL", "The TPM endorsement authorization is not NULL."),
+	(0x80290126, "TPMAPI_E_AUTHORIZATION_REVOKED", "The authorization has been revoked."),
+	(0x80290127, "TPMAPI_E_MALFORMED_AUTHORIZATION_KEY", "The authorization key is malformed."),
+	(0x80290128, "TPMAPI_E_AUTHORIZING_KEY_NOT_SUPPORTED", "The authorizing key is not supported."),
+	(0x80290129, "TPMAPI_E_INVALID_AUTHORIZATION_SIGNATURE", "The authorization signature is invalid."),
+	(0x8029012a, "TPMAPI_E_MALFORMED_AUTHORIZATION_POLICY", "The authorization policy is malformed."),
+	(0x8029012b, "TPMAPI_E_MALFORMED_AUTHORIZATION_OTHER", "The authorization is malformed."),
+	(0x8029012c, "TPMAPI_E_SEALING_KEY_CHANGED", "The sealing key has changed."),
+	(0x80290200, "TBSIMP_E_BUFFER_TOO_SMALL", "The specified buffer was too small."),
+	(0x80290201, "TBSIMP_E_CLEANUP_FAILED", "The context could not be cleaned up."),
+	(0x80290202, "TBSIMP_E_INVALID_CONTEXT_HANDLE", "The specified context handle is invalid."),
+	(0x80290203, "TBSIMP_E_INVALID_CONTEXT_PARAM", "An invalid context parameter was specified."),
+	(0x80290204, "TBSIMP_E_TPM_ERROR", "An error occurred while communicating with the TPM."),
+	(0x80290205, "TBSIMP_E_HASH_BAD_KEY", "No entry with the specified key was found."),
+	(0x80290206, "TBSIMP_E_DUPLICATE_VHANDLE", "The specified virtual handle matches a virtual handle already in use."),
+	(0x80290207, "TBSIMP_E_INVALID_OUTPUT_POINTER", "The pointer to the returned handle location was NULL or invalid."),
+	(0x80290208, "TBSIMP_E_INVALID_PARAMETER", "One or more parameters is invalid."),
+	(0x80290209, "TBSIMP_E_RPC_INIT_FAILED", "The RPC subsystem could not be initialized."),
+	(0x8029020a, "TBSIMP_E_SCHEDULER_NOT_RUNNING", "The TBS scheduler is not running."),
+	(0x8029020b, "TBSIMP_E_COMMAND_CANCELED", "The command was canceled."),
+	(0x8029020c, "TBSIMP_E_OUT_OF_MEMORY", "There was not enough memory to fulfill the request."),
+	(0x8029020d, "TBSIMP_E_LIST_NO_MORE_ITEMS", "The specified list is empty, or the iteration has reached the end of the list."),
+	(0x8029020e, "TBSIMP_E_LIST_NOT_FOUND", "The specified item was not found in the list."),
+	(0x8029020f, "TBSIMP_E_NOT_ENOUGH_SPACE", "The TPM does not have enough space to load the requested resource."),
+	(0x80290210, "TBSIMP_E_NOT_ENOUGH_TPM_CONTEXTS", "There are too many TPM contexts in use."),
+	(0x80290211, "TBSIMP_E_COMMAND_FAILED", "The TPM command failed."),
+	(0x80290212, "TBSIMP_E_UNKNOWN_ORDINAL", "The TBS does not recognize the specified ordinal."),
+	(0x80290213, "TBSIMP_E_RESOURCE_EXPIRED", "The requested resource is no longer available."),
+	(0x80290214, "TBSIMP_E_INVALID_RESOURCE", "The resource type did not match."),
+	(0x80290215, "TBSIMP_E_NOTHING_TO_UNLOAD", "No resources can be unloaded."),
+	(0x80290216, "TBSIMP_E_HASH_TABLE_FULL", "No new entries can be added to the hash table."),
+	(0x80290217, "TBSIMP_E_TOO_MANY_TBS_CONTEXTS", "A new TBS context could not be created because there are too many open contexts."),
+	(0x80290218, "TBSIMP_E_TOO_MANY_RESOURCES", "A new virtual resource could not be created because there are too many open virtual resources."),
+	(0x80290219, "TBSIMP_E_PPI_NOT_SUPPORTED", "The physical presence interface is not supported."),
+	(0x8029021a, "TBSIMP_E_TPM_INCOMPATIBLE", "TBS is not compatible with the version of TPM found on the system."),
+	(0x8029021b, "TBSIMP_E_NO_EVENT_LOG", "No TCG event log is available."),
+	(0x80290300, "TPM_E_PPI_ACPI_FAILURE", "A general error was detected when attempting to acquire the BIOS's response to a Physical Presence command."),
+	(0x80290301, "TPM_E_PPI_USER_ABORT", "The user failed to confirm the TPM operation request."),
+	(0x80290302, "TPM_E_PPI_BIOS_FAILURE", "The BIOS failure prevented the successful execution of the requested TPM operation (e.g. invalid TPM operation request, BIOS communication error with the TPM)."),
+	(0x80290303, "TPM_E_PPI_NOT_SUPPORTED", "The BIOS does not support the physical presence interface."),
+	(0x80290304, "TPM_E_PPI_BLOCKED_IN_BIOS", "The Physical Presence command was blocked by current BIOS settings. The system owner may be able to reconfigure the BIOS settings to allow the command."),
+	(0x80290400, "TPM_E_PCP_ERROR_MASK", "This is an error mask to convert Platform Crypto Provider errors to win errors."),
+	(0x80290401, "TPM_E_PCP_DEVICE_NOT_READY", "The Platform Crypto Device is currently not ready. It needs to be fully provisioned to be operational."),
+	(0x80290402, "TPM_E_PCP_INVALID_HANDLE", "The handle provided to the Platform Crypto Provider is invalid."),
+	(0x80290403, "TPM_E_PCP_INVALID_PARAMETER", "A parameter provided to the Platform Crypto Provider is invalid."),
+	(0x80290404, "TPM_E_PCP_FLAG_NOT_SUPPORTED", "A provided flag to the Platform Crypto Provider is not supported."),
+	(0x80290405, "TPM_E_PCP_NOT_SUPPORTED", "The requested operation is not supported by this Platform Crypto Provider."),
+	(0x80290406, "TPM_E_PCP_BUFFER_TOO_SMALL", "The buffer is too small to contain all data. No information has been written to the buffer."),
+	(0x80290407, "TPM_E_PCP_INTERNAL_ERROR", "An unexpected internal error has occurred in the Platform Crypto Provider."),
+	(0x80290408, "TPM_E_PCP_AUTHENTICATION_FAILED", "The authorization to use a provider object has failed."),
+	(0x80290409, "TPM_E_PCP_AUTHENTICATION_IGNORED", "The Platform Crypto Device has ignored the authorization for the provider object, to mitigate against a dictionary attack."),
+	(0x8029040a, "TPM_E_PCP_POLICY_NOT_FOUND", "The referenced policy was not found."),
+	(0x8029040b, "TPM_E_PCP_PROFILE_NOT_FOUND", "The referenced profile was not found."),
+	(0x8029040c, "TPM_E_PCP_VALIDATION_FAILED", "The validation was not successful."),
+	(0x8029040e, "TPM_E_PCP_WRONG_PARENT", "An attempt was made to import or load a key under an incorrect storage parent."),
+	(0x8029040f, "TPM_E_KEY_NOT_LOADED", "The TPM key is not loaded."),
+	(0x80290410, "TPM_E_NO_KEY_CERTIFICATION", "The TPM key certification has not been generated."),
+	(0x80290411, "TPM_E_KEY_NOT_FINALIZED", "The TPM key is not yet finalized."),
+	(0x80290412, "TPM_E_ATTESTATION_CHALLENGE_NOT_SET", "The TPM attestation challenge is not set."),
+	(0x80290413, "TPM_E_NOT_PCR_BOUND", "The TPM PCR info is not available."),
+	(0x80290414, "TPM_E_KEY_ALREADY_FINALIZED", "The TPM key is already finalized."),
+	(0x80290415, "TPM_E_KEY_USAGE_POLICY_NOT_SUPPORTED", "The TPM key usage policy is not supported."),
+	(0x80290416, "TPM_E_KEY_USAGE_POLICY_INVALID", "The TPM key usage policy is invalid."),
+	(0x80290417, "TPM_E_SOFT_KEY_ERROR", "There was a problem with the software key being imported into the TPM."),
+	(0x80290418, "TPM_E_KEY_NOT_AUTHENTICATED", "The TPM key is not authenticated."),
+	(0x80290419, "TPM_E_PCP_KEY_NOT_AIK", "The TPM key is not an AIK."),
+	(0x8029041a, "TPM_E_KEY_NOT_SIGNING_KEY", "The TPM key is not a signing key."),
+	(0x8029041b, "TPM_E_LOCKED_OUT", "The TPM is locked out."),
+	(0x8029041c, "TPM_E_CLAIM_TYPE_NOT_SUPPORTED", "The claim type requested is not supported."),
+	(0x8029041d, "TPM_E_VERSION_NOT_SUPPORTED", "TPM version is not supported."),
+	(0x8029041e, "TPM_E_BUFFER_LENGTH_MISMATCH", "The buffer lengths do not match."),
+	(0x8029041f, "TPM_E_PCP_IFX_RSA_KEY_CREATION_BLOCKED", "The RSA key creation is blocked on this TPM due to known security vulnerabilities."),
+	(0x80290420, "TPM_E_PCP_TICKET_MISSING", "A ticket required to use a key was not provided."),
+	(0x80290421, "TPM_E_PCP_RAW_POLICY_NOT_SUPPORTED", "This key has a raw policy so the KSP can't authenticate against it."),
+	(0x80290422, "TPM_E_PCP_KEY_HANDLE_INVALIDATED", "The TPM key's handle was unexpectedly invalidated due to a hardware or firmware issue."),
+	(0x80290500, "TPM_E_ZERO_EXHAUST_ENABLED", "TPM is not operational because the TPM firmware update is out of sync with the TPM."),
+	(0x802a0001, "UI_E_CREATE_FAILED", "The object could not be created."),
+	(0x802a0002, "UI_E_SHUTDOWN_CALLED", "Shutdown was already called on this object or the object that owns it."),
+	(0x802a0003, "UI_E_ILLEGAL_REENTRANCY", "This method cannot be called during this type of callback."),
+	(0x802a0004, "UI_E_OBJECT_SEALED", "This object has been sealed, so this change is no longer allowed."),
+	(0x802a0005, "UI_E_VALUE_NOT_SET", "The requested value was never set."),
+	(0x802a0006, "UI_E_VALUE_NOT_DETERMINED", "The requested value cannot be determined."),
+	(0x802a0007, "UI_E_INVALID_OUTPUT", "A callback returned an invalid output parameter."),
+	(0x802a0008, "UI_E_BOOLEAN_EXPECTED", "A callback returned a success code other than S_OK or S_FALSE."),
+	(0x802a0009, "UI_E_DIFFERENT_OWNER", "A parameter that should be owned by this object is owned by a different object."),
+	(0x802a000a, "UI_E_AMBIGUOUS_MATCH", "More than one item matched the search criteria."),
+	(0x802a000b, "UI_E_FP_OVERFLOW", "A floating-point overflow occurred."),
+	(0x802a000c, "UI_E_WRONG_THREAD", "This method can only be called from the thread that created the object."),
+	(0x802a0101, "UI_E_STORYBOARD_ACTIVE", "The storyboard is currently in the schedule."),
+	(0x802a0102, "UI_E_STORYBOARD_NOT_PLAYING", "The storyboard is not playing."),
+	(0x802a0103, "UI_E_START_KEYFRAME_AFTER_END", "The start keyframe might occur after the end keyframe."),
+	(0x802a0104, "UI_E_END_KEYFRAME_NOT_DETERMINED", "It might not be possible to determine the end keyframe time when the start keyframe is reached."),
+	(0x802a0105, "UI_E_LOOPS_OVERLAP", "Two repeated portions of a storyboard might overlap."),
+	(0x802a0106, "UI_E_TRANSITION_ALREADY_USED", "The transition has already been added to a storyboard."),
+	(0x802a0107, "UI_E_TRANSITION_NOT_IN_STORYBOARD", "The transition has not been added to a storyboard."),
+	(0x802a0108, "UI_E_TRANSITION_ECLIPSED", "The transition might eclipse the beginning of another transition in the storyboard."),
+	(0x802a0109, "UI_E_TIME_BEFORE_LAST_UPDATE", "The given time is earlier than the time passed to the last update."),
+	(0x802a010a, "UI_E_TIMER_CLIENT_ALREADY_CONNECTED", "This client is already connected to a timer."),
+	(0x802a010b, "UI_E_INVALID_DIMENSION", "The passed dimension is invalid or does not match the object's dimension."),
+	(0x802a010c, "UI_E_PRIMITIVE_OUT_OF_BOUNDS", "The added primitive begins at or beyond the duration of the interpolator."),
+	(0x802a0201, "UI_E_WINDOW_CLOSED", "The operation cannot be completed because the window is being closed."),
+	(0x80300002, "PLA_E_DCS_NOT_FOUND", "Data Collector Set was not found."),
+	(0x80300045, "PLA_E_TOO_MANY_FOLDERS", "Unable to start Data Collector Set because there are too many folders."),
+	(0x80300070, "PLA_E_NO_MIN_DISK", "Not enough free disk space to start Data Collector Set."),
+	(0x803000aa, "PLA_E_DCS_IN_USE", "Data Collector Set is in use."),
+	(0x803000b7, "PLA_E_DCS_ALREADY_EXISTS", "Data Collector Set already exists."),
+	(0x80300101, "PLA_E_PROPERTY_CONFLICT", "Property value conflict."),
+	(0x80300102, "PLA_E_DCS_SINGLETON_REQUIRED", "The current configuration for this Data Collector Set requires that it contain exactly one Data Collector."),
+	(0x80300103, "PLA_E_CREDENTIALS_REQUIRED", "A user account is required in order to commit the current Data Collector Set properties."),
+	(0x80300104, "PLA_E_DCS_NOT_RUNNING", "Data Collector Set is not running."),
+	(0x80300105, "PLA_E_CONFLICT_INCL_EXCL_API", "A conflict was detected in the list of include/exclude APIs. Do not specify the same API in both the include list and the exclude list."),
+	(0x80300106, "PLA_E_NETWORK_EXE_NOT_VALID", "The executable path you have specified refers to a network share or UNC path."),
+	(0x80300107, "PLA_E_EXE_ALREADY_CONFIGURED", "The executable path you have specified is already configured for API tracing."),
+	(0x80300108, "PLA_E_EXE_PATH_NOT_VALID", "The executable path you have specified does not exist. Verify that the specified path is correct."),
+	(0x80300109, "PLA_E_DC_ALREADY_EXISTS", "Data Collector already exists."),
+	(0x8030010a, "PLA_E_DCS_START_WAIT_TIMEOUT", "The wait for the Data Collector Set start notification has timed out."),
+	(0x8030010b, "PLA_E_DC_START_WAIT_TIMEOUT", "The wait for the Data Collector to start has timed out."),
+	(0x8030010c, "PLA_E_REPORT_WAIT_TIMEOUT", "The wait for the report generation tool to finish has timed out."),
+	(0x8030010d, "PLA_E_NO_DUPLICATES", "Duplicate items are not allowed."),
+	(0x8030010e, "PLA_E_EXE_FULL_PATH_REQUIRED", "When specifying the executable that you want to trace, you must specify a full path to the executable and not just a filename."),
+	(0x8030010f, "PLA_E_INVALID_SESSION_NAME", "The session name provided is invalid."),
+	(0x80300110, "PLA_E_PLA_CHANNEL_NOT_ENABLED", "The Event Log channel Microsoft-Windows-Diagnosis-PLA/Operational must be enabled to perform this operation."),
+	(0x80300111, "PLA_E_TASKSCHED_CHANNEL_NOT_ENABLED", "The Event Log channel Microsoft-Windows-TaskScheduler must be enabled to perform this operation."),
+	(0x80300112, "PLA_E_RULES_MANAGER_FAILED", "The execution of the Rules Manager failed."),
+	(0x80300113, "PLA_E_CABAPI_FAILURE", "An error occurred while attempting to compress or extract the data."),
+	(0x80310000, "FVE_E_LOCKED_VOLUME", "This drive is locked by BitLocker Drive Encryption. You must unlock this drive from Control Panel."),
+	(0x80310001, "FVE_E_NOT_ENCRYPTED", "This drive is not encrypted."),
+	(0x80310002, "FVE_E_NO_TPM_BIOS", "The BIOS did not correctly communicate with the Trusted Platform Module (TPM). Contact the computer manufacturer for BIOS upgrade instructions."),
+	(0x80310003, "FVE_E_NO_MBR_METRIC", "The BIOS did not correctly communicate with the master boot record (MBR). Contact the computer manufacturer for BIOS upgrade instructions."),
+	(0x80310004, "FVE_E_NO_BOOTSECTOR_METRIC", "A required TPM measurement is missing. If there is a bootable CD or DVD in your computer, remove it, restart the computer, and turn on BitLocker again. If the problem persists, ensure the master boot record is up to date."),
+	(0x80310005, "FVE_E_NO_BOOTMGR_METRIC", "The boot sector of this drive is not compatible with BitLocker Drive Encryption. Use the Bootrec.exe tool in the Windows Recovery Environment to update or repair the boot manager (BOOTMGR)."),
+	(0x80310006, "FVE_E_WRONG_BOOTMGR", "The boot manager of this operating system is not compatible with BitLocker Drive Encryption. Use the Bootrec.exe tool in the Windows Recovery Environment to update or repair the boot manager (BOOTMGR)."),
+	(0x80310007, "FVE_E_SECURE_KEY_REQUIRED", "At least one secure key protector is required for this operation to be performed."),
+	(0x80310008, "FVE_E_NOT_ACTIVATED", "BitLocker Drive Encryption is not enabled on this drive. Turn on BitLocker."),
+	(0x80310009, "FVE_E_ACTION_NOT_ALLOWED", "BitLocker Drive Encryption cannot perform the requested action. This condition may occur when two requests are issued at the same time. Wait a few moments and then try the action again."),
+	(0x8031000a, "FVE_E_AD_SCHEMA_NOT_INSTALLED", "The Active Directory Domain Services forest does not contain the required attributes and classes to host BitLocker Drive Encryption or TPM information. Contact your domain administrator to verify that any required BitLocker Active Directory schema extensions have been installed."),
+	(0x8031000b, "FVE_E_AD_INVALID_DATATYPE", "The type of the data obtained from Active Directory was not expected. The BitLocker recovery information may be missing or corrupted."),
+	(0x8031000c, "FVE_E_AD_INVALID_DATASIZE", "The size of the data obtained from Active Directory was not expected. The BitLocker recovery information may be missing or corrupted."),
+	(0x8031000d, "FVE_E_AD_NO_VALUES", "The attribute read from Active Directory does not contain any values. The BitLocker recovery information may be missing or corrupted."),
+	(0x8031000e, "FVE_E_AD_ATTR_NOT_SET", "The attribute was not set. Verify that you are logged on with a domain account that has the ability to write information to Active Directory objects."),
+	(0x8031000f, "FVE_E_AD_GUID_NOT_FOUND", "The specified attribute cannot be found in Active Directory Domain Services. Contact your domain administrator to verify that any required BitLocker Active Directory schema extensions have been installed."),
+	(0x80310010, "FVE_E_BAD_INFORMATION", "The BitLocker metadata for the encrypted drive is not valid. You can attempt to repair the drive to restore access."),
+	(0x80310011, "FVE_E_TOO_SMALL", "The drive cannot be encrypted because it does not have enough free space. Delete any unnecessary data on the drive to create additional free space and then try again."),
+	(0x80310012, "FVE_E_SYSTEM_VOLUME", "The drive cannot be encrypted because it contains system boot information. Create a separate partition for use as the system drive that contains the boot information and a second partition for use as the operating system drive and then encrypt the operating system drive."),
+	(0x80310013, "FVE_E_FAILED_WRONG_FS", "The drive cannot be encrypted because the file system is not supported."),
+	(0x80310014, "FVE_E_BAD_PARTITION_SIZE", "The file system size is larger than the partition size in the partition table. This drive may be corrupt or may have been tampered with. To use it with BitLocker, you must reformat the partition."),
+	(0x80310015, "FVE_E_NOT_SUPPORTED", "This drive cannot be encrypted."),
+	(0x80310016, "FVE_E_BAD_DATA", "The data is not valid."),
+	(0x80310017, "FVE_E_VOLUME_NOT_BOUND", "The data drive specified is not set to automatically unlock on the current computer and cannot be unlocked automatically."),
+	(0x80310018, "FVE_E_TPM_NOT_OWNED", "You must initialize the Trusted Platform Module (TPM) before you can use BitLocker Drive Encryption."),
+	(0x80310019, "FVE_E_NOT_DATA_VOLUME", "The operation attempted cannot be performed on an operating system drive."),
+	(0x8031001a, "FVE_E_AD_INSUFFICIENT_BUFFER", "The buffer supplied to a function was insufficient to contain the returned data. Increase the buffer size before running the function again."),
+	(0x8031001b, "FVE_E_CONV_READ", "A read operation failed while converting the drive. The drive was not converted. Please re-enable BitLocker."),
+	(0x8031001c, "FVE_E_CONV_WRITE", "A write operation failed while converting the drive. The drive was not converted. Please re-enable BitLocker."),
+	(0x8031001d, "FVE_E_KEY_REQUIRED", "One or more BitLocker key protectors are required. You cannot delete the last key on this drive."),
+	(0x8031001e, "FVE_E_CLUSTERING_NOT_SUPPORTED", "Cluster configurations are not supported by BitLocker Drive Encryption."),
+	(0x8031001f, "FVE_E_VOLUME_BOUND_ALREADY", "The drive specified is already configured to be automatically unlocked on the current computer."),
+	(0x80310020, "FVE_E_OS_NOT_PROTECTED", "The operating system drive is not protected by BitLocker Drive Encryption."),
+	(0x80310021, "FVE_E_PROTECTION_DISABLED", "BitLocker Drive Encryption has been suspended on this drive. All BitLocker key protectors configured for this drive are effectively disabled, and the drive will be automatically unlocked using an unencrypted (clear) key."),
+	(0x80310022, "FVE_E_RECOVERY_KEY_REQUIRED", "The drive you are attempting to lock does not have any key protectors available for encryption because BitLocker protection is currently suspended. Re-enable BitLocker to lock this drive."),
+	(0x80310023, "FVE_E_FOREIGN_VOLUME", "BitLocker cannot use the Trusted Platform Module (TPM) to protect a data drive. TPM protection can only be used with the operating system drive."),
+	(0x80310024, "FVE_E_OVERLAPPED_UPDATE", "The BitLocker metadata for the encrypted drive cannot be updated because it was locked for updating by another process. Please try this process again."),
+	(0x80310025, "FVE_E_TPM_SRK_AUTH_NOT_ZERO", "The authorization data for the storage root key (SRK) of the Trusted Platform Module (TPM) is not zero and is therefore incompatible with BitLocker. Please initialize the TPM before attempting to use it with BitLocker."),
+	(0x80310026, "FVE_E_FAILED_SECTOR_SIZE", "The drive encryption algorithm cannot be used on this sector size."),
+	(0x80310027, "FVE_E_FAILED_AUTHENTICATION", "The drive cannot be unlocked with the key provided. Confirm that you have provided the correct key and try again."),
+	(0x80310028, "FVE_E_NOT_OS_VOLUME", "The drive specified is not the operating system drive."),
+	(0x80310029, "FVE_E_AUTOUNLOCK_ENABLED", "BitLocker Drive Encryption cannot be turned off on the operating system drive until the auto unlock feature has been disabled for the fixed data drives and removable data drives associated with this computer."),
+	(0x8031002a, "FVE_E_WRONG_BOOTSECTOR", "The system partition boot sector does not perform Trusted Platform Module (TPM) measurements. Use the Bootrec.exe tool in the Windows Recovery Environment to update or repair the boot sector."),
+	(0x8031002b, "FVE_E_WRONG_SYSTEM_FS", "BitLocker Drive Encryption operating system drives must be formatted with the NTFS file system in order to be encrypted. Convert the drive to NTFS, and then turn on BitLocker."),
+	(0x8031002c, "FVE_E_POLICY_PASSWORD_REQUIRED", "Group Policy settings require that a recovery password be specified before encrypting the drive."),
+	(0x8031002d, "FVE_E_CANNOT_SET_FVEK_ENCRYPTED", "The drive encryption algorithm and key cannot be set on a previously encrypted drive. To encrypt this drive with BitLocker Drive Encryption, remove the previous encryption and then turn on BitLocker."),
+	(0x8031002e, "FVE_E_CANNOT_ENCRYPT_NO_KEY", "BitLocker Drive Encryption cannot encrypt the specified drive because an encryption key is not available. Add a key protector to encrypt this drive."),
+	(0x80310030, "FVE_E_BOOTABLE_CDDVD", "BitLocker Drive Encryption detected bootable media (CD or DVD) in the computer. Remove the media and restart the computer before configuring BitLocker."),
+	(0x80310031, "FVE_E_PROTECTOR_EXISTS", "This key protector cannot be added. Only one key protector of this type is allowed for this drive."),
+	(0x80310032, "FVE_E_RELATIVE_PATH", "The recovery password file was not found because a relative path was specified. Recovery passwords must be saved to a fully qualified path. Environment variables configured on the computer can be used in the path."),
+	(0x80310033, "FVE_E_PROTECTOR_NOT_FOUND", "The specified key protector was not found on the drive. Try another key protector."),
+	(0x80310034, "FVE_E_INVALID_KEY_FORMAT", "The recovery key provided is corrupt and cannot be used to access the drive. An alternative recovery method, such as recovery password, a data recovery agent, or a backup version of the recovery key must be used to recover access to the drive."),
+	(0x80310035, "FVE_E_INVALID_PASSWORD_FORMAT", "The format of the recovery password provided is invalid. BitLocker recovery passwords are 48 digits. Verify that the recovery password is in the correct format and then try again."),
+	(0x80310036, "FVE_E_FIPS_RNG_CHECK_FAILED", "The random number generator check test failed."),
+	(0x80310037, "FVE_E_FIPS_PREVENTS_RECOVERY_PASSWORD", "The Group Policy setting requiring FIPS compliance prevents a local recovery password from being generated or used by BitLocker Drive Encryption. When operating in FIPS-compliant mode, BitLocker recovery options can be either a recovery key stored on a USB drive or recovery through a data recovery agent."),
+	(0x80310038, "FVE_E_FIPS_PREVENTS_EXTERNAL_KEY_EXPORT", "The Group Policy setting requiring FIPS compliance prevents the recovery password from being saved to Active Directory. When operating in FIPS-compliant mode, BitLocker recovery options can be either a recovery key stored on a USB drive or recovery through a data recovery agent. Check your Group Policy settings configuration."),
+	(0x80310039, "FVE_E_NOT_DECRYPTED", "The drive must be fully decrypted to complete this operation."),
+	(0x8031003a, "FVE_E_INVALID_PROTECTOR_TYPE", "The key protector specified cannot be used for this operation."),
+	(0x8031003b, "FVE_E_NO_PROTECTORS_TO_TEST", "No key protectors exist on the drive to perform the hardware test."),
+	(0x8031003c, "FVE_E_KEYFILE_NOT_FOUND", "The BitLocker startup key or recovery password cannot be found on the USB device. Verify that you have the correct USB device, that the USB device is plugged into the computer on an active USB port, restart the computer, and then try again. If the problem persists, contact the computer manufacturer for BIOS upgrade instructions."),
+	(0x8031003d, "FVE_E_KEYFILE_INVALID", "The BitLocker startup key or recovery password file provided is corrupt or invalid. Verify that you have the correct startup key or recovery password file and try again."),
+	(0x8031003e, "FVE_E_KEYFILE_NO_VMK", "The BitLocker encryption key cannot be obtained from the startup key or recovery password. Verify that you have the correct startup key or recovery password and try again."),
+	(0x8031003f, "FVE_E_TPM_DISABLED", "The Trusted Platform Module (TPM) is disabled. The TPM must be enabled, initialized, and have valid ownership before it can be used with BitLocker Drive Encryption."),
+	(0x80310040, "FVE_E_NOT_ALLOWED_IN_SAFE_MODE", "The BitLocker configuration of the specified drive cannot be managed because this computer is currently operating in Safe Mode. While in Safe Mode, BitLocker Drive Encryption can only be used for recovery purposes."),
+	(0x80310041, "FVE_E_TPM_INVALID_PCR", "The Trusted Platform Module (TPM) was unable to unlock the drive. Either the system boot information changed after choosing BitLocker settings or the PIN did not match. If the problem persists after several tries, there may be a hardware or firmware problem."),
+	(0x80310042, "FVE_E_TPM_NO_VMK", "The BitLocker encryption key cannot be obtained from the Trusted Platform Module (TPM)."),
+	(0x80310043, "FVE_E_PIN_INVALID", "The BitLocker encryption key cannot be obtained from the Trusted Platform Module (TPM) and PIN."),
+	(0x80310044, "FVE_E_AUTH_INVALID_APPLICATION", "A boot application has changed since BitLocker Drive Encryption was enabled."),
+	(0x80310045, "FVE_E_AUTH_INVALID_CONFIG", "The Boot Configuration Data (BCD) settings have changed since BitLocker Drive Encryption was enabled."),
+	(0x80310046, "FVE_E_FIPS_DISABLE_PROTECTION_NOT_ALLOWED", "The Group Policy setting requiring FIPS compliance prohibits the use of unencrypted keys, which prevents BitLocker from being suspended on this drive. Please contact your domain administrator for more information."),
+	(0x80310047, "FVE_E_FS_NOT_EXTENDED", "This drive cannot be encrypted by BitLocker Drive Encryption because the file system does not extend to the end of the drive. Repartition this drive and then try again."),
+	(0x80310048, "FVE_E_FIRMWARE_TYPE_NOT_SUPPORTED", "BitLocker Drive Encryption cannot be enabled on the operating system drive. Contact the computer manufacturer for BIOS upgrade instructions."),
+	(0x80310049, "FVE_E_NO_LICENSE", "This version of Windows does not include BitLocker Drive Encryption. To use BitLocker Drive Encryption, please upgrade the operating system."),
+	(0x8031004a, "FVE_E_NOT_ON_STACK", "BitLocker Drive Encryption cannot be used because critical BitLocker system files are missing or corrupted. Use Windows Startup Repair to restore these files to your computer."),
+	(0x8031004b, "FVE_E_FS_MOUNTED", "The drive cannot be locked when the drive is in use."),
+	(0x8031004c, "FVE_E_TOKEN_NOT_IMPERSONATED", "The access token associated with the current thread is not an impersonated token."),
+	(0x8031004d, "FVE_E_DRY_RUN_FAILED", "The BitLocker encryption key cannot be obtained. Verify that the Trusted Platform Module (TPM) is enabled and ownership has been taken. If this computer does not have a TPM, verify that the USB drive is inserted and available."),
+	(0x8031004e, "FVE_E_REBOOT_REQUIRED", "You must restart your computer before continuing with BitLocker Drive Encryption."),
+	(0x8031004f, "FVE_E_DEBUGGER_ENABLED", "Drive encryption cannot occur while boot debugging is enabled. Use the bcdedit command-line tool to turn off boot debugging."),
+	(0x80310050, "FVE_E_RAW_ACCESS", "No action was taken as BitLocker Drive Encryption is in raw access mode."),
+	(0x80310051, "FVE_E_RAW_BLOCKED", "BitLocker Drive Encryption cannot enter raw access mode for this drive because the drive is currently in use."),
+	(0x80310052, "FVE_E_BCD_APPLICATIONS_PATH_INCORRECT", "The path specified in the Boot Configuration Data (BCD) for a BitLocker Drive Encryption integrity-protected application is incorrect. Please verify and correct your BCD settings and try again."),
+	(0x80310053, "FVE_E_NOT_ALLOWED_IN_VERSION", "BitLocker Drive Encryption can only be used for limited provisioning or recovery purposes when the computer is running in pre-installation or recovery environments."),
+	(0x80310054, "FVE_E_NO_AUTOUNLOCK_MASTER_KEY", "The auto-unlock master key was not available from the operating system drive."),
+	(0x80310055, "FVE_E_MOR_FAILED", "The system firmware failed to enable clearing of system memory when the computer was restarted."),
+	(0x80310056, "FVE_E_HIDDEN_VOLUME", "The hidden drive cannot be encrypted."),
+	(0x80310057, "FVE_E_TRANSIENT_STATE", "BitLocker encryption keys were ignored because the drive was in a transient state."),
+	(0x80310058, "FVE_E_PUBKEY_NOT_ALLOWED", "Public key based protectors are not allowed on this drive."),
+	(0x80310059, "FVE_E_VOLUME_HANDLE_OPEN", "BitLocker Drive Encryption is already performing an operation on this drive. Please complete all operations before continuing."),
+	(0x8031005a, "FVE_E_NO_FEATURE_LICENSE", "This version of Windows does not support this feature of BitLocker Drive Encryption. To use this feature, upgrade the operating system."),
+	(0x8031005b, "FVE_E_INVALID_STARTUP_OPTIONS", "The Group Policy settings for BitLocker startup options are in conflict and cannot be applied. Contact your system administrator for more information."),
+	(0x8031005c, "FVE_E_POLICY_RECOVERY_PASSWORD_NOT_ALLOWED", "Group Policy settings do not permit the creation of a recovery password."),
+	(0x8031005d, "FVE_E_POLICY_RECOVERY_PASSWORD_REQUIRED", "Group Policy settings require the creation of a recovery password."),
+	(0x8031005e, "FVE_E_POLICY_RECOVERY_KEY_NOT_ALLOWED", "Group Policy settings do not permit the creation of a recovery key."),
+	(0x8031005f, "FVE_E_POLICY_RECOVERY_KEY_REQUIRED", "Group Policy settings require the creation of a recovery key."),
+	(0x80310060, "FVE_E_POLICY_STARTUP_PIN_NOT_ALLOWED", "Group Policy settings do not permit the use of a PIN at startup. Please choose a different BitLocker startup option."),
+	(0x80310061, "FVE_E_POLICY_STARTUP_PIN_REQUIRED", "Group Policy settings require the use of a PIN at startup. Please choose this BitLocker startup option."),
+	(0x80310062, "FVE_E_POLICY_STARTUP_KEY_NOT_ALLOWED", "Group Policy settings do not permit the use of a startup key. Please choose a different BitLocker startup option."),
+	(0x80310063, "FVE_E_POLICY_STARTUP_KEY_REQUIRED", "Group Policy settings require the use of a startup key. Please choose this BitLocker startup option."),
+	(0x80310064, "FVE_E_POLICY_STARTUP_PIN_KEY_NOT_ALLOWED", "Group Policy settings do not permit the use of a startup key and PIN. Please choose a different BitLocker startup option."),
+	(0x80310065, "FVE_E_POLICY_STARTUP_PIN_KEY_REQUIRED", "Group Policy settings require the use of a startup key and PIN. Please choose this BitLocker startup option."),
+	(0x80310066, "FVE_E_POLICY_STARTUP_TPM_NOT_ALLOWED", "Group policy does not permit the use of TPM-only at startup. Please choose a different BitLocker startup option."),
+	(0x80310067, "FVE_E_POLICY_STARTUP_TPM_REQUIRED", "Group Policy settings require the use of TPM-only at startup. Please choose this BitLocker startup option."),
+	(0x80310068, "FVE_E_POLICY_INVALID_PIN_LENGTH", "The PIN provided does not meet minimum or maximum length requirements."),
+	(0x80310069, "FVE_E_KEY_PROTECTOR_NOT_SUPPORTED", "The key protector is not supported by the version of BitLocker Drive Encryption currently on the drive. Upgrade the drive to add the key protector."),
+	(0x8031006a, "FVE_E_POLICY_PASSPHRASE_NOT_ALLOWED", "Group Policy settings do not permit the creation of a password."),
+	(0x8031006b, "FVE_E_POLICY_PASSPHRASE_REQUIRED", "Group Policy settings require the creation of a password."),
+	(0x8031006c, "FVE_E_FIPS_PREVENTS_PASSPHRASE", "The Group Policy setting requiring FIPS compliance prevents passwords from being generated or used. Please contact your system administrator for more information."),
+	(0x8031006d, "FVE_E_OS_VOLUME_PASSPHRASE_NOT_ALLOWED", "A password cannot be added to the operating system drive."),
+	(0x8031006e, "FVE_E_INVALID_BITLOCKER_OID", "The BitLocker object identifier (OID) on the drive appears to be invalid or corrupt. Use manage-BDE to reset the OID on this drive."),
+	(0x8031006f, "FVE_E_VOLUME_TOO_SMALL", "The drive is too small to be protected using BitLocker Drive Encryption."),
+	(0x80310070, "FVE_E_DV_NOT_SUPPORTED_ON_FS", "The selected discovery drive type is incompatible with the file system on the drive. BitLocker To Go discovery drives must be created on FAT formatted drives."),
+	(0x80310071, "FVE_E_DV_NOT_ALLOWED_BY_GP", "The selected discovery drive type is not allowed by the computer's Group Policy settings. Verify that Group Policy settings allow the creation of discovery drives for use with BitLocker To Go."),
+	(0x80310072, "FVE_E_POLICY_USER_CERTIFICATE_NOT_ALLOWED", "Group Policy settings do not permit user certificates such as smart cards to be used with BitLocker Drive Encryption."),
+	(0x80310073, "FVE_E_POLICY_USER_CERTIFICATE_REQUIRED", "Group Policy settings require that you have a valid user certificate, such as a smart card, to be used with BitLocker Drive Encryption."),
+	(0x80310074, "FVE_E_POLICY_USER_CERT_MUST_BE_HW", "Group Policy settings requires that you use a smart card-based key protector with BitLocker Drive Encryption."),
+	(0x80310075, "FVE_E_POLICY_USER_CONFIGURE_FDV_AUTOUNLOCK_NOT_ALLOWED", "Group Policy settings do not permit BitLocker-protected fixed data drives to be automatically unlocked."),
+	(0x80310076, "FVE_E_POLICY_USER_CONFIGURE_RDV_AUTOUNLOCK_NOT_ALLOWED", "Group Policy settings do not permit BitLocker-protected removable data drives to be automatically unlocked."),
+	(0x80310077, "FVE_E_POLICY_USER_CONFIGURE_RDV_NOT_ALLOWED", "Group Policy settings do not permit you to configure BitLocker Drive Encryption on removable data drives."),
+	(0x80310078, "FVE_E_POLICY_USER_ENABLE_RDV_NOT_ALLOWED", "Group Policy settings do not permit you to turn on BitLocker Drive Encryption on removable data drives. Please contact your system administrator if you need to turn on BitLocker."),
+	(0x80310079, "FVE_E_POLICY_USER_DISABLE_RDV_NOT_ALLOWED", "Group Policy settings do not permit turning off BitLocker Drive Encryption on removable data drives. Please contact your system administrator if you need to turn off BitLocker."),
+	(0x80310080, "FVE_E_POLICY_INVALID_PASSPHRASE_LENGTH", "Your password does not meet minimum password length requirements. By default, passwords must be at least 8 characters in length. Check with your system administrator for the password length requirement in your organization."),
+	(0x80310081, "FVE_E_POLICY_PASSPHRASE_TOO_SIMPLE", "Your password does not meet the complexity requirements set by your system administrator. Try adding upper and lowercase characters, numbers, and symbols."),
+	(0x80310082, "FVE_E_RECOVERY_PARTITION", "This drive cannot be encrypted because it is reserved for Windows System Recovery Options."),
+	(0x80310083, "FVE_E_POLICY_CONFLICT_FDV_RK_OFF_AUK_ON", "BitLocker Drive Encryption cannot be applied to this drive because of conflicting Group Policy settings. BitLocker cannot be configured to automatically unlock fixed data drives when user recovery options are disabled. If you want BitLocker-protected fixed data drives to be automatically unlocked after key validation has occurred, please ask your system administrator to resolve the settings conflict before enabling BitLocker."),
+	(0x80310084, "FVE_E_POLICY_CONFLICT_RDV_RK_OFF_AUK_ON", "BitLocker Drive Encryption cannot be applied to this drive because of conflicting Group Policy settings. BitLocker cannot be configured to automatically unlock removable data drives when user recovery option are disabled. If you want BitLocker-protected removable data drives to be automatically unlocked after key validation has occurred, please ask your system administrator to resolve the settings conflict before enabling BitLocker."),
+	(0x80310085, "FVE_E_NON_BITLOCKER_OID", "The Enhanced Key Usage (EKU) attribute of the specified certificate does not permit it to be used for BitLocker Drive Encryption. BitLocker does not require that a certificate have an EKU attribute, but if one is configured it must be set to an object identifier (OID) that matches the OID configured for BitLocker."),
+	(0x80310086, "FVE_E_POLICY_PROHIBITS_SELFSIGNED", "BitLocker Drive Encryption cannot be applied to this drive as currently configured because of Group Policy settings. The certificate you provided for drive encryption is self-signed. Current Group Policy settings do not permit the use of self-signed certificates. Obtain a new certificate from your certification authority before attempting to enable BitLocker."),
+	(0x80310087, "FVE_E_POLICY_CONFLICT_RO_AND_STARTUP_KEY_REQUIRED", "BitLocker Encryption cannot be applied to this drive because of conflicting Group Policy settings. When write access to drives not protected by BitLocker is denied, the use of a USB startup key cannot be required. Please have your system administrator resolve these policy conflicts before attempting to enable BitLocker."),
+	(0x80310088, "FVE_E_CONV_RECOVERY_FAILED", "BitLocker Drive Encryption failed to recover from an abruptly terminated conversion. This could be due to either all conversion logs being corrupted or the media being write-protected."),
+	(0x80310089, "FVE_E_VIRTUALIZED_SPACE_TOO_BIG", "The requested virtualization size is too big."),
+	(0x80310090, "FVE_E_POLICY_CONFLICT_OSV_RP_OFF_ADB_ON", "BitLocker Drive Encryption cannot be applied to this drive because there are conflicting Group Policy settings for recovery options on operating system drives. Storing recovery information to Active Directory Domain Services cannot be required when the generation of recovery passwords is not permitted. Please have your system administrator resolve these policy conflicts before attempting to enable BitLocker."),
+	(0x80310091, "FVE_E_POLICY_CONFLICT_FDV_RP_OFF_ADB_ON", "BitLocker Drive Encryption cannot be applied to this drive because there are conflicting Group Policy settings for recovery options on fixed data drives. Storing recovery information to Active Directory Domain Services cannot be required when the generation of recovery passwords is not permitted. Please have your system administrator resolve these policy conflicts before attempting to enable BitLocker."),
+	(0x80310092, "FVE_E_POLICY_CONFLICT_RDV_RP_OFF_ADB_ON", "BitLocker Drive Encryption cannot be applied to this drive because there are conflicting Group Policy settings for recovery options on removable data drives. Storing recovery information to Active Directory Domain Services cannot be required when the generation of recovery passwords is not permitted. Please have your system administrator resolve these policy conflicts before attempting to enable BitLocker."),
+	(0x80310093, "FVE_E_NON_BITLOCKER_KU", "The Key Usage (KU) attribute of the specified certificate does not permit it to be used for BitLocker Drive Encryption. BitLocker does not require that a certificate have a KU attribute, but if one is configured it must be set to either Key Encipherment or Key Agreement."),
+	(0x80310094, "FVE_E_PRIVATEKEY_AUTH_FAILED", "The private key associated with the specified certificate cannot be authorized. The private key authorization was either not provided or the provided authorization was invalid."),
+	(0x80310095, "FVE_E_REMOVAL_OF_DRA_FAILED", "Removal of the data recovery agent certificate must be done using the Certificates snap-in."),
+	(0x80310096, "FVE_E_OPERATION_NOT_SUPPORTED_ON_VISTA_VOLUME", "This drive was encrypted using the version of BitLocker Drive Encryption included with Windows Vista and Windows Server 2008 which does not support organizational identifiers. To specify organizational identifiers for this drive upgrade the drive encryption to the latest version using the \"manage-bde -upgrade\" command."),
+	(0x80310097, "FVE_E_CANT_LOCK_AUTOUNLOCK_ENABLED_VOLUME", "The drive cannot be locked because it is automatically unlocked on this computer. Remove the automatic unlock protector to lock this drive."),
+	(0x80310098, "FVE_E_FIPS_HASH_KDF_NOT_ALLOWED", "The default BitLocker Key Derivation Function SP800-56A for ECC smart cards is not supported by your smart card. The Group Policy setting requiring FIPS-compliance prevents BitLocker from using any other key derivation function for encryption. You have to use a FIPS compliant smart card in FIPS restricted environments."),
+	(0x80310099, "FVE_E_ENH_PIN_INVALID", "The BitLocker encryption key could not be obtained from the Trusted Platform Module (TPM) and enhanced PIN. Try using a PIN containing only numerals."),
+	(0x8031009a, "FVE_E_INVALID_PIN_CHARS", "The requested TPM PIN contains invalid characters."),
+	(0x8031009b, "FVE_E_INVALID_DATUM_TYPE", "The management information stored on the drive contained an unknown type. If you are using an old version of Windows, try accessing the drive from the latest version."),
+	(0x8031009c, "FVE_E_EFI_ONLY", "The feature is only supported on EFI systems."),
+	(0x8031009d, "FVE_E_MULTIPLE_NKP_CERTS", "More than one Network Key Protector certificate has been found on the system."),
+	(0x8031009e, "FVE_E_REMOVAL_OF_NKP_FAILED", "Removal of the Network Key Protector certificate must be done using the Certificates snap-in."),
+	(0x8031009f, "FVE_E_INVALID_NKP_CERT", "An invalid certificate has been found in the Network Key Protector certificate store."),
+	(0x803100a0, "FVE_E_NO_EXISTING_PIN", "This drive isn't protected with a PIN."),
+	(0x803100a1, "FVE_E_PROTECTOR_CHANGE_PIN_MISMATCH", "Please enter the correct current PIN."),
+	(0x803100a2, "FVE_E_PIN_PROTECTOR_CHANGE_BY_STD_USER_DISALLOWED", "You must be logged on with an administrator account to change the PIN. Click the link to reset the PIN as an administrator."),
+	(0x803100a3, "FVE_E_PROTECTOR_CHANGE_MAX_PIN_CHANGE_ATTEMPTS_REACHED", "BitLocker has disabled PIN changes after too many failed requests. Click the link to reset the PIN as an administrator."),
+	(0x803100a4, "FVE_E_POLICY_PASSPHRASE_REQUIRES_ASCII", "Your system administrator requires that passwords contain only printable ASCII characters. This includes unaccented letters (A-Z, a-z), numbers (0-9), space, arithmetic signs, common punctuation, separators, and the following symbols: # $ & @ ^ _ ~ ."),
+	(0x803100a5, "FVE_E_FULL_ENCRYPTION_NOT_ALLOWED_ON_TP_STORAGE", "BitLocker Drive Encryption only supports used space only encryption on thin provisioned storage."),
+	(0x803100a6, "FVE_E_WIPE_NOT_ALLOWED_ON_TP_STORAGE", "BitLocker Drive Encryption does not support wiping free space on thin provisioned storage."),
+	(0x803100a7, "FVE_E_KEY_LENGTH_NOT_SUPPORTED_BY_EDRIVE", "The required authentication key length is not supported by the drive."),
+	(0x803100a8, "FVE_E_NO_EXISTING_PASSPHRASE", "This drive isn't protected with a password."),
+	(0x803100a9, "FVE_E_PROTECTOR_CHANGE_PASSPHRASE_MISMATCH", "Please enter the correct current password."),
+	(0x803100aa, "FVE_E_PASSPHRASE_TOO_LONG", "The password cannot exceed 256 characters."),
+	(0x803100ab, "FVE_E_NO_PASSPHRASE_WITH_TPM", "A password key protector cannot be added because a TPM protector exists on the drive."),
+	(0x803100ac, "FVE_E_NO_TPM_WITH_PASSPHRASE", "A TPM key protector cannot be added because a password protector exists on the drive."),
+	(0x803100ad, "FVE_E_NOT_ALLOWED_ON_CSV_STACK", "This command can only be performed from the coordinator node for the specified CSV volume."),
+	(0x803100ae, "FVE_E_NOT_ALLOWED_ON_CLUSTER", "This command cannot be performed on a volume when it is part of a cluster."),
+	(0x803100af, "FVE_E_EDRIVE_NO_FAILOVER_TO_SW", "BitLocker did not revert to using BitLocker software encryption due to group policy configuration."),
+	(0x803100b0, "FVE_E_EDRIVE_BAND_IN_USE", "The drive cannot be managed by BitLocker because the drive's hardware encryption feature is already in use."),
+	(0x803100b1, "FVE_E_EDRIVE_DISALLOWED_BY_GP", "Group Policy settings do not allow the use of hardware-based encryption."),
+	(0x803100b2, "FVE_E_EDRIVE_INCOMPATIBLE_VOLUME", "The drive specified does not support hardware-based encryption."),
+	(0x803100b3, "FVE_E_NOT_ALLOWED_TO_UPGRADE_WHILE_CONVERTING", "BitLocker cannot be upgraded during disk encryption or decryption."),
+	(0x803100b4, "FVE_E_EDRIVE_DV_NOT_SUPPORTED", "Discovery Volumes are not supported for volumes using hardware encryption."),
+	(0x803100b5, "FVE_E_NO_PREBOOT_KEYBOARD_DETECTED", "No pre-boot keyboard detected. The user may not be able to provide required input to unlock the volume."),
+	(0x803100b6, "FVE_E_NO_PREBOOT_KEYBOARD_OR_WINRE_DETECTED", "No pre-boot keyboard or Windows Recovery Environment detected. The user may not be able to provide required input to unlock the volume."),
+	(0x803100b7, "FVE_E_POLICY_REQUIRES_STARTUP_PIN_ON_TOUCH_DEVICE", "Group Policy settings require the creation of a startup PIN, but a pre-boot keyboard is not available on this device. The user may not be able to provide required input to unlock the volume."),
+	(0x803100b8, "FVE_E_POLICY_REQUIRES_RECOVERY_PASSWORD_ON_TOUCH_DEVICE", "Group Policy settings require the creation of a recovery password, but neither a pre-boot keyboard nor Windows Recovery Environment is available on this device. The user may not be able to provide required input to unlock the volume."),
+	(0x803100b9, "FVE_E_WIPE_CANCEL_NOT_APPLICABLE", "Wipe of free space is not currently taking place."),
+	(0x803100ba, "FVE_E_SECUREBOOT_DISABLED", "BitLocker cannot use Secure Boot for platform integrity because Secure Boot has been disabled."),
+	(0x803100bb, "FVE_E_SECUREBOOT_CONFIGURATION_INVALID", "BitLocker cannot use Secure Boot for platform integrity because the Secure Boot configuration does not meet the requirements for BitLocker."),
+	(0x803100bc, "FVE_E_EDRIVE_DRY_RUN_FAILED", "Your computer doesn't support BitLocker hardware-based encryption. Check with your computer manufacturer for firmware updates."),
+	(0x803100bd, "FVE_E_SHADOW_COPY_PRESENT", "BitLocker cannot be enabled on the volume because it contains a Volume Shadow Copy. Remove all Volume Shadow Copies before encrypting the volume."),
+	(0x803100be, "FVE_E_POLICY_INVALID_ENHANCED_BCD_SETTINGS", "BitLocker Drive Encryption cannot be applied to this drive because the Group Policy setting for Enhanced Boot Configuration Data contains invalid data. Please have your system administrator resolve this invalid configuration before attempting to enable BitLocker."),
+	(0x803100bf, "FVE_E_EDRIVE_INCOMPATIBLE_FIRMWARE", "This PC's firmware is not capable of supporting hardware encryption."),
+	(0x803100c0, "FVE_E_PROTECTOR_CHANGE_MAX_PASSPHRASE_CHANGE_ATTEMPTS_REACHED", "BitLocker has disabled password changes after too many failed requests. Click the link to reset the password as an administrator."),
+	(0x803100c1, "FVE_E_PASSPHRASE_PROTECTOR_CHANGE_BY_STD_USER_DISALLOWED", "You must be logged on with an administrator account to change the password. Click the link to reset the password as an administrator."),
+	(0x803100c2, "FVE_E_LIVEID_ACCOUNT_SUSPENDED", "BitLocker cannot save the recovery password because the specified Microsoft account is Suspended."),
+	(0x803100c3, "FVE_E_LIVEID_ACCOUNT_BLOCKED", "BitLocker cannot save the recovery password because the specified Microsoft account is Blocked."),
+	(0x803100c4, "FVE_E_NOT_PROVISIONED_ON_ALL_VOLUMES", "This PC is not provisioned to support device encryption. Please enable BitLocker on all volumes to comply with device encryption policy."),
+	(0x803100c5, "FVE_E_DE_FIXED_DATA_NOT_SUPPORTED", "This PC cannot support device encryption because unencrypted fixed data volumes are present."),
+	(0x803100c6, "FVE_E_DE_HARDWARE_NOT_COMPLIANT", "This PC does not meet the hardware requirements to support device encryption."),
+	(0x803100c7, "FVE_E_DE_WINRE_NOT_CONFIGURED", "This PC cannot support device encryption because WinRE is not properly configured."),
+	(0x803100c8, "FVE_E_DE_PROTECTION_SUSPENDED", "This PC cannot support device encryption because protection is suspended."),
+	(0x803100c9, "FVE_E_DE_OS_VOLUME_NOT_PROTECTED", "Protection is enabled on the volume but has been suspended. This is likely to have happened due to an update being applied to your system. Please try again after a reboot."),
+	(0x803100ca, "FVE_E_DE_DEVICE_LOCKEDOUT", "Device Lock has been triggered due to too many incorrect password attempts."),
+	(0x803100cb, "FVE_E_DE_PROTECTION_NOT_YET_ENABLED", "Protection has not been enabled on the volume. Enabling protection requires a connected account. If you already have a connected account and are seeing this error, please refer to the event log for more information."),
+	(0x803100cc, "FVE_E_INVALID_PIN_CHARS_DETAILED", "Your PIN can only contain numbers from 0 to 9."),
+	(0x803100cd, "FVE_E_DEVICE_LOCKOUT_COUNTER_UNAVAILABLE", "BitLocker cannot use hardware replay protection because no counter is available on your PC."),
+	(0x803100ce, "FVE_E_DEVICELOCKOUT_COUNTER_MISMATCH", "Device Lockout state validation failed due to counter mismatch."),
+	(0x803100cf, "FVE_E_BUFFER_TOO_LARGE", "The input buffer is too large."),
+	(0x803100d0, "FVE_E_NO_SUCH_CAPABILITY_ON_TARGET", "The target of an invocation does not support requested capability."),
+	(0x803100d1, "FVE_E_DE_PREVENTED_FOR_OS", "Device encryption is currently blocked by this PC's configuration."),
+	(0x803100d2, "FVE_E_DE_VOLUME_OPTED_OUT", "This drive has been opted out of device encryption."),
+	(0x803100d3, "FVE_E_DE_VOLUME_NOT_SUPPORTED", "Device encryption isn't available for this drive."),
+	(0x803100d4, "FVE_E_EOW_NOT_SUPPORTED_IN_VERSION", "The encrypt on write mode for BitLocker is not supported in this version of Windows. You can turn on BitLocker without using the encrypt on write mode."),
+	(0x803100d5, "FVE_E_ADBACKUP_NOT_ENABLED", "Group policy prevents you from backing up your recovery password to Active Directory for this drive type. For more info, contact your system administrator."),
+	(0x803100d6, "FVE_E_VOLUME_EXTEND_PREVENTS_EOW_DECRYPT", "Device encryption can't be turned off while this drive is being encrypted. Please try again later."),
+	(0x803100d7, "FVE_E_NOT_DE_VOLUME", "This action isn't supported because this drive isn't automatically managed with device encryption."),
+	(0x803100d8, "FVE_E_PROTECTION_CANNOT_BE_DISABLED", "BitLocker can't be suspended on this drive until the next restart."),
+	(0x803100d9, "FVE_E_OSV_KSR_NOT_ALLOWED", "BitLocker Drive Encryption policy does not allow KSR operation with protected OS volume."),
+	(0x803100da, "FVE_E_AD_BACKUP_REQUIRED_POLICY_NOT_SET_OS_DRIVE", "Recovery key backup to Active Directory is required by policy, but the policy \"Choose how BitLocker-protected operating system drives can be recovered\" is not set."),
+	(0x803100db, "FVE_E_AD_BACKUP_REQUIRED_POLICY_NOT_SET_FIXED_DRIVE", "Recovery key backup to Active Directory is required by policy, but the policy \"Choose how BitLocker-protected fixed drives can be recovered\" is not set."),
+	(0x803100dc, "FVE_E_AD_BACKUP_REQUIRED_POLICY_NOT_SET_REMOVABLE_DRIVE", "Recovery key backup to Active Directory is required by policy, but the policy \"Choose how BitLocker-protected removable drives can be recovered\" is not set."),
+	(0x803100dd, "FVE_E_KEY_ROTATION_NOT_SUPPORTED", "Key rotation not supported by this BitLocker volume."),
+	(0x803100de, "FVE_E_EXECUTE_REQUEST_SENT_TOO_SOON", "The request for key rotation was sent too soon. Please try again later."),
+	(0x803100df, "FVE_E_KEY_ROTATION_NOT_ENABLED", "BitLocker recovery password rotation is not enabled."),
+	(0x803100e0, "FVE_E_DEVICE_NOT_JOINED", "The device is not joined to Azure AD."),
+	(0x80320001, "FWP_E_CALLOUT_NOT_FOUND", "The callout does not exist."),
+	(0x80320002, "FWP_E_CONDITION_NOT_FOUND", "The filter condition does not exist."),
+	(0x80320003, "FWP_E_FILTER_NOT_FOUND", "The filter does not exist."),
+	(0x80320004, "FWP_E_LAYER_NOT_FOUND", "The layer does not exist."),
+	(0x80320005, "FWP_E_PROVIDER_NOT_FOUND", "The provider does not exist."),
+	(0x80320006, "FWP_E_PROVIDER_CONTEXT_NOT_FOUND", "The provider context does not exist."),
+	(0x80320007, "FWP_E_SUBLAYER_NOT_FOUND", "The sublayer does not exist."),
+	(0x80320008, "FWP_E_NOT_FOUND", "The object does not exist."),
+	(0x80320009, "FWP_E_ALREADY_EXISTS", "An object with that GUID or LUID already exists."),
+	(0x8032000a, "FWP_E_IN_USE", "The object is referenced by other objects so cannot be deleted."),
+	(0x8032000b, "FWP_E_DYNAMIC_SESSION_IN_PROGRESS", "The call is not allowed from within a dynamic session."),
+	(0x8032000c, "FWP_E_WRONG_SESSION", "The call was made from the wrong session so cannot be completed."),
+	(0x8032000d, "FWP_E_NO_TXN_IN_PROGRESS", "The call must be made from within an explicit transaction."),
+	(0x8032000e, "FWP_E_TXN_IN_PROGRESS", "The call is not allowed from within an explicit transaction."),
+	(0x8032000f, "FWP_E_TXN_ABORTED", "The explicit transaction has been forcibly cancelled."),
+	(0x80320010, "FWP_E_SESSION_ABORTED", "The session has been cancelled."),
+	(0x80320011, "FWP_E_INCOMPATIBLE_TXN", "The call is not allowed from within a read-only transaction."),
+	(0x80320012, "FWP_E_TIMEOUT", "The call timed out while waiting to acquire the transaction lock."),
+	(0x80320013, "FWP_E_NET_EVENTS_DISABLED", "Collection of network diagnostic events is disabled."),
+	(0x80320014, "FWP_E_INCOMPATIBLE_LAYER", "The operation is not supported by the specified layer."),
+	(0x80320015, "FWP_E_KM_CLIENTS_ONLY", "The call is allowed for kernel-mode callers only."),
+	(0x80320016, "FWP_E_LIFETIME_MISMATCH", "The call tried to associate two objects with incompatible lifetimes."),
+	(0x80320017, "FWP_E_BUILTIN_OBJECT", "The object is built in so cannot be deleted."),
+	(0x80320018, "FWP_E_TOO_MANY_CALLOUTS", "The maximum number of callouts has been reached."),
+	(0x80320019, "FWP_E_NOTIFICATION_DROPPED", "A notification could not be delivered because a message queue is at its maximum capacity."),
+	(0x8032001a, "FWP_E_TRAFFIC_MISMATCH", "The traffic parameters do not match those for the security association context."),
+	(0x8032001b, "FWP_E_INCOMPATIBLE_SA_STATE", "The call is not allowed for the current security association state."),
+	(0x8032001c, "FWP_E_NULL_POINTER", "A required pointer is null."),
+	(0x8032001d, "FWP_E_INVALID_ENUMERATOR", "An enumerator is not valid."),
+	(0x8032001e, "FWP_E_INVALID_FLAGS", "The flags field contains an invalid value."),
+	(0x8032001f, "FWP_E_INVALID_NET_MASK", "A network mask is not valid."),
+	(0x80320020, "FWP_E_INVALID_RANGE", "An FWP_RANGE is not valid."),
+	(0x80320021, "FWP_E_INVALID_INTERVAL", "The time interval is not valid."),
+	(0x80320022, "FWP_E_ZERO_LENGTH_ARRAY", "An array that must contain at least one element is zero length."),
+	(0x80320023, "FWP_E_NULL_DISPLAY_NAME", "The displayData.name field cannot be null."),
+	(0x80320024, "FWP_E_INVALID_ACTION_TYPE", "The action type is not one of the allowed action types for a filter."),
+	(0x80320025, "FWP_E_INVALID_WEIGHT", "The filter weight is not valid."),
+	(0x80320026, "FWP_E_MATCH_TYPE_MISMATCH", "A filter condition contains a match type that is not compatible with the operands."),
+	(0x80320027, "FWP_E_TYPE_MISMATCH", "An FWP_VALUE or FWPM_CONDITION_VALUE is of the wrong type."),
+	(0x80320028, "FWP_E_OUT_OF_BOUNDS", "An integer value is outside the allowed range."),
+	(0x80320029, "FWP_E_RESERVED", "A reserved field is non-zero."),
+	(0x8032002a, "FWP_E_DUPLICATE_CONDITION", "A filter cannot contain multiple conditions operating on a single field."),
+	(0x8032002b, "FWP_E_DUPLICATE_KEYMOD", "A policy cannot contain the same keying module more than once."),
+	(0x8032002c, "FWP_E_ACTION_INCOMPATIBLE_WITH_LAYER", "The action type is not compatible with the layer."),
+	(0x8032002d, "FWP_E_ACTION_INCOMPATIBLE_WITH_SUBLAYER", "The action type is not compatible with the sublayer."),
+	(0x8032002e, "FWP_E_CONTEXT_INCOMPATIBLE_WITH_LAYER", "The raw context or the provider context is not compatible with the layer."),
+	(0x8032002f, "FWP_E_CONTEXT_INCOMPATIBLE_WITH_CALLOUT", "The raw context or the provider context is not compatible with the callout."),
+	(0x80320030, "FWP_E_INCOMPATIBLE_AUTH_METHOD", "The authentication method is not compatible with the policy type."),
+	(0x80320031, "FWP_E_INCOMPATIBLE_DH_GROUP", "The Diffie-Hellman group is not compatible with the policy type."),
+	(0x80320032, "FWP_E_EM_NOT_SUPPORTED", "An IKE policy cannot contain an Extended Mode policy."),
+	(0x80320033, "FWP_E_NEVER_MATCH", "The enumeration template or subscription will never match any objects."),
+	(0x80320034, "FWP_E_PROVIDER_CONTEXT_MISMATCH", "The provider context is of the wrong type."),
+	(0x80320035, "FWP_E_INVALID_PARAMETER", "The parameter is incorrect."),
+	(0x80320036, "FWP_E_TOO_MANY_SUBLAYERS", "The maximum number of sublayers has been reached."),
+	(0x80320037, "FWP_E_CALLOUT_NOTIFICATION_FAILED", "The notification function for a callout returned an error."),
+	(0x80320038, "FWP_E_INVALID_AUTH_TRANSFORM", "The IPsec authentication transform is not valid."),
+	(0x80320039, "FWP_E_INVALID_CIPHER_TRANSFORM", "The IPsec cipher transform is not valid."),
+	(0x8032003a, "FWP_E_INCOMPATIBLE_CIPHER_TRANSFORM", "The IPsec cipher transform is not compatible with the policy."),
+	(0x8032003b, "FWP_E_INVALID_TRANSFORM_COMBINATION", "The combination of IPsec transform types is not valid."),
+	(0x8032003c, "FWP_E_DUPLICATE_AUTH_METHOD", "A policy cannot contain the same auth method more than once."),
+	(0x8032003d, "FWP_E_INVALID_TUNNEL_ENDPOINT", "A tunnel endpoint configuration is invalid."),
+	(0x8032003e, "FWP_E_L2_DRIVER_NOT_READY", "The WFP MAC Layers are not ready."),
+	(0x8032003f, "FWP_E_KEY_DICTATOR_ALREADY_REGISTERED", "A key manager capable of key dictation is already registered."),
+	(0x80320040, "FWP_E_KEY_DICTATION_INVALID_KEYING_MATERIAL", "A key manager dictated invalid keys."),
+	(0x80320041, "FWP_E_CONNECTIONS_DISABLED", "The BFE IPsec Connection Tracking is disabled."),
+	(0x80320042, "FWP_E_INVALID_DNS_NAME", "The DNS name is invalid."),
+	(0x80320043, "FWP_E_STILL_ON", "The engine option is still enabled due to other configuration settings."),
+	(0x80320044, "FWP_E_IKEEXT_NOT_RUNNING", "The IKEEXT service is not running. This service only runs when there is IPsec policy applied to the machine."),
+	(0x80320104, "FWP_E_DROP_NOICMP", "The packet should be dropped, no ICMP should be sent."),
+	(0x80370100, "HCS_E_TERMINATED_DURING_START", "The virtual machine or container exited unexpectedly while starting."),
+	(0x80370101, "HCS_E_IMAGE_MISMATCH", "The container operating system does not match the host operating system."),
+	(0x80370102, "HCS_E_HYPERV_NOT_INSTALLED", "The virtual machine could not be started because a required feature is not installed."),
+	(0x80370105, "HCS_E_INVALID_STATE", "The call is not valid for the current state of the virtual machine or container."),
+	(0x80370106, "HCS_E_UNEXPECTED_EXIT", "The virtual machine or container exited unexpectedly."),
+	(0x80370107, "HCS_E_TERMINATED", "The virtual machine or container was forcefully exited."),
+	(0x80370108, "HCS_E_CONNECT_FAILED", "A connection could not be established with the container or virtual machine."),
+	(0x80370109, "HCS_E_CONNECTION_TIMEOUT", "The operation timed out because a response was not received from the virtual machine or container."),
+	(0x8037010a, "HCS_E_CONNECTION_CLOSED", "The connection with the virtual machine or container was closed."),
+	(0x8037010b, "HCS_E_UNKNOWN_MESSAGE", "An unknown internal message was received by the virtual machine or container."),
+	(0x8037010c, "HCS_E_UNSUPPORTED_PROTOCOL_VERSION", "The virtual machine or container does not support an available version of the communication protocol with the host."),
+	(0x8037010d, "HCS_E_INVALID_JSON", "The virtual machine or container JSON document is invalid."),
+	(0x8037010e, "HCS_E_SYSTEM_NOT_FOUND", "A virtual machine or container with the specified identifier does not exist."),
+	(0x8037010f, "HCS_E_SYSTEM_ALREADY_EXISTS", "A virtual machine or container with the specified identifier already exists."),
+	(0x80370110, "HCS_E_SYSTEM_ALREADY_STOPPED", "The virtual machine or container with the specified identifier is not running."),
+	(0x80370111, "HCS_E_PROTOCOL_ERROR", "A communication protocol error has occurred between the virtual machine or container and the host."),
+	(0x80370112, "HCS_E_INVALID_LAYER", "The container image contains a layer with an unrecognized format."),
+	(0x80370113, "HCS_E_WINDOWS_INSIDER_REQUIRED", "To use this container image, you must join the Windows Insider Program. Please see https://go.microsoft.com/fwlink/?linkid=850659 for more information."),
+	(0x80370114, "HCS_E_SERVICE_NOT_AVAILABLE", "The operation could not be started because a required feature is not installed."),
+	(0x80370115, "HCS_E_OPERATION_NOT_STARTED", "The operation has not started."),
+	(0x80370116, "HCS_E_OPERATION_ALREADY_STARTED", "The operation is already running."),
+	(0x80370117, "HCS_E_OPERATION_PENDING", "The operation is still running."),
+	(0x80370118, "HCS_E_OPERATION_TIMEOUT", "The operation did not complete in time."),
+	(0x80370119, "HCS_E_OPERATION_SYSTEM_CALLBACK_ALREADY_SET", "An event callback has already been registered on this handle."),
+	(0x8037011a, "HCS_E_OPERATION_RESULT_ALLOCATION_FAILED", "Not enough memory available to return the result of the operation."),
+	(0x8037011b, "HCS_E_ACCESS_DENIED", "Insufficient privileges. Only administrators or users that are members of the Hyper-V Administrators user group are permitted to access virtual machines or containers. To add yourself to the Hyper-V Administrators user group, please see https://aka.ms/hcsadmin for more information."),
+	(0x8037011c, "HCS_E_GUEST_CRITICAL_ERROR", "The virtual machine or container reported a critical error and was stopped or restarted."),
+	(0x80370300, "WHV_E_UNKNOWN_CAPABILITY", "The specified capability does not exist."),
+	(0x80370301, "WHV_E_INSUFFICIENT_BUFFER", "The specified buffer is too small for the requested data."),
+	(0x80370302, "WHV_E_UNKNOWN_PROPERTY", "The specified property does not exist."),
+	(0x80370303, "WHV_E_UNSUPPORTED_HYPERVISOR_CONFIG", "The configuration of the hypervisor on this system is not supported."),
+	(0x80370304, "WHV_E_INVALID_PARTITION_CONFIG", "The properties of the partition are not valid."),
+	(0x80370305, "WHV_E_GPA_RANGE_NOT_FOUND", "The specified GPA range was not found."),
+	(0x80370306, "WHV_E_VP_ALREADY_EXISTS", "A virtual processor with the specified index already exists."),
+	(0x80370307, "WHV_E_VP_DOES_NOT_EXIST", "A virtual processor with the specified index does not exist."),
+	(0x80370308, "WHV_E_INVALID_VP_STATE", "The virtual processor is not in the correct state to perform the requested operation."),
+	(0x80370309, "WHV_E_INVALID_VP_REGISTER_NAME", "A virtual processor register with the specified name does not exist."),
+	(0x803b0001, "HCN_E_NETWORK_NOT_FOUND", "The network was not found."),
+	(0x803b0002, "HCN_E_ENDPOINT_NOT_FOUND", "The endpoint was not found."),
+	(0x803b0003, "HCN_E_LAYER_NOT_FOUND", "The network's underlying layer was not found."),
+	(0x803b0004, "HCN_E_SWITCH_NOT_FOUND", "The virtual switch was not found."),
+	(0x803b0005, "HCN_E_SUBNET_NOT_FOUND", "The network does not have a subnet for this endpoint."),
+	(0x803b0006, "HCN_E_ADAPTER_NOT_FOUND", "An adapter was not found."),
+	(0x803b0007, "HCN_E_PORT_NOT_FOUND", "The switch-port was not found."),
+	(0x803b0008, "HCN_E_POLICY_NOT_FOUND", "An expected policy was not found."),
+	(0x803b0009, "HCN_E_VFP_PORTSETTING_NOT_FOUND", "A required VFP port setting was not found."),
+	(0x803b000a, "HCN_E_INVALID_NETWORK", "The specified network is invalid."),
+	(0x803b000b, "HCN_E_INVALID_NETWORK_TYPE", "The network type is invalid."),
+	(0x803b000c, "HCN_E_INVALID_ENDPOINT", "The specified endpoint is invalid."),
+	(0x803b000d, "HCN_E_INVALID_POLICY", "The specified policy is invalid."),
+	(0x803b000e, "HCN_E_INVALID_POLICY_TYPE", "The policy type is invalid."),
+	(0x803b000f, "HCN_E_INVALID_REMOTE_ENDPOINT_OPERATION", "This requested operation is invalid for a remote endpoint."),
+	(0x803b0010, "HCN_E_NETWORK_ALREADY_EXISTS", "A network with this name already exists."),
+	(0x803b0011, "HCN_E_LAYER_ALREADY_EXISTS", "A network layer with this name already exists."),
+	(0x803b0012, "HCN_E_POLICY_ALREADY_EXISTS", "Policy information already exists on this object."),
+	(0x803b0013, "HCN_E_PORT_ALREADY_EXISTS", "The specified port already exists."),
+	(0x803b0014, "HCN_E_ENDPOINT_ALREADY_ATTACHED", "This endpoint is already attached to the switch."),
+	(0x803b0015, "HCN_E_REQUEST_UNSUPPORTED", "The specified request is unsupported."),
+	(0x803b0016, "HCN_E_MAPPING_NOT_SUPPORTED", "Port mapping is not supported on the given network."),
+	(0x803b0017, "HCN_E_DEGRADED_OPERATION", "There was an operation attempted on a degraded object."),
+	(0x803b0018, "HCN_E_SHARED_SWITCH_MODIFICATION", "Cannot modify a switch shared by multiple networks."),
+	(0x803b0019, "HCN_E_GUID_CONVERSION_FAILURE", "Failed to interpret a parameter as a GUID."),
+	(0x803b001a, "HCN_E_REGKEY_FAILURE", "Failed to process registry key."),
+	(0x803b001b, "HCN_E_INVALID_JSON", "Invalid JSON document string."),
+	(0x803b001c, "HCN_E_INVALID_JSON_REFERENCE", "The reference is invalid in the JSON document."),
+	(0x803b001d, "HCN_E_ENDPOINT_SHARING_DISABLED", "Endpoint sharing is disabled."),
+	(0x803b001e, "HCN_E_INVALID_IP", "IP address is either invalid or not part of any configured subnet(s)."),
+	(0x803b001f, "HCN_E_SWITCH_EXTENSION_NOT_FOUND", "The specified switch extension does not exist on this switch."),
+	(0x803b0020, "HCN_E_MANAGER_STOPPED", "Operation cannot be performed while service is stopping."),
+	(0x803b0021, "GCN_E_MODULE_NOT_FOUND", "The specified module was not found."),
+	(0x803b0022, "GCN_E_NO_REQUEST_HANDLERS", "No request handlers could be found for this request."),
+	(0x803b0023, "GCN_E_REQUEST_UNSUPPORTED", "The specified request type is unsupported."),
+	(0x803b0024, "GCN_E_RUNTIMEKEYS_FAILED", "Failed to add or remove the runtime keys."),
+	(0x803b0025, "GCN_E_NETADAPTER_TIMEOUT", "The network adapter was not found in time."),
+	(0x803b0026, "GCN_E_NETADAPTER_NOT_FOUND", "The network adapter was not found."),
+	(0x803b0027, "GCN_E_NETCOMPARTMENT_NOT_FOUND", "The network compartment was not found."),
+	(0x803b0028, "GCN_E_NETINTERFACE_NOT_FOUND", "The network interface was not found."),
+	(0x803b0029, "GCN_E_DEFAULTNAMESPACE_EXISTS", "The default network namespace already exists."),
+	(0x803b002a, "HCN_E_ICS_DISABLED", "Internet Connection Sharing service (SharedAccess) is disabled and cannot be started."),
+	(0x803b002b, "HCN_E_ENDPOINT_NAMESPACE_ALREADY_EXISTS", "This requested operation is invalid as endpoint is already part of a network namespace."),
+	(0x803b002c, "HCN_E_ENTITY_HAS_REFERENCES", "The specified entity cannot be removed while it still has references."),
+	(0x803b002d, "HCN_E_INVALID_INTERNAL_PORT", "The internal port must exist and cannot be zero."),
+	(0x803b002e, "HCN_E_NAMESPACE_ATTACH_FAILED", "The requested operation for attach namespace failed."),
+	(0x803b002f, "HCN_E_ADDR_INVALID_OR_RESERVED", "An address provided is invalid or reserved."),
+	(0x803d0000, "WS_E_INVALID_FORMAT", "The input data was not in the expected format or did not have the expected value."),
+	(0x803d0001, "WS_E_OBJECT_FAULTED", "The operation could not be completed because the object is in a faulted state due to a previous error."),
+	(0x803d0002, "WS_E_NUMERIC_OVERFLOW", "The operation could not be completed because it would lead to numeric overflow."),
+	(0x803d0003, "WS_E_INVALID_OPERATION", "The operation is not allowed due to the current state of the object."),
+	(0x803d0004, "WS_E_OPERATION_ABORTED", "The operation was aborted."),
+	(0x803d0005, "WS_E_ENDPOINT_ACCESS_DENIED", "Access was denied by the remote endpoint."),
+	(0x803d0006, "WS_E_OPERATION_TIMED_OUT", "The operation did not complete within the time allotted."),
+	(0x803d0007, "WS_E_OPERATION_ABANDONED", "The operation was abandoned."),
+	(0x803d0008, "WS_E_QUOTA_EXCEEDED", "A quota was exceeded."),
+	(0x803d0009, "WS_E_NO_TRANSLATION_AVAILABLE", "The information was not available in the specified language."),
+	(0x803d000a, "WS_E_SECURITY_VERIFICATION_FAILURE", "Security verification was not successful for the received data."),
+	(0x803d000b, "WS_E_ADDRESS_IN_USE", "The address is already being used."),
+	(0x803d000c, "WS_E_ADDRESS_NOT_AVAILABLE", "The address is not valid for this context."),
+	(0x803d000d, "WS_E_ENDPOINT_NOT_FOUND", "The remote endpoint does not exist or could not be located."),
+	(0x803d000e, "WS_E_ENDPOINT_NOT_AVAILABLE", "The remote endpoint is not currently in service at this location."),
+	(0x803d000f, "WS_E_ENDPOINT_FAILURE", "The remote endpoint could not process the request."),
+	(0x803d0010, "WS_E_ENDPOINT_UNREACHABLE", "The remote endpoint was not reachable."),
+	(0x803d0011, "WS_E_ENDPOINT_ACTION_NOT_SUPPORTED", "The operation was not supported by the remote endpoint."),
+	(0x803d0012, "WS_E_ENDPOINT_TOO_BUSY", "The remote endpoint is unable to process the request due to being overloaded."),
+	(0x803d0013, "WS_E_ENDPOINT_FAULT_RECEIVED", "A message containing a fault was received from the remote endpoint."),
+	(0x803d0014, "WS_E_ENDPOINT_DISCONNECTED", "The connection with the remote endpoint was terminated."),
+	(0x803d0015, "WS_E_PROXY_FAILURE", "The HTTP proxy server could not process the request."),
+	(0x803d0016, "WS_E_PROXY_ACCESS_DENIED", "Access was denied by the HTTP proxy server."),
+	(0x803d0017, "WS_E_NOT_SUPPORTED", "The requested feature is not available on this platform."),
+	(0x803d0018, "WS_E_PROXY_REQUIRES_BASIC_AUTH", "The HTTP proxy server requires HTTP authentication scheme 'basic'."),
+	(0x803d0019, "WS_E_PROXY_REQUIRES_DIGEST_AUTH", "The HTTP proxy server requires HTTP authentication scheme 'digest'."),
+	(0x803d001a, "WS_E_PROXY_REQUIRES_NTLM_AUTH", "The HTTP proxy server requires HTTP authentication scheme 'NTLM'."),
+	(0x803d001b, "WS_E_PROXY_REQUIRES_NEGOTIATE_AUTH", "The HTTP proxy server requires HTTP authentication scheme 'negotiate'."),
+	(0x803d001c, "WS_E_SERVER_REQUIRES_BASIC_AUTH", "The remote endpoint requires HTTP authentication scheme 'basic'."),
+	(0x803d001d, "WS_E_SERVER_REQUIRES_DIGEST_AUTH", "The remote endpoint requires HTTP authentication scheme 'digest'."),
+	(0x803d001e, "WS_E_SERVER_REQUIRES_NTLM_AUTH", "The remote endpoint requires HTTP authentication scheme 'NTLM'."),
+	(0x803d001f, "WS_E_SERVER_REQUIRES_NEGOTIATE_AUTH", "The remote endpoint requires HTTP authentication scheme 'negotiate'."),
+	(0x803d0020, "WS_E_INVALID_ENDPOINT_URL", "A remote endpoint could not process the request because of an invalid endpoint URL."),
+	(0x803d0021, "WS_E_OTHER", "Unrecognized error occurred in the Windows Web Services framework."),
+	(0x803d0022, "WS_E_SECURITY_TOKEN_EXPIRED", "A security token was rejected by the server because it has expired."),
+	(0x803d0023, "WS_E_SECURITY_SYSTEM_FAILURE", "A security operation failed in the Windows Web Services framework."),
+	(0x803e0100, "WPN_E_CHANNEL_CLOSED", "The notification channel has already been closed."),
+	(0x803e0101, "WPN_E_CHANNEL_REQUEST_NOT_COMPLETE", "The notification channel request did not complete successfully."),
+	(0x803e0102, "WPN_E_INVALID_APP", "The application identifier provided is invalid."),
+	(0x803e0103, "WPN_E_OUTSTANDING_CHANNEL_REQUEST", "A notification channel request for the provided application identifier is in progress."),
+	(0x803e0104, "WPN_E_DUPLICATE_CHANNEL", "The channel identifier is already tied to another application endpoint."),
+	(0x803e0105, "WPN_E_PLATFORM_UNAVAILABLE", "The notification platform is unavailable."),
+	(0x803e0106, "WPN_E_NOTIFICATION_POSTED", "The notification has already been posted."),
+	(0x803e0107, "WPN_E_NOTIFICATION_HIDDEN", "The notification has already been hidden."),
+	(0x803e0108, "WPN_E_NOTIFICATION_NOT_POSTED", "The notification cannot be hidden until it has been shown."),
+	(0x803e0109, "WPN_E_CLOUD_DISABLED", "Cloud notifications have been turned off."),
+	(0x803e0110, "WPN_E_CLOUD_INCAPABLE", "The application does not have the cloud notification capability."),
+	(0x803e0111, "WPN_E_NOTIFICATION_DISABLED", "Settings prevent the notification from being delivered."),
+	(0x803e0112, "WPN_E_NOTIFICATION_INCAPABLE", "Application capabilities prevent the notification from being delivered."),
+	(0x803e0113, "WPN_E_INTERNET_INCAPABLE", "The application does not have the internet access capability."),
+	(0x803e0114, "WPN_E_NOTIFICATION_TYPE_DISABLED", "Settings prevent the notification type from being delivered."),
+	(0x803e0115, "WPN_E_NOTIFICATION_SIZE", "The size of the notification content is too large."),
+	(0x803e0116, "WPN_E_TAG_SIZE", "The size of the notification tag is too large."),
+	(0x803e0117, "WPN_E_ACCESS_DENIED", "The notification platform doesn't have appropriate privilege on resources."),
+	(0x803e0118, "WPN_E_DUPLICATE_REGISTRATION", "The notification platform found application is already registered."),
+	(0x803e0119, "WPN_E_PUSH_NOTIFICATION_INCAPABLE", "The application background task does not have the push notification capability."),
+	(0x803e011a, "WPN_E_CLOUD_AUTH_UNAVAILABLE", "The notification platform is unable to retrieve the authentication credentials required to connect to the cloud notification service."),
+	(0x803e011b, "WPN_E_CLOUD_SERVICE_UNAVAILABLE", "The notification platform is unable to connect to the cloud notification service."),
+	(0x803e011c, "WPN_E_FAILED_LOCK_SCREEN_UPDATE_INTIALIZATION", "The notification platform is unable to initialize a callback for lock screen updates."),
+	(0x803e0120, "WPN_E_DEV_ID_SIZE", "The device identifier is too large."),
+	(0x803e012a, "WPN_E_TAG_ALPHANUMERIC", "The notification tag is not alphanumeric."),
+	(0x803e012b, "WPN_E_INVALID_HTTP_STATUS_CODE", "The notification platform has received invalid HTTP status code other than 2xx for polling."),
+	(0x803e0200, "WPN_E_OUT_OF_SESSION", "The notification platform has run out of presentation layer sessions."),
+	(0x803e0201, "WPN_E_POWER_SAVE", "The notification platform rejects image download request due to system in power save mode."),
+	(0x803e0202, "WPN_E_IMAGE_NOT_FOUND_IN_CACHE", "The notification platform doesn't have the requested image in its cache."),
+	(0x803e0203, "WPN_E_ALL_URL_NOT_COMPLETED", "The notification platform cannot complete all of requested image."),
+	(0x803e0204, "WPN_E_INVALID_CLOUD_IMAGE", "A cloud image downloaded from the notification platform is invalid."),
+	(0x803e0205, "WPN_E_NOTIFICATION_ID_MATCHED", "Notification Id provided as filter is matched with what the notification platform maintains."),
+	(0x803e0206, "WPN_E_CALLBACK_ALREADY_REGISTERED", "Notification callback interface is already registered."),
+	(0x803e0207, "WPN_E_TOAST_NOTIFICATION_DROPPED", "Toast Notification was dropped without being displayed to the user."),
+	(0x803e0208, "WPN_E_STORAGE_LOCKED", "The notification platform does not have the proper privileges to complete the request."),
+	(0x803e0209, "WPN_E_GROUP_SIZE", "The size of the notification group is too large."),
+	(0x803e020a, "WPN_E_GROUP_ALPHANUMERIC", "The notification group is not alphanumeric."),
+	(0x803e020b, "WPN_E_CLOUD_DISABLED_FOR_APP", "Cloud notifications have been disabled for the application due to a policy setting."),
+	(0x80400000, "INPUT_E_OUT_OF_ORDER", "Input data cannot be processed in the non-chronological order."),
+	(0x80400001, "INPUT_E_REENTRANCY", "Requested operation cannot be performed inside the callback or event handler."),
+	(0x80400002, "INPUT_E_MULTIMODAL", "Input cannot be processed because there is ongoing interaction with another pointer type."),
+	(0x80400003, "INPUT_E_PACKET", "One or more fields in the input packet are invalid."),
+	(0x80400004, "INPUT_E_FRAME", "Packets in the frame are inconsistent. Either pointer ids are not unique or there is a discrepancy in timestamps, frame ids, pointer types or source devices."),
+	(0x80400005, "INPUT_E_HISTORY", "The history of frames is inconsistent. Pointer ids, types, source devices don't match, or frame ids are not unique, or timestamps are out of order."),
+	(0x80400006, "INPUT_E_DEVICE_INFO", "Failed to retrieve information about the input device."),
+	(0x80400007, "INPUT_E_TRANSFORM", "Coordinate system transformation failed to transform the data."),
+	(0x80400008, "INPUT_E_DEVICE_PROPERTY", "The property is not supported or not reported correctly by the input device."),
+	(0x80548201, "E_MBN_CONTEXT_NOT_ACTIVATED", "Context is not activated."),
+	(0x80548202, "E_MBN_BAD_SIM", "Bad SIM is inserted."),
+	(0x80548203, "E_MBN_DATA_CLASS_NOT_AVAILABLE", "Requested data class is not available."),
+	(0x80548204, "E_MBN_INVALID_ACCESS_STRING", "Access point name (APN) or Access string is incorrect."),
+	(0x80548205, "E_MBN_MAX_ACTIVATED_CONTEXTS", "Max activated contexts have reached."),
+	(0x80548206, "E_MBN_PACKET_SVC_DETACHED", "Device is in packet detach state."),
+	(0x80548207, "E_MBN_PROVIDER_NOT_VISIBLE", "Provider is not visible."),
+	(0x80548208, "E_MBN_RADIO_POWER_OFF", "Radio is powered off."),
+	(0x80548209, "E_MBN_SERVICE_NOT_ACTIVATED", "MBN subscription is not activated."),
+	(0x8054820a, "E_MBN_SIM_NOT_INSERTED", "SIM is not inserted."),
+	(0x8054820b, "E_MBN_VOICE_CALL_IN_PROGRESS", "Voice call in progress."),
+	(0x8054820c, "E_MBN_INVALID_CACHE", "Visible provider cache is invalid."),
+	(0x8054820d, "E_MBN_NOT_REGISTERED", "Device is not registered."),
+	(0x8054820e, "E_MBN_PROVIDERS_NOT_FOUND", "Providers not found."),
+	(0x8054820f, "E_MBN_PIN_NOT_SUPPORTED", "Pin is not supported."),
+	(0x80548210, "E_MBN_PIN_REQUIRED", "Pin is required."),
+	(0x80548211, "E_MBN_PIN_DISABLED", "PIN is disabled."),
+	(0x80548212, "E_MBN_FAILURE", "Generic Failure."),
+	(0x80548218, "E_MBN_INVALID_PROFILE", "Profile is invalid."),
+	(0x80548219, "E_MBN_DEFAULT_PROFILE_EXIST", "Default profile exist."),
+	(0x80548220, "E_MBN_SMS_ENCODING_NOT_SUPPORTED", "SMS encoding is not supported."),
+	(0x80548221, "E_MBN_SMS_FILTER_NOT_SUPPORTED", "SMS filter is not supported."),
+	(0x80548222, "E_MBN_SMS_INVALID_MEMORY_INDEX", "Invalid SMS memory index is used."),
+	(0x80548223, "E_MBN_SMS_LANG_NOT_SUPPORTED", "SMS language is not supported."),
+	(0x80548224, "E_MBN_SMS_MEMORY_FAILURE", "SMS memory failure occurred."),
+	(0x80548225, "E_MBN_SMS_NETWORK_TIMEOUT", "SMS network timeout happened."),
+	(0x80548226, "E_MBN_SMS_UNKNOWN_SMSC_ADDRESS", "Unknown SMSC address is used."),
+	(0x80548227, "E_MBN_SMS_FORMAT_NOT_SUPPORTED", "SMS format is not supported."),
+	(0x80548228, "E_MBN_SMS_OPERATION_NOT_ALLOWED", "SMS operation is not allowed."),
+	(0x80548229, "E_MBN_SMS_MEMORY_FULL", "Device SMS memory is full."),
+	(0x80550001, "EAS_E_POLICY_NOT_MANAGED_BY_OS", "The requested policy is not managed by the operating system."),
+	(0x80550002, "EAS_E_POLICY_COMPLIANT_WITH_ACTIONS", "The system can be made compliant to the requested policy but certain actions need to be taken first."),
+	(0x80550003, "EAS_E_REQUESTED_POLICY_NOT_ENFORCEABLE", "The system cannot enforce compliance to the requested policy."),
+	(0x80550004, "EAS_E_CURRENT_USER_HAS_BLANK_PASSWORD", "The current user has a blank password."),
+	(0x80550005, "EAS_E_REQUESTED_POLICY_PASSWORD_EXPIRATION_INCOMPATIBLE", "The requested password expiration policy is incompatible with the user's password."),
+	(0x80550006, "EAS_E_USER_CANNOT_CHANGE_PASSWORD", "The user cannot change their password."),
+	(0x80550007, "EAS_E_ADMINS_HAVE_BLANK_PASSWORD", "One or more administrator accounts have blank passwords."),
+	(0x80550008, "EAS_E_ADMINS_CANNOT_CHANGE_PASSWORD", "One or more administrators cannot change their password."),
+	(0x80550009, "EAS_E_LOCAL_CONTROLLED_USERS_CANNOT_CHANGE_PASSWORD", "One or more local users cannot change their password."),
+	(0x8055000a, "EAS_E_PASSWORD_POLICY_NOT_ENFORCEABLE_FOR_CONNECTED_ADMINS", "The password policy cannot be enforced for one or more connected administrator accounts."),
+	(0x8055000b, "EAS_E_CONNECTED_ADMINS_NEED_TO_CHANGE_PASSWORD", "One or more connected administrator accounts need to change their password."),
+	(0x8055000c, "EAS_E_PASSWORD_POLICY_NOT_ENFORCEABLE_FOR_CURRENT_CONNECTED_USER", "The password policy cannot be enforced for the current connected user account."),
+	(0x8055000d, "EAS_E_CURRENT_CONNECTED_USER_NEED_TO_CHANGE_PASSWORD", "The current connected user account needs to change its password."),
+	(0x80630001, "PEER_E_IPV6_NOT_INSTALLED", "The IPv6 protocol is not installed."),
+	(0x80630002, "PEER_E_NOT_INITIALIZED", "The component has not been initialized."),
+	(0x80630003, "PEER_E_CANNOT_START_SERVICE", "The required service cannot be started."),
+	(0x80630004, "PEER_E_NOT_LICENSED", "The P2P protocol is not licensed to run on this OS."),
+	(0x80630010, "PEER_E_INVALID_GRAPH", "The graph handle is invalid."),
+	(0x80630011, "PEER_E_DBNAME_CHANGED", "The graph database name has changed."),
+	(0x80630012, "PEER_E_DUPLICATE_GRAPH", "A graph with the same ID already exists."),
+	(0x80630013, "PEER_E_GRAPH_NOT_READY", "The graph is not ready."),
+	(0x80630014, "PEER_E_GRAPH_SHUTTING_DOWN", "The graph is shutting down."),
+	(0x80630015, "PEER_E_GRAPH_IN_USE", "The graph is still in use."),
+	(0x80630016, "PEER_E_INVALID_DATABASE", "The graph database is corrupt."),
+	(0x80630017, "PEER_E_TOO_MANY_ATTRIBUTES", "Too many attributes have been used."),
+	(0x80630103, "PEER_E_CONNECTION_NOT_FOUND", "The connection can not be found."),
+	(0x80630106, "PEER_E_CONNECT_SELF", "The peer attempted to connect to itself."),
+	(0x80630107, "PEER_E_ALREADY_LISTENING", "The peer is already listening for connections."),
+	(0x80630108, "PEER_E_NODE_NOT_FOUND", "The node was not found."),
+	(0x80630109, "PEER_E_CONNECTION_FAILED", "The Connection attempt failed."),
+	(0x8063010a, "PEER_E_CONNECTION_NOT_AUTHENTICATED", "The peer connection could not be authenticated."),
+	(0x8063010b, "PEER_E_CONNECTION_REFUSED", "The connection was refused."),
+	(0x80630201, "PEER_E_CLASSIFIER_TOO_LONG", "The peer name classifier is too long."),
+	(0x80630202, "PEER_E_TOO_MANY_IDENTITIES", "The maximum number of identities have been created."),
+	(0x80630203, "PEER_E_NO_KEY_ACCESS", "Unable to access a key."),
+	(0x80630204, "PEER_E_GROUPS_EXIST", "The group already exists."),
+	(0x80630301, "PEER_E_RECORD_NOT_FOUND", "The requested record could not be found."),
+	(0x80630302, "PEER_E_DATABASE_ACCESSDENIED", "Access to the database was denied."),
+	(0x80630303, "PEER_E_DBINITIALIZATION_FAILED", "The Database could not be initialized."),
+	(0x80630304, "PEER_E_MAX_RECORD_SIZE_EXCEEDED", "The record is too big."),
+	(0x80630305, "PEER_E_DATABASE_ALREADY_PRESENT", "The database already exists."),
+	(0x80630306, "PEER_E_DATABASE_NOT_PRESENT", "The database could not be found."),
+	(0x80630401, "PEER_E_IDENTITY_NOT_FOUND", "The identity could not be found."),
+	(0x80630501, "PEER_E_EVENT_HANDLE_NOT_FOUND", "The event handle could not be found."),
+	(0x80630601, "PEER_E_INVALID_SEARCH", "Invalid search."),
+	(0x80630602, "PEER_E_INVALID_ATTRIBUTES", "The search attributes are invalid."),
+	(0x80630701, "PEER_E_INVITATION_NOT_TRUSTED", "The invitation is not trusted."),
+	(0x80630703, "PEER_E_CHAIN_TOO_LONG", "The certchain is too long."),
+	(0x80630705, "PEER_E_INVALID_TIME_PERIOD", "The time period is invalid."),
+	(0x80630706, "PEER_E_CIRCULAR_CHAIN_DETECTED", "A circular cert chain was detected."),
+	(0x80630801, "PEER_E_CERT_STORE_CORRUPTED", "The certstore is corrupted."),
+	(0x80631001, "PEER_E_NO_CLOUD", "The specified PNRP cloud does not exist."),
+	(0x80631005, "PEER_E_CLOUD_NAME_AMBIGUOUS", "The cloud name is ambiguous."),
+	(0x80632010, "PEER_E_INVALID_RECORD", "The record is invalid."),
+	(0x80632020, "PEER_E_NOT_AUTHORIZED", "Not authorized."),
+	(0x80632021, "PEER_E_PASSWORD_DOES_NOT_MEET_POLICY", "The password does not meet policy requirements."),
+	(0x80632030, "PEER_E_DEFERRED_VALIDATION", "The record validation has been deferred."),
+	(0x80632040, "PEER_E_INVALID_GROUP_PROPERTIES", "The group properties are invalid."),
+	(0x80632050, "PEER_E_INVALID_PEER_NAME", "The peername is invalid."),
+	(0x80632060, "PEER_E_INVALID_CLASSIFIER", "The classifier is invalid."),
+	(0x80632070, "PEER_E_INVALID_FRIENDLY_NAME", "The friendly name is invalid."),
+	(0x80632071, "PEER_E_INVALID_ROLE_PROPERTY", "Invalid role property."),
+	(0x80632072, "PEER_E_INVALID_CLASSIFIER_PROPERTY", "Invalid classifier property."),
+	(0x80632080, "PEER_E_INVALID_RECORD_EXPIRATION", "Invalid record expiration."),
+	(0x80632081, "PEER_E_INVALID_CREDENTIAL_INFO", "Invalid credential info."),
+	(0x80632082, "PEER_E_INVALID_CREDENTIAL", "Invalid credential."),
+	(0x80632083, "PEER_E_INVALID_RECORD_SIZE", "Invalid record size."),
+	(0x80632090, "PEER_E_UNSUPPORTED_VERSION", "Unsupported version."),
+	(0x80632091, "PEER_E_GROUP_NOT_READY", "The group is not ready."),
+	(0x80632092, "PEER_E_GROUP_IN_USE", "The group is still in use."),
+	(0x80632093, "PEER_E_INVALID_GROUP", "The group is invalid."),
+	(0x80632094, "PEER_E_NO_MEMBERS_FOUND", "No members were found."),
+	(0x80632095, "PEER_E_NO_MEMBER_CONNECTIONS", "There are no member connections."),
+	(0x80632096, "PEER_E_UNABLE_TO_LISTEN", "Unable to listen."),
+	(0x806320a0, "PEER_E_IDENTITY_DELETED", "The identity does not exist."),
+	(0x806320a1, "PEER_E_SERVICE_NOT_AVAILABLE", "The service is not available."),
+	(0x80634001, "PEER_E_CANNOT_CONVERT_PEER_NAME", "Unable to convert the peer name."),
+	(0x80634002, "PEER_E_INVALID_PEER_HOST_NAME", "The peer host name is invalid."),
+	(0x80634003, "PEER_E_NO_MORE", "No more data could be found."),
+	(0x80634005, "PEER_E_PNRP_DUPLICATE_PEER_NAME", "The existing peer name is already registered."),
+	(0x80636001, "PEER_E_CONTACT_NOT_FOUND", "The contact could not be found."),
+	(0x80637000, "PEER_E_INVITE_CANCELLED", "The invitation was cancelled."),
+	(0x80637001, "PEER_E_INVITE_RESPONSE_NOT_AVAILABLE", "No response to the invitation has been received."),
+	(0x80637003, "PEER_E_NOT_SIGNED_IN", "The user is not signed into serverless presence."),
+	(0x80637004, "PEER_E_PRIVACY_DECLINED", "The user declined the privacy policy prompt."),
+	(0x80637005, "PEER_E_TIMEOUT", "A timeout occurred."),
+	(0x80637007, "PEER_E_INVALID_ADDRESS", "The address is invalid."),
+	(0x80637008, "PEER_E_FW_EXCEPTION_DISABLED", "A required firewall exception is disabled."),
+	(0x80637009, "PEER_E_FW_BLOCKED_BY_POLICY", "The service is blocked by a firewall policy."),
+	(0x8063700a, "PEER_E_FW_BLOCKED_BY_SHIELDS_UP", "Firewall exceptions are disabled."),
+	(0x8063700b, "PEER_E_FW_DECLINED", "The user declined to enable the firewall exceptions."),
+	(0x80650001, "E_BLUETOOTH_ATT_INVALID_HANDLE", "The attribute handle given was not valid on this server."),
+	(0x80650002, "E_BLUETOOTH_ATT_READ_NOT_PERMITTED", "The attribute cannot be read."),
+	(0x80650003, "E_BLUETOOTH_ATT_WRITE_NOT_PERMITTED", "The attribute cannot be written."),
+	(0x80650004, "E_BLUETOOTH_ATT_INVALID_PDU", "The attribute PDU was invalid."),
+	(0x80650005, "E_BLUETOOTH_ATT_INSUFFICIENT_AUTHENTICATION", "The attribute requires authentication before it can be read or written."),
+	(0x80650006, "E_BLUETOOTH_ATT_REQUEST_NOT_SUPPORTED", "Attribute server does not support the request received from the client."),
+	(0x80650007, "E_BLUETOOTH_ATT_INVALID_OFFSET", "Offset specified was past the end of the attribute."),
+	(0x80650008, "E_BLUETOOTH_ATT_INSUFFICIENT_AUTHORIZATION", "The attribute requires authorization before it can be read or written."),
+	(0x80650009, "E_BLUETOOTH_ATT_PREPARE_QUEUE_FULL", "Too many prepare writes have been queued."),
+	(0x8065000a, "E_BLUETOOTH_ATT_ATTRIBUTE_NOT_FOUND", "No attribute found within the given attribute handle range."),
+	(0x8065000b, "E_BLUETOOTH_ATT_ATTRIBUTE_NOT_LONG", "The attribute cannot be read or written using the Read Blob Request."),
+	(0x8065000c, "E_BLUETOOTH_ATT_INSUFFICIENT_ENCRYPTION_KEY_SIZE", "The Encryption Key Size used for encrypting this link is insufficient."),
+	(0x8065000d, "E_BLUETOOTH_ATT_INVALID_ATTRIBUTE_VALUE_LENGTH", "The attribute value length is invalid for the operation."),
+	(0x8065000e, "E_BLUETOOTH_ATT_UNLIKELY", "The attribute request that was requested has encountered an error that was unlikely, and therefore could not be completed as requested."),
+	(0x8065000f, "E_BLUETOOTH_ATT_INSUFFICIENT_ENCRYPTION", "The attribute requires encryption before it can be read or written."),
+	(0x80650010, "E_BLUETOOTH_ATT_UNSUPPORTED_GROUP_TYPE", "The attribute type is not a supported grouping attribute as defined by a higher layer specification."),
+	(0x80650011, "E_BLUETOOTH_ATT_INSUFFICIENT_RESOURCES", "Insufficient Resources to complete the request."),
+	(0x80651000, "E_BLUETOOTH_ATT_UNKNOWN_ERROR", "An error that lies in the reserved range has been received."),
+	(0x80660001, "E_AUDIO_ENGINE_NODE_NOT_FOUND", "PortCls could not find an audio engine node exposed by a miniport driver claiming support for IMiniportAudioEngineNode."),
+	(0x80660002, "E_HDAUDIO_EMPTY_CONNECTION_LIST", "HD Audio widget encountered an unexpected empty connection list."),
+	(0x80660003, "E_HDAUDIO_CONNECTION_LIST_NOT_SUPPORTED", "HD Audio widget does not support the connection list parameter."),
+	(0x80660004, "E_HDAUDIO_NO_LOGICAL_DEVICES_CREATED", "No HD Audio subdevices were successfully created."),
+	(0x80660005, "E_HDAUDIO_NULL_LINKED_LIST_ENTRY", "An unexpected NULL pointer was encountered in a linked list."),
+	(0x80670001, "STATEREPOSITORY_E_CONCURRENCY_LOCKING_FAILURE", "Optimistic locking failure. Data cannot be updated if it has changed since it was read."),
+	(0x80670002, "STATEREPOSITORY_E_STATEMENT_INPROGRESS", "A prepared statement has been stepped at least once but not run to completion or reset. This may result in busy waits."),
+	(0x80670003, "STATEREPOSITORY_E_CONFIGURATION_INVALID", "The StateRepository configuration is not valid."),
+	(0x80670004, "STATEREPOSITORY_E_UNKNOWN_SCHEMA_VERSION", "The StateRepository schema version is not known."),
+	(0x80670005, "STATEREPOSITORY_ERROR_DICTIONARY_CORRUPTED", "A StateRepository dictionary is not valid."),
+	(0x80670006, "STATEREPOSITORY_E_BLOCKED", "The request failed because the StateRepository is actively blocking requests."),
+	(0x80670007, "STATEREPOSITORY_E_BUSY_RETRY", "The database file is locked. The request will be retried."),
+	(0x80670008, "STATEREPOSITORY_E_BUSY_RECOVERY_RETRY", "The database file is locked because another process is busy recovering the database. The request will be retried."),
+	(0x80670009, "STATEREPOSITORY_E_LOCKED_RETRY", "A table in the database is locked. The request will be retried."),
+	(0x8067000a, "STATEREPOSITORY_E_LOCKED_SHAREDCACHE_RETRY", "The shared cache for the database is locked by another connection. The request will be retried."),
+	(0x8067000b, "STATEREPOSITORY_E_TRANSACTION_REQUIRED", "A transaction is required to perform the request operation."),
+	(0x8067000c, "STATEREPOSITORY_E_BUSY_TIMEOUT_EXCEEDED", "The database file is locked. The request has exceeded the allowed threshold."),
+	(0x8067000d, "STATEREPOSITORY_E_BUSY_RECOVERY_TIMEOUT_EXCEEDED", "The database file is locked because another process is busy recovering the database. The request has exceeded the allowed threshold."),
+	(0x8067000e, "STATEREPOSITORY_E_LOCKED_TIMEOUT_EXCEEDED", "A table in the database is locked. The request has exceeded the allowed threshold."),
+	(0x8067000f, "STATEREPOSITORY_E_LOCKED_SHAREDCACHE_TIMEOUT_EXCEEDED", "The shared cache for the database is locked by another connection. The request has exceeded the allowed threshold."),
+	(0x80670010, "STATEREPOSITORY_E_SERVICE_STOP_IN_PROGRESS", "The StateRepository service Stop event is in progress."),
+	(0x80670011, "STATEREPOSTORY_E_NESTED_TRANSACTION_NOT_SUPPORTED", "Nested transactions are not supported."),
+	(0x80670012, "STATEREPOSITORY_ERROR_CACHE_CORRUPTED", "The StateRepository cache is not valid."),
+	(0x80820001, "ERROR_VOLSNAP_BOOTFILE_NOT_VALID", "The bootfile is too small to support persistent snapshots."),
+	(0x80820002, "ERROR_VOLSNAP_ACTIVATION_TIMEOUT", "Activation of persistent snapshots on this volume took longer than was allowed."),
+	(0x80830001, "ERROR_TIERING_NOT_SUPPORTED_ON_VOLUME", "The specified volume does not support storage tiers."),
+	(0x80830002, "ERROR_TIERING_VOLUME_DISMOUNT_IN_PROGRESS", "The Storage Tiers Management service detected that the specified volume is in the process of being dismounted."),
+	(0x80830003, "ERROR_TIERING_STORAGE_TIER_NOT_FOUND", "The specified storage tier could not be found on the volume. Confirm that the storage tier name is valid."),
+	(0x80830004, "ERROR_TIERING_INVALID_FILE_ID", "The file identifier specified is not valid on the volume."),
+	(0x80830005, "ERROR_TIERING_WRONG_CLUSTER_NODE", "Storage tier operations must be called on the clustering node that owns the metadata volume."),
+	(0x80830006, "ERROR_TIERING_ALREADY_PROCESSING", "The Storage Tiers Management service is already optimizing the storage tiers on the specified volume."),
+	(0x80830007, "ERROR_TIERING_CANNOT_PIN_OBJECT", "The requested object type cannot be assigned to a storage tier."),
+	(0x80830008, "ERROR_TIERING_FILE_IS_NOT_PINNED", "The requested file is not pinned to a tier."),
+	(0x80830009, "ERROR_NOT_A_TIERED_VOLUME", "The volume is not a tiered volume."),
+	(0x8083000a, "ERROR_ATTRIBUTE_NOT_PRESENT", "The requested attribute is not present on the specified file or directory."),
+	(0x80860001, "ONL_E_INVALID_AUTHENTICATION_TARGET", "Authentication target is invalid or not configured correctly."),
+	(0x80860002, "ONL_E_ACCESS_DENIED_BY_TOU", "Your application cannot get the Online Id properties due to the Terms of Use accepted by the user."),
+	(0x80860003, "ONL_E_INVALID_APPLICATION", "The application requesting authorization has been revoked."),
+	(0x80860004, "ONL_E_PASSWORD_UPDATE_REQUIRED", "The online password must be updated before signing in with an online ID."),
+	(0x80860005, "ONL_E_ACCOUNT_UPDATE_REQUIRED", "The online account properties must be updated before signing in with an online ID."),
+	(0x80860006, "ONL_E_FORCESIGNIN", "To help protect your online ID, please sign in again."),
+	(0x80860007, "ONL_E_ACCOUNT_LOCKED", "The online ID account was locked."),
+	(0x80860008, "ONL_E_PARENTAL_CONSENT_REQUIRED", "The online ID account requires parental consent before proceeding."),
+	(0x80860009, "ONL_E_EMAIL_VERIFICATION_REQUIRED", "The online ID requires email verification before proceeding."),
+	(0x8086000a, "ONL_E_ACCOUNT_SUSPENDED_COMPROIMISE", "We have noticed some unusual activity in your online ID account. Your action is needed to make sure no one else is using your account."),
+	(0x8086000b, "ONL_E_ACCOUNT_SUSPENDED_ABUSE", "We detected some suspicious activity with your online ID account. To help protect you, we've temporarily blocked your account."),
+	(0x8086000c, "ONL_E_ACTION_REQUIRED", "User interaction is required for authentication."),
+	(0x8086000d, "ONL_CONNECTION_COUNT_LIMIT", "User has reached the maximum device associations per user limit."),
+	(0x8086000e, "ONL_E_CONNECTED_ACCOUNT_CAN_NOT_SIGNOUT", "Cannot sign out from the application since the user account is connected."),
+	(0x8086000f, "ONL_E_USER_AUTHENTICATION_REQUIRED", "User authentication is required for this operation."),
+	(0x80860010, "ONL_E_REQUEST_THROTTLED", "We want to make sure this is you. User interaction is required for authentication."),
+	(0x80b00001, "ERROR_DBG_CREATE_PROCESS_FAILURE_LOCKDOWN", "Could not create the process because of a lockdown policy."),
+	(0x80b00002, "ERROR_DBG_ATTACH_PROCESS_FAILURE_LOCKDOWN", "Could not attach the debugger to the process because of a lockdown policy."),
+	(0x80b00003, "ERROR_DBG_CONNECT_SERVER_FAILURE_LOCKDOWN", "Could not connect to the debugger server because of a lockdown policy."),
+	(0x80b00004, "ERROR_DBG_START_SERVER_FAILURE_LOCKDOWN", "Could not start the debugger server because of a lockdown policy."),
+	(0x80e70001, "ERROR_SPACES_FAULT_DOMAIN_TYPE_INVALID", "The specified fault domain type or combination of minimum / maximum fault domain type is not supported."),
+	(0x80e70002, "ERROR_SPACES_INTERNAL_ERROR", "A fault occurred in the storage spaces internal implementation."),
+	(0x80e70003, "ERROR_SPACES_RESILIENCY_TYPE_INVALID", "The value for the resiliency type is not valid."),
+	(0x80e70004, "ERROR_SPACES_DRIVE_SECTOR_SIZE_INVALID", "The sector size of the physical disk is not supported by the storage pool."),
+	(0x80e70006, "ERROR_SPACES_DRIVE_REDUNDANCY_INVALID", "The value for the fault tolerance is outside of the supported range of values."),
+	(0x80e70007, "ERROR_SPACES_NUMBER_OF_DATA_COPIES_INVALID", "The number of data copies requested is not supported."),
+	(0x80e70008, "ERROR_SPACES_PARITY_LAYOUT_INVALID", "The value for the parity layout is outside of the supported range of values."),
+	(0x80e70009, "ERROR_SPACES_INTERLEAVE_LENGTH_INVALID", "The value for the interleave length is outside of the supported range of values or is not a power of 2."),
+	(0x80e7000a, "ERROR_SPACES_NUMBER_OF_COLUMNS_INVALID", "The number of columns specified is outside of the supported range of values."),
+	(0x80e7000b, "ERROR_SPACES_NOT_ENOUGH_DRIVES", "There were not enough physical disks to complete the requested operation."),
+	(0x80e7000c, "ERROR_SPACES_EXTENDED_ERROR", "Extended error information is available."),
+	(0x80e7000d, "ERROR_SPACES_PROVISIONING_TYPE_INVALID", "The specified provisioning type is not valid."),
+	(0x80e7000e, "ERROR_SPACES_ALLOCATION_SIZE_INVALID", "The allocation size is outside of the supported range of values."),
+	(0x80e7000f, "ERROR_SPACES_ENCLOSURE_AWARE_INVALID", "Enclosure awareness is not supported for this virtual disk."),
+	(0x80e70010, "ERROR_SPACES_WRITE_CACHE_SIZE_INVALID", "The write cache size is outside of the supported range of values."),
+	(0x80e70011, "ERROR_SPACES_NUMBER_OF_GROUPS_INVALID", "The value for the number of groups is outside of the supported range of values."),
+	(0x80e70012, "ERROR_SPACES_DRIVE_OPERATIONAL_STATE_INVALID", "The OperationalState of the physical disk is invalid for this operation."),
+	(0x80e70013, "ERROR_SPACES_ENTRY_INCOMPLETE", "The entry is incomplete."),
+	(0x80e70014, "ERROR_SPACES_ENTRY_INVALID", "The entry is invalid."),
+	(0x83750001, "WEB_E_UNSUPPORTED_FORMAT", "The input data was not in the expected format or did not have the expected value."),
+	(0x83750002, "WEB_E_INVALID_XML", "The XML provided is not valid."),
+	(0x83750003, "WEB_E_MISSING_REQUIRED_ELEMENT", "A required element is missing."),
+	(0x83750004, "WEB_E_MISSING_REQUIRED_ATTRIBUTE", "A required attribute is missing."),
+	(0x83750005, "WEB_E_UNEXPECTED_CONTENT", "Unexpected content was found."),
+	(0x83750006, "WEB_E_RESOURCE_TOO_LARGE", "The resource is too large."),
+	(0x83750007, "WEB_E_INVALID_JSON_STRING", "Invalid JSON string."),
+	(0x83750008, "WEB_E_INVALID_JSON_NUMBER", "Invalid JSON number."),
+	(0x83750009, "WEB_E_JSON_VALUE_NOT_FOUND", "JSON value not found."),
+	(0x83760001, "E_INVALID_PROTOCOL_OPERATION", "Invalid operation performed by the protocol."),
+	(0x83760002, "E_INVALID_PROTOCOL_FORMAT", "Invalid data format for the specific protocol operation."),
+	(0x83760003, "E_PROTOCOL_EXTENSIONS_NOT_SUPPORTED", "Protocol extensions are not supported."),
+	(0x83760004, "E_SUBPROTOCOL_NOT_SUPPORTED", "Subprotocol is not supported."),
+	(0x83760005, "E_PROTOCOL_VERSION_NOT_SUPPORTED", "Incorrect protocol version."),
+	(0x87af0001, "SQLITE_E_ERROR", "SQL error or missing database."),
+	(0x87af0002, "SQLITE_E_INTERNAL", "Internal logic error in SQLite."),
+	(0x87af0003, "SQLITE_E_PERM", "Access permission denied."),
+	(0x87af0004, "SQLITE_E_ABORT", "Callback routine requested an abort."),
+	(0x87af0005, "SQLITE_E_BUSY", "The database file is locked."),
+	(0x87af0006, "SQLITE_E_LOCKED", "A table in the database is locked."),
+	(0x87af0007, "SQLITE_E_NOMEM", "A malloc() failed."),
+	(0x87af0008, "SQLITE_E_READONLY", "Attempt to write a readonly database."),
+	(0x87af0009, "SQLITE_E_INTERRUPT", "Operation terminated by sqlite3_interrupt()."),
+	(0x87af000a, "SQLITE_E_IOERR", "Some kind of disk I/O error occurred."),
+	(0x87af000b, "SQLITE_E_CORRUPT", "The database disk image is malformed."),
+	(0x87af000c, "SQLITE_E_NOTFOUND", "Unknown opcode in sqlite3_file_control()."),
+	(0x87af000d, "SQLITE_E_FULL", "Insertion failed because database is full."),
+	(0x87af000e, "SQLITE_E_CANTOPEN", "Unable to open the database file."),
+	(0x87af000f, "SQLITE_E_PROTOCOL", "Database lock protocol error."),
+	(0x87af0010, "SQLITE_E_EMPTY", "Database is empty."),
+	(0x87af0011, "SQLITE_E_SCHEMA", "The database schema changed."),
+	(0x87af0012, "SQLITE_E_TOOBIG", "String or BLOB exceeds size limit."),
+	(0x87af0013, "SQLITE_E_CONSTRAINT", "Abort due to constraint violation."),
+	(0x87af0014, "SQLITE_E_MISMATCH", "Data type mismatch."),
+	(0x87af0015, "SQLITE_E_MISUSE", "Library used incorrectly."),
+	(0x87af0016, "SQLITE_E_NOLFS", "Uses OS features not supported on host."),
+	(0x87af0017, "SQLITE_E_AUTH", "Authorization denied."),
+	(0x87af0018, "SQLITE_E_FORMAT", "Auxiliary database format error."),
+	(0x87af0019, "SQLITE_E_RANGE", "2nd parameter to sqlite3_bind out of range."),
+	(0x87af001a, "SQLITE_E_NOTADB", "File opened that is not a database file."),
+	(0x87af001b, "SQLITE_E_NOTICE", "Notifications from sqlite3_log()."),
+	(0x87af001c, "SQLITE_E_WARNING", "Warnings from sqlite3_log()."),
+	(0x87af0064, "SQLITE_E_ROW", "sqlite3_step() has another row ready."),
+	(0x87af0065, "SQLITE_E_DONE", "sqlite3_step() has finished executing."),
+	(0x87af0105, "SQLITE_E_BUSY_RECOVERY", "The database file is locked because another process is recovering it."),
+	(0x87af0106, "SQLITE_E_LOCKED_SHAREDCACHE", "The shared cache for the database is locked by another connection."),
+	(0x87af0108, "SQLITE_E_READONLY_RECOVERY", "The database needs recovery but is read-only."),
+	(0x87af010a, "SQLITE_E_IOERR_READ", "An I/O error occurred while reading from the database file."),
+	(0x87af010b, "SQLITE_E_CORRUPT_VTAB", "The content of a virtual table is corrupt."),
+	(0x87af010e, "SQLITE_E_CANTOPEN_NOTEMPDIR", "Unable to open a temporary file because no temporary directory is available."),
+	(0x87af0113, "SQLITE_E_CONSTRAINT_CHECK", "A CHECK constraint failed."),
+	(0x87af011b, "SQLITE_E_NOTICE_RECOVER_WAL", "A WAL mode database file was recovered."),
+	(0x87af011c, "SQLITE_E_WARNING_AUTOINDEX", "An automatic index was created for a query."),
+	(0x87af0204, "SQLITE_E_ABORT_ROLLBACK", "The statement was aborted because the transaction was rolled back."),
+	(0x87af0205, "SQLITE_E_BUSY_SNAPSHOT", "The read transaction is no longer on the most recent snapshot of the database."),
+	(0x87af0208, "SQLITE_E_READONLY_CANTLOCK", "Unable to obtain a read lock on the shared-memory file of a read-only WAL database."),
+	(0x87af020a, "SQLITE_E_IOERR_SHORT_READ", "A read from the database file returned fewer bytes than requested."),
+	(0x87af020e, "SQLITE_E_CANTOPEN_ISDIR", "The database file is a directory."),
+	(0x87af0213, "SQLITE_E_CONSTRAINT_COMMITHOOK", "A commit hook callback requested a rollback."),
+	(0x87af021b, "SQLITE_E_NOTICE_RECOVER_ROLLBACK", "A hot journal was rolled back."),
+	(0x87af0308, "SQLITE_E_READONLY_ROLLBACK", "A hot journal needs to be rolled back but the database is read-only."),
+	(0x87af030a, "SQLITE_E_IOERR_WRITE", "An I/O error occurred while writing to the database file."),
+	(0x87af030e, "SQLITE_E_CANTOPEN_FULLPATH", "Unable to convert the database file name into a full path name."),
+	(0x87af0313, "SQLITE_E_CONSTRAINT_FOREIGNKEY", "A foreign key constraint failed."),
+	(0x87af0408, "SQLITE_E_READONLY_DBMOVED", "The database file has been moved since it was opened."),
+	(0x87af040a, "SQLITE_E_IOERR_FSYNC", "An I/O error occurred while flushing data to persistent storage."),
+	(0x87af040e, "SQLITE_E_CANTOPEN_CONVPATH", "Unable to convert the database file name between character encodings."),
+	(0x87af0413, "SQLITE_E_CONSTRAINT_FUNCTION", "A constraint was violated by an extension function."),
+	(0x87af050a, "SQLITE_E_IOERR_DIR_FSYNC", "An I/O error occurred while flushing a directory to persistent storage."),
+	(0x87af0513, "SQLITE_E_CONSTRAINT_NOTNULL", "A NOT NULL constraint failed."),
+	(0x87af060a, "SQLITE_E_IOERR_TRUNCATE", "An I/O error occurred while truncating a file."),
+	(0x87af0613, "SQLITE_E_CONSTRAINT_PRIMARYKEY", "A PRIMARY KEY constraint failed."),
+	(0x87af070a, "SQLITE_E_IOERR_FSTAT", "An I/O error occurred while querying the status of a file."),
+	(0x87af0713, "SQLITE_E_CONSTRAINT_TRIGGER", "A RAISE function within a trigger raised an error."),
+	(0x87af080a, "SQLITE_E_IOERR_UNLOCK", "An I/O error occurred while releasing a file lock."),
+	(0x87af0813, "SQLITE_E_CONSTRAINT_UNIQUE", "A UNIQUE constraint failed."),
+	(0x87af090a, "SQLITE_E_IOERR_RDLOCK", "An I/O error occurred while obtaining a read lock."),
+	(0x87af0913, "SQLITE_E_CONSTRAINT_VTAB", "A constraint was violated by a virtual table."),
+	(0x87af0a0a, "SQLITE_E_IOERR_DELETE", "An I/O error occurred while deleting a file."),
+	(0x87af0a13, "SQLITE_E_CONSTRAINT_ROWID", "A rowid is not unique."),
+	(0x87af0b0a, "SQLITE_E_IOERR_BLOCKED", "An I/O operation was blocked."),
+	(0x87af0c0a, "SQLITE_E_IOERR_NOMEM", "Unable to allocate memory during an I/O operation."),
+	(0x87af0d0a, "SQLITE_E_IOERR_ACCESS", "An I/O error occurred while checking the access rights of a file."),
+	(0x87af0e0a, "SQLITE_E_IOERR_CHECKRESERVEDLOCK", "An I/O error occurred while checking for a reserved lock."),
+	(0x87af0f0a, "SQLITE_E_IOERR_LOCK", "An I/O error occurred in the file locking logic."),
+	(0x87af100a, "SQLITE_E_IOERR_CLOSE", "An I/O error occurred while closing a file."),
+	(0x87af110a, "SQLITE_E_IOERR_DIR_CLOSE", "An I/O error occurred while closing a directory."),
+	(0x87af120a, "SQLITE_E_IOERR_SHMOPEN", "An I/O error occurred while opening the shared-memory file."),
+	(0x87af130a, "SQLITE_E_IOERR_SHMSIZE", "An I/O error occurred while resizing the shared-memory file."),
+	(0x87af140a, "SQLITE_E_IOERR_SHMLOCK", "An I/O error occurred while locking the shared-memory file."),
+	(0x87af150a, "SQLITE_E_IOERR_SHMMAP", "An I/O error occurred while mapping the shared-memory file."),
+	(0x87af160a, "SQLITE_E_IOERR_SEEK", "An I/O error occurred while seeking in a file."),
+	(0x87af170a, "SQLITE_E_IOERR_DELETE_NOENT", "The file to delete does not exist."),
+	(0x87af180a, "SQLITE_E_IOERR_MMAP", "An I/O error occurred while memory-mapping a file."),
+	(0x87af190a, "SQLITE_E_IOERR_GETTEMPPATH", "Unable to determine the temporary directory."),
+	(0x87af1a02, "SQLITE_E_IOERR_VNODE", "A file was changed or deleted by another process."),
+	(0x87af1a03, "SQLITE_E_IOERR_AUTH", "An I/O authorization error occurred."),
+	(0x87af1a0a, "SQLITE_E_IOERR_CONVPATH", "Unable to convert a file path between character encodings."),
+	(0x87c51001, "UTC_E_TOGGLE_TRACE_STARTED", "Toggle (alternative) trace started."),
+	(0x87c51002, "UTC_E_ALTERNATIVE_TRACE_CANNOT_PREEMPT", "Cannot pre-empt running trace. The current trace has a higher priority."),
+	(0x87c51003, "UTC_E_AOT_NOT_RUNNING", "The always-on-trace is not running."),
+	(0x87c51004, "UTC_E_SCRIPT_TYPE_INVALID", "RunScriptAction contains an invalid script type."),
+	(0x87c51005, "UTC_E_SCENARIODEF_NOT_FOUND", "Requested scenario definition cannot be found."),
+	(0x87c51006, "UTC_E_TRACEPROFILE_NOT_FOUND", "Requested trace profile cannot be found."),
+	(0x87c51007, "UTC_E_FORWARDER_ALREADY_ENABLED", "Trigger forwarder is already enabled."),
+	(0x87c51008, "UTC_E_FORWARDER_ALREADY_DISABLED", "Trigger forwarder is already disabled."),
+	(0x87c51009, "UTC_E_EVENTLOG_ENTRY_MALFORMED", "Cannot parse EventLog XML: the entry is malformed."),
+	(0x87c5100a, "UTC_E_DIAGRULES_SCHEMAVERSION_MISMATCH", "<diagrules> node contains a schemaversion which is not compatible with this client."),
+	(0x87c5100b, "UTC_E_SCRIPT_TERMINATED", "RunScriptAction was forced to terminate a script."),
+	(0x87c5100c, "UTC_E_INVALID_CUSTOM_FILTER", "ToggleTraceWithCustomFilterAction contains an invalid custom filter."),
+	(0x87c5100d, "UTC_E_TRACE_NOT_RUNNING", "The trace is not running."),
+	(0x87c5100e, "UTC_E_REESCALATED_TOO_QUICKLY", "A scenario failed to escalate. This scenario has escalated too recently."),
+	(0x87c5100f, "UTC_E_ESCALATION_ALREADY_RUNNING", "A scenario failed to escalate. This scenario is already running an escalation."),
+	(0x87c51010, "UTC_E_PERFTRACK_ALREADY_TRACING", "Cannot start tracing: PerfTrack component is already tracing."),
+	(0x87c51011, "UTC_E_REACHED_MAX_ESCALATIONS", "A scenario failed to escalate. This scenario has reached max escalations for this escalation type."),
+	(0x87c51012, "UTC_E_FORWARDER_PRODUCER_MISMATCH", "Cannot update forwarder: the forwarder passed to the function is of a different type."),
+	(0x87c51013, "UTC_E_INTENTIONAL_SCRIPT_FAILURE", "RunScriptAction failed intentionally to force this escalation to terminate."),
+	(0x87c51014, "UTC_E_SQM_INIT_FAILED", "Failed to initialize SQM logger."),
+	(0x87c51015, "UTC_E_NO_WER_LOGGER_SUPPORTED", "Failed to initialize WER logger: this is not supported on this platform."),
+	(0x87c51016, "UTC_E_TRACERS_DONT_EXIST", "The TraceManager has attempted to take a tracing action on a tracer that does not exist."),
+	(0x87c51017, "UTC_E_WINRT_INIT_FAILED", "WinRT initialization failed."),
+	(0x87c51018, "UTC_E_SCENARIODEF_SCHEMAVERSION_MISMATCH", "<scenario> node contains a schemaversion that is not compatible with this client."),
+	(0x87c51019, "UTC_E_INVALID_FILTER", "Scenario contains an invalid filter that can never be satisfied."),
+	(0x87c5101a, "UTC_E_EXE_TERMINATED", "RunExeWithArgsAction was forced to terminate a running executable."),
+	(0x87c5101b, "UTC_E_ESCALATION_NOT_AUTHORIZED", "Escalation for scenario failed due to insufficient permissions."),
+	(0x87c5101c, "UTC_E_SETUP_NOT_AUTHORIZED", "Setup for scenario failed due to insufficient permissions."),
+	(0x87c5101d, "UTC_E_CHILD_PROCESS_FAILED", "A process launched by UTC failed with a non-zero exit code."),
+	(0x87c5101e, "UTC_E_COMMAND_LINE_NOT_AUTHORIZED", "A RunExeWithArgs action contains an unauthorized command line."),
+	(0x87c5101f, "UTC_E_CANNOT_LOAD_SCENARIO_EDITOR_XML", "UTC cannot load Scenario Editor XML. Convert the scenario file to a DiagTrack XML using the editor."),
+	(0x87c51020, "UTC_E_ESCALATION_TIMED_OUT", "Escalation for scenario has timed out."),
+	(0x87c51021, "UTC_E_SETUP_TIMED_OUT", "Setup for scenario has timed out."),
+	(0x87c51022, "UTC_E_TRIGGER_MISMATCH", "The given trigger does not match the expected trigger type."),
+	(0x87c51023, "UTC_E_TRIGGER_NOT_FOUND", "Requested trigger cannot be found."),
+	(0x87c51024, "UTC_E_SIF_NOT_SUPPORTED", "SIF is not supported on the machine."),
+	(0x87c51025, "UTC_E_DELAY_TERMINATED", "The delay action was terminated."),
+	(0x87c51026, "UTC_E_DEVICE_TICKET_ERROR", "The device ticket was not obtained."),
+	(0x87c51027, "UTC_E_TRACE_BUFFER_LIMIT_EXCEEDED", "The trace profile needs more memory than is available for tracing."),
+	(0x87c51028, "UTC_E_API_RESULT_UNAVAILABLE", "The API was not completed successfully so the result is unavailable."),
+	(0x87c51029, "UTC_E_RPC_TIMEOUT", "The requested API encountered a timeout in the API manager."),
+	(0x87c5102a, "UTC_E_RPC_WAIT_FAILED", "The synchronous API encountered a wait failure."),
+	(0x87c5102b, "UTC_E_API_BUSY", "The UTC API is busy with another request."),
+	(0x87c5102c, "UTC_E_TRACE_MIN_DURATION_REQUIREMENT_NOT_MET", "The running trace profile does not have a sufficient runtime to fulfill the escalation request."),
+	(0x87c5102d, "UTC_E_EXCLUSIVITY_NOT_AVAILABLE", "The trace rundown request failed because exclusivity is not available."),
+	(0x87c5102e, "UTC_E_GETFILE_FILE_PATH_NOT_APPROVED", "The GetFile API is not authorized to get the requested file."),
+	(0x87c5102f, "UTC_E_ESCALATION_DIRECTORY_ALREADY_EXISTS", "The escalation working directory for the requested escalation could not be created because it already exists."),
+	(0x87c51030, "UTC_E_TIME_TRIGGER_ON_START_INVALID", "Time triggers cannot be used on a transition originating from the \"_start\" state."),
+	(0x87c51031, "UTC_E_TIME_TRIGGER_ONLY_VALID_ON_SINGLE_TRANSITION", "Time triggers can only be attached to a single transition."),
+	(0x87c51032, "UTC_E_TIME_TRIGGER_INVALID_TIME_RANGE", "Time trigger duration must fall within an inclusive range of one second and 24 hours."),
+	(0x87c51033, "UTC_E_MULTIPLE_TIME_TRIGGER_ON_SINGLE_STATE", "Only one Time Trigger is allowed per state."),
+	(0x87c51034, "UTC_E_BINARY_MISSING", "A RunExeWithArgs action contains a binary which is not present on the targeted device."),
+	(0x87c51035, "UTC_E_NETWORK_CAPTURE_NOT_ALLOWED", "A network capture trace is not allowed."),
+	(0x87c51036, "UTC_E_FAILED_TO_RESOLVE_CONTAINER_ID", "UTC failed to identify the container id to use for a scenario escalation action."),
+	(0x87c51037, "UTC_E_UNABLE_TO_RESOLVE_SESSION", "Failed to resolve session for escalation."),
+	(0x87c51038, "UTC_E_THROTTLED", "UTC has throttled the event for firing too often."),
+	(0x87c51039, "UTC_E_UNAPPROVED_SCRIPT", "The script is not approved to run as part of DiagTrack scenario."),
+	(0x87c5103a, "UTC_E_SCRIPT_MISSING", "The script referenced in DiagTrack scenario is not present on the system."),
+	(0x87c5103b, "UTC_E_SCENARIO_THROTTLED", "The scenario was throttled."),
+	(0x87c5103c, "UTC_E_API_NOT_SUPPORTED", "The requested UTC API call is not supported on this device."),
+	(0x87c5103d, "UTC_E_GETFILE_EXTERNAL_PATH_NOT_APPROVED", "The file path is not approved for collection on external devices."),
+	(0x87c5103e, "UTC_E_TRY_GET_SCENARIO_TIMEOUT_EXCEEDED", "Querying a scenario definition exceeded the specified maximum timeout."),
+	(0x87c5103f, "UTC_E_CERT_REV_FAILED", "Certification revocation checking has been enabled, but the revocation check failed."),
+	(0x87c51040, "UTC_E_FAILED_TO_START_NDISCAP", "ETW could not start the NdisCap service."),
+	(0x87c51041, "UTC_E_KERNELDUMP_LIMIT_REACHED", "The kernel dump limit has been reached."),
+	(0x87c51042, "UTC_E_MISSING_AGGREGATE_EVENT_TAG", "Event contains an aggregation action without an aggregation tag."),
+	(0x87c51043, "UTC_E_INVALID_AGGREGATION_STRUCT", "Event contains an aggregation action with an invalid aggregation struct."),
+	(0x87c51044, "UTC_E_ACTION_NOT_SUPPORTED_IN_DESTINATION", "The action is not supported in the destination."),
+	(0x87c51045, "UTC_E_FILTER_MISSING_ATTRIBUTE", "The filter is missing a required attribute."),
+	(0x87c51046, "UTC_E_FILTER_INVALID_TYPE", "The filter contains an invalid type."),
+	(0x87c51047, "UTC_E_FILTER_VARIABLE_NOT_FOUND", "The filter references a variable that was not found."),
+	(0x87c51048, "UTC_E_FILTER_FUNCTION_RESTRICTED", "The filter uses a restricted function."),
+	(0x87c51049, "UTC_E_FILTER_VERSION_MISMATCH", "The filter version is not compatible with this client."),
+	(0x87c51050, "UTC_E_FILTER_INVALID_FUNCTION", "The filter contains an invalid function."),
+	(0x87c51051, "UTC_E_FILTER_INVALID_FUNCTION_PARAMS", "The filter contains invalid function parameters."),
+	(0x87c51052, "UTC_E_FILTER_INVALID_COMMAND", "The filter contains an invalid command."),
+	(0x87c51053, "UTC_E_FILTER_ILLEGAL_EVAL", "The filter contains an illegal evaluation."),
+	(0x87c51054, "UTC_E_TTTRACER_RETURNED_ERROR", "The time travel tracer returned an error."),
+	(0x87c51055, "UTC_E_AGENT_DIAGNOSTICS_TOO_LARGE", "The agent diagnostics are too large."),
+	(0x87c51056, "UTC_E_FAILED_TO_RECEIVE_AGENT_DIAGNOSTICS", "Failed to receive the agent diagnostics."),
+	(0x87c51057, "UTC_E_SCENARIO_HAS_NO_ACTIONS", "The scenario has no actions."),
+	(0x87c51058, "UTC_E_TTTRACER_STORAGE_FULL", "The time travel tracer storage is full."),
+	(0x87c51059, "UTC_E_INSUFFICIENT_SPACE_TO_START_TRACE", "There is insufficient disk space to start the trace."),
+	(0x87c5105a, "UTC_E_ESCALATION_CANCELLED_AT_SHUTDOWN", "The escalation was cancelled due to a shutdown."),
+	(0x87c5105b, "UTC_E_GETFILEINFOACTION_FILE_NOT_APPROVED", "The GetFileInfo action is not authorized to get the requested file."),
+	(0x87c5105c, "UTC_E_SETREGKEYACTION_TYPE_NOT_APPROVED", "The SetRegKey action is not authorized for the requested registry value type."),
+	(0x88010001, "WEP_E_NOT_PROVISIONED_ON_ALL_VOLUMES", "Windows Encryption Provider is not provisioned on all volumes."),
+	(0x88010002, "WEP_E_FIXED_DATA_NOT_SUPPORTED", "Windows Encryption Provider does not support fixed data volumes."),
+	(0x88010003, "WEP_E_HARDWARE_NOT_COMPLIANT", "The hardware is not compliant with the Windows Encryption Provider."),
+	(0x88010004, "WEP_E_LOCK_NOT_CONFIGURED", "A device lock is not configured."),
+	(0x88010005, "WEP_E_PROTECTION_SUSPENDED", "Windows Encryption Provider protection is suspended."),
+	(0x88010006, "WEP_E_NO_LICENSE", "There is no license for the Windows Encryption Provider."),
+	(0x88010007, "WEP_E_OS_NOT_PROTECTED", "The operating system volume is not protected."),
+	(0x88010008, "WEP_E_UNEXPECTED_FAIL", "An unexpected failure occurred in the Windows Encryption Provider."),
+	(0x88010009, "WEP_E_BUFFER_TOO_LARGE", "The buffer is too large."),
+	(0x8802b001, "E_SYNCENGINE_FILE_SIZE_OVER_LIMIT", "The file size is larger than supported by the sync engine."),
+	(0x8802b002, "E_SYNCENGINE_FILE_SIZE_EXCEEDS_REMAINING_QUOTA", "The file cannot be uploaded because it doesn't fit in the user's cloud storage."),
+	(0x8802b003, "E_SYNCENGINE_UNSUPPORTED_FILE_NAME", "The file name contains invalid characters."),
+	(0x8802b004, "E_SYNCENGINE_FOLDER_ITEM_COUNT_LIMIT_EXCEEDED", "The maximum file count has been reached for this folder in the sync engine."),
+	(0x8802b005, "E_SYNCENGINE_FILE_SYNC_PARTNER_ERROR", "The file sync has been delegated to another program and has run into an issue."),
+	(0x8802b006, "E_SYNCENGINE_SYNC_PAUSED_BY_SERVICE", "Sync has been delayed due to a throttling request from the service."),
+	(0x8802c002, "E_SYNCENGINE_FILE_IDENTIFIER_UNKNOWN", "We can't seem to find that file. Please try again later."),
+	(0x8802c003, "E_SYNCENGINE_SERVICE_AUTHENTICATION_FAILED", "The account you're signed in with doesn't have permission to open this file."),
+	(0x8802c004, "E_SYNCENGINE_UNKNOWN_SERVICE_ERROR", "There was a problem connecting to the service. Please try again later."),
+	(0x8802c005, "E_SYNCENGINE_SERVICE_RETURNED_UNEXPECTED_SIZE", "Sorry, there was a problem downloading the file."),
+	(0x8802c006, "E_SYNCENGINE_REQUEST_BLOCKED_BY_SERVICE", "We're having trouble downloading the file right now. Please try again later."),
+	(0x8802c007, "E_SYNCENGINE_REQUEST_BLOCKED_DUE_TO_CLIENT_ERROR", "We're having trouble downloading the file right now. Please try again later."),
+	(0x8802d001, "E_SYNCENGINE_FOLDER_INACCESSIBLE", "The sync engine does not have permissions to access a local folder under the sync root."),
+	(0x8802d002, "E_SYNCENGINE_UNSUPPORTED_FOLDER_NAME", "The folder name contains invalid characters."),
+	(0x8802d003, "E_SYNCENGINE_UNSUPPORTED_MARKET", "The sync engine is not allowed to run in your current market."),
+	(0x8802d004, "E_SYNCENGINE_PATH_LENGTH_LIMIT_EXCEEDED", "All files and folders can't be uploaded because a path of a file or folder is too long."),
+	(0x8802d005, "E_SYNCENGINE_REMOTE_PATH_LENGTH_LIMIT_EXCEEDED", "All file and folders cannot be synchronized because a path of a file or folder would exceed the local path limit."),
+	(0x8802d006, "E_SYNCENGINE_CLIENT_UPDATE_NEEDED", "Updates are needed in order to use the sync engine."),
+	(0x8802d007, "E_SYNCENGINE_PROXY_AUTHENTICATION_REQUIRED", "The sync engine needs to authenticate with a proxy server."),
+	(0x8802d008, "E_SYNCENGINE_STORAGE_SERVICE_PROVISIONING_FAILED", "There was a problem setting up the storage services for the account."),
+	(0x8802d009, "E_SYNCENGINE_UNSUPPORTED_REPARSE_POINT", "Files can't be uploaded because there's an unsupported reparse point."),
+	(0x8802d00a, "E_SYNCENGINE_STORAGE_SERVICE_BLOCKED", "The service has blocked your account from accessing the storage service."),
+	(0x8802d00b, "E_SYNCENGINE_FOLDER_IN_REDIRECTION", "The action can't be performed right now because this folder is being moved. Please try again later."),
+	(0x88790001, "D3D10_ERROR_TOO_MANY_UNIQUE_STATE_OBJECTS", "The application has exceeded the maximum number of unique state objects per Direct3D device. The limit is 4096 for feature levels up to 11.1."),
+	(0x88790002, "D3D10_ERROR_FILE_NOT_FOUND", "The specified file was not found."),
+	(0x887c0001, "D3D11_ERROR_TOO_MANY_UNIQUE_STATE_OBJECTS", "The application has exceeded the maximum number of unique state objects per Direct3D device. The limit is 4096 for feature levels up to 11.1."),
+	(0x887c0002, "D3D11_ERROR_FILE_NOT_FOUND", "The specified file was not found."),
+	(0x887c0003, "D3D11_ERROR_TOO_MANY_UNIQUE_VIEW_OBJECTS", "The application has exceeded the maximum number of unique view objects per Direct3D device. The limit is 2^20 for feature levels up to 11.1."),
+	(0x887c0004, "D3D11_ERROR_DEFERRED_CONTEXT_MAP_WITHOUT_INITIAL_DISCARD", "The application's first call per command list to Map on a deferred context did not use D3D11_MAP_WRITE_DISCARD."),
+	(0x887e0001, "D3D12_ERROR_ADAPTER_NOT_FOUND", "The blob provided does not match the adapter that the device was created on."),
+	(0x887e0002, "D3D12_ERROR_DRIVER_VERSION_MISMATCH", "The blob provided was created for a different version of the driver, and must be re-created."),
+	(0x88900001, "WINML_ERR_INVALID_DEVICE", "The binding is incomplete or does not match the device."),
+	(0x88900002, "WINML_ERR_INVALID_BINDING", "The binding does not match the model's input or output."),
+	(0x88900003, "WINML_ERR_VALUE_NOTFOUND", "The model variable cannot be found."),
+	(0x88900004, "WINML_ERR_SIZE_MISMATCH", "The data size does not match the model's expected size."),
+	(0x88980001, "MILERR_OBJECTBUSY", "The object is busy."),
+	(0x88980002, "MILERR_INSUFFICIENTBUFFER", "The buffer is too small."),
+	(0x88980003, "MILERR_WIN32ERROR", "A Win32 error occurred."),
+	(0x88980004, "MILERR_SCANNER_FAILED", "The scanner failed."),
+	(0x88980005, "MILERR_SCREENACCESSDENIED", "Access to the screen was denied."),
+	(0x88980006, "MILERR_DISPLAYSTATEINVALID", "The display state is invalid."),
+	(0x88980007, "MILERR_NONINVERTIBLEMATRIX", "The matrix cannot be inverted."),
+	(0x88980008, "MILERR_ZEROVECTOR", "A zero vector was encountered."),
+	(0x88980009, "MILERR_TERMINATED", "The operation was terminated."),
+	(0x8898000a, "MILERR_BADNUMBER", "A bad number was encountered."),
+	(0x88980080, "MILERR_INTERNALERROR", "An internal error (MIL bug) occurred. On checked builds, an assert would be raised."),
+	(0x88980084, "MILERR_DISPLAYFORMATNOTSUPPORTED", "The display format we need to render is not supported by the hardware device."),
+	(0x88980085, "MILERR_INVALIDCALL", "A call to this method is invalid."),
+	(0x88980086, "MILERR_ALREADYLOCKED", "Lock attempted on an already locked object."),
+	(0x88980087, "MILERR_NOTLOCKED", "Unlock attempted on an unlocked object."),
+	(0x88980088, "MILERR_DEVICECANNOTRENDERTEXT", "No algorithm available to render text with this device."),
+	(0x88980089, "MILERR_GLYPHBITMAPMISSED", "Some glyph bitmaps, required for glyph run rendering, are not contained in glyph cache."),
+	(0x8898008a, "MILERR_MALFORMEDGLYPHCACHE", "Some glyph bitmaps in glyph cache are unexpectedly big."),
+	(0x8898008b, "MILERR_GENERIC_IGNORE", "Marker error for known Win32 errors that are currently being ignored by the compositor. This is to avoid returning S_OK when an error has occurred, but still unwind the stack to the correct location."),
+	(0x8898008c, "MILERR_MALFORMED_GUIDELINE_DATA", "Guideline coordinates are not sorted properly or contain NaNs."),
+	(0x8898008d, "MILERR_NO_HARDWARE_DEVICE", "No HW rendering device is available for this operation."),
+	(0x8898008e, "MILERR_NEED_RECREATE_AND_PRESENT", "There has been a presentation error that may be recoverable. The caller needs to reattempt present."),
+	(0x8898008f, "MILERR_ALREADY_INITIALIZED", "The object has already been initialized."),
+	(0x88980090, "MILERR_MISMATCHED_SIZE", "The size of the object does not match the expected size."),
+	(0x88980091, "MILERR_NO_REDIRECTION_SURFACE_AVAILABLE", "No Redirection surface available."),
+	(0x88980092, "MILERR_REMOTING_NOT_SUPPORTED", "Remoting of this content is not supported."),
+	(0x88980093, "MILERR_QUEUED_PRESENT_NOT_SUPPORTED", "Queued Presents are not supported."),
+	(0x88980094, "MILERR_NOT_QUEUING_PRESENTS", "Queued Presents are not being used."),
+	(0x88980095, "MILERR_NO_REDIRECTION_SURFACE_RETRY_LATER", "No redirection surface was available. Caller should retry the call."),
+	(0x88980096, "MILERR_TOOMANYSHADERELEMNTS", "Shader construction failed because it was too complex."),
+	(0x88980097, "MILERR_MROW_READLOCK_FAILED", "MROW attempt to get a read lock failed."),
+	(0x88980098, "MILERR_MROW_UPDATE_FAILED", "MROW attempt to update the data failed because another update was outstanding."),
+	(0x88980099, "MILERR_SHADER_COMPILE_FAILED", "Shader compilation failed."),
+	(0x8898009a, "MILERR_MAX_TEXTURE_SIZE_EXCEEDED", "Requested DX redirection surface size exceeded maximum texture size."),
+	(0x8898009b, "MILERR_QPC_TIME_WENT_BACKWARD", "QueryPerformanceCounter returned a time in the past."),
+	(0x8898009d, "MILERR_DXGI_ENUMERATION_OUT_OF_SYNC", "Primary Display device returned an invalid refresh rate."),
+	(0x8898009e, "MILERR_ADAPTER_NOT_FOUND", "DWM can not find the adapter specified by the LUID."),
+	(0x8898009f, "MILERR_COLORSPACE_NOT_SUPPORTED", "The requested bitmap color space is not supported."),
+	(0x889800a0, "MILERR_PREFILTER_NOT_SUPPORTED", "The requested bitmap pre-filtering state is not supported."),
+	(0x889800a1, "MILERR_DISPLAYID_ACCESS_DENIED", "Access is denied to the requested bitmap for the specified display id."),
+	(0x88980400, "UCEERR_INVALIDPACKETHEADER", "UCE packet header was invalid."),
+	(0x88980401, "UCEERR_UNKNOWNPACKET", "UCE packet was an unknown type."),
+	(0x88980402, "UCEERR_ILLEGALPACKET", "UCE packet size was too small to be a command or too large."),
+	(0x88980403, "UCEERR_MALFORMEDPACKET", "UCE packet size didn't match the command's expected size."),
+	(0x88980404, "UCEERR_ILLEGALHANDLE", "UCE command contained an invalid handle."),
+	(0x88980405, "UCEERR_HANDLELOOKUPFAILED", "UCE command handle lookup failed."),
+	(0x88980406, "UCEERR_RENDERTHREADFAILURE", "UCE render thread failed."),
+	(0x88980407, "UCEERR_CTXSTACKFRSTTARGETNULL", "UCE context stack first target is null."),
+	(0x88980408, "UCEERR_CONNECTIONIDLOOKUPFAILED", "UCE connection ID lookup failed."),
+	(0x88980409, "UCEERR_BLOCKSFULL", "No more UCE blocks are available."),
+	(0x8898040a, "UCEERR_MEMORYFAILURE", "UCE memory allocation failed."),
+	(0x8898040b, "UCEERR_PACKETRECORDOUTOFRANGE", "UCE packet record is out of range."),
+	(0x8898040c, "UCEERR_ILLEGALRECORDTYPE", "UCE record type is illegal."),
+	(0x8898040d, "UCEERR_OUTOFHANDLES", "UCE has run out of handles."),
+	(0x8898040e, "UCEERR_UNCHANGABLE_UPDATE_ATTEMPTED", "UCE attempted to update an unchangeable resource."),
+	(0x8898040f, "UCEERR_NO_MULTIPLE_WORKER_THREADS", "UCE does not support multiple worker threads."),
+	(0x88980410, "UCEERR_REMOTINGNOTSUPPORTED", "UCE remoting is not supported."),
+	(0x88980411, "UCEERR_MISSINGENDCOMMAND", "UCE is missing an end command."),
+	(0x88980412, "UCEERR_MISSINGBEGINCOMMAND", "UCE is missing a begin command."),
+	(0x88980413, "UCEERR_CHANNELSYNCTIMEDOUT", "UCE channel synchronization timed out."),
+	(0x88980414, "UCEERR_CHANNELSYNCABANDONED", "UCE channel synchronization was abandoned."),
+	(0x88980415, "UCEERR_UNSUPPORTEDTRANSPORTVERSION", "UCE transport version is not supported."),
+	(0x88980416, "UCEERR_TRANSPORTUNAVAILABLE", "UCE transport is unavailable."),
+	(0x88980417, "UCEERR_FEEDBACK_UNSUPPORTED", "UCE feedback is not supported."),
+	(0x88980418, "UCEERR_COMMANDTRANSPORTDENIED", "UCE command transport was denied."),
+	(0x88980419, "UCEERR_GRAPHICSSTREAMUNAVAILABLE", "UCE graphics stream is unavailable."),
+	(0x88980420, "UCEERR_GRAPHICSSTREAMALREADYOPEN", "UCE graphics stream is already open."),
+	(0x88980421, "UCEERR_TRANSPORTDISCONNECTED", "UCE transport was disconnected."),
+	(0x88980422, "UCEERR_TRANSPORTOVERLOADED", "UCE transport is overloaded."),
+	(0x88980423, "UCEERR_PARTITION_ZOMBIED", "UCE partition is zombied."),
+	(0x88980500, "MILAVERR_NOCLOCK", "No clock is available."),
+	(0x88980501, "MILAVERR_NOMEDIATYPE", "No media type is available."),
+	(0x88980502, "MILAVERR_NOVIDEOMIXER", "No video mixer is available."),
+	(0x88980503, "MILAVERR_NOVIDEOPRESENTER", "No video presenter is available."),
+	(0x88980504, "MILAVERR_NOREADYFRAMES", "No ready frames are available."),
+	(0x88980505, "MILAVERR_MODULENOTLOADED", "A required module was not loaded."),
+	(0x88980506, "MILAVERR_WMPFACTORYNOTREGISTERED", "The Windows Media Player factory is not registered."),
+	(0x88980507, "MILAVERR_INVALIDWMPVERSION", "The Windows Media Player version is invalid."),
+	(0x88980508, "MILAVERR_INSUFFICIENTVIDEORESOURCES", "There are insufficient video resources."),
+	(0x88980509, "MILAVERR_VIDEOACCELERATIONNOTAVAILABLE", "Video acceleration is not available."),
+	(0x8898050a, "MILAVERR_REQUESTEDTEXTURETOOBIG", "The requested texture is too big."),
+	(0x8898050b, "MILAVERR_SEEKFAILED", "The seek operation failed."),
+	(0x8898050c, "MILAVERR_UNEXPECTEDWMPFAILURE", "An unexpected Windows Media Player failure occurred."),
+	(0x8898050d, "MILAVERR_MEDIAPLAYERCLOSED", "The media player is closed."),
+	(0x8898050e, "MILAVERR_UNKNOWNHARDWAREERROR", "An unknown hardware error occurred."),
+	(0x8898060e, "MILEFFECTSERR_UNKNOWNPROPERTY", "Unrecognized property."),
+	(0x8898060f, "MILEFFECTSERR_EFFECTNOTPARTOFGROUP", "The effect is not part of the group."),
+	(0x88980610, "MILEFFECTSERR_NOINPUTSOURCEATTACHED", "No input source is attached."),
+	(0x88980611, "MILEFFECTSERR_CONNECTORNOTCONNECTED", "The connector is not connected."),
+	(0x88980612, "MILEFFECTSERR_CONNECTORNOTASSOCIATEDWITHEFFECT", "The connector is not associated with an effect."),
+	(0x88980613, "MILEFFECTSERR_RESERVED", "Reserved error."),
+	(0x88980614, "MILEFFECTSERR_CYCLEDETECTED", "A cycle was detected in the effect graph."),
+	(0x88980615, "MILEFFECTSERR_EFFECTINMORETHANONEGRAPH", "The effect is in more than one graph."),
+	(0x88980616, "MILEFFECTSERR_EFFECTALREADYINAGRAPH", "The effect is already in a graph."),
+	(0x88980617, "MILEFFECTSERR_EFFECTHASNOCHILDREN", "The effect has no children."),
+	(0x88980618, "MILEFFECTSERR_ALREADYATTACHEDTOLISTENER", "The effect is already attached to a listener."),
+	(0x88980619, "MILEFFECTSERR_NOTAFFINETRANSFORM", "The transform is not affine."),
+	(0x8898061a, "MILEFFECTSERR_EMPTYBOUNDS", "The bounds are empty."),
+	(0x8898061b, "MILEFFECTSERR_OUTPUTSIZETOOLARGE", "The output size is too large."),
+	(0x88980700, "DWMERR_STATE_TRANSITION_FAILED", "The DWM state transition failed."),
+	(0x88980701, "DWMERR_THEME_FAILED", "The DWM theme failed."),
+	(0x88980702, "DWMERR_CATASTROPHIC_FAILURE", "A catastrophic DWM failure occurred."),
+	(0x88980800, "DCOMPOSITION_ERROR_WINDOW_ALREADY_COMPOSED", "The window is already composed."),
+	(0x88980801, "DCOMPOSITION_ERROR_SURFACE_BEING_RENDERED", "The surface is already being rendered."),
+	(0x88980802, "DCOMPOSITION_ERROR_SURFACE_NOT_BEING_RENDERED", "The surface is not being rendered."),
+	(0x88982f04, "WINCODEC_ERR_WRONGSTATE", "The codec is in the wrong state."),
+	(0x88982f05, "WINCODEC_ERR_VALUEOUTOFRANGE", "The value is out of range."),
+	(0x88982f07, "WINCODEC_ERR_UNKNOWNIMAGEFORMAT", "The image format is unknown."),
+	(0x88982f0b, "WINCODEC_ERR_UNSUPPORTEDVERSION", "The SDK version is unsupported."),
+	(0x88982f0c, "WINCODEC_ERR_NOTINITIALIZED", "The component is not initialized."),
+	(0x88982f0d, "WINCODEC_ERR_ALREADYLOCKED", "There is already an outstanding read or write lock."),
+	(0x88982f40, "WINCODEC_ERR_PROPERTYNOTFOUND", "The specified bitmap property cannot be found."),
+	(0x88982f41, "WINCODEC_ERR_PROPERTYNOTSUPPORTED", "The bitmap codec does not support the bitmap property."),
+	(0x88982f42, "WINCODEC_ERR_PROPERTYSIZE", "The bitmap property size is invalid."),
+	(0x88982f43, "WINCODEC_ERR_CODECPRESENT", "An unknown error has occurred."),
+	(0x88982f44, "WINCODEC_ERR_CODECNOTHUMBNAIL", "The bitmap codec does not support a thumbnail."),
+	(0x88982f45, "WINCODEC_ERR_PALETTEUNAVAILABLE", "The bitmap palette is unavailable."),
+	(0x88982f46, "WINCODEC_ERR_CODECTOOMANYSCANLINES", "Too many scanlines were requested."),
+	(0x88982f48, "WINCODEC_ERR_INTERNALERROR", "An internal error occurred."),
+	(0x88982f49, "WINCODEC_ERR_SOURCERECTDOESNOTMATCHDIMENSIONS", "The bitmap bounds do not match the bitmap dimensions."),
+	(0x88982f50, "WINCODEC_ERR_COMPONENTNOTFOUND", "The component cannot be found."),
+	(0x88982f51, "WINCODEC_ERR_IMAGESIZEOUTOFRANGE", "The bitmap size is outside the valid range."),
+	(0x88982f52, "WINCODEC_ERR_TOOMUCHMETADATA", "There is too much metadata to be written to the bitmap."),
+	(0x88982f60, "WINCODEC_ERR_BADIMAGE", "The image is unrecognized."),
+	(0x88982f61, "WINCODEC_ERR_BADHEADER", "The image header is unrecognized."),
+	(0x88982f62, "WINCODEC_ERR_FRAMEMISSING", "The bitmap frame is missing."),
+	(0x88982f63, "WINCODEC_ERR_BADMETADATAHEADER", "The image metadata header is unrecognized."),
+	(0x88982f70, "WINCODEC_ERR_BADSTREAMDATA", "The stream data is unrecognized."),
+	(0x88982f71, "WINCODEC_ERR_STREAMWRITE", "Failed to write to the stream."),
+	(0x88982f72, "WINCODEC_ERR_STREAMREAD", "Failed to read from the stream."),
+	(0x88982f73, "WINCODEC_ERR_STREAMNOTAVAILABLE", "The stream is not available."),
+	(0x88982f80, "WINCODEC_ERR_UNSUPPORTEDPIXELFORMAT", "The bitmap pixel format is unsupported."),
+	(0x88982f81, "WINCODEC_ERR_UNSUPPORTEDOPERATION", "The operation is unsupported."),
+	(0x88982f8a, "WINCODEC_ERR_INVALIDREGISTRATION", "The component registration is invalid."),
+	(0x88982f8b, "WINCODEC_ERR_COMPONENTINITIALIZEFAILURE", "The component initialization has failed."),
+	(0x88982f8c, "WINCODEC_ERR_INSUFFICIENTBUFFER", "The buffer allocated is insufficient."),
+	(0x88982f8d, "WINCODEC_ERR_DUPLICATEMETADATAPRESENT", "Duplicate metadata is present."),
+	(0x88982f8e, "WINCODEC_ERR_PROPERTYUNEXPECTEDTYPE", "The bitmap property type is unexpected."),
+	(0x88982f8f, "WINCODEC_ERR_UNEXPECTEDSIZE", "The property size is unexpected."),
+	(0x88982f90, "WINCODEC_ERR_INVALIDQUERYREQUEST", "The metadata query request is invalid."),
+	(0x88982f91, "WINCODEC_ERR_UNEXPECTEDMETADATATYPE", "The metadata type is unexpected."),
+	(0x88982f92, "WINCODEC_ERR_REQUESTONLYVALIDATMETADATAROOT", "The specified bitmap property is only valid at root level."),
+	(0x88982f93, "WINCODEC_ERR_INVALIDQUERYCHARACTER", "The query string contains an invalid character."),
+	(0x88982f94, "WINCODEC_ERR_WIN32ERROR", "Windows Codecs received an error from the Win32 system."),
+	(0x88982f95, "WINCODEC_ERR_INVALIDPROGRESSIVELEVEL", "The requested level of detail is not present."),
+	(0x88982f96, "WINCODEC_ERR_INVALIDJPEGSCANINDEX", "The scan index is invalid."),
+	(0x88985000, "DWRITE_E_FILEFORMAT", "Indicates an error in an input file such as a font file."),
+	(0x88985001, "DWRITE_E_UNEXPECTED", "Indicates an error originating in DirectWrite code, which is not expected to occur but is safe to recover from."),
+	(0x88985002, "DWRITE_E_NOFONT", "Indicates the specified font does not exist."),
+	(0x88985003, "DWRITE_E_FILENOTFOUND", "A font file could not be opened because the file, directory, network location, drive, or other storage location does not exist or is unavailable."),
+	(0x88985004, "DWRITE_E_FILEACCESS", "A font file exists but could not be opened due to access denied, sharing violation, or similar error."),
+	(0x88985005, "DWRITE_E_FONTCOLLECTIONOBSOLETE", "A font collection is obsolete due to changes in the system."),
+	(0x88985006, "DWRITE_E_ALREADYREGISTERED", "The given interface is already registered."),
+	(0x88985007, "DWRITE_E_CACHEFORMAT", "The font cache contains invalid data."),
+	(0x88985008, "DWRITE_E_CACHEVERSION", "A font cache file corresponds to a different version of DirectWrite."),
+	(0x88985009, "DWRITE_E_UNSUPPORTEDOPERATION", "The operation is not supported for this type of font."),
+	(0x8898500a, "DWRITE_E_TEXTRENDERERINCOMPATIBLE", "The version of the text renderer interface is not compatible."),
+	(0x8898500b, "DWRITE_E_FLOWDIRECTIONCONFLICTS", "The flow direction conflicts with the reading direction. They must be perpendicular to each other."),
+	(0x8898500c, "DWRITE_E_NOCOLOR", "The font or glyph run does not contain any colored glyphs."),
+	(0x8898500d, "DWRITE_E_REMOTEFONT", "A font resource could not be accessed because it is remote."),
+	(0x8898500e, "DWRITE_E_DOWNLOADCANCELLED", "A font download was canceled."),
+	(0x8898500f, "DWRITE_E_DOWNLOADFAILED", "A font download failed."),
+	(0x88985010, "DWRITE_E_TOOMANYDOWNLOADS", "A font download request was not added or a download failed because there are too many active downloads."),
+	(0x88990001, "D2DERR_WRONG_STATE", "The object was not in the correct state to process the method."),
+	(0x88990002, "D2DERR_NOT_INITIALIZED", "The object has not yet been initialized."),
+	(0x88990003, "D2DERR_UNSUPPORTED_OPERATION", "The requested operation is not supported."),
+	(0x88990004, "D2DERR_SCANNER_FAILED", "The geometry scanner failed to process the data."),
+	(0x88990005, "D2DERR_SCREEN_ACCESS_DENIED", "Direct2D could not access the screen."),
+	(0x88990006, "D2DERR_DISPLAY_STATE_INVALID", "A valid display state could not be determined."),
+	(0x88990007, "D2DERR_ZERO_VECTOR", "The supplied vector is zero."),
+	(0x88990008, "D2DERR_INTERNAL_ERROR", "An internal error (Direct2D bug) occurred. On checked builds, we would assert. The application should close this instance of Direct2D and should consider restarting its process."),
+	(0x88990009, "D2DERR_DISPLAY_FORMAT_NOT_SUPPORTED", "The display format Direct2D needs to render is not supported by the hardware device."),
+	(0x8899000a, "D2DERR_INVALID_CALL", "A call to this method is invalid."),
+	(0x8899000b, "D2DERR_NO_HARDWARE_DEVICE", "No hardware rendering device is available for this operation."),
+	(0x8899000c, "D2DERR_RECREATE_TARGET", "There has been a presentation error that may be recoverable. The caller needs to recreate, rerender the entire frame, and reattempt present."),
+	(0x8899000d, "D2DERR_TOO_MANY_SHADER_ELEMENTS", "Shader construction failed because it was too complex."),
+	(0x8899000e, "D2DERR_SHADER_COMPILE_FAILED", "Shader compilation failed."),
+	(0x8899000f, "D2DERR_MAX_TEXTURE_SIZE_EXCEEDED", "Requested DirectX surface size exceeded maximum texture size."),
+	(0x88990010, "D2DERR_UNSUPPORTED_VERSION", "The requested Direct2D version is not supported."),
+	(0x88990011, "D2DERR_BAD_NUMBER", "Invalid number."),
+	(0x88990012, "D2DERR_WRONG_FACTORY", "Objects used together must be created from the same factory instance."),
+	(0x88990013, "D2DERR_LAYER_ALREADY_IN_USE", "A layer resource can only be in use once at any point in time."),
+	(0x88990014, "D2DERR_POP_CALL_DID_NOT_MATCH_PUSH", "The pop call did not match the corresponding push call."),
+	(0x88990015, "D2DERR_WRONG_RESOURCE_DOMAIN", "The resource was realized on the wrong render target."),
+	(0x88990016, "D2DERR_PUSH_POP_UNBALANCED", "The push and pop calls were unbalanced."),
+	(0x88990017, "D2DERR_RENDER_TARGET_HAS_LAYER_OR_CLIPRECT", "Attempt to copy from a render target while a layer or clip rect is applied."),
+	(0x88990018, "D2DERR_INCOMPATIBLE_BRUSH_TYPES", "The brush types are incompatible for the call."),
+	(0x88990019, "D2DERR_WIN32_ERROR", "An unknown win32 failure occurred."),
+	(0x8899001a, "D2DERR_TARGET_NOT_GDI_COMPATIBLE", "The render target is not compatible with GDI."),
+	(0x8899001b, "D2DERR_TEXT_EFFECT_IS_WRONG_TYPE", "A text client drawing effect object is of the wrong type."),
+	(0x8899001c, "D2DERR_TEXT_RENDERER_NOT_RELEASED", "The application is holding a reference to the IDWriteTextRenderer interface after the corresponding DrawText or DrawTextLayout call has returned. The IDWriteTextRenderer instance will be invalid."),
+	(0x8899001d, "D2DERR_EXCEEDS_MAX_BITMAP_SIZE", "The requested size is larger than the guaranteed supported texture size at the Direct3D device's current feature level."),
+	(0x8899001e, "D2DERR_INVALID_GRAPH_CONFIGURATION", "There was a configuration error in the graph."),
+	(0x8899001f, "D2DERR_INVALID_INTERNAL_GRAPH_CONFIGURATION", "There was a internal configuration error in the graph."),
+	(0x88990020, "D2DERR_CYCLIC_GRAPH", "There was a cycle in the graph."),
+	(0x88990021, "D2DERR_BITMAP_CANNOT_DRAW", "Cannot draw with a bitmap that has the D2D1_BITMAP_OPTIONS_CANNOT_DRAW option."),
+	(0x88990022, "D2DERR_OUTSTANDING_BITMAP_REFERENCES", "The operation cannot complete while there are outstanding references to the target bitmap."),
+	(0x88990023, "D2DERR_ORIGINAL_TARGET_NOT_BOUND", "The operation failed because the original target is not currently bound as a target."),
+	(0x88990024, "D2DERR_INVALID_TARGET", "Cannot set the image as a target because it is either an effect or is a bitmap that does not have the D2D1_BITMAP_OPTIONS_TARGET flag set."),
+	(0x88990025, "D2DERR_BITMAP_BOUND_AS_TARGET", "Cannot draw with a bitmap that is currently bound as the target bitmap."),
+	(0x88990026, "D2DERR_INSUFFICIENT_DEVICE_CAPABILITIES", "D3D Device does not have sufficient capabilities to perform the requested action."),
+	(0x88990027, "D2DERR_INTERMEDIATE_TOO_LARGE", "The graph could not be rendered with the context's current tiling settings."),
+	(0x88990028, "D2DERR_EFFECT_IS_NOT_REGISTERED", "The CLSID provided to Unregister did not correspond to a registered effect."),
+	(0x88990029, "D2DERR_INVALID_PROPERTY", "The specified property does not exist."),
+	(0x8899002a, "D2DERR_NO_SUBPROPERTIES", "The specified sub-property does not exist."),
+	(0x8899002b, "D2DERR_PRINT_JOB_CLOSED", "AddPage or Close called after print job is already closed."),
+	(0x8899002c, "D2DERR_PRINT_FORMAT_NOT_SUPPORTED", "Error during print control creation. Indicates that none of the package target types (representing printer formats) are supported by Direct2D print control."),
+	(0x8899002d, "D2DERR_TOO_MANY_TRANSFORM_INPUTS", "An effect attempted to use a transform with too many inputs."),
+	(0x8899002e, "D2DERR_INVALID_GLYPH_IMAGE", "The specified glyph image is invalid."),
+	(0x89010001, "ERROR_IO_PREEMPTED", "The operation was preempted by a higher priority operation. It must be resumed later."),
+	(0x89020001, "JSCRIPT_E_CANTEXECUTE", "Function could not execute because it was deleted or garbage collected."),
+	(0xc0090001, "ERROR_AUDITING_DISABLED", "The specified event is currently not being audited."),
+	(0xc0090002, "ERROR_ALL_SIDS_FILTERED", "The SID filtering operation removed all SIDs."),
+	(0xc0090003, "ERROR_BIZRULES_NOT_ENABLED", "Business rule scripts are disabled for the calling application."),
+	(0xc02605e8, "ERROR_GRAPHICS_SESSION_TYPE_CHANGE_IN_PROGRESS", "Graphics session type change is in progress."),
+	(0xc0261003, "ERROR_MONITOR_INVALID_DESCRIPTOR_CHECKSUM", "Monitor descriptor contains an invalid checksum."),
+	(0xc0261004, "ERROR_MONITOR_INVALID_STANDARD_TIMING_BLOCK", "Monitor descriptor contains an invalid standard timing block."),
+	(0xc0261005, "ERROR_MONITOR_WMI_DATABLOCK_REGISTRATION_FAILED", "WMI data block registration failed for one of the MSMonitorClass WMI subclasses."),
+	(0xc0261006, "ERROR_MONITOR_INVALID_SERIAL_NUMBER_MONDSC_BLOCK", "Provided monitor descriptor block is either corrupted or does not contain monitor's detailed serial number."),
+	(0xc0261007, "ERROR_MONITOR_INVALID_USER_FRIENDLY_MONDSC_BLOCK", "Provided monitor descriptor block is either corrupted or does not contain monitor's user friendly name."),
+	(0xc0261008, "ERROR_MONITOR_NO_MORE_DESCRIPTOR_DATA", "There is no monitor descriptor data at the specified (offset, size) region."),
+	(0xc0261009, "ERROR_MONITOR_INVALID_DETAILED_TIMING_BLOCK", "Monitor descriptor contains an invalid detailed timing block."),
+	(0xc026100a, "ERROR_MONITOR_INVALID_MANUFACTURE_DATE", "Monitor descriptor contains invalid manufacture date."),
+	(0xc0262000, "ERROR_GRAPHICS_NOT_EXCLUSIVE_MODE_OWNER", "Exclusive mode ownership is needed to create unmanaged primary allocation."),
+	(0xc0262001, "ERROR_GRAPHICS_INSUFFICIENT_DMA_BUFFER", "The driver needs more DMA buffer space in order to complete the requested operation."),
+	(0xc0262002, "ERROR_GRAPHICS_INVALID_DISPLAY_ADAPTER", "Specified display adapter handle is invalid."),
+	(0xc0262003, "ERROR_GRAPHICS_ADAPTER_WAS_RESET", "Specified display adapter and all of its state has been reset."),
+	(0xc0262004, "ERROR_GRAPHICS_INVALID_DRIVER_MODEL", "The driver stack doesn't match the expected driver model."),
+	(0xc0262005, "ERROR_GRAPHICS_PRESENT_MODE_CHANGED", "Present happened but ended up into the changed desktop mode."),
+	(0xc0262006, "ERROR_GRAPHICS_PRESENT_OCCLUDED", "Nothing to present due to desktop occlusion."),
+	(0xc0262007, "ERROR_GRAPHICS_PRESENT_DENIED", "Not able to present due to denial of desktop access."),
+	(0xc0262008, "ERROR_GRAPHICS_CANNOTCOLORCONVERT", "Not able to present with color conversion."),
+	(0xc0262009, "ERROR_GRAPHICS_DRIVER_MISMATCH", "The kernel driver detected a version mismatch between it and the user mode driver."),
+	(0xc026200b, "ERROR_GRAPHICS_PRESENT_REDIRECTION_DISABLED", "Present redirection is disabled (desktop windowing management subsystem is off)."),
+	(0xc026200c, "ERROR_GRAPHICS_PRESENT_UNOCCLUDED", "Previous exclusive VidPn source owner has released its ownership."),
+	(0xc026200d, "ERROR_GRAPHICS_WINDOWDC_NOT_AVAILABLE", "Window DC is not available for presentation."),
+	(0xc026200e, "ERROR_GRAPHICS_WINDOWLESS_PRESENT_DISABLED", "Windowless present is disabled (desktop windowing management subsystem is off)."),
+	(0xc026200f, "ERROR_GRAPHICS_PRESENT_INVALID_WINDOW", "Window handle is invalid."),
+	(0xc0262010, "ERROR_GRAPHICS_PRESENT_BUFFER_NOT_BOUND", "No buffer is bound to composition surface."),
+	(0xc0262011, "ERROR_GRAPHICS_VAIL_STATE_CHANGED", "Vail state has been changed."),
+	(0xc0262012, "ERROR_GRAPHICS_INDIRECT_DISPLAY_ABANDON_SWAPCHAIN", "Notifying indirect display UMDF class driver to abandon current swapchain."),
+	(0xc0262013, "ERROR_GRAPHICS_INDIRECT_DISPLAY_DEVICE_STOPPED", "Notifying indirect display UMDF class driver that indirect display device has been stopped."),
+	(0xc0262100, "ERROR_GRAPHICS_NO_VIDEO_MEMORY", "Not enough video memory available to complete the operation."),
+	(0xc0262101, "ERROR_GRAPHICS_CANT_LOCK_MEMORY", "Couldn't probe and lock the underlying memory of an allocation."),
+	(0xc0262102, "ERROR_GRAPHICS_ALLOCATION_BUSY", "The allocation is currently busy."),
+	(0xc0262103, "ERROR_GRAPHICS_TOO_MANY_REFERENCES", "An object being referenced has reach the maximum reference count already and can't be reference further."),
+	(0xc0262104, "ERROR_GRAPHICS_TRY_AGAIN_LATER", "A problem couldn't be solved due to some currently existing condition. The problem should be tried again later."),
+	(0xc0262105, "ERROR_GRAPHICS_TRY_AGAIN_NOW", "A problem couldn't be solved due to some currently existing condition. The problem should be tried again immediately."),
+	(0xc0262106, "ERROR_GRAPHICS_ALLOCATION_INVALID", "The allocation is invalid."),
+	(0xc0262107, "ERROR_GRAPHICS_UNSWIZZLING_APERTURE_UNAVAILABLE", "No more unswizzling aperture are currently available."),
+	(0xc0262108, "ERROR_GRAPHICS_UNSWIZZLING_APERTURE_UNSUPPORTED", "The current allocation can't be unswizzled by an aperture."),
+	(0xc0262109, "ERROR_GRAPHICS_CANT_EVICT_PINNED_ALLOCATION", "The request failed because a pinned allocation can't be evicted."),
+	(0xc0262110, "ERROR_GRAPHICS_INVALID_ALLOCATION_USAGE", "The allocation can't be used from its current segment location for the specified operation."),
+	(0xc0262111, "ERROR_GRAPHICS_CANT_RENDER_LOCKED_ALLOCATION", "A locked allocation can't be used in the current command buffer."),
+	(0xc0262112, "ERROR_GRAPHICS_ALLOCATION_CLOSED", "The allocation being referenced has been closed permanently."),
+	(0xc0262113, "ERROR_GRAPHICS_INVALID_ALLOCATION_INSTANCE", "An invalid allocation instance is being referenced."),
+	(0xc0262114, "ERROR_GRAPHICS_INVALID_ALLOCATION_HANDLE", "An invalid allocation handle is being referenced."),
+	(0xc0262115, "ERROR_GRAPHICS_WRONG_ALLOCATION_DEVICE", "The allocation being referenced doesn't belong to the current device."),
+	(0xc0262116, "ERROR_GRAPHICS_ALLOCATION_CONTENT_LOST", "The specified allocation lost its content."),
+	(0xc0262200, "ERROR_GRAPHICS_GPU_EXCEPTION_ON_DEVICE", "GPU exception is detected on the given device. The device is not able to be scheduled."),
+	(0xc0262300, "ERROR_GRAPHICS_INVALID_VIDPN_TOPOLOGY", "Specified VidPN topology is invalid."),
+	(0xc0262301, "ERROR_GRAPHICS_VIDPN_TOPOLOGY_NOT_SUPPORTED", "Specified VidPN topology is valid but is not supported by this model of the display adapter."),
+	(0xc0262302, "ERROR_GRAPHICS_VIDPN_TOPOLOGY_CURRENTLY_NOT_SUPPORTED", "Specified VidPN topology is valid but is not supported by the display adapter at this time, due to current allocation of its resources."),
+	(0xc0262303, "ERROR_GRAPHICS_INVALID_VIDPN", "Specified VidPN handle is invalid."),
+	(0xc0262304, "ERROR_GRAPHICS_INVALID_VIDEO_PRESENT_SOURCE", "Specified video present source is invalid."),
+	(0xc0262305, "ERROR_GRAPHICS_INVALID_VIDEO_PRESENT_TARGET", "Specified video present target is invalid."),
+	(0xc0262306, "ERROR_GRAPHICS_VIDPN_MODALITY_NOT_SUPPORTED", "Specified VidPN modality is not supported (e.g. at least two of the pinned modes are not cofunctional)."),
+	(0xc0262308, "ERROR_GRAPHICS_INVALID_VIDPN_SOURCEMODESET", "Specified VidPN source mode set is invalid."),
+	(0xc0262309, "ERROR_GRAPHICS_INVALID_VIDPN_TARGETMODESET", "Specified VidPN target mode set is invalid."),
+	(0xc026230a, "ERROR_GRAPHICS_INVALID_FREQUENCY", "Specified video signal frequency is invalid."),
+	(0xc026230b, "ERROR_GRAPHICS_INVALID_ACTIVE_REGION", "Specified video signal active region is invalid."),
+	(0xc026230c, "ERROR_GRAPHICS_INVALID_TOTAL_REGION", "Specified video signal total region is invalid."),
+	(0xc0262310, "ERROR_GRAPHICS_INVALID_VIDEO_PRESENT_SOURCE_MODE", "Specified video present source mode is invalid."),
+	(0xc0262311, "ERROR_GRAPHICS_INVALID_VIDEO_PRESENT_TARGET_MODE", "Specified video present target mode is invalid."),
+	(0xc0262312, "ERROR_GRAPHICS_PINNED_MODE_MUST_REMAIN_IN_SET", "Pinned mode must remain in the set on VidPN's cofunctional modality enumeration."),
+	(0xc0262313, "ERROR_GRAPHICS_PATH_ALREADY_IN_TOPOLOGY", "Specified video present path is already in VidPN's topology."),
+	(0xc0262314, "ERROR_GRAPHICS_MODE_ALREADY_IN_MODESET", "Specified mode is already in the mode set."),
+	(0xc0262315, "ERROR_GRAPHICS_INVALID_VIDEOPRESENTSOURCESET", "Specified video present source set is invalid."),
+	(0xc0262316, "ERROR_GRAPHICS_INVALID_VIDEOPRESENTTARGETSET", "Specified video present target set is invalid."),
+	(0xc0262317, "ERROR_GRAPHICS_SOURCE_ALREADY_IN_SET", "Specified video present source is already in the video present source set."),
+	(0xc0262318, "ERROR_GRAPHICS_TARGET_ALREADY_IN_SET", "Specified video present target is already in the video present target set."),
+	(0xc0262319, "ERROR_GRAPHICS_INVALID_VIDPN_PRESENT_PATH", "Specified VidPN present path is invalid."),
+	(0xc026231a, "ERROR_GRAPHICS_NO_RECOMMENDED_VIDPN_TOPOLOGY", "Miniport has no recommendation for augmentation of the specified VidPN's topology."),
+	(0xc026231b, "ERROR_GRAPHICS_INVALID_MONITOR_FREQUENCYRANGESET", "Specified monitor frequency range set is invalid."),
+	(0xc026231c, "ERROR_GRAPHICS_INVALID_MONITOR_FREQUENCYRANGE", "Specified monitor frequency range is invalid."),
+	(0xc026231d, "ERROR_GRAPHICS_FREQUENCYRANGE_NOT_IN_SET", "Specified frequency range is not in the specified monitor frequency range set."),
+	(0xc026231f, "ERROR_GRAPHICS_FREQUENCYRANGE_ALREADY_IN_SET", "Specified frequency range is already in the specified monitor frequency range set."),
+	(0xc0262320, "ERROR_GRAPHICS_STALE_MODESET", "Specified mode set is stale. Please reacquire the new mode set."),
+	(0xc0262321, "ERROR_GRAPHICS_INVALID_MONITOR_SOURCEMODESET", "Specified monitor source mode set is invalid."),
+	(0xc0262322, "ERROR_GRAPHICS_INVALID_MONITOR_SOURCE_MODE", "Specified monitor source mode is invalid."),
+	(0xc0262323, "ERROR_GRAPHICS_NO_RECOMMENDED_FUNCTIONAL_VIDPN", "Miniport does not have any recommendation regarding the request to provide a functional VidPN given the current display adapter configuration."),
+	(0xc0262324, "ERROR_GRAPHICS_MODE_ID_MUST_BE_UNIQUE", "ID of the specified mode is already used by another mode in the set."),
+	(0xc0262325, "ERROR_GRAPHICS_EMPTY_ADAPTER_MONITOR_MODE_SUPPORT_INTERSECTION", "System failed to determine a mode that is supported by both the display adapter and the monitor connected to it."),
+	(0xc0262326, "ERROR_GRAPHICS_VIDEO_PRESENT_TARGETS_LESS_THAN_SOURCES", "Number of video present targets must be greater than or equal to the number of video present sources."),
+	(0xc0262327, "ERROR_GRAPHICS_PATH_NOT_IN_TOPOLOGY", "Specified present path is not in VidPN's topology."),
+	(0xc0262328, "ERROR_GRAPHICS_ADAPTER_MUST_HAVE_AT_LEAST_ONE_SOURCE", "Display adapter must have at least one video present source."),
+	(0xc0262329, "ERROR_GRAPHICS_ADAPTER_MUST_HAVE_AT_LEAST_ONE_TARGET", "Display adapter must have at least one video present target."),
+	(0xc026232a, "ERROR_GRAPHICS_INVALID_MONITORDESCRIPTORSET", "Specified monitor descriptor set is invalid."),
+	(0xc026232b, "ERROR_GRAPHICS_INVALID_MONITORDESCRIPTOR", "Specified monitor descriptor is invalid."),
+	(0xc026232c, "ERROR_GRAPHICS_MONITORDESCRIPTOR_NOT_IN_SET", "Specified descriptor is not in the specified monitor descriptor set."),
+	(0xc026232d, "ERROR_GRAPHICS_MONITORDESCRIPTOR_ALREADY_IN_SET", "Specified descriptor is already in the specified monitor descriptor set."),
+	(0xc026232e, "ERROR_GRAPHICS_MONITORDESCRIPTOR_ID_MUST_BE_UNIQUE", "ID of the specified monitor descriptor is already used by another descriptor in the set."),
+	(0xc026232f, "ERROR_GRAPHICS_INVALID_VIDPN_TARGET_SUBSET_TYPE", "Specified video present target subset type is invalid."),
+	(0xc0262330, "ERROR_GRAPHICS_RESOURCES_NOT_RELATED", "Two or more of the specified resources are not related to each other, as defined by the interface semantics."),
+	(0xc0262331, "ERROR_GRAPHICS_SOURCE_ID_MUST_BE_UNIQUE", "ID of the specified video present source is already used by another source in the set."),
+	(0xc0262332, "ERROR_GRAPHICS_TARGET_ID_MUST_BE_UNIQUE", "ID of the specified video present target is already used by another target in the set."),
+	(0xc0262333, "ERROR_GRAPHICS_NO_AVAILABLE_VIDPN_TARGET", "Specified VidPN source cannot be used because there is no available VidPN target to connect it to."),
+	(0xc0262334, "ERROR_GRAPHICS_MONITOR_COULD_NOT_BE_ASSOCIATED_WITH_ADAPTER", "Newly arrived monitor could not be associated with a display adapter."),
+	(0xc0262335, "ERROR_GRAPHICS_NO_VIDPNMGR", "Display adapter in question does not have an associated VidPN manager."),
+	(0xc0262336, "ERROR_GRAPHICS_NO_ACTIVE_VIDPN", "VidPN manager of the display adapter in question does not have an active VidPN."),
+	(0xc0262337, "ERROR_GRAPHICS_STALE_VIDPN_TOPOLOGY", "Specified VidPN topology is stale. Please reacquire the new topology."),
+	(0xc0262338, "ERROR_GRAPHICS_MONITOR_NOT_CONNECTED", "There is no monitor connected on the specified video present target."),
+	(0xc0262339, "ERROR_GRAPHICS_SOURCE_NOT_IN_TOPOLOGY", "Specified source is not part of the specified VidPN's topology."),
+	(0xc026233a, "ERROR_GRAPHICS_INVALID_PRIMARYSURFACE_SIZE", "Specified primary surface size is invalid."),
+	(0xc026233b, "ERROR_GRAPHICS_INVALID_VISIBLEREGION_SIZE", "Specified visible region size is invalid."),
+	(0xc026233c, "ERROR_GRAPHICS_INVALID_STRIDE", "Specified stride is invalid."),
+	(0xc026233d, "ERROR_GRAPHICS_INVALID_PIXELFORMAT", "Specified pixel format is invalid."),
+	(0xc026233e, "ERROR_GRAPHICS_INVALID_COLORBASIS", "Specified color basis is invalid."),
+	(0xc026233f, "ERROR_GRAPHICS_INVALID_PIXELVALUEACCESSMODE", "Specified pixel value access mode is invalid."),
+	(0xc0262340, "ERROR_GRAPHICS_TARGET_NOT_IN_TOPOLOGY", "Specified target is not part of the specified VidPN's topology."),
+	(0xc0262341, "ERROR_GRAPHICS_NO_DISPLAY_MODE_MANAGEMENT_SUPPORT", "Failed to acquire display mode management interface."),
+	(0xc0262342, "ERROR_GRAPHICS_VIDPN_SOURCE_IN_USE", "Specified VidPN source is already owned by a DMM client and cannot be used until that client releases it."),
+	(0xc0262343, "ERROR_GRAPHICS_CANT_ACCESS_ACTIVE_VIDPN", "Specified VidPN is active and cannot be accessed."),
+	(0xc0262344, "ERROR_GRAPHICS_INVALID_PATH_IMPORTANCE_ORDINAL", "Specified VidPN present path importance ordinal is invalid."),
+	(0xc0262345, "ERROR_GRAPHICS_INVALID_PATH_CONTENT_GEOMETRY_TRANSFORMATION", "Specified VidPN present path content geometry transformation is invalid."),
+	(0xc0262346, "ERROR_GRAPHICS_PATH_CONTENT_GEOMETRY_TRANSFORMATION_NOT_SUPPORTED", "Specified content geometry transformation is not supported on the respective VidPN present path."),
+	(0xc0262347, "ERROR_GRAPHICS_INVALID_GAMMA_RAMP", "Specified gamma ramp is invalid."),
+	(0xc0262348, "ERROR_GRAPHICS_GAMMA_RAMP_NOT_SUPPORTED", "Specified gamma ramp is not supported on the respective VidPN present path."),
+	(0xc0262349, "ERROR_GRAPHICS_MULTISAMPLING_NOT_SUPPORTED", "Multi-sampling is not supported on the respective VidPN present path."),
+	(0xc026234a, "ERROR_GRAPHICS_MODE_NOT_IN_MODESET", "Specified mode is not in the specified mode set."),
+	(0xc026234d, "ERROR_GRAPHICS_INVALID_VIDPN_TOPOLOGY_RECOMMENDATION_REASON", "Specified VidPN topology recommendation reason is invalid."),
+	(0xc026234e, "ERROR_GRAPHICS_INVALID_PATH_CONTENT_TYPE", "Specified VidPN present path content type is invalid."),
+	(0xc026234f, "ERROR_GRAPHICS_INVALID_COPYPROTECTION_TYPE", "Specified VidPN present path copy protection type is invalid."),
+	(0xc0262350, "ERROR_GRAPHICS_UNASSIGNED_MODESET_ALREADY_EXISTS", "No more than one unassigned mode set can exist at any given time for a given VidPN source/target."),
+	(0xc0262352, "ERROR_GRAPHICS_INVALID_SCANLINE_ORDERING", "Specified scanline ordering type is invalid."),
+	(0xc0262353, "ERROR_GRAPHICS_TOPOLOGY_CHANGES_NOT_ALLOWED", "Topology changes are not allowed for the specified VidPN."),
+	(0xc0262354, "ERROR_GRAPHICS_NO_AVAILABLE_IMPORTANCE_ORDINALS", "All available importance ordinals are already used in specified topology."),
+	(0xc0262355, "ERROR_GRAPHICS_INCOMPATIBLE_PRIVATE_FORMAT", "Specified primary surface has a different private format attribute than the current primary surface."),
+	(0xc0262356, "ERROR_GRAPHICS_INVALID_MODE_PRUNING_ALGORITHM", "Specified mode pruning algorithm is invalid."),
+	(0xc0262357, "ERROR_GRAPHICS_INVALID_MONITOR_CAPABILITY_ORIGIN", "Specified monitor capability origin is invalid."),
+	(0xc0262358, "ERROR_GRAPHICS_INVALID_MONITOR_FREQUENCYRANGE_CONSTRAINT", "Specified monitor frequency range constraint is invalid."),
+	(0xc0262359, "ERROR_GRAPHICS_MAX_NUM_PATHS_REACHED", "Maximum supported number of present paths has been reached."),
+	(0xc026235a, "ERROR_GRAPHICS_CANCEL_VIDPN_TOPOLOGY_AUGMENTATION", "Miniport requested that augmentation be cancelled for the specified source of the specified VidPN's topology."),
+	(0xc026235b, "ERROR_GRAPHICS_INVALID_CLIENT_TYPE", "Specified client type was not recognized."),
+	(0xc026235c, "ERROR_GRAPHICS_CLIENTVIDPN_NOT_SET", "Client VidPN is not set on this adapter (e.g. no user mode initiated mode changes took place on this adapter yet)."),
+	(0xc0262400, "ERROR_GRAPHICS_SPECIFIED_CHILD_ALREADY_CONNECTED", "Specified display adapter child device already has an external device connected to it."),
+	(0xc0262401, "ERROR_GRAPHICS_CHILD_DESCRIPTOR_NOT_SUPPORTED", "Specified display adapter child device does not support descriptor exposure."),
+	(0xc0262430, "ERROR_GRAPHICS_NOT_A_LINKED_ADAPTER", "The display adapter is not linked to any other adapters."),
+	(0xc0262431, "ERROR_GRAPHICS_LEADLINK_NOT_ENUMERATED", "Lead adapter in a linked configuration was not enumerated yet."),
+	(0xc0262432, "ERROR_GRAPHICS_CHAINLINKS_NOT_ENUMERATED", "Some chain adapters in a linked configuration were not enumerated yet."),
+	(0xc0262433, "ERROR_GRAPHICS_ADAPTER_CHAIN_NOT_READY", "The chain of linked adapters is not ready to start because of an unknown failure."),
+	(0xc0262434, "ERROR_GRAPHICS_CHAINLINKS_NOT_STARTED", "An attempt was made to start a lead link display adapter when the chain links were not started yet."),
+	(0xc0262435, "ERROR_GRAPHICS_CHAINLINKS_NOT_POWERED_ON", "An attempt was made to power up a lead link display adapter when the chain links were powered down."),
+	(0xc0262436, "ERROR_GRAPHICS_INCONSISTENT_DEVICE_LINK_STATE", "The adapter link was found to be in an inconsistent state. Not all adapters are in an expected PNP/Power state."),
+	(0xc0262438, "ERROR_GRAPHICS_NOT_POST_DEVICE_DRIVER", "The driver trying to start is not the same as the driver for the POSTed display adapter."),
+	(0xc026243b, "ERROR_GRAPHICS_ADAPTER_ACCESS_NOT_EXCLUDED", "An operation is being attempted that requires the display adapter to be in a quiescent state."),
+	(0xc0262500, "ERROR_GRAPHICS_OPM_NOT_SUPPORTED", "The driver does not support OPM."),
+	(0xc0262501, "ERROR_GRAPHICS_COPP_NOT_SUPPORTED", "The driver does not support COPP."),
+	(0xc0262502, "ERROR_GRAPHICS_UAB_NOT_SUPPORTED", "The driver does not support UAB."),
+	(0xc0262503, "ERROR_GRAPHICS_OPM_INVALID_ENCRYPTED_PARAMETERS", "The specified encrypted parameters are invalid."),
+	(0xc0262505, "ERROR_GRAPHICS_OPM_NO_VIDEO_OUTPUTS_EXIST", "The GDI display device passed to this function does not have any active video outputs."),
+	(0xc026250b, "ERROR_GRAPHICS_OPM_INTERNAL_ERROR", "An internal error caused this operation to fail."),
+	(0xc026250c, "ERROR_GRAPHICS_OPM_INVALID_HANDLE", "The function failed because the caller passed in an invalid OPM user mode handle."),
+	(0xc026250e, "ERROR_GRAPHICS_PVP_INVALID_CERTIFICATE_LENGTH", "A certificate could not be returned because the certificate buffer passed to the function was too small."),
+	(0xc026250f, "ERROR_GRAPHICS_OPM_SPANNING_MODE_ENABLED", "A video output could not be created because the frame buffer is in spanning mode."),
+	(0xc0262510, "ERROR_GRAPHICS_OPM_THEATER_MODE_ENABLED", "A video output could not be created because the frame buffer is in theater mode."),
+	(0xc0262511, "ERROR_GRAPHICS_PVP_HFS_FAILED", "The function failed because the display adapter's Hardware Functionality Scan failed to validate the graphics hardware."),
+	(0xc0262512, "ERROR_GRAPHICS_OPM_INVALID_SRM", "The HDCP System Renewability Message passed to this function did not comply with section 5 of the HDCP 1.1 specification."),
+	(0xc0262513, "ERROR_GRAPHICS_OPM_OUTPUT_DOES_NOT_SUPPORT_HDCP", "The video output cannot enable the High-bandwidth Digital Content Protection (HDCP) System because it does not support HDCP."),
+	(0xc0262514, "ERROR_GRAPHICS_OPM_OUTPUT_DOES_NOT_SUPPORT_ACP", "The video output cannot enable Analogue Copy Protection (ACP) because it does not support ACP."),
+	(0xc0262515, "ERROR_GRAPHICS_OPM_OUTPUT_DOES_NOT_SUPPORT_CGMSA", "The video output cannot enable the Content Generation Management System Analogue (CGMS-A) protection technology because it does not support CGMS-A."),
+	(0xc0262516, "ERROR_GRAPHICS_OPM_HDCP_SRM_NEVER_SET", "The IOPMVideoOutput::GetInformation method cannot return the version of the SRM being used because the application never successfully passed an SRM to the video output."),
+	(0xc0262517, "ERROR_GRAPHICS_OPM_RESOLUTION_TOO_HIGH", "The IOPMVideoOutput::Configure method cannot enable the specified output protection technology because the output's screen resolution is too high."),
+	(0xc0262518, "ERROR_GRAPHICS_OPM_ALL_HDCP_HARDWARE_ALREADY_IN_USE", "The IOPMVideoOutput::Configure method cannot enable HDCP because the display adapter's HDCP hardware is already being used by other physical outputs."),
+	(0xc026251a, "ERROR_GRAPHICS_OPM_VIDEO_OUTPUT_NO_LONGER_EXISTS", "The operating system asynchronously destroyed this OPM video output because the operating system's state changed. This error typically occurs because the monitor PDO associated with this video output was removed, the monitor PDO associated with this video output was stopped, the video output's session became a non-console session or the video output's desktop became an inactive desktop."),
+	(0xc026251b, "ERROR_GRAPHICS_OPM_SESSION_TYPE_CHANGE_IN_PROGRESS", "The method failed because the session is changing its type. No IOPMVideoOutput methods can be called when a session is changing its type."),
+	(0xc026251c, "ERROR_GRAPHICS_OPM_VIDEO_OUTPUT_DOES_NOT_HAVE_COPP_SEMANTICS", "Either the IOPMVideoOutput::COPPCompatibleGetInformation, IOPMVideoOutput::GetInformation, or IOPMVideoOutput::Configure method failed. This error is returned when the caller tries to use a COPP specific command while the video output has OPM semantics only."),
+	(0xc026251d, "ERROR_GRAPHICS_OPM_INVALID_INFORMATION_REQUEST", "The IOPMVideoOutput::GetInformation and IOPMVideoOutput::COPPCompatibleGetInformation methods return this error if the passed in sequence number is not the expected sequence number or the passed in OMAC value is invalid."),
+	(0xc026251e, "ERROR_GRAPHICS_OPM_DRIVER_INTERNAL_ERROR", "The method failed because an unexpected error occurred inside of a display driver."),
+	(0xc026251f, "ERROR_GRAPHICS_OPM_VIDEO_OUTPUT_DOES_NOT_HAVE_OPM_SEMANTICS", "Either the IOPMVideoOutput::COPPCompatibleGetInformation, IOPMVideoOutput::GetInformation, or IOPMVideoOutput::Configure method failed. This error is returned when the caller tries to use an OPM specific command while the video output has COPP semantics only."),
+	(0xc0262520, "ERROR_GRAPHICS_OPM_SIGNALING_NOT_SUPPORTED", "The IOPMVideoOutput::COPPCompatibleGetInformation or IOPMVideoOutput::Configure method failed because the display driver does not support the OPM_GET_ACP_AND_CGMSA_SIGNALING and OPM_SET_ACP_AND_CGMSA_SIGNALING GUIDs."),
+	(0xc0262521, "ERROR_GRAPHICS_OPM_INVALID_CONFIGURATION_REQUEST", "The IOPMVideoOutput::Configure function returns this error code if the passed in sequence number is not the expected sequence number or the passed in OMAC value is invalid."),
+	(0xc0262580, "ERROR_GRAPHICS_I2C_NOT_SUPPORTED", "The monitor connected to the specified video output does not have an I2C bus."),
+	(0xc0262581, "ERROR_GRAPHICS_I2C_DEVICE_DOES_NOT_EXIST", "No device on the I2C bus has the specified address."),
+	(0xc0262582, "ERROR_GRAPHICS_I2C_ERROR_TRANSMITTING_DATA", "An error occurred while transmitting data to the device on the I2C bus."),
+	(0xc0262583, "ERROR_GRAPHICS_I2C_ERROR_RECEIVING_DATA", "An error occurred while receiving data from the device on the I2C bus."),
+	(0xc0262584, "ERROR_GRAPHICS_DDCCI_VCP_NOT_SUPPORTED", "The monitor does not support the specified VCP code."),
+	(0xc0262585, "ERROR_GRAPHICS_DDCCI_INVALID_DATA", "The data received from the monitor is invalid."),
+	(0xc0262586, "ERROR_GRAPHICS_DDCCI_MONITOR_RETURNED_INVALID_TIMING_STATUS_BYTE", "The function failed because a monitor returned an invalid Timing Status byte when the operating system used the DDC/CI Get Timing Report & Timing Message command to get a timing report from a monitor."),
+	(0xc0262587, "ERROR_GRAPHICS_MCA_INVALID_CAPABILITIES_STRING", "The monitor returned a DDC/CI capabilities string which did not comply with the ACCESS.bus 3.0, DDC/CI 1.1, or MCCS 2 Revision 1 specification."),
+	(0xc0262588, "ERROR_GRAPHICS_MCA_INTERNAL_ERROR", "An internal Monitor Configuration API error occurred."),
+	(0xc0262589, "ERROR_GRAPHICS_DDCCI_INVALID_MESSAGE_COMMAND", "An operation failed because a DDC/CI message had an invalid value in its command field."),
+	(0xc026258a, "ERROR_GRAPHICS_DDCCI_INVALID_MESSAGE_LENGTH", "An error occurred because the field length of a DDC/CI message contained an invalid value."),
+	(0xc026258b, "ERROR_GRAPHICS_DDCCI_INVALID_MESSAGE_CHECKSUM", "An error occurred because the checksum field in a DDC/CI message did not match the message's computed checksum value."),
+	(0xc026258c, "ERROR_GRAPHICS_INVALID_PHYSICAL_MONITOR_HANDLE", "This function failed because an invalid monitor handle was passed to it."),
+	(0xc026258d, "ERROR_GRAPHICS_MONITOR_NO_LONGER_EXISTS", "The operating system asynchronously destroyed the monitor which corresponds to this handle because the operating system's state changed."),
+	(0xc02625d8, "ERROR_GRAPHICS_DDCCI_CURRENT_CURRENT_VALUE_GREATER_THAN_MAXIMUM_VALUE", "A continuous VCP code's current value is greater than its maximum value."),
+	(0xc02625d9, "ERROR_GRAPHICS_MCA_INVALID_VCP_VERSION", "The monitor's VCP Version (0xDF) VCP code returned an invalid version value."),
+	(0xc02625da, "ERROR_GRAPHICS_MCA_MONITOR_VIOLATES_MCCS_SPECIFICATION", "The monitor does not comply with the MCCS specification it claims to support."),
+	(0xc02625db, "ERROR_GRAPHICS_MCA_MCCS_VERSION_MISMATCH", "The MCCS version in a monitor's mccs_ver capability does not match the MCCS version the monitor reports when the VCP Version (0xDF) VCP code is used."),
+	(0xc02625dc, "ERROR_GRAPHICS_MCA_UNSUPPORTED_MCCS_VERSION", "The Monitor Configuration API only works with monitors that support the MCCS 1.0 specification, MCCS 2.0 specification or the MCCS 2.0 Revision 1 specification."),
+	(0xc02625de, "ERROR_GRAPHICS_MCA_INVALID_TECHNOLOGY_TYPE_RETURNED", "The monitor returned an invalid monitor technology type. CRT, Plasma and LCD (TFT) are examples of monitor technology types."),
+	(0xc02625df, "ERROR_GRAPHICS_MCA_UNSUPPORTED_COLOR_TEMPERATURE", "SetMonitorColorTemperature()'s caller passed a color temperature to it which the current monitor did not support."),
+	(0xc02625e0, "ERROR_GRAPHICS_ONLY_CONSOLE_SESSION_SUPPORTED", "This function can only be used if a program is running in the local console session. It cannot be used if the program is running on a remote desktop session or on a terminal server session."),
+	(0xc02625e1, "ERROR_GRAPHICS_NO_DISPLAY_DEVICE_CORRESPONDS_TO_NAME", "This function cannot find an actual GDI display device which corresponds to the specified GDI display device name."),
+	(0xc02625e2, "ERROR_GRAPHICS_DISPLAY_DEVICE_NOT_ATTACHED_TO_DESKTOP", "The function failed because the specified GDI display device was not attached to the Windows desktop."),
+	(0xc02625e3, "ERROR_GRAPHICS_MIRRORING_DEVICES_NOT_SUPPORTED", "This function does not support GDI mirroring display devices because GDI mirroring display devices do not have any physical monitors associated with them."),
+	(0xc02625e4, "ERROR_GRAPHICS_INVALID_POINTER", "The function failed because an invalid pointer parameter was passed to it."),
+	(0xc02625e5, "ERROR_GRAPHICS_NO_MONITORS_CORRESPOND_TO_DISPLAY_DEVICE", "The function failed because the specified GDI device did not have any monitors associated with it."),
+	(0xc02625e6, "ERROR_GRAPHICS_PARAMETER_ARRAY_TOO_SMALL", "An array passed to the function cannot hold all of the data that the function must copy into the array."),
+	(0xc02625e7, "ERROR_GRAPHICS_INTERNAL_ERROR", "An internal error caused an operation to fail."),
+	(0xc05c0000, "ERROR_SVHDX_ERROR_STORED", "The initiator does not have a valid reservation."),
+	(0xc05cff00, "ERROR_SVHDX_ERROR_NOT_AVAILABLE", "The sense data is not available."),
+	(0xc05cff01, "ERROR_SVHDX_UNIT_ATTENTION_AVAILABLE", "A unit attention condition is available."),
+	(0xc05cff02, "ERROR_SVHDX_UNIT_ATTENTION_CAPACITY_DATA_CHANGED", "The capacity data of the shared virtual disk has changed."),
+	(0xc05cff03, "ERROR_SVHDX_UNIT_ATTENTION_RESERVATIONS_PREEMPTED", "The reservations of the shared virtual disk have been preempted."),
+	(0xc05cff04, "ERROR_SVHDX_UNIT_ATTENTION_RESERVATIONS_RELEASED", "The reservations of the shared virtual disk have been released."),
+	(0xc05cff05, "ERROR_SVHDX_UNIT_ATTENTION_REGISTRATIONS_PREEMPTED", "The registrations of the shared virtual disk have been preempted."),
+	(0xc05cff06, "ERROR_SVHDX_UNIT_ATTENTION_OPERATING_DEFINITION_CHANGED", "The operating definition of the shared virtual disk has changed."),
+	(0xc05cff07, "ERROR_SVHDX_RESERVATION_CONFLICT", "The requested operation conflicts with a reservation on the shared virtual disk."),
+	(0xc05cff08, "ERROR_SVHDX_WRONG_FILE_TYPE", "The requested operation is not supported by this file type."),
+	(0xc05cff09, "ERROR_SVHDX_VERSION_MISMATCH", "The version of the shared virtual disk is not compatible."),
+	(0xc05cff0a, "ERROR_VHD_SHARED", "The virtual disk is shared and cannot be opened in this mode."),
+	(0xc05cff0b, "ERROR_SVHDX_NO_INITIATOR", "The shared virtual disk has no initiator."),
+	(0xc05cff0c, "ERROR_VHDSET_BACKING_STORAGE_NOT_FOUND", "The backing storage of the VHD set could not be found."),
+	(0xc05d0000, "ERROR_SMB_NO_PREAUTH_INTEGRITY_HASH_OVERLAP", "The client and server have no overlapping pre-authentication integrity hash algorithms."),
+	(0xc05d0001, "ERROR_SMB_BAD_CLUSTER_DIALECT", "The SMB cluster dialect is not supported."),
+	(0xc0e80000, "ERROR_SECCORE_INVALID_COMMAND", "The secure core command is invalid."),
+	(0xc0ea0001, "ERROR_NO_APPLICABLE_APP_LICENSES_FOUND", "No applicable app licenses found."),
+	(0xc0ea0002, "ERROR_CLIP_LICENSE_NOT_FOUND", "CLiP license not found."),
+	(0xc0ea0003, "ERROR_CLIP_DEVICE_LICENSE_MISSING", "CLiP device license not found."),
+	(0xc0ea0004, "ERROR_CLIP_LICENSE_INVALID_SIGNATURE", "CLiP license has an invalid signature."),
+	(0xc0ea0005, "ERROR_CLIP_KEYHOLDER_LICENSE_MISSING_OR_INVALID", "CLiP keyholder license is invalid or missing."),
+	(0xc0ea0006, "ERROR_CLIP_LICENSE_EXPIRED", "CLiP license has expired."),
+	(0xc0ea0007, "ERROR_CLIP_LICENSE_SIGNED_BY_UNKNOWN_SOURCE", "CLiP license is signed by an unknown source."),
+	(0xc0ea0008, "ERROR_CLIP_LICENSE_NOT_SIGNED", "CLiP license is not signed."),
+	(0xc0ea0009, "ERROR_CLIP_LICENSE_HARDWARE_ID_OUT_OF_TOLERANCE", "CLiP license hardware ID is out of tolerance."),
+	(0xc0ea000a, "ERROR_CLIP_LICENSE_DEVICE_ID_MISMATCH", "CLiP license device ID does not match the device ID in the bound device license."),
+];
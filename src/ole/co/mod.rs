@@ -1,6 +1,7 @@
 mod consts;
 mod guids;
 mod hresult;
+mod hresult_catalog;
 
 pub use consts::*;
 pub use guids::*;
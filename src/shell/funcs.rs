@@ -11,6 +11,9 @@ use crate::shell::ffi;
 /// [`CommandLineToArgv`](https://learn.microsoft.com/en-us/windows/win32/api/shellapi/nf-shellapi-commandlinetoargvw)
 /// function.
 ///
+/// For a pure-Rust alternative, which can also quote the arguments, see the
+/// [`cmd_line`](crate::cmd_line) module.
+///
 /// # Examples
 ///
 /// ```no_run
@@ -18,6 +18,8 @@ const_bitflag! { ACCELF: u8;
 	/// The `key` member specifies a virtual-key code. If this flag is not
 	/// specified key is assumed to specify a character code.
 	VIRTKEY 1
+	/// No top-level menu item is highlighted when the accelerator is used.
+	NOINVERT 0x02
 	/// The SHIFT key must be held down when the accelerator key is pressed.
 	SHIFT 0x04
 	/// The CTRL key must be held down when the accelerator key is pressed.
@@ -1060,6 +1062,17 @@ const_ordinary! { DLGID: u16;
 	CONTINUE 11
 }
 
+const_ordinary! { DPI_AWARENESS_CONTEXT: isize;
+	/// [`DPI_AWARENESS_CONTEXT`](https://learn.microsoft.com/en-us/windows/win32/hi-dpi/dpi-awareness-context)
+	/// handle (`isize`).
+	=>
+	UNAWARE -1
+	SYSTEM_AWARE -2
+	PER_MONITOR_AWARE -3
+	PER_MONITOR_AWARE_V2 -4
+	UNAWARE_GDISCALED -5
+}
+
 const_ordinary! { DPI_HOSTING_BEHAVIOR: u32;
 	/// [`DPI_HOSTING_BEHAVIOR`](https://learn.microsoft.com/en-us/windows/win32/api/windef/ne-windef-dpi_hosting_behavior)
 	/// enumeration (`u32`).
@@ -1069,6 +1082,50 @@ const_ordinary! { DPI_HOSTING_BEHAVIOR: u32;
 	MIXED 1
 }
 
+const_ws! { DS: u32;
+	/// Dialog box
+	/// [styles](https://learn.microsoft.com/en-us/windows/win32/dlgbox/dialog-box-styles)
+	/// (`u32`).
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	/// Indicates that the coordinates of the dialog box are screen coordinates.
+	ABSALIGN 0x0001
+	/// Obsolete; use `WS_EX::TOPMOST` instead.
+	SYSMODAL 0x0002
+	/// Obsolete; the system automatically applies the three-dimensional look.
+	_3DLOOK 0x0004
+	/// Causes the dialog box to use the `SYSTEM_FIXED_FONT` instead of the
+	/// default `SYSTEM_FONT`.
+	FIXEDSYS 0x0008
+	/// Creates the dialog box even if errors occur.
+	NOFAILCREATE 0x0010
+	/// Applies to 16-bit applications only.
+	LOCALEDIT 0x0020
+	/// Indicates that the header of the dialog box template contains
+	/// additional data specifying the font to use.
+	SETFONT 0x0040
+	/// Creates a dialog box with a modal dialog-box frame.
+	MODALFRAME 0x0080
+	/// Suppresses `WM_ENTERIDLE` messages.
+	NOIDLEMSG 0x0100
+	/// Causes the system to bring the dialog box to the foreground.
+	SETFOREGROUND 0x0200
+	/// Creates a dialog box that works well as a child window of another dialog
+	/// box.
+	CONTROL 0x0400
+	/// Centers the dialog box in the working area of the monitor.
+	CENTER 0x0800
+	/// Centers the dialog box on the mouse cursor.
+	CENTERMOUSE 0x1000
+	/// Includes a question mark in the title bar of the dialog box.
+	CONTEXTHELP 0x2000
+	/// Indicates that the dialog box should use the system font.
+	SHELLFONT Self::SETFONT.0 | Self::FIXEDSYS.0
+	/// Indicates that the dialog coordinates are in pixels.
+	USEPIXELS 0x8000
+}
+
 const_bitflag! { DT: u32;
 	/// [`HDC::DrawText`](crate::prelude::user_Hdc::DrawText) `format` (`u32`).
 	=>
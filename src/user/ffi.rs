@@ -46,6 +46,7 @@ extern_sys! { "user32";
 	CreateAcceleratorTableW(PCVOID, i32) -> HANDLE
 	CreateDesktopExW(PCSTR, PCSTR, PCVOID, u32, u32, PVOID, u32, PVOID) -> HANDLE
 	CreateDesktopW(PCSTR, PCSTR, PCVOID, u32, u32, PVOID) -> HANDLE
+	CreateDialogIndirectParamW(HANDLE, PCVOID, HANDLE, PFUNC, isize) -> HANDLE
 	CreateDialogParamW(HANDLE, PCSTR, HANDLE, PFUNC, isize) -> HANDLE
 	CreateMenu() -> HANDLE
 	CreatePopupMenu() -> HANDLE
@@ -111,6 +112,7 @@ extern_sys! { "user32";
 	GetDlgCtrlID(HANDLE) -> i32
 	GetDlgItem(HANDLE, i32) -> HANDLE
 	GetDoubleClickTime() -> u32
+	GetDpiForSystem() -> u32
 	GetDpiForWindow(HANDLE) -> u32
 	GetFocus() -> HANDLE
 	GetForegroundWindow() -> HANDLE
@@ -191,6 +193,7 @@ extern_sys! { "user32";
 	LoadAcceleratorsW(HANDLE, PCSTR) -> HANDLE
 	LoadCursorW(HANDLE, PCSTR) -> HANDLE
 	LoadIconW(HANDLE, PCSTR) -> HANDLE
+	LoadMenuIndirectW(PCVOID) -> HANDLE
 	LoadMenuW(HANDLE, PCSTR) -> HANDLE
 	LoadStringW(HANDLE, u32, PSTR, i32) -> i32
 	LockSetForegroundWindow(u32) -> BOOL
@@ -248,6 +251,7 @@ extern_sys! { "user32";
 	SetPhysicalCursorPos(i32, i32) -> BOOL
 	SetProcessDefaultLayout(u32) -> BOOL
 	SetProcessDPIAware() -> BOOL
+	SetProcessDpiAwarenessContext(isize) -> BOOL
 	SetScrollInfo(HANDLE, i32, PCVOID, BOOL) -> i32
 	SetScrollPos(HANDLE, i32, i32, BOOL) -> i32
 	SetScrollRange(HANDLE, i32, i32, i32, BOOL) -> BOOL
@@ -272,6 +276,7 @@ extern_sys! { "user32";
 	SubtractRect(PVOID, PCVOID, PCVOID) -> BOOL
 	SwapMouseButton(BOOL) -> BOOL
 	SwitchDesktop(HANDLE) -> BOOL
+	SystemParametersInfoForDpi(u32, u32, PVOID, u32, u32) -> BOOL
 	SystemParametersInfoW(u32, u32, PVOID, u32) -> BOOL
 	TileWindows(HANDLE, u32, PCVOID, u32, PCVOID) -> u16
 	TrackMouseEvent(PVOID) -> BOOL
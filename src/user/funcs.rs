@@ -586,6 +586,13 @@ pub fn GetDoubleClickTime() -> u32 {
 	unsafe { ffi::GetDoubleClickTime() }
 }
 
+/// [`GetDpiForSystem`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getdpiforsystem)
+/// function.
+#[must_use]
+pub fn GetDpiForSystem() -> u32 {
+	unsafe { ffi::GetDpiForSystem() }
+}
+
 /// [`GetGUIThreadInfo`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getguithreadinfo)
 /// function.
 ///
@@ -1034,6 +1041,19 @@ pub fn SetProcessDPIAware() -> SysResult<()> {
 	bool_to_sysresult(unsafe { ffi::SetProcessDPIAware() })
 }
 
+/// [`SetProcessDpiAwarenessContext`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setprocessdpiawarenesscontext)
+/// function.
+///
+/// Available since Windows 10 version 1703, when
+/// [`co::DPI_AWARENESS_CONTEXT::PER_MONITOR_AWARE_V2`](crate::co::DPI_AWARENESS_CONTEXT::PER_MONITOR_AWARE_V2)
+/// was introduced.
+pub fn SetProcessDpiAwarenessContext(
+	value: co::DPI_AWARENESS_CONTEXT,
+) -> SysResult<()>
+{
+	bool_to_sysresult(unsafe { ffi::SetProcessDpiAwarenessContext(value.raw()) })
+}
+
 /// [`SetSysColors`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setsyscolors)
 /// function.
 ///
@@ -1130,6 +1150,32 @@ pub unsafe fn SystemParametersInfo<T>(
 	)
 }
 
+/// [`SystemParametersInfoForDpi`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-systemparametersinfofordpi)
+/// function.
+///
+/// # Safety
+///
+/// The `pv_param` type varies according to `action`. If you set it wrong,
+/// you're likely to cause a buffer overrun.
+pub unsafe fn SystemParametersInfoForDpi<T>(
+	action: co::SPI,
+	ui_param: u32,
+	pv_param: &mut T,
+	win_ini: co::SPIF,
+	dpi: u32,
+) -> SysResult<()>
+{
+	bool_to_sysresult(
+		ffi::SystemParametersInfoForDpi(
+			action.raw(),
+			ui_param,
+			pv_param as *mut _ as _,
+			win_ini.raw(),
+			dpi,
+		),
+	)
+}
+
 /// [`TrackMouseEvent`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-trackmouseevent)
 /// function.
 pub fn TrackMouseEvent(tme: &mut TRACKMOUSEEVENT) -> SysResult<()> {
@@ -2,6 +2,7 @@
 
 use crate::co;
 use crate::decl::*;
+use crate::guard::*;
 use crate::kernel::privs::*;
 use crate::msg::*;
 use crate::prelude::*;
@@ -495,6 +496,23 @@ pub trait user_Hmenu: Handle {
 		unsafe { ffi::IsMenu(self.ptr()) != 0 }
 	}
 
+	/// [`LoadMenuIndirect`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-loadmenuindirectw)
+	/// function.
+	///
+	/// The template can be generated with
+	/// [`MenuTemplate::serialize`](crate::MenuTemplate::serialize), or you can
+	/// simply call [`MenuTemplate::load`](crate::MenuTemplate::load).
+	///
+	/// # Safety
+	///
+	/// The data must be a valid `MENUTEMPLATE` or `MENUEX_TEMPLATE` block,
+	/// including its terminating item.
+	unsafe fn LoadMenuIndirect(menu_template: &[u8]) -> SysResult<DestroyMenuGuard> {
+		let aligned = u32_aligned(menu_template);
+		ptr_to_sysresult_handle(ffi::LoadMenuIndirectW(aligned.as_ptr() as _))
+			.map(|h| DestroyMenuGuard::new(h))
+	}
+
 	/// [`RemoveMenu`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-removemenu)
 	/// function.
 	fn RemoveMenu(&self, id_or_pos: IdPos) -> SysResult<()> {
@@ -352,6 +352,42 @@ impl<'a> MsgSendRecv for DeviceChange<'a> {
 	}
 }
 
+/// [`WM_DPICHANGED`](https://learn.microsoft.com/en-us/windows/win32/hi-dpi/wm-dpichanged)
+/// message parameters.
+///
+/// Return type: `()`.
+pub struct DpiChanged<'a> {
+	pub dpi_x: u16,
+	pub dpi_y: u16,
+	pub suggested_rect: &'a RECT,
+}
+
+impl<'a> MsgSend for DpiChanged<'a> {
+	type RetType = ();
+
+	unsafe fn isize_to_ret(&self, _: isize) -> Self::RetType {
+		()
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::WM::DPICHANGED,
+			wparam: MAKEDWORD(self.dpi_x, self.dpi_y) as _,
+			lparam: self.suggested_rect as *const _ as _,
+		}
+	}
+}
+
+impl<'a> MsgSendRecv for DpiChanged<'a> {
+	unsafe fn from_generic_wm(p: WndMsg) -> Self {
+		Self {
+			dpi_x: LOWORD(p.wparam as _),
+			dpi_y: HIWORD(p.wparam as _),
+			suggested_rect: &*(p.lparam as *const _),
+		}
+	}
+}
+
 /// [`WM_ENABLE`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-enable)
 /// message parameters.
 ///
@@ -0,0 +1,153 @@
+use crate::co;
+use crate::decl::*;
+use crate::guard::*;
+use crate::kernel::privs::*;
+use crate::prelude::*;
+
+/// Owned, pure-Rust representation of an accelerator table, which can be
+/// serialized into the block stored in an `RT_ACCELERATOR` resource, and parsed
+/// back.
+///
+/// The entries can be passed directly to
+/// [`HACCEL::CreateAcceleratorTable`](crate::prelude::user_Haccel::CreateAcceleratorTable),
+/// or simply loaded with [`AccelTable::create`](crate::AccelTable::create).
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co, seq_ids};
+///
+/// seq_ids! {
+///     ID_FILE_OPEN = 101;
+///     ID_VIEW_REFRESH
+/// }
+///
+/// let accels = w::AccelTable {
+///     entries: vec![
+///         w::ACCEL {
+///             fVirt: co::ACCELF::VIRTKEY | co::ACCELF::CONTROL,
+///             key: co::VK::CHAR_O,
+///             cmd: ID_FILE_OPEN,
+///         },
+///         w::ACCEL {
+///             fVirt: co::ACCELF::VIRTKEY,
+///             key: co::VK::F5,
+///             cmd: ID_VIEW_REFRESH,
+///         },
+///     ],
+/// };
+///
+/// assert!(accels.conflicts().is_empty());
+///
+/// let haccel = accels.create()?;
+/// # w::SysResult::Ok(())
+/// ```
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct AccelTable {
+	/// The accelerators.
+	///
+	/// Defaults to none.
+	pub entries: Vec<ACCEL>,
+}
+
+impl AccelTable {
+	const END: u16 = 0x80; // fFlags of the last ACCELTABLEENTRY
+
+	/// Parses an accelerator table, as stored in an `RT_ACCELERATOR` resource,
+	/// which is an array of
+	/// [`ACCELTABLEENTRY`](https://learn.microsoft.com/en-us/windows/win32/menurc/acceltableentry)
+	/// structs.
+	///
+	/// Malformed data yields
+	/// [`ERROR::INVALID_DATA`](crate::co::ERROR::INVALID_DATA).
+	pub fn parse(data: &[u8]) -> SysResult<Self> {
+		let mut rd = RawReader::new(data);
+		let mut entries = Vec::with_capacity(data.len() / 8);
+
+		while rd.remaining() > 0 {
+			let flags = rd.u16()?;
+			let key = rd.u16()?;
+			let cmd = rd.u16()?;
+			rd.skip(2)?; // padding
+
+			entries.push(ACCEL {
+				fVirt: unsafe { co::ACCELF::from_raw((flags & !Self::END) as _) },
+				key: unsafe { co::VK::from_raw(key) },
+				cmd,
+			});
+			if flags & Self::END != 0 {
+				break;
+			}
+		}
+
+		Ok(Self { entries })
+	}
+
+	/// Serializes the entries into an array of `ACCELTABLEENTRY` structs, which
+	/// can be parsed back with [`AccelTable::parse`](crate::AccelTable::parse).
+	#[must_use]
+	pub fn serialize(&self) -> Vec<u8> {
+		let mut wr = RawWriter::new();
+		for (idx, accel) in self.entries.iter().enumerate() {
+			let mut flags = accel.fVirt.raw() as u16;
+			if idx == self.entries.len() - 1 {
+				flags |= Self::END;
+			}
+			wr.u16(flags);
+			wr.u16(accel.key.raw());
+			wr.u16(accel.cmd);
+			wr.u16(0); // padding
+		}
+		wr.into_vec()
+	}
+
+	/// Creates the accelerator table with
+	/// [`HACCEL::CreateAcceleratorTable`](crate::prelude::user_Haccel::CreateAcceleratorTable).
+	pub fn create(&self) -> SysResult<DestroyAcceleratorTableGuard> {
+		HACCEL::CreateAcceleratorTable(&self.entries)
+	}
+
+	/// Returns the indexes of all pairs of entries which are triggered by the
+	/// same keystroke.
+	///
+	/// For character accelerators – without
+	/// [`ACCELF::VIRTKEY`](crate::co::ACCELF::VIRTKEY) – only the
+	/// [`ACCELF::ALT`](crate::co::ACCELF::ALT) modifier is considered, since
+	/// the system ignores the others.
+	///
+	/// An empty result means the table has no conflicting shortcuts.
+	#[must_use]
+	pub fn conflicts(&self) -> Vec<(usize, usize)> {
+		let keystroke = |accel: &ACCEL| {
+			let mods = if accel.fVirt.has(co::ACCELF::VIRTKEY) {
+				co::ACCELF::VIRTKEY | co::ACCELF::SHIFT | co::ACCELF::CONTROL | co::ACCELF::ALT
+			} else {
+				co::ACCELF::ALT
+			};
+			(accel.fVirt & mods, accel.key)
+		};
+
+		let mut pairs = Vec::new();
+		for (i, a) in self.entries.iter().enumerate() {
+			for (j, b) in self.entries.iter().enumerate().skip(i + 1) {
+				if keystroke(a) == keystroke(b) {
+					pairs.push((i, j));
+				}
+			}
+		}
+		pairs
+	}
+
+	/// Returns the command IDs of the entries which are not present in the
+	/// given menu, in table order.
+	///
+	/// An empty result means every shortcut has a corresponding menu item.
+	#[must_use]
+	pub fn missing_in_menu(&self, menu: &MenuTemplate) -> Vec<u16> {
+		let menu_ids = menu.command_ids();
+		self.entries.iter()
+			.map(|accel| accel.cmd)
+			.filter(|cmd| !menu_ids.contains(&(*cmd as u32)))
+			.collect()
+	}
+}
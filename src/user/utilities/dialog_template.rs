@@ -0,0 +1,455 @@
+use crate::co;
+use crate::decl::*;
+use crate::kernel::privs::*;
+use crate::prelude::*;
+
+/// Owned, pure-Rust representation of an in-memory dialog template, which can
+/// be serialized into a
+/// [`DLGTEMPLATEEX`](https://learn.microsoft.com/en-us/windows/win32/dlgbox/dlgtemplateex)
+/// block and parsed back.
+///
+/// The serialized block can be passed to
+/// [`HINSTANCE::DialogBoxIndirectParam`](crate::prelude::user_Hinstance::DialogBoxIndirectParam)
+/// and
+/// [`HINSTANCE::CreateDialogIndirectParam`](crate::prelude::user_Hinstance::CreateDialogIndirectParam),
+/// or used to create dialog-based windows in the [`gui`](crate::gui) module,
+/// without the need of a dialog resource.
+///
+/// All coordinates are in dialog units.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let tmpl = w::DialogTemplate {
+///     style: co::WS::POPUP | co::WS::CAPTION | co::WS::SYSMENU
+///         | co::DS::MODALFRAME.into(),
+///     title: "Generated dialog".to_owned(),
+///     cx: 200,
+///     cy: 80,
+///     items: vec![
+///         w::DialogTemplateItem {
+///             class: w::DialogItemClass::Edit,
+///             style: co::WS::CHILD | co::WS::VISIBLE | co::WS::TABSTOP
+///                 | co::WS::BORDER | co::ES::AUTOHSCROLL.into(),
+///             id: 1001,
+///             x: 10, y: 10, cx: 180, cy: 14,
+///             ..Default::default()
+///         },
+///         w::DialogTemplateItem {
+///             class: w::DialogItemClass::Button,
+///             title: w::ResId::from("OK"),
+///             style: co::WS::CHILD | co::WS::VISIBLE | co::WS::TABSTOP
+///                 | co::BS::DEFPUSHBUTTON.into(),
+///             id: co::DLGID::OK.raw() as _,
+///             x: 140, y: 60, cx: 50, cy: 14,
+///             ..Default::default()
+///         },
+///     ],
+///     ..Default::default()
+/// };
+///
+/// let raw_bytes = tmpl.serialize()?;
+/// # w::SysResult::Ok(())
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct DialogTemplate {
+	/// Context help identifier.
+	///
+	/// Defaults to zero.
+	pub help_id: u32,
+	/// Extended window styles.
+	///
+	/// Defaults to `WS_EX::NoValue`.
+	pub ex_style: co::WS_EX,
+	/// Window styles, which also include the [`co::DS`](crate::co::DS)
+	/// dialog styles.
+	///
+	/// [`DS::SETFONT`](crate::co::DS::SETFONT) is automatically added or
+	/// removed according to the `font` field.
+	///
+	/// Defaults to `WS::POPUP | WS::CAPTION | WS::SYSMENU | DS::MODALFRAME`.
+	pub style: co::WS,
+	/// Horizontal position.
+	///
+	/// Defaults to 0.
+	pub x: i16,
+	/// Vertical position.
+	///
+	/// Defaults to 0.
+	pub y: i16,
+	/// Width.
+	///
+	/// Defaults to 200.
+	pub cx: i16,
+	/// Height.
+	///
+	/// Defaults to 100.
+	pub cy: i16,
+	/// Menu resource, if any.
+	///
+	/// Defaults to `None`.
+	pub menu: Option<ResId>,
+	/// Window class, if not the default dialog box class.
+	///
+	/// Defaults to `None`.
+	pub class_name: Option<ResId>,
+	/// Title of the dialog.
+	///
+	/// Defaults to an empty string.
+	pub title: String,
+	/// Font of the dialog.
+	///
+	/// Defaults to 9-point "Segoe UI".
+	pub font: Option<DialogTemplateFont>,
+	/// The controls of the dialog.
+	///
+	/// Defaults to none.
+	pub items: Vec<DialogTemplateItem>,
+}
+
+impl Default for DialogTemplate {
+	fn default() -> Self {
+		Self {
+			help_id: 0,
+			ex_style: co::WS_EX::NoValue,
+			style: co::WS::POPUP | co::WS::CAPTION | co::WS::SYSMENU
+				| co::DS::MODALFRAME.into(),
+			x: 0,
+			y: 0,
+			cx: 200,
+			cy: 100,
+			menu: None,
+			class_name: None,
+			title: String::new(),
+			font: Some(DialogTemplateFont::default()),
+			items: Vec::new(),
+		}
+	}
+}
+
+/// Font of a [`DialogTemplate`](crate::DialogTemplate).
+#[derive(Clone, PartialEq, Eq)]
+pub struct DialogTemplateFont {
+	/// Point size.
+	///
+	/// Defaults to 9.
+	pub point_size: u16,
+	/// Weight, usually a `co::FW` value.
+	///
+	/// Defaults to 400, which is `FW::NORMAL`.
+	pub weight: u16,
+	/// Italic font.
+	///
+	/// Defaults to `false`.
+	pub italic: bool,
+	/// Character set, usually a `co::CHARSET` value.
+	///
+	/// Defaults to 1, which is `CHARSET::DEFAULT`.
+	pub char_set: u8,
+	/// Typeface name.
+	///
+	/// Defaults to "Segoe UI".
+	pub face_name: String,
+}
+
+impl Default for DialogTemplateFont {
+	fn default() -> Self {
+		Self {
+			point_size: 9,
+			weight: 400,
+			italic: false,
+			char_set: 1,
+			face_name: "Segoe UI".to_owned(),
+		}
+	}
+}
+
+/// A control of a [`DialogTemplate`](crate::DialogTemplate), which is
+/// serialized as a
+/// [`DLGITEMTEMPLATEEX`](https://learn.microsoft.com/en-us/windows/win32/dlgbox/dlgitemtemplateex).
+#[derive(Clone, PartialEq, Eq)]
+pub struct DialogTemplateItem {
+	/// Context help identifier.
+	///
+	/// Defaults to zero.
+	pub help_id: u32,
+	/// Extended window styles.
+	///
+	/// Defaults to `WS_EX::NoValue`.
+	pub ex_style: co::WS_EX,
+	/// Window styles, which also include the control-specific styles, like
+	/// [`co::BS`](crate::co::BS) or [`co::ES`](crate::co::ES).
+	///
+	/// Defaults to `WS::CHILD | WS::VISIBLE`.
+	pub style: co::WS,
+	/// Horizontal position.
+	///
+	/// Defaults to 0.
+	pub x: i16,
+	/// Vertical position.
+	///
+	/// Defaults to 0.
+	pub y: i16,
+	/// Width.
+	///
+	/// Defaults to 0.
+	pub cx: i16,
+	/// Height.
+	///
+	/// Defaults to 0.
+	pub cy: i16,
+	/// Control ID.
+	///
+	/// Defaults to zero.
+	pub id: u32,
+	/// Window class of the control.
+	///
+	/// Defaults to `DialogItemClass::Static`.
+	pub class: DialogItemClass,
+	/// Text of the control, or the ID of a resource, like an icon.
+	///
+	/// Defaults to an empty string.
+	pub title: ResId,
+	/// Creation data passed in the `lParam` of `WM_CREATE`.
+	///
+	/// Defaults to none.
+	pub creation_data: Vec<u8>,
+}
+
+impl Default for DialogTemplateItem {
+	fn default() -> Self {
+		Self {
+			help_id: 0,
+			ex_style: co::WS_EX::NoValue,
+			style: co::WS::CHILD | co::WS::VISIBLE,
+			x: 0,
+			y: 0,
+			cx: 0,
+			cy: 0,
+			id: 0,
+			class: DialogItemClass::Static,
+			title: ResId::Str(String::new()),
+			creation_data: Vec::new(),
+		}
+	}
+}
+
+/// Window class of a [`DialogTemplateItem`](crate::DialogTemplateItem).
+///
+/// The predefined classes are stored as ordinals in the template.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DialogItemClass {
+	/// Button (ordinal `0x0080`).
+	Button,
+	/// Edit (ordinal `0x0081`).
+	Edit,
+	/// Static (ordinal `0x0082`).
+	Static,
+	/// List box (ordinal `0x0083`).
+	ListBox,
+	/// Scroll bar (ordinal `0x0084`).
+	ScrollBar,
+	/// Combo box (ordinal `0x0085`).
+	ComboBox,
+	/// Any other ordinal.
+	Ordinal(u16),
+	/// A registered class name, like `"SysListView32"`.
+	Name(String),
+}
+
+impl DialogItemClass {
+	#[must_use]
+	fn from_res_id(id: ResId) -> Self {
+		match id {
+			ResId::Id(0x0080) => Self::Button,
+			ResId::Id(0x0081) => Self::Edit,
+			ResId::Id(0x0082) => Self::Static,
+			ResId::Id(0x0083) => Self::ListBox,
+			ResId::Id(0x0084) => Self::ScrollBar,
+			ResId::Id(0x0085) => Self::ComboBox,
+			ResId::Id(ord) => Self::Ordinal(ord),
+			ResId::Str(name) => Self::Name(name),
+		}
+	}
+
+	#[must_use]
+	fn to_res_id(&self) -> ResId {
+		match self {
+			Self::Button => ResId::Id(0x0080),
+			Self::Edit => ResId::Id(0x0081),
+			Self::Static => ResId::Id(0x0082),
+			Self::ListBox => ResId::Id(0x0083),
+			Self::ScrollBar => ResId::Id(0x0084),
+			Self::ComboBox => ResId::Id(0x0085),
+			Self::Ordinal(ord) => ResId::Id(*ord),
+			Self::Name(name) => ResId::Str(name.clone()),
+		}
+	}
+}
+
+impl DialogTemplate {
+	/// Parses a dialog template, as stored in an `RT_DIALOG` resource.
+	///
+	/// Both the extended `DLGTEMPLATEEX` and the classic
+	/// [`DLGTEMPLATE`](crate::DLGTEMPLATE) formats are accepted. Malformed data
+	/// yields [`ERROR::INVALID_DATA`](crate::co::ERROR::INVALID_DATA).
+	pub fn parse(data: &[u8]) -> SysResult<Self> {
+		let mut rd = RawReader::new(data);
+		let is_ex = rd.u16()? == 1 && rd.u16()? == 0xffff; // dlgVer and signature
+		rd.seek(0)?;
+
+		let (help_id, ex_style, style) = if is_ex {
+			rd.skip(4)?;
+			(rd.u32()?, rd.u32()?, rd.u32()?)
+		} else {
+			let style = rd.u32()?;
+			(0, rd.u32()?, style)
+		};
+		let (ex_style, style) = unsafe {
+			(co::WS_EX::from_raw(ex_style), co::WS::from_raw(style))
+		};
+		let num_items = rd.u16()?;
+		let (x, y, cx, cy) = (rd.u16()? as i16, rd.u16()? as i16, rd.u16()? as i16, rd.u16()? as i16);
+
+		let menu = read_sz_or_ord(&mut rd)?;
+		let class_name = read_sz_or_ord(&mut rd)?;
+		let title = rd.wstr_nullt()?;
+
+		let font = if style.has(co::DS::SETFONT.into()) {
+			let point_size = rd.u16()?;
+			let (weight, italic, char_set) = if is_ex {
+				(rd.u16()?, rd.u8()? != 0, rd.u8()?)
+			} else {
+				(400, false, 1)
+			};
+			Some(DialogTemplateFont {
+				point_size,
+				weight,
+				italic,
+				char_set,
+				face_name: rd.wstr_nullt()?,
+			})
+		} else {
+			None
+		};
+
+		let mut items = Vec::with_capacity(num_items as _);
+		for _ in 0..num_items {
+			rd.align(4)?;
+			let (help_id, ex_style, style) = if is_ex {
+				(rd.u32()?, rd.u32()?, rd.u32()?)
+			} else {
+				let style = rd.u32()?;
+				(0, rd.u32()?, style)
+			};
+			let (x, y, cx, cy) = (rd.u16()? as i16, rd.u16()? as i16, rd.u16()? as i16, rd.u16()? as i16);
+			let id = if is_ex { rd.u32()? } else { rd.u16()? as u32 };
+			let class = read_sz_or_ord(&mut rd)?
+				.map(DialogItemClass::from_res_id)
+				.ok_or(co::ERROR::INVALID_DATA)?;
+			let title = read_sz_or_ord(&mut rd)?
+				.unwrap_or(ResId::Str(String::new()));
+			let extra_count = rd.u16()? as usize;
+			let creation_data = if is_ex {
+				rd.bytes(extra_count)?
+			} else {
+				rd.bytes(extra_count.saturating_sub(2))? // count includes itself
+			}.to_vec();
+
+			items.push(DialogTemplateItem {
+				help_id,
+				ex_style: unsafe { co::WS_EX::from_raw(ex_style) },
+				style: unsafe { co::WS::from_raw(style) },
+				x, y, cx, cy,
+				id,
+				class,
+				title,
+				creation_data,
+			});
+		}
+
+		Ok(Self {
+			help_id, ex_style, style, x, y, cx, cy,
+			menu, class_name, title, font, items,
+		})
+	}
+
+	/// Serializes the template into a `DLGTEMPLATEEX` block, which can be
+	/// parsed back with [`DialogTemplate::parse`](crate::DialogTemplate::parse).
+	///
+	/// Fails with [`co::ERROR::INVALID_PARAMETER`](crate::co::ERROR::INVALID_PARAMETER)
+	/// if there are more than 65,535 items, or if an item has more than
+	/// 65,535 bytes of creation data.
+	pub fn serialize(&self) -> SysResult<Vec<u8>> {
+		let style = if self.font.is_some() {
+			self.style | co::DS::SETFONT.into()
+		} else {
+			self.style & !co::WS::from(co::DS::SETFONT)
+		};
+
+		let mut wr = RawWriter::new();
+		wr.u16(1); // dlgVer
+		wr.u16(0xffff); // signature
+		wr.u32(self.help_id);
+		wr.u32(self.ex_style.raw());
+		wr.u32(style.raw());
+		wr.u16(u16::try_from(self.items.len()).map_err(|_| co::ERROR::INVALID_PARAMETER)?);
+		[self.x, self.y, self.cx, self.cy].iter()
+			.for_each(|n| wr.u16(*n as _));
+		write_sz_or_ord(&mut wr, self.menu.as_ref());
+		write_sz_or_ord(&mut wr, self.class_name.as_ref());
+		wr.wstr_nullt(&self.title);
+
+		if let Some(font) = &self.font {
+			wr.u16(font.point_size);
+			wr.u16(font.weight);
+			wr.u8(font.italic as _);
+			wr.u8(font.char_set);
+			wr.wstr_nullt(&font.face_name);
+		}
+
+		for item in self.items.iter() {
+			wr.align(4);
+			wr.u32(item.help_id);
+			wr.u32(item.ex_style.raw());
+			wr.u32(item.style.raw());
+			[item.x, item.y, item.cx, item.cy].iter()
+				.for_each(|n| wr.u16(*n as _));
+			wr.u32(item.id);
+			write_sz_or_ord(&mut wr, Some(&item.class.to_res_id()));
+			write_sz_or_ord(&mut wr, Some(&item.title));
+			wr.u16(u16::try_from(item.creation_data.len()).map_err(|_| co::ERROR::INVALID_PARAMETER)?);
+			wr.bytes(&item.creation_data);
+		}
+
+		Ok(wr.into_vec())
+	}
+}
+
+/// Reads a `sz_Or_Ord` field: zero means no value, `0xffff` is followed by an
+/// ordinal, anything else is a null-terminated string.
+pub(in crate::user) fn read_sz_or_ord(rd: &mut RawReader) -> SysResult<Option<ResId>> {
+	let pos = rd.pos();
+	match rd.u16()? {
+		0x0000 => Ok(None),
+		0xffff => Ok(Some(ResId::Id(rd.u16()?))),
+		_ => {
+			rd.seek(pos)?;
+			Ok(Some(ResId::Str(rd.wstr_nullt()?)))
+		},
+	}
+}
+
+/// Writes a `sz_Or_Ord` field. An empty string is written as a single null.
+pub(in crate::user) fn write_sz_or_ord(wr: &mut RawWriter, id: Option<&ResId>) {
+	match id {
+		None => wr.u16(0x0000),
+		Some(ResId::Id(id)) => {
+			wr.u16(0xffff);
+			wr.u16(*id);
+		},
+		Some(ResId::Str(s)) => wr.wstr_nullt(s),
+	}
+}
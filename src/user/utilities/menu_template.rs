@@ -0,0 +1,364 @@
+use crate::co;
+use crate::decl::*;
+use crate::guard::*;
+use crate::kernel::privs::*;
+use crate::prelude::*;
+
+/// Owned, pure-Rust representation of an in-memory menu template, which can be
+/// serialized into a
+/// [`MENUEX_TEMPLATE`](https://learn.microsoft.com/en-us/windows/win32/menurc/menuex-template-header)
+/// block and parsed back.
+///
+/// The serialized block can be passed to
+/// [`HMENU::LoadMenuIndirect`](crate::prelude::user_Hmenu::LoadMenuIndirect),
+/// or simply loaded with [`MenuTemplate::load`](crate::MenuTemplate::load),
+/// without the need of a menu resource.
+///
+/// A menu must have at least one item, so there is no `Default`
+/// implementation.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, seq_ids};
+///
+/// seq_ids! {
+///     ID_FILE_OPEN = 101;
+///     ID_FILE_EXIT
+///     ID_HELP_ABOUT
+/// }
+///
+/// let tmpl = w::MenuTemplate::new(vec![
+///     w::MenuTemplateItem::submenu("&File", vec![
+///         w::MenuTemplateItem::entry(ID_FILE_OPEN as _, "&Open...\tCtrl+O"),
+///         w::MenuTemplateItem::separator(),
+///         w::MenuTemplateItem::entry(ID_FILE_EXIT as _, "E&xit"),
+///     ]),
+///     w::MenuTemplateItem::submenu("&Help", vec![
+///         w::MenuTemplateItem::entry(ID_HELP_ABOUT as _, "&About"),
+///     ]),
+/// ]);
+///
+/// assert!(tmpl.duplicate_ids().is_empty());
+///
+/// let hmenu = tmpl.load()?;
+/// # w::SysResult::Ok(())
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct MenuTemplate {
+	/// Context help identifier of the menu.
+	pub help_id: u32,
+	/// The items of the menu.
+	pub items: Vec<MenuTemplateItem>,
+}
+
+/// An item of a [`MenuTemplate`](crate::MenuTemplate), which is serialized as a
+/// [`MENUEX_TEMPLATE_ITEM`](https://learn.microsoft.com/en-us/windows/win32/menurc/menuex-template-item).
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct MenuTemplateItem {
+	/// Item type.
+	///
+	/// Defaults to `MFT::STRING`.
+	pub item_type: co::MFT,
+	/// Item state.
+	///
+	/// Defaults to `MFS::ENABLED`.
+	pub state: co::MFS,
+	/// Command ID.
+	///
+	/// Defaults to zero.
+	pub id: u32,
+	/// Text of the item.
+	///
+	/// Defaults to an empty string.
+	pub text: String,
+	/// The submenu opened by this item, if any.
+	///
+	/// Defaults to `None`.
+	pub submenu: Option<MenuTemplate>,
+}
+
+impl MenuTemplateItem {
+	/// Creates a command item.
+	#[must_use]
+	pub fn entry(id: u32, text: &str) -> Self {
+		Self {
+			id,
+			text: text.to_owned(),
+			..Default::default()
+		}
+	}
+
+	/// Creates a separator.
+	#[must_use]
+	pub fn separator() -> Self {
+		Self {
+			item_type: co::MFT::SEPARATOR,
+			..Default::default()
+		}
+	}
+
+	/// Creates an item which opens a submenu with the given items.
+	#[must_use]
+	pub fn submenu(text: &str, items: Vec<MenuTemplateItem>) -> Self {
+		Self {
+			text: text.to_owned(),
+			submenu: Some(MenuTemplate::new(items)),
+			..Default::default()
+		}
+	}
+}
+
+impl MenuTemplate {
+	const MAX_DEPTH: usize = 64;
+	const RES_POPUP: u16 = 0x01; // bResInfo flags
+	const RES_END: u16 = 0x80;
+	const CLASSIC_TYPE: u16 = 0x4964; // MF flags which become MFT
+	const CLASSIC_STATE: u16 = 0x100b; // MF flags which become MFS
+
+	/// Creates a new menu template with the given items, and a zero context
+	/// help identifier.
+	#[must_use]
+	pub const fn new(items: Vec<MenuTemplateItem>) -> Self {
+		Self { help_id: 0, items }
+	}
+
+	/// Parses a menu template, as stored in an `RT_MENU` resource.
+	///
+	/// Both the extended `MENUEX_TEMPLATE` and the classic `MENUTEMPLATE`
+	/// formats are accepted. Malformed data yields
+	/// [`ERROR::INVALID_DATA`](crate::co::ERROR::INVALID_DATA).
+	pub fn parse(data: &[u8]) -> SysResult<Self> {
+		let mut rd = RawReader::new(data);
+		let version = rd.u16()?;
+		let offset = rd.u16()? as usize;
+
+		match version {
+			0 => {
+				rd.skip(offset)?;
+				Ok(Self {
+					help_id: 0,
+					items: Self::read_items_classic(&mut rd, 0)?,
+				})
+			},
+			1 => {
+				let help_id = if offset >= 4 { rd.u32()? } else { 0 };
+				rd.seek(4 + offset)?;
+				Ok(Self {
+					help_id,
+					items: Self::read_items_ex(&mut rd, 0)?,
+				})
+			},
+			_ => Err(co::ERROR::INVALID_DATA),
+		}
+	}
+
+	fn read_items_ex(
+		rd: &mut RawReader,
+		depth: usize,
+	) -> SysResult<Vec<MenuTemplateItem>>
+	{
+		if depth > Self::MAX_DEPTH {
+			return Err(co::ERROR::INVALID_DATA);
+		}
+
+		let mut items = Vec::new();
+		loop {
+			let item_type = unsafe { co::MFT::from_raw(rd.u32()?) };
+			let state = unsafe { co::MFS::from_raw(rd.u32()?) };
+			let id = rd.u32()?;
+			let res_info = rd.u16()?;
+			let text = rd.wstr_nullt()?;
+			rd.align_lenient(4);
+
+			let submenu = if res_info & Self::RES_POPUP != 0 {
+				let help_id = rd.u32()?;
+				Some(MenuTemplate {
+					help_id,
+					items: Self::read_items_ex(rd, depth + 1)?,
+				})
+			} else {
+				None
+			};
+
+			items.push(MenuTemplateItem { item_type, state, id, text, submenu });
+			if res_info & Self::RES_END != 0 {
+				return Ok(items);
+			}
+		}
+	}
+
+	fn read_items_classic(
+		rd: &mut RawReader,
+		depth: usize,
+	) -> SysResult<Vec<MenuTemplateItem>>
+	{
+		if depth > Self::MAX_DEPTH {
+			return Err(co::ERROR::INVALID_DATA);
+		}
+
+		let mut items = Vec::new();
+		loop {
+			let option = rd.u16()?;
+			let is_popup = option & co::MF::POPUP.raw() as u16 != 0;
+			let id = if is_popup { 0 } else { rd.u16()? as u32 };
+			let text = rd.wstr_nullt()?;
+
+			let mut item_type = unsafe {
+				co::MFT::from_raw((option & Self::CLASSIC_TYPE) as _)
+			};
+			if !is_popup && id == 0 && text.is_empty() {
+				item_type |= co::MFT::SEPARATOR; // MENUITEM SEPARATOR
+			}
+
+			items.push(MenuTemplateItem {
+				item_type,
+				state: unsafe { co::MFS::from_raw((option & Self::CLASSIC_STATE) as _) },
+				id,
+				text,
+				submenu: if is_popup {
+					Some(MenuTemplate {
+						help_id: 0,
+						items: Self::read_items_classic(rd, depth + 1)?,
+					})
+				} else {
+					None
+				},
+			});
+			if option & Self::RES_END != 0 {
+				return Ok(items);
+			}
+		}
+	}
+
+	/// Serializes the template into a `MENUEX_TEMPLATE` block, which can be
+	/// parsed back with [`MenuTemplate::parse`](crate::MenuTemplate::parse).
+	///
+	/// Fails with [`co::ERROR::INVALID_PARAMETER`](crate::co::ERROR::INVALID_PARAMETER)
+	/// if the menu or any of its submenus has no items, since this cannot be
+	/// represented in the template.
+	pub fn serialize(&self) -> SysResult<Vec<u8>> {
+		let mut wr = RawWriter::new();
+		wr.u16(1); // wVersion
+		wr.u16(4); // wOffset
+		wr.u32(self.help_id);
+		Self::write_items_ex(&mut wr, &self.items)?;
+		Ok(wr.into_vec())
+	}
+
+	fn write_items_ex(
+		wr: &mut RawWriter,
+		items: &[MenuTemplateItem],
+	) -> SysResult<()>
+	{
+		if items.is_empty() {
+			return Err(co::ERROR::INVALID_PARAMETER); // the last item carries the end marker
+		}
+
+		for (idx, item) in items.iter().enumerate() {
+			let mut res_info = 0;
+			if item.submenu.is_some() {
+				res_info |= Self::RES_POPUP;
+			}
+			if idx == items.len() - 1 {
+				res_info |= Self::RES_END;
+			}
+
+			wr.u32(item.item_type.raw());
+			wr.u32(item.state.raw());
+			wr.u32(item.id);
+			wr.u16(res_info);
+			wr.wstr_nullt(&item.text);
+			wr.align(4);
+
+			if let Some(submenu) = &item.submenu {
+				wr.u32(submenu.help_id);
+				Self::write_items_ex(wr, &submenu.items)?;
+			}
+		}
+		Ok(())
+	}
+
+	/// Serializes the template and loads it with
+	/// [`HMENU::LoadMenuIndirect`](crate::prelude::user_Hmenu::LoadMenuIndirect).
+	///
+	/// Fails with [`co::ERROR::INVALID_PARAMETER`](crate::co::ERROR::INVALID_PARAMETER)
+	/// if the menu or any of its submenus has no items.
+	pub fn load(&self) -> SysResult<DestroyMenuGuard> {
+		unsafe { HMENU::LoadMenuIndirect(&self.serialize()?) }
+	}
+
+	/// Returns the IDs of all command items, including the ones in submenus,
+	/// in depth-first order. Separators and items which open submenus are not
+	/// included.
+	#[must_use]
+	pub fn command_ids(&self) -> Vec<u32> {
+		let mut ids = Vec::new();
+		Self::collect_ids(&self.items, &mut ids);
+		ids
+	}
+
+	fn collect_ids(items: &[MenuTemplateItem], ids: &mut Vec<u32>) {
+		for item in items.iter() {
+			if let Some(submenu) = &item.submenu {
+				Self::collect_ids(&submenu.items, ids);
+			} else if !item.item_type.has(co::MFT::SEPARATOR) {
+				ids.push(item.id);
+			}
+		}
+	}
+
+	/// Returns the command IDs which are used by more than one item, sorted.
+	///
+	/// An empty result means the menu has no conflicting commands.
+	#[must_use]
+	pub fn duplicate_ids(&self) -> Vec<u32> {
+		let mut ids = self.command_ids();
+		ids.sort_unstable();
+		let mut dups = ids.windows(2)
+			.filter(|pair| pair[0] == pair[1])
+			.map(|pair| pair[0])
+			.collect::<Vec<_>>();
+		dups.dedup();
+		dups
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn round_trip() {
+		let tmpl = MenuTemplate {
+			help_id: 7,
+			items: vec![
+				MenuTemplateItem::submenu("&File", vec![
+					MenuTemplateItem::entry(101, "&Open...\tCtrl+O"),
+					MenuTemplateItem::separator(),
+					MenuTemplateItem::entry(102, "E&xit"),
+				]),
+				MenuTemplateItem {
+					state: co::MFS::CHECKED,
+					..MenuTemplateItem::entry(103, "&Wrap")
+				},
+			],
+		};
+		let data = tmpl.serialize().unwrap();
+		assert_eq!(&data[..8], &[1, 0, 4, 0, 7, 0, 0, 0]);
+		assert!(MenuTemplate::parse(&data).unwrap() == tmpl);
+		assert_eq!(tmpl.command_ids(), [101, 102, 103]);
+	}
+
+	#[test]
+	fn empty_menus() {
+		let cases: &[MenuTemplate] = &[
+			MenuTemplate::new(Vec::new()),
+			MenuTemplate::new(vec![MenuTemplateItem::submenu("&File", Vec::new())]),
+		];
+		for tmpl in cases.iter() {
+			assert_eq!(tmpl.serialize().map_err(|e| e.raw()),
+				Err(co::ERROR::INVALID_PARAMETER.raw()));
+		}
+	}
+}
@@ -0,0 +1,12 @@
+mod accel_table;
+mod dialog_template;
+mod menu_template;
+
+pub use accel_table::AccelTable;
+pub use dialog_template::{
+	DialogItemClass,
+	DialogTemplate,
+	DialogTemplateFont,
+	DialogTemplateItem,
+};
+pub use menu_template::{MenuTemplate, MenuTemplateItem};
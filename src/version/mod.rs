@@ -2,6 +2,7 @@
 
 mod handles;
 mod structs;
+mod utilities;
 
 pub(in crate::version) mod ffi;
 pub mod co;
@@ -10,6 +11,7 @@ pub mod guard;
 pub mod decl {
 	pub use super::handles::decl::*;
 	pub use super::structs::*;
+	pub use super::utilities::*;
 }
 
 pub mod traits {
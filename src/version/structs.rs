@@ -6,6 +6,7 @@ use crate::decl::*;
 /// [`VS_FIXEDFILEINFO`](https://learn.microsoft.com/en-us/windows/win32/api/verrsrc/ns-verrsrc-vs_fixedfileinfo)
 /// struct.
 #[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct VS_FIXEDFILEINFO {
 	dwSignature: u32,
 	pub dwStrucVersion: u32,
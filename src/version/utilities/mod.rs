@@ -0,0 +1,3 @@
+mod version_info;
+
+pub use version_info::{VersionInfo, VersionStringTable};
@@ -0,0 +1,524 @@
+use crate::co;
+use crate::decl::*;
+use crate::kernel::privs::*;
+use crate::prelude::*;
+
+/// Owned, pure-Rust representation of a
+/// [`VS_VERSIONINFO`](https://learn.microsoft.com/en-us/windows/win32/menurc/vs-versioninfo)
+/// block, which can be parsed from and serialized into raw bytes.
+///
+/// Unlike [`HVERSIONINFO`](crate::HVERSIONINFO), no system calls are needed to
+/// read or build the block, so it can be used to generate version resources
+/// to be written with
+/// [`HUPDATERSRC::UpdateResource`](crate::prelude::kernel_Hupdatersrc::UpdateResource).
+///
+/// # Examples
+///
+/// Reading the product name of the current executable:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let exe_name = w::HINSTANCE::NULL.GetModuleFileName()?;
+/// let ver = w::VersionInfo::from_file(&exe_name)?;
+///
+/// if let Some((lang0, cp0)) = ver.langs_and_cps().first() {
+///     println!("{}", ver.str_val(*lang0, *cp0, "ProductName").unwrap_or_default());
+/// }
+/// # w::SysResult::Ok(())
+/// ```
+///
+/// Building a new block from scratch:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let mut ver = w::VersionInfo::new();
+/// ver.fixed_mut().unwrap().set_dwFileVersion([1, 2, 0, 0]);
+///
+/// let lang = w::LANGID::new(co::LANG::ENGLISH, co::SUBLANG::ENGLISH_US);
+/// ver.set_str_val(lang, co::CP::WINDOWS_1252, "ProductName", "Foo");
+///
+/// let raw_bytes = ver.serialize()?;
+/// # w::SysResult::Ok(())
+/// ```
+#[derive(Clone)]
+pub struct VersionInfo {
+	fixed: Option<VS_FIXEDFILEINFO>,
+	tables: Vec<VersionStringTable>,
+	translations: Vec<(LANGID, co::CP)>,
+}
+
+/// A single `StringTable` of a [`VersionInfo`](crate::VersionInfo) block,
+/// identified by its language and code page.
+#[derive(Clone)]
+pub struct VersionStringTable {
+	/// Language of the strings.
+	pub lang_id: LANGID,
+	/// Code page of the strings.
+	pub code_page: co::CP,
+	/// Name/value pairs, in the order they appear in the block.
+	pub strings: Vec<(String, String)>,
+}
+
+impl Default for VersionInfo {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl VersionInfo {
+	const ROOT_KEY: &'static str = "VS_VERSION_INFO";
+	const FIXED_SIGNATURE: u32 = 0xfeef_04bd;
+
+	/// Creates a new block with a default
+	/// [`VS_FIXEDFILEINFO`](crate::VS_FIXEDFILEINFO), and no string tables or
+	/// translations.
+	#[must_use]
+	pub fn new() -> Self {
+		Self {
+			fixed: Some(VS_FIXEDFILEINFO::default()),
+			tables: Vec::new(),
+			translations: Vec::new(),
+		}
+	}
+
+	/// Loads the version block of the given file with
+	/// [`HVERSIONINFO::GetFileVersionInfo`](crate::prelude::version_Hversioninfo::GetFileVersionInfo),
+	/// then parses it.
+	pub fn from_file(file_name: &str) -> SysResult<Self> {
+		let block_sz = HVERSIONINFO::GetFileVersionInfoSize(file_name)?;
+		let hversion = HVERSIONINFO::GetFileVersionInfo(file_name)?;
+		Self::parse(
+			unsafe {
+				std::slice::from_raw_parts(hversion.ptr() as *const u8, block_sz as _)
+			},
+		)
+	}
+
+	/// Parses a raw `VS_VERSIONINFO` block, as stored in an `RT_VERSION`
+	/// resource.
+	///
+	/// Unknown child blocks are ignored. Malformed data yields
+	/// [`ERROR::INVALID_DATA`](crate::co::ERROR::INVALID_DATA).
+	pub fn parse(data: &[u8]) -> SysResult<Self> {
+		let root = VerBlock::parse(&mut RawReader::new(data))?;
+		if root.key != Self::ROOT_KEY {
+			return Err(co::ERROR::INVALID_DATA);
+		}
+
+		let fixed = if root.value.is_empty() {
+			None
+		} else if root.value.len() < std::mem::size_of::<VS_FIXEDFILEINFO>() {
+			return Err(co::ERROR::INVALID_DATA);
+		} else {
+			let fixed = unsafe {
+				std::ptr::read_unaligned(root.value.as_ptr() as *const VS_FIXEDFILEINFO)
+			};
+			if RawReader::new(&root.value).u32()? != Self::FIXED_SIGNATURE {
+				return Err(co::ERROR::INVALID_DATA);
+			}
+			Some(fixed)
+		};
+
+		let mut new_self = Self { fixed, tables: Vec::new(), translations: Vec::new() };
+
+		for child in root.children.iter() {
+			match child.key.as_str() {
+				"StringFileInfo" => for table in child.children.iter() {
+					let (lang_id, code_page) = Self::parse_table_key(&table.key)?;
+					new_self.tables.push(VersionStringTable {
+						lang_id,
+						code_page,
+						strings: table.children.iter()
+							.map(|s| (s.key.clone(), s.text_value()))
+							.collect(),
+					});
+				},
+				"VarFileInfo" => for var in child.children.iter() {
+					if var.key == "Translation" {
+						let mut rd = RawReader::new(&var.value);
+						while rd.remaining() >= 4 {
+							let lang_id = unsafe { LANGID::from_raw(rd.u16()?) };
+							let code_page = unsafe { co::CP::from_raw(rd.u16()?) };
+							new_self.translations.push((lang_id, code_page));
+						}
+					}
+				},
+				_ => {}, // unknown block, ignore
+			}
+		}
+
+		Ok(new_self)
+	}
+
+	fn parse_table_key(key: &str) -> SysResult<(LANGID, co::CP)> {
+		if key.len() != 8 || !key.is_ascii() {
+			return Err(co::ERROR::INVALID_DATA);
+		}
+		let lang_id = u16::from_str_radix(&key[..4], 16)
+			.map_err(|_| co::ERROR::INVALID_DATA)?;
+		let code_page = u16::from_str_radix(&key[4..], 16)
+			.map_err(|_| co::ERROR::INVALID_DATA)?;
+		Ok(unsafe { (LANGID::from_raw(lang_id), co::CP::from_raw(code_page)) })
+	}
+
+	/// Serializes the object into a raw `VS_VERSIONINFO` block, which can be
+	/// parsed back with [`VersionInfo::parse`](crate::VersionInfo::parse).
+	///
+	/// Fails with [`ERROR::INVALID_DATA`](crate::co::ERROR::INVALID_DATA) if
+	/// the block, or any of its values, is larger than 65,535 bytes.
+	pub fn serialize(&self) -> SysResult<Vec<u8>> {
+		let mut root = VerBlock::new_binary(
+			Self::ROOT_KEY,
+			self.fixed.as_ref()
+				.map(|fixed| unsafe {
+					std::slice::from_raw_parts(
+						fixed as *const _ as *const u8,
+						std::mem::size_of::<VS_FIXEDFILEINFO>(),
+					)
+				}.to_vec())
+				.unwrap_or_default(),
+		);
+
+		if !self.tables.is_empty() {
+			let mut sfi = VerBlock::new_text("StringFileInfo", None);
+			sfi.children = self.tables.iter()
+				.map(|table| {
+					let mut blk = VerBlock::new_text(
+						&format!("{:04x}{:04x}",
+							u16::from(table.lang_id), u16::from(table.code_page)),
+						None,
+					);
+					blk.children = table.strings.iter()
+						.map(|(name, val)| VerBlock::new_text(name, Some(val)))
+						.collect();
+					blk
+				})
+				.collect();
+			root.children.push(sfi);
+		}
+
+		if !self.translations.is_empty() {
+			let mut wr = RawWriter::new();
+			self.translations.iter().for_each(|(lang_id, code_page)| {
+				wr.u16((*lang_id).into());
+				wr.u16((*code_page).raw());
+			});
+			let mut vfi = VerBlock::new_text("VarFileInfo", None);
+			vfi.children.push(VerBlock::new_binary("Translation", wr.into_vec()));
+			root.children.push(vfi);
+		}
+
+		let mut wr = RawWriter::new();
+		root.serialize(&mut wr)?;
+		Ok(wr.into_vec())
+	}
+
+	/// Returns the [`VS_FIXEDFILEINFO`](crate::VS_FIXEDFILEINFO), if any.
+	#[must_use]
+	pub const fn fixed(&self) -> Option<&VS_FIXEDFILEINFO> {
+		self.fixed.as_ref()
+	}
+
+	/// Returns a mutable reference to the
+	/// [`VS_FIXEDFILEINFO`](crate::VS_FIXEDFILEINFO), if any.
+	#[must_use]
+	pub fn fixed_mut(&mut self) -> Option<&mut VS_FIXEDFILEINFO> {
+		self.fixed.as_mut()
+	}
+
+	/// Sets or removes the [`VS_FIXEDFILEINFO`](crate::VS_FIXEDFILEINFO).
+	pub fn set_fixed(&mut self, fixed: Option<VS_FIXEDFILEINFO>) {
+		self.fixed = fixed;
+	}
+
+	/// Returns the language and code page pairs declared in the `Translation`
+	/// entry of `VarFileInfo`.
+	#[must_use]
+	pub fn langs_and_cps(&self) -> &[(LANGID, co::CP)] {
+		&self.translations
+	}
+
+	/// Replaces the language and code page pairs declared in the `Translation`
+	/// entry of `VarFileInfo`.
+	pub fn set_langs_and_cps(&mut self, langs_and_cps: &[(LANGID, co::CP)]) {
+		self.translations = langs_and_cps.to_vec();
+	}
+
+	/// Returns all the string tables.
+	#[must_use]
+	pub fn string_tables(&self) -> &[VersionStringTable] {
+		&self.tables
+	}
+
+	/// Returns all the string tables, allowing them to be modified.
+	#[must_use]
+	pub fn string_tables_mut(&mut self) -> &mut Vec<VersionStringTable> {
+		&mut self.tables
+	}
+
+	/// Returns the string table of the given language and code page, if any.
+	#[must_use]
+	pub fn string_table(&self,
+		lang_id: LANGID,
+		code_page: co::CP,
+	) -> Option<&VersionStringTable>
+	{
+		self.tables.iter()
+			.find(|t| t.lang_id == lang_id && t.code_page == code_page)
+	}
+
+	/// Retrieves a string value, the same way
+	/// [`HVERSIONINFO::str_val`](crate::prelude::version_Hversioninfo::str_val)
+	/// does.
+	#[must_use]
+	pub fn str_val(&self,
+		lang_id: LANGID,
+		code_page: co::CP,
+		name: &str,
+	) -> Option<&str>
+	{
+		self.string_table(lang_id, code_page)
+			.and_then(|t| t.strings.iter().find(|(n, _)| n == name))
+			.map(|(_, val)| val.as_str())
+	}
+
+	/// Sets a string value, replacing any existing one with the same name.
+	///
+	/// If there is no string table for the given language and code page, it
+	/// is created, and the pair is also added to the translations.
+	pub fn set_str_val(&mut self,
+		lang_id: LANGID,
+		code_page: co::CP,
+		name: &str,
+		val: &str,
+	) {
+		let idx = match self.tables.iter()
+			.position(|t| t.lang_id == lang_id && t.code_page == code_page)
+		{
+			Some(idx) => idx,
+			None => {
+				self.tables.push(VersionStringTable {
+					lang_id,
+					code_page,
+					strings: Vec::new(),
+				});
+				if !self.translations.contains(&(lang_id, code_page)) {
+					self.translations.push((lang_id, code_page));
+				}
+				self.tables.len() - 1
+			},
+		};
+
+		let strings = &mut self.tables[idx].strings;
+		match strings.iter_mut().find(|(n, _)| n == name) {
+			Some((_, cur_val)) => *cur_val = val.to_owned(),
+			None => strings.push((name.to_owned(), val.to_owned())),
+		}
+	}
+
+	/// Removes a string value, returning it, if any.
+	pub fn remove_str_val(&mut self,
+		lang_id: LANGID,
+		code_page: co::CP,
+		name: &str,
+	) -> Option<String>
+	{
+		self.tables.iter_mut()
+			.find(|t| t.lang_id == lang_id && t.code_page == code_page)
+			.and_then(|t| {
+				t.strings.iter()
+					.position(|(n, _)| n == name)
+					.map(|idx| t.strings.remove(idx).1)
+			})
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// Generic node of a version block: `VS_VERSIONINFO`, `StringFileInfo`,
+/// `StringTable`, `String`, `VarFileInfo` and `Var` all share this layout.
+struct VerBlock {
+	key: String,
+	is_text: bool,
+	value: Vec<u8>,
+	children: Vec<VerBlock>,
+}
+
+impl VerBlock {
+	fn new_text(key: &str, text: Option<&str>) -> Self {
+		let mut wr = RawWriter::new();
+		if let Some(text) = text {
+			wr.wstr_nullt(text);
+		}
+		Self { key: key.to_owned(), is_text: true, value: wr.into_vec(), children: Vec::new() }
+	}
+
+	fn new_binary(key: &str, value: Vec<u8>) -> Self {
+		Self { key: key.to_owned(), is_text: false, value, children: Vec::new() }
+	}
+
+	/// Parses the block at the current position, which is moved past its end.
+	/// Offsets are aligned relative to the beginning of the reader data.
+	fn parse(rd: &mut RawReader) -> SysResult<Self> {
+		let start = rd.pos();
+		let w_length = rd.u16()? as usize;
+		let w_value_length = rd.u16()? as usize;
+		let w_type = rd.u16()?;
+		let end = start + w_length;
+		if w_length < 6 || end > rd.data().len() {
+			return Err(co::ERROR::INVALID_DATA);
+		}
+
+		let mut blk_rd = RawReader::new(&rd.data()[..end]); // bound to this block
+		blk_rd.seek(rd.pos())?;
+		let key = blk_rd.wstr_nullt()?;
+		blk_rd.align_lenient(4);
+
+		let is_text = w_type == 1;
+		let value_sz = if is_text { w_value_length * 2 } else { w_value_length }
+			.min(blk_rd.remaining()); // some compilers count text in bytes
+		let value = blk_rd.bytes(value_sz)?.to_vec();
+		blk_rd.align_lenient(4);
+
+		let mut children = Vec::new();
+		while blk_rd.remaining() >= 6 {
+			children.push(Self::parse(&mut blk_rd)?);
+			blk_rd.align_lenient(4);
+		}
+
+		rd.seek(end)?;
+		Ok(Self { key, is_text, value, children })
+	}
+
+	/// Returns the text value, up to the first null.
+	fn text_value(&self) -> String {
+		String::from_utf16_lossy(
+			&self.value.chunks_exact(2)
+				.map(|ch2| u16::from_le_bytes([ch2[0], ch2[1]]))
+				.take_while(|wch| *wch != 0x0000)
+				.collect::<Vec<_>>(),
+		)
+	}
+
+	/// Serializes the block, and then patches its `wLength`. No trailing
+	/// padding is written.
+	fn serialize(&self, wr: &mut RawWriter) -> SysResult<()> {
+		let to_u16 = |len: usize| u16::try_from(len).map_err(|_| co::ERROR::INVALID_DATA);
+
+		wr.align(4);
+		let start = wr.pos();
+		wr.u16(0); // wLength, patched below
+		wr.u16(to_u16(if self.is_text { self.value.len() / 2 } else { self.value.len() })?);
+		wr.u16(if self.is_text { 1 } else { 0 });
+		wr.wstr_nullt(&self.key);
+		wr.align(4);
+		wr.bytes(&self.value);
+		for child in self.children.iter() {
+			child.serialize(wr)?;
+		}
+		wr.patch_u16(start, to_u16(wr.pos() - start)?);
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	fn en_us() -> LANGID {
+		LANGID::new(co::LANG::ENGLISH, co::SUBLANG::ENGLISH_US)
+	}
+
+	fn pt_br() -> LANGID {
+		LANGID::new(co::LANG::PORTUGUESE, co::SUBLANG::PORTUGUESE_BRAZILIAN)
+	}
+
+	fn round_trip(ver: &VersionInfo) -> VersionInfo {
+		VersionInfo::parse(&ver.serialize().unwrap()).unwrap()
+	}
+
+	fn tables(ver: &VersionInfo) -> Vec<(LANGID, co::CP, &[(String, String)])> {
+		ver.string_tables().iter()
+			.map(|t| (t.lang_id, t.code_page, &t.strings[..]))
+			.collect()
+	}
+
+	#[test]
+	fn build_and_parse() {
+		let mut ver = VersionInfo::new();
+		ver.fixed_mut().unwrap().set_dwFileVersion([1, 2, 3, 4]);
+		ver.set_str_val(en_us(), co::CP::WINDOWS_1252, "ProductName", "Foo");
+		ver.set_str_val(en_us(), co::CP::WINDOWS_1252, "FileDescription", "Foo tool");
+		ver.set_str_val(en_us(), co::CP::WINDOWS_1252, "Comments", ""); // empty value
+		ver.set_str_val(pt_br(), co::CP::UTF8, "ProductName", "Ação – ✓");
+		ver.set_str_val(pt_br(), co::CP::UTF8, "OddKey", "x"); // odd key length, padding
+
+		let parsed = round_trip(&ver);
+		assert!(parsed.fixed() == ver.fixed());
+		assert_eq!(parsed.fixed().unwrap().dwFileVersion(), [1, 2, 3, 4]);
+		assert_eq!(parsed.langs_and_cps(), ver.langs_and_cps());
+		assert_eq!(tables(&parsed), tables(&ver));
+		assert_eq!(parsed.str_val(pt_br(), co::CP::UTF8, "ProductName"), Some("Ação – ✓"));
+		assert_eq!(parsed.str_val(en_us(), co::CP::WINDOWS_1252, "Comments"), Some(""));
+
+		// serializing again yields the same bytes
+		assert_eq!(parsed.serialize().unwrap(), ver.serialize().unwrap());
+	}
+
+	#[test]
+	fn build_and_parse_minimal() {
+		let parsed = round_trip(&VersionInfo::new());
+		assert!(parsed.fixed() == Some(&VS_FIXEDFILEINFO::default()));
+		assert!(parsed.string_tables().is_empty());
+		assert!(parsed.langs_and_cps().is_empty());
+
+		let mut ver = VersionInfo::new();
+		ver.set_fixed(None);
+		ver.set_langs_and_cps(&[(en_us(), co::CP::WINDOWS_1252)]);
+		let parsed = round_trip(&ver);
+		assert!(parsed.fixed().is_none());
+		assert_eq!(parsed.langs_and_cps(), [(en_us(), co::CP::WINDOWS_1252)]);
+	}
+
+	#[test]
+	fn too_large() {
+		let mut ver = VersionInfo::new();
+		ver.set_str_val(en_us(), co::CP::WINDOWS_1252, "Big", &"x".repeat(40_000)); // value fits, block doesn't
+		assert_eq!(ver.serialize().err(), Some(co::ERROR::INVALID_DATA));
+
+		let mut ver = VersionInfo::new();
+		ver.set_str_val(en_us(), co::CP::WINDOWS_1252, "Huge", &"x".repeat(70_000)); // value doesn't fit
+		assert_eq!(ver.serialize().err(), Some(co::ERROR::INVALID_DATA));
+
+		let mut ver = VersionInfo::new();
+		for idx in 0..2_000 { // many small strings
+			ver.set_str_val(en_us(), co::CP::WINDOWS_1252, &format!("Key{}", idx), "value");
+		}
+		assert_eq!(ver.serialize().err(), Some(co::ERROR::INVALID_DATA));
+
+		let mut ver = VersionInfo::new();
+		ver.set_str_val(en_us(), co::CP::WINDOWS_1252, "Big", &"x".repeat(32_000)); // just below
+		assert_eq!(round_trip(&ver).str_val(en_us(), co::CP::WINDOWS_1252, "Big").unwrap().len(), 32_000);
+	}
+
+	#[test]
+	fn parse_malformed() {
+		let mut ver = VersionInfo::new();
+		ver.set_str_val(en_us(), co::CP::WINDOWS_1252, "ProductName", "Foo");
+		let data = ver.serialize().unwrap();
+
+		assert!(VersionInfo::parse(&[]).is_err());
+		assert!(VersionInfo::parse(&data[..5]).is_err());
+		assert!(VersionInfo::parse(&data[..data.len() / 2]).is_err());
+
+		let mut bad_key = data.clone();
+		bad_key[6] = b'X'; // first char of VS_VERSION_INFO
+		assert_eq!(VersionInfo::parse(&bad_key).err(), Some(co::ERROR::INVALID_DATA));
+
+		let mut bad_signature = data.clone();
+		bad_signature[40] ^= 0xff; // VS_FIXEDFILEINFO::dwSignature
+		assert_eq!(VersionInfo::parse(&bad_signature).err(), Some(co::ERROR::INVALID_DATA));
+	}
+}
@@ -0,0 +1,172 @@
+//! Derive macros for [WinSafe](https://crates.io/crates/winsafe), enabled by
+//! its `derive` feature. Don't use this crate directly.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::ext::IdentExt;
+
+/// Derives `winsafe::prelude::RegistryMapped` for a struct with named fields.
+///
+/// Each field accepts a `#[registry(...)]` attribute with:
+///
+/// * `rename = "Name"` – name of the value or subkey; defaults to the field
+///   name;
+/// * `default` – uses `Default::default()` if the value is missing;
+/// * `default = expr` – uses the expression if the value is missing.
+#[proc_macro_derive(RegistryMapped, attributes(registry))]
+pub fn derive_registry_mapped(input: TokenStream) -> TokenStream {
+	let input = syn::parse_macro_input!(input as syn::DeriveInput);
+	expand(&input)
+		.unwrap_or_else(|err| err.to_compile_error())
+		.into()
+}
+
+/// What to do when the value of a field is missing.
+enum Missing {
+	Error,
+	Default,
+	Expr(syn::Expr),
+}
+
+struct Field {
+	ident: syn::Ident,
+	value_name: syn::LitStr,
+	missing: Missing,
+}
+
+fn expand(input: &syn::DeriveInput) -> syn::Result<TokenStream2> {
+	if !input.generics.params.is_empty() {
+		return Err(syn::Error::new_spanned(&input.generics,
+			"RegistryMapped cannot be derived for generic structs."));
+	}
+
+	let fields = match &input.data {
+		syn::Data::Struct(syn::DataStruct { fields: syn::Fields::Named(fields), .. }) => fields,
+		syn::Data::Struct(_) => return Err(syn::Error::new_spanned(&input.ident,
+			"RegistryMapped can only be derived for structs with named fields.")),
+		_ => return Err(syn::Error::new_spanned(&input.ident,
+			"RegistryMapped can only be derived for structs.")),
+	};
+	let fields = fields.named.iter()
+		.map(parse_field)
+		.collect::<syn::Result<Vec<_>>>()?;
+
+	Ok(generate(&input.ident, &fields))
+}
+
+fn parse_field(field: &syn::Field) -> syn::Result<Field> {
+	let ident = field.ident.clone().unwrap(); // fields are named
+	let mut value_name = syn::LitStr::new(&ident.unraw().to_string(), ident.span());
+	let mut missing = Missing::Error;
+
+	for attr in field.attrs.iter().filter(|attr| attr.path().is_ident("registry")) {
+		attr.parse_nested_meta(|meta| {
+			if meta.path.is_ident("rename") {
+				value_name = meta.value()?.parse()?;
+			} else if meta.path.is_ident("default") {
+				missing = if meta.input.peek(syn::Token![=]) {
+					Missing::Expr(meta.value()?.parse()?)
+				} else {
+					Missing::Default
+				};
+			} else {
+				return Err(meta.error("Unknown registry attribute."));
+			}
+			Ok(())
+		})?;
+	}
+
+	Ok(Field { ident, value_name, missing })
+}
+
+fn generate(name: &syn::Ident, fields: &[Field]) -> TokenStream2 {
+	let reads = fields.iter()
+		.map(|Field { ident, value_name, missing }| {
+			let missing = match missing {
+				Missing::Error => quote! {
+					return ::std::result::Result::Err(::winsafe::RegistryMapError::Missing {
+						sub_key: sub_key.to_owned(),
+						value_name: #value_name.to_owned(),
+					})
+				},
+				Missing::Default => quote! { ::std::default::Default::default() },
+				Missing::Expr(expr) => quote! { { #expr } },
+			};
+			quote! {
+				#ident: match ::winsafe::prelude::RegistryField::read_field(reg, root, sub_key, #value_name)? {
+					::std::option::Option::Some(val) => val,
+					::std::option::Option::None => #missing,
+				},
+			}
+		});
+
+	let writes = fields.iter()
+		.map(|Field { ident, value_name, .. }| quote! {
+			::winsafe::prelude::RegistryField::write_field(&self.#ident, reg, root, sub_key, #value_name)?;
+		});
+
+	quote! {
+		impl ::winsafe::prelude::RegistryMapped for #name {
+			fn read_registry(
+				reg: &impl ::winsafe::prelude::RegistryProvider,
+				root: &::winsafe::HKEY,
+				sub_key: &str,
+			) -> ::std::result::Result<Self, ::winsafe::RegistryMapError>
+			{
+				::std::result::Result::Ok(Self { #(#reads)* })
+			}
+
+			fn write_registry(&self,
+				reg: &impl ::winsafe::prelude::RegistryProvider,
+				root: &::winsafe::HKEY,
+				sub_key: &str,
+			) -> ::std::result::Result<(), ::winsafe::RegistryMapError>
+			{
+				::winsafe::prelude::RegistryProvider::create_key(reg, root, sub_key)
+					.map_err(|error| ::winsafe::RegistryMapError::Sys {
+						sub_key: sub_key.to_owned(),
+						error,
+					})?;
+				#(#writes)*
+				::std::result::Result::Ok(())
+			}
+		}
+	}
+}
+
+// Expansion is tested by deriving real structs, in winsafe's registry_mapped.rs.
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	fn expand_err(input: syn::DeriveInput) -> String {
+		expand(&input).err().unwrap().to_string()
+	}
+
+	#[test]
+	fn rejected() {
+		assert!(expand_err(syn::parse_quote! { struct Config<T> { a: T } })
+			.contains("generic"));
+		assert!(expand_err(syn::parse_quote! { struct Config<'a> { a: &'a str } })
+			.contains("generic"));
+		assert!(expand_err(syn::parse_quote! { struct Config(u32); })
+			.contains("named fields"));
+		assert!(expand_err(syn::parse_quote! { struct Config; })
+			.contains("named fields"));
+		assert!(expand_err(syn::parse_quote! { enum Config { A, B } })
+			.contains("only be derived for structs"));
+		assert!(expand_err(syn::parse_quote! {
+			struct Config {
+				#[registry(renamed = "A")]
+				a: u32,
+			}
+		}).contains("Unknown registry attribute"));
+		assert!(expand_err(syn::parse_quote! {
+			struct Config {
+				#[registry(rename = A)]
+				a: u32,
+			}
+		}).contains("expected string literal"));
+	}
+}